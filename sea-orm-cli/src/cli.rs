@@ -3,7 +3,7 @@ use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
 use dotenvy::dotenv;
 
 #[cfg(feature = "codegen")]
-use crate::{handle_error, run_generate_command, run_migrate_command};
+use crate::{args_with_config, handle_error, run_generate_command, run_migrate_command};
 
 #[derive(Parser, Debug)]
 #[command(
@@ -48,6 +48,14 @@ pub struct Cli {
     #[arg(global = true, short, long, help = "Show debug messages")]
     pub verbose: bool,
 
+    #[arg(
+        global = true,
+        long,
+        value_name = "FILE",
+        help = "Read the options of the commands from the configuration file, `sea-orm.toml` by default"
+    )]
+    pub config: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -128,6 +136,14 @@ pub enum MigrateSubcommands {
             display_order = 1002
         )]
         local_time: bool,
+
+        #[arg(
+            long,
+            value_name = "ENTITY_DIR",
+            help = "Generate a data migration with a frozen copy of the entities in this directory",
+            display_order = 1003
+        )]
+        entities: Option<String>,
     },
     #[command(
         about = "Drop all tables from the database, then reapply all migrations",
@@ -147,6 +163,12 @@ pub enum MigrateSubcommands {
     Up {
         #[arg(short, long, help = "Number of pending migrations to apply")]
         num: Option<u32>,
+
+        #[arg(
+            long,
+            help = "Print the SQL script of the pending migrations without applying them"
+        )]
+        dry_run: bool,
     },
     #[command(about = "Rollback applied migrations", display_order = 80)]
     Down {
@@ -159,6 +181,16 @@ pub enum MigrateSubcommands {
         )]
         num: u32,
     },
+    #[command(about = "Insert or update the seed data", display_order = 100)]
+    Seed {
+        #[arg(
+            short,
+            long,
+            env = "SEED_ENV",
+            help = "Environment to seed, e.g. `development`; only the seeds of every environment are run if omitted"
+        )]
+        env: Option<String>,
+    },
 }
 
 #[derive(Subcommand, PartialEq, Eq, Debug)]
@@ -183,7 +215,7 @@ pub enum GenerateSubcommands {
             short = 't',
             long,
             value_delimiter = ',',
-            help = "Generate entity file for specified tables only (comma separated)"
+            help = "Generate entity file for specified tables only (comma separated), of names or patterns: globs, e.g. `blog_*`, or regular expressions between slashes, e.g. `/^blog_/`"
         )]
         tables: Vec<String>,
 
@@ -191,10 +223,56 @@ pub enum GenerateSubcommands {
             long,
             value_delimiter = ',',
             default_value = "seaql_migrations",
-            help = "Skip generating entity file for specified tables (comma separated)"
+            help = "Skip generating entity file for specified tables (comma separated), of names or patterns as of `--tables`"
         )]
         ignore_tables: Vec<String>,
 
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Skip generating the specified columns (comma separated), as `table.column` or `column` of every table, of names or patterns as of `--tables`, e.g. `--ignore-columns 'user.legacy_flag','audit_*'`. The columns of primary keys and foreign keys are generated"
+        )]
+        ignore_columns: Vec<String>,
+
+        #[arg(long, help = "Generate read-only entity files for views")]
+        include_views: bool,
+
+        #[arg(
+            long,
+            help = "Generate read-only entity files for materialized views, supported by PostgreSQL"
+        )]
+        include_materialized_views: bool,
+
+        #[arg(
+            long,
+            default_value_t,
+            value_enum,
+            help = "The names of the entities: as the tables, or in the singular or the plural, e.g. `Cake` of the table `cakes` by `singular`"
+        )]
+        entity_naming: EntityNaming,
+
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Strip the prefixes from the table names to name the entities (comma separated), e.g. `--strip-table-prefixes 'tbl_','t_'`"
+        )]
+        strip_table_prefixes: Vec<String>,
+
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Case the words of the entity names as the acronyms (comma separated), e.g. `--acronyms API,ID` names the entity of the table `api_key` `APIKey`"
+        )]
+        acronyms: Vec<String>,
+
+        #[arg(
+            long,
+            default_value_t,
+            value_enum,
+            help = "The names of the modules and files of the entities: after the entities, or after the tables"
+        )]
+        module_naming: ModuleNaming,
+
         #[arg(
             long,
             default_value = "1",
@@ -244,6 +322,26 @@ pub enum GenerateSubcommands {
         )]
         serde_skip_hidden_column: bool,
 
+        #[arg(
+            long,
+            value_delimiter = ',',
+            help = "Add serde field attributes to columns, no need for `serde()` (comma separated), e.g. `--column-serde-attributes 'user.password_hash=skip'` to skip a sensitive column, this flag will be effective only when '--with-serde' is enabled"
+        )]
+        column_serde_attributes: Vec<String>,
+
+        #[arg(
+            long,
+            default_value = "false",
+            help = "Derive validator's `Validate` trait for the model, with the `#[validate(length(max = N))]` attribute inferred for the columns of a string type of a maximum length. NOT NULL columns are fields of non-`Option` types, which are always present"
+        )]
+        with_validator: bool,
+
+        #[arg(
+            long,
+            help = "Write the TypeScript types of the models to the file, e.g. `--typescript-output ../frontend/src/entities.ts`: an interface of each model and a union type of the variants of each enum, as serialized by serde"
+        )]
+        typescript_output: Option<String>,
+
         #[arg(
             long,
             default_value = "false",
@@ -305,6 +403,80 @@ pub enum GenerateSubcommands {
         )]
         seaography: bool,
     },
+    #[command(
+        about = "Generate an entity relationship diagram of the tables of a database, or of the entity files of a crate"
+    )]
+    Diagram {
+        #[arg(
+            short = 'u',
+            long,
+            env = "DATABASE_URL",
+            help = "Database URL, of which the tables are drawn unless `--entity-dir` is given"
+        )]
+        database_url: Option<String>,
+
+        #[arg(
+            short = 's',
+            long,
+            env = "DATABASE_SCHEMA",
+            long_help = "Database schema\n \
+                        - For MySQL and SQLite, this argument is ignored.\n \
+                        - For PostgreSQL, this argument is optional with default value 'public'."
+        )]
+        database_schema: Option<String>,
+
+        #[arg(
+            short = 'e',
+            long,
+            help = "The directory of the entity files of the compact format, i.e. deriving `DeriveEntityModel`, to draw instead of the database"
+        )]
+        entity_dir: Option<String>,
+
+        #[arg(
+            long,
+            value_delimiter = ',',
+            default_value = "seaql_migrations",
+            help = "Skip drawing the specified tables of the database (comma separated), of names or patterns as of `generate entity --tables`"
+        )]
+        ignore_tables: Vec<String>,
+
+        #[arg(
+            long,
+            default_value = "1",
+            help = "The maximum amount of connections to use when connecting to the database."
+        )]
+        max_connections: u32,
+
+        #[arg(
+            short = 'f',
+            long,
+            default_value = "mermaid",
+            help = "The format of the diagram: `mermaid` or `graphviz`"
+        )]
+        format: String,
+
+        #[arg(
+            short = 'o',
+            long,
+            help = "The file to write the diagram to, instead of the standard output"
+        )]
+        output_file: Option<String>,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+pub enum EntityNaming {
+    #[default]
+    Table,
+    Singular,
+    Plural,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+pub enum ModuleNaming {
+    #[default]
+    Entity,
+    Table,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
@@ -320,7 +492,11 @@ pub enum DateTimeCrate {
 pub async fn main() {
     dotenv().ok();
 
-    let cli = Cli::parse();
+    let args = match args_with_config(std::env::args().collect()) {
+        Ok(args) => args,
+        Err(e) => return handle_error(e),
+    };
+    let cli = Cli::parse_from(args);
     let verbose = cli.verbose;
 
     match cli.command {
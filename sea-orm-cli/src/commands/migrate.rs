@@ -2,6 +2,7 @@ use chrono::{Local, Utc};
 use regex::Regex;
 use std::{
     error::Error,
+    ffi::OsStr,
     fmt::Display,
     fs,
     io::Write,
@@ -26,19 +27,27 @@ pub fn run_migrate_command(
             migration_name,
             universal_time: _,
             local_time,
-        }) => run_migrate_generate(migration_dir, &migration_name, !local_time)?,
+            entities,
+        }) => run_migrate_generate(
+            migration_dir,
+            &migration_name,
+            !local_time,
+            entities.as_deref(),
+        )?,
         _ => {
             let (subcommand, migration_dir, steps, verbose) = match command {
                 Some(MigrateSubcommands::Fresh) => ("fresh", migration_dir, None, verbose),
                 Some(MigrateSubcommands::Refresh) => ("refresh", migration_dir, None, verbose),
                 Some(MigrateSubcommands::Reset) => ("reset", migration_dir, None, verbose),
                 Some(MigrateSubcommands::Status) => ("status", migration_dir, None, verbose),
-                Some(MigrateSubcommands::Up { num }) => ("up", migration_dir, num, verbose),
+                Some(MigrateSubcommands::Up { num, .. }) => ("up", migration_dir, num, verbose),
                 Some(MigrateSubcommands::Down { num }) => {
                     ("down", migration_dir, Some(num), verbose)
                 }
+                Some(MigrateSubcommands::Seed { .. }) => ("seed", migration_dir, None, verbose),
                 _ => ("up", migration_dir, None, verbose),
             };
+            let dry_run = matches!(command, Some(MigrateSubcommands::Up { dry_run: true, .. }));
 
             // Construct the `--manifest-path`
             let manifest_path = if migration_dir.ends_with('/') {
@@ -62,11 +71,22 @@ pub fn run_migrate_command(
             if let Some(database_schema) = &database_schema {
                 args.extend(["-s", database_schema]);
             }
+            if dry_run {
+                args.push("--dry-run");
+            }
+            if let Some(MigrateSubcommands::Seed { env: Some(env) }) = &command {
+                args.extend(["-e", env.as_str()]);
+            }
             if verbose {
                 args.push("-v");
             }
             // Run migrator CLI on user's behalf
-            println!("Running `cargo {}`", args.join(" "));
+            if dry_run {
+                // stdout is the SQL script
+                eprintln!("Running `cargo {}`", args.join(" "));
+            } else {
+                println!("Running `cargo {}`", args.join(" "));
+            }
             let exit_status = Command::new("cargo").args(args).status()?; // Get the status code
             if !exit_status.success() {
                 // Propagate the error if any
@@ -129,6 +149,7 @@ pub fn run_migrate_generate(
     migration_dir: &str,
     migration_name: &str,
     universal_time: bool,
+    entity_dir: Option<&str>,
 ) -> Result<(), Box<dyn Error>> {
     // Make sure the migration name doesn't contain any characters that
     // are invalid module names in Rust.
@@ -151,7 +172,10 @@ pub fn run_migrate_generate(
     let migration_name = migration_name.trim().replace(' ', "_");
     let migration_name = format!("m{formatted_now}_{migration_name}");
 
-    create_new_migration(&migration_name, migration_dir)?;
+    match entity_dir {
+        Some(entity_dir) => create_new_data_migration(&migration_name, migration_dir, entity_dir)?,
+        None => create_new_migration(&migration_name, migration_dir)?,
+    }
     update_migrator(&migration_name, migration_dir)?;
 
     Ok(())
@@ -187,6 +211,76 @@ fn create_new_migration(migration_name: &str, migration_dir: &str) -> Result<(),
     Ok(())
 }
 
+/// `create_new_data_migration` creates the migration as a module directory,
+/// with a snapshot of the entities in `entity_dir` as its `entities` submodule.
+///
+/// Like `migration_dir`, `entity_dir` can be the root of an entity crate,
+/// in which case its `src` directory is copied, and `lib.rs` becomes `mod.rs`.
+fn create_new_data_migration(
+    migration_name: &str,
+    migration_dir: &str,
+    entity_dir: &str,
+) -> Result<(), Box<dyn Error>> {
+    let migration_path = get_full_migration_dir(migration_dir).join(migration_name);
+    let snapshot_path = migration_path.join("entities");
+    let migration_filepath = migration_path.join("mod.rs");
+    println!("Creating migration file `{}`", migration_filepath.display());
+    fs::create_dir_all(&snapshot_path)?;
+    let migration_template = include_str!("../../template/data_migration/mod.rs");
+    let mut migration_file = fs::File::create(migration_filepath)?;
+    migration_file.write_all(migration_template.as_bytes())?;
+
+    let entity_dir = get_full_migration_dir(entity_dir);
+    println!(
+        "Copying entities of `{}` to `{}`",
+        entity_dir.display(),
+        snapshot_path.display()
+    );
+    snapshot_entities(&entity_dir, &snapshot_path)
+}
+
+fn snapshot_entities(entity_dir: &Path, snapshot_dir: &Path) -> Result<(), Box<dyn Error>> {
+    for entry in fs::read_dir(entity_dir)? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name() else {
+            continue;
+        };
+        if path.is_dir() {
+            let snapshot_subdir = snapshot_dir.join(file_name);
+            fs::create_dir_all(&snapshot_subdir)?;
+            snapshot_entities(&path, &snapshot_subdir)?;
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            let file_name = match file_name.to_str() {
+                Some("lib.rs") => OsStr::new("mod.rs"),
+                _ => file_name,
+            };
+            let content = fs::read_to_string(&path)?;
+            let mut file = fs::File::create(snapshot_dir.join(file_name))?;
+            file.write_all(import_sea_orm(&content).as_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+/// The migration crate depends on `sea_orm` through `sea_orm_migration`,
+/// so the entities import it after their inner doc comments and attributes.
+fn import_sea_orm(content: &str) -> String {
+    if !content.contains("sea_orm::") {
+        return content.to_owned();
+    }
+    let header_len: usize = content
+        .split_inclusive('\n')
+        .take_while(|line| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with("//!") || line.starts_with("#![")
+        })
+        .map(str::len)
+        .sum();
+    let mut content = content.to_owned();
+    content.insert_str(header_len, "use sea_orm_migration::sea_orm;\n");
+    content
+}
+
 /// `get_migrator_filepath` looks for a file `migration_dir/src/lib.rs`
 /// and returns that path if found.
 ///
@@ -293,6 +387,41 @@ mod tests {
         fs::remove_dir_all("/tmp/sea_orm_cli_test_new_migration/").unwrap();
     }
 
+    #[test]
+    fn test_create_new_data_migration() {
+        let migration_name = "test_name";
+        let migration_dir = "/tmp/sea_orm_cli_test_new_data_migration/";
+        let entity_dir = "/tmp/sea_orm_cli_test_new_data_migration_entity/";
+        fs::create_dir_all(format!("{migration_dir}src")).unwrap();
+        fs::create_dir_all(format!("{entity_dir}src")).unwrap();
+        fs::write(
+            format!("{entity_dir}src/lib.rs"),
+            "pub mod prelude;\n\npub mod cake;\n",
+        )
+        .unwrap();
+        fs::write(
+            format!("{entity_dir}src/cake.rs"),
+            "//! `SeaORM` Entity\n\nuse sea_orm::entity::prelude::*;\n",
+        )
+        .unwrap();
+        create_new_data_migration(migration_name, migration_dir, entity_dir).unwrap();
+        let migration_path = Path::new(migration_dir).join("src").join(migration_name);
+        assert_eq!(
+            fs::read_to_string(migration_path.join("mod.rs")).unwrap(),
+            include_str!("../../template/data_migration/mod.rs")
+        );
+        assert_eq!(
+            fs::read_to_string(migration_path.join("entities").join("mod.rs")).unwrap(),
+            "pub mod prelude;\n\npub mod cake;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(migration_path.join("entities").join("cake.rs")).unwrap(),
+            "//! `SeaORM` Entity\n\nuse sea_orm_migration::sea_orm;\nuse sea_orm::entity::prelude::*;\n"
+        );
+        fs::remove_dir_all(migration_dir).unwrap();
+        fs::remove_dir_all(entity_dir).unwrap();
+    }
+
     #[test]
     fn test_update_migrator() {
         let migration_name = "test_name";
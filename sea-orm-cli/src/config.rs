@@ -0,0 +1,265 @@
+use clap::{Command, CommandFactory};
+use std::{error::Error, fs, path::Path};
+use toml::{Table, Value};
+
+use crate::Cli;
+
+/// The configuration file read from the working directory if `--config` isn't given
+pub const CONFIG_FILE: &str = "sea-orm.toml";
+
+/// The key of the environment variable holding the database URL, instead of `DATABASE_URL`
+const DATABASE_URL_ENV: &str = "database-url-env";
+
+/// The command line arguments with the options of the configuration file inserted after the
+/// subcommands, so that a command line option overrides the configuration.
+///
+/// The configuration is read from `--config <FILE>`, or else from `sea-orm.toml` in the working
+/// directory if it exists. Each table of the file holds the options of a subcommand, by their
+/// long names, e.g. `[generate.entity]` or `[migrate]`, and the options of the top level apply to
+/// every subcommand accepting them, e.g. `migration-dir` or `database-schema`.
+///
+/// ```toml
+/// database-url-env = "BAKERY_DATABASE_URL"
+/// migration-dir = "./crates/migration"
+///
+/// [generate.entity]
+/// output-dir = "./src/entities"
+/// with-serde = "both"
+/// expanded-format = true
+/// ignore-tables = ["seaql_migrations", "audit_log"]
+/// ```
+pub fn args_with_config(args: Vec<String>) -> Result<Vec<String>, Box<dyn Error>> {
+    let path = match config_path(&args) {
+        Some(path) => path.to_owned(),
+        None if Path::new(CONFIG_FILE).is_file() => CONFIG_FILE.to_owned(),
+        None => return Ok(args),
+    };
+    let config: Table = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read `{path}`: {e}"))?
+        .parse()
+        .map_err(|e| format!("Failed to parse `{path}`: {e}"))?;
+    insert_config_args(args, &config, |key| std::env::var(key).ok())
+}
+
+/// The value of `--config` in the command line arguments
+fn config_path(args: &[String]) -> Option<&str> {
+    args.iter()
+        .enumerate()
+        .find_map(|(i, arg)| match arg.as_str() {
+            "--config" => args.get(i + 1).map(String::as_str),
+            arg => arg.strip_prefix("--config="),
+        })
+}
+
+fn insert_config_args<F>(
+    mut args: Vec<String>,
+    config: &Table,
+    env_var: F,
+) -> Result<Vec<String>, Box<dyn Error>>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut command = Cli::command();
+    command.build();
+
+    // Find the subcommands, and the tables of their options
+    let mut tables = vec![(String::new(), config)];
+    let mut table = Some(config);
+    let mut section = String::new();
+    let mut insert_at = 1;
+    for (i, arg) in args.iter().enumerate().skip(1) {
+        if arg.starts_with('-') {
+            continue;
+        }
+        let Some(subcommand) = command.find_subcommand(arg).cloned() else {
+            break;
+        };
+        command = subcommand;
+        insert_at = i + 1;
+        if !section.is_empty() {
+            section.push('.');
+        }
+        section.push_str(command.get_name());
+        table = match table.and_then(|table| table.get(command.get_name())) {
+            Some(Value::Table(table)) => Some(table),
+            Some(_) => return Err(format!("`{section}` of the configuration isn't a table").into()),
+            None => None,
+        };
+        if let Some(table) = table {
+            tables.push((section.clone(), table));
+        }
+    }
+
+    let mut config_args = Vec::new();
+    for (depth, (section, table)) in tables.iter().enumerate() {
+        for (key, value) in table.iter() {
+            if matches!(value, Value::Table(_)) {
+                continue;
+            }
+            if key == DATABASE_URL_ENV {
+                let url = match value {
+                    Value::String(var) => env_var(var),
+                    _ => return Err(format!("`{key}` of the configuration isn't a string").into()),
+                };
+                if let Some(url) = url.filter(|_| has_option(&command, "database-url")) {
+                    push_option(&command, &args, &mut config_args, "database-url", url);
+                }
+                continue;
+            }
+            if !has_option(&command, key) {
+                // The options of the top level may belong to other subcommands
+                if depth == 0 {
+                    continue;
+                }
+                return Err(
+                    format!("`{section}` has no option `{key}` in the configuration").into(),
+                );
+            }
+            for value in option_values(key, value)? {
+                push_option(&command, &args, &mut config_args, key, value);
+            }
+        }
+    }
+
+    args.splice(insert_at..insert_at, config_args);
+    Ok(args)
+}
+
+fn has_option(command: &Command, long: &str) -> bool {
+    command
+        .get_arguments()
+        .any(|arg| arg.get_long() == Some(long))
+}
+
+/// Push the option unless it's given in the command line arguments
+fn push_option(
+    command: &Command,
+    args: &[String],
+    config_args: &mut Vec<String>,
+    long: &str,
+    value: String,
+) {
+    let short = command
+        .get_arguments()
+        .find(|arg| arg.get_long() == Some(long))
+        .and_then(|arg| arg.get_short());
+    let given = args.iter().any(|arg| {
+        arg.strip_prefix("--")
+            .map_or(false, |arg| arg.split('=').next() == Some(long))
+            || short.map_or(false, |short| {
+                !arg.starts_with("--") && arg.starts_with('-') && arg[1..].starts_with(short)
+            })
+    });
+    if given {
+        return;
+    }
+    match value.is_empty() {
+        true => config_args.push(format!("--{long}")),
+        false => config_args.push(format!("--{long}={value}")),
+    }
+}
+
+/// The values of an option, an empty string for a flag
+fn option_values(key: &str, value: &Value) -> Result<Vec<String>, Box<dyn Error>> {
+    let values = match value {
+        Value::String(value) => vec![value.clone()],
+        Value::Integer(value) => vec![value.to_string()],
+        Value::Float(value) => vec![value.to_string()],
+        Value::Boolean(true) => vec![String::new()],
+        Value::Boolean(false) => Vec::new(),
+        Value::Array(values) => {
+            return values
+                .iter()
+                .map(|value| match value {
+                    Value::String(value) => Ok(value.clone()),
+                    Value::Integer(value) => Ok(value.to_string()),
+                    _ => {
+                        Err(format!("`{key}` of the configuration isn't a list of strings").into())
+                    }
+                })
+                .collect()
+        }
+        _ => return Err(format!("`{key}` of the configuration isn't supported").into()),
+    };
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn config() -> Table {
+        r#"
+            database-url-env = "BAKERY_DATABASE_URL"
+            migration-dir = "./crates/migration"
+
+            [generate.entity]
+            output-dir = "./src/entities"
+            expanded-format = true
+            with-copy-enums = false
+            ignore-tables = ["seaql_migrations", "audit_log"]
+        "#
+        .parse()
+        .unwrap()
+    }
+
+    fn env_var(key: &str) -> Option<String> {
+        (key == "BAKERY_DATABASE_URL").then(|| "postgres://localhost/bakery".to_owned())
+    }
+
+    #[test]
+    fn test_insert_config_args() {
+        assert_eq!(
+            insert_config_args(
+                args(&["sea-orm-cli", "generate", "entity", "-o", "./out"]),
+                &config(),
+                env_var
+            )
+            .unwrap(),
+            args(&[
+                "sea-orm-cli",
+                "generate",
+                "entity",
+                "--database-url=postgres://localhost/bakery",
+                "--expanded-format",
+                "--ignore-tables=seaql_migrations",
+                "--ignore-tables=audit_log",
+                "-o",
+                "./out",
+            ])
+        );
+        assert_eq!(
+            insert_config_args(
+                args(&["sea-orm-cli", "migrate", "up", "-n", "1"]),
+                &config(),
+                |_| None
+            )
+            .unwrap(),
+            args(&[
+                "sea-orm-cli",
+                "migrate",
+                "up",
+                "--migration-dir=./crates/migration",
+                "-n",
+                "1",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_insert_config_args_unknown_option() {
+        let config: Table = "[generate.entity]\noutput-directory = \"./src\""
+            .parse()
+            .unwrap();
+        assert!(insert_config_args(
+            args(&["sea-orm-cli", "generate", "entity"]),
+            &config,
+            |_| None
+        )
+        .is_err());
+    }
+}
@@ -1,7 +1,11 @@
 #[cfg(feature = "cli")]
 pub mod cli;
 pub mod commands;
+#[cfg(feature = "cli")]
+pub mod config;
 
 #[cfg(feature = "cli")]
 pub use cli::*;
 pub use commands::*;
+#[cfg(feature = "cli")]
+pub use config::*;
@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{entity::*, query::*};
+
+// A frozen copy of the entities at the time this migration was generated. Unlike the entities
+// of the app, it isn't changed when the schema evolves, so the migration keeps compiling.
+#[allow(dead_code)]
+mod entities;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Replace the sample below with your own data migration, e.g.
+        //
+        // for post in entities::post::Entity::find().all(db).await? {
+        //     let mut post: entities::post::ActiveModel = post.into();
+        //     post.slug = Set(post.title.as_ref().to_lowercase().replace(' ', "-"));
+        //     post.update(db).await?;
+        // }
+        todo!();
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Replace the sample below with your own data migration, e.g.
+        //
+        // entities::post::Entity::update_many()
+        //     .col_expr(entities::post::Column::Slug, Expr::value(""))
+        //     .exec(db)
+        //     .await?;
+        todo!();
+    }
+}
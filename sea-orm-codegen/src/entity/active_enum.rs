@@ -1,7 +1,7 @@
 use heck::ToUpperCamelCase;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use sea_query::DynIden;
+use sea_query::{ColumnType, DynIden};
 use std::fmt::Write;
 
 use crate::WithSerde;
@@ -10,9 +10,43 @@ use crate::WithSerde;
 pub struct ActiveEnum {
     pub(crate) enum_name: DynIden,
     pub(crate) values: Vec<DynIden>,
+    /// The string column type the values are stored in, e.g. as restricted by a `CHECK`
+    /// constraint, or `None` for an enum type of the database
+    pub(crate) string_type: Option<ColumnType>,
 }
 
 impl ActiveEnum {
+    /// The identifiers of the enum variants of the values
+    pub(crate) fn get_variant_idents(&self) -> Vec<Ident> {
+        let enum_name = self.enum_name.to_string();
+        self.values
+            .iter()
+            .map(|v| v.to_string())
+            .map(|v| {
+                let v = v.trim();
+                if v.chars().next().map(char::is_numeric).unwrap_or(false) {
+                    format_ident!("_{}", v)
+                } else {
+                    let variant_name = v.to_upper_camel_case();
+                    if variant_name.is_empty() {
+                        println!("Warning: item '{}' in the enumeration '{}' cannot be converted into a valid Rust enum member name. It will be converted to its corresponding UTF-8 encoding. You can modify it later as needed.", v, enum_name);
+                        let mut ss = String::new();
+                        for c in v.chars() {
+                            if c.len_utf8() > 1 {
+                                write!(&mut ss, "{c}").unwrap();
+                            } else {
+                                write!(&mut ss, "U{:04X}", c as u32).unwrap();
+                            }
+                        }
+                        format_ident!("{}", ss)
+                    } else {
+                        format_ident!("{}", variant_name)
+                    }
+                }
+            })
+            .collect()
+    }
+
     pub fn impl_active_enum(
         &self,
         with_serde: &WithSerde,
@@ -23,27 +57,7 @@ impl ActiveEnum {
         let enum_name = &self.enum_name.to_string();
         let enum_iden = format_ident!("{}", enum_name.to_upper_camel_case());
         let values: Vec<String> = self.values.iter().map(|v| v.to_string()).collect();
-        let variants = values.iter().map(|v| v.trim()).map(|v| {
-            if v.chars().next().map(char::is_numeric).unwrap_or(false) {
-                format_ident!("_{}", v)
-            } else {
-                let variant_name = v.to_upper_camel_case();
-                if variant_name.is_empty() {
-                    println!("Warning: item '{}' in the enumeration '{}' cannot be converted into a valid Rust enum member name. It will be converted to its corresponding UTF-8 encoding. You can modify it later as needed.", v, enum_name);
-                    let mut ss = String::new();
-                    for c in v.chars() {
-                        if c.len_utf8() > 1 {
-                            write!(&mut ss, "{c}").unwrap();
-                        } else {
-                            write!(&mut ss, "U{:04X}", c as u32).unwrap();
-                        }
-                    }
-                    format_ident!("{}", ss)
-                } else {
-                    format_ident!("{}", variant_name)
-                }
-            }
-        });
+        let variants = self.get_variant_idents();
 
         let serde_derive = with_serde.extra_derive();
         let copy_derive = if with_copy_enums {
@@ -52,9 +66,21 @@ impl ActiveEnum {
             quote! {}
         };
 
+        let db_type = match &self.string_type {
+            None => quote! { db_type = "Enum", enum_name = #enum_name },
+            Some(col_type) => {
+                let db_type = match col_type {
+                    ColumnType::Char(len) => format!("Char({len:?})"),
+                    ColumnType::String(len) => format!("String(StringLen::{len:?})"),
+                    _ => "Text".to_owned(),
+                };
+                quote! { db_type = #db_type }
+            }
+        };
+
         quote! {
             #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum #copy_derive #serde_derive #extra_derives)]
-            #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = #enum_name)]
+            #[sea_orm(rs_type = "String", #db_type)]
             #extra_attributes
             pub enum #enum_iden {
                 #(
@@ -71,7 +97,7 @@ mod tests {
     use super::*;
     use crate::entity::writer::{bonus_attributes, bonus_derive};
     use pretty_assertions::assert_eq;
-    use sea_query::{Alias, IntoIden};
+    use sea_query::{Alias, IntoIden, StringLen};
 
     #[test]
     fn test_enum_variant_starts_with_number() {
@@ -94,6 +120,7 @@ mod tests {
                 .into_iter()
                 .map(|variant| Alias::new(variant).into_iden())
                 .collect(),
+                string_type: None,
             }
             .impl_active_enum(
                 &WithSerde::None,
@@ -143,6 +170,7 @@ mod tests {
                     .into_iter()
                     .map(|variant| Alias::new(variant).into_iden())
                     .collect(),
+                string_type: None,
             }
             .impl_active_enum(
                 &WithSerde::None,
@@ -179,6 +207,7 @@ mod tests {
                     .into_iter()
                     .map(|variant| Alias::new(variant).into_iden())
                     .collect(),
+                string_type: None,
             }
             .impl_active_enum(
                 &WithSerde::None,
@@ -211,6 +240,7 @@ mod tests {
                     .into_iter()
                     .map(|variant| Alias::new(variant).into_iden())
                     .collect(),
+                string_type: None,
             }
             .impl_active_enum(
                 &WithSerde::None,
@@ -257,6 +287,7 @@ mod tests {
                 .into_iter()
                 .map(|variant| Alias::new(variant).into_iden())
                 .collect(),
+                string_type: None,
             }
             .impl_active_enum(
                 &WithSerde::None,
@@ -290,4 +321,36 @@ mod tests {
             .to_string()
         )
     }
+
+    #[test]
+    fn test_string_active_enum() {
+        assert_eq!(
+            ActiveEnum {
+                enum_name: Alias::new("post_status").into_iden(),
+                values: vec!["draft", "published"]
+                    .into_iter()
+                    .map(|variant| Alias::new(variant).into_iden())
+                    .collect(),
+                string_type: Some(ColumnType::String(StringLen::N(20))),
+            }
+            .impl_active_enum(
+                &WithSerde::None,
+                false,
+                &TokenStream::new(),
+                &TokenStream::new(),
+            )
+            .to_string(),
+            quote!(
+                #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+                #[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+                pub enum PostStatus {
+                    #[sea_orm(string_value = "draft")]
+                    Draft,
+                    #[sea_orm(string_value = "published")]
+                    Published,
+                }
+            )
+            .to_string()
+        )
+    }
 }
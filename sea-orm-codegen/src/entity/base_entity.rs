@@ -5,12 +5,16 @@ use quote::quote;
 use sea_query::ColumnType;
 
 use crate::{
-    util::escape_rust_keyword, Column, ConjunctRelation, DateTimeCrate, PrimaryKey, Relation,
+    util::escape_rust_keyword, Column, ConjunctRelation, DateTimeCrate, EntityName, PrimaryKey,
+    Relation,
 };
 
 #[derive(Clone, Debug)]
 pub struct Entity {
     pub(crate) table_name: String,
+    pub(crate) comment: Option<String>,
+    pub(crate) view: bool,
+    pub(crate) name: Option<EntityName>,
     pub(crate) columns: Vec<Column>,
     pub(crate) relations: Vec<Relation>,
     pub(crate) conjunct_relations: Vec<ConjunctRelation>,
@@ -19,11 +23,17 @@ pub struct Entity {
 
 impl Entity {
     pub fn get_table_name_snake_case(&self) -> String {
-        self.table_name.to_snake_case()
+        match &self.name {
+            Some(name) => name.module.clone(),
+            None => self.table_name.to_snake_case(),
+        }
     }
 
     pub fn get_table_name_camel_case(&self) -> String {
-        self.table_name.to_upper_camel_case()
+        match &self.name {
+            Some(name) => name.camel_case.clone(),
+            None => self.table_name.to_upper_camel_case(),
+        }
     }
 
     pub fn get_table_name_snake_case_ident(&self) -> Ident {
@@ -262,6 +272,13 @@ impl Entity {
             })
             .collect()
     }
+
+    pub fn get_column_validate_attributes(&self) -> Vec<TokenStream> {
+        self.columns
+            .iter()
+            .map(|col| col.get_validate_attribute())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -274,6 +291,9 @@ mod tests {
     fn setup() -> Entity {
         Entity {
             table_name: "special_cake".to_owned(),
+            comment: None,
+            view: false,
+            name: None,
             columns: vec![
                 Column {
                     name: "id".to_owned(),
@@ -281,6 +301,9 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    domain: None,
+                    serde_attributes: Vec::new(),
+                    comment: None,
                 },
                 Column {
                     name: "name".to_owned(),
@@ -288,6 +311,9 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    domain: None,
+                    serde_attributes: Vec::new(),
+                    comment: None,
                 },
             ],
             relations: vec![
@@ -301,6 +327,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 },
                 Relation {
                     ref_table: "filling".to_owned(),
@@ -312,6 +339,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 },
             ],
             conjunct_relations: vec![],
@@ -1,6 +1,6 @@
 use crate::{util::escape_rust_keyword, DateTimeCrate};
 use heck::{ToSnakeCase, ToUpperCamelCase};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote};
 use sea_query::{ColumnDef, ColumnSpec, ColumnType, StringLen};
 use std::fmt::Write as FmtWrite;
@@ -12,6 +12,9 @@ pub struct Column {
     pub(crate) auto_increment: bool,
     pub(crate) not_null: bool,
     pub(crate) unique: bool,
+    pub(crate) domain: Option<String>,
+    pub(crate) serde_attributes: Vec<String>,
+    pub(crate) comment: Option<String>,
 }
 
 impl Column {
@@ -91,6 +94,9 @@ impl Column {
 
     pub fn get_col_type_attrs(&self) -> Option<TokenStream> {
         let col_type = match &self.col_type {
+            ColumnType::Custom(iden) if iden.to_string().eq_ignore_ascii_case("citext") => {
+                return Some(quote! { citext });
+            }
             ColumnType::Float => Some("Float".to_owned()),
             ColumnType::Double => Some("Double".to_owned()),
             ColumnType::Decimal(Some((p, s))) => Some(format!("Decimal(Some(({p}, {s})))")),
@@ -191,6 +197,16 @@ impl Column {
                 .unique()
             });
         }
+        if let Some(domain) = &self.domain {
+            col_def.extend(quote! {
+                .domain(#domain)
+            });
+        }
+        if let Some(comment) = &self.comment {
+            col_def.extend(quote! {
+                .comment(#comment)
+            });
+        }
         col_def
     }
 
@@ -230,7 +246,7 @@ impl Column {
         serde_skip_deserializing_primary_key: bool,
         serde_skip_hidden_column: bool,
     ) -> TokenStream {
-        if self.name.starts_with('_') && serde_skip_hidden_column {
+        let mut attrs = if self.name.starts_with('_') && serde_skip_hidden_column {
             quote! {
                 #[serde(skip)]
             }
@@ -240,6 +256,28 @@ impl Column {
             }
         } else {
             quote! {}
+        };
+        for attr in self.serde_attributes.iter() {
+            let attr: TokenStream = attr.parse().unwrap();
+            attrs.extend(quote! {
+                #[serde(#attr)]
+            });
+        }
+        attrs
+    }
+
+    /// The `validator` attribute of the constraints of the column: the maximum length of
+    /// a string column. A `NOT NULL` column is a field of a non-`Option` type, which
+    /// is always present.
+    pub fn get_validate_attribute(&self) -> TokenStream {
+        match self.col_type {
+            ColumnType::String(StringLen::N(len)) | ColumnType::Char(Some(len)) => {
+                let len = Literal::u32_unsuffixed(len);
+                quote! {
+                    #[validate(length(max = #len))]
+                }
+            }
+            _ => quote! {},
         }
     }
 
@@ -276,12 +314,22 @@ impl From<&ColumnDef> for Column {
             .get_column_spec()
             .iter()
             .any(|spec| matches!(spec, ColumnSpec::UniqueKey));
+        let comment = col_def
+            .get_column_spec()
+            .iter()
+            .find_map(|spec| match spec {
+                ColumnSpec::Comment(comment) => Some(comment.clone()),
+                _ => None,
+            });
         Self {
             name,
             col_type,
             auto_increment,
             not_null,
             unique,
+            domain: None,
+            serde_attributes: Vec::new(),
+            comment,
         }
     }
 }
@@ -302,6 +350,9 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    domain: None,
+                    serde_attributes: Vec::new(),
+                    comment: None,
                 }
             };
         }
@@ -493,6 +544,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_col_type_attrs() {
+        let make_col = |col_type| Column {
+            name: "name".to_owned(),
+            col_type,
+            auto_increment: false,
+            not_null: true,
+            unique: false,
+            domain: None,
+            serde_attributes: Vec::new(),
+            comment: None,
+        };
+        let attrs = |col_type| {
+            make_col(col_type)
+                .get_col_type_attrs()
+                .map(|ts| ts.to_string())
+        };
+        assert_eq!(attrs(ColumnType::Integer), None);
+        assert_eq!(
+            attrs(ColumnType::Text),
+            Some(quote! { column_type = "Text" }.to_string())
+        );
+        assert_eq!(
+            attrs(ColumnType::Custom(SeaRc::new(Alias::new("cus_col")))),
+            Some(quote! { column_type = "custom(\"cus_col\")" }.to_string())
+        );
+        assert_eq!(
+            attrs(ColumnType::Custom(SeaRc::new(Alias::new("citext")))),
+            Some(quote! { citext }.to_string())
+        );
+    }
+
     #[test]
     fn test_get_def() {
         let columns = setup();
@@ -717,4 +800,39 @@ mod tests {
         assert!(column.unique);
         assert!(column.not_null);
     }
+
+    #[test]
+    fn test_get_def_with_domain() {
+        let mut column: Column = ColumnDef::new(Alias::new("email"))
+            .text()
+            .not_null()
+            .to_owned()
+            .into();
+        column.domain = Some("email_address".to_owned());
+        assert_eq!(
+            column.get_def().to_string(),
+            quote! {
+                ColumnType::Text.def().domain("email_address")
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_column_def_with_comment() {
+        let column: Column = ColumnDef::new(Alias::new("name"))
+            .string()
+            .not_null()
+            .comment("The name of the cake")
+            .to_owned()
+            .into();
+        assert_eq!(column.comment.as_deref(), Some("The name of the cake"));
+        assert_eq!(
+            column.get_def().to_string(),
+            quote! {
+                ColumnType::String(StringLen::None).def().comment("The name of the cake")
+            }
+            .to_string()
+        );
+    }
 }
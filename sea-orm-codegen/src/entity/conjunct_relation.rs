@@ -2,25 +2,38 @@ use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::Ident;
 use quote::format_ident;
 
-use crate::util::escape_rust_keyword;
+use crate::{util::escape_rust_keyword, EntityName};
 
 #[derive(Clone, Debug)]
 pub struct ConjunctRelation {
     pub(crate) via: String,
     pub(crate) to: String,
+    pub(crate) via_name: Option<EntityName>,
+    pub(crate) to_name: Option<EntityName>,
 }
 
 impl ConjunctRelation {
     pub fn get_via_snake_case(&self) -> Ident {
-        format_ident!("{}", escape_rust_keyword(self.via.to_snake_case()))
+        let via = match &self.via_name {
+            Some(via_name) => via_name.module.clone(),
+            None => self.via.to_snake_case(),
+        };
+        format_ident!("{}", escape_rust_keyword(via))
     }
 
     pub fn get_to_snake_case(&self) -> Ident {
-        format_ident!("{}", escape_rust_keyword(self.to.to_snake_case()))
+        let to = match &self.to_name {
+            Some(to_name) => to_name.module.clone(),
+            None => self.to.to_snake_case(),
+        };
+        format_ident!("{}", escape_rust_keyword(to))
     }
 
     pub fn get_to_upper_camel_case(&self) -> Ident {
-        format_ident!("{}", self.to.to_upper_camel_case())
+        match &self.to_name {
+            Some(to_name) => format_ident!("{}", to_name.camel_case),
+            None => format_ident!("{}", self.to.to_upper_camel_case()),
+        }
     }
 }
 
@@ -33,10 +46,14 @@ mod tests {
             ConjunctRelation {
                 via: "cake_filling".to_owned(),
                 to: "cake".to_owned(),
+                via_name: None,
+                to_name: None,
             },
             ConjunctRelation {
                 via: "cake_filling".to_owned(),
                 to: "filling".to_owned(),
+                via_name: None,
+                to_name: None,
             },
         ]
     }
@@ -0,0 +1,614 @@
+use crate::{DateTimeCrate, EntityWriter, Error, RelationType};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use std::{collections::BTreeMap, fmt::Write, str::FromStr};
+use syn::{meta::ParseNestedMeta, Attribute, Data, DeriveInput, Fields, LitStr};
+
+/// The format of an entity relationship diagram
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagramFormat {
+    /// A Mermaid `erDiagram`
+    Mermaid,
+    /// A Graphviz `digraph` of the DOT language
+    Graphviz,
+}
+
+impl FromStr for DiagramFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "mermaid" => Self::Mermaid,
+            "graphviz" | "dot" => Self::Graphviz,
+            v => {
+                return Err(Error::TransformError(format!(
+                    "Unsupported diagram format '{v}'"
+                )))
+            }
+        })
+    }
+}
+
+/// An entity relationship diagram of tables, their columns and the foreign keys between them,
+/// of the entities discovered from a database or of the entity files of a crate
+#[derive(Clone, Debug, Default)]
+pub struct Diagram {
+    tables: Vec<DiagramTable>,
+}
+
+#[derive(Clone, Debug)]
+struct DiagramTable {
+    name: String,
+    columns: Vec<DiagramColumn>,
+    foreign_keys: Vec<DiagramForeignKey>,
+}
+
+#[derive(Clone, Debug)]
+struct DiagramColumn {
+    name: String,
+    /// The Rust type of the field, e.g. `String` of an `Option<String>` field
+    rs_type: String,
+    primary_key: bool,
+    nullable: bool,
+}
+
+#[derive(Clone, Debug)]
+struct DiagramForeignKey {
+    ref_table: String,
+    columns: Vec<String>,
+    ref_columns: Vec<String>,
+}
+
+/// The `belongs_to` relation of an entity file, of the paths of the `sea_orm` attribute
+struct BelongsTo {
+    entity: String,
+    from: String,
+    to: String,
+}
+
+impl Diagram {
+    /// The diagram of the entities to be written, e.g. as discovered from a database
+    pub fn from_entity_writer(writer: &EntityWriter, date_time_crate: &DateTimeCrate) -> Self {
+        let tables = writer
+            .entities
+            .iter()
+            .map(|entity| DiagramTable {
+                name: entity.table_name.clone(),
+                columns: entity
+                    .columns
+                    .iter()
+                    .map(|col| {
+                        let mut inner = col.clone();
+                        inner.not_null = true;
+                        DiagramColumn {
+                            name: col.name.clone(),
+                            rs_type: inner.get_rs_type(date_time_crate).to_string(),
+                            primary_key: entity.primary_keys.iter().any(|pk| pk.name == col.name),
+                            nullable: !col.not_null,
+                        }
+                    })
+                    .collect(),
+                foreign_keys: entity
+                    .relations
+                    .iter()
+                    .filter(|rel| matches!(rel.rel_type, RelationType::BelongsTo))
+                    .map(|rel| DiagramForeignKey {
+                        ref_table: rel.ref_table.clone(),
+                        columns: rel.columns.clone(),
+                        ref_columns: rel.ref_columns.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self { tables }
+    }
+
+    /// The diagram of the entity files of a crate, as `(module name, source)`, of the entities
+    /// deriving `DeriveEntityModel`, i.e. generated in the compact format. The foreign keys are
+    /// the `belongs_to` relations, and the other files are skipped.
+    pub fn from_entity_files(files: &[(String, String)]) -> Result<Self, Error> {
+        let mut tables = Vec::new();
+        let mut relations = Vec::new();
+        // The table name and the columns by field of each module
+        let mut modules: BTreeMap<&str, (String, BTreeMap<String, String>)> = BTreeMap::new();
+        for (module, source) in files.iter() {
+            let items = derive_items(source).map_err(|e| {
+                Error::TransformError(format!("Failed to parse the entity `{module}`: {e}"))
+            })?;
+            let Some((table, fields)) = items
+                .iter()
+                .find(|item| item.ident == "Model")
+                .map(entity_table)
+                .transpose()?
+                .flatten()
+            else {
+                continue;
+            };
+            let belongs_to = match items.iter().find(|item| item.ident == "Relation") {
+                Some(item) => belongs_to_relations(item)?,
+                None => Vec::new(),
+            };
+            modules.insert(module, (table.name.clone(), fields));
+            relations.push((module.as_str(), belongs_to));
+            tables.push(table);
+        }
+
+        for (table, (module, belongs_to)) in tables.iter_mut().zip(relations) {
+            let (_, fields) = &modules[module];
+            for relation in belongs_to {
+                let ref_module = relation
+                    .entity
+                    .rsplit("::")
+                    .nth(1)
+                    .filter(|module| *module != "super" && *module != "self")
+                    .unwrap_or(module);
+                let (ref_table, ref_fields) = match modules.get(ref_module) {
+                    Some((ref_table, ref_fields)) => (ref_table.clone(), Some(ref_fields)),
+                    None => (ref_module.to_owned(), None),
+                };
+                table.foreign_keys.push(DiagramForeignKey {
+                    ref_table,
+                    columns: column_names(&relation.from, Some(fields)),
+                    ref_columns: column_names(&relation.to, ref_fields),
+                });
+            }
+        }
+        Ok(Self { tables })
+    }
+
+    pub fn render(&self, format: DiagramFormat) -> String {
+        match format {
+            DiagramFormat::Mermaid => self.render_mermaid(),
+            DiagramFormat::Graphviz => self.render_graphviz(),
+        }
+    }
+
+    fn render_mermaid(&self) -> String {
+        let mut s = "erDiagram\n".to_owned();
+        for table in self.tables.iter() {
+            let _ = writeln!(s, "    {} {{", table.name);
+            for col in table.columns.iter() {
+                let rs_type = col
+                    .rs_type
+                    .replace(' ', "")
+                    .replace('<', "[")
+                    .replace('>', "]");
+                let mut keys = Vec::new();
+                if col.primary_key {
+                    keys.push("PK");
+                }
+                if table.is_foreign_key(&col.name) {
+                    keys.push("FK");
+                }
+                let _ = write!(s, "        {} {}", rs_type, col.name);
+                if !keys.is_empty() {
+                    let _ = write!(s, " {}", keys.join(", "));
+                }
+                if col.nullable {
+                    s.push_str(" \"nullable\"");
+                }
+                s.push('\n');
+            }
+            s.push_str("    }\n");
+        }
+        for table in self.tables.iter() {
+            for fk in table.foreign_keys.iter() {
+                let optional = fk
+                    .columns
+                    .iter()
+                    .any(|name| table.columns.iter().any(|c| &c.name == name && c.nullable));
+                let _ = writeln!(
+                    s,
+                    "    {} {}--o{{ {} : \"{}\"",
+                    fk.ref_table,
+                    if optional { "|o" } else { "||" },
+                    table.name,
+                    fk.columns.join(", ")
+                );
+            }
+        }
+        s
+    }
+
+    fn render_graphviz(&self) -> String {
+        let mut s = "digraph entities {\n    node [shape=record];\n".to_owned();
+        for table in self.tables.iter() {
+            let columns: String = table
+                .columns
+                .iter()
+                .map(|col| {
+                    let mut line = format!("{}: {}", col.name, col.rs_type.replace(' ', ""));
+                    if col.nullable {
+                        line = format!("{line}?");
+                    }
+                    if col.primary_key {
+                        line.push_str(" (PK)");
+                    }
+                    if table.is_foreign_key(&col.name) {
+                        line.push_str(" (FK)");
+                    }
+                    format!("{}\\l", escape_record(&line))
+                })
+                .collect();
+            let _ = writeln!(
+                s,
+                "    \"{}\" [label=\"{{{}|{}}}\"];",
+                table.name,
+                escape_record(&table.name),
+                columns
+            );
+        }
+        for table in self.tables.iter() {
+            for fk in table.foreign_keys.iter() {
+                let _ = writeln!(
+                    s,
+                    "    \"{}\" -> \"{}\" [label=\"{} → {}\"];",
+                    table.name,
+                    fk.ref_table,
+                    fk.columns.join(", "),
+                    fk.ref_columns.join(", ")
+                );
+            }
+        }
+        s.push_str("}\n");
+        s
+    }
+}
+
+impl DiagramTable {
+    fn is_foreign_key(&self, column: &str) -> bool {
+        self.foreign_keys
+            .iter()
+            .any(|fk| fk.columns.iter().any(|name| name == column))
+    }
+}
+
+/// Escape the characters of a label of a Graphviz record
+fn escape_record(label: &str) -> String {
+    let mut escaped = String::new();
+    for c in label.chars() {
+        if matches!(c, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The structs and enums of a source file, which are all parsed as derive inputs; the other
+/// items are skipped
+fn derive_items(source: &str) -> Result<Vec<DeriveInput>, proc_macro2::LexError> {
+    let tokens = TokenStream::from_str(source)?;
+    let mut items = Vec::new();
+    let mut item = TokenStream::new();
+    for tt in tokens {
+        let end = match &tt {
+            TokenTree::Punct(punct) => punct.as_char() == ';',
+            TokenTree::Group(group) => group.delimiter() == proc_macro2::Delimiter::Brace,
+            _ => false,
+        };
+        item.extend([tt]);
+        if end {
+            if let Ok(input) = syn::parse2::<DeriveInput>(std::mem::take(&mut item)) {
+                items.push(input);
+            }
+        }
+    }
+    Ok(items)
+}
+
+/// The table of a `Model` struct with a `table_name`, and its column names by field name
+fn entity_table(
+    model: &DeriveInput,
+) -> Result<Option<(DiagramTable, BTreeMap<String, String>)>, Error> {
+    let mut table_name = None;
+    for attr in sea_orm_attrs(&model.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table_name") {
+                table_name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                skip_meta(&meta)
+            }
+        })
+        .map_err(parse_error)?;
+    }
+    let (Some(name), Data::Struct(data)) = (table_name, &model.data) else {
+        return Ok(None);
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Ok(None);
+    };
+
+    let mut columns = Vec::new();
+    let mut fields = BTreeMap::new();
+    for field in named.named.iter() {
+        let field_name = field
+            .ident
+            .as_ref()
+            .map(|ident| ident.to_string().trim_start_matches("r#").to_owned())
+            .unwrap_or_default();
+        let mut column_name = field_name.clone();
+        let mut primary_key = false;
+        for attr in sea_orm_attrs(&field.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("column_name") {
+                    column_name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("primary_key") {
+                    primary_key = true;
+                    Ok(())
+                } else {
+                    skip_meta(&meta)
+                }
+            })
+            .map_err(parse_error)?;
+        }
+        let ty = field.ty.to_token_stream().to_string().replace(' ', "");
+        let (rs_type, nullable) = match ty.strip_prefix("Option<") {
+            Some(inner) => (inner.strip_suffix('>').unwrap_or(inner).to_owned(), true),
+            None => (ty, false),
+        };
+        fields.insert(field_name, column_name.clone());
+        columns.push(DiagramColumn {
+            name: column_name,
+            rs_type,
+            primary_key,
+            nullable,
+        });
+    }
+    let table = DiagramTable {
+        name,
+        columns,
+        foreign_keys: Vec::new(),
+    };
+    Ok(Some((table, fields)))
+}
+
+/// The `belongs_to` relations of the variants of a `Relation` enum
+fn belongs_to_relations(relation: &DeriveInput) -> Result<Vec<BelongsTo>, Error> {
+    let Data::Enum(data) = &relation.data else {
+        return Ok(Vec::new());
+    };
+    let mut relations = Vec::new();
+    for variant in data.variants.iter() {
+        let (mut entity, mut from, mut to) = (None, None, None);
+        for attr in sea_orm_attrs(&variant.attrs) {
+            attr.parse_nested_meta(|meta| {
+                let value = if meta.path.is_ident("belongs_to") {
+                    &mut entity
+                } else if meta.path.is_ident("from") {
+                    &mut from
+                } else if meta.path.is_ident("to") {
+                    &mut to
+                } else {
+                    return skip_meta(&meta);
+                };
+                *value = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            })
+            .map_err(parse_error)?;
+        }
+        if let (Some(entity), Some(from), Some(to)) = (entity, from, to) {
+            relations.push(BelongsTo { entity, from, to });
+        }
+    }
+    Ok(relations)
+}
+
+/// The column names of the column paths of a relation, e.g. `Column::CakeId` or
+/// `(Column::CakeId, Column::FillingId)`, by the field names of the entity if it's known
+fn column_names(paths: &str, fields: Option<&BTreeMap<String, String>>) -> Vec<String> {
+    paths
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .filter_map(|path| path.trim().rsplit("::").next())
+        .filter(|variant| !variant.is_empty())
+        .map(|variant| {
+            fields
+                .and_then(|fields| {
+                    fields
+                        .iter()
+                        .find(|(field, _)| field.to_upper_camel_case() == variant)
+                        .map(|(_, column)| column.clone())
+                })
+                .unwrap_or_else(|| variant.to_snake_case())
+        })
+        .collect()
+}
+
+fn sea_orm_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("sea_orm"))
+}
+
+/// Skip an argument of an attribute, e.g. `auto_increment = false` or `nullable`
+fn skip_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.step(|cursor| {
+            let mut rest = *cursor;
+            while let Some((tt, next)) = rest.token_tree() {
+                if matches!(&tt, TokenTree::Punct(punct) if punct.as_char() == ',') {
+                    break;
+                }
+                rest = next;
+            }
+            Ok(((), rest))
+        })
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_meta(&meta))
+    } else {
+        Ok(())
+    }
+}
+
+fn parse_error(e: syn::Error) -> Error {
+    Error::TransformError(format!("Failed to parse an entity attribute: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn entity_files() -> Vec<(String, String)> {
+        vec![
+            (
+                "cake".to_owned(),
+                r#"
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "cake", comment = "Cakes of the bakery")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub name: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::fruit::Entity")]
+    Fruit,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+"#
+                .to_owned(),
+            ),
+            (
+                "fruit".to_owned(),
+                r#"
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "fruit")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: i32,
+    pub name: String,
+    #[sea_orm(column_name = "cakeId")]
+    pub cake_id: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::cake::Entity",
+        from = "Column::CakeId",
+        to = "super::cake::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Cake,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+"#
+                .to_owned(),
+            ),
+            (
+                "mod".to_owned(),
+                "pub mod cake;\npub mod fruit;\n".to_owned(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_diagram_format() {
+        assert_eq!(
+            "mermaid".parse::<DiagramFormat>().unwrap(),
+            DiagramFormat::Mermaid
+        );
+        assert_eq!(
+            "dot".parse::<DiagramFormat>().unwrap(),
+            DiagramFormat::Graphviz
+        );
+        assert!("plantuml".parse::<DiagramFormat>().is_err());
+    }
+
+    #[test]
+    fn test_render_mermaid() {
+        let diagram = Diagram::from_entity_files(&entity_files()).unwrap();
+        assert_eq!(
+            diagram.render(DiagramFormat::Mermaid),
+            r#"erDiagram
+    cake {
+        i32 id PK
+        String name "nullable"
+    }
+    fruit {
+        i32 id PK
+        String name
+        i32 cakeId FK "nullable"
+    }
+    cake |o--o{ fruit : "cakeId"
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_graphviz() {
+        let diagram = Diagram::from_entity_files(&entity_files()).unwrap();
+        assert_eq!(
+            diagram.render(DiagramFormat::Graphviz),
+            r#"digraph entities {
+    node [shape=record];
+    "cake" [label="{cake|id: i32 (PK)\lname: String?\l}"];
+    "fruit" [label="{fruit|id: i32 (PK)\lname: String\lcakeId: i32? (FK)\l}"];
+    "fruit" -> "cake" [label="cakeId → id"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_diagram_from_entity_writer() {
+        use crate::EntityTransformer;
+        use sea_query::{Alias, ColumnDef, ForeignKey, Table};
+
+        let writer = EntityTransformer::transform(vec![
+            Table::create()
+                .table(Alias::new("cake"))
+                .col(
+                    ColumnDef::new(Alias::new("id"))
+                        .integer()
+                        .not_null()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(Alias::new("name")).text())
+                .to_owned(),
+            Table::create()
+                .table(Alias::new("fruit"))
+                .col(
+                    ColumnDef::new(Alias::new("id"))
+                        .integer()
+                        .not_null()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(Alias::new("cake_id")).integer().not_null())
+                .foreign_key(
+                    ForeignKey::create()
+                        .from(Alias::new("fruit"), Alias::new("cake_id"))
+                        .to(Alias::new("cake"), Alias::new("id")),
+                )
+                .to_owned(),
+        ])
+        .unwrap();
+        assert_eq!(
+            Diagram::from_entity_writer(&writer, &DateTimeCrate::Chrono)
+                .render(DiagramFormat::Mermaid),
+            r#"erDiagram
+    cake {
+        i32 id PK
+        String name "nullable"
+    }
+    fruit {
+        i32 id PK
+        i32 cake_id FK
+    }
+    cake ||--o{ fruit : "cake_id"
+"#
+        );
+    }
+}
@@ -2,16 +2,23 @@ mod active_enum;
 mod base_entity;
 mod column;
 mod conjunct_relation;
+mod diagram;
+mod naming;
 mod primary_key;
 mod relation;
 mod transformer;
+mod typescript;
+mod user_code;
 mod writer;
 
 pub use active_enum::*;
 pub use base_entity::*;
 pub use column::*;
 pub use conjunct_relation::*;
+pub use diagram::*;
+pub use naming::*;
 pub use primary_key::*;
 pub use relation::*;
 pub use transformer::*;
+pub use user_code::*;
 pub use writer::*;
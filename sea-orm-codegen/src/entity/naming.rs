@@ -0,0 +1,379 @@
+use heck::ToSnakeCase;
+use std::collections::BTreeMap;
+
+use crate::{EntityWriter, Error};
+
+/// The grammatical number of the names of the entities
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EntityNaming {
+    /// Named as the tables
+    #[default]
+    Table,
+    /// Named in the singular, e.g. `Cake` of the table `cakes`
+    Singular,
+    /// Named in the plural, e.g. `Cakes` of the table `cake`
+    Plural,
+}
+
+/// The names of the modules of the entities
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModuleNaming {
+    /// Named after the entities, e.g. `cake` of the entity `Cake`
+    #[default]
+    Entity,
+    /// Named after the tables, e.g. `tbl_cakes` of the table `tbl_cakes`
+    Table,
+}
+
+/// The naming convention of the entities generated of the tables
+#[derive(Clone, Debug, Default)]
+pub struct NamingConvention {
+    pub(crate) entity_naming: EntityNaming,
+    pub(crate) strip_table_prefixes: Vec<String>,
+    pub(crate) acronyms: Vec<String>,
+    pub(crate) module_naming: ModuleNaming,
+}
+
+/// The names of the entity of a table, as a module and as a type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntityName {
+    pub(crate) module: String,
+    pub(crate) camel_case: String,
+}
+
+/// Words of the same singular and plural
+const UNCOUNTABLE_WORDS: [&str; 8] = [
+    "data",
+    "equipment",
+    "information",
+    "metadata",
+    "money",
+    "news",
+    "series",
+    "species",
+];
+
+/// Words of irregular plurals, as `(singular, plural)`
+const IRREGULAR_WORDS: [(&str, &str); 8] = [
+    ("child", "children"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("person", "people"),
+    ("tooth", "teeth"),
+    ("woman", "women"),
+];
+
+impl NamingConvention {
+    pub fn new(
+        entity_naming: EntityNaming,
+        strip_table_prefixes: Vec<String>,
+        acronyms: Vec<String>,
+        module_naming: ModuleNaming,
+    ) -> Self {
+        Self {
+            entity_naming,
+            strip_table_prefixes,
+            acronyms,
+            module_naming,
+        }
+    }
+
+    /// The names of the entity of a table: the first matching prefix of `strip_table_prefixes` is
+    /// stripped, the last word is put in the number of `entity_naming`, and the words of
+    /// `acronyms` are cased as given, e.g. `ApiKey` of the table `tbl_api_keys` as `APIKey`
+    pub fn entity_name(&self, table_name: &str) -> EntityName {
+        let name = self
+            .strip_table_prefixes
+            .iter()
+            .find_map(|prefix| table_name.strip_prefix(prefix.as_str()))
+            .filter(|name| !name.is_empty())
+            .unwrap_or(table_name)
+            .to_snake_case();
+        let mut words: Vec<String> = name
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(str::to_owned)
+            .collect();
+        if let Some(word) = words.last_mut() {
+            *word = match self.entity_naming {
+                EntityNaming::Table => word.clone(),
+                EntityNaming::Singular => singular(word),
+                EntityNaming::Plural => plural(&singular(word)),
+            };
+        }
+        let camel_case = words
+            .iter()
+            .map(|word| self.upper_camel_case(word))
+            .collect();
+        let module = match self.module_naming {
+            ModuleNaming::Entity => words.join("_"),
+            ModuleNaming::Table => table_name.to_snake_case(),
+        };
+        EntityName { module, camel_case }
+    }
+
+    fn upper_camel_case(&self, word: &str) -> String {
+        if let Some(acronym) = self
+            .acronyms
+            .iter()
+            .find(|acronym| acronym.eq_ignore_ascii_case(word))
+        {
+            return acronym.clone();
+        }
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+}
+
+impl EntityWriter {
+    /// Name the entities, their modules and the relations to them by the naming convention. The
+    /// entities keep the names of their tables as `table_name`
+    pub fn set_naming_convention(&mut self, naming: &NamingConvention) -> Result<(), Error> {
+        let mut modules: BTreeMap<String, &str> = BTreeMap::new();
+        for entity in self.entities.iter() {
+            let module = naming.entity_name(&entity.table_name).module;
+            if let Some(other) = modules.insert(module.clone(), &entity.table_name) {
+                return Err(Error::TransformError(format!(
+                    "The entities of the tables `{other}` and `{}` are both named `{module}`",
+                    entity.table_name
+                )));
+            }
+        }
+        for entity in self.entities.iter_mut() {
+            entity.name = Some(naming.entity_name(&entity.table_name));
+            for rel in entity.relations.iter_mut() {
+                rel.ref_name = Some(naming.entity_name(&rel.ref_table));
+            }
+            for conjunct_rel in entity.conjunct_relations.iter_mut() {
+                conjunct_rel.via_name = Some(naming.entity_name(&conjunct_rel.via));
+                conjunct_rel.to_name = Some(naming.entity_name(&conjunct_rel.to));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The singular of an English word in lower case, by the common rules
+fn singular(word: &str) -> String {
+    if UNCOUNTABLE_WORDS.contains(&word) {
+        return word.to_owned();
+    }
+    if let Some((singular, _)) = IRREGULAR_WORDS.iter().find(|(_, plural)| *plural == word) {
+        return (*singular).to_owned();
+    }
+    if let Some(stem) = word.strip_suffix("ies").filter(|stem| stem.len() > 1) {
+        return format!("{stem}y");
+    }
+    for suffix in ["sses", "shes", "ches", "xes", "zzes", "uses"] {
+        if word.ends_with(suffix) && !word.ends_with("ouses") && !word.ends_with("auses") {
+            return word[..word.len() - 2].to_owned();
+        }
+    }
+    if word.ends_with("ss") || word.ends_with("us") || word.ends_with("is") {
+        return word.to_owned();
+    }
+    match word.strip_suffix('s') {
+        Some(stem) if !stem.is_empty() => stem.to_owned(),
+        _ => word.to_owned(),
+    }
+}
+
+/// The plural of an English word in lower case and in the singular, by the common rules
+fn plural(word: &str) -> String {
+    if UNCOUNTABLE_WORDS.contains(&word) {
+        return word.to_owned();
+    }
+    if let Some((_, plural)) = IRREGULAR_WORDS
+        .iter()
+        .find(|(singular, _)| *singular == word)
+    {
+        return (*plural).to_owned();
+    }
+    if let Some(stem) = word.strip_suffix('y') {
+        if !stem.is_empty() && !stem.ends_with(['a', 'e', 'i', 'o', 'u']) {
+            return format!("{stem}ies");
+        }
+    }
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        return format!("{word}es");
+    }
+    format!("{word}s")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn entity_name(naming: &NamingConvention, table_name: &str) -> (String, String) {
+        let name = naming.entity_name(table_name);
+        (name.module, name.camel_case)
+    }
+
+    #[test]
+    fn test_entity_name() {
+        let naming = NamingConvention::default();
+        assert_eq!(
+            entity_name(&naming, "cake_fillings"),
+            ("cake_fillings".to_owned(), "CakeFillings".to_owned())
+        );
+
+        let naming = NamingConvention::new(
+            EntityNaming::Singular,
+            vec!["tbl_".to_owned()],
+            vec!["API".to_owned(), "ID".to_owned()],
+            ModuleNaming::Entity,
+        );
+        assert_eq!(
+            entity_name(&naming, "tbl_api_keys"),
+            ("api_key".to_owned(), "APIKey".to_owned())
+        );
+        assert_eq!(
+            entity_name(&naming, "tbl_categories"),
+            ("category".to_owned(), "Category".to_owned())
+        );
+        assert_eq!(
+            entity_name(&naming, "user_addresses"),
+            ("user_address".to_owned(), "UserAddress".to_owned())
+        );
+        assert_eq!(
+            entity_name(&naming, "tbl_"),
+            ("tbl".to_owned(), "Tbl".to_owned())
+        );
+
+        let naming = NamingConvention::new(
+            EntityNaming::Plural,
+            vec!["tbl_".to_owned()],
+            Vec::new(),
+            ModuleNaming::Table,
+        );
+        assert_eq!(
+            entity_name(&naming, "tbl_person"),
+            ("tbl_person".to_owned(), "People".to_owned())
+        );
+        assert_eq!(
+            entity_name(&naming, "tbl_cakes"),
+            ("tbl_cakes".to_owned(), "Cakes".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_set_naming_convention() {
+        use crate::{DateTimeCrate, EntityTransformer, EntityWriterContext, WithSerde};
+        use sea_query::{Alias, ColumnDef, ForeignKey, Table};
+
+        let mut writer = EntityTransformer::transform(vec![
+            Table::create()
+                .table(Alias::new("tbl_cakes"))
+                .col(
+                    ColumnDef::new(Alias::new("id"))
+                        .integer()
+                        .not_null()
+                        .primary_key(),
+                )
+                .to_owned(),
+            Table::create()
+                .table(Alias::new("tbl_fruits"))
+                .col(
+                    ColumnDef::new(Alias::new("id"))
+                        .integer()
+                        .not_null()
+                        .primary_key(),
+                )
+                .col(ColumnDef::new(Alias::new("cake_id")).integer().not_null())
+                .foreign_key(
+                    ForeignKey::create()
+                        .from(Alias::new("tbl_fruits"), Alias::new("cake_id"))
+                        .to(Alias::new("tbl_cakes"), Alias::new("id")),
+                )
+                .to_owned(),
+        ])
+        .unwrap();
+        let naming = NamingConvention::new(
+            EntityNaming::Singular,
+            vec!["tbl_".to_owned()],
+            Vec::new(),
+            ModuleNaming::Entity,
+        );
+        writer.set_naming_convention(&naming).unwrap();
+
+        let context = EntityWriterContext::new(
+            false,
+            WithSerde::None,
+            false,
+            DateTimeCrate::Chrono,
+            None,
+            false,
+            false,
+            false,
+            false,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            false,
+        );
+        let files = writer.generate(&context).files;
+        let file = |name: &str| {
+            files
+                .iter()
+                .find(|file| file.name == name)
+                .map(|file| file.content.clone())
+                .unwrap()
+        };
+        assert!(file("cake.rs").contains(r#"table_name = "tbl_cakes""#));
+        assert!(file("cake.rs").contains("has_many = \"super::fruit::Entity\""));
+        assert!(file("fruit.rs").contains("belongs_to = \"super::cake::Entity\""));
+        assert!(file("fruit.rs").contains("impl Related < super :: cake :: Entity >"));
+        assert!(file("prelude.rs").contains("pub use super :: cake :: Entity as Cake ;"));
+        assert!(file("mod.rs").contains("pub mod fruit ;"));
+    }
+
+    #[test]
+    fn test_set_naming_convention_conflict() {
+        use crate::EntityTransformer;
+        use sea_query::{Alias, ColumnDef, Table};
+
+        let table = |name: &str| {
+            Table::create()
+                .table(Alias::new(name))
+                .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                .to_owned()
+        };
+        let mut writer = EntityTransformer::transform(vec![table("cake"), table("cakes")]).unwrap();
+        let naming = NamingConvention::new(
+            EntityNaming::Singular,
+            Vec::new(),
+            Vec::new(),
+            ModuleNaming::Entity,
+        );
+        assert!(writer.set_naming_convention(&naming).is_err());
+    }
+
+    #[test]
+    fn test_singular_plural() {
+        for (singular_word, plural_word) in [
+            ("cake", "cakes"),
+            ("category", "categories"),
+            ("key", "keys"),
+            ("address", "addresses"),
+            ("box", "boxes"),
+            ("batch", "batches"),
+            ("status", "statuses"),
+            ("person", "people"),
+            ("metadata", "metadata"),
+        ] {
+            assert_eq!(singular(plural_word), singular_word);
+            assert_eq!(singular(singular_word), singular_word);
+            assert_eq!(plural(singular_word), plural_word);
+        }
+    }
+}
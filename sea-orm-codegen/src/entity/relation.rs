@@ -5,7 +5,7 @@ use quote::{format_ident, quote};
 use sea_query::{ForeignKeyAction, TableForeignKey};
 use syn::{punctuated::Punctuated, token::Comma};
 
-use crate::util::escape_rust_keyword;
+use crate::{util::escape_rust_keyword, EntityName};
 
 #[derive(Clone, Debug)]
 pub enum RelationType {
@@ -25,6 +25,7 @@ pub struct Relation {
     pub(crate) self_referencing: bool,
     pub(crate) num_suffix: usize,
     pub(crate) impl_related: bool,
+    pub(crate) ref_name: Option<EntityName>,
 }
 
 impl Relation {
@@ -32,7 +33,10 @@ impl Relation {
         let name = if self.self_referencing {
             format_ident!("SelfRef")
         } else {
-            format_ident!("{}", self.ref_table.to_upper_camel_case())
+            match &self.ref_name {
+                Some(ref_name) => format_ident!("{}", ref_name.camel_case),
+                None => format_ident!("{}", self.ref_table.to_upper_camel_case()),
+            }
         };
         if self.num_suffix > 0 {
             format_ident!("{}{}", name, self.num_suffix)
@@ -45,10 +49,11 @@ impl Relation {
         if self.self_referencing {
             None
         } else {
-            Some(format_ident!(
-                "{}",
-                escape_rust_keyword(self.ref_table.to_snake_case())
-            ))
+            let module_name = match &self.ref_name {
+                Some(ref_name) => ref_name.module.clone(),
+                None => self.ref_table.to_snake_case(),
+            };
+            Some(format_ident!("{}", escape_rust_keyword(module_name)))
         }
     }
 
@@ -237,6 +242,7 @@ impl From<&TableForeignKey> for Relation {
             self_referencing: false,
             num_suffix: 0,
             impl_related: true,
+            ref_name: None,
         }
     }
 }
@@ -259,6 +265,7 @@ mod tests {
                 self_referencing: false,
                 num_suffix: 0,
                 impl_related: true,
+                ref_name: None,
             },
             Relation {
                 ref_table: "filling".to_owned(),
@@ -270,6 +277,7 @@ mod tests {
                 self_referencing: false,
                 num_suffix: 0,
                 impl_related: true,
+                ref_name: None,
             },
             Relation {
                 ref_table: "filling".to_owned(),
@@ -281,6 +289,7 @@ mod tests {
                 self_referencing: false,
                 num_suffix: 0,
                 impl_related: true,
+                ref_name: None,
             },
         ]
     }
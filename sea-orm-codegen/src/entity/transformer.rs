@@ -65,6 +65,7 @@ impl EntityTransformer {
                             ActiveEnum {
                                 enum_name: name.clone(),
                                 values: variants.clone(),
+                                string_type: None,
                             },
                         );
                     }
@@ -119,6 +120,9 @@ impl EntityTransformer {
             );
             let entity = Entity {
                 table_name: table_name.clone(),
+                comment: table_create.get_comment().cloned(),
+                view: false,
+                name: None,
                 columns,
                 relations: relations.clone(),
                 conjunct_relations: vec![],
@@ -206,6 +210,8 @@ impl EntityTransformer {
                     let conjunct_relation = ConjunctRelation {
                         via: table_name.clone(),
                         to: another_rel.ref_table.clone(),
+                        via_name: None,
+                        to_name: None,
                     };
                     entity.conjunct_relations.push(conjunct_relation);
                 }
@@ -395,6 +401,7 @@ mod tests {
                     &None,
                     false,
                     false,
+                    false,
                     &Default::default(),
                     &Default::default(),
                     false,
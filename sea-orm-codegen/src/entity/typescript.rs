@@ -0,0 +1,217 @@
+use crate::{Column, EntityWriter, EntityWriterContext, WithSerde};
+use heck::ToUpperCamelCase;
+use sea_query::ColumnType;
+
+impl EntityWriter {
+    /// Generate the TypeScript types of the models as they are serialized by serde: an interface
+    /// of each model, and a union type of the variants of each ActiveEnum
+    pub fn gen_typescript(&self, context: &EntityWriterContext) -> String {
+        let serde_skip_hidden_column =
+            context.serde_skip_hidden_column && context.with_serde != WithSerde::None;
+        let ver = env!("CARGO_PKG_VERSION");
+        let mut blocks = vec![format!(
+            "// `SeaORM` Entity types, @generated by sea-orm-codegen {ver}"
+        )];
+        for active_enum in self.enums.values() {
+            let variants: Vec<String> = active_enum
+                .get_variant_idents()
+                .iter()
+                .map(|variant| format!("{:?}", variant.to_string()))
+                .collect();
+            blocks.push(format!(
+                "export type {} = {};",
+                active_enum.enum_name.to_string().to_upper_camel_case(),
+                variants.join(" | ")
+            ));
+        }
+        for entity in self.entities.iter() {
+            let fields: Vec<String> = entity
+                .columns
+                .iter()
+                .filter(|col| !(serde_skip_hidden_column && col.name.starts_with('_')))
+                .filter_map(|col| {
+                    let name = col.get_ts_field_name()?;
+                    Some(format!("  {}: {};\n", name, col.get_ts_type()))
+                })
+                .collect();
+            blocks.push(format!(
+                "export interface {} {{\n{}}}",
+                entity.get_table_name_camel_case(),
+                fields.concat()
+            ));
+        }
+        blocks.join("\n\n") + "\n"
+    }
+}
+
+impl Column {
+    /// The name of the field as serialized by serde, or `None` if it's skipped
+    fn get_ts_field_name(&self) -> Option<String> {
+        let mut name = self
+            .get_name_snake_case()
+            .to_string()
+            .trim_start_matches("r#")
+            .to_owned();
+        for attr in self.serde_attributes.iter() {
+            let attr = attr.trim();
+            if attr == "skip" || attr == "skip_serializing" {
+                return None;
+            }
+            if let Some((key, value)) = attr.split_once('=') {
+                if key.trim() == "rename" {
+                    name = value.trim().trim_matches('"').to_owned();
+                }
+            }
+        }
+        Some(name)
+    }
+
+    fn get_ts_type(&self) -> String {
+        fn write_ts_type(col_type: &ColumnType) -> String {
+            match col_type {
+                ColumnType::TinyInteger
+                | ColumnType::SmallInteger
+                | ColumnType::Integer
+                | ColumnType::BigInteger
+                | ColumnType::TinyUnsigned
+                | ColumnType::SmallUnsigned
+                | ColumnType::Unsigned
+                | ColumnType::BigUnsigned
+                | ColumnType::Float
+                | ColumnType::Double => "number".to_owned(),
+                ColumnType::Boolean => "boolean".to_owned(),
+                ColumnType::Binary(_) | ColumnType::VarBinary(_) | ColumnType::Blob => {
+                    "number[]".to_owned()
+                }
+                ColumnType::Json | ColumnType::JsonBinary => "unknown".to_owned(),
+                ColumnType::Enum { name, .. } => name.to_string().to_upper_camel_case(),
+                ColumnType::Array(col_type) => format!("{}[]", write_ts_type(col_type)),
+                // Decimals, UUIDs, dates and times are serialized as strings
+                _ => "string".to_owned(),
+            }
+        }
+        let ts_type = write_ts_type(&self.col_type);
+        match self.not_null {
+            true => ts_type,
+            false => format!("{ts_type} | null"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ActiveEnum, Column, DateTimeCrate, Entity, EntityWriter, EntityWriterContext, PrimaryKey,
+        WithSerde,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::{Alias, ColumnType, IntoIden, RcOrArc, StringLen};
+
+    fn column(name: &str, col_type: ColumnType, not_null: bool) -> Column {
+        Column {
+            name: name.to_owned(),
+            col_type,
+            auto_increment: false,
+            not_null,
+            unique: false,
+            domain: None,
+            serde_attributes: Vec::new(),
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn test_gen_typescript() {
+        let tea = Alias::new("tea").into_iden();
+        let mut password = column("password", ColumnType::Text, true);
+        password.serde_attributes.push("skip".to_owned());
+        let mut created_at = column("created_at", ColumnType::TimestampWithTimeZone, true);
+        created_at
+            .serde_attributes
+            .push(r#"rename = "createdAt""#.to_owned());
+        let writer = EntityWriter {
+            entities: vec![Entity {
+                table_name: "cake".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
+                columns: vec![
+                    column("id", ColumnType::Integer, true),
+                    column("name", ColumnType::String(StringLen::N(255)), true),
+                    column("price", ColumnType::Decimal(None), false),
+                    column(
+                        "tea",
+                        ColumnType::Enum {
+                            name: tea.clone(),
+                            variants: Vec::new(),
+                        },
+                        false,
+                    ),
+                    column(
+                        "tags",
+                        ColumnType::Array(RcOrArc::new(ColumnType::Text)),
+                        true,
+                    ),
+                    column("type", ColumnType::Json, true),
+                    password,
+                    created_at,
+                    column("_hidden", ColumnType::Boolean, true),
+                ],
+                relations: Vec::new(),
+                conjunct_relations: Vec::new(),
+                primary_keys: vec![PrimaryKey {
+                    name: "id".to_owned(),
+                }],
+            }],
+            enums: [(
+                "tea".to_owned(),
+                ActiveEnum {
+                    enum_name: tea,
+                    values: vec!["EverydayTea", "BreakfastTea"]
+                        .into_iter()
+                        .map(|variant| Alias::new(variant).into_iden())
+                        .collect(),
+                    string_type: None,
+                },
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let context = EntityWriterContext::new(
+            false,
+            WithSerde::Both,
+            false,
+            DateTimeCrate::Chrono,
+            None,
+            false,
+            false,
+            true,
+            false,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            false,
+        );
+        assert_eq!(
+            writer.gen_typescript(&context),
+            format!(
+                r#"// `SeaORM` Entity types, @generated by sea-orm-codegen {}
+
+export type Tea = "EverydayTea" | "BreakfastTea";
+
+export interface Cake {{
+  id: number;
+  name: string;
+  price: string | null;
+  tea: Tea | null;
+  tags: string[];
+  type: unknown;
+  createdAt: string;
+}}
+"#,
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+}
@@ -0,0 +1,176 @@
+/// The line beginning a region of user code in a generated file, see [`keep_user_code`]
+pub const USER_CODE_BEGIN: &str = "// @user-code-begin";
+
+/// The line ending a region of user code in a generated file, see [`keep_user_code`]
+pub const USER_CODE_END: &str = "// @user-code-end";
+
+/// A region of user code, and the item it's placed before
+struct UserCode<'a> {
+    lines: &'a [&'a str],
+    anchor: Option<&'a str>,
+}
+
+/// Keep the regions of user code of a file, e.g. custom impls, derives and doc comments, in the
+/// file generated again, e.g. to declare companion modules in `mod.rs`.
+///
+/// A region stays before the item following it in the previous file, e.g. `pub struct Model {`,
+/// while the attributes in between are generated again. A region at the end of the file, or before
+/// an item which is no longer generated, is appended. The files are compared line by line, so both
+/// have to be formatted by `rustfmt`.
+pub fn keep_user_code(previous: &str, generated: &str) -> String {
+    let previous_lines: Vec<&str> = previous.lines().collect();
+    let regions = user_code_regions(&previous_lines);
+    if regions.is_empty() {
+        return generated.to_owned();
+    }
+
+    let mut placed = vec![false; regions.len()];
+    let mut lines: Vec<&str> = Vec::new();
+    for line in generated.lines() {
+        for (region, placed) in regions.iter().zip(placed.iter_mut()) {
+            if !*placed && region.anchor == Some(line.trim()) {
+                lines.extend(region.lines);
+                *placed = true;
+            }
+        }
+        lines.push(line);
+    }
+    for (region, placed) in regions.iter().zip(placed) {
+        if !placed {
+            lines.push("");
+            lines.extend(region.lines);
+        }
+    }
+    let mut file = lines.join("\n");
+    if generated.ends_with('\n') {
+        file.push('\n');
+    }
+    file
+}
+
+fn user_code_regions<'a>(lines: &'a [&'a str]) -> Vec<UserCode<'a>> {
+    let mut regions = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].trim() != USER_CODE_BEGIN {
+            i += 1;
+            continue;
+        }
+        let end = region_end(lines, i);
+        regions.push(UserCode {
+            lines: &lines[i..end],
+            anchor: region_anchor(lines, end),
+        });
+        i = end;
+    }
+    regions
+}
+
+/// The index of the line after the region beginning at `begin`, or the end of the file if the
+/// region isn't ended
+fn region_end(lines: &[&str], begin: usize) -> usize {
+    lines[begin + 1..]
+        .iter()
+        .position(|line| line.trim() == USER_CODE_END)
+        .map_or(lines.len(), |pos| begin + pos + 2)
+}
+
+/// The next line of code from `start`, skipping attributes, comments and other regions
+fn region_anchor<'a>(lines: &[&'a str], start: usize) -> Option<&'a str> {
+    let mut i = start;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        if line == USER_CODE_BEGIN {
+            i = region_end(lines, i);
+        } else if line.is_empty() || line.starts_with("#[") || line.starts_with("//") {
+            i += 1;
+        } else {
+            return Some(line);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_keep_user_code() {
+        let previous = r#"//! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.0
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "cake")]
+// @user-code-begin
+/// A cake of the bakery
+#[derive(Hash)]
+// @user-code-end
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// @user-code-begin
+impl Model {
+    pub fn is_new(&self) -> bool {
+        self.id == 0
+    }
+}
+// @user-code-end
+"#;
+        let generated = r#"//! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.0
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "cake")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+"#;
+        assert_eq!(
+            keep_user_code(previous, generated),
+            r#"//! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.0
+
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "cake")]
+// @user-code-begin
+/// A cake of the bakery
+#[derive(Hash)]
+// @user-code-end
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// @user-code-begin
+impl Model {
+    pub fn is_new(&self) -> bool {
+        self.id == 0
+    }
+}
+// @user-code-end
+"#
+        );
+    }
+
+    #[test]
+    fn test_keep_no_user_code() {
+        let generated = "pub mod cake;\n";
+        assert_eq!(keep_user_code("pub mod fruit;\n", generated), generated);
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{util::escape_rust_keyword, ActiveEnum, Entity};
+use crate::{
+    util::{check_enum_values, data_type_column_type, enum_type_values, escape_rust_keyword},
+    ActiveEnum, Column, Entity, PrimaryKey,
+};
 use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use sea_query::{Alias, ColumnType, IntoIden};
 use std::{collections::BTreeMap, str::FromStr};
 use syn::{punctuated::Punctuated, token::Comma};
 use tracing::info;
@@ -45,6 +49,7 @@ pub struct EntityWriterContext {
     pub(crate) lib: bool,
     pub(crate) serde_skip_hidden_column: bool,
     pub(crate) serde_skip_deserializing_primary_key: bool,
+    pub(crate) with_validator: bool,
     pub(crate) model_extra_derives: TokenStream,
     pub(crate) model_extra_attributes: TokenStream,
     pub(crate) enum_extra_derives: TokenStream,
@@ -143,6 +148,7 @@ impl EntityWriterContext {
         lib: bool,
         serde_skip_deserializing_primary_key: bool,
         serde_skip_hidden_column: bool,
+        with_validator: bool,
         model_extra_derives: Vec<String>,
         model_extra_attributes: Vec<String>,
         enum_extra_derives: Vec<String>,
@@ -158,6 +164,7 @@ impl EntityWriterContext {
             lib,
             serde_skip_deserializing_primary_key,
             serde_skip_hidden_column,
+            with_validator,
             model_extra_derives: bonus_derive(model_extra_derives),
             model_extra_attributes: bonus_attributes(model_extra_attributes),
             enum_extra_derives: bonus_derive(enum_extra_derives),
@@ -168,6 +175,147 @@ impl EntityWriterContext {
 }
 
 impl EntityWriter {
+    /// Set the Postgres domain a column is defined over
+    pub fn set_column_domain(&mut self, table_name: &str, column_name: &str, domain: &str) {
+        let columns = self
+            .entities
+            .iter_mut()
+            .filter(|entity| entity.table_name == table_name)
+            .flat_map(|entity| entity.columns.iter_mut())
+            .filter(|col| col.name == column_name);
+        for col in columns {
+            col.domain = Some(domain.to_owned());
+        }
+    }
+
+    /// Add a serde field attribute, e.g. `skip`, to a column, effective only when serde is derived
+    pub fn add_column_serde_attribute(&mut self, table_name: &str, column_name: &str, attr: &str) {
+        let columns = self
+            .entities
+            .iter_mut()
+            .filter(|entity| entity.table_name == table_name)
+            .flat_map(|entity| entity.columns.iter_mut())
+            .filter(|col| col.name == column_name);
+        for col in columns {
+            col.serde_attributes.push(attr.to_owned());
+        }
+    }
+
+    /// Add a read-only entity of a view, of its columns as `(name, data type, nullable)` with the
+    /// data types as the database shows them, e.g. `character varying(20)`. A view has no primary
+    /// key, so the column `id`, or else the first column, is taken as the primary key of the entity
+    pub fn add_view(&mut self, view_name: &str, columns: &[(String, String, bool)]) {
+        let columns: Vec<Column> = columns
+            .iter()
+            .map(|(name, data_type, nullable)| Column {
+                name: name.clone(),
+                col_type: data_type_column_type(data_type),
+                auto_increment: false,
+                not_null: !nullable,
+                unique: false,
+                domain: None,
+                serde_attributes: Vec::new(),
+                comment: None,
+            })
+            .collect();
+        let primary_key = columns
+            .iter()
+            .find(|col| col.name == "id")
+            .or_else(|| columns.first())
+            .map(|col| PrimaryKey {
+                name: col.name.clone(),
+            });
+        self.entities.push(Entity {
+            table_name: view_name.to_owned(),
+            comment: None,
+            view: true,
+            name: None,
+            columns,
+            relations: Vec::new(),
+            conjunct_relations: Vec::new(),
+            primary_keys: primary_key.into_iter().collect(),
+        });
+    }
+
+    /// Generate a MySQL enum column, of its column type e.g. `enum('draft','published')`, as an
+    /// ActiveEnum named after the table and the column
+    pub fn set_column_enum_type(&mut self, table_name: &str, column_name: &str, column_type: &str) {
+        if let Some(values) = enum_type_values(column_type) {
+            self.set_column_enum(table_name, column_name, values, false);
+        }
+    }
+
+    /// Generate a string column restricted to a list of values by a `CHECK` constraint, e.g.
+    /// `status IN ('draft', 'published')`, as an ActiveEnum named after the table and the column
+    pub fn set_column_check(&mut self, table_name: &str, column_name: &str, check_clause: &str) {
+        if let Some(values) = check_enum_values(column_name, check_clause) {
+            self.set_column_enum(table_name, column_name, values, true);
+        }
+    }
+
+    fn set_column_enum(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        values: Vec<String>,
+        string_column: bool,
+    ) {
+        let enum_name = format!("{table_name}_{column_name}");
+        let values: Vec<_> = values
+            .into_iter()
+            .map(|value| Alias::new(value).into_iden())
+            .collect();
+        let columns = self
+            .entities
+            .iter_mut()
+            .filter(|entity| entity.table_name == table_name)
+            .flat_map(|entity| entity.columns.iter_mut())
+            .filter(|col| col.name == column_name);
+        for col in columns {
+            let string_type = match &col.col_type {
+                ColumnType::Enum { .. } => continue,
+                ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text if string_column => {
+                    Some(col.col_type.clone())
+                }
+                _ if string_column => continue,
+                _ => None,
+            };
+            col.col_type = ColumnType::Enum {
+                name: Alias::new(&enum_name).into_iden(),
+                variants: values.clone(),
+            };
+            self.enums.insert(
+                enum_name.clone(),
+                ActiveEnum {
+                    enum_name: Alias::new(&enum_name).into_iden(),
+                    values: values.clone(),
+                    string_type,
+                },
+            );
+        }
+    }
+
+    /// Remove the columns of which `ignored(table_name, column_name)` is true from the entities,
+    /// except the columns of primary keys and foreign keys, which the entities and their relations
+    /// are defined by
+    pub fn ignore_columns<F>(&mut self, ignored: F)
+    where
+        F: Fn(&str, &str) -> bool,
+    {
+        for entity in self.entities.iter_mut() {
+            let keys: Vec<String> = entity
+                .primary_keys
+                .iter()
+                .map(|pk| pk.name.clone())
+                .chain(entity.relations.iter().flat_map(|rel| rel.columns.clone()))
+                .collect();
+            let table_name = &entity.table_name;
+            entity
+                .columns
+                .retain(|col| keys.contains(&col.name) || !ignored(table_name, &col.name));
+        }
+    }
+
     pub fn generate(self, context: &EntityWriterContext) -> WriterOutput {
         let mut files = Vec::new();
         files.extend(self.write_entities(context));
@@ -219,6 +367,7 @@ impl EntityWriter {
                         &context.schema_name,
                         serde_skip_deserializing_primary_key,
                         serde_skip_hidden_column,
+                        context.with_validator,
                         &context.model_extra_derives,
                         &context.model_extra_attributes,
                         context.seaography,
@@ -231,6 +380,7 @@ impl EntityWriter {
                         &context.schema_name,
                         serde_skip_deserializing_primary_key,
                         serde_skip_hidden_column,
+                        context.with_validator,
                         &context.model_extra_derives,
                         &context.model_extra_attributes,
                         context.seaography,
@@ -344,11 +494,17 @@ impl EntityWriter {
         schema_name: &Option<String>,
         serde_skip_deserializing_primary_key: bool,
         serde_skip_hidden_column: bool,
+        with_validator: bool,
         model_extra_derives: &TokenStream,
         model_extra_attributes: &TokenStream,
         seaography: bool,
     ) -> Vec<TokenStream> {
         let mut imports = Self::gen_import(with_serde);
+        if with_validator {
+            imports.extend(quote! {
+                use validator::Validate;
+            });
+        }
         imports.extend(Self::gen_import_active_enum(entity));
         let mut code_blocks = vec![
             imports,
@@ -360,6 +516,7 @@ impl EntityWriter {
                 date_time_crate,
                 serde_skip_deserializing_primary_key,
                 serde_skip_hidden_column,
+                with_validator,
                 model_extra_derives,
                 model_extra_attributes,
             ),
@@ -387,11 +544,17 @@ impl EntityWriter {
         schema_name: &Option<String>,
         serde_skip_deserializing_primary_key: bool,
         serde_skip_hidden_column: bool,
+        with_validator: bool,
         model_extra_derives: &TokenStream,
         model_extra_attributes: &TokenStream,
         seaography: bool,
     ) -> Vec<TokenStream> {
         let mut imports = Self::gen_import(with_serde);
+        if with_validator {
+            imports.extend(quote! {
+                use validator::Validate;
+            });
+        }
         imports.extend(Self::gen_import_active_enum(entity));
         let mut code_blocks = vec![
             imports,
@@ -402,6 +565,7 @@ impl EntityWriter {
                 schema_name,
                 serde_skip_deserializing_primary_key,
                 serde_skip_hidden_column,
+                with_validator,
                 model_extra_derives,
                 model_extra_attributes,
             ),
@@ -466,10 +630,26 @@ impl EntityWriter {
                 #table_name
             }
         };
+        let comment = entity.comment.as_ref().map(|comment| {
+            quote! {
+                fn comment(&self) -> Option<&str> {
+                    Some(#comment)
+                }
+            }
+        });
+        let view = entity.view.then(|| {
+            quote! {
+                fn is_view(&self) -> bool {
+                    true
+                }
+            }
+        });
         quote! {
             impl EntityName for Entity {
                 #schema_name
                 #table_name
+                #comment
+                #view
             }
         }
     }
@@ -503,6 +683,7 @@ impl EntityWriter {
         date_time_crate: &DateTimeCrate,
         serde_skip_deserializing_primary_key: bool,
         serde_skip_hidden_column: bool,
+        with_validator: bool,
         model_extra_derives: &TokenStream,
         model_extra_attributes: &TokenStream,
     ) -> TokenStream {
@@ -513,14 +694,21 @@ impl EntityWriter {
             serde_skip_deserializing_primary_key,
             serde_skip_hidden_column,
         );
+        let validate_attributes = if with_validator {
+            entity.get_column_validate_attributes()
+        } else {
+            vec![quote! {}; entity.columns.len()]
+        };
         let extra_derive = with_serde.extra_derive();
+        let validate_derive = with_validator.then(|| quote! { , Validate });
 
         quote! {
-            #[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel #if_eq_needed #extra_derive #model_extra_derives)]
+            #[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel #if_eq_needed #extra_derive #validate_derive #model_extra_derives)]
             #model_extra_attributes
             pub struct Model {
                 #(
                     #serde_attributes
+                    #validate_attributes
                     pub #column_names_snake_case: #column_rs_types,
                 )*
             }
@@ -720,6 +908,7 @@ impl EntityWriter {
         schema_name: &Option<String>,
         serde_skip_deserializing_primary_key: bool,
         serde_skip_hidden_column: bool,
+        with_validator: bool,
         model_extra_derives: &TokenStream,
         model_extra_attributes: &TokenStream,
     ) -> TokenStream {
@@ -757,6 +946,12 @@ impl EntityWriter {
                 if col.unique {
                     attrs.push(quote! { unique });
                 }
+                if let Some(domain) = &col.domain {
+                    attrs.push(quote! { domain = #domain });
+                }
+                if let Some(comment) = &col.comment {
+                    attrs.push(quote! { comment = #comment });
+                }
                 let mut ts = quote! {};
                 if !attrs.is_empty() {
                     for (i, attr) in attrs.into_iter().enumerate() {
@@ -772,9 +967,11 @@ impl EntityWriter {
                     serde_skip_deserializing_primary_key,
                     serde_skip_hidden_column,
                 );
+                let validate_attribute = with_validator.then(|| col.get_validate_attribute());
                 ts = quote! {
                     #ts
                     #serde_attribute
+                    #validate_attribute
                 };
                 ts
             })
@@ -785,13 +982,21 @@ impl EntityWriter {
             },
             None => quote! {},
         };
+        let comment = entity
+            .comment
+            .as_ref()
+            .map(|comment| quote! { , comment = #comment });
+        let view = entity.view.then(|| quote! { , view });
         let extra_derive = with_serde.extra_derive();
+        let validate_derive = with_validator.then(|| quote! { , Validate });
 
         quote! {
-            #[derive(Clone, Debug, PartialEq, DeriveEntityModel #if_eq_needed #extra_derive #model_extra_derives)]
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel #if_eq_needed #extra_derive #validate_derive #model_extra_derives)]
             #[sea_orm(
                 #schema_name
                 table_name = #table_name
+                #comment
+                #view
             )]
             #model_extra_attributes
             pub struct Model {
@@ -841,6 +1046,9 @@ mod tests {
         vec![
             Entity {
                 table_name: "cake".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -848,6 +1056,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -855,6 +1066,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -867,10 +1081,13 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![ConjunctRelation {
                     via: "cake_filling".to_owned(),
                     to: "filling".to_owned(),
+                    via_name: None,
+                    to_name: None,
                 }],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
@@ -878,6 +1095,9 @@ mod tests {
             },
             Entity {
                 table_name: "_cake_filling_".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "cake_id".to_owned(),
@@ -885,6 +1105,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "filling_id".to_owned(),
@@ -892,6 +1115,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![
@@ -905,6 +1131,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 0,
                         impl_related: true,
+                        ref_name: None,
                     },
                     Relation {
                         ref_table: "filling".to_owned(),
@@ -916,6 +1143,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 0,
                         impl_related: true,
+                        ref_name: None,
                     },
                 ],
                 conjunct_relations: vec![],
@@ -930,6 +1158,9 @@ mod tests {
             },
             Entity {
                 table_name: "cake_filling_price".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "cake_id".to_owned(),
@@ -937,6 +1168,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "filling_id".to_owned(),
@@ -944,6 +1178,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "price".to_owned(),
@@ -951,6 +1188,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -963,6 +1203,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![],
                 primary_keys: vec![
@@ -976,6 +1217,9 @@ mod tests {
             },
             Entity {
                 table_name: "filling".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -983,6 +1227,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -990,12 +1237,17 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![],
                 conjunct_relations: vec![ConjunctRelation {
                     via: "cake_filling".to_owned(),
                     to: "cake".to_owned(),
+                    via_name: None,
+                    to_name: None,
                 }],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
@@ -1003,6 +1255,9 @@ mod tests {
             },
             Entity {
                 table_name: "fruit".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1010,6 +1265,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1017,6 +1275,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "cake_id".to_owned(),
@@ -1024,6 +1285,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![
@@ -1037,6 +1301,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 0,
                         impl_related: true,
+                        ref_name: None,
                     },
                     Relation {
                         ref_table: "vendor".to_owned(),
@@ -1048,6 +1313,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 0,
                         impl_related: true,
+                        ref_name: None,
                     },
                 ],
                 conjunct_relations: vec![],
@@ -1057,6 +1323,9 @@ mod tests {
             },
             Entity {
                 table_name: "vendor".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1064,6 +1333,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "_name_".to_owned(),
@@ -1071,6 +1343,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "fruitId".to_owned(),
@@ -1078,6 +1353,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -1090,6 +1368,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![],
                 primary_keys: vec![PrimaryKey {
@@ -1098,6 +1377,9 @@ mod tests {
             },
             Entity {
                 table_name: "rust_keyword".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1105,6 +1387,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "testing".to_owned(),
@@ -1112,6 +1397,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "rust".to_owned(),
@@ -1119,6 +1407,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "keywords".to_owned(),
@@ -1126,6 +1417,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "type".to_owned(),
@@ -1133,6 +1427,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "typeof".to_owned(),
@@ -1140,6 +1437,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "crate".to_owned(),
@@ -1147,6 +1447,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "self".to_owned(),
@@ -1154,6 +1457,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "self_id1".to_owned(),
@@ -1161,6 +1467,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "self_id2".to_owned(),
@@ -1168,6 +1477,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "fruit_id1".to_owned(),
@@ -1175,6 +1487,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "fruit_id2".to_owned(),
@@ -1182,6 +1497,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "cake_id".to_owned(),
@@ -1189,6 +1507,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![
@@ -1202,6 +1523,7 @@ mod tests {
                         self_referencing: true,
                         num_suffix: 1,
                         impl_related: true,
+                        ref_name: None,
                     },
                     Relation {
                         ref_table: "rust_keyword".to_owned(),
@@ -1213,6 +1535,7 @@ mod tests {
                         self_referencing: true,
                         num_suffix: 2,
                         impl_related: true,
+                        ref_name: None,
                     },
                     Relation {
                         ref_table: "fruit".to_owned(),
@@ -1224,6 +1547,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 1,
                         impl_related: true,
+                        ref_name: None,
                     },
                     Relation {
                         ref_table: "fruit".to_owned(),
@@ -1235,6 +1559,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 2,
                         impl_related: true,
+                        ref_name: None,
                     },
                     Relation {
                         ref_table: "cake".to_owned(),
@@ -1246,6 +1571,7 @@ mod tests {
                         self_referencing: false,
                         num_suffix: 0,
                         impl_related: true,
+                        ref_name: None,
                     },
                 ],
                 conjunct_relations: vec![],
@@ -1255,6 +1581,9 @@ mod tests {
             },
             Entity {
                 table_name: "cake_with_float".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1262,6 +1591,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1269,6 +1601,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "price".to_owned(),
@@ -1276,6 +1611,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -1288,10 +1626,13 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![ConjunctRelation {
                     via: "cake_filling".to_owned(),
                     to: "filling".to_owned(),
+                    via_name: None,
+                    to_name: None,
                 }],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
@@ -1299,6 +1640,9 @@ mod tests {
             },
             Entity {
                 table_name: "cake_with_double".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1306,6 +1650,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -1313,6 +1660,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "price".to_owned(),
@@ -1320,6 +1670,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -1332,10 +1685,13 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![ConjunctRelation {
                     via: "cake_filling".to_owned(),
                     to: "filling".to_owned(),
+                    via_name: None,
+                    to_name: None,
                 }],
                 primary_keys: vec![PrimaryKey {
                     name: "id".to_owned(),
@@ -1343,6 +1699,9 @@ mod tests {
             },
             Entity {
                 table_name: "collection".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1350,6 +1709,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "integers".to_owned(),
@@ -1357,6 +1719,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "integers_opt".to_owned(),
@@ -1364,6 +1729,9 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![],
@@ -1374,6 +1742,9 @@ mod tests {
             },
             Entity {
                 table_name: "collection_float".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1381,6 +1752,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "floats".to_owned(),
@@ -1388,6 +1762,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "doubles".to_owned(),
@@ -1395,6 +1772,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![],
@@ -1405,6 +1785,9 @@ mod tests {
             },
             Entity {
                 table_name: "parent".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id1".to_owned(),
@@ -1412,6 +1795,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "id2".to_owned(),
@@ -1419,6 +1805,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -1431,6 +1820,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![],
                 primary_keys: vec![
@@ -1444,6 +1834,9 @@ mod tests {
             },
             Entity {
                 table_name: "child".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -1451,6 +1844,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "parent_id1".to_owned(),
@@ -1458,6 +1854,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "parent_id2".to_owned(),
@@ -1465,6 +1864,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -1477,6 +1879,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 }],
                 conjunct_relations: vec![],
                 primary_keys: vec![PrimaryKey {
@@ -1550,6 +1953,7 @@ mod tests {
                     &None,
                     false,
                     false,
+                    false,
                     &TokenStream::new(),
                     &TokenStream::new(),
                     false
@@ -1571,9 +1975,10 @@ mod tests {
                     &Some("schema_name".to_owned()),
                     false,
                     false,
+                    false,
                     &TokenStream::new(),
                     &TokenStream::new(),
-                    false,
+                    false
                 )
                 .into_iter()
                 .skip(1)
@@ -1634,9 +2039,10 @@ mod tests {
                     &None,
                     false,
                     false,
+                    false,
                     &TokenStream::new(),
                     &TokenStream::new(),
-                    false,
+                    false
                 )
                 .into_iter()
                 .skip(1)
@@ -1655,9 +2061,10 @@ mod tests {
                     &Some("schema_name".to_owned()),
                     false,
                     false,
+                    false,
                     &TokenStream::new(),
                     &TokenStream::new(),
-                    false,
+                    false
                 )
                 .into_iter()
                 .skip(1)
@@ -1688,9 +2095,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1704,9 +2112,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1720,9 +2129,10 @@ mod tests {
                 &None,
                 true,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1734,9 +2144,10 @@ mod tests {
                 &None,
                 true,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
 
@@ -1750,9 +2161,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1766,9 +2178,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1782,9 +2195,10 @@ mod tests {
                 &None,
                 true,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1796,9 +2210,10 @@ mod tests {
                 &None,
                 true,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
 
@@ -1809,6 +2224,9 @@ mod tests {
     fn test_gen_with_seaography() -> io::Result<()> {
         let cake_entity = Entity {
             table_name: "cake".to_owned(),
+            comment: None,
+            view: false,
+            name: None,
             columns: vec![
                 Column {
                     name: "id".to_owned(),
@@ -1816,6 +2234,9 @@ mod tests {
                     auto_increment: true,
                     not_null: true,
                     unique: false,
+                    domain: None,
+                    serde_attributes: Vec::new(),
+                    comment: None,
                 },
                 Column {
                     name: "name".to_owned(),
@@ -1823,6 +2244,9 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    domain: None,
+                    serde_attributes: Vec::new(),
+                    comment: None,
                 },
                 Column {
                     name: "base_id".to_owned(),
@@ -1830,6 +2254,9 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    domain: None,
+                    serde_attributes: Vec::new(),
+                    comment: None,
                 },
             ],
             relations: vec![
@@ -1843,6 +2270,7 @@ mod tests {
                     self_referencing: false,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 },
                 Relation {
                     ref_table: "cake".to_owned(),
@@ -1854,11 +2282,14 @@ mod tests {
                     self_referencing: true,
                     num_suffix: 0,
                     impl_related: true,
+                    ref_name: None,
                 },
             ],
             conjunct_relations: vec![ConjunctRelation {
                 via: "cake_filling".to_owned(),
                 to: "filling".to_owned(),
+                via_name: None,
+                to_name: None,
             }],
             primary_keys: vec![PrimaryKey {
                 name: "id".to_owned(),
@@ -1877,9 +2308,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                true,
+                true
             ))
         );
 
@@ -1893,9 +2325,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                true,
+                true
             ))
         );
 
@@ -1920,9 +2353,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1934,9 +2368,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &bonus_derive(["ts_rs::TS"]),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1950,9 +2385,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &bonus_derive(["ts_rs::TS", "utoipa::ToSchema"]),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
 
@@ -1968,9 +2404,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -1984,9 +2421,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &bonus_derive(["ts_rs::TS"]),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -2000,9 +2438,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &bonus_derive(["ts_rs::TS", "utoipa::ToSchema"]),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
 
@@ -2045,6 +2484,7 @@ mod tests {
                 &Option<String>,
                 bool,
                 bool,
+                bool,
                 &TokenStream,
                 &TokenStream,
                 bool,
@@ -2076,6 +2516,7 @@ mod tests {
             &entity_serde_variant.2,
             serde_skip_deserializing_primary_key,
             serde_skip_hidden_column,
+            false,
             &TokenStream::new(),
             &TokenStream::new(),
             false,
@@ -2108,9 +2549,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -2124,9 +2566,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#]),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -2140,9 +2583,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#, "ts(export)"]),
-                false,
+                false
             ))
         );
 
@@ -2158,9 +2602,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &TokenStream::new(),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -2174,9 +2619,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#]),
-                false,
+                false
             ))
         );
         assert_eq!(
@@ -2190,9 +2636,10 @@ mod tests {
                 &None,
                 false,
                 false,
+                false,
                 &TokenStream::new(),
                 &bonus_attributes([r#"serde(rename_all = "camelCase")"#, "ts(export)"]),
-                false,
+                false
             ))
         );
 
@@ -2236,6 +2683,9 @@ mod tests {
             // https://github.com/SeaQL/sea-orm/issues/1344
             Entity {
                 table_name: "task".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -2243,6 +2693,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "payload".to_owned(),
@@ -2250,6 +2703,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "payload_binary".to_owned(),
@@ -2257,6 +2713,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![],
@@ -2283,9 +2742,10 @@ mod tests {
                     &None,
                     false,
                     false,
+                    false,
                     &TokenStream::new(),
                     &TokenStream::new(),
-                    false,
+                    false
                 )
                 .into_iter()
                 .skip(1)
@@ -2304,9 +2764,10 @@ mod tests {
                     &Some("schema_name".to_owned()),
                     false,
                     false,
+                    false,
                     &TokenStream::new(),
                     &TokenStream::new(),
-                    false,
+                    false
                 )
                 .into_iter()
                 .skip(1)
@@ -2326,6 +2787,9 @@ mod tests {
         let entities = vec![
             Entity {
                 table_name: "tea_pairing".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -2333,6 +2797,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "first_tea".to_owned(),
@@ -2346,6 +2813,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "second_tea".to_owned(),
@@ -2359,6 +2829,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![],
@@ -2369,6 +2842,9 @@ mod tests {
             },
             Entity {
                 table_name: "tea_pairing_with_size".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -2376,6 +2852,9 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "first_tea".to_owned(),
@@ -2389,6 +2868,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "second_tea".to_owned(),
@@ -2402,6 +2884,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                     Column {
                         name: "size".to_owned(),
@@ -2416,6 +2901,9 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        domain: None,
+                        serde_attributes: Vec::new(),
+                        comment: None,
                     },
                 ],
                 relations: vec![],
@@ -2445,4 +2933,285 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_gen_with_comment() {
+        let entity = Entity {
+            table_name: "cake".to_owned(),
+            comment: Some("Cakes on sale".to_owned()),
+            view: false,
+            name: None,
+            columns: vec![Column {
+                name: "id".to_owned(),
+                col_type: ColumnType::Integer,
+                auto_increment: true,
+                not_null: true,
+                unique: false,
+                domain: None,
+                serde_attributes: Vec::new(),
+                comment: Some("The cake id".to_owned()),
+            }],
+            relations: vec![],
+            conjunct_relations: vec![],
+            primary_keys: vec![PrimaryKey {
+                name: "id".to_owned(),
+            }],
+        };
+
+        assert_eq!(
+            EntityWriter::gen_compact_model_struct(
+                &entity,
+                &WithSerde::None,
+                &DateTimeCrate::Chrono,
+                &None,
+                false,
+                false,
+                false,
+                &TokenStream::new(),
+                &TokenStream::new()
+            )
+            .to_string(),
+            quote! {
+                #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+                #[sea_orm(table_name = "cake", comment = "Cakes on sale")]
+                pub struct Model {
+                    #[sea_orm(primary_key, comment = "The cake id")]
+                    pub id: i32,
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            EntityWriter::gen_impl_entity_name(&entity, &None).to_string(),
+            quote! {
+                impl EntityName for Entity {
+                    fn table_name(&self) -> &str {
+                        "cake"
+                    }
+                    fn comment(&self) -> Option<&str> {
+                        Some("Cakes on sale")
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_gen_with_validator_and_serde_attributes() {
+        let column = |name: &str, col_type: ColumnType| Column {
+            name: name.to_owned(),
+            col_type,
+            auto_increment: false,
+            not_null: true,
+            unique: false,
+            domain: None,
+            serde_attributes: Vec::new(),
+            comment: None,
+        };
+        let mut writer = EntityWriter {
+            entities: vec![Entity {
+                table_name: "user".to_owned(),
+                comment: None,
+                view: false,
+                name: None,
+                columns: vec![
+                    column("id", ColumnType::Integer),
+                    column("email", ColumnType::String(StringLen::N(255))),
+                    column("password_hash", ColumnType::Text),
+                ],
+                relations: vec![],
+                conjunct_relations: vec![],
+                primary_keys: vec![PrimaryKey {
+                    name: "id".to_owned(),
+                }],
+            }],
+            enums: Default::default(),
+        };
+        writer.add_column_serde_attribute("user", "password_hash", "skip");
+        let entity = &writer.entities[0];
+
+        assert_eq!(
+            EntityWriter::gen_compact_model_struct(
+                entity,
+                &WithSerde::Both,
+                &DateTimeCrate::Chrono,
+                &None,
+                false,
+                false,
+                true,
+                &TokenStream::new(),
+                &TokenStream::new()
+            )
+            .to_string(),
+            quote! {
+                #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, Validate)]
+                #[sea_orm(table_name = "user")]
+                pub struct Model {
+                    #[sea_orm(primary_key, auto_increment = false)]
+                    pub id: i32,
+                    #[validate(length(max = 255))]
+                    pub email: String,
+                    #[sea_orm(column_type = "Text")]
+                    #[serde(skip)]
+                    pub password_hash: String,
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            EntityWriter::gen_model_struct(
+                entity,
+                &WithSerde::Both,
+                &DateTimeCrate::Chrono,
+                false,
+                false,
+                true,
+                &TokenStream::new(),
+                &TokenStream::new()
+            )
+            .to_string(),
+            quote! {
+                #[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel, Eq, Serialize, Deserialize, Validate)]
+                pub struct Model {
+                    pub id: i32,
+                    #[validate(length(max = 255))]
+                    pub email: String,
+                    #[serde(skip)]
+                    pub password_hash: String,
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_ignore_columns() {
+        let mut writer = EntityWriter {
+            entities: setup(),
+            enums: Default::default(),
+        };
+        writer.ignore_columns(|table, _| table == "cake_filling_price" || table == "fruit");
+
+        let column_names = |table: &str| -> Vec<&str> {
+            let entity = writer
+                .entities
+                .iter()
+                .find(|entity| entity.table_name == table)
+                .unwrap();
+            entity.columns.iter().map(|col| col.name.as_str()).collect()
+        };
+        assert_eq!(column_names("cake"), ["id", "name"]);
+        assert_eq!(
+            column_names("cake_filling_price"),
+            ["cake_id", "filling_id"]
+        );
+        assert_eq!(column_names("fruit"), ["id", "cake_id"]);
+    }
+
+    #[test]
+    fn test_set_column_enum() {
+        let mut writer = EntityWriter {
+            entities: setup(),
+            enums: Default::default(),
+        };
+        writer.set_column_check("cake", "name", "(name IN ('cheese', 'chocolate'))");
+        writer.set_column_check("cake", "id", "(id IN ('1', '2'))");
+        writer.set_column_enum_type("fruit", "name", "enum('apple','banana')");
+
+        let col_type = |table: &str, column: &str| -> ColumnType {
+            writer
+                .entities
+                .iter()
+                .find(|entity| entity.table_name == table)
+                .and_then(|entity| entity.columns.iter().find(|col| col.name == column))
+                .unwrap()
+                .col_type
+                .clone()
+        };
+        assert!(matches!(col_type("cake", "name"), ColumnType::Enum { .. }));
+        assert!(matches!(col_type("cake", "id"), ColumnType::Integer));
+        assert!(matches!(col_type("fruit", "name"), ColumnType::Enum { .. }));
+
+        assert_eq!(
+            writer.enums.keys().collect::<Vec<_>>(),
+            ["cake_name", "fruit_name"]
+        );
+        assert!(writer.enums["cake_name"].string_type.is_some());
+        assert!(writer.enums["fruit_name"].string_type.is_none());
+        assert_eq!(
+            writer.enums["fruit_name"]
+                .values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            ["apple", "banana"]
+        );
+    }
+
+    #[test]
+    fn test_add_view() {
+        let mut writer = EntityWriter {
+            entities: Vec::new(),
+            enums: Default::default(),
+        };
+        writer.add_view(
+            "cake_count",
+            &[
+                (
+                    "name".to_owned(),
+                    "character varying(255)".to_owned(),
+                    false,
+                ),
+                ("count".to_owned(), "bigint".to_owned(), true),
+            ],
+        );
+        let entity = &writer.entities[0];
+        assert!(entity.view);
+        assert_eq!(entity.primary_keys[0].name, "name");
+        assert_eq!(
+            entity
+                .columns
+                .iter()
+                .map(|col| (col.name.as_str(), col.not_null))
+                .collect::<Vec<_>>(),
+            [("name", true), ("count", false)]
+        );
+
+        let generate = |expanded_format: bool| -> String {
+            let context = crate::EntityWriterContext::new(
+                expanded_format,
+                WithSerde::None,
+                false,
+                DateTimeCrate::Chrono,
+                None,
+                false,
+                false,
+                false,
+                false,
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                false,
+            );
+            writer
+                .clone()
+                .generate(&context)
+                .files
+                .into_iter()
+                .find(|file| file.name == "cake_count.rs")
+                .unwrap()
+                .content
+        };
+        let compact = generate(false);
+        for tokens in [
+            quote! { #[sea_orm(table_name = "cake_count", view)] },
+            quote! { pub count: Option<i64>, },
+        ] {
+            assert!(compact.contains(&tokens.to_string()));
+        }
+        let expanded = generate(true);
+        assert!(expanded.contains(&quote! { fn is_view(&self) -> bool { true } }.to_string()));
+    }
 }
@@ -1,4 +1,4 @@
-use sea_query::TableRef;
+use sea_query::{Alias, ColumnType, RcOrArc, SeaRc, StringLen, TableRef};
 
 pub(crate) fn escape_rust_keyword<T>(string: T) -> String
 where
@@ -37,3 +37,228 @@ pub(crate) fn unpack_table_ref(table_ref: &TableRef) -> String {
         | TableRef::FunctionCall(_, tbl) => tbl.to_string(),
     }
 }
+
+/// The column type of a data type as the database shows it, e.g. `character varying(20)` or
+/// `int unsigned`; any other data type is kept as a custom type, generated as a `String`
+pub(crate) fn data_type_column_type(data_type: &str) -> ColumnType {
+    let data_type = data_type.trim().to_lowercase();
+    if let Some(element_type) = data_type.strip_suffix("[]") {
+        return ColumnType::Array(RcOrArc::new(data_type_column_type(element_type)));
+    }
+    let (name, args) = match (data_type.find('('), data_type.find(')')) {
+        (Some(open), Some(close)) if open < close => (
+            format!("{} {}", &data_type[..open], &data_type[close + 1..]),
+            data_type[open + 1..close]
+                .split(',')
+                .filter_map(|arg| arg.trim().parse::<u32>().ok())
+                .collect(),
+        ),
+        _ => (data_type.clone(), Vec::new()),
+    };
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    let (name, unsigned) = match name.strip_suffix(" unsigned") {
+        Some(name) => (name, true),
+        None => (name.as_str(), false),
+    };
+    let len = args.first().copied();
+    let precision = match args[..] {
+        [precision, scale] => Some((precision, scale)),
+        [precision] => Some((precision, 0)),
+        _ => None,
+    };
+    match name {
+        "tinyint" if unsigned => ColumnType::TinyUnsigned,
+        "tinyint" => ColumnType::TinyInteger,
+        "smallint" | "int2" if unsigned => ColumnType::SmallUnsigned,
+        "smallint" | "int2" => ColumnType::SmallInteger,
+        "int" | "integer" | "int4" | "mediumint" if unsigned => ColumnType::Unsigned,
+        "int" | "integer" | "int4" | "mediumint" => ColumnType::Integer,
+        "bigint" | "int8" if unsigned => ColumnType::BigUnsigned,
+        "bigint" | "int8" => ColumnType::BigInteger,
+        "bool" | "boolean" => ColumnType::Boolean,
+        "real" | "float" | "float4" => ColumnType::Float,
+        "double" | "double precision" | "float8" => ColumnType::Double,
+        "decimal" | "numeric" => ColumnType::Decimal(precision),
+        "money" => ColumnType::Money(None),
+        "char" | "character" | "bpchar" => ColumnType::Char(len),
+        "varchar" | "character varying" => {
+            ColumnType::String(len.map_or(StringLen::None, StringLen::N))
+        }
+        "text" | "tinytext" | "mediumtext" | "longtext" => ColumnType::Text,
+        "date" => ColumnType::Date,
+        "time" | "time without time zone" => ColumnType::Time,
+        "datetime" | "timestamp without time zone" => ColumnType::DateTime,
+        "timestamp" => ColumnType::Timestamp,
+        "timestamptz" | "timestamp with time zone" => ColumnType::TimestampWithTimeZone,
+        "uuid" => ColumnType::Uuid,
+        "json" => ColumnType::Json,
+        "jsonb" => ColumnType::JsonBinary,
+        "binary" => ColumnType::Binary(len.unwrap_or(1)),
+        "varbinary" | "bytea" => ColumnType::VarBinary(len.map_or(StringLen::None, StringLen::N)),
+        "blob" | "tinyblob" | "mediumblob" | "longblob" => ColumnType::Blob,
+        _ => ColumnType::Custom(SeaRc::new(Alias::new(data_type))),
+    }
+}
+
+/// The values of a MySQL enum column type, e.g. `enum('draft','published')`
+pub(crate) fn enum_type_values(column_type: &str) -> Option<Vec<String>> {
+    let (sql, values) = replace_string_literals(column_type);
+    let placeholders = "?".repeat(values.len());
+    (!values.is_empty() && normalize_sql(&sql) == format!("enum{placeholders}")).then_some(values)
+}
+
+/// The values of a `CHECK` constraint restricting a column to a list of strings, as shown by
+/// MySQL, e.g. ``(`status` in (_utf8mb4'draft',_utf8mb4'published'))``, or by PostgreSQL, e.g.
+/// `((status)::text = ANY ((ARRAY['draft'::character varying, 'published'::character varying])::text[]))`
+pub(crate) fn check_enum_values(column_name: &str, check_clause: &str) -> Option<Vec<String>> {
+    let (sql, values) = replace_string_literals(check_clause);
+    let column = column_name.to_lowercase();
+    let placeholders = "?".repeat(values.len());
+    let sql = normalize_sql(&sql);
+    (!values.is_empty()
+        && (sql == format!("{column}in{placeholders}")
+            || sql == format!("{column}=anyarray[{placeholders}]")))
+    .then_some(values)
+}
+
+/// Replace each string literal of SQL by `?`, with the values of the literals
+fn replace_string_literals(sql: &str) -> (String, Vec<String>) {
+    let mut replaced = String::new();
+    let mut values = Vec::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            replaced.push(c);
+            continue;
+        }
+        let mut value = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => value.extend(chars.next()),
+                '\'' if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    value.push('\'');
+                }
+                '\'' => break,
+                c => value.push(c),
+            }
+        }
+        replaced.push('?');
+        values.push(value);
+    }
+    (replaced, values)
+}
+
+/// Lowercase SQL and strip it of whitespace, punctuation, identifier quotes, `::type` casts and
+/// `_charset` introducers, leaving the operators and `?` of the string literals to compare
+fn normalize_sql(sql: &str) -> String {
+    let sql: String = sql
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let mut normalized = String::new();
+    let mut rest = sql.as_str();
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        if c == ':' && rest.starts_with(':') {
+            let type_len = rest[1..]
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len() - 1);
+            rest = rest[1 + type_len..].trim_start_matches("[]");
+        } else {
+            if c == '?' {
+                if let Some(pos) = normalized.rfind('_') {
+                    let introducer = &normalized[pos + 1..];
+                    if introducer.chars().all(|c| c.is_ascii_alphanumeric())
+                        && !normalized[..pos].ends_with(|c: char| c.is_ascii_alphanumeric())
+                    {
+                        normalized.truncate(pos);
+                    }
+                }
+            }
+            normalized.push(c);
+        }
+    }
+    normalized.retain(|c| !matches!(c, '(' | ')' | ',' | '`' | '"'));
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_type_column_type() {
+        for (data_type, col_type) in [
+            ("integer", ColumnType::Integer),
+            ("int(11) unsigned", ColumnType::Unsigned),
+            (
+                "character varying(20)",
+                ColumnType::String(StringLen::N(20)),
+            ),
+            ("varchar", ColumnType::String(StringLen::None)),
+            ("numeric(10,2)", ColumnType::Decimal(Some((10, 2)))),
+            (
+                "timestamp(3) with time zone",
+                ColumnType::TimestampWithTimeZone,
+            ),
+            ("text[]", ColumnType::Array(RcOrArc::new(ColumnType::Text))),
+            (
+                "tsvector",
+                ColumnType::Custom(SeaRc::new(Alias::new("tsvector"))),
+            ),
+        ] {
+            assert_eq!(data_type_column_type(data_type), col_type, "{data_type}");
+        }
+    }
+
+    #[test]
+    fn test_enum_type_values() {
+        assert_eq!(
+            enum_type_values("enum('draft','it''s published')"),
+            Some(vec!["draft".to_owned(), "it's published".to_owned()])
+        );
+        assert_eq!(enum_type_values("varchar(20)"), None);
+        assert_eq!(enum_type_values("set('a','b')"), None);
+    }
+
+    #[test]
+    fn test_check_enum_values() {
+        let values = Some(vec!["draft".to_owned(), "published".to_owned()]);
+        assert_eq!(
+            check_enum_values(
+                "status",
+                "(`status` in (_utf8mb4'draft',_utf8mb4'published'))"
+            ),
+            values
+        );
+        assert_eq!(
+            check_enum_values(
+                "status",
+                "((status = ANY (ARRAY['draft'::text, 'published'::text])))"
+            ),
+            values
+        );
+        assert_eq!(
+            check_enum_values(
+                "status",
+                "(((status)::text = ANY ((ARRAY['draft'::character varying, 'published'::character varying])::text[])))"
+            ),
+            values
+        );
+        assert_eq!(
+            check_enum_values("status", "status IN ('draft', 'published')"),
+            values
+        );
+        assert_eq!(
+            check_enum_values("status", "status NOT IN ('draft', 'published')"),
+            None
+        );
+        assert_eq!(
+            check_enum_values("title", "status IN ('draft', 'published')"),
+            None
+        );
+        assert_eq!(check_enum_values("price", "(price > 0)"), None);
+    }
+}
@@ -1,5 +1,6 @@
 use super::util::{
     escape_rust_keyword, field_not_ignored, format_field_ident, trim_starting_raw_identifier,
+    FieldConverters,
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::{Ident, TokenStream};
@@ -66,6 +67,32 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
         })
         .collect::<Result<_, _>>()?;
 
+    let converters: Vec<FieldConverters> = fields
+        .clone()
+        .map(|field| FieldConverters::from_field(&field))
+        .collect::<Result<_, _>>()?;
+
+    // the stored value of a field with a `save_with` function
+    let wrapped_value: Vec<TokenStream> = converters
+        .iter()
+        .map(|converters| match &converters.save_with {
+            Some(save_with) => quote! { .map(#save_with).into_wrapped_value() },
+            None => quote! { .into_wrapped_value() },
+        })
+        .collect();
+
+    let set_value: Vec<TokenStream> = field
+        .iter()
+        .zip(&converters)
+        .map(|(field, converters)| match &converters.load_with {
+            Some(load_with) => {
+                let msg = format!("failed to load {field} with `load_with`");
+                quote! { sea_orm::load_with(v.unwrap(), #load_with).expect(#msg) }
+            }
+            None => quote! { v.unwrap() },
+        })
+        .collect();
+
     let ty: Vec<Type> = fields.into_iter().map(|Field { ty, .. }| ty).collect();
 
     Ok(quote!(
@@ -111,7 +138,7 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
                     #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => {
                         let mut value = sea_orm::ActiveValue::not_set();
                         std::mem::swap(&mut value, &mut self.#field);
-                        value.into_wrapped_value()
+                        value #wrapped_value
                     },)*
                     _ => sea_orm::ActiveValue::not_set(),
                 }
@@ -119,14 +146,14 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
 
             fn get(&self, c: <Self::Entity as sea_orm::EntityTrait>::Column) -> sea_orm::ActiveValue<sea_orm::Value> {
                 match c {
-                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field.clone().into_wrapped_value(),)*
+                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field.clone() #wrapped_value,)*
                     _ => sea_orm::ActiveValue::not_set(),
                 }
             }
 
             fn set(&mut self, c: <Self::Entity as sea_orm::EntityTrait>::Column, v: sea_orm::Value) {
                 match c {
-                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::set(v.unwrap()),)*
+                    #(<Self::Entity as sea_orm::EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::set(#set_value),)*
                     _ => panic!("This ActiveModel does not have this field"),
                 }
             }
@@ -171,17 +198,28 @@ fn derive_into_model(model_fields: IntoIter<Field>) -> syn::Result<TokenStream>
     let model_field: Vec<Ident> = model_fields.clone().map(format_field_ident).collect();
 
     let ignore_attr: Vec<bool> = model_fields
+        .clone()
         .map(|field| !field_not_ignored(&field))
         .collect();
 
+    let converters: Vec<FieldConverters> = model_fields
+        .map(|field| FieldConverters::from_field(&field))
+        .collect::<Result<_, _>>()?;
+
     let model_field_value: Vec<TokenStream> = model_field
         .iter()
         .zip(ignore_attr)
-        .map(|(field, ignore)| {
+        .zip(converters)
+        .map(|((field, ignore), converters)| {
             if ignore {
                 quote! {
                     Default::default()
                 }
+            } else if converters.save_with.is_some() || converters.load_with.is_some() {
+                // the field may not be convertible into a `Value`
+                quote! {
+                    a.#field.unwrap()
+                }
             } else {
                 quote! {
                     a.#field.into_value().unwrap().unwrap()
@@ -12,10 +12,12 @@ pub mod derive_attr {
         pub primary_key: Option<syn::Ident>,
         pub relation: Option<syn::Ident>,
         pub schema_name: Option<syn::Lit>,
+        pub database_name: Option<syn::Lit>,
         pub table_name: Option<syn::Lit>,
         pub comment: Option<syn::Lit>,
         pub table_iden: Option<()>,
         pub rename_all: Option<syn::Lit>,
+        pub view: Option<()>,
     }
 }
 
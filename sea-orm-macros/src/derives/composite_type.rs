@@ -0,0 +1,165 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+struct DeriveCompositeType {
+    ident: syn::Ident,
+    type_name: String,
+    fields: Vec<syn::Ident>,
+}
+
+impl DeriveCompositeType {
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let ident = input.ident;
+        let mut type_name = ident.to_string().to_snake_case();
+
+        for attr in input.attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("type_name") {
+                    let litstr: LitStr = meta.value()?.parse()?;
+                    type_name = litstr.value();
+                } else {
+                    return Err(meta.error(format!(
+                        "Unknown attribute parameter found: {:?}",
+                        meta.path.get_ident()
+                    )));
+                }
+                Ok(())
+            })?;
+        }
+
+        let fields = match input.data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Named(fields),
+                ..
+            }) => fields
+                .named
+                .into_iter()
+                .filter_map(|field| field.ident)
+                .collect(),
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "DeriveCompositeType can only be applied to a struct with named fields",
+                ))
+            }
+        };
+
+        Ok(Self {
+            ident,
+            type_name,
+            fields,
+        })
+    }
+
+    fn expand(&self) -> TokenStream {
+        let Self {
+            ident,
+            type_name,
+            fields,
+        } = self;
+        let field_count = fields.len();
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::CompositeType for #ident {
+                fn name() -> sea_orm::sea_query::DynIden {
+                    sea_orm::sea_query::SeaRc::new(sea_orm::sea_query::Alias::new(#type_name))
+                }
+
+                fn into_values(self) -> std::vec::Vec<sea_orm::Value> {
+                    vec![#(self.#fields.into()),*]
+                }
+
+                fn from_fields(
+                    fields: std::vec::Vec<std::option::Option<std::string::String>>,
+                ) -> std::result::Result<Self, sea_orm::DbErr> {
+                    let count = fields.len();
+                    let [#(#fields),*]: [std::option::Option<std::string::String>; #field_count] =
+                        fields.try_into().map_err(|_| {
+                            sea_orm::DbErr::Type(format!(
+                                "Expected {} attributes in a row value of `{}`, found {}",
+                                #field_count, #type_name, count
+                            ))
+                        })?;
+                    Ok(Self {
+                        #(#fields: sea_orm::CompositeField::from_field(#fields)?),*
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::CompositeField for #ident {
+                fn from_field(
+                    field: std::option::Option<std::string::String>,
+                ) -> std::result::Result<Self, sea_orm::DbErr> {
+                    match field {
+                        Some(text) => <Self as sea_orm::CompositeType>::from_text(&text),
+                        None => Err(sea_orm::DbErr::Type(format!(
+                            "Unexpected NULL attribute of type `{}` in a row value",
+                            #type_name
+                        ))),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl std::convert::From<#ident> for sea_orm::Value {
+                fn from(source: #ident) -> Self {
+                    sea_orm::Value::String(Some(std::boxed::Box::new(
+                        <#ident as sea_orm::CompositeType>::into_text(source),
+                    )))
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::TryGetable for #ident {
+                fn try_get_by<I: sea_orm::ColIdx>(res: &sea_orm::QueryResult, idx: I)
+                    -> std::result::Result<Self, sea_orm::TryGetError> {
+                    let text = <std::string::String as sea_orm::TryGetable>::try_get_by(res, idx)?;
+                    <Self as sea_orm::CompositeType>::from_text(&text).map_err(sea_orm::TryGetError::DbErr)
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::ValueType for #ident {
+                fn try_from(v: sea_orm::Value) -> std::result::Result<Self, sea_orm::sea_query::ValueTypeErr> {
+                    match v {
+                        sea_orm::Value::String(Some(text)) => {
+                            <Self as sea_orm::CompositeType>::from_text(&text)
+                                .map_err(|_| sea_orm::sea_query::ValueTypeErr)
+                        }
+                        _ => Err(sea_orm::sea_query::ValueTypeErr),
+                    }
+                }
+
+                fn type_name() -> std::string::String {
+                    stringify!(#ident).to_owned()
+                }
+
+                fn array_type() -> sea_orm::sea_query::ArrayType {
+                    sea_orm::sea_query::ArrayType::String
+                }
+
+                fn column_type() -> sea_orm::sea_query::ColumnType {
+                    sea_orm::sea_query::ColumnType::Custom(<Self as sea_orm::CompositeType>::name())
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::Nullable for #ident {
+                fn null() -> sea_orm::Value {
+                    sea_orm::Value::String(None)
+                }
+            }
+        )
+    }
+}
+
+pub fn expand_derive_composite_type(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    DeriveCompositeType::new(input).map(|composite| composite.expand())
+}
@@ -13,7 +13,10 @@ struct DeriveEntity {
     primary_key_ident: syn::Ident,
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
+    database_name: Option<syn::Lit>,
     table_name: Option<syn::Lit>,
+    comment: Option<syn::Lit>,
+    view: bool,
 }
 
 impl DeriveEntity {
@@ -35,6 +38,9 @@ impl DeriveEntity {
 
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
+        let database_name = sea_attr.database_name;
+        let comment = sea_attr.comment;
+        let view = sea_attr.view.is_some();
 
         Ok(DeriveEntity {
             column_ident,
@@ -44,7 +50,10 @@ impl DeriveEntity {
             primary_key_ident,
             relation_ident,
             schema_name,
+            database_name,
             table_name,
+            comment,
+            view,
         })
     }
 
@@ -73,6 +82,17 @@ impl DeriveEntity {
             .as_ref()
             .map(|schema| quote!(Some(#schema)))
             .unwrap_or_else(|| quote!(None));
+        let expanded_database_name = self
+            .database_name
+            .as_ref()
+            .map(|database| quote!(Some(#database)))
+            .unwrap_or_else(|| quote!(None));
+        let expanded_comment = self
+            .comment
+            .as_ref()
+            .map(|comment| quote!(Some(#comment)))
+            .unwrap_or_else(|| quote!(None));
+        let view = self.view;
 
         quote!(
             #[automatically_derived]
@@ -84,6 +104,18 @@ impl DeriveEntity {
                 fn table_name(&self) -> &str {
                     #table_name
                 }
+
+                fn comment(&self) -> Option<&str> {
+                    #expanded_comment
+                }
+
+                fn database_name(&self) -> Option<&str> {
+                    #expanded_database_name
+                }
+
+                fn is_view(&self) -> bool {
+                    #view
+                }
             }
         )
     }
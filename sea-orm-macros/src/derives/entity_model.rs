@@ -13,7 +13,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut table_name = None;
     let mut comment = quote! {None};
     let mut schema_name = quote! { None };
+    let mut database_name = quote! { None };
     let mut table_iden = false;
+    let mut view = false;
     let mut rename_all: Option<CaseStyle> = None;
 
     attrs
@@ -29,8 +31,13 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                 } else if meta.path.is_ident("schema_name") {
                     let name: Lit = meta.value()?.parse()?;
                     schema_name = quote! { Some(#name) };
+                } else if meta.path.is_ident("database_name") {
+                    let name: Lit = meta.value()?.parse()?;
+                    database_name = quote! { Some(#name) };
                 } else if meta.path.is_ident("table_iden") {
                     table_iden = true;
+                } else if meta.path.is_ident("view") {
+                    view = true;
                 } else if meta.path.is_ident("rename_all") {
                     rename_all = Some((&meta).try_into()?);
                 } else {
@@ -65,6 +72,14 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     fn comment(&self) -> Option<&str> {
                         #comment
                     }
+
+                    fn database_name(&self) -> Option<&str> {
+                        #database_name
+                    }
+
+                    fn is_view(&self) -> bool {
+                        #view
+                    }
                 }
             }
         })
@@ -105,9 +120,13 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut nullable = false;
                     let mut default_value = None;
                     let mut comment = None;
+                    let mut domain = None;
+                    let mut collation = None;
+                    let mut uuid_repr = None;
                     let mut default_expr = None;
                     let mut select_as = None;
                     let mut save_as = None;
+                    let mut save_with = None;
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
@@ -125,7 +144,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
 
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique, citext, domain = "email", collation = "C", uuid_repr = "binary", duration_repr = "millis", save_with = "f", load_with = "g")]
                     for attr in field.attrs.iter() {
                         if !attr.path().is_ident("sea_orm") {
                             continue;
@@ -154,6 +173,14 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 }
                             } else if meta.path.is_ident("comment") {
                                 comment = Some(meta.value()?.parse::<Lit>()?);
+                            } else if meta.path.is_ident("domain") {
+                                domain = Some(meta.value()?.parse::<Lit>()?);
+                            } else if meta.path.is_ident("collation") {
+                                collation = Some(meta.value()?.parse::<Lit>()?);
+                            } else if meta.path.is_ident("uuid_repr") {
+                                uuid_repr = Some(crate::derives::util::parse_uuid_repr(
+                                    &meta.value()?.parse()?,
+                                )?);
                             } else if meta.path.is_ident("default_value") {
                                 default_value = Some(meta.value()?.parse::<Lit>()?);
                             } else if meta.path.is_ident("default_expr") {
@@ -197,6 +224,17 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 } else {
                                     return Err(meta.error(format!("Invalid save_as {:?}", lit)));
                                 }
+                            } else if meta.path.is_ident("save_with") {
+                                let lit = meta.value()?.parse()?;
+                                if let Lit::Str(litstr) = lit {
+                                    save_with = Some(litstr.parse::<syn::Path>()?);
+                                } else {
+                                    return Err(meta.error(format!("Invalid save_with {:?}", lit)));
+                                }
+                            } else if meta.path.is_ident("duration_repr") {
+                                crate::derives::util::parse_duration_repr(&meta.value()?.parse()?)?;
+                                // the count of milliseconds or microseconds
+                                sql_type = Some(quote! { BigInteger });
                             } else if meta.path.is_ident("ignore") {
                                 ignore = true;
                             } else if meta.path.is_ident("primary_key") {
@@ -208,6 +246,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 indexed = true;
                             } else if meta.path.is_ident("unique") {
                                 unique = true;
+                            } else if meta.path.is_ident("citext") {
+                                sql_type = Some(quote! { custom("citext") });
                             } else {
                                 // Reads the value expression to advance the parse stream.
                                 // Some parameters, such as `primary_key`, do not have any value,
@@ -274,9 +314,19 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     };
                     let field_span = field.span();
 
-                    let sea_query_col_type = crate::derives::sql_type_match::col_type_match(
-                        sql_type, field_type, field_span,
-                    );
+                    if uuid_repr.is_some() && sql_type.is_none() {
+                        // the bytes of the UUID
+                        sql_type = Some(quote! { Binary(16) });
+                    }
+                    let sea_query_col_type = match (sql_type, save_with) {
+                        // the column type of the value returned by the `save_with` function
+                        (None, Some(save_with)) => {
+                            quote! { sea_orm::save_with_column_type(#save_with) }
+                        }
+                        (sql_type, _) => crate::derives::sql_type_match::col_type_match(
+                            sql_type, field_type, field_span,
+                        ),
+                    };
 
                     let col_def =
                         quote! { sea_orm::prelude::ColumnTypeTrait::def(#sea_query_col_type) };
@@ -300,6 +350,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if let Some(default_expr) = default_expr {
                         match_row = quote! { #match_row.default(#default_expr) };
                     }
+                    if let Some(domain) = domain {
+                        match_row = quote! { #match_row.domain(#domain) };
+                    }
+                    if let Some(collation) = collation {
+                        match_row = quote! { #match_row.collation(#collation) };
+                    }
+                    if let Some(uuid_repr) = uuid_repr {
+                        match_row = quote! { #match_row.uuid_repr(#uuid_repr) };
+                    }
                     // match_row = quote! { #match_row.comment() };
                     columns_trait.push(match_row);
                 }
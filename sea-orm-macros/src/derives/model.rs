@@ -1,6 +1,6 @@
 use super::{
     attributes::derive_attr,
-    util::{escape_rust_keyword, field_not_ignored, trim_starting_raw_identifier},
+    util::{escape_rust_keyword, field_not_ignored, trim_starting_raw_identifier, FieldConverters},
 };
 use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
@@ -15,6 +15,7 @@ enum Error {
 
 struct DeriveModel {
     column_idents: Vec<syn::Ident>,
+    converters: Vec<FieldConverters>,
     entity_ident: syn::Ident,
     field_idents: Vec<syn::Ident>,
     ident: syn::Ident,
@@ -79,8 +80,15 @@ impl DeriveModel {
             .map(|field| !field_not_ignored(field))
             .collect();
 
+        let converters = fields
+            .iter()
+            .map(FieldConverters::from_field)
+            .collect::<Result<_, _>>()
+            .map_err(Error::Syn)?;
+
         Ok(DeriveModel {
             column_idents,
+            converters,
             entity_ident,
             field_idents,
             ident,
@@ -105,15 +113,28 @@ impl DeriveModel {
         let field_values: Vec<TokenStream> = column_idents
             .iter()
             .zip(&self.ignore_attrs)
-            .map(|(column_ident, ignore)| {
+            .zip(&self.converters)
+            .map(|((column_ident, ignore), converters)| {
                 if *ignore {
-                    quote! {
+                    return quote! {
                         Default::default()
-                    }
-                } else {
-                    quote! {
-                        row.try_get(pre, sea_orm::IdenStatic::as_str(&<<Self as sea_orm::ModelTrait>::Entity as sea_orm::entity::EntityTrait>::Column::#column_ident).into())?
-                    }
+                    };
+                }
+                let column = quote! {
+                    sea_orm::IdenStatic::as_str(&<<Self as sea_orm::ModelTrait>::Entity as sea_orm::entity::EntityTrait>::Column::#column_ident)
+                };
+                if let Some(uuid_repr) = &converters.uuid_repr {
+                    // the bytes of the UUID
+                    return quote! {
+                        sea_orm::FromUuidRepr::try_get_repr(row, pre, #column, #uuid_repr)?
+                    };
+                }
+                let value = quote! {
+                    row.try_get(pre, #column.into())?
+                };
+                match &converters.load_with {
+                    Some(load_with) => quote! { sea_orm::load_with(#value, #load_with)? },
+                    None => value,
                 }
             })
             .collect();
@@ -153,6 +174,32 @@ impl DeriveModel {
             .zip(ignore_attrs)
             .filter_map(ignore)
             .collect();
+        let converters: Vec<&FieldConverters> = self
+            .converters
+            .iter()
+            .zip(ignore_attrs)
+            .filter(|(_, ignore)| !**ignore)
+            .map(|(converters, _)| converters)
+            .collect();
+        let get_values: Vec<TokenStream> = field_idents
+            .iter()
+            .zip(&converters)
+            .map(|(field_ident, converters)| match &converters.save_with {
+                Some(save_with) => quote! { #save_with(self.#field_ident.clone()).into() },
+                None => quote! { self.#field_ident.clone().into() },
+            })
+            .collect();
+        let set_values: Vec<TokenStream> = field_idents
+            .iter()
+            .zip(&converters)
+            .map(|(field_ident, converters)| match &converters.load_with {
+                Some(load_with) => {
+                    let msg = format!("failed to load {field_ident} with `load_with`");
+                    quote! { sea_orm::load_with(v.unwrap(), #load_with).expect(#msg) }
+                }
+                None => quote! { v.unwrap() },
+            })
+            .collect();
 
         let missing_field_msg = format!("field does not exist on {ident}");
 
@@ -163,14 +210,14 @@ impl DeriveModel {
 
                 fn get(&self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column) -> sea_orm::Value {
                     match c {
-                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents.clone().into(),)*
+                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => #get_values,)*
                         _ => panic!(#missing_field_msg),
                     }
                 }
 
                 fn set(&mut self, c: <Self::Entity as sea_orm::entity::EntityTrait>::Column, v: sea_orm::Value) {
                     match c {
-                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents = v.unwrap(),)*
+                        #(<Self::Entity as sea_orm::entity::EntityTrait>::Column::#column_idents => self.#field_idents = #set_values,)*
                         _ => panic!(#missing_field_msg),
                     }
                 }
@@ -21,6 +21,7 @@ pub fn col_type_match(
                 "u32" => quote! { Unsigned },
                 "i64" => quote! { BigInteger },
                 "u64" => quote! { BigUnsigned },
+                "i128" | "u128" => quote! { Decimal(Some((39, 0))) },
                 "f32" => quote! { Float },
                 "f64" => quote! { Double },
                 "bool" => quote! { Boolean },
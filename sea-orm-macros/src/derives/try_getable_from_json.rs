@@ -1,7 +1,101 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::quote;
+use syn::{Attribute, Data, DeriveInput, LitStr, Token};
+
+/// The `#[serde(tag = "..")]` of an internally or adjacently tagged enum
+struct SerdeTag {
+    tag: String,
+    variants: Vec<(Ident, String)>,
+}
+
+/// Parse the `key = "value"` pairs of the `serde` attributes, skipping the other keys
+fn parse_serde_attrs(attrs: &[Attribute]) -> syn::Result<Vec<(String, LitStr)>> {
+    let mut pairs = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.get_ident().map(|ident| ident.to_string());
+            if meta.input.peek(Token![=]) {
+                let value = meta.value()?;
+                if value.peek(LitStr) {
+                    if let Some(key) = key {
+                        pairs.push((key, value.parse()?));
+                        return Ok(());
+                    }
+                }
+                value.parse::<syn::Expr>()?;
+            } else if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                // a parenthesized list, e.g. `rename(deserialize = "..")`
+                meta.input.parse::<TokenTree>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(pairs)
+}
+
+/// Rename a variant as `#[serde(rename_all = "..")]` does
+fn rename_variant(variant: &str, rule: &LitStr) -> syn::Result<String> {
+    let snake_case = || {
+        let mut snake = String::new();
+        for (i, ch) in variant.char_indices() {
+            if i > 0 && ch.is_uppercase() {
+                snake.push('_');
+            }
+            snake.push(ch.to_ascii_lowercase());
+        }
+        snake
+    };
+    Ok(match rule.value().as_str() {
+        "lowercase" => variant.to_ascii_lowercase(),
+        "UPPERCASE" => variant.to_ascii_uppercase(),
+        "PascalCase" => variant.to_owned(),
+        "camelCase" => variant[..1].to_ascii_lowercase() + &variant[1..],
+        "snake_case" => snake_case(),
+        "SCREAMING_SNAKE_CASE" => snake_case().to_ascii_uppercase(),
+        "kebab-case" => snake_case().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake_case().to_ascii_uppercase().replace('_', "-"),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                rule,
+                "Unknown `rename_all` rule of serde",
+            ))
+        }
+    })
+}
+
+fn parse_serde_tag(input: &DeriveInput) -> syn::Result<Option<SerdeTag>> {
+    let Data::Enum(data) = &input.data else {
+        return Ok(None);
+    };
+    let pairs = parse_serde_attrs(&input.attrs)?;
+    let Some((_, tag)) = pairs.iter().find(|(key, _)| key == "tag") else {
+        return Ok(None);
+    };
+    let rename_all = pairs.iter().find(|(key, _)| key == "rename_all");
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let rename = parse_serde_attrs(&variant.attrs)?
+            .into_iter()
+            .find(|(key, _)| key == "rename");
+        let name = match (rename, rename_all) {
+            (Some((_, rename)), _) => rename.value(),
+            (None, Some((_, rule))) => rename_variant(&variant.ident.to_string(), rule)?,
+            (None, None) => variant.ident.to_string(),
+        };
+        variants.push((variant.ident.clone(), name));
+    }
+
+    Ok(Some(SerdeTag {
+        tag: tag.value(),
+        variants,
+    }))
+}
+
+pub fn expand_derive_from_json_query_result(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let serde_tag = parse_serde_tag(&input)?;
 
-pub fn expand_derive_from_json_query_result(ident: Ident) -> syn::Result<TokenStream> {
     let impl_not_u8 = if cfg!(feature = "postgres-array") {
         quote!(
             #[automatically_derived]
@@ -11,9 +105,45 @@ pub fn expand_derive_from_json_query_result(ident: Ident) -> syn::Result<TokenSt
         quote!()
     };
 
+    let impl_try_getable = match &serde_tag {
+        Some(SerdeTag { tag, variants }) => {
+            let names: Vec<_> = variants.iter().map(|(_, name)| name).collect();
+            let arms = variants
+                .iter()
+                .map(|(variant, name)| quote!(Self::#variant { .. } => #name));
+            quote!(
+                #[automatically_derived]
+                impl sea_orm::TryGetableFromJson for #ident {
+                    fn try_get_from_json<I: sea_orm::ColIdx>(
+                        res: &sea_orm::QueryResult,
+                        idx: I,
+                    ) -> Result<Self, sea_orm::TryGetError> {
+                        let json = <serde_json::Value as sea_orm::TryGetable>::try_get_by(res, idx)?;
+                        sea_orm::from_tagged_json(json).map_err(Into::into)
+                    }
+                }
+
+                #[automatically_derived]
+                impl sea_orm::JsonTagged for #ident {
+                    const TAG: &'static str = #tag;
+                    const TAGS: &'static [&'static str] = &[#(#names),*];
+
+                    fn tag(&self) -> &'static str {
+                        match self {
+                            #(#arms,)*
+                        }
+                    }
+                }
+            )
+        }
+        None => quote!(
+            #[automatically_derived]
+            impl sea_orm::TryGetableFromJson for #ident {}
+        ),
+    };
+
     Ok(quote!(
-        #[automatically_derived]
-        impl sea_orm::TryGetableFromJson for #ident {}
+        #impl_try_getable
 
         #[automatically_derived]
         impl std::convert::From<#ident> for sea_orm::Value {
@@ -1,6 +1,8 @@
 use heck::ToUpperCamelCase;
-use quote::format_ident;
-use syn::{punctuated::Punctuated, token::Comma, Field, Ident, Meta};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, punctuated::Punctuated, token::Comma, Expr, Field, Ident, LitStr, Meta, Path,
+};
 
 pub(crate) fn field_not_ignored(field: &Field) -> bool {
     for attr in field.attrs.iter() {
@@ -27,6 +29,91 @@ pub(crate) fn field_not_ignored(field: &Field) -> bool {
     true
 }
 
+/// The functions converting a field from and into its stored value,
+/// from `#[sea_orm(save_with = "f", load_with = "g")]` or `#[sea_orm(duration_repr = "millis")]`
+#[derive(Default)]
+pub(crate) struct FieldConverters {
+    pub save_with: Option<Path>,
+    pub load_with: Option<Path>,
+    /// The `sea_orm::UuidRepr` of a `Uuid` field stored as bytes, from `#[sea_orm(uuid_repr = "binary")]`
+    pub uuid_repr: Option<Path>,
+}
+
+/// The `sea_orm::UuidRepr` variant of a `uuid_repr` attribute
+pub(crate) fn parse_uuid_repr(lit: &LitStr) -> syn::Result<Path> {
+    match lit.value().as_str() {
+        "binary" => Ok(parse_quote! { sea_orm::UuidRepr::Binary }),
+        "binary_swapped" => Ok(parse_quote! { sea_orm::UuidRepr::BinarySwapped }),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "Invalid uuid_repr, expected \"binary\" or \"binary_swapped\"",
+        )),
+    }
+}
+
+/// The `sea_orm::IntegerDuration` functions saving and loading a field of a `duration_repr` attribute
+pub(crate) fn parse_duration_repr(lit: &LitStr) -> syn::Result<(Path, Path)> {
+    match lit.value().as_str() {
+        "millis" => Ok((
+            parse_quote! { sea_orm::IntegerDuration::into_millis },
+            parse_quote! { sea_orm::IntegerDuration::from_millis },
+        )),
+        "micros" => Ok((
+            parse_quote! { sea_orm::IntegerDuration::into_micros },
+            parse_quote! { sea_orm::IntegerDuration::from_micros },
+        )),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "Invalid duration_repr, expected \"millis\" or \"micros\"",
+        )),
+    }
+}
+
+impl FieldConverters {
+    pub(crate) fn from_field(field: &Field) -> syn::Result<Self> {
+        let mut converters = Self::default();
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("sea_orm") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("save_with") {
+                    converters.save_with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("load_with") {
+                    converters.load_with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("uuid_repr") {
+                    converters.uuid_repr = Some(parse_uuid_repr(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("duration_repr") {
+                    // a `Duration` stored as an integer count
+                    let (save_with, load_with) = parse_duration_repr(&meta.value()?.parse()?)?;
+                    converters.save_with = Some(save_with);
+                    converters.load_with = Some(load_with);
+                } else {
+                    // Reads the value expression to advance the parse stream.
+                    // Some parameters, such as `primary_key`, do not have any value,
+                    // so ignoring an error occurred here.
+                    let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                }
+
+                Ok(())
+            })?;
+        }
+        if converters.save_with.is_none() && converters.load_with.is_none() {
+            let field_type = &field.ty;
+            let field_type = quote! { #field_type }.to_string().replace(' ', "");
+            if matches!(
+                field_type.as_str(),
+                "i128" | "u128" | "Option<i128>" | "Option<u128>"
+            ) {
+                // an integer wider than a `bigint` is stored in a `NUMERIC`
+                converters.save_with = Some(parse_quote! { sea_orm::NumericInteger::into_numeric });
+                converters.load_with = Some(parse_quote! { sea_orm::NumericInteger::from_numeric });
+            }
+        }
+        Ok(converters)
+    }
+}
+
 pub(crate) fn format_field_ident(field: Field) -> Ident {
     format_ident!("{}", field.ident.unwrap().to_string())
 }
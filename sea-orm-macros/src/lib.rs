@@ -113,6 +113,126 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
 ///
+/// A `String` field can be stored in a Postgres `citext` column with `#[sea_orm(citext)]`.
+/// The values compared with the column are cast into `citext` so that `eq` and `is_in` are case-insensitive;
+/// comparing with `text` would be case-sensitive. The column is created as `text` in MySQL,
+/// and as `text collate nocase` in SQLite.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "tag")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(citext, unique)]
+///     pub name: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// A field of any type can be stored through a pair of functions with
+/// `#[sea_orm(save_with = "f", load_with = "g")]`. `f` converts the field into a value of a supported type,
+/// which also gives the column type, and `g` converts it back, returning an error on invalid values.
+/// Values compared with the column in filters are of the stored type.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "subscriber")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(save_with = "Plan::into_code", load_with = "Plan::from_code")]
+///     pub plan: Plan,
+/// }
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// pub enum Plan {
+///     Free,
+///     Pro,
+/// }
+///
+/// impl Plan {
+///     fn into_code(self) -> i16 {
+///         self as i16
+///     }
+///
+///     fn from_code(code: i16) -> Result<Self, String> {
+///         match code {
+///             0 => Ok(Self::Free),
+///             1 => Ok(Self::Pro),
+///             _ => Err(format!("Unknown plan code {code}")),
+///         }
+///     }
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// A `i128` or `u128` field is stored in a `NUMERIC(39, 0)` column, converted with `sea_orm::NumericInteger`
+/// of the `with-bigdecimal` feature.
+///
+/// A column of a Postgres domain is declared with `#[sea_orm(domain = "name")]`, over the column type
+/// of the field as its base type. The domain has to be created before the table, as SeaORM doesn't
+/// know its constraints; values are bound and read as of the base type.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "mailbox")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(column_type = "Text", domain = "email_address")]
+///     pub address: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// The collation of a text column is set with `#[sea_orm(collation = "name")]`, emitted as the `COLLATE`
+/// of the column by `Schema::create_table_from_entity`.
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "word")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(collation = "utf8mb4_unicode_ci")]
+///     pub spelling: String,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// A `Uuid` field is stored as the bytes of a `BINARY(16)` column with `#[sea_orm(uuid_repr = "binary")]`,
+/// or with its time fields swapped like MySQL's `UUID_TO_BIN(uuid, 1)` with `uuid_repr = "binary_swapped"`,
+/// see `sea_orm::UuidRepr`.
+///
+/// A `std::time::Duration` field is stored in a `BIGINT` column as a count of milliseconds with
+/// `#[sea_orm(duration_repr = "millis")]`, or of microseconds with `duration_repr = "micros"`,
+/// see `sea_orm::IntegerDuration`.
+///
 /// Entity should always have a primary key.
 /// Or, it will result in a compile error.
 /// See <https://github.com/SeaQL/sea-orm/issues/485> for details.
@@ -713,11 +833,11 @@ pub fn derive_migration_name(input: TokenStream) -> TokenStream {
 }
 
 #[cfg(feature = "derive")]
-#[proc_macro_derive(FromJsonQueryResult)]
+#[proc_macro_derive(FromJsonQueryResult, attributes(serde))]
 pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
-    let DeriveInput { ident, .. } = parse_macro_input!(input);
+    let input = parse_macro_input!(input as DeriveInput);
 
-    match derives::expand_derive_from_json_query_result(ident) {
+    match derives::expand_derive_from_json_query_result(input) {
         Ok(ts) => ts.into(),
         Err(e) => e.to_compile_error().into(),
     }
@@ -866,6 +986,40 @@ pub fn derive_value_type(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Implements traits for a struct mapped to a PostgreSQL composite type.
+///
+/// This procedure macro implements `sea_orm::CompositeType`, `From<T> for Value`,
+/// `sea_orm::TryGetable` and `sea_query::ValueType` for the struct `T`, so that it can be used
+/// as the type of a column and bound as a parameter. It is converted to and from the text of a
+/// row value, and its fields must be in the order of the attributes of the composite type.
+///
+/// The name of the composite type is the struct name in snake case, unless it is set with
+/// `#[sea_orm(type_name = "..")]`.
+///
+/// ## Usage
+///
+/// ```rust
+/// use sea_orm::DeriveCompositeType;
+///
+/// // CREATE TYPE address AS (street text, city text, zip integer);
+/// #[derive(DeriveCompositeType)]
+/// #[sea_orm(type_name = "address")]
+/// struct Address {
+///     street: String,
+///     city: String,
+///     zip: Option<i32>,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+#[proc_macro_derive(DeriveCompositeType, attributes(sea_orm))]
+pub fn derive_composite_type(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    match derives::expand_derive_composite_type(derive_input) {
+        Ok(token_stream) => token_stream.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveDisplay, attributes(sea_orm))]
 pub fn derive_active_enum_display(input: TokenStream) -> TokenStream {
@@ -6,13 +6,22 @@ use tracing_subscriber::{prelude::*, EnvFilter};
 use sea_orm::{ConnectOptions, Database, DbConn};
 use sea_orm_cli::{run_migrate_generate, run_migrate_init, MigrateSubcommands};
 
-use super::MigratorTrait;
+use super::{MigratorTrait, SeederTrait};
 
 const MIGRATION_DIR: &str = "./";
 
 pub async fn run_cli<M>(migrator: M)
 where
     M: MigratorTrait,
+{
+    run_cli_with_seeder(migrator, ()).await
+}
+
+/// Run the migrator CLI, of which the `seed` subcommand runs the seeds of `seeder`
+pub async fn run_cli_with_seeder<M, S>(migrator: M, seeder: S)
+where
+    M: MigratorTrait,
+    S: SeederTrait,
 {
     dotenv().ok();
     let cli = Cli::parse();
@@ -29,19 +38,33 @@ where
         .await
         .expect("Fail to acquire database connection");
 
-    run_migrate(migrator, db, cli.command, cli.verbose)
+    run_migrate_with_seeder(migrator, seeder, db, cli.command, cli.verbose)
         .await
         .unwrap_or_else(handle_error);
 }
 
 pub async fn run_migrate<M>(
+    migrator: M,
+    db: &DbConn,
+    command: Option<MigrateSubcommands>,
+    verbose: bool,
+) -> Result<(), Box<dyn Error>>
+where
+    M: MigratorTrait,
+{
+    run_migrate_with_seeder(migrator, (), db, command, verbose).await
+}
+
+pub async fn run_migrate_with_seeder<M, S>(
     _: M,
+    _: S,
     db: &DbConn,
     command: Option<MigrateSubcommands>,
     verbose: bool,
 ) -> Result<(), Box<dyn Error>>
 where
     M: MigratorTrait,
+    S: SeederTrait,
 {
     let filter = match verbose {
         true => "debug",
@@ -72,14 +95,24 @@ where
         Some(MigrateSubcommands::Refresh) => M::refresh(db).await?,
         Some(MigrateSubcommands::Reset) => M::reset(db).await?,
         Some(MigrateSubcommands::Status) => M::status(db).await?,
-        Some(MigrateSubcommands::Up { num }) => M::up(db, num).await?,
+        Some(MigrateSubcommands::Up { num, dry_run: true }) => {
+            print!("{}", M::up_to_sql(db, num).await?)
+        }
+        Some(MigrateSubcommands::Up { num, .. }) => M::up(db, num).await?,
         Some(MigrateSubcommands::Down { num }) => M::down(db, Some(num)).await?,
+        Some(MigrateSubcommands::Seed { env }) => S::seed(db, env.as_deref()).await?,
         Some(MigrateSubcommands::Init) => run_migrate_init(MIGRATION_DIR)?,
         Some(MigrateSubcommands::Generate {
             migration_name,
             universal_time: _,
             local_time,
-        }) => run_migrate_generate(MIGRATION_DIR, &migration_name, !local_time)?,
+            entities,
+        }) => run_migrate_generate(
+            MIGRATION_DIR,
+            &migration_name,
+            !local_time,
+            entities.as_deref(),
+        )?,
         _ => M::up(db, None).await?,
     };
 
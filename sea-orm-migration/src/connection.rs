@@ -4,10 +4,59 @@ use sea_orm::{
     ExecResult, IsolationLevel, QueryResult, Statement, TransactionError, TransactionTrait,
 };
 use std::pin::Pin;
+use std::sync::Mutex;
 
 pub enum SchemaManagerConnection<'c> {
     Connection(&'c DatabaseConnection),
     Transaction(&'c DatabaseTransaction),
+    DryRun(&'c DryRunConnection<'c>),
+}
+
+/// A connection recording the statements executed instead of executing them, see
+/// [MigratorTrait::up_to_sql](crate::MigratorTrait::up_to_sql)
+///
+/// `SELECT` queries are run on the database; other queries, e.g. an `INSERT` with a `RETURNING`
+/// clause, are recorded and return no row. Transactions can't be started.
+pub struct DryRunConnection<'c> {
+    conn: SchemaManagerConnection<'c>,
+    stmts: Mutex<Vec<Statement>>,
+}
+
+impl<'c> DryRunConnection<'c> {
+    pub fn new<T>(conn: T) -> Self
+    where
+        T: IntoSchemaManagerConnection<'c>,
+    {
+        Self {
+            conn: conn.into_schema_manager_connection(),
+            stmts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The statements recorded, in the order they were executed
+    pub fn into_statements(self) -> Vec<Statement> {
+        self.stmts.into_inner().expect("Fail to acquire mutex")
+    }
+
+    fn record(&self, stmt: Statement) -> ExecResult {
+        self.stmts.lock().expect("Fail to acquire mutex").push(stmt);
+        ExecResult::dry_run()
+    }
+
+    /// Run a read-only query on the database, or record another query
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        let sql = stmt.sql.trim_start().to_uppercase();
+        if sql.starts_with("SELECT") || sql.starts_with("WITH") {
+            self.conn.query_all(stmt).await
+        } else {
+            self.record(stmt);
+            Ok(Vec::new())
+        }
+    }
+
+    fn transaction_error() -> DbErr {
+        DbErr::Migration("Transactions are not supported in a dry run".to_owned())
+    }
 }
 
 #[async_trait::async_trait]
@@ -16,6 +65,7 @@ impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.get_database_backend(),
             SchemaManagerConnection::Transaction(trans) => trans.get_database_backend(),
+            SchemaManagerConnection::DryRun(dry_run) => dry_run.conn.get_database_backend(),
         }
     }
 
@@ -23,6 +73,7 @@ impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.execute(stmt).await,
             SchemaManagerConnection::Transaction(trans) => trans.execute(stmt).await,
+            SchemaManagerConnection::DryRun(dry_run) => Ok(dry_run.record(stmt)),
         }
     }
 
@@ -30,6 +81,10 @@ impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.execute_unprepared(sql).await,
             SchemaManagerConnection::Transaction(trans) => trans.execute_unprepared(sql).await,
+            SchemaManagerConnection::DryRun(dry_run) => Ok(dry_run.record(Statement::from_string(
+                dry_run.conn.get_database_backend(),
+                sql,
+            ))),
         }
     }
 
@@ -37,6 +92,9 @@ impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.query_one(stmt).await,
             SchemaManagerConnection::Transaction(trans) => trans.query_one(stmt).await,
+            SchemaManagerConnection::DryRun(dry_run) => {
+                Ok(dry_run.query_all(stmt).await?.into_iter().next())
+            }
         }
     }
 
@@ -44,6 +102,7 @@ impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.query_all(stmt).await,
             SchemaManagerConnection::Transaction(trans) => trans.query_all(stmt).await,
+            SchemaManagerConnection::DryRun(dry_run) => dry_run.query_all(stmt).await,
         }
     }
 
@@ -51,6 +110,7 @@ impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.is_mock_connection(),
             SchemaManagerConnection::Transaction(trans) => trans.is_mock_connection(),
+            SchemaManagerConnection::DryRun(dry_run) => dry_run.conn.is_mock_connection(),
         }
     }
 }
@@ -61,6 +121,7 @@ impl<'c> TransactionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.begin().await,
             SchemaManagerConnection::Transaction(trans) => trans.begin().await,
+            SchemaManagerConnection::DryRun(_) => Err(DryRunConnection::transaction_error()),
         }
     }
 
@@ -76,6 +137,7 @@ impl<'c> TransactionTrait for SchemaManagerConnection<'c> {
             SchemaManagerConnection::Transaction(trans) => {
                 trans.begin_with_config(isolation_level, access_mode).await
             }
+            SchemaManagerConnection::DryRun(_) => Err(DryRunConnection::transaction_error()),
         }
     }
 
@@ -91,6 +153,9 @@ impl<'c> TransactionTrait for SchemaManagerConnection<'c> {
         match self {
             SchemaManagerConnection::Connection(conn) => conn.transaction(callback).await,
             SchemaManagerConnection::Transaction(trans) => trans.transaction(callback).await,
+            SchemaManagerConnection::DryRun(_) => Err(TransactionError::Connection(
+                DryRunConnection::transaction_error(),
+            )),
         }
     }
 
@@ -118,6 +183,9 @@ impl<'c> TransactionTrait for SchemaManagerConnection<'c> {
                     .transaction_with_config(callback, isolation_level, access_mode)
                     .await
             }
+            SchemaManagerConnection::DryRun(_) => Err(TransactionError::Connection(
+                DryRunConnection::transaction_error(),
+            )),
         }
     }
 }
@@ -135,6 +203,18 @@ impl<'c> IntoSchemaManagerConnection<'c> for SchemaManagerConnection<'c> {
     }
 }
 
+impl<'a, 'c: 'a> IntoSchemaManagerConnection<'a> for &'a SchemaManagerConnection<'c> {
+    fn into_schema_manager_connection(self) -> SchemaManagerConnection<'a> {
+        match self {
+            SchemaManagerConnection::Connection(conn) => SchemaManagerConnection::Connection(conn),
+            SchemaManagerConnection::Transaction(trans) => {
+                SchemaManagerConnection::Transaction(trans)
+            }
+            SchemaManagerConnection::DryRun(dry_run) => SchemaManagerConnection::DryRun(dry_run),
+        }
+    }
+}
+
 impl<'c> IntoSchemaManagerConnection<'c> for &'c DatabaseConnection {
     fn into_schema_manager_connection(self) -> SchemaManagerConnection<'c> {
         SchemaManagerConnection::Connection(self)
@@ -146,3 +226,9 @@ impl<'c> IntoSchemaManagerConnection<'c> for &'c DatabaseTransaction {
         SchemaManagerConnection::Transaction(self)
     }
 }
+
+impl<'c> IntoSchemaManagerConnection<'c> for &'c DryRunConnection<'c> {
+    fn into_schema_manager_connection(self) -> SchemaManagerConnection<'c> {
+        SchemaManagerConnection::DryRun(self)
+    }
+}
@@ -0,0 +1,428 @@
+//! Deriving the down migration of the schema changes of [MigrationTrait::up](crate::MigrationTrait::up)
+//!
+//! The deriving [SchemaManager] records the statements its methods are called with and keeps the
+//! statements reversing them. Tables, triggers, trigger functions, partitions and table histories
+//! are reversed from what their builders expose. The builders of indexes, foreign keys, types and
+//! table alterations don't expose the names of what they create, so these are read back from the
+//! statement as built for Postgres.
+
+use crate::{DryRunConnection, MigrationTrait, SchemaManager};
+use sea_orm::sea_query::{
+    extension::postgres::{Type, TypeCreateStatement, TypeDropStatement, TypeRef},
+    Alias, DynIden, ForeignKey, ForeignKeyCreateStatement, ForeignKeyDropStatement, Index,
+    IndexCreateStatement, IndexDropStatement, IntoIden, PostgresQueryBuilder, Table,
+    TableAlterStatement, TableCreateStatement, TableDropStatement, TableRef, TableRenameStatement,
+};
+use sea_orm::{ConnectionTrait, DbErr};
+
+/// Roll back the migration with the statements reversing the schema changes of its `up`
+///
+/// `up` is run without executing its schema changes, while its queries are run on the database.
+/// It fails if `up` executes a statement which can't be reversed, or no statement at all.
+pub(crate) async fn exec<M>(migration: &M, manager: &SchemaManager<'_>) -> Result<(), DbErr>
+where
+    M: MigrationTrait + ?Sized,
+{
+    let db = manager.get_connection();
+    let dry_run = DryRunConnection::new(db);
+    let deriving = SchemaManager::deriving_down(&dry_run);
+    let up = migration.up(&deriving).await;
+    let stmts = deriving.into_down_statements();
+
+    if let Some(stmt) = dry_run.into_statements().first() {
+        return Err(DbErr::Migration(format!(
+            "The down migration of `{}` can't be derived from `{stmt}`, implement `MigrationTrait::down`",
+            migration.name()
+        )));
+    }
+    up?;
+    if stmts.is_empty() {
+        return Err(DbErr::Migration(format!(
+            "The down migration of `{}` can't be derived, as its `up` makes no schema change which can be reversed, implement `MigrationTrait::down`",
+            migration.name()
+        )));
+    }
+
+    for stmt in stmts.into_iter().rev() {
+        db.execute(stmt).await?;
+    }
+    Ok(())
+}
+
+/// Drop the table created
+pub(crate) fn create_table(stmt: &TableCreateStatement) -> Option<TableDropStatement> {
+    let table = stmt.get_table_name()?.clone();
+    Some(Table::drop().table(table).to_owned())
+}
+
+/// Drop the index created, from `CREATE [UNIQUE] INDEX [IF NOT EXISTS] "name" ON "table"`
+pub(crate) fn create_index(stmt: &IndexCreateStatement) -> Option<IndexDropStatement> {
+    let sql = stmt.to_string(PostgresQueryBuilder);
+    let mut tokens = Tokens::new(&sql);
+    tokens.skip_past("INDEX")?;
+    tokens.if_not_exists();
+    let name = tokens.iden()?;
+    tokens.word("ON").then_some(())?;
+    let table = tokens.table_ref()?;
+    Some(Index::drop().name(name.to_string()).table(table).to_owned())
+}
+
+/// Drop the foreign key created, from `ALTER TABLE "table" ADD CONSTRAINT "name" FOREIGN KEY`
+pub(crate) fn create_foreign_key(
+    stmt: &ForeignKeyCreateStatement,
+) -> Option<ForeignKeyDropStatement> {
+    let sql = stmt.to_string(PostgresQueryBuilder);
+    let mut tokens = Tokens::new(&sql);
+    tokens.words(&["ALTER", "TABLE"]).then_some(())?;
+    let table = tokens.table_ref()?;
+    tokens.words(&["ADD", "CONSTRAINT"]).then_some(())?;
+    let name = tokens.iden()?;
+    Some(
+        ForeignKey::drop()
+            .name(name.to_string())
+            .table(table)
+            .to_owned(),
+    )
+}
+
+/// Drop the type created, from `CREATE TYPE "name"`
+pub(crate) fn create_type(stmt: &TypeCreateStatement) -> Option<TypeDropStatement> {
+    let sql = stmt.to_string(PostgresQueryBuilder);
+    let mut tokens = Tokens::new(&sql);
+    tokens.words(&["CREATE", "TYPE"]).then_some(())?;
+    let name = match tokens.idens().as_slice() {
+        [name] => TypeRef::Type(name.clone()),
+        [schema, name] => TypeRef::SchemaType(schema.clone(), name.clone()),
+        _ => return None,
+    };
+    Some(Type::drop().name(name).to_owned())
+}
+
+/// Rename the table back, from `ALTER TABLE "from" RENAME TO "to"`
+pub(crate) fn rename_table(stmt: &TableRenameStatement) -> Option<TableRenameStatement> {
+    let sql = stmt.to_string(PostgresQueryBuilder);
+    let mut tokens = Tokens::new(&sql);
+    tokens.words(&["ALTER", "TABLE"]).then_some(())?;
+    let from = tokens.table_ref()?;
+    tokens.words(&["RENAME", "TO"]).then_some(())?;
+    let to = tokens.table_ref()?;
+    Some(Table::rename().table(to, from).to_owned())
+}
+
+/// Reverse the options of the table altered, in the reverse order: a column added is dropped,
+/// a column renamed is renamed back and a foreign key added is dropped
+pub(crate) fn alter_table(stmt: &TableAlterStatement) -> Option<TableAlterStatement> {
+    let sql = stmt.to_string(PostgresQueryBuilder);
+    let mut tokens = Tokens::new(&sql);
+    tokens.words(&["ALTER", "TABLE"]).then_some(())?;
+    let mut alter = Table::alter().table(tokens.table_ref()?).to_owned();
+
+    let mut options = Vec::new();
+    loop {
+        if tokens.words(&["ADD", "COLUMN"]) {
+            tokens.if_not_exists();
+            let column = tokens.iden()?;
+            options.push(Reversal::DropColumn(column));
+        } else if tokens.words(&["RENAME", "COLUMN"]) {
+            let from = tokens.iden()?;
+            tokens.word("TO").then_some(())?;
+            let to = tokens.iden()?;
+            options.push(Reversal::RenameColumn(to, from));
+        } else if tokens.words(&["ADD", "CONSTRAINT"]) {
+            let name = tokens.iden()?;
+            tokens.words(&["FOREIGN", "KEY"]).then_some(())?;
+            options.push(Reversal::DropForeignKey(name));
+        } else {
+            return None;
+        }
+        if !tokens.skip_past_comma() {
+            break;
+        }
+    }
+    if options.is_empty() {
+        return None;
+    }
+
+    for option in options.into_iter().rev() {
+        match option {
+            Reversal::DropColumn(column) => alter.drop_column(column),
+            Reversal::RenameColumn(from, to) => alter.rename_column(from, to),
+            Reversal::DropForeignKey(name) => alter.drop_foreign_key(name),
+        };
+    }
+    Some(alter)
+}
+
+enum Reversal {
+    DropColumn(DynIden),
+    RenameColumn(DynIden, DynIden),
+    DropForeignKey(DynIden),
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Iden(String),
+    Dot,
+    Comma,
+    /// A string literal, a group in parentheses or any other character
+    Other,
+}
+
+/// The tokens of a statement, where the groups in parentheses are single tokens
+struct Tokens {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Tokens {
+    fn new(sql: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = sql.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                c if c.is_whitespace() => {}
+                '"' => {
+                    let mut iden = String::new();
+                    while let Some(c) = chars.next() {
+                        if c == '"' {
+                            if chars.peek() != Some(&'"') {
+                                break;
+                            }
+                            chars.next();
+                        }
+                        iden.push(c);
+                    }
+                    tokens.push(Token::Iden(iden));
+                }
+                '\'' => {
+                    while let Some(c) = chars.next() {
+                        if c == '\'' {
+                            if chars.peek() != Some(&'\'') {
+                                break;
+                            }
+                            chars.next();
+                        }
+                    }
+                    tokens.push(Token::Other);
+                }
+                '(' => {
+                    let mut depth = 1;
+                    while depth > 0 {
+                        match chars.next() {
+                            Some('(') => depth += 1,
+                            Some(')') => depth -= 1,
+                            // Quotes inside the group are skipped with their content
+                            Some(q @ ('\'' | '"')) => {
+                                for c in chars.by_ref() {
+                                    if c == q {
+                                        break;
+                                    }
+                                }
+                            }
+                            Some(_) => {}
+                            None => break,
+                        }
+                    }
+                    tokens.push(Token::Other);
+                }
+                '.' => tokens.push(Token::Dot),
+                ',' => tokens.push(Token::Comma),
+                c if c.is_ascii_alphanumeric() || c == '_' => {
+                    let mut word = String::from(c);
+                    while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                        word.push(c);
+                    }
+                    tokens.push(Token::Word(word.to_uppercase()));
+                }
+                _ => tokens.push(Token::Other),
+            }
+        }
+        Self { tokens, pos: 0 }
+    }
+
+    /// Consume the next token if it's the keyword
+    fn word(&mut self, word: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(w)) if w == word => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume the next tokens if they're the keywords
+    fn words(&mut self, words: &[&str]) -> bool {
+        let pos = self.pos;
+        if words.iter().all(|word| self.word(word)) {
+            return true;
+        }
+        self.pos = pos;
+        false
+    }
+
+    fn if_not_exists(&mut self) {
+        self.words(&["IF", "NOT", "EXISTS"]);
+    }
+
+    fn iden(&mut self) -> Option<DynIden> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Iden(iden)) => {
+                self.pos += 1;
+                Some(Alias::new(iden).into_iden())
+            }
+            _ => None,
+        }
+    }
+
+    /// Identifiers separated by dots, e.g. `"schema"."table"`
+    fn idens(&mut self) -> Vec<DynIden> {
+        let mut idens = Vec::new();
+        while let Some(iden) = self.iden() {
+            idens.push(iden);
+            if self.tokens.get(self.pos) != Some(&Token::Dot) {
+                break;
+            }
+            self.pos += 1;
+        }
+        idens
+    }
+
+    fn table_ref(&mut self) -> Option<TableRef> {
+        match self.idens().as_slice() {
+            [table] => Some(TableRef::Table(table.clone())),
+            [schema, table] => Some(TableRef::SchemaTable(schema.clone(), table.clone())),
+            [database, schema, table] => Some(TableRef::DatabaseSchemaTable(
+                database.clone(),
+                schema.clone(),
+                table.clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Skip the tokens up to and including the keyword
+    fn skip_past(&mut self, word: &str) -> Option<()> {
+        while self.pos < self.tokens.len() {
+            if self.word(word) {
+                return Some(());
+            }
+            self.pos += 1;
+        }
+        None
+    }
+
+    /// Skip the tokens up to and including the next comma, returning whether there was one
+    fn skip_past_comma(&mut self) -> bool {
+        while let Some(token) = self.tokens.get(self.pos) {
+            self.pos += 1;
+            if token == &Token::Comma {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::sea_query::{ColumnDef, TableForeignKey};
+
+    #[test]
+    fn test_reverse_create_table() {
+        let create = Table::create()
+            .table((Alias::new("public"), Alias::new("cake")))
+            .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+            .to_owned();
+        assert_eq!(
+            create_table(&create)
+                .unwrap()
+                .to_string(PostgresQueryBuilder),
+            r#"DROP TABLE "public"."cake""#
+        );
+        assert!(create_table(&Table::create()).is_none());
+    }
+
+    #[test]
+    fn test_reverse_create() {
+        assert_eq!(
+            create_index(
+                Index::create()
+                    .if_not_exists()
+                    .unique()
+                    .name("idx-cake-name")
+                    .table((Alias::new("public"), Alias::new("cake")))
+                    .col(Alias::new("name"))
+            )
+            .unwrap()
+            .to_string(PostgresQueryBuilder),
+            r#"DROP INDEX "public"."idx-cake-name""#
+        );
+        assert_eq!(
+            create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-fruit-cake_id")
+                    .from(Alias::new("fruit"), Alias::new("cake_id"))
+                    .to(Alias::new("cake"), Alias::new("id"))
+            )
+            .unwrap()
+            .to_string(PostgresQueryBuilder),
+            r#"ALTER TABLE "fruit" DROP CONSTRAINT "fk-fruit-cake_id""#
+        );
+        assert_eq!(
+            create_type(
+                Type::create()
+                    .as_enum(Alias::new("tea"))
+                    .values([Alias::new("EverydayTea")])
+            )
+            .unwrap()
+            .to_string(PostgresQueryBuilder),
+            r#"DROP TYPE "tea""#
+        );
+        assert_eq!(
+            rename_table(Table::rename().table(Alias::new("cake"), Alias::new("pie")))
+                .unwrap()
+                .to_string(PostgresQueryBuilder),
+            r#"ALTER TABLE "pie" RENAME TO "cake""#
+        );
+    }
+
+    #[test]
+    fn test_reverse_alter_table() {
+        let alter = Table::alter()
+            .table(Alias::new("fruit"))
+            .add_column(
+                ColumnDef::new(Alias::new("price"))
+                    .decimal_len(10, 2)
+                    .default("1,00")
+                    .not_null(),
+            )
+            .rename_column(Alias::new("name"), Alias::new("title"))
+            .add_foreign_key(
+                TableForeignKey::new()
+                    .name("fk-fruit-cake_id")
+                    .from_tbl(Alias::new("fruit"))
+                    .from_col(Alias::new("cake_id"))
+                    .to_tbl(Alias::new("cake"))
+                    .to_col(Alias::new("id")),
+            )
+            .to_owned();
+        assert_eq!(
+            alter_table(&alter).unwrap().to_string(PostgresQueryBuilder),
+            [
+                r#"ALTER TABLE "fruit" DROP CONSTRAINT "fk-fruit-cake_id","#,
+                r#"RENAME COLUMN "title" TO "name","#,
+                r#"DROP COLUMN "price""#,
+            ]
+            .join(" ")
+        );
+
+        let alter = Table::alter()
+            .table(Alias::new("fruit"))
+            .drop_column(Alias::new("price"))
+            .to_owned();
+        assert!(alter_table(&alter).is_none());
+    }
+}
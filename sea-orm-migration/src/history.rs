@@ -0,0 +1,271 @@
+//! # Table History
+//!
+//! Keeping the previous versions of the rows of a Postgres table in a shadow history table,
+//! so that the table can be queried as it was at a time with `Select::as_of`, the portable
+//! counterpart of the system-versioned tables of MariaDB.
+//!
+//! Creating the history of a table `post`:
+//!
+//! - adds a `valid_from` column to `post`, of the time each row was last changed;
+//! - creates the `post_history` table, of the columns of `post` and a `valid_to` column;
+//! - creates the `post_keep_history` trigger, and its trigger function, which copy a row
+//!   updated or deleted into `post_history` with the time it's valid to.
+//!
+//! The history table isn't altered with the table, so a column later added to the table
+//! has to be added to the history table too.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sea_orm_migration::{history::*, prelude::*};
+//!
+//! #[derive(DeriveMigrationName)]
+//! pub struct Migration;
+//!
+//! #[async_trait::async_trait]
+//! impl MigrationTrait for Migration {
+//!     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+//!         manager
+//!             .create_history(History::create().table(Post::Table).to_owned())
+//!             .await
+//!     }
+//!
+//!     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+//!         manager
+//!             .drop_history(History::drop().table(Post::Table).to_owned())
+//!             .await
+//!     }
+//! }
+//!
+//! #[derive(DeriveIden)]
+//! pub enum Post {
+//!     Table,
+//! }
+//! ```
+
+use sea_orm::sea_query::{
+    Alias, ColumnDef, Expr, Iden, IntoIden, IntoTableRef, PostgresQueryBuilder, QueryBuilder,
+    QuotedBuilder, Table, TableRef,
+};
+use sea_orm::{DbBackend, Statement};
+
+const VALID_FROM: &str = "valid_from";
+const VALID_TO: &str = "valid_to";
+
+/// Building the statements of table histories
+#[derive(Debug, Clone)]
+pub struct History;
+
+/// Create the history of a table
+#[derive(Debug, Clone, Default)]
+pub struct HistoryCreateStatement {
+    table: Option<TableRef>,
+}
+
+/// Drop the history of a table, with its `valid_from` column
+#[derive(Debug, Clone, Default)]
+pub struct HistoryDropStatement {
+    table: Option<TableRef>,
+}
+
+impl History {
+    /// Construct a [HistoryCreateStatement]
+    pub fn create() -> HistoryCreateStatement {
+        HistoryCreateStatement::default()
+    }
+
+    /// Construct a [HistoryDropStatement]
+    pub fn drop() -> HistoryDropStatement {
+        HistoryDropStatement::default()
+    }
+}
+
+impl HistoryCreateStatement {
+    /// Set the table of which the history is kept
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+
+    /// The statements dropping the history, in a derived down migration
+    pub(crate) fn reverse(&self) -> HistoryDropStatement {
+        HistoryDropStatement {
+            table: self.table.clone(),
+        }
+    }
+
+    /// The statements creating the history, in the order they're executed
+    pub fn statements(&self) -> Vec<Statement> {
+        let Some(table) = &self.table else {
+            return Vec::new();
+        };
+        let query_builder = PostgresQueryBuilder;
+        let quote = query_builder.quote();
+        let table_sql = table_ref_sql(table);
+        let history_sql = table_ref_sql(&suffixed(table, "history"));
+        let function_sql = table_ref_sql(&suffixed(table, "keep_history"));
+        let mut trigger_sql = String::new();
+        Alias::new(format!("{}_keep_history", table_name(table))).prepare(&mut trigger_sql, quote);
+        let mut valid_from_sql = String::new();
+        Alias::new(VALID_FROM).prepare(&mut valid_from_sql, quote);
+        let mut valid_to_sql = String::new();
+        Alias::new(VALID_TO).prepare(&mut valid_to_sql, quote);
+
+        let add_valid_from = Table::alter()
+            .table(table.clone())
+            .add_column(
+                ColumnDef::new(Alias::new(VALID_FROM))
+                    .timestamp_with_time_zone()
+                    .not_null()
+                    .default(Expr::current_timestamp()),
+            )
+            .to_string(query_builder);
+        let create_history = format!(
+            "CREATE TABLE {history_sql} (LIKE {table_sql}, {valid_to_sql} timestamp with time zone NOT NULL)"
+        );
+        let create_function = format!(
+            "CREATE OR REPLACE FUNCTION {function_sql}() RETURNS TRIGGER AS $$ BEGIN \
+            INSERT INTO {history_sql} SELECT OLD.*, CURRENT_TIMESTAMP; \
+            IF TG_OP = 'UPDATE' THEN NEW.{valid_from_sql} := CURRENT_TIMESTAMP; RETURN NEW; END IF; \
+            RETURN OLD; END; $$ LANGUAGE plpgsql"
+        );
+        let create_trigger = format!(
+            "CREATE TRIGGER {trigger_sql} BEFORE UPDATE OR DELETE ON {table_sql} \
+            FOR EACH ROW EXECUTE FUNCTION {function_sql}()"
+        );
+
+        [
+            add_valid_from,
+            create_history,
+            create_function,
+            create_trigger,
+        ]
+        .into_iter()
+        .map(|sql| Statement::from_string(DbBackend::Postgres, sql))
+        .collect()
+    }
+}
+
+impl HistoryDropStatement {
+    /// Set the table of which the history is dropped
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+
+    /// The statements dropping the history, in the order they're executed
+    pub fn statements(&self) -> Vec<Statement> {
+        let Some(table) = &self.table else {
+            return Vec::new();
+        };
+        let table_sql = table_ref_sql(table);
+        let mut trigger_sql = String::new();
+        Alias::new(format!("{}_keep_history", table_name(table)))
+            .prepare(&mut trigger_sql, PostgresQueryBuilder.quote());
+
+        let drop_trigger = format!("DROP TRIGGER {trigger_sql} ON {table_sql}");
+        let drop_function = format!(
+            "DROP FUNCTION {}()",
+            table_ref_sql(&suffixed(table, "keep_history"))
+        );
+        let drop_history = Table::drop()
+            .table(suffixed(table, "history"))
+            .to_string(PostgresQueryBuilder);
+        let drop_valid_from = Table::alter()
+            .table(table.clone())
+            .drop_column(Alias::new(VALID_FROM))
+            .to_string(PostgresQueryBuilder);
+
+        [drop_trigger, drop_function, drop_history, drop_valid_from]
+            .into_iter()
+            .map(|sql| Statement::from_string(DbBackend::Postgres, sql))
+            .collect()
+    }
+}
+
+fn table_name(table: &TableRef) -> String {
+    match table {
+        TableRef::Table(table)
+        | TableRef::SchemaTable(_, table)
+        | TableRef::DatabaseSchemaTable(_, _, table) => table.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// The table of the same schema, named after the table with the suffix
+fn suffixed(table: &TableRef, suffix: &str) -> TableRef {
+    let name = Alias::new(format!("{}_{suffix}", table_name(table))).into_iden();
+    match table {
+        TableRef::SchemaTable(schema, _) | TableRef::DatabaseSchemaTable(_, schema, _) => {
+            TableRef::SchemaTable(schema.clone(), name)
+        }
+        _ => TableRef::Table(name),
+    }
+}
+
+fn table_ref_sql(table: &TableRef) -> String {
+    let mut sql = String::new();
+    PostgresQueryBuilder.prepare_table_ref(table, &mut sql);
+    sql
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sql(statements: Vec<Statement>) -> Vec<String> {
+        statements
+            .into_iter()
+            .map(|stmt| stmt.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_create_history() {
+        assert_eq!(
+            sql(History::create()
+                .table((Alias::new("blog"), Alias::new("post")))
+                .statements()),
+            [
+                r#"ALTER TABLE "blog"."post" ADD COLUMN "valid_from" timestamp with time zone NOT NULL DEFAULT CURRENT_TIMESTAMP"#,
+                r#"CREATE TABLE "blog"."post_history" (LIKE "blog"."post", "valid_to" timestamp with time zone NOT NULL)"#,
+                [
+                    r#"CREATE OR REPLACE FUNCTION "blog"."post_keep_history"() RETURNS TRIGGER AS $$ BEGIN"#,
+                    r#"INSERT INTO "blog"."post_history" SELECT OLD.*, CURRENT_TIMESTAMP;"#,
+                    r#"IF TG_OP = 'UPDATE' THEN NEW."valid_from" := CURRENT_TIMESTAMP; RETURN NEW; END IF;"#,
+                    r#"RETURN OLD; END; $$ LANGUAGE plpgsql"#,
+                ]
+                .join(" ")
+                .as_str(),
+                r#"CREATE TRIGGER "post_keep_history" BEFORE UPDATE OR DELETE ON "blog"."post" FOR EACH ROW EXECUTE FUNCTION "blog"."post_keep_history"()"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drop_history() {
+        assert_eq!(
+            sql(History::drop().table(Alias::new("post")).statements()),
+            [
+                r#"DROP TRIGGER "post_keep_history" ON "post""#,
+                r#"DROP FUNCTION "post_keep_history"()"#,
+                r#"DROP TABLE "post_history""#,
+                r#"ALTER TABLE "post" DROP COLUMN "valid_from""#,
+            ]
+        );
+    }
+}
@@ -1,16 +1,22 @@
 #[cfg(feature = "cli")]
 pub mod cli;
 pub mod connection;
+mod down;
+pub mod history;
 pub mod manager;
 pub mod migrator;
+pub mod partition;
 pub mod prelude;
 pub mod schema;
 pub mod seaql_migrations;
+pub mod seeder;
+pub mod trigger;
 pub mod util;
 
 pub use connection::*;
 pub use manager::*;
 pub use migrator::*;
+pub use seeder::*;
 
 pub use async_trait;
 pub use sea_orm;
@@ -28,7 +34,16 @@ pub trait MigrationTrait: MigrationName + Send + Sync {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr>;
 
     /// Define actions to perform when rolling back the migration
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Err(DbErr::Migration("We Don't Do That Here".to_owned()))
+    ///
+    /// By default, the schema changes of [MigrationTrait::up] are reversed in the reverse order:
+    /// the tables, indexes, foreign keys, types, triggers, trigger functions, partitions and table
+    /// histories created are dropped, the tables renamed are renamed back, and the columns and
+    /// foreign keys added and the columns renamed by [SchemaManager::alter_table] are dropped and
+    /// renamed back. `up` is run to find them without executing them, so it should make its
+    /// changes unconditionally. If it executes any other statement, e.g. dropping a table or
+    /// inserting rows, or no statement at all, the down migration can't be derived and has to be
+    /// implemented.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        down::exec(self, manager).await
     }
 }
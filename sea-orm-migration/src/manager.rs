@@ -1,16 +1,30 @@
-use super::{IntoSchemaManagerConnection, SchemaManagerConnection};
+use super::{
+    down,
+    history::{HistoryCreateStatement, HistoryDropStatement},
+    partition::PartitionCreateStatement,
+    trigger::{
+        TriggerCreateStatement, TriggerDropStatement, TriggerFunctionCreateStatement,
+        TriggerFunctionDropStatement,
+    },
+    IntoSchemaManagerConnection, SchemaManagerConnection,
+};
 use sea_orm::sea_query::{
     extension::postgres::{TypeAlterStatement, TypeCreateStatement, TypeDropStatement},
     ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement, IndexDropStatement,
     TableAlterStatement, TableCreateStatement, TableDropStatement, TableRenameStatement,
     TableTruncateStatement,
 };
-use sea_orm::{ConnectionTrait, DbBackend, DbErr, StatementBuilder};
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, Statement, StatementBuilder};
 use sea_schema::{mysql::MySql, postgres::Postgres, probe::SchemaProbe, sqlite::Sqlite};
+use std::sync::Mutex;
 
 /// Helper struct for writing migration scripts in migration file
 pub struct SchemaManager<'c> {
     conn: SchemaManagerConnection<'c>,
+    /// The statements reversing the schema changes, when deriving a down migration
+    down: Option<Mutex<Vec<Statement>>>,
+    /// The FNV-1a hash of the schema statements executed since it was last taken
+    checksum: Mutex<Option<u64>>,
 }
 
 impl<'c> SchemaManager<'c> {
@@ -20,7 +34,48 @@ impl<'c> SchemaManager<'c> {
     {
         Self {
             conn: conn.into_schema_manager_connection(),
+            down: None,
+            checksum: Mutex::new(None),
+        }
+    }
+
+    /// A manager collecting the statements reversing the schema changes instead of executing them;
+    /// other statements are executed on the connection
+    pub(crate) fn deriving_down<T>(conn: T) -> Self
+    where
+        T: IntoSchemaManagerConnection<'c>,
+    {
+        Self {
+            conn: conn.into_schema_manager_connection(),
+            down: Some(Mutex::new(Vec::new())),
+            checksum: Mutex::new(None),
+        }
+    }
+
+    /// The statements reversing the schema changes, in the order of the changes
+    pub(crate) fn into_down_statements(self) -> Vec<Statement> {
+        self.down
+            .map(|down| down.into_inner().expect("Fail to acquire mutex"))
+            .unwrap_or_default()
+    }
+
+    /// Execute a schema change, or collect the statement reversing it when deriving a down migration
+    async fn exec_reversible<S, D, F>(&self, stmt: S, reverse: F) -> Result<(), DbErr>
+    where
+        S: StatementBuilder,
+        D: StatementBuilder,
+        F: FnOnce(&S) -> Option<D>,
+    {
+        if let Some(down) = &self.down {
+            if let Some(reversed) = reverse(&stmt) {
+                let builder = self.conn.get_database_backend();
+                down.lock()
+                    .expect("Fail to acquire mutex")
+                    .push(builder.build(&reversed));
+                return Ok(());
+            }
         }
+        self.exec_stmt(stmt).await
     }
 
     pub async fn exec_stmt<S>(&self, stmt: S) -> Result<(), DbErr>
@@ -28,7 +83,35 @@ impl<'c> SchemaManager<'c> {
         S: StatementBuilder,
     {
         let builder = self.conn.get_database_backend();
-        self.conn.execute(builder.build(&stmt)).await.map(|_| ())
+        self.exec_statement(builder.build(&stmt)).await
+    }
+
+    async fn exec_statement(&self, stmt: Statement) -> Result<(), DbErr> {
+        self.hash_statement(&stmt);
+        self.conn.execute(stmt).await.map(|_| ())
+    }
+
+    fn hash_statement(&self, stmt: &Statement) {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let mut checksum = self.checksum.lock().expect("Fail to acquire mutex");
+        let mut hash = checksum.unwrap_or(FNV_OFFSET_BASIS);
+        for byte in stmt.to_string().bytes().chain([b';']) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        *checksum = Some(hash);
+    }
+
+    /// The checksum of the schema statements executed by the manager since it was last taken,
+    /// if any; it's the checksum of a migration when taken before and after it's applied
+    pub(crate) fn take_checksum(&self) -> Option<String> {
+        self.checksum
+            .lock()
+            .expect("Fail to acquire mutex")
+            .take()
+            .map(|hash| format!("{hash:016x}"))
     }
 
     pub fn get_database_backend(&self) -> DbBackend {
@@ -43,26 +126,77 @@ impl<'c> SchemaManager<'c> {
 /// Schema Creation
 impl<'c> SchemaManager<'c> {
     pub async fn create_table(&self, stmt: TableCreateStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        self.exec_reversible(stmt, down::create_table).await
     }
 
     pub async fn create_index(&self, stmt: IndexCreateStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        self.exec_reversible(stmt, down::create_index).await
     }
 
     pub async fn create_foreign_key(&self, stmt: ForeignKeyCreateStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        self.exec_reversible(stmt, down::create_foreign_key).await
     }
 
     pub async fn create_type(&self, stmt: TypeCreateStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        self.exec_reversible(stmt, down::create_type).await
+    }
+
+    pub async fn create_trigger(&self, stmt: TriggerCreateStatement) -> Result<(), DbErr> {
+        self.exec_reversible(stmt, TriggerCreateStatement::reverse)
+            .await
+    }
+
+    /// Create a partition of a partitioned table, which is supported by Postgres
+    pub async fn create_partition(&self, stmt: PartitionCreateStatement) -> Result<(), DbErr> {
+        let db_backend = self.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return Err(DbErr::Migration(format!(
+                "Partitioned tables aren't supported by {db_backend:?}"
+            )));
+        }
+        self.exec_reversible(stmt, PartitionCreateStatement::reverse)
+            .await
+    }
+
+    /// Keep the history of the rows of a table, which is supported by Postgres
+    pub async fn create_history(&self, stmt: HistoryCreateStatement) -> Result<(), DbErr> {
+        let db_backend = self.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return Err(DbErr::Migration(format!(
+                "Table histories aren't supported by {db_backend:?}"
+            )));
+        }
+        if let Some(down) = &self.down {
+            // The statements reversing the changes are executed in the reverse order
+            let reversed = stmt.reverse().statements().into_iter().rev();
+            down.lock().expect("Fail to acquire mutex").extend(reversed);
+            return Ok(());
+        }
+        for stmt in stmt.statements() {
+            self.exec_statement(stmt).await?;
+        }
+        Ok(())
+    }
+
+    /// Create a trigger function on Postgres; the triggers of other backends don't need one
+    pub async fn create_trigger_function(
+        &self,
+        stmt: TriggerFunctionCreateStatement,
+    ) -> Result<(), DbErr> {
+        match self.get_database_backend() {
+            DbBackend::Postgres => {
+                self.exec_reversible(stmt, TriggerFunctionCreateStatement::reverse)
+                    .await
+            }
+            DbBackend::MySql | DbBackend::Sqlite => Ok(()),
+        }
     }
 }
 
 /// Schema Mutation
 impl<'c> SchemaManager<'c> {
     pub async fn alter_table(&self, stmt: TableAlterStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        self.exec_reversible(stmt, down::alter_table).await
     }
 
     pub async fn drop_table(&self, stmt: TableDropStatement) -> Result<(), DbErr> {
@@ -70,7 +204,7 @@ impl<'c> SchemaManager<'c> {
     }
 
     pub async fn rename_table(&self, stmt: TableRenameStatement) -> Result<(), DbErr> {
-        self.exec_stmt(stmt).await
+        self.exec_reversible(stmt, down::rename_table).await
     }
 
     pub async fn truncate_table(&self, stmt: TableTruncateStatement) -> Result<(), DbErr> {
@@ -92,6 +226,35 @@ impl<'c> SchemaManager<'c> {
     pub async fn drop_type(&self, stmt: TypeDropStatement) -> Result<(), DbErr> {
         self.exec_stmt(stmt).await
     }
+
+    pub async fn drop_trigger(&self, stmt: TriggerDropStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Drop the history of a table, which is supported by Postgres
+    pub async fn drop_history(&self, stmt: HistoryDropStatement) -> Result<(), DbErr> {
+        let db_backend = self.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return Err(DbErr::Migration(format!(
+                "Table histories aren't supported by {db_backend:?}"
+            )));
+        }
+        for stmt in stmt.statements() {
+            self.exec_statement(stmt).await?;
+        }
+        Ok(())
+    }
+
+    /// Drop a trigger function on Postgres, doing nothing on other backends
+    pub async fn drop_trigger_function(
+        &self,
+        stmt: TriggerFunctionDropStatement,
+    ) -> Result<(), DbErr> {
+        match self.get_database_backend() {
+            DbBackend::Postgres => self.exec_stmt(stmt).await,
+            DbBackend::MySql | DbBackend::Sqlite => Ok(()),
+        }
+    }
 }
 
 /// Schema Inspection.
@@ -108,20 +271,7 @@ impl<'c> SchemaManager<'c> {
         T: AsRef<str>,
         C: AsRef<str>,
     {
-        let stmt = match self.conn.get_database_backend() {
-            DbBackend::MySql => MySql.has_column(table, column),
-            DbBackend::Postgres => Postgres.has_column(table, column),
-            DbBackend::Sqlite => Sqlite.has_column(table, column),
-        };
-
-        let builder = self.conn.get_database_backend();
-        let res = self
-            .conn
-            .query_one(builder.build(&stmt))
-            .await?
-            .ok_or_else(|| DbErr::Custom("Failed to check column exists".to_owned()))?;
-
-        res.try_get("", "has_column")
+        has_column(&self.conn, table, column).await
     }
 
     pub async fn has_index<T, I>(&self, table: T, index: I) -> Result<bool, DbErr>
@@ -165,3 +315,24 @@ where
 
     res.try_get("", "has_table")
 }
+
+pub(crate) async fn has_column<C, T, U>(conn: &C, table: T, column: U) -> Result<bool, DbErr>
+where
+    C: ConnectionTrait,
+    T: AsRef<str>,
+    U: AsRef<str>,
+{
+    let stmt = match conn.get_database_backend() {
+        DbBackend::MySql => MySql.has_column(table, column),
+        DbBackend::Postgres => Postgres.has_column(table, column),
+        DbBackend::Sqlite => Sqlite.has_column(table, column),
+    };
+
+    let builder = conn.get_database_backend();
+    let res = conn
+        .query_one(builder.build(&stmt))
+        .await?
+        .ok_or_else(|| DbErr::Custom("Failed to check column exists".to_owned()))?;
+
+    res.try_get("", "has_column")
+}
@@ -1,5 +1,5 @@
 use futures::Future;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::pin::Pin;
 use std::time::SystemTime;
@@ -10,13 +10,16 @@ use sea_orm::sea_query::{
     SelectStatement, SimpleExpr, Table,
 };
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, Condition, ConnectionTrait, DbBackend, DbErr, DeriveIden,
-    DynIden, EntityTrait, FromQueryResult, Iterable, QueryFilter, Schema, Statement,
-    TransactionTrait,
+    ActiveModelTrait, ActiveValue, Condition, ConnectionTrait, DatabaseTransaction, DbBackend,
+    DbErr, DeriveIden, DynIden, EntityTrait, FromQueryResult, IdenStatic, Iterable, QueryFilter,
+    QueryTrait, Schema, Statement, TransactionTrait,
 };
 use sea_schema::{mysql::MySql, postgres::Postgres, probe::SchemaProbe, sqlite::Sqlite};
 
-use super::{seaql_migrations, IntoSchemaManagerConnection, MigrationTrait, SchemaManager};
+use super::{
+    manager::{has_column, has_table},
+    seaql_migrations, DryRunConnection, IntoSchemaManagerConnection, MigrationTrait, SchemaManager,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 /// Status of migration
@@ -54,6 +57,39 @@ impl Migration {
     }
 }
 
+/// The applied and pending migrations, see [MigratorTrait::plan]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationPlan {
+    /// The applied migrations, in time sequence
+    pub applied: Vec<AppliedMigration>,
+    /// The pending migrations, in the order they would be applied
+    pub pending: Vec<PendingMigration>,
+}
+
+/// A migration recorded in the migration table
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub name: String,
+    /// When the migration was applied, in seconds since the Unix epoch
+    pub applied_at: i64,
+    /// The checksum of the schema statements the migration executed through [SchemaManager];
+    /// it's `None` for a migration without any, or applied before checksums were recorded
+    pub checksum: Option<String>,
+}
+
+/// A migration which isn't applied yet
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingMigration {
+    pub name: String,
+}
+
+impl MigrationPlan {
+    /// Whether all the migrations are applied
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
 /// Performing migrations on a database
 #[async_trait::async_trait]
 pub trait MigratorTrait: Send {
@@ -82,15 +118,7 @@ pub trait MigratorTrait: Send {
         C: ConnectionTrait,
     {
         Self::install(db).await?;
-        let stmt = Query::select()
-            .table_name(Self::migration_table_name())
-            .columns(seaql_migrations::Column::iter().map(IntoIden::into_iden))
-            .order_by(seaql_migrations::Column::Version, Order::Asc)
-            .to_owned();
-        let builder = db.get_database_backend();
-        seaql_migrations::Model::find_by_statement(builder.build(&stmt))
-            .all(db)
-            .await
+        query_migration_models::<Self, _>(db).await
     }
 
     /// Get list of migrations with status
@@ -158,7 +186,48 @@ pub trait MigratorTrait: Send {
             .collect())
     }
 
+    /// Get the applied migrations, with when they were applied and their checksums,
+    /// and the pending migrations
+    ///
+    /// ```ignore
+    /// let plan = Migrator::plan(db).await?;
+    /// if !plan.is_up_to_date() {
+    ///     panic!("{} migrations are pending", plan.pending.len());
+    /// }
+    /// ```
+    async fn plan<C>(db: &C) -> Result<MigrationPlan, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let migrations = Self::get_migration_with_status(db).await?;
+        let mut models: HashMap<String, seaql_migrations::Model> =
+            query_migration_models::<Self, _>(db)
+                .await?
+                .into_iter()
+                .map(|model| (model.version.clone(), model))
+                .collect();
+
+        let mut plan = MigrationPlan {
+            applied: Vec::new(),
+            pending: Vec::new(),
+        };
+        for migration in migrations {
+            let name = migration.name().to_owned();
+            match models.remove(&name) {
+                Some(model) => plan.applied.push(AppliedMigration {
+                    name,
+                    applied_at: model.applied_at,
+                    checksum: model.checksum,
+                }),
+                None => plan.pending.push(PendingMigration { name }),
+            }
+        }
+        Ok(plan)
+    }
+
     /// Create migration table `seaql_migrations` in the database
+    ///
+    /// The `checksum` column is added to a migration table created without it by [MigratorTrait::up]
     async fn install<C>(db: &C) -> Result<(), DbErr>
     where
         C: ConnectionTrait,
@@ -226,12 +295,19 @@ pub trait MigratorTrait: Send {
     }
 
     /// Apply pending migrations
+    ///
+    /// The migrations are applied while holding a lock keyed on the migration table, so that multiple
+    /// instances starting at the same time apply them one after another: an advisory lock of the
+    /// transaction on Postgres, a `GET_LOCK` named lock on MySQL and the write lock of the database on SQLite.
+    /// An instance waiting for the lock finds the migrations applied by the others when it gets it.
+    /// On MySQL, the migrations are applied on the connection holding the lock, which is released
+    /// once they're committed or rolled back.
     async fn up<'c, C>(db: C, steps: Option<u32>) -> Result<(), DbErr>
     where
         C: IntoSchemaManagerConnection<'c>,
     {
         exec_with_connection::<'_, _, _>(db, move |manager| {
-            Box::pin(async move { exec_up::<Self>(manager, steps).await })
+            Box::pin(async move { exec_up_with_lock::<Self>(manager, steps).await })
         })
         .await
     }
@@ -246,6 +322,112 @@ pub trait MigratorTrait: Send {
         })
         .await
     }
+
+    /// Run the migrator on the tables of a Postgres schema, e.g. the one of a tenant
+    ///
+    /// The schema is created if it doesn't exist, then `f` is run in a transaction of which the
+    /// `search_path` is the schema, then `public`. The tables created by the migrations, and the
+    /// migration table, are in the schema, so the migrations applied are tracked per schema.
+    ///
+    /// ```ignore
+    /// for tenant in ["tenant_a", "tenant_b"] {
+    ///     Migrator::with_schema(db, tenant, |db| Migrator::up(db, None)).await?;
+    /// }
+    /// ```
+    async fn with_schema<'c, C, F, T>(db: C, schema: &str, f: F) -> Result<T, DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+        F: for<'b> FnOnce(
+                &'b DatabaseTransaction,
+            )
+                -> Pin<Box<dyn Future<Output = Result<T, DbErr>> + Send + 'b>>
+            + Send,
+        T: Send,
+    {
+        let db = db.into_schema_manager_connection();
+        let db_backend = db.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return Err(DbErr::Migration(format!(
+                "Migrating a schema isn't supported by {db_backend:?}, connect to its database instead"
+            )));
+        }
+
+        let schema = format!("\"{}\"", schema.replace('"', "\"\""));
+        let transaction = db.begin().await?;
+        transaction
+            .execute(Statement::from_string(
+                db_backend,
+                format!("CREATE SCHEMA IF NOT EXISTS {schema}"),
+            ))
+            .await?;
+        transaction
+            .set_local("search_path", &format!("{schema}, public"))
+            .await?;
+        let res = f(&transaction).await?;
+        transaction.commit().await?;
+        Ok(res)
+    }
+
+    /// Render the pending migrations into a SQL script of the backend, without applying them
+    ///
+    /// The statements executed by the migrations, and those recording them as applied, are collected
+    /// into the script instead of being executed; the queries of the migrations are run on the database,
+    /// which is of the schema before the migrations. The script is wrapped in a transaction on Postgres.
+    async fn up_to_sql<'c, C>(db: C, steps: Option<u32>) -> Result<String, DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_schema_manager_connection();
+        let db_backend = db.get_database_backend();
+        let dry_run = DryRunConnection::new(db);
+        exec_up_dry_run::<Self>(&dry_run, steps).await?;
+        Ok(sql_script(db_backend, dry_run.into_statements()))
+    }
+}
+
+async fn query_migration_models<M, C>(db: &C) -> Result<Vec<seaql_migrations::Model>, DbErr>
+where
+    M: MigratorTrait + ?Sized,
+    C: ConnectionTrait,
+{
+    let table_name = M::migration_table_name();
+    let checksum = seaql_migrations::Column::Checksum;
+    let mut stmt = Query::select().table_name(table_name.clone());
+    // The checksums of a migration table created without them are null, until `up` adds the column
+    if has_column(db, table_name.to_string(), checksum.as_str()).await? {
+        stmt.columns(seaql_migrations::Column::iter().map(IntoIden::into_iden));
+    } else {
+        stmt.columns(
+            seaql_migrations::Column::iter()
+                .filter(|column| !matches!(column, seaql_migrations::Column::Checksum))
+                .map(IntoIden::into_iden),
+        )
+        .expr_as(Expr::val(Option::<String>::None), checksum);
+    }
+    stmt.order_by(seaql_migrations::Column::Version, Order::Asc);
+    let builder = db.get_database_backend();
+    seaql_migrations::Model::find_by_statement(builder.build(&stmt))
+        .all(db)
+        .await
+}
+
+/// Add the `checksum` column to a migration table created without it
+async fn upgrade_migration_table<M, C>(db: &C) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+    C: ConnectionTrait,
+{
+    let table_name = M::migration_table_name();
+    let column = seaql_migrations::Column::Checksum;
+    if has_column(db, table_name.to_string(), column.as_str()).await? {
+        return Ok(());
+    }
+    let builder = db.get_database_backend();
+    let stmt = Table::alter()
+        .table(table_name)
+        .add_column(&mut Schema::new(builder).get_column_def::<seaql_migrations::Entity>(column))
+        .to_owned();
+    db.execute(builder.build(&stmt)).await.map(|_| ())
 }
 
 async fn exec_with_connection<'c, C, F>(db: C, f: F) -> Result<(), DbErr>
@@ -363,6 +545,7 @@ where
     let db = manager.get_connection();
 
     M::install(db).await?;
+    upgrade_migration_table::<M, _>(db).await?;
 
     if let Some(steps) = steps {
         info!("Applying {} pending migrations", steps);
@@ -382,6 +565,7 @@ where
             *steps -= 1;
         }
         info!("Applying migration '{}'", migration.name());
+        manager.take_checksum();
         migration.up(manager).await?;
         info!("Migration '{}' has been applied", migration.name());
         let now = SystemTime::now()
@@ -390,6 +574,7 @@ where
         seaql_migrations::Entity::insert(seaql_migrations::ActiveModel {
             version: ActiveValue::Set(migration.name().to_owned()),
             applied_at: ActiveValue::Set(now.as_secs() as i64),
+            checksum: ActiveValue::Set(manager.take_checksum()),
         })
         .table_name(M::migration_table_name())
         .exec(db)
@@ -399,6 +584,93 @@ where
     Ok(())
 }
 
+async fn exec_up_with_lock<M>(manager: &SchemaManager<'_>, steps: Option<u32>) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+{
+    let db = manager.get_connection();
+    let db_backend = db.get_database_backend();
+    let lock_name = M::migration_table_name().to_string();
+
+    info!("Acquiring migration lock");
+    match db_backend {
+        DbBackend::Postgres => {
+            // Released when the transaction of the migrations ends
+            db.execute(Statement::from_sql_and_values(
+                db_backend,
+                "SELECT pg_advisory_xact_lock(hashtext(current_schema() || '.' || $1))",
+                [lock_name.into()],
+            ))
+            .await?;
+            info!("Migration lock acquired");
+            exec_up::<M>(manager, steps).await
+        }
+        DbBackend::MySql => {
+            // Named locks belong to the session, so the migrations are applied on the connection
+            // holding the lock, pinned by a transaction. With autocommit off, the statements after
+            // the implicit commit of a schema change run in a new transaction, in which a migration
+            // can begin a nested one.
+            let lock = db.begin().await?;
+            lock.execute_unprepared("SET autocommit = 0").await?;
+            let locked = lock
+                .query_one(Statement::from_sql_and_values(
+                    db_backend,
+                    "SELECT GET_LOCK(LEFT(CONCAT(DATABASE(), '.', ?), 64), -1) AS locked",
+                    [lock_name.clone().into()],
+                ))
+                .await
+                .and_then(|row| {
+                    row.map(|row| row.try_get::<Option<i64>>("", "locked"))
+                        .transpose()
+                });
+            let res = match locked {
+                Ok(Some(Some(1))) => {
+                    info!("Migration lock acquired");
+                    exec_up::<M>(&SchemaManager::new(&lock), steps).await
+                }
+                Ok(_) => Err(DbErr::Migration(format!(
+                    "Failed to acquire migration lock '{lock_name}'"
+                ))),
+                Err(err) => Err(err),
+            };
+            // The changes are committed, by turning autocommit back on, or rolled back before
+            // the lock is released
+            let rolled_back = match &res {
+                Ok(_) => Ok(()),
+                Err(_) => lock.execute_unprepared("ROLLBACK").await.map(|_| ()),
+            };
+            let autocommit = lock.execute_unprepared("SET autocommit = 1").await;
+            let released = lock
+                .execute(Statement::from_sql_and_values(
+                    db_backend,
+                    "SELECT RELEASE_LOCK(LEFT(CONCAT(DATABASE(), '.', ?), 64))",
+                    [lock_name.into()],
+                ))
+                .await;
+            let committed = lock.commit().await;
+            res?;
+            rolled_back?;
+            autocommit?;
+            released?;
+            committed
+        }
+        DbBackend::Sqlite => {
+            M::install(db).await?;
+            // A write as the first statement of the transaction waits for the write lock of the database,
+            // which is held until the transaction ends
+            let transaction = db.begin().await?;
+            let stmt = Query::delete()
+                .from_table(M::migration_table_name())
+                .and_where(Expr::val(1).eq(0))
+                .to_owned();
+            transaction.execute(db_backend.build(&stmt)).await?;
+            info!("Migration lock acquired");
+            exec_up::<M>(&SchemaManager::new(&transaction), steps).await?;
+            transaction.commit().await
+        }
+    }
+}
+
 async fn exec_down<M>(manager: &SchemaManager<'_>, mut steps: Option<u32>) -> Result<(), DbErr>
 where
     M: MigratorTrait + ?Sized,
@@ -437,6 +709,70 @@ where
     Ok(())
 }
 
+async fn exec_up_dry_run<'c, M>(
+    dry_run: &'c DryRunConnection<'c>,
+    mut steps: Option<u32>,
+) -> Result<(), DbErr>
+where
+    M: MigratorTrait + ?Sized,
+{
+    let manager = SchemaManager::new(dry_run);
+    let db = manager.get_connection();
+
+    // The migration table is created by the script if it doesn't exist yet
+    let applied: HashSet<String> = if has_table(db, M::migration_table_name().to_string()).await? {
+        upgrade_migration_table::<M, _>(db).await?;
+        query_migration_models::<M, _>(db)
+            .await?
+            .into_iter()
+            .map(|model| model.version)
+            .collect()
+    } else {
+        M::install(db).await?;
+        HashSet::new()
+    };
+
+    let migrations = M::get_migration_files()
+        .into_iter()
+        .filter(|file| !applied.contains(file.name()));
+    for Migration { migration, .. } in migrations {
+        if let Some(steps) = steps.as_mut() {
+            if steps == &0 {
+                break;
+            }
+            *steps -= 1;
+        }
+        manager.take_checksum();
+        migration.up(&manager).await?;
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("SystemTime before UNIX EPOCH!");
+        let stmt = seaql_migrations::Entity::insert(seaql_migrations::ActiveModel {
+            version: ActiveValue::Set(migration.name().to_owned()),
+            applied_at: ActiveValue::Set(now.as_secs() as i64),
+            checksum: ActiveValue::Set(manager.take_checksum()),
+        })
+        .table_name(M::migration_table_name());
+        db.execute(QueryTrait::build(&stmt, db.get_database_backend()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// The statements of a dry run as a SQL script, in a transaction on Postgres like the migrations
+fn sql_script(db_backend: DbBackend, stmts: Vec<Statement>) -> String {
+    if stmts.is_empty() {
+        return String::new();
+    }
+    let mut lines: Vec<String> = stmts.iter().map(|stmt| format!("{stmt};")).collect();
+    if db_backend == DbBackend::Postgres {
+        lines.insert(0, "BEGIN;".to_owned());
+        lines.push("COMMIT;".to_owned());
+    }
+    lines.join("\n\n") + "\n"
+}
+
 async fn query_tables<C>(db: &C) -> SelectStatement
 where
     C: ConnectionTrait,
@@ -0,0 +1,319 @@
+//! # Partitioned Tables
+//!
+//! Helpers for the declarative partitioning of Postgres, where a table is partitioned by a range,
+//! a list or a hash of columns, and its rows are stored in partitions created as tables of their own.
+//! The primary key and unique indexes of a partitioned table must include the partition columns.
+//!
+//! A partition is dropped as a table, with [`Table::drop`](crate::sea_query::Table::drop),
+//! and its rows can be inserted into it with [`Insert::partition`](crate::sea_orm::Insert::partition).
+//!
+//! # Example
+//!
+//! ```rust
+//! use sea_orm_migration::{partition::*, prelude::*, schema::*};
+//!
+//! #[derive(DeriveMigrationName)]
+//! pub struct Migration;
+//!
+//! #[async_trait::async_trait]
+//! impl MigrationTrait for Migration {
+//!     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+//!         let table = Table::create()
+//!             .table(Metric::Table)
+//!             .col(big_integer(Metric::Id))
+//!             .col(timestamp(Metric::RecordedAt))
+//!             .col(double(Metric::Value))
+//!             .primary_key(Index::create().col(Metric::Id).col(Metric::RecordedAt))
+//!             .to_owned();
+//!         manager
+//!             .create_table(partition_by_range(table, [Metric::RecordedAt]))
+//!             .await?;
+//!         manager
+//!             .create_partition(
+//!                 Partition::create()
+//!                     .name(Alias::new("metric_2024"))
+//!                     .table(Metric::Table)
+//!                     .from_to(["2024-01-01"], ["2025-01-01"])
+//!                     .to_owned(),
+//!             )
+//!             .await
+//!     }
+//!
+//!     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+//!         manager
+//!             .drop_table(Table::drop().table(Metric::Table).to_owned())
+//!             .await
+//!     }
+//! }
+//!
+//! #[derive(DeriveIden)]
+//! pub enum Metric {
+//!     Table,
+//!     Id,
+//!     RecordedAt,
+//!     Value,
+//! }
+//! ```
+
+use sea_orm::sea_query::{
+    DynIden, IntoIden, IntoTableRef, PostgresQueryBuilder, QueryBuilder, QuotedBuilder, SimpleExpr,
+    Table, TableCreateStatement, TableDropStatement, TableRef,
+};
+use sea_orm::{DbBackend, Statement, StatementBuilder};
+
+/// Partition a table by ranges of the values of the columns
+pub fn partition_by_range<I, C>(table: TableCreateStatement, columns: I) -> TableCreateStatement
+where
+    I: IntoIterator<Item = C>,
+    C: IntoIden,
+{
+    partition_by(table, "RANGE", columns)
+}
+
+/// Partition a table by lists of the values of the columns
+pub fn partition_by_list<I, C>(table: TableCreateStatement, columns: I) -> TableCreateStatement
+where
+    I: IntoIterator<Item = C>,
+    C: IntoIden,
+{
+    partition_by(table, "LIST", columns)
+}
+
+/// Partition a table by the remainders of the hash of the columns
+pub fn partition_by_hash<I, C>(table: TableCreateStatement, columns: I) -> TableCreateStatement
+where
+    I: IntoIterator<Item = C>,
+    C: IntoIden,
+{
+    partition_by(table, "HASH", columns)
+}
+
+fn partition_by<I, C>(
+    mut table: TableCreateStatement,
+    strategy: &str,
+    columns: I,
+) -> TableCreateStatement
+where
+    I: IntoIterator<Item = C>,
+    C: IntoIden,
+{
+    let columns: Vec<_> = columns
+        .into_iter()
+        .map(|column| {
+            let mut sql = String::new();
+            column
+                .into_iden()
+                .prepare(&mut sql, PostgresQueryBuilder.quote());
+            sql
+        })
+        .collect();
+    table
+        .extra(format!("PARTITION BY {strategy} ({})", columns.join(", ")))
+        .take()
+}
+
+/// Building the statements of partitions
+#[derive(Debug, Clone)]
+pub struct Partition;
+
+/// The rows stored in a partition
+#[derive(Debug, Clone)]
+pub enum PartitionBound {
+    /// The rows of which the partition columns are from the first values (inclusive)
+    /// to the second values (exclusive)
+    Range(Vec<SimpleExpr>, Vec<SimpleExpr>),
+    /// The rows of which the partition column is one of the values
+    List(Vec<SimpleExpr>),
+    /// The rows of which the hash of the partition columns has the remainder when divided by the modulus
+    Hash { modulus: u32, remainder: u32 },
+    /// The rows which aren't stored in any other partition
+    Default,
+}
+
+/// Create a partition of a partitioned table
+#[derive(Debug, Clone)]
+pub struct PartitionCreateStatement {
+    name: Option<DynIden>,
+    table: Option<TableRef>,
+    bound: PartitionBound,
+    if_not_exists: bool,
+}
+
+impl Partition {
+    /// Construct a [PartitionCreateStatement], of the default partition unless its bound is set
+    pub fn create() -> PartitionCreateStatement {
+        PartitionCreateStatement {
+            name: None,
+            table: None,
+            bound: PartitionBound::Default,
+            if_not_exists: false,
+        }
+    }
+}
+
+impl PartitionCreateStatement {
+    /// Set the name of the partition, which is the name of its table
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the partitioned table
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    /// Store the rows of a range of a table partitioned with [partition_by_range]
+    pub fn from_to<I, J, V>(&mut self, from: I, to: J) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        J: IntoIterator<Item = V>,
+        V: Into<SimpleExpr>,
+    {
+        self.bound = PartitionBound::Range(
+            from.into_iter().map(Into::into).collect(),
+            to.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Store the rows of a list of values of a table partitioned with [partition_by_list]
+    pub fn values_in<I, V>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<SimpleExpr>,
+    {
+        self.bound = PartitionBound::List(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Store the rows of a remainder of a table partitioned with [partition_by_hash]
+    pub fn modulus_remainder(&mut self, modulus: u32, remainder: u32) -> &mut Self {
+        self.bound = PartitionBound::Hash { modulus, remainder };
+        self
+    }
+
+    /// Store the rows which aren't stored in any other partition
+    pub fn default_partition(&mut self) -> &mut Self {
+        self.bound = PartitionBound::Default;
+        self
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::replace(self, Partition::create())
+    }
+
+    /// The statement dropping the partition, in a derived down migration
+    pub(crate) fn reverse(&self) -> Option<TableDropStatement> {
+        let name = self.name.clone()?;
+        Some(Table::drop().table(name).to_owned())
+    }
+}
+
+/// The partition is created with the syntax of Postgres
+impl StatementBuilder for PartitionCreateStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        let query_builder = PostgresQueryBuilder;
+        let values = |values: &[SimpleExpr], sql: &mut String| {
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(", ");
+                }
+                query_builder.prepare_simple_expr(value, sql);
+            }
+        };
+
+        let mut sql = String::from("CREATE TABLE ");
+        if self.if_not_exists {
+            sql.push_str("IF NOT EXISTS ");
+        }
+        if let Some(name) = &self.name {
+            name.prepare(&mut sql, query_builder.quote());
+        }
+        sql.push_str(" PARTITION OF ");
+        if let Some(table) = &self.table {
+            query_builder.prepare_table_ref(table, &mut sql);
+        }
+        match &self.bound {
+            PartitionBound::Range(from, to) => {
+                sql.push_str(" FOR VALUES FROM (");
+                values(from, &mut sql);
+                sql.push_str(") TO (");
+                values(to, &mut sql);
+                sql.push(')');
+            }
+            PartitionBound::List(list) => {
+                sql.push_str(" FOR VALUES IN (");
+                values(list, &mut sql);
+                sql.push(')');
+            }
+            PartitionBound::Hash { modulus, remainder } => {
+                sql.push_str(&format!(
+                    " FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})"
+                ));
+            }
+            PartitionBound::Default => sql.push_str(" DEFAULT"),
+        }
+        Statement::from_string(*db_backend, sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::sea_query::{Alias, ColumnDef, Table};
+
+    #[test]
+    fn test_partition_by() {
+        let table = Table::create()
+            .table(Alias::new("metric"))
+            .col(ColumnDef::new(Alias::new("recorded_at")).timestamp())
+            .to_owned();
+        assert_eq!(
+            partition_by_range(table, [Alias::new("recorded_at")]).to_string(PostgresQueryBuilder),
+            r#"CREATE TABLE "metric" ( "recorded_at" timestamp ) PARTITION BY RANGE ("recorded_at")"#
+        );
+    }
+
+    #[test]
+    fn test_create_partition() {
+        let partition = || {
+            Partition::create()
+                .name(Alias::new("metric_1"))
+                .table(Alias::new("metric"))
+                .to_owned()
+        };
+        let sql = |partition: &mut PartitionCreateStatement| {
+            partition.build(&DbBackend::Postgres).to_string()
+        };
+
+        assert_eq!(
+            sql(partition().from_to(["2024-01-01"], ["2025-01-01"])),
+            r#"CREATE TABLE "metric_1" PARTITION OF "metric" FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')"#
+        );
+        assert_eq!(
+            sql(partition().values_in([1, 2])),
+            r#"CREATE TABLE "metric_1" PARTITION OF "metric" FOR VALUES IN (1, 2)"#
+        );
+        assert_eq!(
+            sql(partition().if_not_exists().modulus_remainder(4, 1)),
+            r#"CREATE TABLE IF NOT EXISTS "metric_1" PARTITION OF "metric" FOR VALUES WITH (MODULUS 4, REMAINDER 1)"#
+        );
+        assert_eq!(
+            sql(&mut partition()),
+            r#"CREATE TABLE "metric_1" PARTITION OF "metric" DEFAULT"#
+        );
+    }
+}
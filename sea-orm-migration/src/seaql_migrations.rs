@@ -7,6 +7,8 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub version: String,
     pub applied_at: i64,
+    /// The checksum of the schema statements executed by the migration, see [MigrationPlan](crate::MigrationPlan)
+    pub checksum: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,133 @@
+use tracing::info;
+
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ConnectionTrait, DbErr, EntityTrait, IdenStatic, IntoActiveModel, Iterable,
+    PrimaryKeyToColumn, TransactionTrait,
+};
+
+use super::{IntoSchemaManagerConnection, MigrationName, SchemaManagerConnection};
+
+/// A set of seed data, e.g. the initial users or the fixtures of development
+///
+/// Seeds are run again by every [SeederTrait::seed], so they should insert their data with
+/// [upsert_many] or [upsert_many_on], which update the rows inserted by a previous run.
+/// The name of a seed is derived with `#[derive(DeriveMigrationName)]` as for a migration.
+#[async_trait::async_trait]
+pub trait SeedTrait: MigrationName + Send + Sync {
+    /// The environments the seed is run in, e.g. `development`; it's run in every environment if empty
+    fn environments(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Insert or update the seed data
+    async fn run(&self, db: &SchemaManagerConnection) -> Result<(), DbErr>;
+}
+
+/// Seeding a database with initial data
+#[async_trait::async_trait]
+pub trait SeederTrait: Send {
+    /// Vector of seeds in the order they are run
+    fn seeds() -> Vec<Box<dyn SeedTrait>>;
+
+    /// Run the seeds of an environment in one transaction, in the order of [SeederTrait::seeds]
+    ///
+    /// Seeds run in every environment are also run if `env` is `None`, while the ones of
+    /// specific environments are skipped.
+    async fn seed<'c, C>(db: C, env: Option<&str>) -> Result<(), DbErr>
+    where
+        C: IntoSchemaManagerConnection<'c>,
+    {
+        let db = db.into_schema_manager_connection();
+        let transaction = db.begin().await?;
+        let txn = (&transaction).into_schema_manager_connection();
+
+        match env {
+            Some(env) => info!("Seeding the database for environment '{}'", env),
+            None => info!("Seeding the database"),
+        }
+
+        let seeds = Self::seeds();
+        if seeds.is_empty() {
+            info!("No seeds");
+        }
+        for seed in seeds {
+            let environments = seed.environments();
+            let included =
+                environments.is_empty() || matches!(env, Some(env) if environments.contains(&env));
+            if !included {
+                info!("Skipping seed '{}'", seed.name());
+                continue;
+            }
+            info!("Running seed '{}'", seed.name());
+            seed.run(&txn).await?;
+            info!("Seed '{}' has been run", seed.name());
+        }
+
+        transaction.commit().await
+    }
+}
+
+/// No seeds, for a migrator CLI without a seeder
+impl SeederTrait for () {
+    fn seeds() -> Vec<Box<dyn SeedTrait>> {
+        Vec::new()
+    }
+}
+
+/// Insert the models, or update the rows of the same primary key with the columns set in them
+pub async fn upsert_many<A, I, C>(db: &C, models: I) -> Result<(), DbErr>
+where
+    A: ActiveModelTrait,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    I: IntoIterator<Item = A>,
+    C: ConnectionTrait,
+{
+    let primary_key = <A::Entity as EntityTrait>::PrimaryKey::iter()
+        .map(|key| key.into_column())
+        .collect();
+    upsert_many_on(db, primary_key, models).await
+}
+
+/// Insert the models, or update the rows conflicting on the columns, e.g. those of a unique index,
+/// with the other columns set in them
+///
+/// The columns set must be the same in every model.
+pub async fn upsert_many_on<A, I, C>(
+    db: &C,
+    conflict_columns: Vec<<A::Entity as EntityTrait>::Column>,
+    models: I,
+) -> Result<(), DbErr>
+where
+    A: ActiveModelTrait,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    I: IntoIterator<Item = A>,
+    C: ConnectionTrait,
+{
+    let models: Vec<A> = models.into_iter().collect();
+    let Some(model) = models.first() else {
+        return Ok(());
+    };
+    let mut update_columns: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+        .filter(|column| !model.is_not_set(*column))
+        .filter(|column| {
+            !conflict_columns
+                .iter()
+                .any(|conflict| conflict.as_str() == column.as_str())
+        })
+        .collect();
+    if update_columns.is_empty() {
+        // Updating a conflict column with its own value does nothing, on every backend
+        update_columns = conflict_columns.clone();
+    }
+
+    let on_conflict = OnConflict::columns(conflict_columns)
+        .update_columns(update_columns)
+        .to_owned();
+    <A::Entity as EntityTrait>::insert_many(models)
+        .on_conflict(on_conflict)
+        .exec_without_returning(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,537 @@
+//! # Triggers and Trigger Functions
+//!
+//! Builders of the statements creating and dropping triggers which set columns of the rows
+//! inserted or updated, generating the SQL of each database backend:
+//!
+//! - On Postgres, the columns are set by a trigger function executed by the trigger.
+//! - On MySQL, the trigger sets the columns of the `NEW` row itself.
+//! - On SQLite, which can't set the columns of the `NEW` row, the trigger updates the row after
+//!   it's inserted or updated, by its `rowid`; the table must not be created `WITHOUT ROWID`.
+//!
+//! The trigger function is only created and dropped on Postgres, so the same migration can be
+//! run on every backend.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sea_orm_migration::{prelude::*, trigger::*};
+//!
+//! #[derive(DeriveMigrationName)]
+//! pub struct Migration;
+//!
+//! #[async_trait::async_trait]
+//! impl MigrationTrait for Migration {
+//!     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+//!         manager
+//!             .create_trigger_function(set_updated_at(Post::UpdatedAt))
+//!             .await?;
+//!         manager
+//!             .create_trigger(
+//!                 Trigger::create()
+//!                     .name(Alias::new("post_set_updated_at"))
+//!                     .table(Post::Table)
+//!                     .before()
+//!                     .update()
+//!                     .execute(set_updated_at(Post::UpdatedAt))
+//!                     .to_owned(),
+//!             )
+//!             .await
+//!     }
+//!
+//!     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+//!         manager
+//!             .drop_trigger(
+//!                 Trigger::drop()
+//!                     .name(Alias::new("post_set_updated_at"))
+//!                     .table(Post::Table)
+//!                     .to_owned(),
+//!             )
+//!             .await?;
+//!         manager
+//!             .drop_trigger_function(
+//!                 TriggerFunction::drop()
+//!                     .name(Alias::new("set_updated_at"))
+//!                     .to_owned(),
+//!             )
+//!             .await
+//!     }
+//! }
+//!
+//! #[derive(DeriveIden)]
+//! pub enum Post {
+//!     Table,
+//!     UpdatedAt,
+//! }
+//! ```
+
+use sea_orm::sea_query::{Alias, DynIden, Expr, IntoIden, IntoTableRef, SimpleExpr, TableRef};
+use sea_orm::{DbBackend, Statement, StatementBuilder};
+
+/// Building the statements of triggers
+#[derive(Debug, Clone)]
+pub struct Trigger;
+
+/// Building the statements of trigger functions
+#[derive(Debug, Clone)]
+pub struct TriggerFunction;
+
+/// When a trigger is executed, relative to the statement changing a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerTiming {
+    Before,
+    After,
+}
+
+/// The statement changing a row which a trigger is executed for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerEvent {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Create a trigger executed for each row, setting its columns with a trigger function
+#[derive(Debug, Clone)]
+pub struct TriggerCreateStatement {
+    name: Option<DynIden>,
+    table: Option<TableRef>,
+    timing: TriggerTiming,
+    event: TriggerEvent,
+    function: Option<TriggerFunctionCreateStatement>,
+}
+
+/// Drop a trigger
+#[derive(Debug, Clone)]
+pub struct TriggerDropStatement {
+    name: Option<DynIden>,
+    table: Option<TableRef>,
+    if_exists: bool,
+}
+
+/// Create a trigger function setting columns of the row inserted or updated
+#[derive(Debug, Clone, Default)]
+pub struct TriggerFunctionCreateStatement {
+    name: Option<DynIden>,
+    values: Vec<(DynIden, SimpleExpr)>,
+}
+
+/// Drop a trigger function
+#[derive(Debug, Clone, Default)]
+pub struct TriggerFunctionDropStatement {
+    name: Option<DynIden>,
+    if_exists: bool,
+}
+
+/// The trigger function `set_<column>`, setting the column to the current timestamp,
+/// e.g. `set_updated_at`
+pub fn set_updated_at<C>(column: C) -> TriggerFunctionCreateStatement
+where
+    C: IntoIden,
+{
+    let column = column.into_iden();
+    TriggerFunction::create()
+        .name(Alias::new(format!("set_{}", column.to_string())))
+        .value(column, Expr::current_timestamp())
+        .to_owned()
+}
+
+impl Trigger {
+    /// Construct a [TriggerCreateStatement], of a `BEFORE INSERT` trigger by default
+    pub fn create() -> TriggerCreateStatement {
+        TriggerCreateStatement {
+            name: None,
+            table: None,
+            timing: TriggerTiming::Before,
+            event: TriggerEvent::Insert,
+            function: None,
+        }
+    }
+
+    /// Construct a [TriggerDropStatement]
+    pub fn drop() -> TriggerDropStatement {
+        TriggerDropStatement {
+            name: None,
+            table: None,
+            if_exists: false,
+        }
+    }
+}
+
+impl TriggerFunction {
+    /// Construct a [TriggerFunctionCreateStatement]
+    pub fn create() -> TriggerFunctionCreateStatement {
+        TriggerFunctionCreateStatement::default()
+    }
+
+    /// Construct a [TriggerFunctionDropStatement]
+    pub fn drop() -> TriggerFunctionDropStatement {
+        TriggerFunctionDropStatement::default()
+    }
+}
+
+impl TriggerCreateStatement {
+    /// Set the name of the trigger
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table of the rows the trigger is executed for
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    /// Execute the trigger before the row is changed, as is needed to set its columns on MySQL
+    pub fn before(&mut self) -> &mut Self {
+        self.timing = TriggerTiming::Before;
+        self
+    }
+
+    /// Execute the trigger after the row is changed
+    pub fn after(&mut self) -> &mut Self {
+        self.timing = TriggerTiming::After;
+        self
+    }
+
+    /// Execute the trigger for inserted rows
+    pub fn insert(&mut self) -> &mut Self {
+        self.event = TriggerEvent::Insert;
+        self
+    }
+
+    /// Execute the trigger for updated rows
+    pub fn update(&mut self) -> &mut Self {
+        self.event = TriggerEvent::Update;
+        self
+    }
+
+    /// Execute the trigger for deleted rows
+    pub fn delete(&mut self) -> &mut Self {
+        self.event = TriggerEvent::Delete;
+        self
+    }
+
+    /// Set the trigger function executed by the trigger, which must be created on Postgres
+    pub fn execute(&mut self, function: TriggerFunctionCreateStatement) -> &mut Self {
+        self.function = Some(function);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::replace(self, Trigger::create())
+    }
+
+    /// The statement dropping the trigger, in a derived down migration
+    pub(crate) fn reverse(&self) -> Option<TriggerDropStatement> {
+        Some(TriggerDropStatement {
+            name: self.name.clone(),
+            table: self.table.clone(),
+            if_exists: false,
+        })
+    }
+
+    fn to_string(&self, db_backend: DbBackend) -> String {
+        let query_builder = db_backend.get_query_builder();
+        let quote = query_builder.quote();
+        let values = self
+            .function
+            .as_ref()
+            .map(|function| function.values.as_slice())
+            .unwrap_or_default();
+        // SQLite can't set the columns of the `NEW` row, so they are updated after
+        let timing = match (db_backend, self.timing) {
+            (DbBackend::Sqlite, _) if !values.is_empty() => TriggerTiming::After,
+            (_, timing) => timing,
+        };
+
+        let mut sql = String::from("CREATE TRIGGER ");
+        if let Some(name) = &self.name {
+            name.prepare(&mut sql, quote);
+        }
+        sql.push_str(match timing {
+            TriggerTiming::Before => " BEFORE",
+            TriggerTiming::After => " AFTER",
+        });
+        sql.push_str(match self.event {
+            TriggerEvent::Insert => " INSERT ON ",
+            TriggerEvent::Update => " UPDATE ON ",
+            TriggerEvent::Delete => " DELETE ON ",
+        });
+        if let Some(table) = &self.table {
+            query_builder.prepare_table_ref(table, &mut sql);
+        }
+        sql.push_str(" FOR EACH ROW ");
+
+        match db_backend {
+            DbBackend::Postgres => {
+                sql.push_str("EXECUTE FUNCTION ");
+                if let Some(name) = self.function.as_ref().and_then(|f| f.name.as_ref()) {
+                    name.prepare(&mut sql, quote);
+                }
+                sql.push_str("()");
+            }
+            DbBackend::MySql => {
+                sql.push_str("SET ");
+                for (i, (column, value)) in values.iter().enumerate() {
+                    if i > 0 {
+                        sql.push_str(", ");
+                    }
+                    sql.push_str("NEW.");
+                    column.prepare(&mut sql, quote);
+                    sql.push_str(" = ");
+                    query_builder.prepare_simple_expr(value, &mut sql);
+                }
+            }
+            DbBackend::Sqlite => {
+                sql.push_str("BEGIN UPDATE ");
+                if let Some(table) = &self.table {
+                    query_builder.prepare_table_ref(table, &mut sql);
+                }
+                sql.push_str(" SET ");
+                for (i, (column, value)) in values.iter().enumerate() {
+                    if i > 0 {
+                        sql.push_str(", ");
+                    }
+                    column.prepare(&mut sql, quote);
+                    sql.push_str(" = ");
+                    query_builder.prepare_simple_expr(value, &mut sql);
+                }
+                sql.push_str(" WHERE rowid = NEW.rowid; END");
+            }
+        }
+        sql
+    }
+}
+
+impl StatementBuilder for TriggerCreateStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        Statement::from_string(*db_backend, self.to_string(*db_backend))
+    }
+}
+
+impl TriggerDropStatement {
+    /// Set the name of the trigger
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table of the trigger, which is needed on Postgres
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::replace(self, Trigger::drop())
+    }
+}
+
+impl StatementBuilder for TriggerDropStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        let query_builder = db_backend.get_query_builder();
+        let mut sql = String::from("DROP TRIGGER ");
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        if let Some(name) = &self.name {
+            name.prepare(&mut sql, query_builder.quote());
+        }
+        if let (DbBackend::Postgres, Some(table)) = (db_backend, &self.table) {
+            sql.push_str(" ON ");
+            query_builder.prepare_table_ref(table, &mut sql);
+        }
+        Statement::from_string(*db_backend, sql)
+    }
+}
+
+impl TriggerFunctionCreateStatement {
+    /// Set the name of the trigger function
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set a column of the row to the value of an expression, e.g. `Expr::current_timestamp()`
+    pub fn value<C, V>(&mut self, column: C, value: V) -> &mut Self
+    where
+        C: IntoIden,
+        V: Into<SimpleExpr>,
+    {
+        self.values.push((column.into_iden(), value.into()));
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+
+    /// The statement dropping the trigger function, in a derived down migration
+    pub(crate) fn reverse(&self) -> Option<TriggerFunctionDropStatement> {
+        Some(TriggerFunctionDropStatement {
+            name: self.name.clone(),
+            if_exists: false,
+        })
+    }
+}
+
+/// The function is a PL/pgSQL function of Postgres, which is replaced if it exists
+impl StatementBuilder for TriggerFunctionCreateStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        let query_builder = db_backend.get_query_builder();
+        let quote = query_builder.quote();
+        let mut sql = String::from("CREATE OR REPLACE FUNCTION ");
+        if let Some(name) = &self.name {
+            name.prepare(&mut sql, quote);
+        }
+        sql.push_str("() RETURNS TRIGGER AS $$ BEGIN ");
+        for (column, value) in self.values.iter() {
+            sql.push_str("NEW.");
+            column.prepare(&mut sql, quote);
+            sql.push_str(" := ");
+            query_builder.prepare_simple_expr(value, &mut sql);
+            sql.push_str("; ");
+        }
+        sql.push_str("RETURN NEW; END; $$ LANGUAGE plpgsql");
+        Statement::from_string(*db_backend, sql)
+    }
+}
+
+impl TriggerFunctionDropStatement {
+    /// Set the name of the trigger function
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+}
+
+impl StatementBuilder for TriggerFunctionDropStatement {
+    fn build(&self, db_backend: &DbBackend) -> Statement {
+        let mut sql = String::from("DROP FUNCTION ");
+        if self.if_exists {
+            sql.push_str("IF EXISTS ");
+        }
+        if let Some(name) = &self.name {
+            name.prepare(&mut sql, db_backend.get_query_builder().quote());
+        }
+        sql.push_str("()");
+        Statement::from_string(*db_backend, sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_trigger() -> TriggerCreateStatement {
+        Trigger::create()
+            .name(Alias::new("post_set_updated_at"))
+            .table(Alias::new("post"))
+            .before()
+            .update()
+            .execute(set_updated_at(Alias::new("updated_at")))
+            .to_owned()
+    }
+
+    #[test]
+    fn test_create_trigger_function() {
+        assert_eq!(
+            set_updated_at(Alias::new("updated_at"))
+                .build(&DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"CREATE OR REPLACE FUNCTION "set_updated_at"() RETURNS TRIGGER AS $$"#,
+                r#"BEGIN NEW."updated_at" := CURRENT_TIMESTAMP; RETURN NEW; END;"#,
+                r#"$$ LANGUAGE plpgsql"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_create_trigger() {
+        assert_eq!(
+            create_trigger().build(&DbBackend::Postgres).to_string(),
+            [
+                r#"CREATE TRIGGER "post_set_updated_at" BEFORE UPDATE ON "post""#,
+                r#"FOR EACH ROW EXECUTE FUNCTION "set_updated_at"()"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            create_trigger().build(&DbBackend::MySql).to_string(),
+            [
+                r#"CREATE TRIGGER `post_set_updated_at` BEFORE UPDATE ON `post`"#,
+                r#"FOR EACH ROW SET NEW.`updated_at` = CURRENT_TIMESTAMP"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            create_trigger().build(&DbBackend::Sqlite).to_string(),
+            [
+                r#"CREATE TRIGGER "post_set_updated_at" AFTER UPDATE ON "post""#,
+                r#"FOR EACH ROW BEGIN UPDATE "post" SET "updated_at" = CURRENT_TIMESTAMP"#,
+                r#"WHERE rowid = NEW.rowid; END"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_drop_trigger() {
+        let drop_trigger = Trigger::drop()
+            .name(Alias::new("post_set_updated_at"))
+            .table(Alias::new("post"))
+            .if_exists()
+            .to_owned();
+        assert_eq!(
+            drop_trigger.build(&DbBackend::Postgres).to_string(),
+            r#"DROP TRIGGER IF EXISTS "post_set_updated_at" ON "post""#
+        );
+        assert_eq!(
+            drop_trigger.build(&DbBackend::MySql).to_string(),
+            "DROP TRIGGER IF EXISTS `post_set_updated_at`"
+        );
+        assert_eq!(
+            TriggerFunction::drop()
+                .name(Alias::new("set_updated_at"))
+                .to_owned()
+                .build(&DbBackend::Postgres)
+                .to_string(),
+            r#"DROP FUNCTION "set_updated_at"()"#
+        );
+    }
+}
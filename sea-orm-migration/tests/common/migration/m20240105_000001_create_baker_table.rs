@@ -0,0 +1,46 @@
+use super::m20220118_000001_create_cake_table::Cake;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// The down migration is derived, dropping the column, the index and the table
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Baker::Table)
+                    .col(pk_auto(Baker::Id))
+                    .col(string(Baker::Name))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-baker-name")
+                    .table(Baker::Table)
+                    .col(Baker::Name)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Cake::Table)
+                    .add_column(integer_null(Baker::BakerId))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Baker {
+    Table,
+    Id,
+    Name,
+    BakerId,
+}
@@ -4,3 +4,4 @@ pub mod m20220118_000003_seed_cake_table;
 pub mod m20220118_000004_create_tea_enum;
 pub mod m20220923_000001_seed_cake_table;
 pub mod m20230109_000001_seed_cake_table;
+pub mod m20240105_000001_create_baker_table;
@@ -13,6 +13,7 @@ impl MigratorTrait for Migrator {
             Box::new(m20220118_000004_create_tea_enum::Migration),
             Box::new(m20220923_000001_seed_cake_table::Migration),
             Box::new(m20230109_000001_seed_cake_table::Migration),
+            Box::new(m20240105_000001_create_baker_table::Migration),
         ]
     }
 
@@ -1,2 +1,4 @@
 pub mod migration;
 pub mod migrator;
+pub mod seed;
+pub mod seeder;
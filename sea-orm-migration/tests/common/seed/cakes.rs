@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::entity::*;
+use sea_orm_migration::upsert_many;
+
+#[derive(DeriveMigrationName)]
+pub struct Seed;
+
+#[async_trait::async_trait]
+impl SeedTrait for Seed {
+    async fn run(&self, db: &SchemaManagerConnection) -> Result<(), DbErr> {
+        upsert_many(
+            db,
+            [
+                cake::ActiveModel {
+                    id: Set(1001),
+                    name: Set("Sponge Cake".to_owned()),
+                },
+                cake::ActiveModel {
+                    id: Set(1002),
+                    name: Set("Carrot Cake".to_owned()),
+                },
+            ],
+        )
+        .await
+    }
+}
+
+mod cake {
+    use sea_orm_migration::sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "cake")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
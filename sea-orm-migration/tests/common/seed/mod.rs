@@ -0,0 +1,2 @@
+pub mod cakes;
+pub mod development_cakes;
@@ -0,0 +1,11 @@
+use crate::common::seed::*;
+use sea_orm_migration::prelude::*;
+
+pub struct Seeder;
+
+#[async_trait::async_trait]
+impl SeederTrait for Seeder {
+    fn seeds() -> Vec<Box<dyn SeedTrait>> {
+        vec![Box::new(cakes::Seed), Box::new(development_cakes::Seed)]
+    }
+}
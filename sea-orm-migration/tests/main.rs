@@ -1,6 +1,6 @@
 mod common;
 
-use common::migrator::*;
+use common::{migrator::*, seeder::Seeder};
 use sea_orm::{ConnectOptions, ConnectionTrait, Database, DbBackend, DbErr, Statement};
 use sea_orm_migration::{migrator::MigrationStatus, prelude::*};
 
@@ -116,6 +116,18 @@ where
     assert!(!manager.has_table("cake").await?);
     assert!(!manager.has_table("fruit").await?);
 
+    println!("\nMigrator::up_to_sql");
+    let sql = Migrator::up_to_sql(db, Some(2)).await?;
+    println!("{sql}");
+    assert_eq!(sql.matches("CREATE TABLE").count(), 2);
+    assert!(sql.contains("'m20220118_000001_create_cake_table'"));
+    assert!(sql.contains("'m20220118_000002_create_fruit_table'"));
+    assert!(!sql.contains("m20220118_000003_seed_cake_table"));
+
+    assert!(!manager.has_table("cake").await?);
+    assert!(!manager.has_table("fruit").await?);
+    assert_eq!(Migrator::get_pending_migrations(db).await?.len(), 7);
+
     println!("\nMigrator::up");
     Migrator::up(db, Some(0)).await?;
 
@@ -127,7 +139,7 @@ where
 
     println!("\nMigrator::get_pending_migrations");
     let migrations = Migrator::get_pending_migrations(db).await?;
-    assert_eq!(migrations.len(), 5);
+    assert_eq!(migrations.len(), 6);
 
     let migration = migrations.get(0).unwrap();
     assert_eq!(migration.name(), "m20220118_000002_create_fruit_table");
@@ -136,6 +148,15 @@ where
     assert!(manager.has_table("cake").await?);
     assert!(!manager.has_table("fruit").await?);
 
+    println!("\nMigrator::plan");
+    let plan = Migrator::plan(db).await?;
+    assert!(!plan.is_up_to_date());
+    assert_eq!(plan.applied.len(), 1);
+    assert_eq!(plan.applied[0].name, "m20220118_000001_create_cake_table");
+    assert!(plan.applied[0].checksum.is_some());
+    assert_eq!(plan.pending.len(), 6);
+    assert_eq!(plan.pending[0].name, "m20220118_000002_create_fruit_table");
+
     println!("\nMigrator::down");
     Migrator::down(db, Some(0)).await?;
 
@@ -180,7 +201,7 @@ where
 
     println!("\nMigrator::get_applied_migrations");
     let migrations = Migrator::get_applied_migrations(db).await?;
-    assert_eq!(migrations.len(), 6);
+    assert_eq!(migrations.len(), 7);
 
     assert!(!manager.has_index("cake", "non_existent_index").await?);
     assert!(manager.has_index("cake", "cake_name_index").await?);
@@ -197,6 +218,33 @@ where
 
     assert!(manager.has_column("cake", "name").await?);
     assert!(manager.has_column("fruit", "cake_id").await?);
+    assert!(manager.has_table("baker").await?);
+    assert!(manager.has_index("baker", "idx-baker-name").await?);
+    assert!(manager.has_column("cake", "baker_id").await?);
+
+    println!("\nSeeder::seed");
+    let seeded_cakes = || async {
+        let stmt = Statement::from_string(
+            db.get_database_backend(),
+            "SELECT name FROM cake WHERE id > 1000 ORDER BY id",
+        );
+        db.query_all(stmt)
+            .await?
+            .into_iter()
+            .map(|row| row.try_get::<String>("", "name"))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    Seeder::seed(db, None).await?;
+    assert_eq!(seeded_cakes().await?, ["Sponge Cake", "Carrot Cake"]);
+
+    // Seeds are run again, updating the rows inserted before
+    Seeder::seed(db, Some("development")).await?;
+    Seeder::seed(db, Some("development")).await?;
+    assert_eq!(
+        seeded_cakes().await?,
+        ["Sponge Cake", "Carrot Cake", "Test Cake"]
+    );
 
     // Tests rolling back changes of "migrate down" when running migration on Postgres
     if matches!(db.get_database_backend(), DbBackend::Postgres) {
@@ -225,6 +273,14 @@ where
         std::env::remove_var("ABORT_MIGRATION");
     }
 
+    // The down migration of the last migration is derived from its `up`
+    println!("\nMigrator::down");
+    Migrator::down(db, Some(1)).await?;
+
+    assert!(!manager.has_table("baker").await?);
+    assert!(manager.has_table("cake").await?);
+    assert!(!manager.has_column("cake", "baker_id").await?);
+
     println!("\nMigrator::down");
     Migrator::down(db, None).await?;
 
@@ -236,6 +292,39 @@ where
     assert!(!manager.has_table("cake").await?);
     assert!(!manager.has_table("fruit").await?);
 
+    // Migrators started at the same time apply the migrations one after another
+    println!("\nMigrator::up concurrently");
+    let (first, second) = futures::join!(Migrator::up(db, None), Migrator::up(db, None));
+    first?;
+    second?;
+
+    assert_eq!(Migrator::get_applied_migrations(db).await?.len(), 7);
+    assert!(manager.has_table("cake").await?);
+    assert!(manager.has_table("fruit").await?);
+
+    println!("\nMigrator::down");
+    Migrator::down(db, None).await?;
+
+    // Tests migrating the tables of another schema when running migration on Postgres
+    if matches!(db.get_database_backend(), DbBackend::Postgres) {
+        println!("\nMigrator::with_schema");
+        Migrator::with_schema(db, "tenant", |db| Migrator::up(db, None)).await?;
+
+        let migrations =
+            Migrator::with_schema(db, "tenant", |db| Migrator::get_applied_migrations(db)).await?;
+        assert_eq!(migrations.len(), 7);
+
+        // The migrations of the tenant don't apply to the current schema
+        assert!(!manager.has_table("cake").await?);
+        assert_eq!(Migrator::get_pending_migrations(db).await?.len(), 7);
+
+        db.execute(Statement::from_string(
+            db.get_database_backend(),
+            "DROP SCHEMA \"tenant\" CASCADE",
+        ))
+        .await?;
+    }
+
     println!("\nMigrator::fresh");
     Migrator::fresh(db).await?;
 
@@ -0,0 +1,115 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Poll, Waker},
+};
+
+/// A token cancelling the statements it is attached to, see [`crate::Statement::cancel_on`].
+///
+/// Clones of a token share its state, so the token can be cancelled from another task,
+/// e.g. once the request which issued the query has been aborted.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+/// #
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+///
+/// let token = CancellationToken::new();
+/// token.cancel();
+///
+/// assert_eq!(
+///     cake::Entity::find().cancel_on(token).all(&db).await,
+///     Err(DbErr::QueryCancelled)
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl CancellationToken {
+    /// Create a token which has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the statements running with this token, and the ones started with it afterwards
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        if let Ok(mut wakers) = self.0.wakers.lock() {
+            for waker in wakers.drain(..) {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Whether the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Wait until the token is cancelled
+    pub async fn cancelled(&self) {
+        futures::future::poll_fn(|cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+            if let Ok(mut wakers) = self.0.wakers.lock() {
+                if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                    wakers.push(cx.waker().clone());
+                }
+            }
+            // the token may have been cancelled before the waker was registered
+            if self.is_cancelled() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+/// Tokens are equal if they are clones of each other
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancellationToken;
+
+    #[smol_potat::test]
+    async fn cancellation_token() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+        assert_ne!(token, CancellationToken::new());
+
+        let clone = token.clone();
+        assert_eq!(token, clone);
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            clone.cancel();
+        });
+        token.cancelled().await;
+        assert!(token.is_cancelled());
+        token.cancelled().await;
+    }
+}
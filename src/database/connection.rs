@@ -1,8 +1,9 @@
 use crate::{
-    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError,
+    CopyOutStream, DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult,
+    ReadOnlyTransaction, RetryPolicy, Statement, TransactionError,
 };
 use futures::Stream;
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, time::Duration};
 
 /// The generic API for a database connection that can perform query or execute statements.
 /// It abstracts database connection and transaction
@@ -24,6 +25,33 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [Statement] and return a collection Vec<[QueryResult]> on success
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
 
+    /// Run several independent queries one by one, returning the rows of each,
+    /// see [`Pipeline`](crate::Pipeline).
+    async fn query_pipeline(&self, stmts: Vec<Statement>) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+        let mut results = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            results.push(self.query_all(stmt).await?);
+        }
+        Ok(results)
+    }
+
+    /// Run a `COPY .. FROM STDIN` statement, sending the chunks of data in the format given by
+    /// the statement, and return the number of rows copied. Only supported by PostgreSQL,
+    /// see [`EntityTrait::copy_in`](crate::EntityTrait::copy_in).
+    async fn copy_in_raw(
+        &self,
+        _statement: &str,
+        _data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        Err(copy_not_supported())
+    }
+
+    /// Run a `COPY .. TO STDOUT` statement, streaming the data in the format given by
+    /// the statement. Only supported by PostgreSQL, see [`Select::copy_out`](crate::Select::copy_out).
+    async fn copy_out_raw(&self, _statement: &str) -> Result<CopyOutStream<'_>, DbErr> {
+        Err(copy_not_supported())
+    }
+
     /// Check if the connection supports `RETURNING` syntax on insert and update
     fn support_returning(&self) -> bool {
         let db_backend = self.get_database_backend();
@@ -36,6 +64,10 @@ pub trait ConnectionTrait: Sync {
     }
 }
 
+pub(crate) fn copy_not_supported() -> DbErr {
+    DbErr::Custom("COPY is only supported by PostgreSQL".to_owned())
+}
+
 /// Stream query results
 pub trait StreamTrait: Send + Sync {
     /// Create a stream for the [QueryResult]
@@ -92,6 +124,60 @@ impl std::fmt::Display for AccessMode {
     }
 }
 
+/// The options of a transaction, see [`TransactionTrait::begin_with_options`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionConfig {
+    pub(crate) isolation_level: Option<IsolationLevel>,
+    pub(crate) access_mode: Option<AccessMode>,
+    pub(crate) statement_timeout: Option<Duration>,
+    pub(crate) lock_timeout: Option<Duration>,
+    pub(crate) settings: Vec<(String, String)>,
+}
+
+impl TransactionConfig {
+    /// Create a config with the default options of the database
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the isolation level of the transaction
+    pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = Some(isolation_level);
+        self
+    }
+
+    /// Set the access mode of the transaction
+    pub fn access_mode(mut self, access_mode: AccessMode) -> Self {
+        self.access_mode = Some(access_mode);
+        self
+    }
+
+    /// Abort any statement of the transaction that runs longer than this.
+    /// Sets `statement_timeout` on PostgreSQL, and `max_execution_time` on MySQL, which only applies to `SELECT` statements.
+    pub fn statement_timeout(mut self, timeout: Duration) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
+
+    /// Abort any statement of the transaction that waits longer than this for a lock.
+    /// Sets `lock_timeout` on PostgreSQL, and `innodb_lock_wait_timeout` on MySQL, rounded up to whole seconds.
+    pub fn lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
+    /// Change a setting until the transaction ends, see [`DatabaseTransaction::set_local`].
+    /// This is only supported by PostgreSQL.
+    pub fn set_local<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.settings.push((name.into(), value.into()));
+        self
+    }
+}
+
 /// Spawn database transaction
 #[async_trait::async_trait]
 pub trait TransactionTrait {
@@ -133,4 +219,228 @@ pub trait TransactionTrait {
             + Send,
         T: Send,
         E: std::error::Error + Send;
+
+    /// Execute SQL `BEGIN` transaction with `SET TRANSACTION READ ONLY`.
+    /// The returned [`ReadOnlyTransaction`] can only run queries, which is checked at compile time.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let txn = db.begin_read_only().await?;
+    /// let cakes = cake::Entity::find().all(&txn).await?;
+    /// txn.commit().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn begin_read_only(&self) -> Result<ReadOnlyTransaction, DbErr> {
+        let transaction = self
+            .begin_with_config(None, Some(AccessMode::ReadOnly))
+            .await?;
+        Ok(ReadOnlyTransaction::new(transaction))
+    }
+
+    /// Execute SQL `BEGIN` transaction with the given options, which apply until the transaction ends.
+    ///
+    /// On PostgreSQL, the timeouts are set with `SET LOCAL`. MySQL has no transaction scoped settings,
+    /// so they are set for the session and reset to their defaults when the transaction is committed
+    /// or rolled back explicitly.
+    /// SQLite does not support timeouts.
+    /// Other settings, e.g. the ones read by row-level security policies, are only supported by PostgreSQL.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 0 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 0 },
+    /// #     ])
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    /// use std::time::Duration;
+    ///
+    /// let config = TransactionConfig::new()
+    ///     .statement_timeout(Duration::from_secs(5))
+    ///     .lock_timeout(Duration::from_secs(1));
+    ///
+    /// let txn = db.begin_with_options(config).await?;
+    /// let cakes = cake::Entity::find().all(&txn).await?;
+    /// txn.commit().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn begin_with_options(
+        &self,
+        config: TransactionConfig,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let mut transaction = self
+            .begin_with_config(config.isolation_level, config.access_mode)
+            .await?;
+        transaction.set_timeouts(&config).await?;
+        for (name, value) in &config.settings {
+            transaction.set_local(name, value).await?;
+        }
+        Ok(transaction)
+    }
+
+    /// Execute the function inside a transaction with the given options, see [`TransactionTrait::begin_with_options`].
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    async fn transaction_with_options<F, T, E>(
+        &self,
+        callback: F,
+        config: TransactionConfig,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        let transaction = self
+            .begin_with_options(config)
+            .await
+            .map_err(TransactionError::Connection)?;
+        transaction.run(callback).await
+    }
+
+    /// Execute the function inside a transaction, and run it again in a new transaction if it fails
+    /// because of a serialization failure or a deadlock, which are expected under the `SERIALIZABLE`
+    /// isolation level. Retries are delayed by an exponential backoff, see [`RetryPolicy`].
+    ///
+    /// The function may run several times, so it should not have side effects outside of the transaction.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), TransactionError<DbErr>> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let policy = RetryPolicy::new()
+    ///     .max_attempts(3)
+    ///     .isolation_level(IsolationLevel::Serializable);
+    ///
+    /// let cakes = db
+    ///     .transaction_with_retry::<_, _, DbErr>(policy, |txn| {
+    ///         Box::pin(async move { cake::Entity::find().all(txn).await })
+    ///     })
+    ///     .await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn transaction_with_retry<F, T, E>(
+        &self,
+        policy: RetryPolicy,
+        callback: F,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> Fn(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send
+            + Sync,
+        T: Send,
+        E: std::error::Error + Send + 'static,
+    {
+        let mut attempt = 0;
+        loop {
+            let transaction = self
+                .begin_with_config(policy.isolation_level, policy.access_mode)
+                .await
+                .map_err(TransactionError::Connection)?;
+            match transaction.run(&callback).await {
+                Err(err) if policy.should_retry(attempt, &err) => {
+                    super::runtime::sleep(policy.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Execute the statements in order inside a transaction, returning the result of each of them.
+    /// If a statement fails, the transaction is rolled back and the error is returned.
+    /// Inside a transaction, a savepoint is used instead.
+    ///
+    /// The statements are not batched: each of them takes a round trip to the database, as do
+    /// the `BEGIN` and `COMMIT` around them. It saves writing the transaction, not round trips.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 3 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let backend = db.get_database_backend();
+    /// let delete_fruits = fruit::Entity::delete_many().filter(fruit::Column::CakeId.eq(1));
+    /// let delete_cake = cake::Entity::delete_by_id(1);
+    /// let results = db
+    ///     .execute_all([
+    ///         backend.build(&delete_fruits.into_query()),
+    ///         backend.build(&delete_cake.into_query()),
+    ///     ])
+    ///     .await?;
+    ///
+    /// assert_eq!(results[0].rows_affected(), 3);
+    /// assert_eq!(results[1].rows_affected(), 1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn execute_all<I>(&self, stmts: I) -> Result<Vec<ExecResult>, DbErr>
+    where
+        I: IntoIterator<Item = Statement> + Send,
+        I::IntoIter: Send,
+    {
+        let transaction = self.begin().await?;
+        let mut results = Vec::new();
+        for stmt in stmts {
+            match transaction.execute(stmt).await {
+                Ok(res) => results.push(res),
+                Err(err) => {
+                    transaction.rollback().await?;
+                    return Err(err);
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(results)
+    }
 }
@@ -10,7 +10,6 @@ use url::Url;
 #[cfg(feature = "sqlx-dep")]
 use sqlx::pool::PoolConnection;
 
-#[cfg(any(feature = "mock", feature = "proxy"))]
 use std::sync::Arc;
 
 /// Handle a database connection depending on the backend enabled by the feature
@@ -38,6 +37,13 @@ pub enum DatabaseConnection {
     #[cfg(feature = "proxy")]
     ProxyDatabaseConnection(Arc<crate::ProxyDatabaseConnection>),
 
+    /// Create a connection to a custom database driver
+    #[cfg(feature = "proxy")]
+    DriverConnection(Arc<crate::DriverConnection>),
+
+    /// A primary database with read replicas
+    ReplicatedConnection(Arc<crate::ReplicatedConnection>),
+
     /// The connection to the database has been severed
     Disconnected,
 }
@@ -78,6 +84,8 @@ pub(crate) enum InnerConnection {
     Mock(Arc<crate::MockDatabaseConnection>),
     #[cfg(feature = "proxy")]
     Proxy(Arc<crate::ProxyDatabaseConnection>),
+    #[cfg(feature = "proxy")]
+    Driver(Vec<Arc<dyn crate::DatabaseDriver>>),
 }
 
 impl std::fmt::Debug for DatabaseConnection {
@@ -96,6 +104,9 @@ impl std::fmt::Debug for DatabaseConnection {
                 Self::MockDatabaseConnection(_) => "MockDatabaseConnection",
                 #[cfg(feature = "proxy")]
                 Self::ProxyDatabaseConnection(_) => "ProxyDatabaseConnection",
+                #[cfg(feature = "proxy")]
+                Self::DriverConnection(_) => "DriverConnection",
+                Self::ReplicatedConnection(_) => "ReplicatedConnection",
                 Self::Disconnected => "Disconnected",
             }
         )
@@ -116,6 +127,9 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::MockDatabaseConnection(conn) => conn.get_database_backend(),
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.get_database_backend(),
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.get_database_backend(),
+            DatabaseConnection::ReplicatedConnection(conn) => conn.primary().get_database_backend(),
             DatabaseConnection::Disconnected => panic!("Disconnected"),
         }
     }
@@ -134,6 +148,9 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::MockDatabaseConnection(conn) => conn.execute(stmt),
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.execute(stmt),
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.execute(stmt).await,
+            DatabaseConnection::ReplicatedConnection(conn) => conn.primary().execute(stmt).await,
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -164,6 +181,11 @@ impl ConnectionTrait for DatabaseConnection {
                 let stmt = Statement::from_string(db_backend, sql);
                 conn.execute(stmt)
             }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.execute_unprepared(sql).await,
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                conn.primary().execute_unprepared(sql).await
+            }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -182,6 +204,17 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::MockDatabaseConnection(conn) => conn.query_one(stmt),
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.query_one(stmt),
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.query_one(stmt).await,
+            DatabaseConnection::ReplicatedConnection(conn) => match conn.reader(&stmt) {
+                Some((index, replica)) => {
+                    let started = std::time::Instant::now();
+                    let res = replica.query_one(stmt).await;
+                    conn.record_latency(index, started);
+                    res
+                }
+                None => conn.primary().query_one(stmt).await,
+            },
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -200,13 +233,86 @@ impl ConnectionTrait for DatabaseConnection {
             DatabaseConnection::MockDatabaseConnection(conn) => conn.query_all(stmt),
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.query_all(stmt),
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.query_all(stmt).await,
+            DatabaseConnection::ReplicatedConnection(conn) => match conn.reader(&stmt) {
+                Some((index, replica)) => {
+                    let started = std::time::Instant::now();
+                    let res = replica.query_all(stmt).await;
+                    conn.record_latency(index, started);
+                    res
+                }
+                None => conn.primary().query_all(stmt).await,
+            },
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
 
+    #[instrument(level = "trace")]
+    async fn query_pipeline(&self, stmts: Vec<Statement>) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.query_pipeline(stmts).await
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                Box::pin(conn.primary().query_pipeline(stmts)).await
+            }
+            _ => {
+                let mut results = Vec::with_capacity(stmts.len());
+                for stmt in stmts {
+                    results.push(self.query_all(stmt).await?);
+                }
+                Ok(results)
+            }
+        }
+    }
+
+    #[instrument(level = "trace", skip(data))]
+    #[allow(unused_variables)]
+    async fn copy_in_raw(
+        &self,
+        statement: &str,
+        data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.copy_in_raw(statement, data).await
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                Box::pin(conn.primary().copy_in_raw(statement, data)).await
+            }
+            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            #[allow(unreachable_patterns)]
+            _ => Err(crate::copy_not_supported()),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    #[allow(unused_variables)]
+    async fn copy_out_raw(&self, statement: &str) -> Result<crate::CopyOutStream<'_>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.copy_out_raw(statement).await
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                Box::pin(conn.primary().copy_out_raw(statement)).await
+            }
+            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+            #[allow(unreachable_patterns)]
+            _ => Err(crate::copy_not_supported()),
+        }
+    }
+
     #[cfg(feature = "mock")]
     fn is_mock_connection(&self) -> bool {
-        matches!(self, DatabaseConnection::MockDatabaseConnection(_))
+        match self {
+            DatabaseConnection::MockDatabaseConnection(_) => true,
+            DatabaseConnection::ReplicatedConnection(conn) => conn.primary().is_mock_connection(),
+            _ => false,
+        }
     }
 }
 
@@ -236,6 +342,12 @@ impl StreamTrait for DatabaseConnection {
                 DatabaseConnection::ProxyDatabaseConnection(conn) => {
                     Ok(crate::QueryStream::from((Arc::clone(conn), stmt, None)))
                 }
+                #[cfg(feature = "proxy")]
+                DatabaseConnection::DriverConnection(conn) => Ok(conn.stream(stmt)),
+                DatabaseConnection::ReplicatedConnection(conn) => match conn.reader(&stmt) {
+                    Some((_, replica)) => replica.stream(stmt).await,
+                    None => conn.primary().stream(stmt).await,
+                },
                 DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
             }
         })
@@ -261,6 +373,9 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::ProxyDatabaseConnection(conn) => {
                 DatabaseTransaction::new_proxy(conn.clone(), None).await
             }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.begin(None, None).await,
+            DatabaseConnection::ReplicatedConnection(conn) => conn.primary().begin().await,
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -292,6 +407,15 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::ProxyDatabaseConnection(conn) => {
                 DatabaseTransaction::new_proxy(conn.clone(), None).await
             }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => {
+                conn.begin(_isolation_level, _access_mode).await
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                conn.primary()
+                    .begin_with_config(_isolation_level, _access_mode)
+                    .await
+            }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -335,6 +459,17 @@ impl TransactionTrait for DatabaseConnection {
                     .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
             }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => {
+                let transaction = conn
+                    .begin(None, None)
+                    .await
+                    .map_err(TransactionError::Connection)?;
+                transaction.run(_callback).await
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                conn.primary().transaction(_callback).await
+            }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected").into()),
         }
     }
@@ -386,6 +521,19 @@ impl TransactionTrait for DatabaseConnection {
                     .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
             }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => {
+                let transaction = conn
+                    .begin(_isolation_level, _access_mode)
+                    .await
+                    .map_err(TransactionError::Connection)?;
+                transaction.run(_callback).await
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                conn.primary()
+                    .transaction_with_config(_callback, _isolation_level, _access_mode)
+                    .await
+            }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected").into()),
         }
     }
@@ -436,7 +584,11 @@ impl DatabaseConnection {
 }
 
 impl DatabaseConnection {
-    /// Sets a callback to metric this connection
+    /// Sets a callback to metric this connection.
+    ///
+    /// For a [`crate::ReplicatedConnection`], the callback is set on its primary and replicas,
+    /// which is only possible before the connection is cloned: on a clone sharing them, it's
+    /// not set, and a warning is logged.
     pub fn set_metric_callback<F>(&mut self, _callback: F)
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
@@ -454,6 +606,57 @@ impl DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
                 conn.set_metric_callback(_callback)
             }
+            DatabaseConnection::ReplicatedConnection(conn) => match Arc::get_mut(conn) {
+                Some(conn) => conn.set_metric_callback(_callback),
+                None => tracing::warn!(
+                    "The metric callback isn't set on a replicated connection which is shared"
+                ),
+            },
+            _ => {}
+        }
+    }
+
+    /// Get the statistics of the connection pool, `None` if the connection is not a sqlx pool.
+    /// For a [`crate::ReplicatedConnection`], get the statistics of its primary and replicas instead.
+    pub fn pool_stats(&self) -> Option<crate::metric::PoolStats> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => Some(conn.pool_stats()),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => Some(conn.pool_stats()),
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => Some(conn.pool_stats()),
+            _ => None,
+        }
+    }
+
+    /// Sets a callback receiving the events of the connection pool, replacing the previous one.
+    /// Connections released back to the pool are only reported for pools created by [`crate::Database::connect`].
+    pub fn set_pool_callback<F>(&self, callback: F)
+    where
+        F: Fn(&crate::metric::PoolEvent) + Send + Sync + 'static,
+    {
+        self.set_pool_callback_arc(Arc::new(callback))
+    }
+
+    fn set_pool_callback_arc(
+        &self,
+        _callback: Arc<dyn Fn(&crate::metric::PoolEvent) + Send + Sync>,
+    ) {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.set_pool_callback(_callback),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.set_pool_callback(_callback)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.set_pool_callback(_callback),
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                for conn in std::iter::once(conn.primary()).chain(conn.replicas()) {
+                    conn.set_pool_callback_arc(Arc::clone(&_callback));
+                }
+            }
             _ => {}
         }
     }
@@ -471,10 +674,125 @@ impl DatabaseConnection {
             DatabaseConnection::MockDatabaseConnection(conn) => conn.ping(),
             #[cfg(feature = "proxy")]
             DatabaseConnection::ProxyDatabaseConnection(conn) => conn.ping(),
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.ping().await,
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                Box::pin(conn.primary().ping()).await?;
+                for replica in conn.replicas() {
+                    Box::pin(replica.ping()).await?;
+                }
+                Ok(())
+            }
+            DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
+        }
+    }
+
+    /// Begin a transaction that can be prepared for a two-phase commit with
+    /// [`DatabaseTransaction::prepare`], to coordinate writes across several databases.
+    /// `gid` is the global transaction identifier, of up to 64 ASCII letters, digits, `-`, `_`, `.` or `:`.
+    ///
+    /// This uses `PREPARE TRANSACTION` on PostgreSQL, which requires `max_prepared_transactions` to be
+    /// set, and an XA transaction on MySQL. An XA transaction can not be nested, and has to be prepared,
+    /// committed or rolled back explicitly before it is dropped.
+    /// SQLite does not support two-phase commits.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 1 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 0 },
+    /// #         MockExecResult { last_insert_id: 0, rows_affected: 0 },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let txn = db.begin_two_phase("order-42").await?;
+    /// cake::Entity::delete_by_id(1).exec(&txn).await?;
+    /// txn.prepare().await?;
+    ///
+    /// // once every participant is prepared
+    /// db.commit_prepared("order-42").await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_two_phase(&self, gid: &str) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.begin_two_phase(gid).await,
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.begin_two_phase(gid).await,
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(_) => Err(DbErr::Custom(
+                "SQLite does not support two-phase commit".to_owned(),
+            )),
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                DatabaseTransaction::begin_two_phase(
+                    Arc::new(futures::lock::Mutex::new(InnerConnection::Mock(
+                        Arc::clone(conn),
+                    ))),
+                    conn.get_database_backend(),
+                    None,
+                    gid,
+                )
+                .await
+            }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::ProxyDatabaseConnection(conn) => {
+                DatabaseTransaction::begin_two_phase(
+                    Arc::new(futures::lock::Mutex::new(InnerConnection::Proxy(
+                        Arc::clone(conn),
+                    ))),
+                    conn.get_database_backend(),
+                    None,
+                    gid,
+                )
+                .await
+            }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(conn) => conn.begin_two_phase(gid).await,
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                Box::pin(conn.primary().begin_two_phase(gid)).await
+            }
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
 
+    /// Commit a transaction prepared by [`DatabaseTransaction::prepare`]
+    pub async fn commit_prepared(&self, gid: &str) -> Result<(), DbErr> {
+        self.end_prepared(gid, "COMMIT PREPARED", "XA COMMIT").await
+    }
+
+    /// Roll back a transaction prepared by [`DatabaseTransaction::prepare`]
+    pub async fn rollback_prepared(&self, gid: &str) -> Result<(), DbErr> {
+        self.end_prepared(gid, "ROLLBACK PREPARED", "XA ROLLBACK")
+            .await
+    }
+
+    async fn end_prepared(&self, gid: &str, postgres: &str, mysql: &str) -> Result<(), DbErr> {
+        let xid = crate::xid_literal(gid)?;
+        let command = match self.get_database_backend() {
+            DbBackend::Postgres => postgres,
+            DbBackend::MySql => mysql,
+            DbBackend::Sqlite => {
+                return Err(DbErr::Custom(
+                    "SQLite does not support two-phase commit".to_owned(),
+                ))
+            }
+        };
+        self.execute_unprepared(&format!("{command} {xid}"))
+            .await
+            .map(|_| ())
+    }
+
     /// Explicitly close the database connection
     pub async fn close(self) -> Result<(), DbErr> {
         match self {
@@ -494,6 +812,16 @@ impl DatabaseConnection {
                 // Nothing to cleanup, we just consume the `DatabaseConnection`
                 Ok(())
             }
+            #[cfg(feature = "proxy")]
+            DatabaseConnection::DriverConnection(_) => {
+                // Nothing to cleanup, we just consume the `DatabaseConnection`
+                Ok(())
+            }
+            DatabaseConnection::ReplicatedConnection(conn) => match Arc::try_unwrap(conn) {
+                Ok(conn) => Box::pin(conn.close()).await,
+                // The connections are still used by a clone
+                Err(_) => Ok(()),
+            },
             DatabaseConnection::Disconnected => Err(conn_err("Disconnected")),
         }
     }
@@ -0,0 +1,247 @@
+use crate::{
+    error::*, AccessMode, DbBackend, IsolationLevel, ProxyExecResult, ProxyRow, Statement,
+};
+use futures::{Stream, StreamExt, TryStreamExt};
+use std::{fmt::Debug, pin::Pin};
+
+/// The rows streamed by a [`DatabaseDriver`]
+pub type DriverStream<'a> = Pin<Box<dyn Stream<Item = Result<ProxyRow, DbErr>> + Send + 'a>>;
+
+/// A database driver implemented outside of this crate, e.g. for an HTTP data API or an embedded
+/// engine, see [`crate::Database::connect_driver`]. Entities and queries are built for the
+/// [`DbBackend`] of the driver, and the rows it returns are decoded like the ones of a proxy.
+///
+/// A transaction is run by the driver returned by [`DatabaseDriver::begin`], until it is
+/// committed or rolled back. Beginning a transaction on that driver begins a nested one,
+/// e.g. with a savepoint. A transaction which is dropped without being committed or rolled back
+/// is dropped as well, so the driver should roll it back when it is dropped.
+///
+/// ```
+/// # use sea_orm::{error::*, *};
+/// #[derive(Debug)]
+/// struct DataApi;
+///
+/// #[async_trait::async_trait]
+/// impl DatabaseDriver for DataApi {
+///     fn get_database_backend(&self) -> DbBackend {
+///         DbBackend::Postgres
+///     }
+///
+///     async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+///         // send the statement to the API
+///         # unimplemented!()
+///     }
+///
+///     async fn query_all(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+///         # unimplemented!()
+///     }
+///
+///     async fn begin(
+///         &self,
+///         isolation_level: Option<IsolationLevel>,
+///         access_mode: Option<AccessMode>,
+///     ) -> Result<Box<dyn DatabaseDriver>, DbErr> {
+///         // open a transaction, and return a driver running its statements
+///         # unimplemented!()
+///     }
+/// }
+///
+/// # async fn connect() -> Result<(), DbErr> {
+/// let db = Database::connect_driver(DataApi).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait DatabaseDriver: Debug + Send + Sync {
+    /// Get the backend the statements are built for
+    fn get_database_backend(&self) -> DbBackend;
+
+    /// Execute a statement
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr>;
+
+    /// Execute a SQL string without parameters, e.g. a migration with several statements
+    async fn execute_unprepared(&self, sql: &str) -> Result<ProxyExecResult, DbErr> {
+        self.execute(Statement::from_string(self.get_database_backend(), sql))
+            .await
+    }
+
+    /// Run a query, returning its first row
+    async fn query_one(&self, statement: Statement) -> Result<Option<ProxyRow>, DbErr> {
+        Ok(self.query_all(statement).await?.into_iter().next())
+    }
+
+    /// Run a query, returning all its rows
+    async fn query_all(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr>;
+
+    /// Run a query, streaming its rows. The rows are all fetched at once unless this is overridden.
+    fn stream(&self, statement: Statement) -> DriverStream<'_> {
+        futures::stream::once(async move {
+            self.query_all(statement)
+                .await
+                .map(|rows| futures::stream::iter(rows.into_iter().map(Ok)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    /// Begin a transaction, or a nested transaction if this driver runs a transaction,
+    /// returning the driver running it
+    async fn begin(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<Box<dyn DatabaseDriver>, DbErr>;
+
+    /// Commit the transaction this driver runs
+    async fn commit(&self) -> Result<(), DbErr> {
+        Err(conn_err("Not in a transaction"))
+    }
+
+    /// Roll back the transaction this driver runs
+    async fn rollback(&self) -> Result<(), DbErr> {
+        Err(conn_err("Not in a transaction"))
+    }
+
+    /// Check if the database can be reached
+    async fn ping(&self) -> Result<(), DbErr> {
+        self.query_one(Statement::from_string(
+            self.get_database_backend(),
+            "SELECT 1",
+        ))
+        .await
+        .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tests-cfg")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, tests_cfg::*, ConnectionTrait, Database, StreamTrait, TransactionTrait,
+    };
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+
+    /// Logs the statements, prefixed by the depth of the transaction running them
+    #[derive(Debug, Default)]
+    struct Driver {
+        depth: usize,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Driver {
+        fn log(&self, sql: String) -> Result<(), DbErr> {
+            self.log
+                .lock()
+                .map_err(|e| conn_err(e.to_string()))?
+                .push(format!("{} {sql}", self.depth));
+            Ok(())
+        }
+    }
+
+    impl Drop for Driver {
+        fn drop(&mut self) {
+            if self.depth > 0 {
+                let _ = self.log("dropped".to_owned());
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl DatabaseDriver for Driver {
+        fn get_database_backend(&self) -> DbBackend {
+            DbBackend::Postgres
+        }
+
+        async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+            self.log(statement.sql)?;
+            Ok(ProxyExecResult::new(0, 1))
+        }
+
+        async fn query_all(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+            self.log(statement.sql)?;
+            Ok(vec![ProxyRow::new(BTreeMap::from([
+                ("id".to_owned(), 1i64.into()),
+                ("name".to_owned(), "Cheese".into()),
+            ]))])
+        }
+
+        async fn begin(
+            &self,
+            _: Option<IsolationLevel>,
+            _: Option<AccessMode>,
+        ) -> Result<Box<dyn DatabaseDriver>, DbErr> {
+            self.log("begin".to_owned())?;
+            Ok(Box::new(Driver {
+                depth: self.depth + 1,
+                log: Arc::clone(&self.log),
+            }))
+        }
+
+        async fn commit(&self) -> Result<(), DbErr> {
+            self.log("commit".to_owned())
+        }
+
+        async fn rollback(&self) -> Result<(), DbErr> {
+            self.log("rollback".to_owned())
+        }
+    }
+
+    #[smol_potat::test]
+    async fn database_driver() -> Result<(), DbErr> {
+        let log = Arc::default();
+        let db = Database::connect_driver(Driver {
+            depth: 0,
+            log: Arc::clone(&log),
+        })
+        .await?;
+
+        let cheese = cake::Model {
+            id: 1,
+            name: "Cheese".to_owned(),
+        };
+        assert_eq!(cake::Entity::find().one(&db).await?, Some(cheese.clone()));
+
+        let txn = db.begin().await?;
+        cake::Entity::delete_by_id(2).exec(&txn).await?;
+        {
+            let nested = txn.begin().await?;
+            let stream = cake::Entity::find().stream(&nested).await?;
+            assert_eq!(stream.try_collect::<Vec<_>>().await?, [cheese]);
+            nested.rollback().await?;
+        }
+        {
+            let nested = txn.begin().await?;
+            nested.execute_unprepared("TRUNCATE cake").await?;
+        }
+        txn.commit().await?;
+        assert!(db
+            .stream(Statement::from_string(DbBackend::Postgres, "SELECT 1"))
+            .await
+            .is_ok());
+
+        assert_eq!(
+            *log.lock().map_err(|e| conn_err(e.to_string()))?,
+            [
+                r#"0 SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                "0 begin",
+                r#"1 DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                "1 begin",
+                r#"2 SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                "2 rollback",
+                "2 dropped",
+                "1 begin",
+                "2 TRUNCATE cake",
+                "2 dropped",
+                "1 commit",
+                "1 dropped",
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    metric::PoolStats, ConnectionTrait, DatabaseConnection, DbBackend, DbErr, QueryResult,
+    Statement,
+};
+use std::time::{Duration, Instant};
+
+/// The health of a database, see [`DatabaseConnection::health_check`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HealthCheck {
+    /// The database backend
+    pub backend: DbBackend,
+    /// The time a ping to the database took
+    pub latency: Duration,
+    /// The version of the database server, e.g. `16.2` for PostgreSQL
+    pub version: String,
+    /// How far behind its primary the database is, `None` if it is not a replica or the lag is
+    /// unknown. On PostgreSQL, this is the time since the last replayed transaction, and on MySQL
+    /// the `Seconds_Behind_Source` of the replica status.
+    pub replication_lag: Option<Duration>,
+    /// The statistics of the connection pool, `None` if the connection is not a sqlx pool
+    pub pool: Option<PoolStats>,
+    /// The health of each replica of a [`crate::ReplicatedConnection`], whose primary is
+    /// described by the other fields
+    pub replicas: Vec<HealthCheck>,
+}
+
+impl DatabaseConnection {
+    /// Check the health of the database, e.g. for a readiness endpoint.
+    /// Fails if the database cannot be reached, or if its version cannot be queried.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([
+    /// #         [BTreeMap::from([("version".to_owned(), Value::from("16.2"))])],
+    /// #         [BTreeMap::from([("lag".to_owned(), Value::Double(None))])],
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// let health = db.health_check().await?;
+    ///
+    /// assert_eq!(health.version, "16.2");
+    /// assert_eq!(health.replication_lag, None);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> Result<HealthCheck, DbErr> {
+        if let DatabaseConnection::ReplicatedConnection(conn) = self {
+            let mut health = Box::pin(conn.primary().health_check()).await?;
+            for replica in conn.replicas() {
+                health
+                    .replicas
+                    .push(Box::pin(replica.health_check()).await?);
+            }
+            return Ok(health);
+        }
+
+        let started = Instant::now();
+        self.ping().await?;
+        let latency = started.elapsed();
+
+        let backend = self.get_database_backend();
+        let sql = match backend {
+            DbBackend::MySql => "SELECT VERSION() AS version",
+            DbBackend::Postgres => "SELECT current_setting('server_version') AS version",
+            DbBackend::Sqlite => "SELECT sqlite_version() AS version",
+        };
+        let version = match self.query_one(Statement::from_string(backend, sql)).await? {
+            Some(row) => row.try_get("", "version")?,
+            None => return Err(DbErr::RecordNotFound("No server version".to_owned())),
+        };
+
+        Ok(HealthCheck {
+            backend,
+            latency,
+            version,
+            replication_lag: self.replication_lag(backend).await,
+            pool: self.pool_stats(),
+            replicas: Vec::new(),
+        })
+    }
+
+    /// The replication lag of a replica, which may not be visible to the user
+    async fn replication_lag(&self, backend: DbBackend) -> Option<Duration> {
+        match backend {
+            DbBackend::MySql => {
+                // `SHOW REPLICA STATUS` replaced `SHOW SLAVE STATUS` in MySQL 8.0.22
+                for (sql, col) in [
+                    ("SHOW REPLICA STATUS", "Seconds_Behind_Source"),
+                    ("SHOW SLAVE STATUS", "Seconds_Behind_Master"),
+                ] {
+                    if let Ok(row) = self.query_one(Statement::from_string(backend, sql)).await {
+                        return row.and_then(|row| seconds(&row, col));
+                    }
+                }
+                None
+            }
+            DbBackend::Postgres => {
+                let sql = "SELECT CASE WHEN pg_is_in_recovery() \
+                    THEN EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp())::float8 END AS lag";
+                let row = self
+                    .query_one(Statement::from_string(backend, sql))
+                    .await
+                    .ok()??;
+                let lag: Option<f64> = row.try_get("", "lag").ok()?;
+                lag.map(|lag| Duration::from_secs_f64(lag.max(0.0)))
+            }
+            DbBackend::Sqlite => None,
+        }
+    }
+}
+
+/// A number of seconds which may be reported as a signed or an unsigned integer
+fn seconds(row: &QueryResult, col: &str) -> Option<Duration> {
+    match row.try_get::<Option<u64>>("", col) {
+        Ok(secs) => secs.map(Duration::from_secs),
+        Err(_) => row
+            .try_get::<Option<i64>>("", col)
+            .ok()?
+            .map(|secs| Duration::from_secs(secs.max(0) as u64)),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{DatabaseConnection, DbBackend, DbErr, MockDatabase, ReplicaStrategy, Value};
+    use std::{collections::BTreeMap, time::Duration};
+
+    fn mock(version: &str, lag: Option<f64>) -> DatabaseConnection {
+        MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [BTreeMap::from([(
+                    "version".to_owned(),
+                    Value::from(version),
+                )])],
+                [BTreeMap::from([("lag".to_owned(), Value::Double(lag))])],
+            ])
+            .into_connection()
+    }
+
+    #[smol_potat::test]
+    async fn health_check() -> Result<(), DbErr> {
+        let db = DatabaseConnection::with_replicas(
+            mock("16.2", None),
+            vec![mock("16.1", Some(1.5))],
+            ReplicaStrategy::RoundRobin,
+        );
+
+        let health = db.health_check().await?;
+        assert_eq!(health.backend, DbBackend::Postgres);
+        assert_eq!(health.version, "16.2");
+        assert_eq!(health.replication_lag, None);
+        assert_eq!(health.pool, None);
+        assert_eq!(health.replicas.len(), 1);
+        assert_eq!(health.replicas[0].version, "16.1");
+        assert_eq!(
+            health.replicas[0].replication_lag,
+            Some(Duration::from_millis(1500))
+        );
+
+        assert!(MockDatabase::new(DbBackend::Postgres)
+            .into_connection()
+            .health_check()
+            .await
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{error::*, ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use futures::Stream;
+use std::{
+    fmt::Display,
+    pin::Pin,
+    str::FromStr,
+    task::{Context, Poll},
+};
+
+/// A notification sent with `NOTIFY` on a PostgreSQL channel, see [`DatabaseConnection::listen`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Notification {
+    /// The channel the notification was sent on
+    pub channel: String,
+    /// The payload of the notification, empty if it has none
+    pub payload: String,
+    /// The process id of the backend which sent the notification
+    pub process_id: u32,
+}
+
+impl Notification {
+    /// Parse the payload, e.g. as the id of a changed row
+    pub fn parse<T>(&self) -> Result<T, DbErr>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.payload.parse().map_err(|e| {
+            DbErr::Type(format!(
+                "Failed to parse the payload of a notification on `{}`: {e}",
+                self.channel
+            ))
+        })
+    }
+
+    /// Parse the payload as JSON, e.g. built with `json_build_object` in a trigger
+    #[cfg(feature = "with-json")]
+    pub fn json<T>(&self) -> Result<T, DbErr>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_str(&self.payload).map_err(|e| DbErr::Json(e.to_string()))
+    }
+}
+
+/// The notifications received on the channels listened to, see [`DatabaseConnection::listen`]
+pub struct NotificationStream {
+    stream: Pin<Box<dyn Stream<Item = Result<Notification, DbErr>> + Send>>,
+}
+
+impl std::fmt::Debug for NotificationStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NotificationStream")
+    }
+}
+
+impl NotificationStream {
+    #[allow(dead_code)]
+    pub(crate) fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Notification, DbErr>> + Send + 'static,
+    {
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl Stream for NotificationStream {
+    type Item = Result<Notification, DbErr>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().stream.as_mut().poll_next(cx)
+    }
+}
+
+impl DatabaseConnection {
+    /// Listen to a PostgreSQL channel, receiving the notifications sent on it with `NOTIFY`,
+    /// e.g. to invalidate a cache when a trigger reports a change.
+    ///
+    /// The notifications are received on a connection of the pool, kept for as long as the stream.
+    /// If the connection is lost, it is reconnected on the next poll of the stream, which yields
+    /// an error if reconnecting fails. The notifications sent while disconnected are lost.
+    /// The stream ends once the pool is closed.
+    ///
+    /// ```no_run
+    /// # use sea_orm::{error::*, *};
+    /// # use futures::TryStreamExt;
+    /// # async fn listen(db: &DatabaseConnection) -> Result<(), DbErr> {
+    /// let mut notifications = db.listen("cake_changed").await?;
+    /// while let Some(notification) = notifications.try_next().await? {
+    ///     let cake_id: i32 = notification.parse()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn listen(&self, channel: &str) -> Result<NotificationStream, DbErr> {
+        self.listen_all([channel]).await
+    }
+
+    /// Listen to several PostgreSQL channels, see [`DatabaseConnection::listen`]
+    pub async fn listen_all<'a, I>(&self, channels: I) -> Result<NotificationStream, DbErr>
+    where
+        I: IntoIterator<Item = &'a str> + Send,
+        I::IntoIter: Send,
+    {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.listen_all(channels).await,
+            DatabaseConnection::ReplicatedConnection(conn) => {
+                Box::pin(conn.primary().listen_all(channels)).await
+            }
+            _ => Err(not_supported()),
+        }
+    }
+
+    /// Send a notification on a PostgreSQL channel. To notify once a transaction is committed,
+    /// run `SELECT pg_notify($1, $2)` in the transaction instead.
+    pub async fn notify(&self, channel: &str, payload: &str) -> Result<(), DbErr> {
+        if self.get_database_backend() != DbBackend::Postgres {
+            return Err(not_supported());
+        }
+        self.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_notify($1, $2)",
+            [channel.into(), payload.into()],
+        ))
+        .await
+        .map(|_| ())
+    }
+}
+
+fn not_supported() -> DbErr {
+    DbErr::Custom("LISTEN/NOTIFY is only supported by PostgreSQL".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Notification;
+    use crate::DbErr;
+
+    #[test]
+    fn notification_payload() -> Result<(), DbErr> {
+        let notification = Notification {
+            channel: "cake_changed".to_owned(),
+            payload: "42".to_owned(),
+            process_id: 1,
+        };
+        assert_eq!(notification.parse::<i32>()?, 42);
+        #[cfg(feature = "with-json")]
+        assert_eq!(notification.json::<serde_json::Value>()?, 42);
+
+        let notification = Notification {
+            payload: "cheese".to_owned(),
+            ..notification
+        };
+        assert!(matches!(notification.parse::<i32>(), Err(DbErr::Type(_))));
+        #[cfg(feature = "with-json")]
+        assert!(matches!(
+            notification.json::<serde_json::Value>(),
+            Err(DbErr::Json(_))
+        ));
+        Ok(())
+    }
+}
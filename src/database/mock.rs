@@ -152,6 +152,7 @@ impl MockDatabaseTrait for MockDatabase {
                     .into_iter()
                     .map(|row| QueryResult {
                         row: QueryResultRow::Mock(row),
+                        timezone: Default::default(),
                     })
                     .collect()),
                 Err(err) => Err(err),
@@ -421,8 +422,8 @@ impl OpenTransaction {
 #[cfg(feature = "mock")]
 mod tests {
     use crate::{
-        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, Statement,
-        Transaction, TransactionError, TransactionTrait,
+        entity::*, error::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase,
+        MockExecResult, Statement, Transaction, TransactionError, TransactionTrait,
     };
     use pretty_assertions::assert_eq;
 
@@ -621,6 +622,366 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_transaction_hooks() -> Result<(), DbErr> {
+        use std::sync::{Arc, Mutex};
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let event = |name: &'static str| {
+            let events = Arc::clone(&events);
+            async move {
+                if let Ok(mut events) = events.lock() {
+                    events.push(name);
+                }
+            }
+        };
+        let events_so_far = || events.lock().map(|e| e.clone()).unwrap_or_default();
+
+        let txn = db.begin().await?;
+        txn.on_commit(event("outer committed"));
+        txn.on_rollback(event("outer rolled back"));
+
+        let nested = txn.begin().await?;
+        nested.on_commit(event("nested committed"));
+        nested.on_rollback(event("nested rolled back"));
+        nested.commit().await?;
+
+        let nested = txn.begin().await?;
+        nested.on_commit(event("discarded"));
+        nested.on_rollback(event("savepoint rolled back"));
+        nested.rollback().await?;
+
+        assert_eq!(events_so_far(), ["savepoint rolled back"]);
+        txn.commit().await?;
+        assert_eq!(
+            events_so_far(),
+            [
+                "savepoint rolled back",
+                "outer committed",
+                "nested committed"
+            ]
+        );
+
+        let txn = db.begin().await?;
+        txn.on_commit(event("discarded"));
+        let nested = txn.begin().await?;
+        nested.on_rollback(event("nested rolled back"));
+        nested.commit().await?;
+        txn.rollback().await?;
+        assert_eq!(events_so_far().last(), Some(&"nested rolled back"));
+        assert_eq!(events_so_far().len(), 4);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_timeouts() -> Result<(), DbErr> {
+        use crate::TransactionConfig;
+        use std::time::Duration;
+
+        let config = TransactionConfig::new()
+            .statement_timeout(Duration::from_millis(1500))
+            .lock_timeout(Duration::from_millis(1500));
+        let exec_results = (0..3).map(|_| MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 0,
+        });
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(exec_results.clone())
+            .into_connection();
+        db.begin_with_options(config.clone())
+            .await?
+            .commit()
+            .await?;
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many(
+                [
+                    "BEGIN",
+                    "SET LOCAL statement_timeout = 1500",
+                    "SET LOCAL lock_timeout = 1500",
+                    "COMMIT",
+                ]
+                .map(|sql| Statement::from_string(DbBackend::Postgres, sql))
+            )]
+        );
+
+        let session = [maplit::btreemap! {
+            "max_execution_time" => sea_query::Value::from(3000i64),
+            "innodb_lock_wait_timeout" => sea_query::Value::from(50i64),
+        }];
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([session.clone()])
+            .append_exec_results(exec_results.clone())
+            .into_connection();
+        db.begin_with_options(config.clone())
+            .await?
+            .rollback()
+            .await?;
+        let session_logs = [
+            vec![
+                "BEGIN",
+                concat!(
+                    "SELECT CAST(@@SESSION.max_execution_time AS SIGNED) AS max_execution_time, ",
+                    "CAST(@@SESSION.innodb_lock_wait_timeout AS SIGNED) AS innodb_lock_wait_timeout"
+                ),
+                "SET SESSION max_execution_time = 1500",
+                "SET SESSION innodb_lock_wait_timeout = 2",
+                "ROLLBACK",
+            ],
+            vec!["SET SESSION max_execution_time = 3000, innodb_lock_wait_timeout = 50"],
+        ];
+        let sqls = |db: crate::DatabaseConnection| {
+            db.into_transaction_log()
+                .into_iter()
+                .map(|txn| txn.stmts.into_iter().map(|stmt| stmt.sql).collect())
+                .collect::<Vec<Vec<_>>>()
+        };
+        assert_eq!(sqls(db), session_logs);
+
+        // the session settings are restored as well when the transaction is dropped
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([session])
+            .append_exec_results(exec_results)
+            .into_connection();
+        drop(db.begin_with_options(config).await?);
+        assert_eq!(sqls(db), session_logs);
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_settings() -> Result<(), DbErr> {
+        use crate::TransactionConfig;
+
+        let config = TransactionConfig::new()
+            .set_local("role", "tenant")
+            .set_local("app.current_tenant", "42");
+        let exec_results = (0..3).map(|_| MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 1,
+        });
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(exec_results.clone())
+            .into_connection();
+        db.transaction_with_options::<_, _, DbErr>(
+            |txn| {
+                Box::pin(async move {
+                    cake::Entity::delete_many().exec(txn).await?;
+                    Ok(())
+                })
+            },
+            config.clone(),
+        )
+        .await
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+        let set_config = |name: &str, value: &str| {
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT set_config($1, $2, true)",
+                [name.into(), value.into()],
+            )
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                set_config("role", "tenant"),
+                set_config("app.current_tenant", "42"),
+                Statement::from_sql_and_values(DbBackend::Postgres, r#"DELETE FROM "cake""#, []),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ])]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(exec_results)
+            .into_connection();
+        assert!(db.begin_with_options(config).await.is_err());
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_execute_all() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 3,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .append_exec_errors([DbErr::Custom("failed".to_owned())])
+            .into_connection();
+        let stmts = || {
+            [
+                Statement::from_string(DbBackend::Postgres, "DELETE FROM \"fruit\""),
+                Statement::from_string(DbBackend::Postgres, "DELETE FROM \"cake\""),
+            ]
+        };
+
+        let results = db.execute_all(stmts()).await?;
+        assert_eq!(
+            results
+                .iter()
+                .map(|res| res.rows_affected())
+                .collect::<Vec<_>>(),
+            [3, 1]
+        );
+        assert!(db.execute_all(stmts()).await.is_err());
+
+        let [delete_fruit, delete_cake] = stmts();
+        let begin = Statement::from_string(DbBackend::Postgres, "BEGIN");
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::many([
+                    begin.clone(),
+                    delete_fruit.clone(),
+                    delete_cake,
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+                Transaction::many([
+                    begin,
+                    delete_fruit,
+                    Statement::from_string(DbBackend::Postgres, "ROLLBACK"),
+                ]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_two_phase_commit_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .into_connection();
+
+        assert!(db.begin_two_phase("it's").await.is_err());
+        assert!(db.begin().await?.prepare().await.is_err());
+
+        let txn = db.begin_two_phase("order-42").await?;
+        cake::Entity::delete_by_id(1).exec(&txn).await?;
+        txn.prepare().await?;
+        db.commit_prepared("order-42").await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_string(DbBackend::Postgres, "ROLLBACK"),
+                ]),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                        [1i32.into()]
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "PREPARE TRANSACTION 'order-42'"),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+                Transaction::one(Statement::from_string(
+                    DbBackend::Postgres,
+                    "COMMIT PREPARED 'order-42'"
+                )),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_two_phase_commit_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results((0..8).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }))
+            .into_connection();
+
+        let txn = db.begin_two_phase("order-42").await?;
+        assert!(txn.begin().await.is_err());
+        txn.prepare().await?;
+        db.rollback_prepared("order-42").await?;
+
+        let txn = db.begin_two_phase("order-43").await?;
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            Transaction::wrap(
+                [
+                    "XA START 'order-42'",
+                    "XA END 'order-42'",
+                    "XA PREPARE 'order-42'",
+                    "XA ROLLBACK 'order-42'",
+                    "XA START 'order-43'",
+                    "XA END 'order-43'",
+                    "XA COMMIT 'order-43' ONE PHASE",
+                ]
+                .map(|sql| Statement::from_string(DbBackend::MySql, sql))
+            )
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_two_phase_drop_mysql() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results((0..4).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+
+        {
+            let txn = db.begin_two_phase("order-44").await?;
+            cake::Entity::delete_by_id(1).exec(&txn).await?;
+        }
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::one(Statement::from_string(
+                    DbBackend::MySql,
+                    "XA START 'order-44'"
+                )),
+                Transaction::one(Statement::from_sql_and_values(
+                    DbBackend::MySql,
+                    "DELETE FROM `cake` WHERE `cake`.`id` = ?",
+                    [1i32.into()]
+                )),
+                Transaction::one(Statement::from_string(
+                    DbBackend::MySql,
+                    "XA END 'order-44'"
+                )),
+                Transaction::one(Statement::from_string(
+                    DbBackend::MySql,
+                    "XA ROLLBACK 'order-44'"
+                )),
+            ]
+        );
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_stream_1() -> Result<(), DbErr> {
         use futures::TryStreamExt;
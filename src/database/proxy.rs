@@ -83,6 +83,10 @@ impl From<ExecResult> for ProxyExecResult {
                 rows_affected: result.rows_affected,
             },
             ExecResultHolder::Proxy(result) => result,
+            ExecResultHolder::DryRun => Self {
+                last_insert_id: 0,
+                rows_affected: 0,
+            },
         }
     }
 }
@@ -132,6 +136,7 @@ impl From<ProxyRow> for QueryResult {
     fn from(row: ProxyRow) -> Self {
         QueryResult {
             row: QueryResultRow::Proxy(row),
+            timezone: Default::default(),
         }
     }
 }
@@ -170,20 +175,19 @@ impl ProxyRow {
         T: ValueType,
     {
         if let Some(index) = index.as_str() {
-            T::try_from(
+            try_from_value(
                 self.values
                     .get(index)
                     .ok_or_else(|| query_err(format!("No column for ColIdx {index:?}")))?
                     .clone(),
             )
-            .map_err(type_err)
         } else if let Some(index) = index.as_usize() {
             let (_, value) = self
                 .values
                 .iter()
                 .nth(*index)
                 .ok_or_else(|| query_err(format!("Column at index {index} not found")))?;
-            T::try_from(value.clone()).map_err(type_err)
+            try_from_value(value.clone())
         } else {
             unreachable!("Missing ColIdx implementation for ProxyRow");
         }
@@ -195,6 +199,56 @@ impl ProxyRow {
     }
 }
 
+/// Convert a value, or the same number in another integer or float type, e.g. a 64-bit integer of
+/// a database which only has one integer type to the `i32` of a model
+fn try_from_value<T>(value: Value) -> Result<T, DbErr>
+where
+    T: ValueType,
+{
+    let err = match T::try_from(value.clone()) {
+        Ok(v) => return Ok(v),
+        Err(err) => err,
+    };
+    let int = match value {
+        Value::TinyInt(v) => v.map(i64::from),
+        Value::SmallInt(v) => v.map(i64::from),
+        Value::Int(v) => v.map(i64::from),
+        Value::BigInt(v) => v,
+        Value::TinyUnsigned(v) => v.map(i64::from),
+        Value::SmallUnsigned(v) => v.map(i64::from),
+        Value::Unsigned(v) => v.map(i64::from),
+        Value::BigUnsigned(v) => match v.map(<i64 as TryFrom<u64>>::try_from).transpose() {
+            Ok(v) => v,
+            Err(_) => return Err(type_err(err)),
+        },
+        Value::Float(v) => return T::try_from(Value::Double(v.map(f64::from))).map_err(type_err),
+        Value::Double(v) => {
+            return T::try_from(Value::Float(v.map(|v| v as f32))).map_err(type_err)
+        }
+        _ => return Err(type_err(err)),
+    };
+    macro_rules! narrow {
+        ($($variant: ident),*) => {
+            [$(int.map(TryInto::try_into).transpose().ok().map(Value::$variant)),*]
+        };
+    }
+    narrow!(
+        TinyInt,
+        SmallInt,
+        Int,
+        BigInt,
+        TinyUnsigned,
+        SmallUnsigned,
+        Unsigned,
+        BigUnsigned
+    )
+    .into_iter()
+    .flatten()
+    .chain([Value::Bool(int.map(|v| v != 0))])
+    .find_map(|value| T::try_from(value).ok())
+    .ok_or_else(|| type_err(err))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
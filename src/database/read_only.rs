@@ -0,0 +1,165 @@
+use crate::{
+    ConnectionTrait, DatabaseTransaction, DbBackend, DbErr, QueryResult, Statement, StreamTrait,
+    TransactionStream,
+};
+use futures::Future;
+use std::pin::Pin;
+use tracing::instrument;
+
+/// The query side of [`ConnectionTrait`], which is all that is needed to run a select.
+/// It is implemented by every [`ConnectionTrait`] and by [`ReadOnlyTransaction`].
+#[async_trait::async_trait]
+pub trait ReadConnectionTrait: Sync {
+    /// Fetch the database backend as specified in [DbBackend]
+    fn backend(&self) -> DbBackend;
+
+    /// Execute a [Statement] and return a query
+    async fn read_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr>;
+
+    /// Execute a [Statement] and return a collection Vec<[QueryResult]> on success
+    async fn read_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
+}
+
+#[async_trait::async_trait]
+impl<C> ReadConnectionTrait for C
+where
+    C: ConnectionTrait,
+{
+    fn backend(&self) -> DbBackend {
+        self.get_database_backend()
+    }
+
+    async fn read_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.query_one(stmt).await
+    }
+
+    async fn read_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.query_all(stmt).await
+    }
+}
+
+/// A transaction started with `SET TRANSACTION READ ONLY`, see [`crate::TransactionTrait::begin_read_only`].
+///
+/// It does not implement [`ConnectionTrait`], so it can be passed to selects and paginators
+/// but not to inserts, updates, deletes or [`ConnectionTrait::execute`].
+///
+/// ```compile_fail
+/// # use sea_orm::{tests_cfg::*, *};
+/// # async fn function(db: &DatabaseConnection) -> Result<(), DbErr> {
+/// let txn = db.begin_read_only().await?;
+/// cake::Entity::delete_many().exec(&txn).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReadOnlyTransaction {
+    inner: DatabaseTransaction,
+}
+
+impl ReadOnlyTransaction {
+    pub(crate) fn new(inner: DatabaseTransaction) -> Self {
+        Self { inner }
+    }
+
+    /// Fetch the database backend as specified in [DbBackend]
+    pub fn get_database_backend(&self) -> DbBackend {
+        self.inner.get_database_backend()
+    }
+
+    /// Execute a [Statement] and return a query
+    pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.inner.query_one(stmt).await
+    }
+
+    /// Execute a [Statement] and return a collection Vec<[QueryResult]> on success
+    pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.inner.query_all(stmt).await
+    }
+
+    /// End the transaction with `COMMIT`
+    #[instrument(level = "trace")]
+    pub async fn commit(self) -> Result<(), DbErr> {
+        self.inner.commit().await
+    }
+
+    /// End the transaction with `ROLLBACK`
+    #[instrument(level = "trace")]
+    pub async fn rollback(self) -> Result<(), DbErr> {
+        self.inner.rollback().await
+    }
+}
+
+#[async_trait::async_trait]
+impl ReadConnectionTrait for ReadOnlyTransaction {
+    fn backend(&self) -> DbBackend {
+        self.inner.get_database_backend()
+    }
+
+    async fn read_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.inner.query_one(stmt).await
+    }
+
+    async fn read_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.inner.query_all(stmt).await
+    }
+}
+
+impl StreamTrait for ReadOnlyTransaction {
+    type Stream<'a> = TransactionStream<'a>;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.inner.stream(stmt)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, PaginatorTrait, Statement,
+        Transaction, TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn read_only_transaction() -> Result<(), DbErr> {
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: None,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![apple.clone()]])
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<Value>::into(1i64),
+            }]])
+            .into_connection();
+
+        let txn = db.begin_read_only().await?;
+        assert_eq!(fruit::Entity::find().all(&txn).await?, [apple]);
+        assert_eq!(fruit::Entity::find().count(&txn).await?, 1);
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    []
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT COUNT(*) AS num_items FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit") AS "sub_query""#,
+                    []
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ])]
+        );
+        Ok(())
+    }
+}
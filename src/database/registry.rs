@@ -0,0 +1,154 @@
+use crate::{DatabaseConnection, DbErr, EntityTrait};
+use std::collections::HashMap;
+
+/// A registry of named database connections, e.g. `"primary"`, `"analytics"` and `"legacy"`.
+///
+/// An entity is routed to the database named by `#[sea_orm(database_name = "..")]`,
+/// or to the default database, which is the first one added unless set with [`Databases::default_database`].
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let primary = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model { id: 1, name: "Cheese".to_owned() }]])
+/// #     .into_connection();
+/// # let analytics = MockDatabase::new(DbBackend::Postgres).into_connection();
+/// #
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+///
+/// let dbs = Databases::new()
+///     .add("primary", primary)
+///     .add("analytics", analytics);
+///
+/// let cake = cake::Entity::find_by_id(1).one(dbs.of::<cake::Entity>()?).await?;
+/// let analytics = dbs.get("analytics")?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Databases {
+    connections: HashMap<String, DatabaseConnection>,
+    default: Option<String>,
+}
+
+impl Databases {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a database connection under a name, replacing the connection previously added under it
+    pub fn add<N>(mut self, name: N, db: DatabaseConnection) -> Self
+    where
+        N: Into<String>,
+    {
+        let name = name.into();
+        if self.default.is_none() {
+            self.default = Some(name.clone());
+        }
+        self.connections.insert(name, db);
+        self
+    }
+
+    /// Set the database of the entities without a `database_name`
+    pub fn default_database<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.default = Some(name.into());
+        self
+    }
+
+    /// The connection to a database by its name
+    pub fn get(&self, name: &str) -> Result<&DatabaseConnection, DbErr> {
+        self.connections
+            .get(name)
+            .ok_or_else(|| DbErr::Custom(format!("No database named `{name}`")))
+    }
+
+    /// The connection to the default database
+    pub fn default_connection(&self) -> Result<&DatabaseConnection, DbErr> {
+        match &self.default {
+            Some(name) => self.get(name),
+            None => Err(DbErr::Custom("No database has been added".to_owned())),
+        }
+    }
+
+    /// The connection to the database of an entity
+    pub fn of<E>(&self) -> Result<&DatabaseConnection, DbErr>
+    where
+        E: EntityTrait,
+    {
+        match E::default().database_name() {
+            Some(name) => self.get(name),
+            None => self.default_connection(),
+        }
+    }
+
+    /// The names of the databases, in no particular order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.connections.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::prelude::*, tests_cfg::*, DatabaseConnection, Databases, DbBackend, MockDatabase,
+    };
+
+    mod report {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "report", database_name = "analytics")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn mock() -> DatabaseConnection {
+        MockDatabase::new(DbBackend::Postgres).into_connection()
+    }
+
+    #[test]
+    fn databases() -> Result<(), DbErr> {
+        let dbs = Databases::new()
+            .add("primary", mock())
+            .add("analytics", mock());
+
+        assert_eq!(report::Entity.database_name(), Some("analytics"));
+        assert!(std::ptr::eq(
+            dbs.of::<report::Entity>()?,
+            dbs.get("analytics")?
+        ));
+        assert!(std::ptr::eq(dbs.of::<cake::Entity>()?, dbs.get("primary")?));
+        assert!(dbs.get("legacy").is_err());
+
+        let dbs = dbs.default_database("analytics");
+        assert!(std::ptr::eq(
+            dbs.of::<cake::Entity>()?,
+            dbs.get("analytics")?
+        ));
+
+        let mut names: Vec<_> = dbs.names().collect();
+        names.sort();
+        assert_eq!(names, ["analytics", "primary"]);
+
+        assert!(Databases::new().of::<cake::Entity>().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{ConnectOptions, Database, DatabaseConnection, DbErr, Statement};
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// How a [`ReplicatedConnection`] picks the replica running a query
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplicaStrategy {
+    /// Use each replica in turn
+    #[default]
+    RoundRobin,
+    /// Use the replica with the lowest average query latency
+    LowestLatency,
+}
+
+/// A primary database with read replicas, see [`Database::connect_with_replicas`].
+///
+/// Statements are executed, and transactions begun, on the primary. Queries starting with
+/// `SELECT` are run on a replica, unless [`DatabaseConnection::on_primary`] is used to read
+/// the writes that may not have been replicated yet. A `SELECT .. INTO` and a `SELECT` locking
+/// rows, e.g. `FOR UPDATE`, are run on the primary.
+#[derive(Debug)]
+pub struct ReplicatedConnection {
+    primary: DatabaseConnection,
+    replicas: Vec<DatabaseConnection>,
+    strategy: ReplicaStrategy,
+    next: AtomicUsize,
+    /// The moving average of the query latency of each replica in microseconds, 0 if unknown
+    latencies: Vec<AtomicU64>,
+}
+
+impl Database {
+    /// Connect to a primary database and its read replicas, see [`ReplicatedConnection`]
+    pub async fn connect_with_replicas<C, R>(
+        primary: C,
+        replicas: R,
+        strategy: ReplicaStrategy,
+    ) -> Result<DatabaseConnection, DbErr>
+    where
+        C: Into<ConnectOptions>,
+        R: IntoIterator,
+        R::Item: Into<ConnectOptions>,
+    {
+        let primary = Database::connect(primary).await?;
+        let mut connections = Vec::new();
+        for replica in replicas {
+            connections.push(Database::connect(replica).await?);
+        }
+        Ok(DatabaseConnection::with_replicas(
+            primary,
+            connections,
+            strategy,
+        ))
+    }
+}
+
+impl DatabaseConnection {
+    /// Route the queries outside of transactions to the replicas, see [`ReplicatedConnection`]
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let primary = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model { id: 1, name: "New York Cheese".to_owned() }]])
+    /// #     .into_connection();
+    /// # let replica = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cheese".to_owned() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let db = DatabaseConnection::with_replicas(primary, vec![replica], ReplicaStrategy::RoundRobin);
+    ///
+    /// // may be stale
+    /// let cake = cake::Entity::find_by_id(1).one(&db).await?;
+    /// // read after write
+    /// let cake = cake::Entity::find_by_id(1).one(db.on_primary()).await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_replicas(
+        primary: DatabaseConnection,
+        replicas: Vec<DatabaseConnection>,
+        strategy: ReplicaStrategy,
+    ) -> Self {
+        let latencies = replicas.iter().map(|_| AtomicU64::new(0)).collect();
+        DatabaseConnection::ReplicatedConnection(Arc::new(ReplicatedConnection {
+            primary,
+            replicas,
+            strategy,
+            next: AtomicUsize::new(0),
+            latencies,
+        }))
+    }
+
+    /// The connection to the primary database, or the connection itself if it has no replicas
+    pub fn on_primary(&self) -> &DatabaseConnection {
+        match self {
+            DatabaseConnection::ReplicatedConnection(conn) => &conn.primary,
+            _ => self,
+        }
+    }
+}
+
+impl ReplicatedConnection {
+    /// The connection to the primary database
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    /// The connections to the replicas
+    pub fn replicas(&self) -> &[DatabaseConnection] {
+        &self.replicas
+    }
+
+    /// The replica to run a query on, or the primary if the statement may write or lock rows
+    pub(crate) fn reader(&self, stmt: &Statement) -> Option<(usize, &DatabaseConnection)> {
+        if !is_read_only_select(&stmt.sql) || self.replicas.is_empty() {
+            return None;
+        }
+        let index = match self.strategy {
+            ReplicaStrategy::RoundRobin => {
+                self.next.fetch_add(1, Ordering::Relaxed) % self.replicas.len()
+            }
+            ReplicaStrategy::LowestLatency => self
+                .latencies
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, latency)| latency.load(Ordering::Relaxed))
+                .map_or(0, |(index, _)| index),
+        };
+        Some((index, &self.replicas[index]))
+    }
+
+    /// Record the latency of a query run on a replica
+    pub(crate) fn record_latency(&self, index: usize, started: Instant) {
+        let sample = started.elapsed().as_micros().max(1) as u64;
+        let latency = &self.latencies[index];
+        let average = match latency.load(Ordering::Relaxed) {
+            0 => sample,
+            average => (average * 7 + sample) / 8,
+        };
+        latency.store(average, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
+    {
+        let callback = Arc::new(callback);
+        for conn in std::iter::once(&mut self.primary).chain(&mut self.replicas) {
+            let callback = Arc::clone(&callback);
+            conn.set_metric_callback(move |info| callback(info));
+        }
+    }
+
+    pub(crate) async fn close(self) -> Result<(), DbErr> {
+        self.primary.close().await?;
+        for replica in self.replicas {
+            replica.close().await?;
+        }
+        Ok(())
+    }
+
+    /// The average query latency of each replica, `None` until a query has been run on it
+    pub fn latencies(&self) -> Vec<Option<Duration>> {
+        self.latencies
+            .iter()
+            .map(|latency| match latency.load(Ordering::Relaxed) {
+                0 => None,
+                micros => Some(Duration::from_micros(micros)),
+            })
+            .collect()
+    }
+}
+
+/// Whether the statement is a `SELECT` which neither writes, as `SELECT .. INTO` does, nor locks
+/// rows, as `FOR UPDATE`, `FOR SHARE` and `LOCK IN SHARE MODE` do
+fn is_read_only_select(sql: &str) -> bool {
+    let words: Vec<&str> = sql.split_whitespace().collect();
+    let is_word =
+        |i: usize, word: &str| words.get(i).map_or(false, |w| w.eq_ignore_ascii_case(word));
+    if !is_word(0, "SELECT") {
+        return false;
+    }
+    !(0..words.len()).any(|i| {
+        is_word(i, "INTO")
+            || (is_word(i, "FOR")
+                && ["UPDATE", "SHARE", "NO", "KEY"]
+                    .iter()
+                    .any(|word| is_word(i + 1, word)))
+            || (is_word(i, "LOCK") && is_word(i + 1, "IN") && is_word(i + 2, "SHARE"))
+    })
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, ConnectionTrait, DatabaseConnection, DbBackend,
+        MockDatabase, MockExecResult, QuerySelect, ReplicaStrategy, Statement, Transaction,
+        TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn transaction_log(db: &DatabaseConnection) -> Vec<Transaction> {
+        db.as_mock_connection()
+            .get_mocker_mutex()
+            .lock()
+            .map(|mut mocker| mocker.drain_transaction_log())
+            .unwrap_or_default()
+    }
+
+    fn find_cake() -> Transaction {
+        Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+            [1u64.into()],
+        )
+    }
+
+    #[smol_potat::test]
+    async fn replicated_connection() -> Result<(), DbErr> {
+        let cake = cake::Model {
+            id: 1,
+            name: "Cheese".to_owned(),
+        };
+        let mock = || {
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([[cake.clone()], [cake.clone()]])
+                .append_exec_results([MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }])
+                .into_connection()
+        };
+        let db = DatabaseConnection::with_replicas(
+            mock(),
+            vec![mock(), mock()],
+            ReplicaStrategy::RoundRobin,
+        );
+
+        for _ in 0..3 {
+            cake::Entity::find().one(&db).await?;
+        }
+        cake::Entity::find().one(db.on_primary()).await?;
+        cake::Entity::delete_many().exec(&db).await?;
+        let txn = db.begin().await?;
+        cake::Entity::find().one(&txn).await?;
+        txn.commit().await?;
+
+        let DatabaseConnection::ReplicatedConnection(conn) = &db else {
+            panic!("Not a replicated connection");
+        };
+        assert_eq!(
+            transaction_log(&conn.replicas()[0]),
+            [find_cake(), find_cake()]
+        );
+        assert_eq!(transaction_log(&conn.replicas()[1]), [find_cake()]);
+        assert_eq!(
+            transaction_log(conn.primary()),
+            [
+                find_cake(),
+                Transaction::from_sql_and_values(DbBackend::Postgres, r#"DELETE FROM "cake""#, []),
+                Transaction::many([
+                    Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                        [1u64.into()],
+                    ),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT"),
+                ]),
+            ]
+        );
+        assert!(conn.latencies().iter().all(Option::is_some));
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn lowest_latency_replica() -> Result<(), DbErr> {
+        let mock = || {
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([Vec::<cake::Model>::new(), Vec::new()])
+                .into_connection()
+        };
+        let db = DatabaseConnection::with_replicas(
+            mock(),
+            vec![mock(), mock()],
+            ReplicaStrategy::LowestLatency,
+        );
+        let DatabaseConnection::ReplicatedConnection(conn) = &db else {
+            panic!("Not a replicated connection");
+        };
+
+        // every replica is tried first
+        cake::Entity::find().one(&db).await?;
+        cake::Entity::find().one(&db).await?;
+        assert_eq!(transaction_log(&conn.replicas()[0]).len(), 1);
+        assert_eq!(transaction_log(&conn.replicas()[1]).len(), 1);
+
+        conn.latencies[0].store(500, std::sync::atomic::Ordering::Relaxed);
+        conn.latencies[1].store(100, std::sync::atomic::Ordering::Relaxed);
+        cake::Entity::find().one(&db).await?;
+        assert_eq!(transaction_log(&conn.replicas()[0]).len(), 0);
+        assert_eq!(transaction_log(&conn.replicas()[1]).len(), 1);
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn locking_select_on_primary() -> Result<(), DbErr> {
+        let mock = || {
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([Vec::<cake::Model>::new(), Vec::new()])
+                .into_connection()
+        };
+        let db =
+            DatabaseConnection::with_replicas(mock(), vec![mock()], ReplicaStrategy::RoundRobin);
+        let DatabaseConnection::ReplicatedConnection(conn) = &db else {
+            panic!("Not a replicated connection");
+        };
+
+        cake::Entity::find().lock_exclusive().one(&db).await?;
+        db.query_all(Statement::from_string(
+            DbBackend::Postgres,
+            r#"SELECT * INTO "cake_copy" FROM "cake""#,
+        ))
+        .await?;
+        assert_eq!(transaction_log(&conn.replicas()[0]), []);
+        assert_eq!(transaction_log(conn.primary()).len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn read_only_select() {
+        use super::is_read_only_select;
+
+        assert!(is_read_only_select(r#"SELECT "id" FROM "cake""#));
+        assert!(is_read_only_select(r#" select "into" FROM "cake""#));
+        assert!(!is_read_only_select(
+            r#"SELECT "id" FROM "cake" FOR UPDATE"#
+        ));
+        assert!(!is_read_only_select(
+            r#"SELECT "id" FROM "cake" FOR NO KEY UPDATE"#
+        ));
+        assert!(!is_read_only_select(r#"SELECT "id" FROM "cake" FOR SHARE"#));
+        assert!(!is_read_only_select(
+            "SELECT `id` FROM `cake` LOCK IN SHARE MODE"
+        ));
+        assert!(!is_read_only_select(
+            "SELECT `id` FROM `cake` INTO OUTFILE '/tmp/cake'"
+        ));
+        assert!(!is_read_only_select(r#"DELETE FROM "cake""#));
+    }
+}
@@ -0,0 +1,348 @@
+use super::runtime::sleep;
+use crate::{AccessMode, DbErr, IsolationLevel, SqlErr, TransactionError};
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// How [`crate::TransactionTrait::transaction_with_retry`] retries a transaction that failed
+/// because of a serialization failure or a deadlock
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) isolation_level: Option<IsolationLevel>,
+    pub(crate) access_mode: Option<AccessMode>,
+    pub(crate) retry_if: fn(&DbErr) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            isolation_level: None,
+            access_mode: None,
+            retry_if: is_retryable,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Retry up to 4 times, waiting from 10 milliseconds up to 1 second in between
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of times the transaction is run at most, including the first attempt
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the time to wait before the first retry, which is doubled for every following retry
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the longest time to wait before a retry
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the isolation level of the transaction, e.g. [`IsolationLevel::Serializable`]
+    pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = Some(isolation_level);
+        self
+    }
+
+    /// Set the access mode of the transaction
+    pub fn access_mode(mut self, access_mode: AccessMode) -> Self {
+        self.access_mode = Some(access_mode);
+        self
+    }
+
+    /// Decide which errors are retried. By default, these are the errors where
+    /// [`DbErr::sql_err`] is a [`SqlErr::SerializationFailure`] or a [`SqlErr::Deadlock`].
+    pub fn retry_if(mut self, retry_if: fn(&DbErr) -> bool) -> Self {
+        self.retry_if = retry_if;
+        self
+    }
+
+    /// Whether the error of the given attempt, counted from 0, should be retried.
+    /// An error of the transaction callback is retried if it is, or is caused by, a retryable [`DbErr`].
+    pub(crate) fn should_retry<E>(&self, attempt: u32, err: &TransactionError<E>) -> bool
+    where
+        E: std::error::Error + 'static,
+    {
+        if attempt + 1 >= self.max_attempts {
+            return false;
+        }
+        let mut source: Option<&(dyn std::error::Error + 'static)> = match err {
+            TransactionError::Connection(e) => Some(e),
+            TransactionError::Transaction(e) => Some(e),
+        };
+        while let Some(err) = source {
+            if let Some(err) = err.downcast_ref::<DbErr>() {
+                return (self.retry_if)(err);
+            }
+            source = err.source();
+        }
+        false
+    }
+
+    /// The exponential backoff before retrying the given attempt, with a random jitter of up to half of it
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        backoff(self.initial_backoff, self.max_backoff, attempt)
+    }
+}
+
+fn is_retryable(err: &DbErr) -> bool {
+    matches!(
+        err.sql_err(),
+        Some(SqlErr::SerializationFailure(_) | SqlErr::Deadlock(_))
+    )
+}
+
+/// How connecting to a database, and acquiring a connection from its pool, are retried,
+/// see [`crate::ConnectOptions::connect_retry`]
+#[derive(Clone, Debug)]
+pub struct ConnectRetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) retry_if: fn(&DbErr) -> bool,
+}
+
+impl Default for ConnectRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            retry_if: is_connection_error,
+        }
+    }
+}
+
+impl ConnectRetryPolicy {
+    /// Retry up to 4 times, waiting from 100 milliseconds up to 5 seconds in between
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of times to try at most, including the first attempt
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the time to wait before the first retry, which is doubled for every following retry
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the longest time to wait before a retry
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Decide which errors are retried. By default, these are [`DbErr::Conn`],
+    /// e.g. a failed DNS lookup or a refused connection, and [`DbErr::ConnectionAcquire`].
+    pub fn retry_if(mut self, retry_if: fn(&DbErr) -> bool) -> Self {
+        self.retry_if = retry_if;
+        self
+    }
+
+    /// Run `f` until it succeeds, fails with an error which isn't retried, or runs out of attempts
+    pub(crate) async fn run<T, F, Fut>(&self, mut f: F) -> Result<T, DbErr>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, DbErr>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match f().await {
+                Err(err) if attempt + 1 < self.max_attempts && (self.retry_if)(&err) => {
+                    tracing::warn!("Retrying to connect after error: {err}");
+                    sleep(backoff(self.initial_backoff, self.max_backoff, attempt)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+fn is_connection_error(err: &DbErr) -> bool {
+    matches!(err, DbErr::Conn(_) | DbErr::ConnectionAcquire(_))
+}
+
+/// The exponential backoff before retrying the given attempt, with a random jitter of up to half of it
+fn backoff(initial: Duration, max: Duration, attempt: u32) -> Duration {
+    let backoff = initial
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(max);
+    let jitter = RandomState::new().build_hasher().finish() % 1024;
+    backoff - backoff / 2 * jitter as u32 / 1024
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DatabaseTransaction, DbBackend, MockDatabase,
+        MockExecResult, RetryPolicy, Statement, Transaction, TransactionError, TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+    use std::{future::Future, pin::Pin, time::Duration};
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new()
+            .max_attempts(3)
+            .initial_backoff(Duration::ZERO)
+            .retry_if(|err| matches!(err, DbErr::Custom(msg) if msg == "deadlock"))
+    }
+
+    fn delete_transaction(end: &str) -> Transaction {
+        Transaction::many([
+            Statement::from_string(DbBackend::Postgres, "BEGIN"),
+            Statement::from_sql_and_values(DbBackend::Postgres, r#"DELETE FROM "fruit""#, []),
+            Statement::from_string(DbBackend::Postgres, end),
+        ])
+    }
+
+    #[smol_potat::test]
+    async fn transaction_with_retry() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_errors([DbErr::Custom("deadlock".to_owned())])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let rows_affected = db
+            .transaction_with_retry::<_, _, DbErr>(policy(), |txn| {
+                Box::pin(async move {
+                    let res = fruit::Entity::delete_many().exec(txn).await?;
+                    Ok(res.rows_affected)
+                })
+            })
+            .await
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+
+        assert_eq!(rows_affected, 2);
+        assert_eq!(
+            db.into_transaction_log(),
+            [delete_transaction("ROLLBACK"), delete_transaction("COMMIT")]
+        );
+        Ok(())
+    }
+
+    fn delete(
+        txn: &DatabaseTransaction,
+    ) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + '_>> {
+        Box::pin(async move {
+            fruit::Entity::delete_many().exec(txn).await?;
+            Ok(())
+        })
+    }
+
+    #[smol_potat::test]
+    async fn transaction_with_retry_gives_up() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_errors([
+                DbErr::Custom("deadlock".to_owned()),
+                DbErr::Custom("deadlock".to_owned()),
+                DbErr::Custom("deadlock".to_owned()),
+            ])
+            .into_connection();
+
+        let res = db
+            .transaction_with_retry::<_, (), DbErr>(policy(), delete)
+            .await;
+        assert!(matches!(
+            res,
+            Err(TransactionError::Transaction(DbErr::Custom(msg))) if msg == "deadlock"
+        ));
+        assert_eq!(db.into_transaction_log().len(), 3);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_errors([DbErr::Custom("other".to_owned())])
+            .into_connection();
+        let res = db
+            .transaction_with_retry::<_, (), DbErr>(policy(), delete)
+            .await;
+        assert!(matches!(
+            res,
+            Err(TransactionError::Transaction(DbErr::Custom(msg))) if msg == "other"
+        ));
+        assert_eq!(db.into_transaction_log().len(), 1);
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn connect_retry() {
+        use crate::{ConnAcquireErr, ConnectRetryPolicy};
+        use std::cell::Cell;
+
+        let policy = ConnectRetryPolicy::new()
+            .max_attempts(3)
+            .initial_backoff(Duration::ZERO);
+        let attempts = Cell::new(0);
+        let res = policy
+            .run(|| {
+                attempts.set(attempts.get() + 1);
+                let attempt = attempts.get();
+                async move {
+                    match attempt {
+                        1 => Err(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)),
+                        _ => Ok(attempt),
+                    }
+                }
+            })
+            .await;
+        assert_eq!(res, Ok(2));
+
+        attempts.set(0);
+        let res: Result<(), _> = policy
+            .run(|| {
+                attempts.set(attempts.get() + 1);
+                async { Err(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)) }
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(attempts.get(), 3);
+
+        attempts.set(0);
+        let res: Result<(), _> = policy
+            .run(|| {
+                attempts.set(attempts.get() + 1);
+                async { Err(DbErr::Custom("not retried".to_owned())) }
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn retry_backoff() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300));
+        for (attempt, max) in [(0, 100), (1, 200), (2, 300), (10, 300)] {
+            let backoff = policy.backoff(attempt);
+            assert!(backoff <= Duration::from_millis(max));
+            assert!(backoff >= Duration::from_millis(max / 2));
+        }
+    }
+}
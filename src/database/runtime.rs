@@ -0,0 +1,70 @@
+use std::{future::Future, time::Duration};
+
+/// Wait on the timer of the async runtime. Without a runtime feature, or outside of a Tokio
+/// runtime, e.g. on a mock connection, the task is woken from a thread instead.
+pub(crate) async fn sleep(duration: Duration) {
+    if duration.is_zero() {
+        return;
+    }
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return tokio::time::sleep(duration).await;
+    }
+    #[cfg(feature = "runtime-async-std")]
+    async_std::task::sleep(duration).await;
+    #[cfg(not(feature = "runtime-async-std"))]
+    thread_sleep(duration).await;
+}
+
+/// Run a future until it completes, returning its output, or until the timeout passed, returning
+/// `None`, with the timer of the async runtime as in [`sleep`]
+pub(crate) async fn timeout<F>(duration: Duration, fut: F) -> Option<F::Output>
+where
+    F: Future,
+{
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return tokio::time::timeout(duration, fut).await.ok();
+    }
+    #[cfg(feature = "runtime-async-std")]
+    let res = async_std::future::timeout(duration, fut).await.ok();
+    #[cfg(not(feature = "runtime-async-std"))]
+    let res = {
+        let sleep = thread_sleep(duration);
+        futures::pin_mut!(fut, sleep);
+        match futures::future::select(fut, sleep).await {
+            futures::future::Either::Left((res, _)) => Some(res),
+            futures::future::Either::Right(_) => None,
+        }
+    };
+    res
+}
+
+/// Run a future in the background on the async runtime, returning whether there is one to run it
+#[cfg(feature = "sqlx-mysql")]
+pub(crate) fn spawn<F>(fut: F) -> bool
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        handle.spawn(fut);
+        return true;
+    }
+    #[cfg(feature = "runtime-async-std")]
+    async_std::task::spawn(fut);
+    #[cfg(not(feature = "runtime-async-std"))]
+    drop(fut);
+    cfg!(feature = "runtime-async-std")
+}
+
+/// Wait by waking the task from a thread, which doesn't depend on an async runtime
+#[cfg(not(feature = "runtime-async-std"))]
+async fn thread_sleep(duration: Duration) {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(duration);
+        let _ = sender.send(());
+    });
+    let _ = receiver.await;
+}
@@ -0,0 +1,202 @@
+use crate::{
+    debug_print, error::*, sqlx_error_to_exec_err, sqlx_error_to_query_err, ConnectionTrait,
+    DbBackend, ExecResult, QueryResult, Statement,
+};
+use futures::lock::Mutex;
+use std::{future::Future, pin::Pin, sync::Arc};
+use tracing::instrument;
+
+type Callback = dyn for<'a, 'c> Fn(
+        &'a SessionConnection<'c>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'a>>
+    + Send
+    + Sync;
+
+/// The callback run on every new connection of a pool, see [`crate::ConnectOptions::after_connect`]
+#[derive(Clone)]
+pub(crate) struct AfterConnect(pub(crate) Arc<Callback>);
+
+impl std::fmt::Debug for AfterConnect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AfterConnect")
+    }
+}
+
+impl AfterConnect {
+    /// Run the callback on a new connection, before it is added to the pool
+    pub(crate) async fn run(&self, conn: InnerSession<'_>) -> Result<(), sqlx::Error> {
+        let backend = match &conn {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerSession::MySql(_) => DbBackend::MySql,
+            #[cfg(feature = "sqlx-postgres")]
+            InnerSession::Postgres(_) => DbBackend::Postgres,
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerSession::Sqlite(_) => DbBackend::Sqlite,
+        };
+        let session = SessionConnection {
+            backend,
+            conn: Mutex::new(conn),
+        };
+        (self.0)(&session).await.map_err(|err| match err {
+            DbErr::Conn(RuntimeErr::SqlxError(err))
+            | DbErr::Exec(RuntimeErr::SqlxError(err))
+            | DbErr::Query(RuntimeErr::SqlxError(err)) => err,
+            err => sqlx::Error::Configuration(Box::new(err)),
+        })
+    }
+}
+
+/// A connection which has just been opened by the pool, to initialize its session,
+/// see [`crate::ConnectOptions::after_connect`]
+pub struct SessionConnection<'c> {
+    backend: DbBackend,
+    conn: Mutex<InnerSession<'c>>,
+}
+
+pub(crate) enum InnerSession<'c> {
+    #[cfg(feature = "sqlx-mysql")]
+    MySql(&'c mut sqlx::MySqlConnection),
+    #[cfg(feature = "sqlx-postgres")]
+    Postgres(&'c mut sqlx::PgConnection),
+    #[cfg(feature = "sqlx-sqlite")]
+    Sqlite(&'c mut sqlx::SqliteConnection),
+}
+
+impl std::fmt::Debug for SessionConnection<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SessionConnection {{ backend: {:?} }}", self.backend)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for SessionConnection<'_> {
+    fn get_database_backend(&self) -> DbBackend {
+        self.backend
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerSession::MySql(conn) => crate::driver::sqlx_mysql::sqlx_query(&stmt)
+                .execute(&mut **conn)
+                .await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err),
+            #[cfg(feature = "sqlx-postgres")]
+            InnerSession::Postgres(conn) => crate::driver::sqlx_postgres::sqlx_query(&stmt)?
+                .execute(&mut **conn)
+                .await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err),
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerSession::Sqlite(conn) => crate::driver::sqlx_sqlite::sqlx_query(&stmt)
+                .execute(&mut **conn)
+                .await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerSession::MySql(conn) => sqlx::Executor::execute(&mut **conn, sql)
+                .await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err),
+            #[cfg(feature = "sqlx-postgres")]
+            InnerSession::Postgres(conn) => sqlx::Executor::execute(&mut **conn, sql)
+                .await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err),
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerSession::Sqlite(conn) => sqlx::Executor::execute(&mut **conn, sql)
+                .await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerSession::MySql(conn) => crate::sqlx_map_err_ignore_not_found(
+                crate::driver::sqlx_mysql::sqlx_query(&stmt)
+                    .fetch_one(&mut **conn)
+                    .await
+                    .map(|row| Some(row.into())),
+            ),
+            #[cfg(feature = "sqlx-postgres")]
+            InnerSession::Postgres(conn) => crate::sqlx_map_err_ignore_not_found(
+                crate::driver::sqlx_postgres::sqlx_query(&stmt)?
+                    .fetch_one(&mut **conn)
+                    .await
+                    .map(|row| Some(row.into())),
+            ),
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerSession::Sqlite(conn) => crate::sqlx_map_err_ignore_not_found(
+                crate::driver::sqlx_sqlite::sqlx_query(&stmt)
+                    .fetch_one(&mut **conn)
+                    .await
+                    .map(|row| Some(row.into())),
+            ),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerSession::MySql(conn) => crate::driver::sqlx_mysql::sqlx_query(&stmt)
+                .fetch_all(&mut **conn)
+                .await
+                .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                .map_err(sqlx_error_to_query_err),
+            #[cfg(feature = "sqlx-postgres")]
+            InnerSession::Postgres(conn) => crate::driver::sqlx_postgres::sqlx_query(&stmt)?
+                .fetch_all(&mut **conn)
+                .await
+                .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                .map_err(sqlx_error_to_query_err),
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerSession::Sqlite(conn) => crate::driver::sqlx_sqlite::sqlx_query(&stmt)
+                .fetch_all(&mut **conn)
+                .await
+                .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                .map_err(sqlx_error_to_query_err),
+        }
+    }
+    #[instrument(level = "trace", skip(data))]
+    #[allow(unused_variables)]
+    async fn copy_in_raw(
+        &self,
+        statement: &str,
+        data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        debug_print!("{}", statement);
+
+        match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-postgres")]
+            InnerSession::Postgres(conn) => {
+                let copy = conn
+                    .copy_in_raw(statement)
+                    .await
+                    .map_err(sqlx_error_to_exec_err)?;
+                crate::driver::sqlx_postgres::sqlx_copy_in(copy, data).await
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(crate::copy_not_supported()),
+        }
+    }
+}
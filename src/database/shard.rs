@@ -0,0 +1,134 @@
+use crate::{DatabaseConnection, DbErr, ModelTrait, Value};
+
+/// Pick the shard of a key, given the number of shards
+pub type ShardRouter = fn(&Value, usize) -> Option<usize>;
+
+/// Several databases holding disjoint parts of the same tables, picked by a shard key.
+///
+/// By default, an integer key `k` is stored in the shard `k mod n`, and a string, bytes or UUID key
+/// in the shard of its FNV-1a hash, which are stable across processes and versions.
+/// A custom router can be set with [`ShardedDatabase::router`].
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let cake = cake::Model { id: 3, name: "Cheese".to_owned() };
+/// # let shard = || MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake.clone()], [cake.clone()]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+///
+/// let db = ShardedDatabase::new(vec![shard(), shard()]);
+///
+/// // on a single shard
+/// let cake = cake::Entity::find_by_id(3).shard(3).one(&db).await?;
+/// // on every shard
+/// let cakes = cake::Entity::find().all_shards(&db).await?;
+/// #
+/// # assert_eq!(cakes.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ShardedDatabase {
+    shards: Vec<DatabaseConnection>,
+    router: ShardRouter,
+}
+
+impl ShardedDatabase {
+    /// Create a sharded database from the connection to each shard, in order
+    pub fn new(shards: Vec<DatabaseConnection>) -> Self {
+        Self {
+            shards,
+            router: route_by_hash,
+        }
+    }
+
+    /// Set the function picking the shard of a key
+    pub fn router(mut self, router: ShardRouter) -> Self {
+        self.router = router;
+        self
+    }
+
+    /// The connections to every shard
+    pub fn shards(&self) -> &[DatabaseConnection] {
+        &self.shards
+    }
+
+    /// The connection to the shard of a key
+    pub fn shard<K>(&self, key: K) -> Result<&DatabaseConnection, DbErr>
+    where
+        K: Into<Value>,
+    {
+        let key = key.into();
+        (self.router)(&key, self.shards.len())
+            .and_then(|index| self.shards.get(index))
+            .ok_or_else(|| DbErr::Custom(format!("No shard for the key {key:?}")))
+    }
+
+    /// The connection to the shard of a model, whose shard key is the given column
+    pub fn shard_of<M>(
+        &self,
+        model: &M,
+        column: <M::Entity as crate::EntityTrait>::Column,
+    ) -> Result<&DatabaseConnection, DbErr>
+    where
+        M: ModelTrait,
+    {
+        self.shard(model.get(column))
+    }
+}
+
+fn route_by_hash(key: &Value, shards: usize) -> Option<usize> {
+    if shards == 0 {
+        return None;
+    }
+    let hash = match key {
+        Value::TinyInt(Some(v)) => (*v as i64).rem_euclid(shards as i64) as u64,
+        Value::SmallInt(Some(v)) => (*v as i64).rem_euclid(shards as i64) as u64,
+        Value::Int(Some(v)) => (*v as i64).rem_euclid(shards as i64) as u64,
+        Value::BigInt(Some(v)) => v.rem_euclid(shards as i64) as u64,
+        Value::TinyUnsigned(Some(v)) => *v as u64,
+        Value::SmallUnsigned(Some(v)) => *v as u64,
+        Value::Unsigned(Some(v)) => *v as u64,
+        Value::BigUnsigned(Some(v)) => *v,
+        Value::String(Some(v)) => fnv1a(v.as_bytes()),
+        Value::Char(Some(v)) => fnv1a(v.to_string().as_bytes()),
+        Value::Bytes(Some(v)) => fnv1a(v),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(Some(v)) => fnv1a(v.as_bytes()),
+        _ => return None,
+    };
+    Some((hash % shards as u64) as usize)
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shard_routing() {
+        assert_eq!(route_by_hash(&7i32.into(), 3), Some(1));
+        assert_eq!(route_by_hash(&(-7i64).into(), 3), Some(2));
+        assert_eq!(route_by_hash(&7u64.into(), 3), Some(1));
+        assert_eq!(
+            route_by_hash(&"tenant".into(), 4),
+            Some(fnv1a(b"tenant") as usize % 4)
+        );
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(route_by_hash(&Value::Int(None), 3), None);
+        assert_eq!(route_by_hash(&1.5f64.into(), 3), None);
+        assert_eq!(route_by_hash(&1i32.into(), 0), None);
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+/// The `journal_mode` pragma of a SQLite database, see [`SqliteOptions::journal_mode`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqliteJournalMode {
+    /// Delete the rollback journal at the end of each transaction
+    Delete,
+    /// Truncate the rollback journal at the end of each transaction
+    Truncate,
+    /// Keep the rollback journal, overwriting its header at the end of each transaction
+    Persist,
+    /// Keep the rollback journal in memory
+    Memory,
+    /// Use a write-ahead log, letting readers run concurrently with a writer
+    Wal,
+    /// Do not keep a rollback journal
+    Off,
+}
+
+/// The `synchronous` pragma of a SQLite database, see [`SqliteOptions::synchronous`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqliteSynchronous {
+    /// Do not sync to disk
+    Off,
+    /// Sync at the most critical moments, which is durable enough in WAL mode
+    Normal,
+    /// Sync to disk at every commit
+    Full,
+    /// Also sync the directory of the rollback journal
+    Extra,
+}
+
+/// The settings of every connection to a SQLite database, see [`crate::ConnectOptions::sqlite`].
+/// The settings left unset keep the defaults of sqlx, which enables foreign keys and waits for
+/// 5 seconds on a locked database.
+///
+/// ```
+/// # use sea_orm::{ConnectOptions, SqliteJournalMode, SqliteOptions, SqliteSynchronous};
+/// # use std::time::Duration;
+/// let mut opt = ConnectOptions::new("sqlite://bakery.db?mode=rwc");
+/// opt.sqlite(
+///     SqliteOptions::new()
+///         .journal_mode(SqliteJournalMode::Wal)
+///         .synchronous(SqliteSynchronous::Normal)
+///         .busy_timeout(Duration::from_secs(10))
+///         .foreign_keys(true)
+///         .extension("vec0"),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SqliteOptions {
+    pub(crate) journal_mode: Option<SqliteJournalMode>,
+    pub(crate) synchronous: Option<SqliteSynchronous>,
+    pub(crate) busy_timeout: Option<Duration>,
+    pub(crate) foreign_keys: Option<bool>,
+    pub(crate) extensions: Vec<(String, Option<String>)>,
+}
+
+impl SqliteOptions {
+    /// Create SQLite options keeping every default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the journal mode, which is persisted in the database file for [`SqliteJournalMode::Wal`]
+    pub fn journal_mode(mut self, mode: SqliteJournalMode) -> Self {
+        self.journal_mode = Some(mode);
+        self
+    }
+
+    /// Set how often SQLite syncs to disk
+    pub fn synchronous(mut self, synchronous: SqliteSynchronous) -> Self {
+        self.synchronous = Some(synchronous);
+        self
+    }
+
+    /// Set how long a statement waits for a locked database before failing
+    pub fn busy_timeout(mut self, timeout: Duration) -> Self {
+        self.busy_timeout = Some(timeout);
+        self
+    }
+
+    /// Set whether foreign key constraints are enforced
+    pub fn foreign_keys(mut self, on: bool) -> Self {
+        self.foreign_keys = Some(on);
+        self
+    }
+
+    /// Load an extension on every connection, by the name or path of its shared library,
+    /// e.g. `"vec0"` for sqlite-vec. Connecting fails if the extension cannot be loaded.
+    pub fn extension<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.extensions.push((name.into(), None));
+        self
+    }
+
+    /// Load an extension on every connection, calling the given init function instead of the one
+    /// SQLite derives from the name of the library
+    pub fn extension_with_entrypoint<N, E>(mut self, name: N, entrypoint: E) -> Self
+    where
+        N: Into<String>,
+        E: Into<String>,
+    {
+        self.extensions.push((name.into(), Some(entrypoint.into())));
+        self
+    }
+}
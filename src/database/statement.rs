@@ -1,7 +1,7 @@
-use crate::DbBackend;
+use crate::{CancellationToken, DbBackend};
 use sea_query::{inject_parameters, MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder};
 pub use sea_query::{Value, Values};
-use std::fmt;
+use std::{fmt, time::Duration};
 
 /// Defines an SQL statement
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +13,10 @@ pub struct Statement {
     /// The database backend this statement is constructed for.
     /// The SQL dialect and values should be valid for the DbBackend.
     pub db_backend: DbBackend,
+    /// The time the statement may run for, see [`Statement::timeout`]
+    pub timeout: Option<Duration>,
+    /// The token cancelling the statement, see [`Statement::cancel_on`]
+    pub cancel_token: Option<CancellationToken>,
 }
 
 /// Any type that can build a [Statement]
@@ -31,6 +35,8 @@ impl Statement {
             sql: stmt.into(),
             values: None,
             db_backend,
+            timeout: None,
+            cancel_token: None,
         }
     }
 
@@ -44,6 +50,27 @@ impl Statement {
         Self::from_string_values_tuple(db_backend, (sql, Values(values.into_iter().collect())))
     }
 
+    /// Fail with [`crate::DbErr::QueryTimeout`] if the statement runs for longer than the timeout.
+    ///
+    /// On a pooled MySQL or PostgreSQL connection, the statement is cancelled on the server once it
+    /// timed out, which takes an extra query to find the connection id beforehand. Inside of a
+    /// transaction, and on SQLite, the statement is only abandoned by the client and keeps its
+    /// connection busy until it completes.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fail with [`crate::DbErr::QueryCancelled`] once the token is cancelled, or right away if it
+    /// already was.
+    ///
+    /// The statement is cancelled on the server as with [`Statement::timeout`], provided that the
+    /// query is still awaited when the token is cancelled.
+    pub fn cancel_on(mut self, token: CancellationToken) -> Self {
+        self.cancel_token = Some(token);
+        self
+    }
+
     pub(crate) fn from_string_values_tuple<T>(db_backend: DbBackend, stmt: (T, Values)) -> Statement
     where
         T: Into<String>,
@@ -52,7 +79,41 @@ impl Statement {
             sql: stmt.0.into(),
             values: Some(stmt.1),
             db_backend,
+            timeout: None,
+            cancel_token: None,
+        }
+    }
+}
+
+/// Run a statement until its timeout, returning [`crate::DbErr::QueryTimeout`] once it passed,
+/// or until its token is cancelled, returning [`crate::DbErr::QueryCancelled`]
+pub(crate) async fn with_cancellation<F, T>(
+    timeout: Option<Duration>,
+    token: Option<&CancellationToken>,
+    fut: F,
+) -> Result<T, crate::DbErr>
+where
+    F: std::future::Future<Output = Result<T, crate::DbErr>>,
+{
+    let fut = async {
+        match timeout {
+            Some(timeout) => super::runtime::timeout(timeout, fut)
+                .await
+                .unwrap_or(Err(crate::DbErr::QueryTimeout(timeout))),
+            None => fut.await,
+        }
+    };
+    match token {
+        Some(token) if token.is_cancelled() => Err(crate::DbErr::QueryCancelled),
+        Some(token) => {
+            let cancelled = token.cancelled();
+            futures::pin_mut!(fut, cancelled);
+            match futures::future::select(fut, cancelled).await {
+                futures::future::Either::Left((res, _)) => res,
+                futures::future::Either::Right(_) => Err(crate::DbErr::QueryCancelled),
+            }
         }
+        None => fut.await,
     }
 }
 
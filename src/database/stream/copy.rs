@@ -0,0 +1,107 @@
+use futures::Stream;
+use std::{pin::Pin, task::Poll};
+
+use crate::DbErr;
+
+/// The data of a `COPY .. TO STDOUT`, in chunks of bytes, see [`crate::Select::copy_out`]
+pub struct CopyOutStream<'a> {
+    stream: Pin<Box<dyn Stream<Item = Result<Vec<u8>, DbErr>> + Send + 'a>>,
+}
+
+impl std::fmt::Debug for CopyOutStream<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CopyOutStream")
+    }
+}
+
+impl<'a> CopyOutStream<'a> {
+    #[allow(dead_code)]
+    pub(crate) fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Vec<u8>, DbErr>> + Send + 'a,
+    {
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl Stream for CopyOutStream<'_> {
+    type Item = Result<Vec<u8>, DbErr>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.get_mut().stream.as_mut().poll_next(cx)
+    }
+}
+
+/// The connection a `COPY .. TO STDOUT` is run on
+#[cfg(feature = "sqlx-postgres")]
+pub(crate) enum CopyOutConnection<'a> {
+    Pool(Box<sqlx::pool::PoolConnection<sqlx::Postgres>>),
+    /// The connection of a transaction, which is locked until the copy ends
+    Transaction(futures::lock::MutexGuard<'a, crate::InnerConnection>),
+}
+
+/// A `COPY .. TO STDOUT` run on PostgreSQL
+#[cfg(feature = "sqlx-postgres")]
+#[ouroboros::self_referencing]
+pub(crate) struct PgCopyOut<'a> {
+    statement: String,
+    conn: CopyOutConnection<'a>,
+    #[borrows(mut conn, statement)]
+    #[not_covariant]
+    stream: futures::stream::BoxStream<'this, Result<Vec<u8>, DbErr>>,
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl<'a> PgCopyOut<'a> {
+    pub(crate) async fn start(
+        conn: CopyOutConnection<'a>,
+        statement: String,
+    ) -> Result<Self, DbErr> {
+        use futures::{StreamExt, TryStreamExt};
+
+        PgCopyOutAsyncSendTryBuilder {
+            statement,
+            conn,
+            stream_builder: |conn, statement| {
+                Box::pin(async move {
+                    let conn: &mut sqlx::PgConnection = match conn {
+                        CopyOutConnection::Pool(conn) => conn,
+                        CopyOutConnection::Transaction(conn) => match &mut **conn {
+                            crate::InnerConnection::Postgres(conn) => conn,
+                            #[allow(unreachable_patterns)]
+                            _ => return Err(crate::copy_not_supported()),
+                        },
+                    };
+                    let stream = conn
+                        .copy_out_raw(statement)
+                        .await
+                        .map_err(crate::sqlx_error_to_exec_err)?;
+                    Ok(stream
+                        .map_ok(Into::into)
+                        .map_err(crate::sqlx_error_to_query_err)
+                        .boxed())
+                })
+            },
+        }
+        .try_build()
+        .await
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl Stream for PgCopyOut<'_> {
+    type Item = Result<Vec<u8>, DbErr>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.get_mut()
+            .with_stream_mut(|stream| stream.as_mut().poll_next(cx))
+    }
+}
@@ -1,7 +1,9 @@
 mod metric;
 
+mod copy;
 mod query;
 mod transaction;
 
+pub use copy::*;
 pub use query::*;
 pub use transaction::*;
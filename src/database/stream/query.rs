@@ -21,6 +21,7 @@ pub struct QueryStream {
     stmt: Statement,
     conn: InnerConnection,
     metric_callback: Option<crate::metric::Callback>,
+    timezone_policy: crate::TimezonePolicy,
     #[borrows(mut conn, stmt, metric_callback)]
     #[not_covariant]
     stream: MetricStream<'this>,
@@ -43,6 +44,7 @@ impl QueryStream {
             stmt,
             conn,
             metric_callback,
+            timezone_policy: Default::default(),
             stream_builder: |conn, stmt, _metric_callback| match conn {
                 #[cfg(feature = "sqlx-mysql")]
                 InnerConnection::MySql(c) => {
@@ -57,12 +59,17 @@ impl QueryStream {
                 }
                 #[cfg(feature = "sqlx-postgres")]
                 InnerConnection::Postgres(c) => {
-                    let query = crate::driver::sqlx_postgres::sqlx_query(stmt);
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(sqlx_error_to_query_err);
+                    let stream = match crate::driver::sqlx_postgres::sqlx_query(stmt) {
+                        Ok(query) => futures::StreamExt::boxed(
+                            c.fetch(query)
+                                .map_ok(Into::into)
+                                .map_err(sqlx_error_to_query_err),
+                        ),
+                        Err(e) => {
+                            futures::StreamExt::boxed(futures::stream::once(async { Err(e) }))
+                        }
+                    };
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -91,12 +98,32 @@ impl QueryStream {
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
+                #[cfg(feature = "proxy")]
+                InnerConnection::Driver(drivers) => {
+                    let stream = match crate::current_driver(drivers) {
+                        Ok(driver) => driver.stream(stmt.clone()),
+                        Err(e) => Box::pin(futures::stream::once(async { Err(e) })),
+                    };
+                    MetricStream::new(
+                        _metric_callback,
+                        stmt,
+                        None,
+                        futures::StreamExt::map(stream, |row| row.map(Into::into)),
+                    )
+                }
                 #[allow(unreachable_patterns)]
                 _ => unreachable!(),
             },
         }
         .build()
     }
+
+    /// Read timestamps without a time zone with the policy of the connection
+    #[allow(dead_code)]
+    pub(crate) fn with_timezone(mut self, timezone: crate::TimezonePolicy) -> Self {
+        self.with_timezone_policy_mut(|timezone_policy| *timezone_policy = timezone);
+        self
+    }
 }
 
 impl Stream for QueryStream {
@@ -107,6 +134,8 @@ impl Stream for QueryStream {
         cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
         let this = self.get_mut();
+        let timezone = *this.borrow_timezone_policy();
         this.with_stream_mut(|stream| Pin::new(stream).poll_next(cx))
+            .map(|row| row.map(|row| row.map(|row| row.with_timezone(timezone))))
     }
 }
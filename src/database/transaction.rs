@@ -1,16 +1,29 @@
 use crate::{
     debug_print, error::*, AccessMode, ConnectionTrait, DbBackend, DbErr, ExecResult,
-    InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionStream,
-    TransactionTrait,
+    InnerConnection, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionConfig,
+    TransactionStream, TransactionTrait,
 };
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
 use futures::lock::Mutex;
 #[cfg(feature = "sqlx-dep")]
 use sqlx::TransactionManager;
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex as SyncMutex, MutexGuard},
+};
 use tracing::instrument;
 
+type Hook = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Callbacks waiting for a transaction to end
+#[derive(Default)]
+struct TransactionHooks {
+    on_commit: Vec<Hook>,
+    on_rollback: Vec<Hook>,
+}
+
 // a Transaction is just a sugar for a connection where START TRANSACTION has been executed
 /// Defines a database transaction, whether it is an open transaction and the type of
 /// backend to use
@@ -19,6 +32,11 @@ pub struct DatabaseTransaction {
     backend: DbBackend,
     open: bool,
     metric_callback: Option<crate::metric::Callback>,
+    hooks: Arc<SyncMutex<TransactionHooks>>,
+    parent_hooks: Option<Arc<SyncMutex<TransactionHooks>>>,
+    xid: Option<String>,
+    reset_session: Option<String>,
+    timezone: crate::TimezonePolicy,
 }
 
 impl std::fmt::Debug for DatabaseTransaction {
@@ -41,6 +59,11 @@ impl DatabaseTransaction {
             backend,
             open: true,
             metric_callback,
+            hooks: Default::default(),
+            parent_hooks: None,
+            xid: None,
+            reset_session: None,
+            timezone: Default::default(),
         };
         match *res.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
@@ -79,12 +102,70 @@ impl DatabaseTransaction {
                 c.begin();
                 Ok(())
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(ref mut c) => {
+                c.begin();
+                Ok(())
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Driver(ref mut drivers) => {
+                let driver = crate::current_driver(drivers)?
+                    .begin(isolation_level, access_mode)
+                    .await?;
+                drivers.push(driver.into());
+                Ok(())
+            }
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }?;
         Ok(res)
     }
 
+    /// Begin a transaction that can be prepared for a two-phase commit.
+    /// On MySQL, this starts an XA transaction instead of a regular one.
+    #[allow(dead_code)]
+    #[instrument(level = "trace", skip(metric_callback))]
+    pub(crate) async fn begin_two_phase(
+        conn: Arc<Mutex<InnerConnection>>,
+        backend: DbBackend,
+        metric_callback: Option<crate::metric::Callback>,
+        gid: &str,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        let xid = xid_literal(gid)?;
+        match backend {
+            DbBackend::Postgres => {
+                let mut res = Self::begin(conn, backend, metric_callback, None, None).await?;
+                res.xid = Some(xid);
+                Ok(res)
+            }
+            DbBackend::MySql => {
+                let res = DatabaseTransaction {
+                    conn,
+                    backend,
+                    open: true,
+                    metric_callback,
+                    hooks: Default::default(),
+                    parent_hooks: None,
+                    xid: Some(xid),
+                    reset_session: None,
+                    timezone: Default::default(),
+                };
+                res.execute_xa("XA START", "").await?;
+                Ok(res)
+            }
+            DbBackend::Sqlite => Err(DbErr::Custom(
+                "SQLite does not support two-phase commit".to_owned(),
+            )),
+        }
+    }
+
+    /// Read and bind timestamps without a time zone with the policy of the connection
+    #[allow(dead_code)]
+    pub(crate) fn with_timezone(mut self, timezone: crate::TimezonePolicy) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Runs a transaction to completion returning an rolling back the transaction on
     /// encountering an error if it fails
     #[instrument(level = "trace", skip(callback))]
@@ -112,6 +193,53 @@ impl DatabaseTransaction {
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
     pub async fn commit(mut self) -> Result<(), DbErr> {
+        let res = match self.mysql_xid() {
+            Some(_) => match self.execute_xa("XA END", "").await {
+                Ok(()) => self.execute_xa("XA COMMIT", " ONE PHASE").await,
+                Err(e) => Err(e),
+            },
+            None => self.commit_driver().await,
+        };
+        self.reset_session().await;
+        self.run_hooks(res.is_ok()).await;
+        res?;
+        self.open = false;
+        Ok(())
+    }
+
+    /// Prepare the transaction for a two-phase commit, under the transaction identifier given to
+    /// [`crate::DatabaseConnection::begin_two_phase`]. Its changes are persisted, but are only
+    /// visible once [`crate::DatabaseConnection::commit_prepared`] is called with the same identifier,
+    /// possibly from another connection; or they are discarded by [`crate::DatabaseConnection::rollback_prepared`].
+    ///
+    /// Callbacks registered with [`DatabaseTransaction::on_commit`] or [`DatabaseTransaction::on_rollback`] are discarded.
+    #[instrument(level = "trace")]
+    #[allow(unused_assignments)]
+    pub async fn prepare(mut self) -> Result<(), DbErr> {
+        match (self.backend, &self.xid) {
+            (DbBackend::MySql, Some(_)) => {
+                self.execute_xa("XA END", "").await?;
+                self.execute_xa("XA PREPARE", "").await?;
+            }
+            (_, Some(_)) => {
+                self.execute_xa("PREPARE TRANSACTION", "").await?;
+                // the session has left the transaction, which the driver still has to be told about;
+                // PostgreSQL only warns about the following `COMMIT`
+                self.commit_driver().await?;
+            }
+            (_, None) => {
+                return Err(DbErr::Custom(
+                    "Only a transaction begun with `begin_two_phase` can be prepared".to_owned(),
+                ))
+            }
+        }
+        *lock_hooks(&self.hooks) = Default::default();
+        self.open = false;
+        Ok(())
+    }
+
+    #[allow(unreachable_code, unused_mut)]
+    async fn commit_driver(&self) -> Result<(), DbErr> {
         match *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(ref mut c) => {
@@ -136,18 +264,30 @@ impl DatabaseTransaction {
                 c.commit();
                 Ok(())
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(ref mut c) => {
+                c.commit();
+                Ok(())
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Driver(ref mut drivers) => end_driver_transaction(drivers, true).await,
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
-        }?;
-        self.open = false;
-        Ok(())
+        }
     }
 
     /// rolls back a transaction in case error are encountered during the operation
     #[instrument(level = "trace")]
     #[allow(unreachable_code, unused_mut)]
     pub async fn rollback(mut self) -> Result<(), DbErr> {
-        match *self.conn.lock().await {
+        if self.mysql_xid().is_some() {
+            let res = match self.execute_xa("XA END", "").await {
+                Ok(()) => self.execute_xa("XA ROLLBACK", "").await,
+                Err(e) => Err(e),
+            };
+            return self.end_rollback(res).await;
+        }
+        let res = match *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(ref mut c) => {
                 <sqlx::MySql as sqlx::Database>::TransactionManager::rollback(c)
@@ -171,18 +311,252 @@ impl DatabaseTransaction {
                 c.rollback();
                 Ok(())
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(ref mut c) => {
+                c.rollback();
+                Ok(())
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Driver(ref mut drivers) => {
+                end_driver_transaction(drivers, false).await
+            }
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
-        }?;
+        };
+        self.end_rollback(res).await
+    }
+
+    async fn end_rollback(mut self, res: Result<(), DbErr>) -> Result<(), DbErr> {
+        self.reset_session().await;
+        self.run_hooks(false).await;
+        res?;
         self.open = false;
         Ok(())
     }
 
+    /// Register a callback that runs after the transaction is committed, e.g. to publish an event
+    /// or invalidate a cache only if the changes were persisted. Callbacks run in the order they
+    /// are registered. For a nested transaction, they wait until the outermost transaction is committed.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([MockExecResult {
+    /// #         last_insert_id: 1,
+    /// #         rows_affected: 1,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let txn = db.begin().await?;
+    /// cake::Entity::delete_by_id(1).exec(&txn).await?;
+    /// txn.on_commit(async move {
+    ///     println!("cake 1 deleted");
+    /// });
+    /// txn.commit().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_commit<F>(&self, callback: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        lock_hooks(&self.hooks).on_commit.push(Box::pin(callback));
+    }
+
+    /// Register a callback that runs after the transaction is rolled back, or fails to commit.
+    /// Callbacks of a nested transaction also run if the outermost transaction is rolled back.
+    /// A transaction dropped without being committed or rolled back discards its callbacks.
+    pub fn on_rollback<F>(&self, callback: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        lock_hooks(&self.hooks).on_rollback.push(Box::pin(callback));
+    }
+
+    /// Change a setting until the transaction ends, like `SET LOCAL`, e.g. `role` or a custom
+    /// setting such as `app.current_tenant` read by a row-level security policy.
+    /// As the setting is reset on commit or rollback, it never leaks to another user of a pooled connection.
+    ///
+    /// The value is bound as a parameter of `set_config`, so it does not need to be escaped.
+    /// This is only supported by PostgreSQL.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 1,
+    /// #     }])
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let txn = db.begin().await?;
+    /// txn.set_local("app.current_tenant", "42").await?;
+    /// let cakes = cake::Entity::find().all(&txn).await?;
+    /// txn.commit().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_local(&self, name: &str, value: &str) -> Result<(), DbErr> {
+        if self.backend != DbBackend::Postgres {
+            return Err(DbErr::Custom(format!(
+                "Transaction scoped settings aren't supported by {:?}",
+                self.backend
+            )));
+        }
+        self.execute(Statement::from_sql_and_values(
+            self.backend,
+            "SELECT set_config($1, $2, true)",
+            [name.into(), value.into()],
+        ))
+        .await?;
+        Ok(())
+    }
+
+    /// Apply the timeouts of a config to the transaction
+    pub(crate) async fn set_timeouts(&mut self, config: &TransactionConfig) -> Result<(), DbErr> {
+        let millis = |timeout: std::time::Duration| timeout.as_millis();
+        let settings = match self.backend {
+            DbBackend::Postgres => [
+                config
+                    .statement_timeout
+                    .map(|t| format!("SET LOCAL statement_timeout = {}", millis(t))),
+                config
+                    .lock_timeout
+                    .map(|t| format!("SET LOCAL lock_timeout = {}", millis(t))),
+            ],
+            DbBackend::MySql => [
+                config
+                    .statement_timeout
+                    .map(|t| format!("SET SESSION max_execution_time = {}", millis(t))),
+                config.lock_timeout.map(|t| {
+                    let secs = (millis(t) + 999) / 1000;
+                    format!("SET SESSION innodb_lock_wait_timeout = {}", secs.max(1))
+                }),
+            ],
+            DbBackend::Sqlite => {
+                if config.statement_timeout.is_some() || config.lock_timeout.is_some() {
+                    tracing::warn!("Setting timeouts in a SQLite transaction isn't supported");
+                }
+                return Ok(());
+            }
+        };
+        if self.backend == DbBackend::MySql {
+            // the session values are restored after the transaction, e.g. the ones set on connect
+            let mut names = Vec::new();
+            if config.statement_timeout.is_some() {
+                names.push("max_execution_time");
+            }
+            if config.lock_timeout.is_some() {
+                names.push("innodb_lock_wait_timeout");
+            }
+            if !names.is_empty() {
+                let select = names
+                    .iter()
+                    .map(|name| format!("CAST(@@SESSION.{name} AS SIGNED) AS {name}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let row = self
+                    .query_one(Statement::from_string(
+                        self.backend,
+                        format!("SELECT {select}"),
+                    ))
+                    .await?
+                    .ok_or_else(|| DbErr::RecordNotFound("The session settings".to_owned()))?;
+                let reset = names
+                    .iter()
+                    .map(|name| Ok(format!("{name} = {}", row.try_get::<i64>("", name)?)))
+                    .collect::<Result<Vec<_>, DbErr>>()?;
+                self.reset_session = Some(format!("SET SESSION {}", reset.join(", ")));
+            }
+        }
+        for sql in settings.into_iter().flatten() {
+            self.execute_unprepared(&sql).await?;
+        }
+        Ok(())
+    }
+
+    /// Restore the session settings changed by [`DatabaseTransaction::set_timeouts`] on MySQL.
+    /// A failure is only logged, as the transaction has already ended.
+    async fn reset_session(&mut self) {
+        if let Some(sql) = self.reset_session.take() {
+            if let Err(err) = self.execute_unprepared(&sql).await {
+                tracing::warn!("Failed to reset session settings: {err}");
+            }
+        }
+    }
+
+    /// The transaction identifier of a MySQL XA transaction, which is not managed by the driver
+    fn mysql_xid(&self) -> Option<&str> {
+        match self.backend {
+            DbBackend::MySql => self.xid.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Execute a statement of the two-phase commit, which takes the transaction identifier as a literal
+    async fn execute_xa(&self, command: &str, suffix: &str) -> Result<(), DbErr> {
+        let xid = self.xid.as_deref().unwrap_or_default();
+        self.execute_unprepared(&format!("{command} {xid}{suffix}"))
+            .await
+            .map(|_| ())
+    }
+
+    /// Run the callbacks of a transaction that ended, or hand them over to the
+    /// enclosing transaction if this nested transaction was committed
+    async fn run_hooks(&self, committed: bool) {
+        let hooks = std::mem::take(&mut *lock_hooks(&self.hooks));
+        match (&self.parent_hooks, committed) {
+            (Some(parent), true) => {
+                let mut parent = lock_hooks(parent);
+                parent.on_commit.extend(hooks.on_commit);
+                parent.on_rollback.extend(hooks.on_rollback);
+            }
+            (None, true) => {
+                for hook in hooks.on_commit {
+                    hook.await;
+                }
+            }
+            (_, false) => {
+                for hook in hooks.on_rollback {
+                    hook.await;
+                }
+            }
+        }
+    }
+
     // the rollback is queued and will be performed on next async operation, like returning the connection to the pool
     #[instrument(level = "trace")]
     fn start_rollback(&mut self) -> Result<(), DbErr> {
         if self.open {
             if let Some(mut conn) = self.conn.try_lock() {
+                // the session settings are restored once the transaction is rolled back
+                let reset_session = self.reset_session.clone();
+                // an XA transaction isn't managed by the driver, it has to be ended by statements
+                if let Some(xid) = self.mysql_xid() {
+                    let sqls = vec![format!("XA END {xid}"), format!("XA ROLLBACK {xid}")];
+                    self.execute_on_drop(
+                        &mut conn,
+                        sqls.into_iter().chain(reset_session).collect(),
+                    );
+                    return Ok(());
+                }
                 match &mut *conn {
                     #[cfg(feature = "sqlx-mysql")]
                     InnerConnection::MySql(c) => {
@@ -200,9 +574,21 @@ impl DatabaseTransaction {
                     InnerConnection::Mock(c) => {
                         c.rollback();
                     }
+                    #[cfg(feature = "proxy")]
+                    InnerConnection::Proxy(c) => {
+                        c.rollback();
+                    }
+                    #[cfg(feature = "proxy")]
+                    InnerConnection::Driver(drivers) => {
+                        // the driver running the transaction rolls it back once dropped
+                        if drivers.len() > 1 {
+                            drivers.pop();
+                        }
+                    }
                     #[allow(unreachable_patterns)]
                     _ => return Err(conn_err("Disconnected")),
                 }
+                self.execute_on_drop(&mut conn, reset_session.into_iter().collect());
             } else {
                 //this should never happen
                 return Err(conn_err("Dropping a locked Transaction"));
@@ -212,6 +598,100 @@ impl DatabaseTransaction {
     }
 }
 
+impl DatabaseTransaction {
+    /// Run statements on the connection of a dropped transaction before it's returned to the pool,
+    /// e.g. to end an XA transaction. On a pooled connection, they're run in the background on the
+    /// async runtime, which keeps the connection until they're done.
+    #[allow(unused_variables)]
+    fn execute_on_drop(&self, conn: &mut InnerConnection, sqls: Vec<String>) {
+        if sqls.is_empty() {
+            return;
+        }
+        match conn {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerConnection::MySql(_) => {
+                let conn = Arc::clone(&self.conn);
+                let spawned = crate::database::runtime::spawn(async move {
+                    #[allow(irrefutable_let_patterns)]
+                    if let InnerConnection::MySql(c) = &mut *conn.lock().await {
+                        for sql in sqls {
+                            let c: &mut sqlx::MySqlConnection = &mut *c;
+                            if let Err(err) = sqlx::Executor::execute(c, sql.as_str()).await {
+                                tracing::error!("Failed to clean up a dropped transaction: {err}");
+                                break;
+                            }
+                        }
+                    }
+                });
+                if !spawned {
+                    tracing::error!(
+                        "Failed to clean up a dropped transaction without an async runtime"
+                    );
+                }
+            }
+            #[cfg(feature = "mock")]
+            InnerConnection::Mock(c) => {
+                for sql in sqls {
+                    if let Err(err) = c.execute(Statement::from_string(self.backend, sql)) {
+                        tracing::error!("Failed to clean up a dropped transaction: {err}");
+                        break;
+                    }
+                }
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(c) => {
+                for sql in sqls {
+                    if let Err(err) = c.execute(Statement::from_string(self.backend, sql)) {
+                        tracing::error!("Failed to clean up a dropped transaction: {err}");
+                        break;
+                    }
+                }
+            }
+            #[allow(unreachable_patterns)]
+            _ => {}
+        }
+    }
+}
+
+/// Commit or roll back the innermost transaction of a driver. The transaction is kept open if this
+/// fails, to be rolled back once dropped.
+#[cfg(feature = "proxy")]
+async fn end_driver_transaction(
+    drivers: &mut Vec<Arc<dyn crate::DatabaseDriver>>,
+    commit: bool,
+) -> Result<(), DbErr> {
+    if drivers.len() < 2 {
+        return Err(conn_err("Not in a transaction"));
+    }
+    let driver = crate::current_driver(drivers)?;
+    if commit {
+        driver.commit().await?;
+    } else {
+        driver.rollback().await?;
+    }
+    drivers.pop();
+    Ok(())
+}
+
+/// Quote a transaction identifier of a two-phase commit, which can not be a bound parameter
+pub(crate) fn xid_literal(gid: &str) -> Result<String, DbErr> {
+    if gid.is_empty()
+        || gid.len() > 64
+        || !gid
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+    {
+        return Err(DbErr::Custom(format!(
+            "Invalid transaction identifier `{gid}`, expected up to 64 ASCII letters, digits, `-`, `_`, `.` or `:`"
+        )));
+    }
+    Ok(format!("'{gid}'"))
+}
+
+fn lock_hooks(hooks: &SyncMutex<TransactionHooks>) -> MutexGuard<'_, TransactionHooks> {
+    hooks.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 impl Drop for DatabaseTransaction {
     fn drop(&mut self) {
         self.start_rollback().expect("Fail to rollback transaction");
@@ -229,40 +709,51 @@ impl ConnectionTrait for DatabaseTransaction {
     #[allow(unused_variables)]
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(self.backend, stmt);
 
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
-                })
-                .map_err(sqlx_error_to_exec_err)
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                let conn: &mut sqlx::PgConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
-                })
-                .map_err(sqlx_error_to_exec_err)
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query.execute(conn).await.map(Into::into)
-                })
-                .map_err(sqlx_error_to_exec_err)
+        crate::with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+            match &mut *self.conn.lock().await {
+                #[cfg(feature = "sqlx-mysql")]
+                InnerConnection::MySql(conn) => {
+                    let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                    let conn: &mut sqlx::MySqlConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        query.execute(conn).await.map(Into::into)
+                    })
+                    .map_err(sqlx_error_to_exec_err)
+                }
+                #[cfg(feature = "sqlx-postgres")]
+                InnerConnection::Postgres(conn) => {
+                    let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                    let conn: &mut sqlx::PgConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        query.execute(conn).await.map(Into::into)
+                    })
+                    .map_err(sqlx_error_to_exec_err)
+                }
+                #[cfg(feature = "sqlx-sqlite")]
+                InnerConnection::Sqlite(conn) => {
+                    let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                    let conn: &mut sqlx::SqliteConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        query.execute(conn).await.map(Into::into)
+                    })
+                    .map_err(sqlx_error_to_exec_err)
+                }
+                #[cfg(feature = "mock")]
+                InnerConnection::Mock(conn) => conn.execute(stmt.clone()),
+                #[cfg(feature = "proxy")]
+                InnerConnection::Proxy(conn) => conn.execute(stmt.clone()),
+                #[cfg(feature = "proxy")]
+                InnerConnection::Driver(drivers) => crate::current_driver(drivers)?
+                    .execute(stmt.clone())
+                    .await
+                    .map(Into::into),
+                #[allow(unreachable_patterns)]
+                _ => Err(conn_err("Disconnected")),
             }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.execute(stmt),
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }
+        })
+        .await
     }
 
     #[instrument(level = "trace")]
@@ -301,6 +792,17 @@ impl ConnectionTrait for DatabaseTransaction {
                 let stmt = Statement::from_string(db_backend, sql);
                 conn.execute(stmt)
             }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Proxy(conn) => {
+                let db_backend = conn.get_database_backend();
+                let stmt = Statement::from_string(db_backend, sql);
+                conn.execute(stmt)
+            }
+            #[cfg(feature = "proxy")]
+            InnerConnection::Driver(drivers) => crate::current_driver(drivers)?
+                .execute_unprepared(sql)
+                .await
+                .map(Into::into),
             #[allow(unreachable_patterns)]
             _ => Err(conn_err("Disconnected")),
         }
@@ -310,92 +812,191 @@ impl ConnectionTrait for DatabaseTransaction {
     #[allow(unused_variables)]
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(self.backend, stmt);
 
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    crate::sqlx_map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
-                    )
-                })
-            }
-            #[cfg(feature = "sqlx-postgres")]
-            InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                let conn: &mut sqlx::PgConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    crate::sqlx_map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
-                    )
-                })
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    crate::sqlx_map_err_ignore_not_found(
-                        query.fetch_one(conn).await.map(|row| Some(row.into())),
-                    )
-                })
+        crate::with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+            match &mut *self.conn.lock().await {
+                #[cfg(feature = "sqlx-mysql")]
+                InnerConnection::MySql(conn) => {
+                    let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                    let conn: &mut sqlx::MySqlConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::sqlx_map_err_ignore_not_found(
+                            query.fetch_one(conn).await.map(|row| {
+                                Some(QueryResult::from(row).with_timezone(self.timezone))
+                            }),
+                        )
+                    })
+                }
+                #[cfg(feature = "sqlx-postgres")]
+                InnerConnection::Postgres(conn) => {
+                    let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                    let conn: &mut sqlx::PgConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::sqlx_map_err_ignore_not_found(
+                            query.fetch_one(conn).await.map(|row| {
+                                Some(QueryResult::from(row).with_timezone(self.timezone))
+                            }),
+                        )
+                    })
+                }
+                #[cfg(feature = "sqlx-sqlite")]
+                InnerConnection::Sqlite(conn) => {
+                    let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                    let conn: &mut sqlx::SqliteConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        crate::sqlx_map_err_ignore_not_found(
+                            query.fetch_one(conn).await.map(|row| {
+                                Some(QueryResult::from(row).with_timezone(self.timezone))
+                            }),
+                        )
+                    })
+                }
+                #[cfg(feature = "mock")]
+                InnerConnection::Mock(conn) => conn.query_one(stmt.clone()),
+                #[cfg(feature = "proxy")]
+                InnerConnection::Proxy(conn) => conn.query_one(stmt.clone()),
+                #[cfg(feature = "proxy")]
+                InnerConnection::Driver(drivers) => crate::current_driver(drivers)?
+                    .query_one(stmt.clone())
+                    .await
+                    .map(|row| row.map(Into::into)),
+                #[allow(unreachable_patterns)]
+                _ => Err(conn_err("Disconnected")),
             }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.query_one(stmt),
-            #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
-        }
+        })
+        .await
     }
 
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(self.backend, stmt);
 
-        match &mut *self.conn.lock().await {
-            #[cfg(feature = "sqlx-mysql")]
-            InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                let conn: &mut sqlx::MySqlConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
-                        .await
-                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
-                        .map_err(sqlx_error_to_query_err)
-                })
+        crate::with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+            match &mut *self.conn.lock().await {
+                #[cfg(feature = "sqlx-mysql")]
+                InnerConnection::MySql(conn) => {
+                    let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                    let conn: &mut sqlx::MySqlConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        query
+                            .fetch_all(conn)
+                            .await
+                            .map(|rows| {
+                                rows.into_iter()
+                                    .map(|r| QueryResult::from(r).with_timezone(self.timezone))
+                                    .collect()
+                            })
+                            .map_err(sqlx_error_to_query_err)
+                    })
+                }
+                #[cfg(feature = "sqlx-postgres")]
+                InnerConnection::Postgres(conn) => {
+                    let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                    let conn: &mut sqlx::PgConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        query
+                            .fetch_all(conn)
+                            .await
+                            .map(|rows| {
+                                rows.into_iter()
+                                    .map(|r| QueryResult::from(r).with_timezone(self.timezone))
+                                    .collect()
+                            })
+                            .map_err(sqlx_error_to_query_err)
+                    })
+                }
+                #[cfg(feature = "sqlx-sqlite")]
+                InnerConnection::Sqlite(conn) => {
+                    let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                    let conn: &mut sqlx::SqliteConnection = &mut *conn;
+                    crate::metric::metric!(self.metric_callback, &stmt, {
+                        query
+                            .fetch_all(conn)
+                            .await
+                            .map(|rows| {
+                                rows.into_iter()
+                                    .map(|r| QueryResult::from(r).with_timezone(self.timezone))
+                                    .collect()
+                            })
+                            .map_err(sqlx_error_to_query_err)
+                    })
+                }
+                #[cfg(feature = "mock")]
+                InnerConnection::Mock(conn) => conn.query_all(stmt.clone()),
+                #[cfg(feature = "proxy")]
+                InnerConnection::Proxy(conn) => conn.query_all(stmt.clone()),
+                #[cfg(feature = "proxy")]
+                InnerConnection::Driver(drivers) => crate::current_driver(drivers)?
+                    .query_all(stmt.clone())
+                    .await
+                    .map(|rows| rows.into_iter().map(Into::into).collect()),
+                #[allow(unreachable_patterns)]
+                _ => Err(conn_err("Disconnected")),
             }
+        })
+        .await
+    }
+    #[instrument(level = "trace")]
+    async fn query_pipeline(&self, stmts: Vec<Statement>) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+        #[cfg(feature = "sqlx-postgres")]
+        #[allow(irrefutable_let_patterns)]
+        if let InnerConnection::Postgres(conn) = &mut *self.conn.lock().await {
+            let results = crate::driver::sqlx_postgres::sqlx_query_pipeline(conn, &stmts).await?;
+            return Ok(results
+                .into_iter()
+                .map(|rows| {
+                    rows.into_iter()
+                        .map(|row| row.with_timezone(self.timezone))
+                        .collect()
+                })
+                .collect());
+        }
+        let mut results = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            results.push(self.query_all(stmt).await?);
+        }
+        Ok(results)
+    }
+
+    #[instrument(level = "trace", skip(data))]
+    #[allow(unused_variables)]
+    async fn copy_in_raw(
+        &self,
+        statement: &str,
+        data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        debug_print!("{}", statement);
+
+        match &mut *self.conn.lock().await {
             #[cfg(feature = "sqlx-postgres")]
             InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                let conn: &mut sqlx::PgConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
-                        .await
-                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
-                        .map_err(sqlx_error_to_query_err)
-                })
-            }
-            #[cfg(feature = "sqlx-sqlite")]
-            InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                let conn: &mut sqlx::SqliteConnection = &mut *conn;
-                crate::metric::metric!(self.metric_callback, &stmt, {
-                    query
-                        .fetch_all(conn)
-                        .await
-                        .map(|rows| rows.into_iter().map(|r| r.into()).collect())
-                        .map_err(sqlx_error_to_query_err)
-                })
+                let copy = conn
+                    .copy_in_raw(statement)
+                    .await
+                    .map_err(sqlx_error_to_exec_err)?;
+                crate::driver::sqlx_postgres::sqlx_copy_in(copy, data).await
             }
-            #[cfg(feature = "mock")]
-            InnerConnection::Mock(conn) => return conn.query_all(stmt),
             #[allow(unreachable_patterns)]
-            _ => Err(conn_err("Disconnected")),
+            _ => Err(crate::copy_not_supported()),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    #[allow(unused_variables)]
+    async fn copy_out_raw(&self, statement: &str) -> Result<crate::CopyOutStream<'_>, DbErr> {
+        debug_print!("{}", statement);
+
+        #[cfg(feature = "sqlx-postgres")]
+        {
+            let conn = crate::CopyOutConnection::Transaction(self.conn.lock().await);
+            let stream = crate::PgCopyOut::start(conn, statement.to_owned()).await?;
+            Ok(crate::CopyOutStream::new(stream))
         }
+        #[cfg(not(feature = "sqlx-postgres"))]
+        Err(crate::copy_not_supported())
     }
 }
 
@@ -409,11 +1010,11 @@ impl StreamTrait for DatabaseTransaction {
     ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
         Box::pin(async move {
             let conn = self.conn.lock().await;
-            Ok(crate::TransactionStream::build(
-                conn,
-                stmt,
-                self.metric_callback.clone(),
-            ))
+            let stmt = self.timezone.bind(self.backend, stmt);
+            Ok(
+                crate::TransactionStream::build(conn, stmt, self.metric_callback.clone())
+                    .with_timezone(self.timezone),
+            )
         })
     }
 }
@@ -422,14 +1023,22 @@ impl StreamTrait for DatabaseTransaction {
 impl TransactionTrait for DatabaseTransaction {
     #[instrument(level = "trace")]
     async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
-        DatabaseTransaction::begin(
+        if self.mysql_xid().is_some() {
+            return Err(DbErr::Custom(
+                "An XA transaction can not be nested".to_owned(),
+            ));
+        }
+        let mut transaction = DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
             self.metric_callback.clone(),
             None,
             None,
         )
-        .await
+        .await?;
+        transaction.parent_hooks = Some(Arc::clone(&self.hooks));
+        transaction.timezone = self.timezone;
+        Ok(transaction)
     }
 
     #[instrument(level = "trace")]
@@ -438,14 +1047,22 @@ impl TransactionTrait for DatabaseTransaction {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        DatabaseTransaction::begin(
+        if self.mysql_xid().is_some() {
+            return Err(DbErr::Custom(
+                "An XA transaction can not be nested".to_owned(),
+            ));
+        }
+        let mut transaction = DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
             self.metric_callback.clone(),
             isolation_level,
             access_mode,
         )
-        .await
+        .await?;
+        transaction.parent_hooks = Some(Arc::clone(&self.hooks));
+        transaction.timezone = self.timezone;
+        Ok(transaction)
     }
 
     /// Execute the function inside a transaction.
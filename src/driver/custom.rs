@@ -0,0 +1,145 @@
+use crate::{
+    debug_print, error::*, AccessMode, Database, DatabaseConnection, DatabaseDriver,
+    DatabaseTransaction, DbBackend, ExecResult, InnerConnection, IsolationLevel, QueryResult,
+    Statement,
+};
+use futures::lock::Mutex;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Defines a connection to a [DatabaseDriver]
+#[derive(Debug)]
+pub struct DriverConnection {
+    driver: Arc<dyn DatabaseDriver>,
+}
+
+impl Database {
+    /// Method to create a [DatabaseConnection] on a custom [DatabaseDriver]
+    pub async fn connect_driver<D>(driver: D) -> Result<DatabaseConnection, DbErr>
+    where
+        D: DatabaseDriver + 'static,
+    {
+        Ok(DatabaseConnection::DriverConnection(Arc::new(
+            DriverConnection {
+                driver: Arc::new(driver),
+            },
+        )))
+    }
+}
+
+impl DriverConnection {
+    /// Get the [DatabaseBackend](crate::DatabaseBackend) of the driver
+    pub fn get_database_backend(&self) -> DbBackend {
+        self.driver.get_database_backend()
+    }
+
+    /// Get the driver
+    pub fn driver(&self) -> &Arc<dyn DatabaseDriver> {
+        &self.driver
+    }
+
+    /// Execute a [Statement] with the driver
+    #[instrument(level = "trace")]
+    pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+        let token = stmt.cancel_token.clone();
+        crate::with_cancellation(stmt.timeout, token.as_ref(), async {
+            self.driver.execute(stmt).await.map(Into::into)
+        })
+        .await
+    }
+
+    /// Execute an unprepared SQL statement with the driver
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+        self.driver.execute_unprepared(sql).await.map(Into::into)
+    }
+
+    /// Get one result from a SQL query. Returns [Option::None] if no match was found
+    #[instrument(level = "trace")]
+    pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+        let token = stmt.cancel_token.clone();
+        crate::with_cancellation(stmt.timeout, token.as_ref(), async {
+            self.driver
+                .query_one(stmt)
+                .await
+                .map(|row| row.map(Into::into))
+        })
+        .await
+    }
+
+    /// Get the results of a query returning them as a Vec<[QueryResult]>
+    #[instrument(level = "trace")]
+    pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+        let token = stmt.cancel_token.clone();
+        crate::with_cancellation(stmt.timeout, token.as_ref(), async {
+            self.driver
+                .query_all(stmt)
+                .await
+                .map(|rows| rows.into_iter().map(Into::into).collect())
+        })
+        .await
+    }
+
+    /// Stream the results of executing a SQL query
+    #[instrument(level = "trace")]
+    pub fn stream(&self, stmt: Statement) -> crate::QueryStream {
+        debug_print!("{}", stmt);
+        crate::QueryStream::build(
+            stmt,
+            InnerConnection::Driver(vec![Arc::clone(&self.driver)]),
+            None,
+        )
+    }
+
+    /// Bundle a set of SQL statements that execute together.
+    #[instrument(level = "trace")]
+    pub async fn begin(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        DatabaseTransaction::begin(
+            Arc::new(Mutex::new(InnerConnection::Driver(vec![Arc::clone(
+                &self.driver,
+            )]))),
+            self.get_database_backend(),
+            None,
+            isolation_level,
+            access_mode,
+        )
+        .await
+    }
+
+    /// Begin a transaction which can be prepared for a two-phase commit
+    #[instrument(level = "trace")]
+    pub async fn begin_two_phase(&self, gid: &str) -> Result<DatabaseTransaction, DbErr> {
+        DatabaseTransaction::begin_two_phase(
+            Arc::new(Mutex::new(InnerConnection::Driver(vec![Arc::clone(
+                &self.driver,
+            )]))),
+            self.get_database_backend(),
+            None,
+            gid,
+        )
+        .await
+    }
+
+    /// Checks if a connection to the database is still valid.
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        self.driver.ping().await
+    }
+}
+
+/// The driver running the innermost transaction, or the driver of the connection
+pub(crate) fn current_driver(
+    drivers: &[Arc<dyn DatabaseDriver>],
+) -> Result<&dyn DatabaseDriver, DbErr> {
+    drivers
+        .last()
+        .map(AsRef::as_ref)
+        .ok_or_else(|| conn_err("Disconnected"))
+}
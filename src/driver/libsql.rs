@@ -0,0 +1,634 @@
+use crate::{
+    error::*, AccessMode, Database, DatabaseConnection, DatabaseDriver, DbBackend, IsolationLevel,
+    ProxyExecResult, ProxyRow, Statement,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sea_query::Value;
+use serde_json::{json, Value as Json};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// Sends the HTTP requests of the libSQL remote protocol, with the HTTP client of the application
+pub trait LibsqlTransport: Send + Sync + std::fmt::Debug {
+    /// `POST` a JSON body to the URL, with the token as `Authorization: Bearer <token>` if any,
+    /// and return the JSON body of the response. A response with an error status should be
+    /// returned as an error.
+    fn post(&self, url: &str, auth_token: Option<&str>, body: &Json) -> Result<Json, DbErr>;
+}
+
+/// A libSQL database, e.g. on Turso, speaking the Hrana protocol over HTTP, see
+/// [`Database::connect_libsql`]. Its entities and queries are the ones of SQLite.
+///
+/// Each transaction is kept open on a stream of its own, so the statements of a transaction are
+/// never mixed up with the ones of another task running on the same connection.
+///
+/// Columns are read as integers, reals, text or blobs, so a model can only have fields of the
+/// types of [`Value`] these convert to, e.g. integers, `bool`, floats, `String` and `Vec<u8>`.
+///
+/// ```
+/// # use sea_orm::{error::*, *};
+/// # use serde_json::Value as Json;
+/// #[derive(Debug)]
+/// struct Client;
+///
+/// impl LibsqlTransport for Client {
+///     fn post(&self, url: &str, auth_token: Option<&str>, body: &Json) -> Result<Json, DbErr> {
+///         // e.g. `ureq::post(url).set("Authorization", ..).send_json(body)`
+///         # unimplemented!()
+///     }
+/// }
+///
+/// # async fn connect() -> Result<(), DbErr> {
+/// let db = Database::connect_libsql(
+///     LibsqlDatabase::new("libsql://bakery-sea.turso.io", Client).auth_token("token"),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LibsqlDatabase {
+    client: Arc<Client>,
+    /// The stream of the transaction this database runs, shared with its nested transactions
+    stream: Option<Arc<Mutex<Stream>>>,
+    /// The number of nested transactions this database runs
+    depth: usize,
+    /// Whether the transaction this database runs has been committed or rolled back
+    ended: AtomicBool,
+}
+
+/// Sends the requests to the database
+#[derive(Debug)]
+struct Client {
+    url: String,
+    auth_token: Option<String>,
+    transport: Box<dyn LibsqlTransport>,
+}
+
+/// The response to a pipeline of requests
+#[derive(Debug)]
+struct Pipeline {
+    /// The baton to continue the stream with
+    baton: Option<String>,
+    /// The URL to send the next requests of the stream to, if not the one of the database
+    base_url: Option<String>,
+    results: Vec<Result<Json, DbErr>>,
+}
+
+/// A stream of the protocol, on which the transaction is kept open between requests
+#[derive(Debug)]
+struct Stream {
+    baton: String,
+    base_url: Option<String>,
+}
+
+impl Database {
+    /// Connect to a libSQL database, see [`LibsqlDatabase`]
+    pub async fn connect_libsql(db: LibsqlDatabase) -> Result<DatabaseConnection, DbErr> {
+        db.ping().await?;
+        Database::connect_driver(db).await
+    }
+}
+
+impl LibsqlDatabase {
+    /// Create a database from its URL, which may start with `libsql://`, `https://` or `http://`
+    pub fn new<U, T>(url: U, transport: T) -> Self
+    where
+        U: Into<String>,
+        T: LibsqlTransport + 'static,
+    {
+        let url = url.into();
+        let url = match url.strip_prefix("libsql://") {
+            Some(host) => format!("https://{host}"),
+            None => url,
+        };
+        Self {
+            client: Arc::new(Client {
+                url: url.trim_end_matches('/').to_owned(),
+                auth_token: None,
+                transport: Box::new(transport),
+            }),
+            stream: None,
+            depth: 0,
+            ended: AtomicBool::new(false),
+        }
+    }
+
+    /// Set the token authenticating the requests
+    pub fn auth_token<T>(mut self, token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        if let Some(client) = Arc::get_mut(&mut self.client) {
+            client.auth_token = Some(token.into());
+        }
+        self
+    }
+
+    /// Run a statement, in the transaction this database runs if any
+    fn run(&self, stmt: Json) -> Result<Json, DbErr> {
+        let mut requests = vec![json!({ "type": "execute", "stmt": stmt })];
+        if self.stream.is_none() {
+            requests.push(json!({ "type": "close" }));
+        }
+        self.pipeline(requests)?.results.swap_remove(0)
+    }
+
+    /// Send requests on the stream of the transaction, or else on a new stream
+    fn pipeline(&self, requests: Vec<Json>) -> Result<Pipeline, DbErr> {
+        let mut stream = match &self.stream {
+            Some(stream) => Some(
+                stream
+                    .lock()
+                    .map_err(|e| conn_err(format!("Failed to lock the libSQL stream: {e}")))?,
+            ),
+            None => None,
+        };
+        let res = self.client.pipeline(stream.as_deref(), requests)?;
+        if let (Some(stream), Some(baton)) = (stream.as_mut(), &res.baton) {
+            stream.baton = baton.clone();
+            stream.base_url = res.base_url.clone().or(stream.base_url.take());
+        }
+        Ok(res)
+    }
+
+    /// End the transaction this database runs, closing the stream after the outermost one
+    fn end(&self, commit: bool) -> Result<(), DbErr> {
+        if self.depth == 0 || self.ended.load(Ordering::SeqCst) {
+            return Err(conn_err("Not in a transaction"));
+        }
+        let sqls = match (commit, self.depth) {
+            (true, 1) => vec!["COMMIT".to_owned()],
+            (false, 1) => vec!["ROLLBACK".to_owned()],
+            (true, depth) => vec![format!("RELEASE SAVEPOINT sea_orm_{depth}")],
+            (false, depth) => vec![
+                format!("ROLLBACK TO SAVEPOINT sea_orm_{depth}"),
+                format!("RELEASE SAVEPOINT sea_orm_{depth}"),
+            ],
+        };
+        let mut requests: Vec<_> = sqls
+            .into_iter()
+            .map(|sql| json!({ "type": "execute", "stmt": { "sql": sql } }))
+            .collect();
+        if self.depth == 1 {
+            requests.push(json!({ "type": "close" }));
+        }
+        for result in self.pipeline(requests)?.results {
+            result?;
+        }
+        self.ended.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl Client {
+    /// Send requests on a stream, or on a new one
+    fn pipeline(&self, stream: Option<&Stream>, requests: Vec<Json>) -> Result<Pipeline, DbErr> {
+        let url = match stream.and_then(|s| s.base_url.as_deref()) {
+            Some(base_url) => format!("{}/v2/pipeline", base_url.trim_end_matches('/')),
+            None => format!("{}/v2/pipeline", self.url),
+        };
+        let body = json!({
+            "baton": stream.map(|s| s.baton.as_str()),
+            "requests": requests,
+        });
+        let res = self
+            .transport
+            .post(&url, self.auth_token.as_deref(), &body)?;
+        let results = res["results"]
+            .as_array()
+            .ok_or_else(|| protocol_err("a pipeline response without results"))?
+            .iter()
+            .map(|result| match result["type"].as_str() {
+                Some("ok") => Ok(result["response"]["result"].clone()),
+                Some("error") => Err(DbErr::Query(RuntimeErr::Internal(
+                    result["error"]["message"]
+                        .as_str()
+                        .unwrap_or("Unknown libSQL error")
+                        .to_owned(),
+                ))),
+                _ => Err(protocol_err("a result which is neither ok nor an error")),
+            })
+            .collect::<Vec<_>>();
+        if results.len() != requests.len() {
+            return Err(protocol_err("a result for each request"));
+        }
+        Ok(Pipeline {
+            baton: res["baton"].as_str().map(ToOwned::to_owned),
+            base_url: res["base_url"].as_str().map(ToOwned::to_owned),
+            results,
+        })
+    }
+}
+
+/// Roll back a transaction which has not been committed or rolled back
+impl Drop for LibsqlDatabase {
+    fn drop(&mut self) {
+        if self.depth > 0 && !self.ended.load(Ordering::SeqCst) {
+            if let Err(err) = self.end(false) {
+                tracing::warn!("Failed to roll back the libSQL transaction: {err}");
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatabaseDriver for LibsqlDatabase {
+    fn get_database_backend(&self) -> DbBackend {
+        DbBackend::Sqlite
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        let result = self
+            .run(to_libsql_stmt(&statement)?)
+            .map_err(|err| match err {
+                DbErr::Query(err) => DbErr::Exec(err),
+                err => err,
+            })?;
+        let last_insert_id = result["last_insert_rowid"]
+            .as_str()
+            .and_then(|id| id.parse().ok())
+            .unwrap_or_default();
+        let rows_affected = result["affected_row_count"].as_u64().unwrap_or_default();
+        Ok(ProxyExecResult::new(last_insert_id, rows_affected))
+    }
+
+    async fn query_all(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        let result = self.run(to_libsql_stmt(&statement)?)?;
+        let cols = result["cols"]
+            .as_array()
+            .ok_or_else(|| protocol_err("a result without columns"))?;
+        let rows = result["rows"]
+            .as_array()
+            .ok_or_else(|| protocol_err("a result without rows"))?;
+        rows.iter()
+            .map(|row| {
+                let values = row
+                    .as_array()
+                    .ok_or_else(|| protocol_err("a row which is not an array"))?;
+                cols.iter()
+                    .zip(values)
+                    .map(|(col, value)| {
+                        let name = col["name"].as_str().unwrap_or_default().to_owned();
+                        Ok((name, from_libsql_value(value, col["decltype"].as_str())?))
+                    })
+                    .collect::<Result<BTreeMap<_, _>, DbErr>>()
+                    .map(ProxyRow::new)
+            })
+            .collect()
+    }
+
+    async fn begin(
+        &self,
+        _isolation_level: Option<IsolationLevel>,
+        _access_mode: Option<AccessMode>,
+    ) -> Result<Box<dyn DatabaseDriver>, DbErr> {
+        let depth = self.depth + 1;
+        let stream = match &self.stream {
+            // a nested transaction is a savepoint on the stream of the outer one
+            Some(stream) => {
+                self.run(json!({ "sql": format!("SAVEPOINT sea_orm_{depth}") }))?;
+                Arc::clone(stream)
+            }
+            // the outermost transaction opens a stream of its own
+            None => {
+                let begin = json!({ "type": "execute", "stmt": { "sql": "BEGIN" } });
+                let mut res = self.client.pipeline(None, vec![begin])?;
+                res.results.swap_remove(0)?;
+                Arc::new(Mutex::new(Stream {
+                    baton: res
+                        .baton
+                        .ok_or_else(|| protocol_err("a baton for the transaction"))?,
+                    base_url: res.base_url,
+                }))
+            }
+        };
+        Ok(Box::new(Self {
+            client: Arc::clone(&self.client),
+            stream: Some(stream),
+            depth,
+            ended: AtomicBool::new(false),
+        }))
+    }
+
+    async fn commit(&self) -> Result<(), DbErr> {
+        self.end(true)
+    }
+
+    async fn rollback(&self) -> Result<(), DbErr> {
+        self.end(false)
+    }
+
+    async fn ping(&self) -> Result<(), DbErr> {
+        self.run(json!({ "sql": "SELECT 1" })).map(|_| ())
+    }
+}
+
+fn protocol_err(expected: &str) -> DbErr {
+    conn_err(format!("Invalid libSQL response, expected {expected}"))
+}
+
+fn to_libsql_stmt(statement: &Statement) -> Result<Json, DbErr> {
+    let args = match &statement.values {
+        Some(values) => values.0.iter().map(to_libsql_value).collect(),
+        None => Vec::new(),
+    };
+    Ok(json!({ "sql": statement.sql, "args": args, "want_rows": true }))
+}
+
+fn to_libsql_value(value: &Value) -> Json {
+    if let Value::Bytes(Some(bytes)) = value {
+        return json!({ "type": "blob", "base64": STANDARD.encode(bytes.as_slice()) });
+    }
+    // other values are stored in the representation of SQLite, e.g. a date as text
+    match sea_query::sea_value_to_json_value(value) {
+        Json::Null => json!({ "type": "null" }),
+        Json::Bool(v) => json!({ "type": "integer", "value": (v as i64).to_string() }),
+        Json::Number(v) if v.is_f64() => json!({ "type": "float", "value": v }),
+        Json::Number(v) => json!({ "type": "integer", "value": v.to_string() }),
+        Json::String(v) => json!({ "type": "text", "value": v }),
+        v => json!({ "type": "text", "value": v.to_string() }),
+    }
+}
+
+fn from_libsql_value(value: &Json, decltype: Option<&str>) -> Result<Value, DbErr> {
+    let value = match value["type"].as_str() {
+        Some("integer") => Value::BigInt(Some(
+            value["value"]
+                .as_str()
+                .and_then(|v| v.parse().ok())
+                .ok_or_else(|| protocol_err("an integer as a string"))?,
+        )),
+        Some("float") => Value::Double(Some(
+            value["value"]
+                .as_f64()
+                .ok_or_else(|| protocol_err("a float as a number"))?,
+        )),
+        Some("text") => Value::String(Some(Box::new(
+            value["value"]
+                .as_str()
+                .ok_or_else(|| protocol_err("a text as a string"))?
+                .to_owned(),
+        ))),
+        Some("blob") => Value::Bytes(Some(Box::new(
+            value["base64"]
+                .as_str()
+                .and_then(|v| STANDARD.decode(v).ok())
+                .ok_or_else(|| protocol_err("a blob in base64"))?,
+        ))),
+        Some("null") => {
+            // the type of a null value follows the affinity rules of SQLite
+            let decltype = decltype.unwrap_or_default().to_uppercase();
+            if decltype.contains("INT") {
+                Value::BigInt(None)
+            } else if ["REAL", "FLOA", "DOUB"]
+                .iter()
+                .any(|t| decltype.contains(t))
+            {
+                Value::Double(None)
+            } else if decltype.contains("BLOB") {
+                Value::Bytes(None)
+            } else {
+                Value::String(None)
+            }
+        }
+        _ => return Err(protocol_err("a value of a known type")),
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{entity::*, tests_cfg::*, TransactionTrait};
+    use pretty_assertions::assert_eq;
+
+    /// Records the requests and answers them with queued responses
+    #[derive(Debug, Default)]
+    struct Transport {
+        requests: Arc<Mutex<Vec<(String, Json)>>>,
+        responses: Mutex<Vec<Json>>,
+    }
+
+    impl LibsqlTransport for Transport {
+        fn post(&self, url: &str, auth_token: Option<&str>, body: &Json) -> Result<Json, DbErr> {
+            assert_eq!(auth_token, Some("token"));
+            if let Ok(mut requests) = self.requests.lock() {
+                requests.push((url.to_owned(), body.clone()));
+            }
+            self.responses
+                .lock()
+                .map_err(|e| conn_err(e.to_string()))?
+                .pop()
+                .ok_or_else(|| conn_err("No response"))
+        }
+    }
+
+    fn ok(baton: Option<&str>, result: Json, close: bool) -> Json {
+        let mut results = vec![json!({
+            "type": "ok",
+            "response": { "type": "execute", "result": result },
+        })];
+        if close {
+            results.push(json!({ "type": "ok", "response": { "type": "close" } }));
+        }
+        json!({ "baton": baton, "base_url": null, "results": results })
+    }
+
+    fn exec(rows_affected: u64, last_insert_rowid: Option<&str>) -> Json {
+        json!({
+            "cols": [],
+            "rows": [],
+            "affected_row_count": rows_affected,
+            "last_insert_rowid": last_insert_rowid,
+        })
+    }
+
+    #[smol_potat::test]
+    async fn libsql() -> Result<(), DbErr> {
+        let requests = Arc::default();
+        let mut responses = vec![
+            ok(None, exec(0, None), true),
+            ok(
+                None,
+                json!({
+                    "cols": [
+                        { "name": "id", "decltype": "INTEGER" },
+                        { "name": "name", "decltype": "TEXT" },
+                    ],
+                    "rows": [[
+                        { "type": "integer", "value": "1" },
+                        { "type": "text", "value": "Cheese" },
+                    ]],
+                    "affected_row_count": 0,
+                    "last_insert_rowid": null,
+                }),
+                true,
+            ),
+            ok(Some("b1"), exec(0, None), false),
+            ok(Some("b2"), exec(1, Some("2")), false),
+            ok(None, exec(0, None), true),
+        ];
+        responses.reverse();
+        let transport = Transport {
+            requests: Arc::clone(&requests),
+            responses: Mutex::new(responses),
+        };
+        let db = Database::connect_libsql(
+            LibsqlDatabase::new("libsql://bakery.turso.io/", transport).auth_token("token"),
+        )
+        .await?;
+
+        assert_eq!(
+            cake::Entity::find_by_id(1).one(&db).await?,
+            Some(cake::Model {
+                id: 1,
+                name: "Cheese".to_owned(),
+            })
+        );
+
+        let txn = db.begin().await?;
+        let res = cake::Entity::insert(cake::ActiveModel {
+            name: Set("Chocolate".to_owned()),
+            ..Default::default()
+        })
+        .exec_without_returning(&txn)
+        .await?;
+        assert_eq!(res, 1);
+        txn.commit().await?;
+
+        let requests = requests.lock().map_err(|e| conn_err(e.to_string()))?;
+        assert!(requests
+            .iter()
+            .all(|(url, _)| url == "https://bakery.turso.io/v2/pipeline"));
+        let bodies: Vec<_> = requests.iter().map(|(_, body)| body.clone()).collect();
+        assert_eq!(
+            bodies,
+            [
+                json!({ "baton": null, "requests": [
+                    { "type": "execute", "stmt": { "sql": "SELECT 1" } },
+                    { "type": "close" },
+                ]}),
+                json!({ "baton": null, "requests": [
+                    { "type": "execute", "stmt": {
+                        "sql": r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = ? LIMIT ?"#,
+                        "args": [
+                            { "type": "integer", "value": "1" },
+                            { "type": "integer", "value": "1" },
+                        ],
+                        "want_rows": true,
+                    }},
+                    { "type": "close" },
+                ]}),
+                json!({ "baton": null, "requests": [
+                    { "type": "execute", "stmt": { "sql": "BEGIN" } },
+                ]}),
+                json!({ "baton": "b1", "requests": [
+                    { "type": "execute", "stmt": {
+                        "sql": r#"INSERT INTO "cake" ("name") VALUES (?)"#,
+                        "args": [{ "type": "text", "value": "Chocolate" }],
+                        "want_rows": true,
+                    }},
+                ]}),
+                json!({ "baton": "b2", "requests": [
+                    { "type": "execute", "stmt": { "sql": "COMMIT" } },
+                    { "type": "close" },
+                ]}),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn libsql_transactions() -> Result<(), DbErr> {
+        let requests = Arc::default();
+        let mut responses = vec![
+            ok(None, exec(0, None), true),
+            ok(Some("a1"), exec(0, None), false),
+            ok(Some("b1"), exec(0, None), false),
+            ok(Some("a2"), exec(1, Some("2")), false),
+            ok(None, exec(0, None), true),
+            ok(None, exec(0, None), true),
+        ];
+        responses.reverse();
+        let transport = Transport {
+            requests: Arc::clone(&requests),
+            responses: Mutex::new(responses),
+        };
+        let db = Database::connect_libsql(
+            LibsqlDatabase::new("libsql://bakery.turso.io", transport).auth_token("token"),
+        )
+        .await?;
+
+        let txn_1 = db.begin().await?;
+        let txn_2 = db.begin().await?;
+        cake::Entity::insert(cake::ActiveModel {
+            name: Set("Chocolate".to_owned()),
+            ..Default::default()
+        })
+        .exec_without_returning(&txn_1)
+        .await?;
+        drop(txn_2);
+        txn_1.commit().await?;
+
+        let requests = requests.lock().map_err(|e| conn_err(e.to_string()))?;
+        let batons: Vec<_> = requests
+            .iter()
+            .map(|(_, body)| body["baton"].as_str().map(ToOwned::to_owned))
+            .collect();
+        assert_eq!(
+            batons,
+            [
+                None,
+                None,
+                None,
+                Some("a1".to_owned()),
+                Some("b1".to_owned()),
+                Some("a2".to_owned()),
+            ]
+        );
+        assert_eq!(
+            requests[4].1["requests"],
+            json!([
+                { "type": "execute", "stmt": { "sql": "ROLLBACK" } },
+                { "type": "close" },
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn libsql_values() -> Result<(), DbErr> {
+        assert_eq!(
+            to_libsql_value(&Value::Bytes(Some(Box::new(vec![1, 2])))),
+            json!({ "type": "blob", "base64": "AQI=" })
+        );
+        assert_eq!(
+            to_libsql_value(&Value::Bool(Some(true))),
+            json!({ "type": "integer", "value": "1" })
+        );
+        assert_eq!(
+            to_libsql_value(&Value::Double(Some(1.5))),
+            json!({ "type": "float", "value": 1.5 })
+        );
+        assert_eq!(
+            to_libsql_value(&Value::Int(None)),
+            json!({ "type": "null" })
+        );
+        assert_eq!(
+            from_libsql_value(&json!({ "type": "null" }), Some("BIGINT"))?,
+            Value::BigInt(None)
+        );
+        assert_eq!(
+            from_libsql_value(&json!({ "type": "blob", "base64": "AQI=" }), None)?,
+            Value::Bytes(Some(Box::new(vec![1, 2])))
+        );
+        Ok(())
+    }
+}
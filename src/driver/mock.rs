@@ -99,6 +99,14 @@ impl MockDatabaseConnector {
     }
 }
 
+/// A mock statement runs right away, so it can only be cancelled beforehand
+fn check_cancelled(statement: &Statement) -> Result<(), DbErr> {
+    match &statement.cancel_token {
+        Some(token) if token.is_cancelled() => Err(DbErr::QueryCancelled),
+        _ => Ok(()),
+    }
+}
+
 impl MockDatabaseConnection {
     /// Create a connection to the [MockDatabase]
     pub fn new<M: 'static>(m: M) -> Self
@@ -132,6 +140,7 @@ impl MockDatabaseConnection {
     #[instrument(level = "trace")]
     pub fn execute(&self, statement: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", statement);
+        check_cancelled(&statement)?;
         let counter = self.execute_counter.fetch_add(1, Ordering::SeqCst);
         self.mocker
             .lock()
@@ -143,6 +152,7 @@ impl MockDatabaseConnection {
     #[instrument(level = "trace")]
     pub fn query_one(&self, statement: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", statement);
+        check_cancelled(&statement)?;
         let counter = self.query_counter.fetch_add(1, Ordering::SeqCst);
         let result = self
             .mocker
@@ -156,6 +166,7 @@ impl MockDatabaseConnection {
     #[instrument(level = "trace")]
     pub fn query_all(&self, statement: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", statement);
+        check_cancelled(&statement)?;
         let counter = self.query_counter.fetch_add(1, Ordering::SeqCst);
         self.mocker
             .lock()
@@ -1,7 +1,13 @@
+#[cfg(feature = "proxy")]
+mod custom;
+#[cfg(feature = "libsql")]
+mod libsql;
 #[cfg(feature = "mock")]
 mod mock;
 #[cfg(feature = "proxy")]
 mod proxy;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_sqlite;
 #[cfg(feature = "sqlx-dep")]
 mod sqlx_common;
 #[cfg(feature = "sqlx-mysql")]
@@ -11,10 +17,16 @@ pub(crate) mod sqlx_postgres;
 #[cfg(feature = "sqlx-sqlite")]
 pub(crate) mod sqlx_sqlite;
 
+#[cfg(feature = "proxy")]
+pub use custom::*;
+#[cfg(feature = "libsql")]
+pub use libsql::*;
 #[cfg(feature = "mock")]
 pub use mock::*;
 #[cfg(feature = "proxy")]
 pub use proxy::*;
+#[cfg(feature = "rusqlite")]
+pub use rusqlite_sqlite::*;
 #[cfg(feature = "sqlx-dep")]
 pub(crate) use sqlx_common::*;
 #[cfg(feature = "sqlx-mysql")]
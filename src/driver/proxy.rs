@@ -77,6 +77,7 @@ impl ProxyDatabaseConnection {
         if let Some(first) = result.first() {
             return Ok(Some(QueryResult {
                 row: crate::QueryResultRow::Proxy(first.to_owned()),
+                timezone: Default::default(),
             }));
         } else {
             return Ok(None);
@@ -93,6 +94,7 @@ impl ProxyDatabaseConnection {
             .into_iter()
             .map(|row| QueryResult {
                 row: crate::QueryResultRow::Proxy(row),
+                timezone: Default::default(),
             })
             .collect())
     }
@@ -0,0 +1,433 @@
+use crate::{
+    error::*, AccessMode, ConnectOptions, Database, DatabaseConnection, DatabaseDriver, DbBackend,
+    IsolationLevel, ProxyExecResult, ProxyRow, SqliteJournalMode, SqliteSynchronous, Statement,
+};
+use ::rusqlite::{
+    params_from_iter,
+    types::{Value as SqliteValue, ValueRef},
+    Connection, OpenFlags,
+};
+use sea_query::Value;
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+use tracing::instrument;
+
+/// Defines the [rusqlite] connector for SQLite
+#[derive(Debug)]
+pub struct RusqliteConnector;
+
+/// A SQLite database accessed with [rusqlite], which needs neither sqlx nor an async runtime,
+/// see [`Database::connect_rusqlite`]. Its entities and queries are the ones of SQLite.
+///
+/// Statements run on the calling thread, so the futures of the connection complete when first
+/// polled, e.g. with `futures::executor::block_on` in a CLI tool or on WASI. There is a single
+/// connection, on which one transaction can be open at a time.
+///
+/// Columns are read as integers, reals, text or blobs, so a model can only have fields of the
+/// types of [`Value`] these convert to, e.g. integers, `bool`, floats, `String` and `Vec<u8>`.
+///
+/// ```
+/// # use sea_orm::{error::*, *};
+/// # fn main() -> Result<(), DbErr> {
+/// futures::executor::block_on(async {
+///     let db = Database::connect_rusqlite(RusqliteDatabase::open_in_memory()?).await?;
+///     db.execute_unprepared("CREATE TABLE cake (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+///         .await?;
+///     # Ok(())
+/// })
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RusqliteDatabase {
+    conn: Arc<Mutex<Connection>>,
+    /// The number of nested transactions this database runs
+    depth: usize,
+    /// Whether the transaction this database runs has been committed or rolled back
+    ended: AtomicBool,
+}
+
+impl Database {
+    /// Connect to a SQLite database with [rusqlite], see [`RusqliteDatabase`]
+    pub async fn connect_rusqlite(db: RusqliteDatabase) -> Result<DatabaseConnection, DbErr> {
+        Database::connect_driver(db).await
+    }
+}
+
+impl RusqliteConnector {
+    /// Check if the URI provided corresponds to `sqlite:` for a SQLite database
+    pub fn accepts(string: &str) -> bool {
+        DbBackend::Sqlite.is_prefix_of(string)
+    }
+
+    /// Open a SQLite database from a URL like the ones of sqlx, e.g. `sqlite::memory:` or
+    /// `sqlite://bakery.db?mode=rwc`, applying the [`crate::SqliteOptions`] of the options
+    #[instrument(level = "trace")]
+    pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let db = RusqliteDatabase::open_url(options.get_url())?;
+        if let Some(sqlite) = options.get_sqlite() {
+            let mut pragmas = Vec::new();
+            if let Some(mode) = sqlite.journal_mode {
+                let mode = match mode {
+                    SqliteJournalMode::Delete => "DELETE",
+                    SqliteJournalMode::Truncate => "TRUNCATE",
+                    SqliteJournalMode::Persist => "PERSIST",
+                    SqliteJournalMode::Memory => "MEMORY",
+                    SqliteJournalMode::Wal => "WAL",
+                    SqliteJournalMode::Off => "OFF",
+                };
+                pragmas.push(format!("PRAGMA journal_mode = {mode};"));
+            }
+            if let Some(synchronous) = sqlite.synchronous {
+                let synchronous = match synchronous {
+                    SqliteSynchronous::Off => "OFF",
+                    SqliteSynchronous::Normal => "NORMAL",
+                    SqliteSynchronous::Full => "FULL",
+                    SqliteSynchronous::Extra => "EXTRA",
+                };
+                pragmas.push(format!("PRAGMA synchronous = {synchronous};"));
+            }
+            if let Some(timeout) = sqlite.busy_timeout {
+                pragmas.push(format!("PRAGMA busy_timeout = {};", timeout.as_millis()));
+            }
+            if let Some(on) = sqlite.foreign_keys {
+                pragmas.push(format!(
+                    "PRAGMA foreign_keys = {};",
+                    if on { "ON" } else { "OFF" }
+                ));
+            }
+            if !sqlite.extensions.is_empty() {
+                return Err(conn_err(
+                    "Loading SQLite extensions is not supported by the rusqlite backend",
+                ));
+            }
+            db.lock()?
+                .execute_batch(&pragmas.concat())
+                .map_err(|e| conn_err(e.to_string()))?;
+        }
+        Database::connect_rusqlite(db).await
+    }
+}
+
+impl RusqliteDatabase {
+    /// Open the SQLite database at the path, creating it if it does not exist
+    pub fn open<P>(path: P) -> Result<Self, DbErr>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Connection::open(path)
+            .map(Self::from)
+            .map_err(|e| conn_err(e.to_string()))
+    }
+
+    /// Open a new in-memory SQLite database
+    pub fn open_in_memory() -> Result<Self, DbErr> {
+        Connection::open_in_memory()
+            .map(Self::from)
+            .map_err(|e| conn_err(e.to_string()))
+    }
+
+    /// Open a SQLite database from a URL, where the `mode` parameter is `ro` to open it read-only,
+    /// `rw` by default, or `rwc` to create it if it does not exist
+    fn open_url(url: &str) -> Result<Self, DbErr> {
+        let path = url.trim_start_matches("sqlite:").trim_start_matches("//");
+        let (path, params) = path.split_once('?').unwrap_or((path, ""));
+        if path.is_empty() || path == ":memory:" {
+            return Self::open_in_memory();
+        }
+        let mut flags = OpenFlags::SQLITE_OPEN_URI | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        for (key, value) in params.split('&').filter_map(|param| param.split_once('=')) {
+            match (key, value) {
+                ("mode", "ro") => flags |= OpenFlags::SQLITE_OPEN_READ_ONLY,
+                ("mode", "rwc") => {
+                    flags |= OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_CREATE
+                }
+                ("mode", "memory") => return Self::open_in_memory(),
+                _ => {}
+            }
+        }
+        if !flags.contains(OpenFlags::SQLITE_OPEN_READ_ONLY) {
+            flags |= OpenFlags::SQLITE_OPEN_READ_WRITE;
+        }
+        Connection::open_with_flags(path, flags)
+            .map(Self::from)
+            .map_err(|e| conn_err(e.to_string()))
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, DbErr> {
+        self.conn
+            .lock()
+            .map_err(|e| conn_err(format!("Failed to lock the SQLite connection: {e}")))
+    }
+
+    /// End the transaction this database runs
+    fn end(&self, commit: bool) -> Result<(), DbErr> {
+        if self.depth == 0 || self.ended.load(Ordering::SeqCst) {
+            return Err(conn_err("Not in a transaction"));
+        }
+        let sql = match (commit, self.depth) {
+            (true, 1) => "COMMIT".to_owned(),
+            (false, 1) => "ROLLBACK".to_owned(),
+            (true, depth) => format!("RELEASE SAVEPOINT sea_orm_{depth}"),
+            (false, depth) => {
+                format!("ROLLBACK TO SAVEPOINT sea_orm_{depth}; RELEASE SAVEPOINT sea_orm_{depth}")
+            }
+        };
+        self.lock()?
+            .execute_batch(&sql)
+            .map_err(|e| exec_err(e.to_string()))?;
+        self.ended.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl From<Connection> for RusqliteDatabase {
+    fn from(conn: Connection) -> Self {
+        Self {
+            conn: Arc::new(Mutex::new(conn)),
+            depth: 0,
+            ended: AtomicBool::new(false),
+        }
+    }
+}
+
+/// Roll back a transaction which has not been committed or rolled back
+impl Drop for RusqliteDatabase {
+    fn drop(&mut self) {
+        if self.depth > 0 && !self.ended.load(Ordering::SeqCst) {
+            if let Err(err) = self.end(false) {
+                tracing::warn!("Failed to roll back the SQLite transaction: {err}");
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatabaseDriver for RusqliteDatabase {
+    fn get_database_backend(&self) -> DbBackend {
+        DbBackend::Sqlite
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        let conn = self.lock()?;
+        let params = rusqlite_params(&statement)?;
+        let rows_affected = conn
+            .prepare(&statement.sql)
+            .and_then(|mut stmt| stmt.execute(params_from_iter(params)))
+            .map_err(|e| exec_err(e.to_string()))?;
+        Ok(ProxyExecResult::new(
+            conn.last_insert_rowid() as u64,
+            rows_affected as u64,
+        ))
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ProxyExecResult, DbErr> {
+        let conn = self.lock()?;
+        conn.execute_batch(sql)
+            .map_err(|e| exec_err(e.to_string()))?;
+        Ok(ProxyExecResult::new(
+            conn.last_insert_rowid() as u64,
+            conn.changes(),
+        ))
+    }
+
+    async fn query_all(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        let conn = self.lock()?;
+        let params = rusqlite_params(&statement)?;
+        let mut stmt = conn
+            .prepare(&statement.sql)
+            .map_err(|e| query_err(e.to_string()))?;
+        let columns: Vec<_> = stmt
+            .columns()
+            .into_iter()
+            .map(|col| {
+                (
+                    col.name().to_owned(),
+                    col.decl_type().map(ToOwned::to_owned),
+                )
+            })
+            .collect();
+        let mut rows = stmt
+            .query(params_from_iter(params))
+            .map_err(|e| query_err(e.to_string()))?;
+        let mut res = Vec::new();
+        while let Some(row) = rows.next().map_err(|e| query_err(e.to_string()))? {
+            let values = columns
+                .iter()
+                .enumerate()
+                .map(|(i, (name, decltype))| {
+                    let value = row.get_ref(i).map_err(|e| query_err(e.to_string()))?;
+                    Ok((
+                        name.clone(),
+                        from_rusqlite_value(value, decltype.as_deref()),
+                    ))
+                })
+                .collect::<Result<BTreeMap<_, _>, DbErr>>()?;
+            res.push(ProxyRow::new(values));
+        }
+        Ok(res)
+    }
+
+    async fn begin(
+        &self,
+        _isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<Box<dyn DatabaseDriver>, DbErr> {
+        let depth = self.depth + 1;
+        let sql = match (depth, access_mode) {
+            // take the write lock upfront, instead of failing to upgrade a read lock later on
+            (1, Some(AccessMode::ReadWrite)) => "BEGIN IMMEDIATE".to_owned(),
+            (1, _) => "BEGIN".to_owned(),
+            (depth, _) => format!("SAVEPOINT sea_orm_{depth}"),
+        };
+        self.lock()?
+            .execute_batch(&sql)
+            .map_err(|e| exec_err(e.to_string()))?;
+        Ok(Box::new(Self {
+            conn: Arc::clone(&self.conn),
+            depth,
+            ended: AtomicBool::new(false),
+        }))
+    }
+
+    async fn commit(&self) -> Result<(), DbErr> {
+        self.end(true)
+    }
+
+    async fn rollback(&self) -> Result<(), DbErr> {
+        self.end(false)
+    }
+}
+
+fn rusqlite_params(statement: &Statement) -> Result<Vec<SqliteValue>, DbErr> {
+    match &statement.values {
+        Some(values) => values.0.iter().map(to_rusqlite_value).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn to_rusqlite_value(value: &Value) -> Result<SqliteValue, DbErr> {
+    let value = match value {
+        Value::Bool(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::TinyInt(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::SmallInt(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::Int(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::BigInt(Some(v)) => SqliteValue::Integer(*v),
+        Value::TinyUnsigned(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::SmallUnsigned(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::Unsigned(Some(v)) => SqliteValue::Integer(*v as i64),
+        Value::BigUnsigned(Some(v)) => SqliteValue::Integer(
+            <i64 as TryFrom<u64>>::try_from(*v)
+                .map_err(|_| DbErr::Type(format!("{v} is out of the range of a SQLite integer")))?,
+        ),
+        Value::Float(Some(v)) => SqliteValue::Real(*v as f64),
+        Value::Double(Some(v)) => SqliteValue::Real(*v),
+        Value::String(Some(v)) => SqliteValue::Text(v.as_ref().clone()),
+        Value::Char(Some(v)) => SqliteValue::Text(v.to_string()),
+        Value::Bytes(Some(v)) => SqliteValue::Blob(v.as_ref().clone()),
+        // other values are stored in the representation of SQLite, e.g. a date as text
+        value => match sea_query::sea_value_to_json_value(value) {
+            serde_json::Value::Null => SqliteValue::Null,
+            serde_json::Value::String(v) => SqliteValue::Text(v),
+            v => SqliteValue::Text(v.to_string()),
+        },
+    };
+    Ok(value)
+}
+
+fn from_rusqlite_value(value: ValueRef<'_>, decltype: Option<&str>) -> Value {
+    match value {
+        ValueRef::Integer(v) => Value::BigInt(Some(v)),
+        ValueRef::Real(v) => Value::Double(Some(v)),
+        ValueRef::Text(v) => Value::String(Some(Box::new(String::from_utf8_lossy(v).into_owned()))),
+        ValueRef::Blob(v) => Value::Bytes(Some(Box::new(v.to_vec()))),
+        ValueRef::Null => {
+            // the type of a null value follows the affinity rules of SQLite
+            let decltype = decltype.unwrap_or_default().to_uppercase();
+            if decltype.contains("INT") {
+                Value::BigInt(None)
+            } else if ["REAL", "FLOA", "DOUB"]
+                .iter()
+                .any(|t| decltype.contains(t))
+            {
+                Value::Double(None)
+            } else if decltype.contains("BLOB") {
+                Value::Bytes(None)
+            } else {
+                Value::String(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tests-cfg")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, tests_cfg::*, ConnectionTrait, PaginatorTrait, Schema, TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn rusqlite() -> Result<(), DbErr> {
+        let db = Database::connect_rusqlite(RusqliteDatabase::open_in_memory()?).await?;
+        let schema = Schema::new(DbBackend::Sqlite);
+        db.execute(
+            db.get_database_backend()
+                .build(&schema.create_table_from_entity(cake::Entity)),
+        )
+        .await?;
+
+        let res = cake::Entity::insert(cake::ActiveModel {
+            name: Set("Cheese".to_owned()),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.last_insert_id, 1);
+
+        let txn = db.begin().await?;
+        cake::ActiveModel {
+            name: Set("Chocolate".to_owned()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+        {
+            let nested = txn.begin().await?;
+            cake::Entity::delete_by_id(1).exec(&nested).await?;
+            nested.rollback().await?;
+        }
+        {
+            let nested = txn.begin().await?;
+            cake::Entity::delete_by_id(2).exec(&nested).await?;
+        }
+        txn.commit().await?;
+
+        assert_eq!(
+            cake::Entity::find().all(&db).await?,
+            [
+                cake::Model {
+                    id: 1,
+                    name: "Cheese".to_owned(),
+                },
+                cake::Model {
+                    id: 2,
+                    name: "Chocolate".to_owned(),
+                },
+            ]
+        );
+
+        {
+            let txn = db.begin().await?;
+            cake::Entity::delete_many().exec(&txn).await?;
+        }
+        assert_eq!(cake::Entity::find().count(&db).await?, 2);
+        Ok(())
+    }
+}
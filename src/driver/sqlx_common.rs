@@ -1,4 +1,8 @@
-use crate::{ConnAcquireErr, ConnectOptions, DbErr, RuntimeErr};
+use crate::{
+    metric::{PoolMetrics, PoolStats},
+    ConnAcquireErr, ConnectOptions, ConnectRetryPolicy, DbErr, RuntimeErr,
+};
+use std::{sync::Arc, time::Instant};
 
 /// Converts an [sqlx::error] execution error to a [DbErr]
 pub fn sqlx_error_to_exec_err(err: sqlx::Error) -> DbErr {
@@ -35,6 +39,55 @@ pub fn sqlx_conn_acquire_err(sqlx_err: sqlx::Error) -> DbErr {
     }
 }
 
+/// Acquire a connection from a [sqlx::Pool], with an optional retry policy, recording the wait time
+pub(crate) async fn sqlx_acquire<DB>(
+    pool: &sqlx::Pool<DB>,
+    retry: Option<&ConnectRetryPolicy>,
+    metrics: &PoolMetrics,
+) -> Result<sqlx::pool::PoolConnection<DB>, DbErr>
+where
+    DB: sqlx::Database,
+{
+    let start = Instant::now();
+    let acquire = || async { pool.acquire().await.map_err(sqlx_conn_acquire_err) };
+    let res = match retry {
+        Some(policy) => policy.run(acquire).await,
+        None => acquire().await,
+    };
+    metrics.record_acquire(start.elapsed(), res.is_err());
+    res
+}
+
+/// Report the connections released back to the pool to its [PoolMetrics]
+pub(crate) fn sqlx_record_release<DB>(
+    pool_options: sqlx::pool::PoolOptions<DB>,
+    metrics: Arc<PoolMetrics>,
+) -> sqlx::pool::PoolOptions<DB>
+where
+    DB: sqlx::Database,
+{
+    pool_options.after_release(move |_, _| {
+        metrics.record_release();
+        Box::pin(async { Ok(true) })
+    })
+}
+
+/// The [PoolStats] of a [sqlx::Pool]
+pub(crate) fn sqlx_pool_stats<DB>(pool: &sqlx::Pool<DB>, metrics: &PoolMetrics) -> PoolStats
+where
+    DB: sqlx::Database,
+{
+    let size = pool.size();
+    let idle = pool.num_idle();
+    PoolStats {
+        size,
+        max_size: pool.options().get_max_connections(),
+        idle,
+        in_use: (size as usize).saturating_sub(idle),
+        acquire_wait: metrics.acquire_wait(),
+    }
+}
+
 impl ConnectOptions {
     /// Convert [ConnectOptions] into [sqlx::pool::PoolOptions]
     pub fn sqlx_pool_options<DB>(self) -> sqlx::pool::PoolOptions<DB>
@@ -64,3 +117,30 @@ impl ConnectOptions {
         opt
     }
 }
+
+/// Bind network addresses as text, on the backends without network types
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+#[cfg(any(feature = "with-ipnetwork", feature = "with-mac_address"))]
+pub(crate) fn sqlx_network_values_as_text(values: sea_query::Values) -> sea_query::Values {
+    use sea_query::Value;
+
+    sea_query::Values(
+        values
+            .0
+            .into_iter()
+            .map(|value| match value {
+                #[cfg(feature = "with-ipnetwork")]
+                Value::IpNetwork(v) => Value::String(v.map(|v| Box::new(v.to_string()))),
+                #[cfg(feature = "with-mac_address")]
+                Value::MacAddress(v) => Value::String(v.map(|v| Box::new(v.to_string()))),
+                value => value,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+#[cfg(not(any(feature = "with-ipnetwork", feature = "with-mac_address")))]
+pub(crate) fn sqlx_network_values_as_text(values: sea_query::Values) -> sea_query::Values {
+    values
+}
@@ -4,7 +4,7 @@ use sea_query::Values;
 use std::{future::Future, pin::Pin, sync::Arc};
 
 use sqlx::{
-    mysql::{MySqlConnectOptions, MySqlQueryResult, MySqlRow},
+    mysql::{MySqlConnectOptions, MySqlQueryResult, MySqlRow, MySqlSslMode},
     pool::PoolConnection,
     Connection, Executor, MySql, MySqlPool,
 };
@@ -13,8 +13,9 @@ use sea_query_binder::SqlxValues;
 use tracing::instrument;
 
 use crate::{
-    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
-    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
+    debug_print, error::*, executor::*, with_cancellation, AccessMode, ConnectOptions,
+    ConnectRetryPolicy, DatabaseConnection, DatabaseTransaction, DbBackend, InnerSession,
+    IsolationLevel, Pem, QueryStream, Statement, TlsMode, TlsOptions, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -28,6 +29,9 @@ pub struct SqlxMySqlConnector;
 pub struct SqlxMySqlPoolConnection {
     pub(crate) pool: MySqlPool,
     metric_callback: Option<crate::metric::Callback>,
+    connect_retry: Option<ConnectRetryPolicy>,
+    pool_metrics: Arc<crate::metric::PoolMetrics>,
+    pub(crate) timezone: crate::TimezonePolicy,
 }
 
 impl std::fmt::Debug for SqlxMySqlPoolConnection {
@@ -61,11 +65,33 @@ impl SqlxMySqlConnector {
                 );
             }
         }
-        match options.sqlx_pool_options().connect_with(opt).await {
+        if let Some(tls) = &options.tls {
+            opt = sqlx_tls(opt, tls);
+        }
+        #[cfg(feature = "with-chrono")]
+        if let Some(policy) = &options.timezone_policy {
+            opt = opt.timezone(policy.mysql_time_zone());
+        }
+        let connect_retry = options.connect_retry.clone();
+        let timezone = options.timezone_policy.unwrap_or_default();
+        let after_connect = options.after_connect.clone();
+        let pool_metrics = Arc::<crate::metric::PoolMetrics>::default();
+        let mut pool_options =
+            sqlx_record_release(options.sqlx_pool_options(), Arc::clone(&pool_metrics));
+        if let Some(after_connect) = after_connect {
+            pool_options = pool_options.after_connect(move |conn, _| {
+                let after_connect = after_connect.clone();
+                Box::pin(async move { after_connect.run(InnerSession::MySql(conn)).await })
+            });
+        }
+        match pool_options.connect_with(opt).await {
             Ok(pool) => Ok(DatabaseConnection::SqlxMySqlPoolConnection(
                 SqlxMySqlPoolConnection {
                     pool,
                     metric_callback: None,
+                    connect_retry,
+                    pool_metrics,
+                    timezone,
                 },
             )),
             Err(e) => Err(sqlx_error_to_conn_err(e)),
@@ -79,24 +105,43 @@ impl SqlxMySqlConnector {
         DatabaseConnection::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
             pool,
             metric_callback: None,
+            connect_retry: None,
+            pool_metrics: Default::default(),
+            timezone: Default::default(),
         })
     }
 }
 
 impl SqlxMySqlPoolConnection {
+    /// Acquire a connection from the pool, retrying as configured by [`ConnectOptions::connect_retry`]
+    async fn acquire(&self) -> Result<PoolConnection<MySql>, DbErr> {
+        sqlx_acquire(&self.pool, self.connect_retry.as_ref(), &self.pool_metrics).await
+    }
+
+    /// Acquire a connection to run a statement on, unless its token is cancelled beforehand
+    async fn acquire_for(&self, stmt: &Statement) -> Result<PoolConnection<MySql>, DbErr> {
+        with_cancellation(None, stmt.cancel_token.as_ref(), self.acquire()).await
+    }
+
     /// Execute a [Statement] on a MySQL backend
     #[instrument(level = "trace")]
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::MySql, stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
-        })
+        let mut conn = self.acquire_for(&stmt).await?;
+        let id = self.connection_id(&mut conn, &stmt).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.execute(&mut *conn).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_server(id, res).await
     }
 
     /// Execute an unprepared SQL statement on a MySQL backend
@@ -104,7 +149,7 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self.acquire().await?;
         match conn.execute(sql).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
@@ -115,46 +160,99 @@ impl SqlxMySqlPoolConnection {
     #[instrument(level = "trace")]
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::MySql, stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
-        })
+        let mut conn = self.acquire_for(&stmt).await?;
+        let id = self.connection_id(&mut conn, &stmt).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.fetch_one(&mut *conn).await {
+                    Ok(row) => Ok(Some(QueryResult::from(row).with_timezone(self.timezone))),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
+            .await
+        });
+        self.cancel_on_server(id, res).await
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
     #[instrument(level = "trace")]
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::MySql, stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
+        let mut conn = self.acquire_for(&stmt).await?;
+        let id = self.connection_id(&mut conn, &stmt).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.fetch_all(&mut *conn).await {
+                    Ok(rows) => Ok(rows
+                        .into_iter()
+                        .map(|r| QueryResult::from(r).with_timezone(self.timezone))
+                        .collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_server(id, res).await
+    }
+
+    /// The id of a connection on the server, to cancel its statement with `KILL QUERY`.
+    /// The extra query is only made for statements with a timeout or a cancellation token.
+    async fn connection_id(
+        &self,
+        conn: &mut PoolConnection<MySql>,
+        stmt: &Statement,
+    ) -> Result<Option<u64>, DbErr> {
+        if stmt.timeout.is_none() && stmt.cancel_token.is_none() {
+            return Ok(None);
+        }
+        sqlx::query_scalar("SELECT CONNECTION_ID()")
+            .fetch_one(&mut **conn)
+            .await
+            .map(Some)
+            .map_err(sqlx_error_to_query_err)
+    }
+
+    /// Cancel the statement of a connection on the server, once it timed out or its token was cancelled
+    async fn cancel_on_server<T>(
+        &self,
+        id: Option<u64>,
+        res: Result<T, DbErr>,
+    ) -> Result<T, DbErr> {
+        if let (Some(id), Err(DbErr::QueryTimeout(_) | DbErr::QueryCancelled)) = (id, &res) {
+            let cancelled = match self.pool.acquire().await {
+                Ok(mut conn) => conn
+                    .execute(format!("KILL QUERY {id}").as_str())
+                    .await
+                    .map(|_| ()),
+                Err(err) => Err(err),
+            };
+            if let Err(err) = cancelled {
+                tracing::warn!("Failed to cancel the statement on the server: {err}");
             }
-        })
+        }
+        res
     }
 
     /// Stream the results of executing a SQL query
     #[instrument(level = "trace")]
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::MySql, stmt);
 
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        Ok(QueryStream::from((
-            conn,
-            stmt,
-            self.metric_callback.clone(),
-        )))
+        let conn = self.acquire().await?;
+        Ok(
+            QueryStream::from((conn, stmt, self.metric_callback.clone()))
+                .with_timezone(self.timezone),
+        )
     }
 
     /// Bundle a set of SQL statements that execute together.
@@ -164,7 +262,7 @@ impl SqlxMySqlPoolConnection {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self.acquire().await?;
         DatabaseTransaction::new_mysql(
             conn,
             self.metric_callback.clone(),
@@ -172,6 +270,21 @@ impl SqlxMySqlPoolConnection {
             access_mode,
         )
         .await
+        .map(|t| t.with_timezone(self.timezone))
+    }
+
+    /// Begin a transaction that can be prepared for a two-phase commit
+    #[instrument(level = "trace")]
+    pub(crate) async fn begin_two_phase(&self, gid: &str) -> Result<DatabaseTransaction, DbErr> {
+        let conn = self.acquire().await?;
+        DatabaseTransaction::begin_two_phase(
+            Arc::new(Mutex::new(crate::InnerConnection::MySql(conn))),
+            crate::DbBackend::MySql,
+            self.metric_callback.clone(),
+            gid,
+        )
+        .await
+        .map(|t| t.with_timezone(self.timezone))
     }
 
     /// Create a MySQL transaction
@@ -190,7 +303,7 @@ impl SqlxMySqlPoolConnection {
         T: Send,
         E: std::error::Error + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self.acquire().await?;
         let transaction = DatabaseTransaction::new_mysql(
             conn,
             self.metric_callback.clone(),
@@ -198,6 +311,7 @@ impl SqlxMySqlPoolConnection {
             access_mode,
         )
         .await
+        .map(|t| t.with_timezone(self.timezone))
         .map_err(|e| TransactionError::Connection(e))?;
         transaction.run(callback).await
     }
@@ -209,9 +323,18 @@ impl SqlxMySqlPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    /// Get the statistics of the pool
+    pub fn pool_stats(&self) -> crate::metric::PoolStats {
+        sqlx_pool_stats(&self.pool, &self.pool_metrics)
+    }
+
+    pub(crate) fn set_pool_callback(&self, callback: crate::metric::PoolCallback) {
+        self.pool_metrics.set_callback(callback);
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self.acquire().await?;
         match conn.ping().await {
             Ok(_) => Ok(()),
             Err(err) => Err(sqlx_error_to_conn_err(err)),
@@ -229,6 +352,7 @@ impl From<MySqlRow> for QueryResult {
     fn from(row: MySqlRow) -> QueryResult {
         QueryResult {
             row: QueryResultRow::SqlxMySql(row),
+            timezone: Default::default(),
         }
     }
 }
@@ -241,11 +365,39 @@ impl From<MySqlQueryResult> for ExecResult {
     }
 }
 
+/// Apply the TLS settings of [`ConnectOptions::tls`] over the ones of the URL
+fn sqlx_tls(mut opt: MySqlConnectOptions, tls: &TlsOptions) -> MySqlConnectOptions {
+    if let Some(mode) = tls.mode {
+        opt = opt.ssl_mode(match mode {
+            TlsMode::Disable => MySqlSslMode::Disabled,
+            TlsMode::Prefer => MySqlSslMode::Preferred,
+            TlsMode::Require => MySqlSslMode::Required,
+            TlsMode::VerifyCa => MySqlSslMode::VerifyCa,
+            TlsMode::VerifyFull => MySqlSslMode::VerifyIdentity,
+        });
+    }
+    match &tls.root_cert {
+        Some(Pem::File(path)) => opt = opt.ssl_ca(path),
+        Some(Pem::Bytes(pem)) => opt = opt.ssl_ca_from_pem(pem.clone()),
+        None => {}
+    }
+    match &tls.client_cert {
+        Some(Pem::File(path)) => opt = opt.ssl_client_cert(path),
+        Some(Pem::Bytes(pem)) => opt = opt.ssl_client_cert_from_pem(pem),
+        None => {}
+    }
+    match &tls.client_key {
+        Some(Pem::File(path)) => opt = opt.ssl_client_key(path),
+        Some(Pem::Bytes(pem)) => opt = opt.ssl_client_key_from_pem(pem),
+        None => {}
+    }
+    opt
+}
+
 pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, MySql, SqlxValues> {
-    let values = stmt
-        .values
-        .as_ref()
-        .map_or(Values(Vec::new()), |values| values.clone());
+    let values = stmt.values.as_ref().map_or(Values(Vec::new()), |values| {
+        sqlx_network_values_as_text(values.clone())
+    });
     sqlx::query_with(&stmt.sql, SqlxValues(values))
 }
 
@@ -259,6 +411,8 @@ pub(crate) async fn set_transaction_config(
             sql: format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
             values: None,
             db_backend: DbBackend::MySql,
+            timeout: None,
+            cancel_token: None,
         };
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
@@ -268,6 +422,8 @@ pub(crate) async fn set_transaction_config(
             sql: format!("SET TRANSACTION {access_mode}"),
             values: None,
             db_backend: DbBackend::MySql,
+            timeout: None,
+            cancel_token: None,
         };
         let query = sqlx_query(&stmt);
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
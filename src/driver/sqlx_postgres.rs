@@ -1,11 +1,11 @@
 use futures::lock::Mutex;
 use log::LevelFilter;
-use sea_query::Values;
+use sea_query::{ArrayType, Value, Values};
 use std::{future::Future, pin::Pin, sync::Arc};
 
 use sqlx::{
     pool::PoolConnection,
-    postgres::{PgConnectOptions, PgQueryResult, PgRow},
+    postgres::{PgConnectOptions, PgQueryResult, PgRow, PgSslMode},
     Connection, Executor, PgPool, Postgres,
 };
 
@@ -13,8 +13,9 @@ use sea_query_binder::SqlxValues;
 use tracing::instrument;
 
 use crate::{
-    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
-    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
+    debug_print, error::*, executor::*, with_cancellation, AccessMode, ConnectOptions,
+    ConnectRetryPolicy, DatabaseConnection, DatabaseTransaction, DbBackend, InnerSession,
+    IsolationLevel, Pem, QueryStream, Statement, TlsMode, TlsOptions, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -28,6 +29,9 @@ pub struct SqlxPostgresConnector;
 pub struct SqlxPostgresPoolConnection {
     pub(crate) pool: PgPool,
     metric_callback: Option<crate::metric::Callback>,
+    connect_retry: Option<ConnectRetryPolicy>,
+    pool_metrics: Arc<crate::metric::PoolMetrics>,
+    pub(crate) timezone: crate::TimezonePolicy,
 }
 
 impl std::fmt::Debug for SqlxPostgresPoolConnection {
@@ -61,18 +65,47 @@ impl SqlxPostgresConnector {
                 );
             }
         }
+        if let Some(tls) = &options.tls {
+            opt = sqlx_tls(opt, tls);
+        }
         let set_search_path_sql = options
             .schema_search_path
             .as_ref()
             .map(|schema| format!("SET search_path = {schema}"));
-        let mut pool_options = options.sqlx_pool_options();
-        if let Some(sql) = set_search_path_sql {
+        #[cfg(feature = "with-chrono")]
+        let session_sql = match &options.timezone_policy {
+            Some(policy) => {
+                let sql = format!(
+                    "SET TIME ZONE '{}'",
+                    policy.postgres_time_zone().replace('\'', "''")
+                );
+                Some(match set_search_path_sql {
+                    Some(search_path) => format!("{search_path}; {sql}"),
+                    None => sql,
+                })
+            }
+            None => set_search_path_sql,
+        };
+        #[cfg(not(feature = "with-chrono"))]
+        let session_sql = set_search_path_sql;
+        let connect_retry = options.connect_retry.clone();
+        let timezone = options.timezone_policy.unwrap_or_default();
+        let after_connect = options.after_connect.clone();
+        let pool_metrics = Arc::<crate::metric::PoolMetrics>::default();
+        let mut pool_options =
+            sqlx_record_release(options.sqlx_pool_options(), Arc::clone(&pool_metrics));
+        if session_sql.is_some() || after_connect.is_some() {
             pool_options = pool_options.after_connect(move |conn, _| {
-                let sql = sql.clone();
+                let sql = session_sql.clone();
+                let after_connect = after_connect.clone();
                 Box::pin(async move {
-                    sqlx::Executor::execute(conn, sql.as_str())
-                        .await
-                        .map(|_| ())
+                    if let Some(sql) = sql {
+                        sqlx::Executor::execute(&mut *conn, sql.as_str()).await?;
+                    }
+                    if let Some(after_connect) = after_connect {
+                        after_connect.run(InnerSession::Postgres(conn)).await?;
+                    }
+                    Ok(())
                 })
             });
         }
@@ -81,6 +114,9 @@ impl SqlxPostgresConnector {
                 SqlxPostgresPoolConnection {
                     pool,
                     metric_callback: None,
+                    connect_retry,
+                    pool_metrics,
+                    timezone,
                 },
             )),
             Err(e) => Err(sqlx_error_to_conn_err(e)),
@@ -94,24 +130,42 @@ impl SqlxPostgresConnector {
         DatabaseConnection::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
             pool,
             metric_callback: None,
+            connect_retry: None,
+            pool_metrics: Default::default(),
+            timezone: Default::default(),
         })
     }
 }
 
 impl SqlxPostgresPoolConnection {
+    /// Acquire a connection from the pool, retrying as configured by [`ConnectOptions::connect_retry`]
+    async fn acquire(&self) -> Result<PoolConnection<Postgres>, DbErr> {
+        sqlx_acquire(&self.pool, self.connect_retry.as_ref(), &self.pool_metrics).await
+    }
+
+    /// Acquire a connection to run a statement on, unless its token is cancelled beforehand
+    async fn acquire_for(&self, stmt: &Statement) -> Result<PoolConnection<Postgres>, DbErr> {
+        with_cancellation(None, stmt.cancel_token.as_ref(), self.acquire()).await
+    }
+
     /// Execute a [Statement] on a PostgreSQL backend
     #[instrument(level = "trace")]
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
-        })
+        let query = sqlx_query(&stmt)?;
+        let mut conn = self.acquire_for(&stmt).await?;
+        let id = self.connection_id(&mut conn, &stmt).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.execute(&mut *conn).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_server(id, res).await
     }
 
     /// Execute an unprepared SQL statement on a PostgreSQL backend
@@ -119,7 +173,7 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self.acquire().await?;
         match conn.execute(sql).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
@@ -131,17 +185,22 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
-        })
+        let query = sqlx_query(&stmt)?;
+        let mut conn = self.acquire_for(&stmt).await?;
+        let id = self.connection_id(&mut conn, &stmt).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.fetch_one(&mut *conn).await {
+                    Ok(row) => Ok(Some(QueryResult::from(row).with_timezone(self.timezone))),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
+            .await
+        });
+        self.cancel_on_server(id, res).await
     }
 
     /// Get the results of a query returning them as a Vec<[QueryResult]>
@@ -149,14 +208,61 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
+        let query = sqlx_query(&stmt)?;
+        let mut conn = self.acquire_for(&stmt).await?;
+        let id = self.connection_id(&mut conn, &stmt).await?;
+        let res = crate::metric::metric!(self.metric_callback, &stmt, {
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.fetch_all(&mut *conn).await {
+                    Ok(rows) => Ok(rows
+                        .into_iter()
+                        .map(|r| QueryResult::from(r).with_timezone(self.timezone))
+                        .collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
+            .await
+        });
+        self.cancel_on_server(id, res).await
+    }
+
+    /// The id of the server process of a connection, to cancel its statement with `pg_cancel_backend`.
+    /// The extra query is only made for statements with a timeout or a cancellation token.
+    async fn connection_id(
+        &self,
+        conn: &mut PoolConnection<Postgres>,
+        stmt: &Statement,
+    ) -> Result<Option<i32>, DbErr> {
+        if stmt.timeout.is_none() && stmt.cancel_token.is_none() {
+            return Ok(None);
+        }
+        sqlx::query_scalar("SELECT pg_backend_pid()")
+            .fetch_one(&mut **conn)
+            .await
+            .map(Some)
+            .map_err(sqlx_error_to_query_err)
+    }
+
+    /// Cancel the statement of a connection on the server, once it timed out or its token was cancelled
+    async fn cancel_on_server<T>(
+        &self,
+        id: Option<i32>,
+        res: Result<T, DbErr>,
+    ) -> Result<T, DbErr> {
+        if let (Some(id), Err(DbErr::QueryTimeout(_) | DbErr::QueryCancelled)) = (id, &res) {
+            let cancelled = match self.pool.acquire().await {
+                Ok(mut conn) => sqlx::query("SELECT pg_cancel_backend($1)")
+                    .bind(id)
+                    .execute(&mut *conn)
+                    .await
+                    .map(|_| ()),
+                Err(err) => Err(err),
+            };
+            if let Err(err) = cancelled {
+                tracing::warn!("Failed to cancel the statement on the server: {err}");
             }
-        })
+        }
+        res
     }
 
     /// Stream the results of executing a SQL query
@@ -164,12 +270,11 @@ impl SqlxPostgresPoolConnection {
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
 
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        Ok(QueryStream::from((
-            conn,
-            stmt,
-            self.metric_callback.clone(),
-        )))
+        let conn = self.acquire().await?;
+        Ok(
+            QueryStream::from((conn, stmt, self.metric_callback.clone()))
+                .with_timezone(self.timezone),
+        )
     }
 
     /// Bundle a set of SQL statements that execute together.
@@ -179,7 +284,7 @@ impl SqlxPostgresPoolConnection {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self.acquire().await?;
         DatabaseTransaction::new_postgres(
             conn,
             self.metric_callback.clone(),
@@ -187,6 +292,21 @@ impl SqlxPostgresPoolConnection {
             access_mode,
         )
         .await
+        .map(|t| t.with_timezone(self.timezone))
+    }
+
+    /// Begin a transaction that can be prepared for a two-phase commit
+    #[instrument(level = "trace")]
+    pub(crate) async fn begin_two_phase(&self, gid: &str) -> Result<DatabaseTransaction, DbErr> {
+        let conn = self.acquire().await?;
+        DatabaseTransaction::begin_two_phase(
+            Arc::new(Mutex::new(crate::InnerConnection::Postgres(conn))),
+            crate::DbBackend::Postgres,
+            self.metric_callback.clone(),
+            gid,
+        )
+        .await
+        .map(|t| t.with_timezone(self.timezone))
     }
 
     /// Create a PostgreSQL transaction
@@ -205,7 +325,7 @@ impl SqlxPostgresPoolConnection {
         T: Send,
         E: std::error::Error + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self.acquire().await?;
         let transaction = DatabaseTransaction::new_postgres(
             conn,
             self.metric_callback.clone(),
@@ -213,6 +333,7 @@ impl SqlxPostgresPoolConnection {
             access_mode,
         )
         .await
+        .map(|t| t.with_timezone(self.timezone))
         .map_err(|e| TransactionError::Connection(e))?;
         transaction.run(callback).await
     }
@@ -224,15 +345,107 @@ impl SqlxPostgresPoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    /// Get the statistics of the pool
+    pub fn pool_stats(&self) -> crate::metric::PoolStats {
+        sqlx_pool_stats(&self.pool, &self.pool_metrics)
+    }
+
+    pub(crate) fn set_pool_callback(&self, callback: crate::metric::PoolCallback) {
+        self.pool_metrics.set_callback(callback);
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self.acquire().await?;
         match conn.ping().await {
             Ok(_) => Ok(()),
             Err(err) => Err(sqlx_error_to_conn_err(err)),
         }
     }
 
+    /// Listen to channels on a connection of the pool, see [`DatabaseConnection::listen`]
+    pub async fn listen_all<'a, I>(&self, channels: I) -> Result<crate::NotificationStream, DbErr>
+    where
+        I: IntoIterator<Item = &'a str> + Send,
+        I::IntoIter: Send,
+    {
+        let mut listener = sqlx::postgres::PgListener::connect_with(&self.pool)
+            .await
+            .map_err(sqlx_error_to_conn_err)?;
+        listener
+            .listen_all(channels)
+            .await
+            .map_err(sqlx_error_to_query_err)?;
+        let stream =
+            futures::stream::unfold((listener, false), |(mut listener, failed)| async move {
+                if failed {
+                    // wait before reconnecting again, instead of retrying in a busy loop
+                    crate::database::runtime::sleep(std::time::Duration::from_secs(1)).await;
+                }
+                match listener.recv().await {
+                    Ok(notification) => {
+                        let notification = crate::Notification {
+                            channel: notification.channel().to_owned(),
+                            payload: notification.payload().to_owned(),
+                            process_id: notification.process_id(),
+                        };
+                        Some((Ok(notification), (listener, false)))
+                    }
+                    Err(sqlx::Error::PoolClosed) => None,
+                    Err(err) => Some((Err(sqlx_error_to_conn_err(err)), (listener, true))),
+                }
+            });
+        Ok(crate::NotificationStream::new(stream))
+    }
+
+    /// Run several queries on a connection of the pool, see [`crate::Pipeline`]
+    #[instrument(level = "trace")]
+    pub async fn query_pipeline(
+        &self,
+        stmts: Vec<Statement>,
+    ) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+        let mut conn = self.acquire().await?;
+        let results = sqlx_query_pipeline(&mut conn, &stmts).await?;
+        Ok(results
+            .into_iter()
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| row.with_timezone(self.timezone))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Run a `COPY .. FROM STDIN` statement on a connection of the pool, see [`crate::EntityTrait::copy_in`]
+    #[instrument(level = "trace", skip(data))]
+    pub async fn copy_in_raw(
+        &self,
+        statement: &str,
+        data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        debug_print!("{}", statement);
+
+        let mut conn = self.acquire().await?;
+        let copy = conn
+            .copy_in_raw(statement)
+            .await
+            .map_err(sqlx_error_to_exec_err)?;
+        sqlx_copy_in(copy, data).await
+    }
+
+    /// Run a `COPY .. TO STDOUT` statement on a connection of the pool, see [`crate::Select::copy_out`]
+    #[instrument(level = "trace")]
+    pub async fn copy_out_raw(
+        &self,
+        statement: &str,
+    ) -> Result<crate::CopyOutStream<'static>, DbErr> {
+        debug_print!("{}", statement);
+
+        let conn = crate::CopyOutConnection::Pool(Box::new(self.acquire().await?));
+        let stream = crate::PgCopyOut::start(conn, statement.to_owned()).await?;
+        Ok(crate::CopyOutStream::new(stream))
+    }
+
     /// Explicitly close the Postgres connection
     pub async fn close(self) -> Result<(), DbErr> {
         self.pool.close().await;
@@ -240,10 +453,88 @@ impl SqlxPostgresPoolConnection {
     }
 }
 
+/// Run several queries, splitting the rows at the end of each query. Queries without values are
+/// sent as one multi-statement simple query, in one round trip. As a simple query takes no
+/// parameters, queries with values are run one by one with their values bound instead.
+pub(crate) async fn sqlx_query_pipeline(
+    conn: &mut sqlx::PgConnection,
+    stmts: &[Statement],
+) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+    use futures::TryStreamExt;
+
+    if stmts.iter().any(|stmt| {
+        stmt.values
+            .as_ref()
+            .map_or(false, |values| !values.0.is_empty())
+    }) {
+        let mut results = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            debug_print!("{}", stmt);
+            let rows = sqlx_query(stmt)?
+                .fetch_all(&mut *conn)
+                .await
+                .map_err(sqlx_error_to_query_err)?;
+            results.push(rows.into_iter().map(Into::into).collect());
+        }
+        return Ok(results);
+    }
+
+    let sql = stmts
+        .iter()
+        .map(|stmt| stmt.sql.as_str())
+        .collect::<Vec<_>>()
+        .join(";\n");
+    debug_print!("{}", sql);
+
+    let mut results = Vec::with_capacity(stmts.len());
+    let mut rows = Vec::new();
+    let mut stream = conn.fetch_many(sql.as_str());
+    while let Some(item) = stream.try_next().await.map_err(sqlx_error_to_query_err)? {
+        match item {
+            sqlx::Either::Left(_) => results.push(std::mem::take(&mut rows)),
+            sqlx::Either::Right(row) => rows.push(row.into()),
+        }
+    }
+    if results.len() != stmts.len() {
+        return Err(DbErr::Query(RuntimeErr::Internal(format!(
+            "Expected the results of {} queries, got {}",
+            stmts.len(),
+            results.len()
+        ))));
+    }
+    Ok(results)
+}
+
+/// Send the chunks of data of a `COPY .. FROM STDIN`, aborting it on the first error
+pub(crate) async fn sqlx_copy_in<C>(
+    mut copy: sqlx::postgres::PgCopyIn<C>,
+    data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+) -> Result<u64, DbErr>
+where
+    C: std::ops::DerefMut<Target = sqlx::PgConnection>,
+{
+    for chunk in data {
+        let res = match chunk {
+            Ok(chunk) => copy
+                .send(chunk)
+                .await
+                .map(|_| ())
+                .map_err(sqlx_error_to_exec_err),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = res {
+            copy.abort(err.to_string()).await.ok();
+            return Err(err);
+        }
+    }
+    copy.finish().await.map_err(sqlx_error_to_exec_err)
+}
+
 impl From<PgRow> for QueryResult {
     fn from(row: PgRow) -> QueryResult {
         QueryResult {
             row: QueryResultRow::SqlxPostgres(row),
+            timezone: Default::default(),
         }
     }
 }
@@ -256,12 +547,75 @@ impl From<PgQueryResult> for ExecResult {
     }
 }
 
-pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Postgres, SqlxValues> {
+/// Apply the TLS settings of [`ConnectOptions::tls`] over the ones of the URL
+fn sqlx_tls(mut opt: PgConnectOptions, tls: &TlsOptions) -> PgConnectOptions {
+    if let Some(mode) = tls.mode {
+        opt = opt.ssl_mode(match mode {
+            TlsMode::Disable => PgSslMode::Disable,
+            TlsMode::Prefer => PgSslMode::Prefer,
+            TlsMode::Require => PgSslMode::Require,
+            TlsMode::VerifyCa => PgSslMode::VerifyCa,
+            TlsMode::VerifyFull => PgSslMode::VerifyFull,
+        });
+    }
+    match &tls.root_cert {
+        Some(Pem::File(path)) => opt = opt.ssl_root_cert(path),
+        Some(Pem::Bytes(pem)) => opt = opt.ssl_root_cert_from_pem(pem.clone()),
+        None => {}
+    }
+    match &tls.client_cert {
+        Some(Pem::File(path)) => opt = opt.ssl_client_cert(path),
+        Some(Pem::Bytes(pem)) => opt = opt.ssl_client_cert_from_pem(pem),
+        None => {}
+    }
+    match &tls.client_key {
+        Some(Pem::File(path)) => opt = opt.ssl_client_key(path),
+        Some(Pem::Bytes(pem)) => opt = opt.ssl_client_key_from_pem(pem),
+        None => {}
+    }
+    opt
+}
+
+pub(crate) fn sqlx_query(
+    stmt: &Statement,
+) -> Result<sqlx::query::Query<'_, Postgres, SqlxValues>, DbErr> {
     let values = stmt
         .values
         .as_ref()
         .map_or(Values(Vec::new()), |values| values.clone());
-    sqlx::query_with(&stmt.sql, SqlxValues(values))
+    let values = sqlx_unsigned_values_as_signed(values)?;
+    Ok(sqlx::query_with(&stmt.sql, SqlxValues(values)))
+}
+
+/// Bind `u64` as `bigint`, as PostgreSQL has no unsigned types,
+/// failing on a value out of range instead of wrapping around
+fn sqlx_unsigned_values_as_signed(values: Values) -> Result<Values, DbErr> {
+    fn to_signed(value: Value) -> Result<Value, DbErr> {
+        match value {
+            Value::BigUnsigned(Some(n)) => i64::try_from(n)
+                .map(|n| Value::BigInt(Some(n)))
+                .map_err(|e| DbErr::TryIntoErr {
+                    from: stringify!(u64),
+                    into: stringify!(i64),
+                    source: Box::new(e),
+                }),
+            Value::BigUnsigned(None) => Ok(Value::BigInt(None)),
+            Value::Array(ArrayType::BigUnsigned, values) => {
+                let values = values
+                    .map(|values| values.into_iter().map(to_signed).collect())
+                    .transpose()?
+                    .map(Box::new);
+                Ok(Value::Array(ArrayType::BigInt, values))
+            }
+            value => Ok(value),
+        }
+    }
+
+    values
+        .into_iter()
+        .map(to_signed)
+        .collect::<Result<_, _>>()
+        .map(Values)
 }
 
 pub(crate) async fn set_transaction_config(
@@ -274,8 +628,10 @@ pub(crate) async fn set_transaction_config(
             sql: format!("SET TRANSACTION ISOLATION LEVEL {isolation_level}"),
             values: None,
             db_backend: DbBackend::Postgres,
+            timeout: None,
+            cancel_token: None,
         };
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt)?;
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
     if let Some(access_mode) = access_mode {
@@ -283,8 +639,10 @@ pub(crate) async fn set_transaction_config(
             sql: format!("SET TRANSACTION {access_mode}"),
             values: None,
             db_backend: DbBackend::Postgres,
+            timeout: None,
+            cancel_token: None,
         };
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt)?;
         conn.execute(query).await.map_err(sqlx_error_to_exec_err)?;
     }
     Ok(())
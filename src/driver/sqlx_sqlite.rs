@@ -13,9 +13,10 @@ use sea_query_binder::SqlxValues;
 use tracing::{instrument, warn};
 
 use crate::{
-    debug_print, error::*, executor::*, sqlx_error_to_exec_err, AccessMode, ConnectOptions,
-    DatabaseConnection, DatabaseTransaction, IsolationLevel, QueryStream, Statement,
-    TransactionError,
+    debug_print, error::*, executor::*, sqlx_error_to_exec_err, with_cancellation, AccessMode,
+    ConnectOptions, ConnectRetryPolicy, DatabaseConnection, DatabaseTransaction, DbBackend,
+    InnerSession, IsolationLevel, QueryStream, SqliteJournalMode, SqliteOptions, SqliteSynchronous,
+    Statement, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -29,6 +30,9 @@ pub struct SqlxSqliteConnector;
 pub struct SqlxSqlitePoolConnection {
     pub(crate) pool: SqlitePool,
     metric_callback: Option<crate::metric::Callback>,
+    connect_retry: Option<ConnectRetryPolicy>,
+    pool_metrics: Arc<crate::metric::PoolMetrics>,
+    pub(crate) timezone: crate::TimezonePolicy,
 }
 
 impl std::fmt::Debug for SqlxSqlitePoolConnection {
@@ -54,6 +58,9 @@ impl SqlxSqliteConnector {
         if let Some(sqlcipher_key) = &options.sqlcipher_key {
             opt = opt.pragma("key", sqlcipher_key.clone());
         }
+        if let Some(sqlite) = &options.sqlite {
+            opt = sqlx_sqlite_options(opt, sqlite);
+        }
         use sqlx::ConnectOptions;
         if !options.sqlx_logging {
             opt = opt.disable_statement_logging();
@@ -69,11 +76,26 @@ impl SqlxSqliteConnector {
         if options.get_max_connections().is_none() {
             options.max_connections(1);
         }
-        match options.sqlx_pool_options().connect_with(opt).await {
+        let connect_retry = options.connect_retry.clone();
+        let timezone = options.timezone_policy.unwrap_or_default();
+        let after_connect = options.after_connect.clone();
+        let pool_metrics = Arc::<crate::metric::PoolMetrics>::default();
+        let mut pool_options =
+            sqlx_record_release(options.sqlx_pool_options(), Arc::clone(&pool_metrics));
+        if let Some(after_connect) = after_connect {
+            pool_options = pool_options.after_connect(move |conn, _| {
+                let after_connect = after_connect.clone();
+                Box::pin(async move { after_connect.run(InnerSession::Sqlite(conn)).await })
+            });
+        }
+        match pool_options.connect_with(opt).await {
             Ok(pool) => {
                 let pool = SqlxSqlitePoolConnection {
                     pool,
                     metric_callback: None,
+                    connect_retry,
+                    pool_metrics,
+                    timezone,
                 };
 
                 #[cfg(feature = "sqlite-use-returning-for-3_35")]
@@ -95,23 +117,40 @@ impl SqlxSqliteConnector {
         DatabaseConnection::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection {
             pool,
             metric_callback: None,
+            connect_retry: None,
+            pool_metrics: Default::default(),
+            timezone: Default::default(),
         })
     }
 }
 
 impl SqlxSqlitePoolConnection {
+    /// Acquire a connection from the pool, retrying as configured by [`ConnectOptions::connect_retry`]
+    async fn acquire(&self) -> Result<PoolConnection<Sqlite>, DbErr> {
+        sqlx_acquire(&self.pool, self.connect_retry.as_ref(), &self.pool_metrics).await
+    }
+
+    /// Acquire a connection to run a statement on, unless its token is cancelled beforehand
+    async fn acquire_for(&self, stmt: &Statement) -> Result<PoolConnection<Sqlite>, DbErr> {
+        with_cancellation(None, stmt.cancel_token.as_ref(), self.acquire()).await
+    }
+
     /// Execute a [Statement] on a SQLite backend
     #[instrument(level = "trace")]
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::Sqlite, stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self.acquire_for(&stmt).await?;
         crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.execute(&mut *conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.execute(&mut *conn).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+            .await
         })
     }
 
@@ -120,7 +159,7 @@ impl SqlxSqlitePoolConnection {
     pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
         debug_print!("{}", sql);
 
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self.acquire().await?;
         match conn.execute(sql).await {
             Ok(res) => Ok(res.into()),
             Err(err) => Err(sqlx_error_to_exec_err(err)),
@@ -131,17 +170,21 @@ impl SqlxSqlitePoolConnection {
     #[instrument(level = "trace")]
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::Sqlite, stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self.acquire_for(&stmt).await?;
         crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_one(&mut *conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(sqlx_error_to_query_err(err)),
-                },
-            }
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.fetch_one(&mut *conn).await {
+                    Ok(row) => Ok(Some(QueryResult::from(row).with_timezone(self.timezone))),
+                    Err(err) => match err {
+                        sqlx::Error::RowNotFound => Ok(None),
+                        _ => Err(sqlx_error_to_query_err(err)),
+                    },
+                }
+            })
+            .await
         })
     }
 
@@ -149,14 +192,21 @@ impl SqlxSqlitePoolConnection {
     #[instrument(level = "trace")]
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::Sqlite, stmt);
 
         let query = sqlx_query(&stmt);
-        let mut conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let mut conn = self.acquire_for(&stmt).await?;
         crate::metric::metric!(self.metric_callback, &stmt, {
-            match query.fetch_all(&mut *conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
+            with_cancellation(stmt.timeout, stmt.cancel_token.as_ref(), async {
+                match query.fetch_all(&mut *conn).await {
+                    Ok(rows) => Ok(rows
+                        .into_iter()
+                        .map(|r| QueryResult::from(r).with_timezone(self.timezone))
+                        .collect()),
+                    Err(err) => Err(sqlx_error_to_query_err(err)),
+                }
+            })
+            .await
         })
     }
 
@@ -164,13 +214,13 @@ impl SqlxSqlitePoolConnection {
     #[instrument(level = "trace")]
     pub async fn stream(&self, stmt: Statement) -> Result<QueryStream, DbErr> {
         debug_print!("{}", stmt);
+        let stmt = self.timezone.bind(DbBackend::Sqlite, stmt);
 
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
-        Ok(QueryStream::from((
-            conn,
-            stmt,
-            self.metric_callback.clone(),
-        )))
+        let conn = self.acquire().await?;
+        Ok(
+            QueryStream::from((conn, stmt, self.metric_callback.clone()))
+                .with_timezone(self.timezone),
+        )
     }
 
     /// Bundle a set of SQL statements that execute together.
@@ -180,7 +230,7 @@ impl SqlxSqlitePoolConnection {
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self.acquire().await?;
         DatabaseTransaction::new_sqlite(
             conn,
             self.metric_callback.clone(),
@@ -188,6 +238,7 @@ impl SqlxSqlitePoolConnection {
             access_mode,
         )
         .await
+        .map(|t| t.with_timezone(self.timezone))
     }
 
     /// Create a MySQL transaction
@@ -206,7 +257,7 @@ impl SqlxSqlitePoolConnection {
         T: Send,
         E: std::error::Error + Send,
     {
-        let conn = self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = self.acquire().await?;
         let transaction = DatabaseTransaction::new_sqlite(
             conn,
             self.metric_callback.clone(),
@@ -214,6 +265,7 @@ impl SqlxSqlitePoolConnection {
             access_mode,
         )
         .await
+        .map(|t| t.with_timezone(self.timezone))
         .map_err(|e| TransactionError::Connection(e))?;
         transaction.run(callback).await
     }
@@ -225,9 +277,18 @@ impl SqlxSqlitePoolConnection {
         self.metric_callback = Some(Arc::new(callback));
     }
 
+    /// Get the statistics of the pool
+    pub fn pool_stats(&self) -> crate::metric::PoolStats {
+        sqlx_pool_stats(&self.pool, &self.pool_metrics)
+    }
+
+    pub(crate) fn set_pool_callback(&self, callback: crate::metric::PoolCallback) {
+        self.pool_metrics.set_callback(callback);
+    }
+
     /// Checks if a connection to the database is still valid.
     pub async fn ping(&self) -> Result<(), DbErr> {
-        let conn = &mut self.pool.acquire().await.map_err(sqlx_conn_acquire_err)?;
+        let conn = &mut self.acquire().await?;
         match conn.ping().await {
             Ok(_) => Ok(()),
             Err(err) => Err(sqlx_error_to_conn_err(err)),
@@ -245,6 +306,7 @@ impl From<SqliteRow> for QueryResult {
     fn from(row: SqliteRow) -> QueryResult {
         QueryResult {
             row: QueryResultRow::SqlxSqlite(row),
+            timezone: Default::default(),
         }
     }
 }
@@ -257,11 +319,50 @@ impl From<SqliteQueryResult> for ExecResult {
     }
 }
 
+/// Apply the pragmas and extensions of [`ConnectOptions::sqlite`]
+fn sqlx_sqlite_options(
+    mut opt: SqliteConnectOptions,
+    sqlite: &SqliteOptions,
+) -> SqliteConnectOptions {
+    use sqlx::sqlite::{SqliteJournalMode as JournalMode, SqliteSynchronous as Synchronous};
+
+    if let Some(mode) = sqlite.journal_mode {
+        opt = opt.journal_mode(match mode {
+            SqliteJournalMode::Delete => JournalMode::Delete,
+            SqliteJournalMode::Truncate => JournalMode::Truncate,
+            SqliteJournalMode::Persist => JournalMode::Persist,
+            SqliteJournalMode::Memory => JournalMode::Memory,
+            SqliteJournalMode::Wal => JournalMode::Wal,
+            SqliteJournalMode::Off => JournalMode::Off,
+        });
+    }
+    if let Some(synchronous) = sqlite.synchronous {
+        opt = opt.synchronous(match synchronous {
+            SqliteSynchronous::Off => Synchronous::Off,
+            SqliteSynchronous::Normal => Synchronous::Normal,
+            SqliteSynchronous::Full => Synchronous::Full,
+            SqliteSynchronous::Extra => Synchronous::Extra,
+        });
+    }
+    if let Some(timeout) = sqlite.busy_timeout {
+        opt = opt.busy_timeout(timeout);
+    }
+    if let Some(on) = sqlite.foreign_keys {
+        opt = opt.foreign_keys(on);
+    }
+    for (name, entrypoint) in &sqlite.extensions {
+        opt = match entrypoint {
+            Some(entrypoint) => opt.extension_with_entrypoint(name.clone(), entrypoint.clone()),
+            None => opt.extension(name.clone()),
+        };
+    }
+    opt
+}
+
 pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Sqlite, SqlxValues> {
-    let values = stmt
-        .values
-        .as_ref()
-        .map_or(Values(Vec::new()), |values| values.clone());
+    let values = stmt.values.as_ref().map_or(Values(Vec::new()), |values| {
+        sqlx_network_values_as_text(values.clone())
+    });
     sqlx::query_with(&stmt.sql, SqlxValues(values))
 }
 
@@ -37,10 +37,7 @@ pub use ActiveValue::NotSet;
 /// );
 /// ```
 #[derive(Clone, Debug)]
-pub enum ActiveValue<V>
-where
-    V: Into<Value>,
-{
+pub enum ActiveValue<V> {
     /// A defined [Value] actively being set
     Set(V),
     /// A defined [Value] remain unchanged
@@ -51,10 +48,7 @@ where
 
 /// Defines a set operation on an [ActiveValue]
 #[allow(non_snake_case)]
-pub fn Set<V>(v: V) -> ActiveValue<V>
-where
-    V: Into<Value>,
-{
+pub fn Set<V>(v: V) -> ActiveValue<V> {
     ActiveValue::set(v)
 }
 
@@ -64,19 +58,13 @@ where
     note = "Please use [`ActiveValue::NotSet`] or [`NotSet`]"
 )]
 #[allow(non_snake_case)]
-pub fn Unset<V>(_: Option<bool>) -> ActiveValue<V>
-where
-    V: Into<Value>,
-{
+pub fn Unset<V>(_: Option<bool>) -> ActiveValue<V> {
     ActiveValue::not_set()
 }
 
 /// Defines an unchanged operation on an [ActiveValue]
 #[allow(non_snake_case)]
-pub fn Unchanged<V>(value: V) -> ActiveValue<V>
-where
-    V: Into<Value>,
-{
+pub fn Unchanged<V>(value: V) -> ActiveValue<V> {
     ActiveValue::unchanged(value)
 }
 
@@ -760,20 +748,14 @@ impl_into_active_value!(crate::prelude::TimeDateTime);
 #[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
 impl_into_active_value!(crate::prelude::TimeDateTimeWithTimeZone);
 
-impl<V> Default for ActiveValue<V>
-where
-    V: Into<Value>,
-{
+impl<V> Default for ActiveValue<V> {
     /// Create an [ActiveValue::NotSet]
     fn default() -> Self {
         Self::NotSet
     }
 }
 
-impl<V> ActiveValue<V>
-where
-    V: Into<Value>,
-{
+impl<V> ActiveValue<V> {
     /// Create an [ActiveValue::Set]
     pub fn set(value: V) -> Self {
         Self::Set(value)
@@ -826,7 +808,10 @@ where
     }
 
     /// Check if a [Value] exists or not
-    pub fn into_value(self) -> Option<Value> {
+    pub fn into_value(self) -> Option<Value>
+    where
+        V: Into<Value>,
+    {
         match self {
             ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value.into()),
             ActiveValue::NotSet => None,
@@ -834,7 +819,10 @@ where
     }
 
     /// Wrap the [Value] into a `ActiveValue<Value>`
-    pub fn into_wrapped_value(self) -> ActiveValue<Value> {
+    pub fn into_wrapped_value(self) -> ActiveValue<Value>
+    where
+        V: Into<Value>,
+    {
         match self {
             Self::Set(value) => ActiveValue::set(value.into()),
             Self::Unchanged(value) => ActiveValue::unchanged(value.into()),
@@ -842,6 +830,26 @@ where
         }
     }
 
+    /// Convert the inner value with `f`, keeping whether it is set or unchanged
+    ///
+    /// ```
+    /// # use sea_orm::ActiveValue;
+    /// #
+    /// assert_eq!(ActiveValue::Set(42).map(i64::from), ActiveValue::Set(42i64));
+    /// assert_eq!(ActiveValue::Unchanged("a").map(str::len), ActiveValue::Unchanged(1));
+    /// assert_eq!(ActiveValue::NotSet.map(|v: i32| v + 1), ActiveValue::NotSet);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> ActiveValue<U>
+    where
+        F: FnOnce(V) -> U,
+    {
+        match self {
+            Self::Set(value) => ActiveValue::Set(f(value)),
+            Self::Unchanged(value) => ActiveValue::Unchanged(f(value)),
+            Self::NotSet => ActiveValue::NotSet,
+        }
+    }
+
     /// Reset the value from [ActiveValue::Unchanged] to [ActiveValue::Set],
     /// leaving [ActiveValue::NotSet] untouched.
     pub fn reset(&mut self) {
@@ -909,10 +917,7 @@ where
     }
 }
 
-impl<V> std::convert::AsRef<V> for ActiveValue<V>
-where
-    V: Into<Value>,
-{
+impl<V> std::convert::AsRef<V> for ActiveValue<V> {
     /// # Panics
     ///
     /// Panics if it is [ActiveValue::NotSet].
@@ -928,7 +933,7 @@ where
 
 impl<V> PartialEq for ActiveValue<V>
 where
-    V: Into<Value> + std::cmp::PartialEq,
+    V: std::cmp::PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -1,9 +1,10 @@
 use crate::{
-    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne,
-    FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related,
-    RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectionTrait, CopyIn, DbBackend, DbErr,
+    Delete, DeleteMany, DeleteOne, FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, QueryOrder, QuerySelect, Related, RelationBuilder, RelationTrait,
+    RelationType, Select, Statement, Update, UpdateMany, UpdateOne,
 };
-use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
+use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef, Value};
 use std::fmt::Debug;
 pub use strum::IntoEnumIterator as Iterable;
 
@@ -20,6 +21,12 @@ pub trait EntityName: IdenStatic + Default {
         None
     }
 
+    /// Method to get the name of the database in a [`crate::Databases`] registry,
+    /// defaults to [Option::None] if not set
+    fn database_name(&self) -> Option<&str> {
+        None
+    }
+
     /// Method to get the comment for the schema, defaults to [Option::None] if not set
     fn comment(&self) -> Option<&str> {
         None
@@ -28,6 +35,12 @@ pub trait EntityName: IdenStatic + Default {
     /// Get the name of the table
     fn table_name(&self) -> &str;
 
+    /// Whether the entity is backed by a view, of which the rows can't be inserted, updated or deleted,
+    /// defaults to `false`
+    fn is_view(&self) -> bool {
+        false
+    }
+
     /// Get the name of the module from the invoking `self.table_name()`
     fn module_name(&self) -> &str {
         self.table_name()
@@ -42,6 +55,47 @@ pub trait EntityName: IdenStatic + Default {
     }
 }
 
+/// Fail to insert, update or delete the rows of an entity backed by a view
+pub(crate) fn check_writable<E>(entity: E) -> Result<(), DbErr>
+where
+    E: EntityName,
+{
+    if entity.is_view() {
+        return Err(DbErr::Custom(format!(
+            "`{}` is a view, which is read-only",
+            entity.table_name()
+        )));
+    }
+    Ok(())
+}
+
+/// `REFRESH MATERIALIZED VIEW` of an entity backed by a materialized view, on Postgres
+pub(crate) fn refresh_materialized_view<E>(
+    entity: E,
+    backend: DbBackend,
+    concurrently: bool,
+) -> Result<Statement, DbErr>
+where
+    E: EntityName,
+{
+    if backend != DbBackend::Postgres {
+        return Err(DbErr::Custom(format!(
+            "Materialized views aren't supported by {backend:?}"
+        )));
+    }
+    let quote = backend.get_query_builder().quote();
+    let mut sql = String::from("REFRESH MATERIALIZED VIEW ");
+    if concurrently {
+        sql.push_str("CONCURRENTLY ");
+    }
+    if let Some(schema) = entity.schema_name() {
+        Alias::new(schema).prepare(&mut sql, quote);
+        sql.push('.');
+    }
+    entity.prepare(&mut sql, quote);
+    Ok(Statement::from_string(backend, sql))
+}
+
 /// An abstract base class for defining Entities.
 ///
 /// This trait provides an API for you to inspect it's properties
@@ -54,6 +108,7 @@ pub trait EntityName: IdenStatic + Default {
 /// - Insert: `insert`, `insert_*`
 /// - Update: `update`, `update_*`
 /// - Delete: `delete`, `delete_*`
+#[async_trait::async_trait]
 pub trait EntityTrait: EntityName {
     #[allow(missing_docs)]
     type Model: ModelTrait<Entity = Self> + FromQueryResult;
@@ -177,6 +232,42 @@ pub trait EntityTrait: EntityName {
         Select::new()
     }
 
+    /// Find `n` random rows, ordered with `RANDOM()` or `RAND()` depending on the backend.
+    /// For large tables, see [`Select::random_sampled`].
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_random(3)
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY RANDOM() ASC LIMIT 3"#
+    /// );
+    /// ```
+    fn find_random(n: u64) -> Select<Self> {
+        Self::find().order_by_random().limit(n)
+    }
+
+    /// Find the rows as they were at the given time, see [`Select::as_of`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find_as_of("2024-01-01 00:00:00", DbBackend::MySql)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` FOR SYSTEM_TIME AS OF TIMESTAMP '2024-01-01 00:00:00'"
+    /// );
+    /// ```
+    fn find_as_of<T>(time: T, db_backend: DbBackend) -> Select<Self>
+    where
+        T: Into<Value>,
+    {
+        Self::find().as_of(time, db_backend)
+    }
+
     /// Find a model by primary key
     ///
     /// # Example
@@ -288,6 +379,34 @@ pub trait EntityTrait: EntityName {
         select
     }
 
+    /// Find the `k` models nearest to a vector, by the Euclidean distance of a pgvector column,
+    /// see [`ColumnTrait::vector_l2_distance`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::document, DbBackend, PgVector};
+    ///
+    /// assert_eq!(
+    ///     document::Entity::find_nearest(document::Column::Embedding, PgVector(vec![1.0, 0.5]), 5)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "document"."id", "document"."title", CAST("document"."embedding" AS text)"#,
+    ///         r#"FROM "document""#,
+    ///         r#"ORDER BY "document"."embedding" <-> CAST('[1,0.5]' AS vector) ASC"#,
+    ///         r#"LIMIT 5"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn find_nearest<V>(col: Self::Column, v: V, k: u64) -> Select<Self>
+    where
+        V: Into<sea_query::Value>,
+    {
+        Self::find()
+            .order_by_asc(col.vector_l2_distance(v))
+            .limit(k)
+    }
+
     /// Insert an model into database
     ///
     /// # Example (Postgres)
@@ -475,6 +594,33 @@ pub trait EntityTrait: EntityName {
         Insert::many(models)
     }
 
+    /// Copy many models into the table with PostgreSQL's `COPY .. FROM STDIN`, which is much faster
+    /// than inserting them, e.g. to load a large data set. The models are converted and sent while
+    /// being copied, so they can be read lazily. Unlike an insert, no `ActiveModelBehavior` hook is run.
+    ///
+    /// ```no_run
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// # async fn copy(db: &DatabaseConnection) -> Result<(), DbErr> {
+    /// let cakes = (1..=100_000).map(|i| cake::ActiveModel {
+    ///     name: Set(format!("Cake {i}")),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// let rows = cake::Entity::copy_in(cakes).exec(db).await?;
+    ///
+    /// assert_eq!(rows, 100_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn copy_in<'a, A, I>(models: I) -> CopyIn<'a, A>
+    where
+        A: ActiveModelTrait<Entity = Self> + 'a,
+        I: IntoIterator<Item = A>,
+        I::IntoIter: Send + 'a,
+    {
+        CopyIn::many(models)
+    }
+
     /// Update an model in database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
@@ -853,6 +999,52 @@ pub trait EntityTrait: EntityName {
         }
         delete
     }
+
+    /// Refresh the materialized view backing the entity, e.g. one created by
+    /// [`Schema::create_materialized_view_from_select`](crate::Schema::create_materialized_view_from_select),
+    /// which is supported by Postgres
+    ///
+    /// A view refreshed `concurrently` can be queried while it's refreshed, but it must have
+    /// a unique index on its rows.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 0,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// cake::Entity::refresh_materialized(&db, true).await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::one(Statement::from_string(
+    ///         DbBackend::Postgres,
+    ///         r#"REFRESH MATERIALIZED VIEW CONCURRENTLY "cake""#,
+    ///     ))]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn refresh_materialized<C>(db: &C, concurrently: bool) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let stmt =
+            refresh_materialized_view(Self::default(), db.get_database_backend(), concurrently)?;
+        db.execute(stmt).await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -972,4 +1164,77 @@ mod tests {
         delete_by_id("UUID");
         delete_by_id(Cow::from("UUID"));
     }
+
+    #[smol_potat::test]
+    #[cfg(all(feature = "macros", feature = "mock"))]
+    async fn entity_model_view() -> Result<(), crate::DbErr> {
+        use crate::{entity::*, DbBackend, DbErr, MockDatabase};
+
+        mod cake_count {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "cake_count", view)]
+            pub struct Model {
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub name: String,
+                pub count: i64,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let model = cake_count::Model {
+            name: "Chocolate".to_owned(),
+            count: 2,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[model.clone()]])
+            .into_connection();
+
+        assert!(cake_count::Entity.is_view());
+        assert_eq!(
+            cake_count::Entity::find().one(&db).await?,
+            Some(model.clone())
+        );
+
+        let read_only = || DbErr::Custom("`cake_count` is a view, which is read-only".to_owned());
+        assert_eq!(
+            model.clone().into_active_model().insert(&db).await,
+            Err(read_only())
+        );
+        assert_eq!(
+            cake_count::Entity::delete_many()
+                .exec(&db)
+                .await
+                .unwrap_err(),
+            read_only()
+        );
+        assert_eq!(db.into_transaction_log().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_materialized_view() {
+        use super::refresh_materialized_view;
+        use crate::{tests_cfg::*, DbBackend, DbErr};
+
+        assert_eq!(
+            refresh_materialized_view(cake_filling_price::Entity, DbBackend::Postgres, false)
+                .unwrap()
+                .to_string(),
+            r#"REFRESH MATERIALIZED VIEW "public"."cake_filling_price""#
+        );
+        assert_eq!(
+            refresh_materialized_view(cake::Entity, DbBackend::MySql, false),
+            Err(DbErr::Custom(
+                "Materialized views aren't supported by MySql".to_owned()
+            ))
+        );
+    }
 }
@@ -1,6 +1,8 @@
-use crate::{DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable};
+use crate::{CaseWhen, DbBackend, EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable};
 use sea_query::{
-    Alias, BinOper, DynIden, Expr, IntoIden, SeaRc, SelectStatement, SimpleExpr, Value,
+    extension::{postgres::PgBinOper, sqlite::SqliteBinOper},
+    Alias, BinOper, DynIden, Expr, Func, IntoIden, Quote, SeaRc, SelectStatement, SimpleExpr,
+    Value,
 };
 use std::str::FromStr;
 
@@ -17,6 +19,9 @@ pub struct ColumnDef {
     pub(crate) indexed: bool,
     pub(crate) default: Option<SimpleExpr>,
     pub(crate) comment: Option<String>,
+    pub(crate) domain: Option<String>,
+    pub(crate) collation: Option<String>,
+    pub(crate) uuid_repr: Option<crate::UuidRepr>,
 }
 
 macro_rules! bind_oper {
@@ -85,6 +90,22 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         (self.entity_name(), SeaRc::new(*self) as DynIden)
     }
 
+    /// Find the column with exactly the given name in the database, e.g. to validate a column name coming from user input.
+    /// Unlike `FromStr`, the camelCase variant of the name is not accepted.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::fruit};
+    ///
+    /// assert!(matches!(
+    ///     fruit::Column::from_name("cake_id"),
+    ///     Some(fruit::Column::CakeId)
+    /// ));
+    /// assert!(fruit::Column::from_name("cakeId").is_none());
+    /// ```
+    fn from_name(name: &str) -> Option<Self> {
+        Self::iter().find(|col| col.as_str() == name)
+    }
+
     bind_oper!(eq, Equal);
     bind_oper!(ne, NotEqual);
     bind_oper!(gt, GreaterThan);
@@ -228,6 +249,15 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_func_no_params!(is_null);
     bind_func_no_params!(is_not_null);
 
+    /// `GROUPING(col)`, returns 1 in rows where the column has been aggregated away by
+    /// [`QuerySelect::group_by_rollup`](crate::QuerySelect::group_by_rollup) and friends, 0 otherwise.
+    /// Supported by Postgres and MySQL.
+    fn grouping(&self) -> SimpleExpr {
+        Func::cust(Alias::new("GROUPING"))
+            .arg(Expr::col((self.entity_name(), *self)))
+            .into()
+    }
+
     /// Perform an operation if the column is null
     fn if_null<V>(&self, v: V) -> SimpleExpr
     where
@@ -242,6 +272,842 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_subquery_func!(in_subquery);
     bind_subquery_func!(not_in_subquery);
 
+    /// Extract the JSON value at `path` from a JSON column. `path` is a dot separated list of
+    /// object keys with array indices in brackets, e.g. `"user.id"` or `"items[0].name"`,
+    /// or a slice of [`JsonPathSegment`](crate::JsonPathSegment) to address any key, see [`IntoJsonPath`](crate::IntoJsonPath).
+    ///
+    /// Renders a chain of `->` on Postgres and `JSON_EXTRACT` on MySQL and SQLite.
+    /// On Postgres the result is still JSON, use [`ColumnTrait::json_get_text`] to compare against plain values.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(
+    ///             recipe::Column::Payload
+    ///                 .json_get(DbBackend::MySql, "user.id")
+    ///                 .eq(5)
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"SELECT `recipe`.`id` FROM `recipe` WHERE JSON_EXTRACT(`recipe`.`payload`, '$.user.id') = 5"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(
+    ///             recipe::Column::Payload
+    ///                 .json_get(DbBackend::Postgres, "tags[0]")
+    ///                 .is_not_null()
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE (("recipe"."payload" -> 'tags') -> 0) IS NOT NULL"#
+    /// );
+    ///
+    /// // A key containing a dot is addressed by segments
+    /// use sea_orm::JsonPathSegment;
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(
+    ///             recipe::Column::Payload
+    ///                 .json_get(
+    ///                     DbBackend::Sqlite,
+    ///                     [
+    ///                         JsonPathSegment::Key("v1.2".to_owned()),
+    ///                         JsonPathSegment::Key("2024".to_owned()),
+    ///                     ],
+    ///                 )
+    ///                 .eq(5)
+    ///         )
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE JSON_EXTRACT("recipe"."payload", '$."v1.2"."2024"') = 5"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn json_get<P>(&self, db_backend: DbBackend, path: P) -> Expr
+    where
+        P: crate::IntoJsonPath,
+    {
+        json_path_expr(
+            Expr::col((self.entity_name(), *self)).into(),
+            db_backend,
+            &path.into_json_path(),
+            false,
+        )
+    }
+
+    /// Extract the value at `path` from a JSON column as text, see [`ColumnTrait::json_get`] for the path syntax.
+    ///
+    /// Renders a chain of `->` ending with `->>` on Postgres, `JSON_UNQUOTE(JSON_EXTRACT(..))` on MySQL
+    /// and `JSON_EXTRACT` on SQLite.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(
+    ///             recipe::Column::Payload
+    ///                 .json_get_text(DbBackend::Postgres, "user.name")
+    ///                 .eq("Alice")
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE (("recipe"."payload" -> 'user') ->> 'name') = 'Alice'"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(
+    ///             recipe::Column::Payload
+    ///                 .json_get_text(DbBackend::MySql, "user.name")
+    ///                 .eq("Alice")
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"SELECT `recipe`.`id` FROM `recipe` WHERE JSON_UNQUOTE(JSON_EXTRACT(`recipe`.`payload`, '$.user.name')) = 'Alice'"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(
+    ///             recipe::Column::Payload
+    ///                 .json_get_text(DbBackend::Sqlite, "user.name")
+    ///                 .eq("Alice")
+    ///         )
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE JSON_EXTRACT("recipe"."payload", '$.user.name') = 'Alice'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn json_get_text<P>(&self, db_backend: DbBackend, path: P) -> Expr
+    where
+        P: crate::IntoJsonPath,
+    {
+        json_path_expr(
+            Expr::col((self.entity_name(), *self)).into(),
+            db_backend,
+            &path.into_json_path(),
+            true,
+        )
+    }
+
+    /// Postgres only. Check if a `jsonb` column contains the given JSON document, using the `@>` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Payload.json_contains(json!({ "status": "open" })))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE "recipe"."payload" @> E'{\"status\":\"open\"}'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn json_contains<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(PgBinOper::Contains, Expr::val(v))
+    }
+
+    /// Postgres only. Check if a `jsonb` column has the given top-level key, using the `?` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Payload.json_has_key("user"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE "recipe"."payload" ? 'user'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn json_has_key<T>(&self, key: T) -> SimpleExpr
+    where
+        T: Into<String>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom("?"), Expr::val(key.into()))
+    }
+
+    /// Check if the tag of a [`JsonTagged`](crate::JsonTagged) enum stored in a JSON column is the given tag,
+    /// see [`ColumnTrait::json_get_text`].
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend, FromJsonQueryResult};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, FromJsonQueryResult)]
+    /// #[serde(tag = "type", rename_all = "snake_case")]
+    /// pub enum Event {
+    ///     OrderPlaced { order_id: i32 },
+    ///     OrderShipped { order_id: i32 },
+    /// }
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Payload.json_tag_eq::<Event>(DbBackend::Postgres, "order_placed"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE ("recipe"."payload" ->> 'type') = 'order_placed'"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Payload.json_tag_is_in::<Event, _>(
+    ///             DbBackend::MySql,
+    ///             ["order_placed", "order_shipped"]
+    ///         ))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"SELECT `recipe`.`id` FROM `recipe` WHERE JSON_UNQUOTE(JSON_EXTRACT(`recipe`.`payload`, '$.type')) IN ('order_placed', 'order_shipped')"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn json_tag_eq<T>(&self, db_backend: DbBackend, tag: &str) -> SimpleExpr
+    where
+        T: crate::JsonTagged,
+    {
+        self.json_get_text(db_backend, T::TAG).eq(tag)
+    }
+
+    /// Check if the tag of a [`JsonTagged`](crate::JsonTagged) enum stored in a JSON column is one of the given tags,
+    /// see [`ColumnTrait::json_tag_eq`].
+    #[cfg(feature = "with-json")]
+    fn json_tag_is_in<T, I>(&self, db_backend: DbBackend, tags: I) -> SimpleExpr
+    where
+        T: crate::JsonTagged,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.json_get_text(db_backend, T::TAG)
+            .is_in(tags.into_iter().map(|tag| tag.as_ref().to_owned()))
+    }
+
+    /// Postgres only. Check if a range column overlaps the given range, using the `&&` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::booking, DbBackend, PgRange};
+    ///
+    /// assert_eq!(
+    ///     booking::Entity::find()
+    ///         .select_only()
+    ///         .column(booking::Column::Id)
+    ///         .filter(booking::Column::Seats.range_overlaps(PgRange::from(1..10)))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "booking"."id" FROM "booking" WHERE "booking"."seats" && CAST('[1,10)' AS int4range)"#
+    /// );
+    /// ```
+    fn range_overlaps<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(PgBinOper::Overlap, self.save_as(Expr::val(v)))
+    }
+
+    /// Postgres only. Check if a range column contains the given element, using the `@>` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::booking, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     booking::Entity::find()
+    ///         .select_only()
+    ///         .column(booking::Column::Id)
+    ///         .filter(booking::Column::Seats.range_contains(5))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "booking"."id" FROM "booking" WHERE "booking"."seats" @> 5"#
+    /// );
+    /// ```
+    fn range_contains<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(PgBinOper::Contains, Expr::val(v))
+    }
+
+    /// Postgres only. Check if a range column contains the given range, using the `@>` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::booking, DbBackend, PgRange};
+    ///
+    /// assert_eq!(
+    ///     booking::Entity::find()
+    ///         .select_only()
+    ///         .column(booking::Column::Id)
+    ///         .filter(booking::Column::Seats.range_contains_range(PgRange::from(2..=4)))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "booking"."id" FROM "booking" WHERE "booking"."seats" @> CAST('[2,4]' AS int4range)"#
+    /// );
+    /// ```
+    fn range_contains_range<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(PgBinOper::Contains, self.save_as(Expr::val(v)))
+    }
+
+    /// Postgres only. Check if a range column is contained by the given range, using the `<@` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::booking, DbBackend, PgRange};
+    ///
+    /// assert_eq!(
+    ///     booking::Entity::find()
+    ///         .select_only()
+    ///         .column(booking::Column::Id)
+    ///         .filter(booking::Column::Seats.range_contained_by(PgRange::from(..100)))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "booking"."id" FROM "booking" WHERE "booking"."seats" <@ CAST('(,100)' AS int4range)"#
+    /// );
+    /// ```
+    fn range_contained_by<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(PgBinOper::Contained, self.save_as(Expr::val(v)))
+    }
+
+    /// PostGIS only. Check if a geometry column is within the given distance of a geometry, using `ST_DWithin`.
+    /// The distance is in the units of the spatial reference system, or in meters for a `geography`.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::place, DbBackend, PgGeometry};
+    ///
+    /// assert_eq!(
+    ///     place::Entity::find()
+    ///         .select_only()
+    ///         .column(place::Column::Id)
+    ///         .filter(place::Column::Location.geo_within_distance(
+    ///             PgGeometry::with_srid(4326, geo_types::point!(x: 1.5, y: 2.0)),
+    ///             100.0
+    ///         ))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "place"."id" FROM "place" WHERE ST_DWithin("place"."location", CAST('SRID=4326;POINT(1.5 2)' AS geometry), 100)"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-geo")]
+    fn geo_within_distance<V>(&self, v: V, distance: f64) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Func::cust(Alias::new("ST_DWithin"))
+            .arg(Expr::col((self.entity_name(), *self)))
+            .arg(self.save_as(Expr::val(v)))
+            .arg(distance)
+            .into()
+    }
+
+    /// PostGIS only. Check if a geometry column contains the given geometry, using `ST_Contains`.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::place, DbBackend, PgGeometry};
+    ///
+    /// assert_eq!(
+    ///     place::Entity::find()
+    ///         .select_only()
+    ///         .column(place::Column::Id)
+    ///         .filter(place::Column::Location.geo_contains(PgGeometry::new(geo_types::point!(x: 1.0, y: 2.0))))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "place"."id" FROM "place" WHERE ST_Contains("place"."location", CAST('POINT(1 2)' AS geometry))"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-geo")]
+    fn geo_contains<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Func::cust(Alias::new("ST_Contains"))
+            .arg(Expr::col((self.entity_name(), *self)))
+            .arg(self.save_as(Expr::val(v)))
+            .into()
+    }
+
+    /// PostGIS only. Check if the bounding box of a geometry column intersects the one of the given geometry,
+    /// using the `&&` operator, which is fast with a spatial index.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::place, DbBackend, PgGeometry};
+    ///
+    /// let area = geo_types::Rect::new(geo_types::coord! { x: 0., y: 0. }, geo_types::coord! { x: 2., y: 2. });
+    ///
+    /// assert_eq!(
+    ///     place::Entity::find()
+    ///         .select_only()
+    ///         .column(place::Column::Id)
+    ///         .filter(place::Column::Location.geo_bbox_intersects(PgGeometry::new(geo_types::Geometry::from(area))))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "place"."id" FROM "place" WHERE "place"."location" && CAST('POLYGON((2 0,2 2,0 2,0 0,2 0))' AS geometry)"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-geo")]
+    fn geo_bbox_intersects<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(PgBinOper::Overlap, self.save_as(Expr::val(v)))
+    }
+
+    /// pgvector only. The Euclidean (L2) distance between a vector column and the given vector, using the `<->` operator,
+    /// e.g. to order by similarity.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::document, DbBackend, PgVector};
+    ///
+    /// assert_eq!(
+    ///     document::Entity::find()
+    ///         .select_only()
+    ///         .column(document::Column::Id)
+    ///         .order_by_asc(document::Column::Embedding.vector_l2_distance(PgVector(vec![1.0, 0.5])))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "document"."id" FROM "document" ORDER BY "document"."embedding" <-> CAST('[1,0.5]' AS vector) ASC"#
+    /// );
+    /// ```
+    fn vector_l2_distance<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(BinOper::Custom("<->"), self.save_as(Expr::val(v)))
+    }
+
+    /// pgvector only. The negative inner product between a vector column and the given vector, using the `<#>` operator,
+    /// e.g. to order by similarity.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::document, DbBackend, PgVector};
+    ///
+    /// assert_eq!(
+    ///     document::Entity::find()
+    ///         .select_only()
+    ///         .column(document::Column::Id)
+    ///         .order_by_asc(document::Column::Embedding.vector_inner_product(PgVector(vec![1.0, 0.5])))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "document"."id" FROM "document" ORDER BY "document"."embedding" <#> CAST('[1,0.5]' AS vector) ASC"#
+    /// );
+    /// ```
+    fn vector_inner_product<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(BinOper::Custom("<#>"), self.save_as(Expr::val(v)))
+    }
+
+    /// pgvector only. The cosine distance between a vector column and the given vector, using the `<=>` operator,
+    /// e.g. to order by similarity.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::document, DbBackend, PgVector};
+    ///
+    /// assert_eq!(
+    ///     document::Entity::find()
+    ///         .select_only()
+    ///         .column(document::Column::Id)
+    ///         .order_by_asc(document::Column::Embedding.vector_cosine_distance(PgVector(vec![1.0, 0.5])))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "document"."id" FROM "document" ORDER BY "document"."embedding" <=> CAST('[1,0.5]' AS vector) ASC"#
+    /// );
+    /// ```
+    fn vector_cosine_distance<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(BinOper::Custom("<=>"), self.save_as(Expr::val(v)))
+    }
+
+    /// Postgres only. Check if an ltree column is an ancestor of the given path, or the path itself,
+    /// using the `@>` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::category_tree, DbBackend, PgLtree};
+    ///
+    /// assert_eq!(
+    ///     category_tree::Entity::find()
+    ///         .select_only()
+    ///         .column(category_tree::Column::Id)
+    ///         .filter(category_tree::Column::Path.ltree_ancestor_of("Top.Science".parse::<PgLtree>().unwrap()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "category_tree"."id" FROM "category_tree" WHERE "category_tree"."path" @> CAST('Top.Science' AS ltree)"#
+    /// );
+    /// ```
+    fn ltree_ancestor_of<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(PgBinOper::Contains, self.save_as(Expr::val(v)))
+    }
+
+    /// Postgres only. Check if an ltree column is a descendant of the given path, or the path itself,
+    /// using the `<@` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::category_tree, DbBackend, PgLtree};
+    ///
+    /// assert_eq!(
+    ///     category_tree::Entity::find()
+    ///         .select_only()
+    ///         .column(category_tree::Column::Id)
+    ///         .filter(category_tree::Column::Path.ltree_descendant_of("Top.Science".parse::<PgLtree>().unwrap()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "category_tree"."id" FROM "category_tree" WHERE "category_tree"."path" <@ CAST('Top.Science' AS ltree)"#
+    /// );
+    /// ```
+    fn ltree_descendant_of<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self))
+            .binary(PgBinOper::Contained, self.save_as(Expr::val(v)))
+    }
+
+    /// Postgres only. Check if an ltree column matches the given `lquery` pattern, using the `~` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::category_tree, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     category_tree::Entity::find()
+    ///         .select_only()
+    ///         .column(category_tree::Column::Id)
+    ///         .filter(category_tree::Column::Path.ltree_matches("*.Astronomy.*"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "category_tree"."id" FROM "category_tree" WHERE "category_tree"."path" ~ CAST('*.Astronomy.*' AS lquery)"#
+    /// );
+    /// ```
+    fn ltree_matches(&self, lquery: &str) -> SimpleExpr {
+        Expr::col((self.entity_name(), *self)).binary(
+            BinOper::Custom("~"),
+            Expr::val(lquery).cast_as(Alias::new("lquery")),
+        )
+    }
+
+    /// Postgres only. Check if an `inet` or `cidr` column is contained by the given subnet, using the `<<` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::host, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     host::Entity::find()
+    ///         .select_only()
+    ///         .column(host::Column::Id)
+    ///         .filter(host::Column::Ip.inet_contained_by("192.168.1.0/24".parse::<ipnetwork::IpNetwork>().unwrap()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "host"."id" FROM "host" WHERE "host"."ip" << '192.168.1.0/24'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-ipnetwork")]
+    fn inet_contained_by<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom("<<"), Expr::val(v))
+    }
+
+    /// Postgres only. Check if an `inet` or `cidr` column is contained by or equal to the given subnet, using the `<<=` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::host, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     host::Entity::find()
+    ///         .select_only()
+    ///         .column(host::Column::Id)
+    ///         .filter(host::Column::Ip.inet_contained_by_or_eq("192.168.1.0/24".parse::<ipnetwork::IpNetwork>().unwrap()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "host"."id" FROM "host" WHERE "host"."ip" <<= '192.168.1.0/24'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-ipnetwork")]
+    fn inet_contained_by_or_eq<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom("<<="), Expr::val(v))
+    }
+
+    /// Postgres only. Check if an `inet` or `cidr` column contains the given address or subnet, using the `>>` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::host, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     host::Entity::find()
+    ///         .select_only()
+    ///         .column(host::Column::Id)
+    ///         .filter(host::Column::Ip.inet_contains("192.168.1.5".parse::<ipnetwork::IpNetwork>().unwrap()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "host"."id" FROM "host" WHERE "host"."ip" >> '192.168.1.5/32'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-ipnetwork")]
+    fn inet_contains<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom(">>"), Expr::val(v))
+    }
+
+    /// Postgres only. Check if an `inet` or `cidr` column contains or is equal to the given address or subnet, using the `>>=` operator.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::host, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     host::Entity::find()
+    ///         .select_only()
+    ///         .column(host::Column::Id)
+    ///         .filter(host::Column::Ip.inet_contains_or_eq("192.168.1.5".parse::<ipnetwork::IpNetwork>().unwrap()))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "host"."id" FROM "host" WHERE "host"."ip" >>= '192.168.1.5/32'"#
+    /// );
+    /// ```
+    #[cfg(feature = "with-ipnetwork")]
+    fn inet_contains_or_eq<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Custom(">>="), Expr::val(v))
+    }
+
+    /// Full-text search on a text column.
+    ///
+    /// Renders `to_tsvector(..) @@ plainto_tsquery(..)` on Postgres, `MATCH (..) AGAINST (..)` on MySQL
+    /// (requires a `FULLTEXT` index) and the `MATCH` operator on SQLite (the table must be an FTS5 virtual table).
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Name.full_text_matches(DbBackend::Postgres, "chocolate cake"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE to_tsvector("recipe"."name") @@ plainto_tsquery('chocolate cake')"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Name.full_text_matches(DbBackend::MySql, "chocolate cake"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"SELECT `recipe`.`id` FROM `recipe` WHERE MATCH (`recipe`.`name`) AGAINST ('chocolate cake')"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Name.full_text_matches(DbBackend::Sqlite, "chocolate cake"))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" WHERE "recipe"."name" MATCH 'chocolate cake'"#
+    /// );
+    /// ```
+    fn full_text_matches<T>(&self, db_backend: DbBackend, query: T) -> SimpleExpr
+    where
+        T: Into<String>,
+    {
+        let col = Expr::col((self.entity_name(), *self));
+        let query = query.into();
+        match db_backend {
+            DbBackend::Postgres => Expr::expr(Func::cust(Alias::new("to_tsvector")).arg(col))
+                .binary(
+                    PgBinOper::Matches,
+                    Func::cust(Alias::new("plainto_tsquery")).arg(query),
+                ),
+            DbBackend::MySql => Expr::cust_with_exprs(
+                "MATCH (?) AGAINST (?)",
+                [col.into(), Expr::val(query).into()],
+            ),
+            DbBackend::Sqlite => col.binary(SqliteBinOper::Match, Expr::val(query)),
+        }
+    }
+
+    /// Relevance of a row against a full-text search query, to be used with [`ColumnTrait::full_text_matches`].
+    /// A higher value means a more relevant row, so order by it descendingly.
+    ///
+    /// Renders `ts_rank` on Postgres, `MATCH (..) AGAINST (..)` on MySQL and the negated `bm25` of the FTS5 table on SQLite.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .filter(recipe::Column::Name.full_text_matches(DbBackend::Postgres, "cake"))
+    ///         .order_by_desc(recipe::Column::Name.full_text_rank(DbBackend::Postgres, "cake"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "recipe"."id" FROM "recipe""#,
+    ///         r#"WHERE to_tsvector("recipe"."name") @@ plainto_tsquery('cake')"#,
+    ///         r#"ORDER BY ts_rank(to_tsvector("recipe"."name"), plainto_tsquery('cake')) DESC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find()
+    ///         .select_only()
+    ///         .column(recipe::Column::Id)
+    ///         .order_by_desc(recipe::Column::Name.full_text_rank(DbBackend::Sqlite, "cake"))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id" FROM "recipe" ORDER BY bm25("recipe") * -1 DESC"#
+    /// );
+    /// ```
+    fn full_text_rank<T>(&self, db_backend: DbBackend, query: T) -> SimpleExpr
+    where
+        T: Into<String>,
+    {
+        let col = Expr::col((self.entity_name(), *self));
+        let query = query.into();
+        match db_backend {
+            DbBackend::Postgres => Func::cust(Alias::new("ts_rank"))
+                .arg(Func::cust(Alias::new("to_tsvector")).arg(col))
+                .arg(Func::cust(Alias::new("plainto_tsquery")).arg(query))
+                .into(),
+            DbBackend::MySql => Expr::cust_with_exprs(
+                "MATCH (?) AGAINST (?)",
+                [col.into(), Expr::val(query).into()],
+            ),
+            DbBackend::Sqlite => {
+                Expr::expr(Func::cust(Alias::new("bm25")).arg(Expr::col(self.entity_name())))
+                    .mul(-1)
+            }
+        }
+    }
+
+    /// Map values of the column to values of type `T` with a [`CaseWhen`] expression,
+    /// e.g. to turn a status enum into a sort weight. Use [`CaseWhen::otherwise`] to set the fallback value.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .order_by_asc(fruit::Column::Name.case_map([("Apple", 1), ("Banana", 2)]))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" ORDER BY"#,
+    ///         r#"(CASE WHEN ("fruit"."name" = 'Apple') THEN 1 WHEN ("fruit"."name" = 'Banana') THEN 2 END) ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn case_map<V, T, I>(&self, branches: I) -> CaseWhen<T>
+    where
+        V: Into<Value>,
+        T: Into<Value>,
+        I: IntoIterator<Item = (V, T)>,
+    {
+        branches
+            .into_iter()
+            .fold(CaseWhen::new(), |case, (v, then)| {
+                case.when(self.eq(v), then)
+            })
+    }
+
+    /// Compare or sort the column by a collation, with `COLLATE`, instead of that of the column.
+    /// The name of the collation is quoted as an identifier, so it's case-sensitive.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.collate("und-x-icu").eq("Crème"))
+    ///         .order_by_asc(cake::Column::Name.collate("C"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."name" COLLATE "und-x-icu" = 'Crème'"#,
+    ///         r#"ORDER BY "cake"."name" COLLATE "C" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.collate("utf8mb4_bin").eq("Crème"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+    ///         "WHERE `cake`.`name` COLLATE `utf8mb4_bin` = 'Crème'",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn collate(&self, collation: &str) -> Expr {
+        let collated = Collated {
+            column: self.into_iden(),
+            collation: collation.to_owned(),
+        };
+        Expr::col((self.entity_name(), collated))
+    }
+
     /// Construct a [`SimpleExpr::Column`] wrapped in [`Expr`].
     fn into_expr(self) -> Expr {
         Expr::expr(self.into_simple_expr())
@@ -262,7 +1128,15 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     }
 
     /// Cast enum column as text; do nothing if `self` is not an enum.
+    /// The column of a Postgres domain is cast into its base type.
     fn select_enum_as(&self, expr: Expr) -> SimpleExpr {
+        let col_def = self.def();
+        if col_def.get_domain().is_some() {
+            // sqlx only reads a domain as its base type
+            if let Some(base_type) = pg_type_name(col_def.get_column_type()) {
+                return expr.as_enum(Alias::new(base_type));
+            }
+        }
         cast_enum_as(expr, self, |col, _, col_type| {
             let type_name = match col_type {
                 ColumnType::Array(_) => TextArray.into_iden(),
@@ -279,8 +1153,26 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     }
 
     /// Cast value of an enum column as enum type; do nothing if `self` is not an enum.
-    /// Will also transform `Array(Vec<Json>)` into `Json(Vec<Json>)` if the column type is `Json`.
+    /// Will also transform `Array(Vec<Json>)` into `Json(Vec<Json>)` if the column type is `Json`,
+    /// cast the value of an `Interval` or `citext` column into its type on Postgres,
+    /// and convert a `Uuid` into the bytes of a column with a [crate::UuidRepr].
     fn save_enum_as(&self, val: Expr) -> SimpleExpr {
+        let col_def = self.def();
+        #[cfg(feature = "with-uuid")]
+        if let Some(repr) = col_def.get_uuid_repr() {
+            return match val.into() {
+                SimpleExpr::Value(Value::Uuid(uuid)) => SimpleExpr::Value(Value::Bytes(
+                    uuid.map(|uuid| Box::new(repr.to_bytes(*uuid).to_vec())),
+                )),
+                val => val,
+            };
+        }
+        match col_def.get_column_type() {
+            ColumnType::Interval(..) => return val.as_enum(Alias::new("interval")),
+            // without the cast, the column is compared with text case-sensitively
+            col_type if col_type.is_citext() => return val.as_enum(Alias::new("citext")),
+            _ => {}
+        }
         cast_enum_as(val, self, |col, enum_name, col_type| {
             let type_name = match col_type {
                 ColumnType::Array(_) => {
@@ -300,6 +1192,9 @@ pub trait ColumnTypeTrait {
 
     /// Get the name of the enum if this is a enum column
     fn get_enum_name(&self) -> Option<&DynIden>;
+
+    /// Whether this is a Postgres `citext` column, holding case-insensitive text
+    fn is_citext(&self) -> bool;
 }
 
 impl ColumnTypeTrait for ColumnType {
@@ -311,12 +1206,19 @@ impl ColumnTypeTrait for ColumnType {
             indexed: false,
             default: None,
             comment: None,
+            domain: None,
+            collation: None,
+            uuid_repr: None,
         }
     }
 
     fn get_enum_name(&self) -> Option<&DynIden> {
         enum_name(self)
     }
+
+    fn is_citext(&self) -> bool {
+        is_citext(self)
+    }
 }
 
 impl ColumnTypeTrait for ColumnDef {
@@ -327,6 +1229,51 @@ impl ColumnTypeTrait for ColumnDef {
     fn get_enum_name(&self) -> Option<&DynIden> {
         enum_name(&self.col_type)
     }
+
+    fn is_citext(&self) -> bool {
+        is_citext(&self.col_type)
+    }
+}
+
+fn is_citext(col_type: &ColumnType) -> bool {
+    match col_type {
+        ColumnType::Custom(name) => name.to_string().eq_ignore_ascii_case("citext"),
+        _ => false,
+    }
+}
+
+/// The name of the Postgres type of a column, as in `pg_type`
+fn pg_type_name(col_type: &ColumnType) -> Option<String> {
+    let name = match col_type {
+        ColumnType::Char(_) => "bpchar",
+        ColumnType::String(_) => "varchar",
+        ColumnType::Text => "text",
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned => "int2",
+        ColumnType::Integer | ColumnType::Unsigned => "int4",
+        ColumnType::BigInteger | ColumnType::BigUnsigned => "int8",
+        ColumnType::Float => "float4",
+        ColumnType::Double => "float8",
+        ColumnType::Decimal(_) | ColumnType::Money(_) => "numeric",
+        ColumnType::Boolean => "bool",
+        ColumnType::Date => "date",
+        ColumnType::Time => "time",
+        ColumnType::DateTime | ColumnType::Timestamp => "timestamp",
+        ColumnType::TimestampWithTimeZone => "timestamptz",
+        ColumnType::Interval(..) => "interval",
+        ColumnType::Uuid => "uuid",
+        ColumnType::Json => "json",
+        ColumnType::JsonBinary => "jsonb",
+        ColumnType::Binary(_) | ColumnType::VarBinary(_) | ColumnType::Blob => "bytea",
+        ColumnType::Inet => "inet",
+        ColumnType::Cidr => "cidr",
+        ColumnType::MacAddr => "macaddr",
+        ColumnType::Custom(name) => return Some(name.to_string()),
+        _ => return None,
+    };
+    Some(name.to_owned())
 }
 
 fn enum_name(col_type: &ColumnType) -> Option<&DynIden> {
@@ -384,6 +1331,39 @@ impl ColumnDef {
         self
     }
 
+    /// Set the Postgres domain of the column, defined over the column type
+    pub fn domain(mut self, name: &str) -> Self {
+        self.domain = Some(name.to_owned());
+        self
+    }
+
+    /// Get the Postgres domain of the column
+    pub fn get_domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    /// Set the collation of the column, by which its text values are compared and sorted
+    pub fn collation(mut self, name: &str) -> Self {
+        self.collation = Some(name.to_owned());
+        self
+    }
+
+    /// Get the collation of the column
+    pub fn get_collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
+    /// Store the `Uuid` values of the column as bytes, see [crate::UuidRepr]
+    pub fn uuid_repr(mut self, repr: crate::UuidRepr) -> Self {
+        self.uuid_repr = Some(repr);
+        self
+    }
+
+    /// Get how the `Uuid` values of the column are stored
+    pub fn get_uuid_repr(&self) -> Option<crate::UuidRepr> {
+        self.uuid_repr
+    }
+
     /// Get [ColumnType] as reference
     pub fn get_column_type(&self) -> &ColumnType {
         &self.col_type
@@ -395,6 +1375,24 @@ impl ColumnDef {
     }
 }
 
+/// A column name followed by its `COLLATE` clause
+struct Collated {
+    column: DynIden,
+    collation: String,
+}
+
+impl Iden for Collated {
+    fn prepare(&self, s: &mut dyn std::fmt::Write, q: Quote) {
+        self.column.prepare(s, q);
+        let _ = write!(s, " COLLATE ");
+        Alias::new(&self.collation).prepare(s, q);
+    }
+
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        self.column.unquoted(s);
+    }
+}
+
 struct Text;
 struct TextArray;
 
@@ -455,6 +1453,46 @@ where
     }
 }
 
+#[cfg(feature = "with-json")]
+fn json_path_expr(
+    col: SimpleExpr,
+    db_backend: DbBackend,
+    segments: &[crate::JsonPathSegment],
+    as_text: bool,
+) -> Expr {
+    match db_backend {
+        DbBackend::Postgres => {
+            let last = segments.len().saturating_sub(1);
+            let expr = segments.iter().enumerate().fold(col, |expr, (i, segment)| {
+                let oper = if as_text && i == last {
+                    PgBinOper::CastJsonField
+                } else {
+                    PgBinOper::GetJsonField
+                };
+                let key: Value = match segment {
+                    crate::JsonPathSegment::Key(key) => key.as_str().into(),
+                    crate::JsonPathSegment::Index(index) => {
+                        i32::try_from(*index).unwrap_or(i32::MAX).into()
+                    }
+                };
+                Expr::expr(expr).binary(oper, Expr::val(key))
+            });
+            Expr::expr(expr)
+        }
+        DbBackend::MySql | DbBackend::Sqlite => {
+            let extract = Func::cust(Alias::new("JSON_EXTRACT"))
+                .arg(col)
+                .arg(crate::json_path_string(segments));
+            match db_backend {
+                DbBackend::MySql if as_text => {
+                    Expr::expr(Func::cust(Alias::new("JSON_UNQUOTE")).arg(extract))
+                }
+                _ => Expr::expr(extract),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -536,6 +1574,21 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_col_from_name() {
+        assert!(matches!(
+            fruit::Column::from_name("id"),
+            Some(fruit::Column::Id)
+        ));
+        assert!(matches!(
+            fruit::Column::from_name("cake_id"),
+            Some(fruit::Column::CakeId)
+        ));
+        assert!(fruit::Column::from_name("cakeId").is_none());
+        assert!(fruit::Column::from_name("CakeId").is_none());
+        assert!(fruit::Column::from_name("").is_none());
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn entity_model_column_1() {
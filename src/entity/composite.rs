@@ -0,0 +1,358 @@
+use crate::{DbErr, DynIden};
+use sea_query::{PostgresQueryBuilder, QueryBuilder, Value};
+
+/// A Rust struct mapped to a PostgreSQL composite type, so that it can be used as the type of
+/// a column and bound as a parameter.
+///
+/// The struct is converted to and from the text of a row value, e.g. `("1 Main St",Springfield,)`.
+/// Its fields must be in the order of the attributes of the composite type.
+///
+/// > See [DeriveCompositeType](sea_orm_macros::DeriveCompositeType) for the full specification of macro attributes.
+///
+/// ```rust
+/// use sea_orm::entity::prelude::*;
+///
+/// // CREATE TYPE address AS (street text, city text, zip integer);
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveCompositeType)]
+/// #[sea_orm(type_name = "address")]
+/// pub struct Address {
+///     pub street: String,
+///     pub city: String,
+///     pub zip: Option<i32>,
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "customer")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     // The row value is read as text, and the text parameter is cast into the composite type
+///     #[sea_orm(select_as = "text", save_as = "address")]
+///     pub address: Address,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+pub trait CompositeType: Sized {
+    /// Get the name of the composite type
+    fn name() -> DynIden;
+
+    /// Convert the fields into values, in the order of the attributes of the composite type
+    fn into_values(self) -> Vec<Value>;
+
+    /// Construct from the text of the attributes of a row value, which is `None` if it is NULL
+    fn from_fields(fields: Vec<Option<String>>) -> Result<Self, DbErr>;
+
+    /// Convert into the text of a row value
+    fn into_text(self) -> String {
+        let mut text = String::from("(");
+        for (i, value) in self.into_values().iter().enumerate() {
+            if i > 0 {
+                text.push(',');
+            }
+            if let Some(field) = value_to_text(value) {
+                quote_field(&field, &mut text);
+            }
+        }
+        text.push(')');
+        text
+    }
+
+    /// Parse the text of a row value
+    fn from_text(text: &str) -> Result<Self, DbErr> {
+        Self::from_fields(parse_row(text)?)
+    }
+}
+
+/// A type which can be an attribute of a [CompositeType], parsed from its text in a row value
+pub trait CompositeField: Sized {
+    /// Parse the text of an attribute, which is `None` if it is NULL
+    fn from_field(field: Option<String>) -> Result<Self, DbErr>;
+}
+
+impl<T> CompositeField for Option<T>
+where
+    T: CompositeField,
+{
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        match field {
+            Some(field) => T::from_field(Some(field)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+fn null_err<T>() -> DbErr {
+    DbErr::Type(format!(
+        "Unexpected NULL attribute of type `{}` in a row value",
+        std::any::type_name::<T>()
+    ))
+}
+
+fn not_null<T>(field: Option<String>) -> Result<String, DbErr> {
+    field.ok_or_else(null_err::<T>)
+}
+
+fn parse_err<T>(field: &str) -> DbErr {
+    DbErr::Type(format!(
+        "Failed to parse `{field}` as `{}` in a row value",
+        std::any::type_name::<T>()
+    ))
+}
+
+impl CompositeField for String {
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        not_null::<Self>(field)
+    }
+}
+
+impl CompositeField for bool {
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        let field = not_null::<Self>(field)?;
+        match field.as_str() {
+            "t" | "true" => Ok(true),
+            "f" | "false" => Ok(false),
+            _ => Err(parse_err::<Self>(&field)),
+        }
+    }
+}
+
+macro_rules! composite_field_from_str {
+    ( $type: ty ) => {
+        impl CompositeField for $type {
+            fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+                let field = not_null::<Self>(field)?;
+                field.parse().map_err(|_| parse_err::<Self>(&field))
+            }
+        }
+    };
+}
+
+composite_field_from_str!(i8);
+composite_field_from_str!(i16);
+composite_field_from_str!(i32);
+composite_field_from_str!(i64);
+composite_field_from_str!(u8);
+composite_field_from_str!(u16);
+composite_field_from_str!(u32);
+composite_field_from_str!(u64);
+composite_field_from_str!(f32);
+composite_field_from_str!(f64);
+
+#[cfg(feature = "with-rust_decimal")]
+composite_field_from_str!(rust_decimal::Decimal);
+
+#[cfg(feature = "with-bigdecimal")]
+composite_field_from_str!(bigdecimal::BigDecimal);
+
+#[cfg(feature = "with-uuid")]
+composite_field_from_str!(uuid::Uuid);
+
+#[cfg(feature = "with-chrono")]
+composite_field_from_str!(chrono::NaiveDate);
+
+#[cfg(feature = "with-chrono")]
+composite_field_from_str!(chrono::NaiveTime);
+
+#[cfg(feature = "with-json")]
+impl CompositeField for serde_json::Value {
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        let field = not_null::<Self>(field)?;
+        serde_json::from_str(&field).map_err(|_| parse_err::<Self>(&field))
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CompositeField for chrono::NaiveDateTime {
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        let field = not_null::<Self>(field)?;
+        Self::parse_from_str(&field, "%Y-%m-%d %H:%M:%S%.f").map_err(|_| parse_err::<Self>(&field))
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CompositeField for chrono::DateTime<chrono::FixedOffset> {
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        let field = not_null::<Self>(field)?;
+        // PostgreSQL omits the minutes of a whole hour offset, e.g. `+08`
+        Self::parse_from_str(&field, "%Y-%m-%d %H:%M:%S%.f%#z")
+            .map_err(|_| parse_err::<Self>(&field))
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CompositeField for chrono::DateTime<chrono::Utc> {
+    fn from_field(field: Option<String>) -> Result<Self, DbErr> {
+        chrono::DateTime::<chrono::FixedOffset>::from_field(field)
+            .map(|datetime| datetime.with_timezone(&chrono::Utc))
+    }
+}
+
+/// Split the text of a row value into the text of its attributes, which is `None` if it is NULL
+fn parse_row(text: &str) -> Result<Vec<Option<String>>, DbErr> {
+    let inner = text
+        .strip_prefix('(')
+        .and_then(|text| text.strip_suffix(')'))
+        .ok_or_else(|| DbErr::Type(format!("Invalid row value `{text}`")))?;
+    split_fields(inner).ok_or_else(|| DbErr::Type(format!("Invalid row value `{text}`")))
+}
+
+/// Split comma separated fields, which may be quoted or escaped like in a row value or a range.
+/// An empty unquoted field is `None`.
+pub(crate) fn split_fields(text: &str) -> Option<Vec<Option<String>>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut null = true;
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => {
+                quoted = !quoted;
+                null = false;
+            }
+            '\\' => {
+                field.push(chars.next()?);
+                null = false;
+            }
+            ',' if !quoted => {
+                fields.push((!null).then(|| std::mem::take(&mut field)));
+                null = true;
+            }
+            c => {
+                field.push(c);
+                null = false;
+            }
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push((!null).then_some(field));
+    Some(fields)
+}
+
+/// Write a field of a row value or a range, quoted if it is empty or has special characters
+pub(crate) fn quote_field(field: &str, out: &mut String) {
+    let special = |c: char| c.is_whitespace() || "\"\\,()[]{}".contains(c);
+    if !field.is_empty() && !field.contains(special) {
+        out.push_str(field);
+        return;
+    }
+    out.push('"');
+    out.push_str(&field.replace('\\', "\\\\").replace('"', "\"\""));
+    out.push('"');
+}
+
+/// The text representation of a value in PostgreSQL, or `None` if it is NULL
+pub(crate) fn value_to_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(Some(v)) => v.as_ref().clone(),
+        Value::Char(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-json")]
+        Value::Json(Some(v)) => v.to_string(),
+        // the literals of the query builder drop the fractional seconds
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(Some(v)) => v.to_rfc3339(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(Some(v)) => v.to_rfc3339(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(Some(v)) => v.to_rfc3339(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(Some(v)) => v.to_string(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(Some(v)) => v
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_else(|_| v.to_string()),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, Some(v)) => {
+            let mut text = String::from("{");
+            for (i, element) in v.iter().enumerate() {
+                if i > 0 {
+                    text.push(',');
+                }
+                match value_to_text(element) {
+                    Some(element) => {
+                        text.push('"');
+                        text.push_str(&element.replace('\\', "\\\\").replace('"', "\\\""));
+                        text.push('"');
+                    }
+                    None => text.push_str("NULL"),
+                }
+            }
+            text.push('}');
+            text
+        }
+        // the literal of the other values is their text representation, unless it is quoted
+        value => {
+            let literal = PostgresQueryBuilder.value_to_string(value);
+            if literal == "NULL" {
+                return None;
+            }
+            literal.trim_matches('\'').to_owned()
+        }
+    };
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_row_value() -> Result<(), DbErr> {
+        assert_eq!(
+            parse_row(r#"(1,"a ""b"", c",,"",\,)"#)?,
+            [
+                Some("1".to_owned()),
+                Some("a \"b\", c".to_owned()),
+                None,
+                Some("".to_owned()),
+                Some(",".to_owned()),
+            ]
+        );
+        assert_eq!(parse_row("()")?, [None]);
+        assert!(parse_row("1,2").is_err());
+        assert!(parse_row(r#"("1)"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn composite_fields() -> Result<(), DbErr> {
+        assert!(bool::from_field(Some("t".to_owned()))?);
+        assert_eq!(i32::from_field(Some("-7".to_owned()))?, -7);
+        assert_eq!(Option::<i32>::from_field(None)?, None);
+        assert!(matches!(i32::from_field(None), Err(DbErr::Type(_))));
+        assert!(matches!(
+            i32::from_field(Some("x".to_owned())),
+            Err(DbErr::Type(_))
+        ));
+        #[cfg(feature = "with-chrono")]
+        assert_eq!(
+            chrono::DateTime::<chrono::FixedOffset>::from_field(Some(
+                "2021-09-17 17:50:20.5+08".to_owned()
+            ))?,
+            chrono::DateTime::parse_from_rfc3339("2021-09-17T17:50:20.5+08:00")
+                .map_err(|e| DbErr::Type(e.to_string()))?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{ColIdx, DbErr, Plaintext, QueryResult, TryGetError, TryGetable};
+use sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use std::ops::Deref;
+
+/// The algorithm of a [Compressed] value, stored in its first byte
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compression {
+    /// Stored without compression
+    Stored,
+    /// Compressed with gzip, requires the `with-gzip` feature
+    Gzip,
+    /// Compressed with zstd, requires the `with-zstd` feature
+    Zstd,
+}
+
+impl Compression {
+    fn marker(self) -> u8 {
+        match self {
+            Self::Stored => 0,
+            Self::Gzip => 1,
+            Self::Zstd => 2,
+        }
+    }
+
+    fn from_marker(marker: u8) -> Result<Self, DbErr> {
+        match marker {
+            0 => Ok(Self::Stored),
+            1 => Ok(Self::Gzip),
+            2 => Ok(Self::Zstd),
+            _ => Err(DbErr::Type(format!("Unknown compression marker {marker}"))),
+        }
+    }
+
+    #[allow(unreachable_code)]
+    fn compress(self, bytes: &[u8]) -> Result<Vec<u8>, DbErr> {
+        match self {
+            Self::Stored => Ok(bytes.to_vec()),
+            Self::Gzip => {
+                #[cfg(feature = "with-gzip")]
+                {
+                    use std::io::Write;
+                    let mut encoder =
+                        flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                    encoder.write_all(bytes).map_err(compression_err)?;
+                    return encoder.finish().map_err(compression_err);
+                }
+                Err(self.unsupported("with-gzip"))
+            }
+            Self::Zstd => {
+                #[cfg(feature = "with-zstd")]
+                return zstd::encode_all(bytes, 0).map_err(compression_err);
+                Err(self.unsupported("with-zstd"))
+            }
+        }
+    }
+
+    #[allow(unreachable_code)]
+    fn decompress(self, bytes: &[u8]) -> Result<Vec<u8>, DbErr> {
+        match self {
+            Self::Stored => Ok(bytes.to_vec()),
+            Self::Gzip => {
+                #[cfg(feature = "with-gzip")]
+                {
+                    use std::io::Read;
+                    let mut decompressed = Vec::new();
+                    flate2::read::GzDecoder::new(bytes)
+                        .read_to_end(&mut decompressed)
+                        .map_err(compression_err)?;
+                    return Ok(decompressed);
+                }
+                Err(self.unsupported("with-gzip"))
+            }
+            Self::Zstd => {
+                #[cfg(feature = "with-zstd")]
+                return zstd::decode_all(bytes).map_err(compression_err);
+                Err(self.unsupported("with-zstd"))
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn unsupported(self, feature: &str) -> DbErr {
+        DbErr::Type(format!(
+            "{self:?} compression requires the `{feature}` feature"
+        ))
+    }
+}
+
+/// Zstd if the `with-zstd` feature is enabled, otherwise gzip if `with-gzip` is,
+/// otherwise no compression
+impl Default for Compression {
+    fn default() -> Self {
+        if cfg!(feature = "with-zstd") {
+            Self::Zstd
+        } else if cfg!(feature = "with-gzip") {
+            Self::Gzip
+        } else {
+            Self::Stored
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn compression_err(err: std::io::Error) -> DbErr {
+    DbErr::Type(format!("Failed to compress or decompress value: {err}"))
+}
+
+/// A `Vec<u8>` or a `String` compressed into a binary column, for large payloads.
+///
+/// It is compressed with the default [Compression] when bound to a statement,
+/// and decompressed with the algorithm marked in its first byte when read.
+/// A value which compression doesn't shrink is stored uncompressed.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, Compressed};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "document")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub body: Compressed<String>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Compressed<T>(pub T);
+
+impl<T> Compressed<T>
+where
+    T: Plaintext,
+{
+    /// Compress the value with an algorithm, prepending its marker
+    pub fn compress(&self, compression: Compression) -> Result<Vec<u8>, DbErr> {
+        let bytes = self.0.to_plaintext();
+        let compressed = compression.compress(&bytes)?;
+        let (compression, payload) = if compressed.len() < bytes.len() {
+            (compression, compressed)
+        } else {
+            (Compression::Stored, bytes)
+        };
+        let mut stored = Vec::with_capacity(payload.len() + 1);
+        stored.push(compression.marker());
+        stored.extend(payload);
+        Ok(stored)
+    }
+
+    /// Decompress a value with the algorithm of its marker
+    pub fn decompress(stored: &[u8]) -> Result<Self, DbErr> {
+        let (marker, payload) = stored
+            .split_first()
+            .ok_or_else(|| DbErr::Type("Compressed value without a marker".to_owned()))?;
+        let bytes = Compression::from_marker(*marker)?.decompress(payload)?;
+        T::from_plaintext(bytes).map(Self)
+    }
+
+    /// Get the decompressed value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Compressed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Compressed<T>
+where
+    T: Plaintext,
+{
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Compressed<T>> for Value
+where
+    T: Plaintext,
+{
+    fn from(value: Compressed<T>) -> Self {
+        let stored = value
+            .compress(Compression::default())
+            .or_else(|_| value.compress(Compression::Stored))
+            .unwrap_or_default();
+        Value::Bytes(Some(Box::new(stored)))
+    }
+}
+
+impl<T> TryGetable for Compressed<T>
+where
+    T: Plaintext,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let stored = Vec::<u8>::try_get_by(res, idx)?;
+        Self::decompress(&stored).map_err(TryGetError::DbErr)
+    }
+}
+
+impl<T> ValueType for Compressed<T>
+where
+    T: Plaintext,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bytes(Some(stored)) => Self::decompress(&stored).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("Compressed<{}>", std::any::type_name::<T>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Blob
+    }
+}
+
+impl<T> Nullable for Compressed<T>
+where
+    T: Plaintext,
+{
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn compressed_value() -> Result<(), DbErr> {
+        let text = Compressed("sea-orm ".repeat(100));
+
+        let stored = text.compress(Compression::Stored)?;
+        assert_eq!(stored[0], 0);
+        assert_eq!(Compressed::<String>::decompress(&stored)?, text);
+
+        #[cfg(feature = "with-gzip")]
+        {
+            let stored = text.compress(Compression::Gzip)?;
+            assert_eq!(stored[0], 1);
+            assert!(stored.len() < 100);
+            assert_eq!(Compressed::<String>::decompress(&stored)?, text);
+        }
+
+        #[cfg(feature = "with-zstd")]
+        {
+            let stored = text.compress(Compression::Zstd)?;
+            assert_eq!(stored[0], 2);
+            assert!(stored.len() < 100);
+            assert_eq!(Compressed::<String>::decompress(&stored)?, text);
+        }
+
+        // not shrunk by compression
+        let bytes = Compressed(vec![1u8, 2, 3]);
+        assert_eq!(bytes.compress(Compression::default())?, [0, 1, 2, 3]);
+
+        assert!(Compressed::<String>::decompress(&[9, 1]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::DbErr;
+use sea_query::{ColumnType, ValueType};
+use std::fmt::Display;
+
+/// Convert a value read from the database with the `load_with` function of a field
+#[doc(hidden)]
+pub fn load_with<S, T, E, F>(value: S, f: F) -> Result<T, DbErr>
+where
+    E: Display,
+    F: FnOnce(S) -> Result<T, E>,
+{
+    f(value).map_err(|e| DbErr::Type(e.to_string()))
+}
+
+/// The column type of a field stored with the `save_with` function
+#[doc(hidden)]
+pub fn save_with_column_type<T, S, F>(_: F) -> ColumnType
+where
+    S: ValueType,
+    F: FnOnce(T) -> S,
+{
+    S::column_type()
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+/// A [Duration] stored in a `BIGINT` column as a count of milliseconds or microseconds
+///
+/// A field is converted with it by `#[sea_orm(duration_repr = "millis")]` or `duration_repr = "micros"`,
+/// as with `#[sea_orm(save_with = "IntegerDuration::into_millis", load_with = "IntegerDuration::from_millis")]`.
+/// The part of a duration finer than the unit is truncated, and a duration too long for a `BIGINT`
+/// is stored as `i64::MAX`. Values compared with the column in filters are converted the same way.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, IntegerDuration};
+/// use std::time::Duration;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "schedule")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     #[sea_orm(duration_repr = "millis")]
+///     pub interval: Duration,
+///     #[sea_orm(duration_repr = "micros")]
+///     pub jitter: Option<Duration>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+///
+/// assert_eq!(
+///     Column::Interval.def().get_column_type(),
+///     &ColumnType::BigInteger
+/// );
+/// assert_eq!(Duration::from_secs(90).into_millis(), 90_000);
+/// ```
+pub trait IntegerDuration: Sized {
+    /// The stored value
+    type Integer;
+
+    /// Convert into a count of milliseconds
+    fn into_millis(self) -> Self::Integer;
+
+    /// Convert from a count of milliseconds, returning an error if it's negative
+    fn from_millis(millis: Self::Integer) -> Result<Self, String>;
+
+    /// Convert into a count of microseconds
+    fn into_micros(self) -> Self::Integer;
+
+    /// Convert from a count of microseconds, returning an error if it's negative
+    fn from_micros(micros: Self::Integer) -> Result<Self, String>;
+}
+
+impl IntegerDuration for Duration {
+    type Integer = i64;
+
+    fn into_millis(self) -> i64 {
+        i64::try_from(self.as_millis()).unwrap_or(i64::MAX)
+    }
+
+    fn from_millis(millis: i64) -> Result<Self, String> {
+        u64::try_from(millis)
+            .map(Duration::from_millis)
+            .map_err(|_| format!("Duration of `{millis}` milliseconds is negative"))
+    }
+
+    fn into_micros(self) -> i64 {
+        i64::try_from(self.as_micros()).unwrap_or(i64::MAX)
+    }
+
+    fn from_micros(micros: i64) -> Result<Self, String> {
+        u64::try_from(micros)
+            .map(Duration::from_micros)
+            .map_err(|_| format!("Duration of `{micros}` microseconds is negative"))
+    }
+}
+
+impl<T> IntegerDuration for Option<T>
+where
+    T: IntegerDuration<Integer = i64>,
+{
+    type Integer = Option<i64>;
+
+    fn into_millis(self) -> Option<i64> {
+        self.map(T::into_millis)
+    }
+
+    fn from_millis(millis: Option<i64>) -> Result<Self, String> {
+        millis.map(T::from_millis).transpose()
+    }
+
+    fn into_micros(self) -> Option<i64> {
+        self.map(T::into_micros)
+    }
+
+    fn from_micros(micros: Option<i64>) -> Result<Self, String> {
+        micros.map(T::from_micros).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn integer_duration() {
+        let duration = Duration::new(3, 4_005_006);
+        assert_eq!(duration.into_millis(), 3_004);
+        assert_eq!(duration.into_micros(), 3_004_005);
+        assert_eq!(
+            <Duration as IntegerDuration>::from_millis(3_004),
+            Ok(Duration::new(3, 4_000_000))
+        );
+        assert_eq!(
+            <Duration as IntegerDuration>::from_micros(3_004_005),
+            Ok(Duration::new(3, 4_005_000))
+        );
+
+        assert_eq!(Duration::MAX.into_millis(), i64::MAX);
+        assert!(<Duration as IntegerDuration>::from_millis(-1).is_err());
+
+        assert_eq!(Some(Duration::from_secs(1)).into_micros(), Some(1_000_000));
+        assert_eq!(Option::<Duration>::from_millis(None), Ok(None));
+    }
+}
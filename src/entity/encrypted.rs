@@ -0,0 +1,304 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{ArrayType, ColumnType, Nullable, StringLen, Value, ValueType, ValueTypeErr};
+use std::{
+    fmt,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+static CIPHER: RwLock<Option<Arc<dyn Cipher>>> = RwLock::new(None);
+
+/// Encrypts and decrypts the values of [Encrypted] columns, and computes their [BlindIndex]
+///
+/// SeaORM doesn't implement any cipher; use an authenticated cipher like AES-GCM,
+/// with the keys from your key provider.
+pub trait Cipher: Send + Sync {
+    /// Encrypt a plaintext, prepending the random nonce to the ciphertext
+    fn encrypt(&self, plaintext: &[u8]) -> Vec<u8>;
+
+    /// Decrypt a ciphertext returned by [Cipher::encrypt]
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, DbErr>;
+
+    /// A keyed hash of a plaintext, like an HMAC with a key other than the encryption key
+    fn blind_index(&self, plaintext: &[u8]) -> Vec<u8>;
+}
+
+/// Set the [Cipher] of all [Encrypted] values, replacing the previous one
+pub fn set_cipher<C>(cipher: C)
+where
+    C: Cipher + 'static,
+{
+    *CIPHER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(cipher));
+}
+
+fn cipher() -> Result<Arc<dyn Cipher>, DbErr> {
+    CIPHER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .ok_or_else(|| DbErr::Type("No cipher is set to encrypt values".to_owned()))
+}
+
+/// A value which can be [Encrypted] or [crate::Compressed], converted from and into bytes
+pub trait Plaintext: Sized {
+    /// Convert into bytes
+    fn to_plaintext(&self) -> Vec<u8>;
+
+    /// Convert from bytes
+    fn from_plaintext(bytes: Vec<u8>) -> Result<Self, DbErr>;
+}
+
+impl Plaintext for String {
+    fn to_plaintext(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn from_plaintext(bytes: Vec<u8>) -> Result<Self, DbErr> {
+        String::from_utf8(bytes).map_err(|e| DbErr::Type(e.to_string()))
+    }
+}
+
+impl Plaintext for Vec<u8> {
+    fn to_plaintext(&self) -> Vec<u8> {
+        self.clone()
+    }
+
+    fn from_plaintext(bytes: Vec<u8>) -> Result<Self, DbErr> {
+        Ok(bytes)
+    }
+}
+
+/// A value stored encrypted in a binary column, with the [Cipher] set by [set_cipher].
+/// It is encrypted when bound to a statement and decrypted when read.
+///
+/// As the ciphertext of a value differs on each encryption, an encrypted column can't be filtered;
+/// find a row by the [BlindIndex] of the value stored in another column instead.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, BlindIndex, Encrypted};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "patient")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub email: Encrypted<String>,
+///     #[sea_orm(unique)]
+///     pub email_index: BlindIndex,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+///
+/// # fn find(email: String) -> Result<(), DbErr> {
+/// let query = Entity::find().filter(Column::EmailIndex.eq(BlindIndex::of(&email)?));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Encrypted<T>(pub T);
+
+impl<T> Encrypted<T>
+where
+    T: Plaintext,
+{
+    /// Encrypt the value
+    pub fn encrypt(&self) -> Result<Vec<u8>, DbErr> {
+        Ok(cipher()?.encrypt(&self.0.to_plaintext()))
+    }
+
+    /// Decrypt a value
+    pub fn decrypt(ciphertext: &[u8]) -> Result<Self, DbErr> {
+        T::from_plaintext(cipher()?.decrypt(ciphertext)?).map(Self)
+    }
+
+    /// The blind index of the value
+    pub fn blind_index(&self) -> Result<BlindIndex, DbErr> {
+        BlindIndex::of(&self.0)
+    }
+
+    /// Get the decrypted value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// The plaintext is never printed
+impl<T> fmt::Debug for Encrypted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Encrypted(..)")
+    }
+}
+
+impl<T> From<T> for Encrypted<T>
+where
+    T: Plaintext,
+{
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<Encrypted<T>> for Value
+where
+    T: Plaintext,
+{
+    /// # Panics
+    ///
+    /// Panics if no cipher is set with [set_cipher]
+    fn from(value: Encrypted<T>) -> Self {
+        match value.encrypt() {
+            Ok(ciphertext) => Value::Bytes(Some(Box::new(ciphertext))),
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+impl<T> TryGetable for Encrypted<T>
+where
+    T: Plaintext,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let ciphertext = Vec::<u8>::try_get_by(res, idx)?;
+        Self::decrypt(&ciphertext).map_err(TryGetError::DbErr)
+    }
+}
+
+impl<T> ValueType for Encrypted<T>
+where
+    T: Plaintext,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bytes(Some(ciphertext)) => Self::decrypt(&ciphertext).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("Encrypted<{}>", std::any::type_name::<T>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::VarBinary(StringLen::None)
+    }
+}
+
+impl<T> Nullable for Encrypted<T>
+where
+    T: Plaintext,
+{
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+/// The keyed hash of an [Encrypted] value, computed by [Cipher::blind_index],
+/// to find a row by equality
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BlindIndex(Vec<u8>);
+
+impl BlindIndex {
+    /// The blind index of a value
+    pub fn of<T>(value: &T) -> Result<Self, DbErr>
+    where
+        T: Plaintext,
+    {
+        Ok(Self(cipher()?.blind_index(&value.to_plaintext())))
+    }
+
+    /// Get the bytes of the index
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<BlindIndex> for Value {
+    fn from(index: BlindIndex) -> Self {
+        Value::Bytes(Some(Box::new(index.0)))
+    }
+}
+
+impl TryGetable for BlindIndex {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        Vec::<u8>::try_get_by(res, idx).map(Self)
+    }
+}
+
+impl ValueType for BlindIndex {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        <Vec<u8> as ValueType>::try_from(v).map(Self)
+    }
+
+    fn type_name() -> String {
+        stringify!(BlindIndex).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::VarBinary(StringLen::None)
+    }
+}
+
+impl Nullable for BlindIndex {
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// A toy cipher xor-ing the plaintext with a key and a nonce
+    struct XorCipher;
+
+    impl Cipher for XorCipher {
+        fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+            let nonce = plaintext.len() as u8;
+            let mut ciphertext = vec![nonce];
+            ciphertext.extend(plaintext.iter().map(|b| b ^ 0x5a ^ nonce));
+            ciphertext
+        }
+
+        fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, DbErr> {
+            let (nonce, ciphertext) = ciphertext
+                .split_first()
+                .ok_or_else(|| DbErr::Type("Empty ciphertext".to_owned()))?;
+            Ok(ciphertext.iter().map(|b| b ^ 0x5a ^ nonce).collect())
+        }
+
+        fn blind_index(&self, plaintext: &[u8]) -> Vec<u8> {
+            plaintext.iter().map(|b| b ^ 0xa5).collect()
+        }
+    }
+
+    #[test]
+    fn encrypted_value() -> Result<(), DbErr> {
+        set_cipher(XorCipher);
+
+        let email = Encrypted("a@b.c".to_owned());
+        let value: Value = email.clone().into();
+        let ciphertext = match &value {
+            Value::Bytes(Some(bytes)) => bytes.to_vec(),
+            _ => panic!("not bytes"),
+        };
+        assert_ne!(&ciphertext[1..], b"a@b.c");
+        assert_eq!(Encrypted::<String>::decrypt(&ciphertext)?, email);
+        assert_eq!(<Encrypted<String> as ValueType>::unwrap(value), email);
+
+        assert_eq!(email.blind_index()?, BlindIndex::of(&"a@b.c".to_owned())?);
+        assert_ne!(email.blind_index()?.as_bytes(), b"a@b.c");
+        assert_eq!(format!("{email:?}"), "Encrypted(..)");
+        Ok(())
+    }
+}
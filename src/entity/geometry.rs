@@ -0,0 +1,416 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use sea_query::{Alias, ArrayType, ColumnType, Nullable, SeaRc, Value, ValueType, ValueTypeErr};
+use std::fmt::Write;
+
+/// A PostGIS `geometry` or `geography`, holding a [geo-types](geo_types) geometry, e.g. a
+/// [`Point`] or any [`Geometry`], and its spatial reference system.
+///
+/// It is written as EWKT, e.g. `SRID=4326;POINT(1 2)`, and read from the hex encoded EWKB which
+/// PostGIS returns when a geometry is cast into text. Only the X and Y coordinates are read.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, PgGeometry};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "place")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     // The geometry is read as text, and the text parameter is cast into a geometry
+///     #[sea_orm(select_as = "text", save_as = "geometry")]
+///     pub location: PgGeometry<geo_types::Point>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PgGeometry<G = Geometry> {
+    /// The identifier of the spatial reference system, e.g. `4326` for WGS 84
+    pub srid: Option<i32>,
+    /// The geometry
+    pub geometry: G,
+}
+
+impl<G> PgGeometry<G> {
+    /// Create a geometry without a spatial reference system
+    pub fn new(geometry: G) -> Self {
+        Self {
+            srid: None,
+            geometry,
+        }
+    }
+
+    /// Create a geometry in the given spatial reference system
+    pub fn with_srid(srid: i32, geometry: G) -> Self {
+        Self {
+            srid: Some(srid),
+            geometry,
+        }
+    }
+}
+
+impl<G> PgGeometry<G>
+where
+    G: Into<Geometry> + TryFrom<Geometry>,
+{
+    /// Convert into EWKT
+    pub fn into_text(self) -> String {
+        let mut text = String::new();
+        if let Some(srid) = self.srid {
+            write!(text, "SRID={srid};").ok();
+        }
+        write_geometry(&self.geometry.into(), &mut text);
+        text
+    }
+
+    /// Parse the hex encoded EWKB of a geometry
+    pub fn from_text(text: &str) -> Result<Self, DbErr> {
+        let invalid = || DbErr::Type(format!("Invalid hex encoded EWKB `{text}`"));
+        if text.len() % 2 != 0 {
+            return Err(invalid());
+        }
+        let bytes = (0..text.len())
+            .step_by(2)
+            .map(|i| {
+                text.get(i..i + 2)
+                    .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(invalid)?;
+        let mut reader = WkbReader {
+            bytes: &bytes,
+            little_endian: false,
+        };
+        let (geometry, srid) = reader.geometry().ok_or_else(invalid)?;
+        let geometry = G::try_from(geometry).map_err(|_| {
+            DbErr::Type(format!(
+                "Expected a geometry of type `{}`",
+                std::any::type_name::<G>()
+            ))
+        })?;
+        Ok(Self { srid, geometry })
+    }
+}
+
+impl<G> From<PgGeometry<G>> for Value
+where
+    G: Into<Geometry> + TryFrom<Geometry>,
+{
+    fn from(geometry: PgGeometry<G>) -> Self {
+        Value::String(Some(Box::new(geometry.into_text())))
+    }
+}
+
+impl<G> TryGetable for PgGeometry<G>
+where
+    G: Into<Geometry> + TryFrom<Geometry>,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, idx)?;
+        Self::from_text(&text).map_err(TryGetError::DbErr)
+    }
+}
+
+impl<G> ValueType for PgGeometry<G>
+where
+    G: Into<Geometry> + TryFrom<Geometry>,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => Self::from_text(&text).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("PgGeometry<{}>", std::any::type_name::<G>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(SeaRc::new(Alias::new("geometry")))
+    }
+}
+
+impl<G> Nullable for PgGeometry<G>
+where
+    G: Into<Geometry> + TryFrom<Geometry>,
+{
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+fn write_geometry(geometry: &Geometry, out: &mut String) {
+    match geometry {
+        Geometry::Point(point) => {
+            out.push_str("POINT");
+            write_coords([point.0], out);
+        }
+        Geometry::Line(line) => {
+            out.push_str("LINESTRING");
+            write_coords([line.start, line.end], out);
+        }
+        Geometry::LineString(line_string) => {
+            out.push_str("LINESTRING");
+            write_coords(line_string.coords().copied(), out);
+        }
+        Geometry::Polygon(polygon) => {
+            out.push_str("POLYGON");
+            write_polygon(polygon, out);
+        }
+        Geometry::MultiPoint(multi_point) => {
+            out.push_str("MULTIPOINT");
+            write_list(&multi_point.0, out, |point, out| {
+                write_coords([point.0], out)
+            });
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            out.push_str("MULTILINESTRING");
+            write_list(&multi_line_string.0, out, |line_string, out| {
+                write_coords(line_string.coords().copied(), out)
+            });
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            out.push_str("MULTIPOLYGON");
+            write_list(&multi_polygon.0, out, write_polygon);
+        }
+        Geometry::GeometryCollection(collection) => {
+            out.push_str("GEOMETRYCOLLECTION");
+            write_list(&collection.0, out, write_geometry);
+        }
+        Geometry::Rect(rect) => {
+            out.push_str("POLYGON");
+            write_polygon(&rect.to_polygon(), out);
+        }
+        Geometry::Triangle(triangle) => {
+            out.push_str("POLYGON");
+            write_polygon(&triangle.to_polygon(), out);
+        }
+    }
+}
+
+fn write_polygon(polygon: &Polygon, out: &mut String) {
+    let rings: Vec<_> = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .filter(|ring| !ring.0.is_empty())
+        .collect();
+    write_list(&rings, out, |ring, out| {
+        write_coords(ring.coords().copied(), out)
+    });
+}
+
+fn write_coords<I>(coords: I, out: &mut String)
+where
+    I: IntoIterator<Item = Coord>,
+{
+    let coords: Vec<_> = coords.into_iter().collect();
+    write_list(&coords, out, |coord, out| {
+        write!(out, "{} {}", coord.x, coord.y).ok();
+    });
+}
+
+/// Write the items in parentheses, or `EMPTY` if there is none
+fn write_list<T, F>(items: &[T], out: &mut String, write_item: F)
+where
+    F: Fn(&T, &mut String),
+{
+    if items.is_empty() {
+        out.push_str(" EMPTY");
+        return;
+    }
+    out.push('(');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_item(item, out);
+    }
+    out.push(')');
+}
+
+/// Reads (E)WKB, see <https://libgeos.org/specifications/wkb/>
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl WkbReader<'_> {
+    const SRID_FLAG: u32 = 0x2000_0000;
+    const M_FLAG: u32 = 0x4000_0000;
+    const Z_FLAG: u32 = 0x8000_0000;
+
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.bytes.len() < N {
+            return None;
+        }
+        let (bytes, rest) = self.bytes.split_at(N);
+        self.bytes = rest;
+        bytes.try_into().ok()
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.take()?;
+        Some(match self.little_endian {
+            true => u32::from_le_bytes(bytes),
+            false => u32::from_be_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        let bytes = self.take()?;
+        Some(match self.little_endian {
+            true => f64::from_le_bytes(bytes),
+            false => f64::from_be_bytes(bytes),
+        })
+    }
+
+    /// Read a geometry and its SRID
+    fn geometry(&mut self) -> Option<(Geometry, Option<i32>)> {
+        let [byte_order] = self.take()?;
+        self.little_endian = byte_order == 1;
+        let type_id = self.u32()?;
+        let srid = match type_id & Self::SRID_FLAG {
+            0 => None,
+            _ => Some(self.u32()? as i32),
+        };
+        // the dimensions are either flags of EWKB or thousands of ISO WKB
+        let base = type_id & 0x0fff_ffff;
+        let mut dims = 2;
+        if type_id & Self::Z_FLAG != 0 {
+            dims += 1;
+        }
+        if type_id & Self::M_FLAG != 0 {
+            dims += 1;
+        }
+        dims += match base / 1000 {
+            1 | 2 => 1,
+            3 => 2,
+            _ => 0,
+        };
+
+        let geometry = match base % 1000 {
+            1 => Geometry::Point(Point(self.coord(dims)?)),
+            2 => Geometry::LineString(self.line_string(dims)?),
+            3 => Geometry::Polygon(self.polygon(dims)?),
+            4 => Geometry::MultiPoint(MultiPoint(self.list(
+                |reader| match reader.geometry()?.0 {
+                    Geometry::Point(point) => Some(point),
+                    _ => None,
+                },
+            )?)),
+            5 => Geometry::MultiLineString(MultiLineString(self.list(|reader| {
+                match reader.geometry()?.0 {
+                    Geometry::LineString(line_string) => Some(line_string),
+                    _ => None,
+                }
+            })?)),
+            6 => Geometry::MultiPolygon(MultiPolygon(self.list(
+                |reader| match reader.geometry()?.0 {
+                    Geometry::Polygon(polygon) => Some(polygon),
+                    _ => None,
+                },
+            )?)),
+            7 => Geometry::GeometryCollection(GeometryCollection(
+                self.list(|reader| Some(reader.geometry()?.0))?,
+            )),
+            _ => return None,
+        };
+        Some((geometry, srid))
+    }
+
+    fn list<T, F>(&mut self, mut read_item: F) -> Option<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> Option<T>,
+    {
+        let len = self.u32()?;
+        // the byte order of the items may differ from the one of the list
+        let little_endian = self.little_endian;
+        let items = (0..len).map(|_| read_item(self)).collect();
+        self.little_endian = little_endian;
+        items
+    }
+
+    fn coord(&mut self, dims: usize) -> Option<Coord> {
+        let coord = Coord {
+            x: self.f64()?,
+            y: self.f64()?,
+        };
+        for _ in 2..dims {
+            self.f64()?;
+        }
+        Some(coord)
+    }
+
+    fn line_string(&mut self, dims: usize) -> Option<LineString> {
+        self.list(|reader| reader.coord(dims)).map(LineString)
+    }
+
+    fn polygon(&mut self, dims: usize) -> Option<Polygon> {
+        let mut rings = self.list(|reader| reader.line_string(dims))?.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(Vec::new()));
+        Some(Polygon::new(exterior, rings.collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{line_string, point, polygon};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn geometry_text() {
+        assert_eq!(
+            PgGeometry::with_srid(4326, point!(x: 1.5, y: -2.0)).into_text(),
+            "SRID=4326;POINT(1.5 -2)"
+        );
+        assert_eq!(
+            PgGeometry::new(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]).into_text(),
+            "POLYGON((0 0,1 0,1 1,0 0))"
+        );
+        assert_eq!(
+            PgGeometry::new(Geometry::from(MultiPoint::<f64>(Vec::new()))).into_text(),
+            "MULTIPOINT EMPTY"
+        );
+    }
+
+    #[test]
+    fn geometry_from_ewkb() -> Result<(), DbErr> {
+        // SELECT CAST(ST_GeomFromEWKT('SRID=4326;POINT(1.5 -2)') AS text)
+        assert_eq!(
+            PgGeometry::<Point>::from_text("0101000020E6100000000000000000F83F00000000000000C0")?,
+            PgGeometry::with_srid(4326, point!(x: 1.5, y: -2.0))
+        );
+        // SELECT CAST(ST_GeomFromText('LINESTRING Z (1 2 3,4 5 6)') AS text)
+        assert_eq!(
+            PgGeometry::<Geometry>::from_text(
+                "010200008002000000000000000000F03F00000000000000400000000000000840000000000000104000000000000014400000000000001840"
+            )?,
+            PgGeometry::new(Geometry::LineString(line_string![(x: 1., y: 2.), (x: 4., y: 5.)]))
+        );
+        // SELECT CAST(ST_GeomFromText('MULTIPOINT((1 2),(3 4))') AS text)
+        assert_eq!(
+            PgGeometry::<MultiPoint>::from_text(
+                "0104000000020000000101000000000000000000F03F0000000000000040010100000000000000000008400000000000001040"
+            )?,
+            PgGeometry::new(MultiPoint(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]))
+        );
+        assert!(matches!(
+            PgGeometry::<Polygon>::from_text("0101000020E6100000000000000000F83F00000000000000C0"),
+            Err(DbErr::Type(_))
+        ));
+        assert!(PgGeometry::<Geometry>::from_text("01010000").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use std::ops::Deref;
+
+const MICROS_PER_SECOND: i128 = 1_000_000;
+
+/// A span of time stored in a Postgres `interval`, or in a MySQL `time`, as a [std::time::Duration]
+/// or a [chrono::Duration].
+///
+/// It is bound as text like `-25:30:00.5`, which is cast into an `interval` on Postgres.
+/// An interval with months can't be read, as the length of a month varies.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, Interval};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "job")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub timeout: Interval<std::time::Duration>,
+///     pub elapsed: Option<Interval<chrono::Duration>>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<D = std::time::Duration>(pub D);
+
+/// The duration of an [Interval]
+pub trait IntervalDuration: Copy {
+    /// The number of microseconds of the duration
+    fn to_micros(self) -> i128;
+
+    /// Create a duration from a number of microseconds
+    fn from_micros(micros: i128) -> Result<Self, DbErr>;
+}
+
+impl IntervalDuration for std::time::Duration {
+    fn to_micros(self) -> i128 {
+        self.as_micros() as i128
+    }
+
+    fn from_micros(micros: i128) -> Result<Self, DbErr> {
+        let micros = <u64 as TryFrom<i128>>::try_from(micros).map_err(|_| {
+            DbErr::Type(format!(
+                "Interval of {micros} microseconds is out of range of `std::time::Duration`"
+            ))
+        })?;
+        Ok(Self::from_micros(micros))
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl IntervalDuration for chrono::Duration {
+    fn to_micros(self) -> i128 {
+        let micros = self.num_seconds() as i128 * MICROS_PER_SECOND;
+        micros + (self.subsec_nanos() / 1_000) as i128
+    }
+
+    fn from_micros(micros: i128) -> Result<Self, DbErr> {
+        <i64 as TryFrom<i128>>::try_from(micros)
+            .map(Self::microseconds)
+            .map_err(|_| {
+                DbErr::Type(format!(
+                    "Interval of {micros} microseconds is out of range of `chrono::Duration`"
+                ))
+            })
+    }
+}
+
+impl<D> Interval<D>
+where
+    D: IntervalDuration,
+{
+    /// Convert into text, like `-25:30:00.5`
+    pub fn into_text(self) -> String {
+        let micros = self.0.to_micros();
+        let sign = if micros < 0 { "-" } else { "" };
+        let micros = micros.unsigned_abs();
+        let seconds = micros / MICROS_PER_SECOND as u128;
+        let mut text = format!(
+            "{sign}{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        );
+        let fraction = micros % MICROS_PER_SECOND as u128;
+        if fraction != 0 {
+            text.push_str(format!(".{fraction:06}").trim_end_matches('0'));
+        }
+        text
+    }
+
+    /// Parse text like `-25:30:00.5`
+    pub fn from_text(text: &str) -> Result<Self, DbErr> {
+        let invalid = || DbErr::Type(format!("Invalid interval `{text}`"));
+        let (negative, rest) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (time, fraction) = match rest.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (rest, None),
+        };
+        let mut parts = time.split(':');
+        let mut micros: i128 = 0;
+        for scale in [3600, 60, 1] {
+            let part: u32 = parts
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(invalid)?;
+            micros += part as i128 * scale * MICROS_PER_SECOND;
+        }
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        if let Some(fraction) = fraction {
+            if fraction.is_empty()
+                || fraction.len() > 6
+                || !fraction.bytes().all(|b| b.is_ascii_digit())
+            {
+                return Err(invalid());
+            }
+            let digits: i128 = fraction.parse().map_err(|_| invalid())?;
+            micros += digits * 10i128.pow(6 - fraction.len() as u32);
+        }
+        D::from_micros(if negative { -micros } else { micros }).map(Self)
+    }
+
+    /// Decode the binary or text value of a MySQL `time`
+    #[cfg(feature = "sqlx-mysql")]
+    fn from_mysql_time(bytes: &[u8]) -> Result<Self, DbErr> {
+        let le_u32 = |b: &[u8]| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as i128;
+        match bytes.len() {
+            0 => D::from_micros(0).map(Self),
+            // the binary format: sign, days, hours, minutes, seconds and optional microseconds
+            8 | 12 if bytes[0] <= 1 => {
+                let seconds = le_u32(&bytes[1..5]) * 86_400
+                    + bytes[5] as i128 * 3600
+                    + bytes[6] as i128 * 60
+                    + bytes[7] as i128;
+                let mut micros = seconds * MICROS_PER_SECOND;
+                if bytes.len() == 12 {
+                    micros += le_u32(&bytes[8..12]);
+                }
+                D::from_micros(if bytes[0] == 1 { -micros } else { micros }).map(Self)
+            }
+            _ => {
+                Self::from_text(std::str::from_utf8(bytes).map_err(|e| DbErr::Type(e.to_string()))?)
+            }
+        }
+    }
+
+    /// Convert a Postgres `interval` without months
+    #[cfg(feature = "sqlx-postgres")]
+    fn from_pg_interval(interval: sqlx::postgres::types::PgInterval) -> Result<Self, DbErr> {
+        if interval.months != 0 {
+            return Err(DbErr::Type(format!(
+                "Interval of {} months can't be converted into a duration",
+                interval.months
+            )));
+        }
+        let micros =
+            interval.days as i128 * 86_400 * MICROS_PER_SECOND + interval.microseconds as i128;
+        D::from_micros(micros).map(Self)
+    }
+}
+
+impl<D> Deref for Interval<D> {
+    type Target = D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<D> From<D> for Interval<D>
+where
+    D: IntervalDuration,
+{
+    fn from(duration: D) -> Self {
+        Self(duration)
+    }
+}
+
+impl<D> From<Interval<D>> for Value
+where
+    D: IntervalDuration,
+{
+    fn from(interval: Interval<D>) -> Self {
+        Value::String(Some(Box::new(interval.into_text())))
+    }
+}
+
+impl<D> TryGetable for Interval<D>
+where
+    D: IntervalDuration + 'static,
+{
+    #[allow(unused_variables)]
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let null = || TryGetError::Null(format!("{idx:?}"));
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            crate::QueryResultRow::SqlxMySql(row) => {
+                use sqlx::Row;
+                // the type check is skipped to read the raw value of the `time`
+                row.try_get_unchecked::<Option<&[u8]>, _>(idx.as_sqlx_mysql_index())
+                    .map_err(|e| crate::sqlx_error_to_query_err(e).into())
+                    .and_then(|opt| opt.ok_or_else(null))
+                    .and_then(|bytes| Self::from_mysql_time(bytes).map_err(TryGetError::DbErr))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            crate::QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::{postgres::types::PgInterval, Row};
+                row.try_get::<Option<PgInterval>, _>(idx.as_sqlx_postgres_index())
+                    .map_err(|e| crate::sqlx_error_to_query_err(e).into())
+                    .and_then(|opt| opt.ok_or_else(null))
+                    .and_then(|v| Self::from_pg_interval(v).map_err(TryGetError::DbErr))
+            }
+            #[allow(unreachable_patterns)]
+            _ => {
+                let text = String::try_get_by(res, idx)?;
+                Self::from_text(&text).map_err(TryGetError::DbErr)
+            }
+        }
+    }
+}
+
+impl<D> ValueType for Interval<D>
+where
+    D: IntervalDuration,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => Self::from_text(&text).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("Interval<{}>", std::any::type_name::<D>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Interval(None, None)
+    }
+}
+
+impl<D> Nullable for Interval<D>
+where
+    D: IntervalDuration,
+{
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn interval_text() -> Result<(), DbErr> {
+        let interval = Interval(Duration::from_micros(91_800_500_000));
+        assert_eq!(interval.into_text(), "25:30:00.5");
+        assert_eq!(Interval::from_text("25:30:00.5")?, interval);
+        assert_eq!(Interval(Duration::ZERO).into_text(), "0:00:00");
+        assert_eq!(
+            Interval::<Duration>::from_text("00:00:01.000002")?,
+            Interval(Duration::from_micros(1_000_002))
+        );
+        assert!(Interval::<Duration>::from_text("-00:00:01").is_err());
+        assert!(Interval::<Duration>::from_text("1:00").is_err());
+        assert!(Interval::<Duration>::from_text("1:00:00.1234567").is_err());
+
+        #[cfg(feature = "with-chrono")]
+        {
+            let interval = Interval(-chrono::Duration::milliseconds(1_500));
+            assert_eq!(interval.into_text(), "-0:00:01.5");
+            assert_eq!(Interval::from_text("-0:00:01.5")?, interval);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "sqlx-mysql", feature = "with-chrono"))]
+    fn interval_from_mysql_time() -> Result<(), DbErr> {
+        // -1 day 02:03:04.000005
+        let bytes = [1, 1, 0, 0, 0, 2, 3, 4, 5, 0, 0, 0];
+        assert_eq!(
+            Interval::<chrono::Duration>::from_mysql_time(&bytes)?,
+            Interval::from_text("-26:03:04.000005")?
+        );
+        assert_eq!(
+            Interval::<Duration>::from_mysql_time(b"838:59:59")?,
+            Interval(Duration::from_secs(3_020_399))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+/// A segment of a path into a JSON document, see [`crate::ColumnTrait::json_get`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    /// The value of an object at a key
+    Key(String),
+    /// The element of an array at an index
+    Index(u32),
+}
+
+/// A path into a JSON document, either a string like `"items[0].name"` or a slice of [`JsonPathSegment`]
+///
+/// In a string, the keys are separated by dots and an array index is written in brackets after
+/// its key, so a key made of digits is still a key. A key containing a dot or brackets can only be
+/// addressed by segments.
+///
+/// ```
+/// use sea_orm::{IntoJsonPath, JsonPathSegment};
+///
+/// assert_eq!(
+///     "items[0].name".into_json_path(),
+///     [
+///         JsonPathSegment::Key("items".to_owned()),
+///         JsonPathSegment::Index(0),
+///         JsonPathSegment::Key("name".to_owned()),
+///     ]
+/// );
+/// assert_eq!(
+///     "stats.2024".into_json_path(),
+///     [
+///         JsonPathSegment::Key("stats".to_owned()),
+///         JsonPathSegment::Key("2024".to_owned()),
+///     ]
+/// );
+/// ```
+pub trait IntoJsonPath {
+    /// The segments of the path
+    fn into_json_path(self) -> Vec<JsonPathSegment>;
+}
+
+impl IntoJsonPath for &str {
+    fn into_json_path(self) -> Vec<JsonPathSegment> {
+        let mut segments = Vec::new();
+        for part in self.split('.').filter(|s| !s.is_empty()) {
+            match split_indices(part) {
+                Some((key, indices)) => {
+                    if !key.is_empty() {
+                        segments.push(JsonPathSegment::Key(key.to_owned()));
+                    }
+                    segments.extend(indices.into_iter().map(JsonPathSegment::Index));
+                }
+                None => segments.push(JsonPathSegment::Key(part.to_owned())),
+            }
+        }
+        segments
+    }
+}
+
+impl IntoJsonPath for &String {
+    fn into_json_path(self) -> Vec<JsonPathSegment> {
+        self.as_str().into_json_path()
+    }
+}
+
+impl IntoJsonPath for String {
+    fn into_json_path(self) -> Vec<JsonPathSegment> {
+        self.as_str().into_json_path()
+    }
+}
+
+impl IntoJsonPath for &[JsonPathSegment] {
+    fn into_json_path(self) -> Vec<JsonPathSegment> {
+        self.to_vec()
+    }
+}
+
+impl<const N: usize> IntoJsonPath for [JsonPathSegment; N] {
+    fn into_json_path(self) -> Vec<JsonPathSegment> {
+        self.into()
+    }
+}
+
+impl IntoJsonPath for Vec<JsonPathSegment> {
+    fn into_json_path(self) -> Vec<JsonPathSegment> {
+        self
+    }
+}
+
+/// Split `items[0][1]` into `items` and `[0, 1]`, if it ends with indices
+fn split_indices(part: &str) -> Option<(&str, Vec<u32>)> {
+    let mut indices = Vec::new();
+    let mut rest = part;
+    while let Some(head) = rest.strip_suffix(']') {
+        let (head, index) = head.rsplit_once('[')?;
+        indices.push(index.parse().ok()?);
+        rest = head;
+    }
+    if indices.is_empty() {
+        return None;
+    }
+    indices.reverse();
+    Some((rest, indices))
+}
+
+/// Render a path as a MySQL / SQLite JSON path, e.g. `$.items[0]."2024"`, quoting the keys
+/// which aren't identifiers
+pub(crate) fn json_path_string(segments: &[JsonPathSegment]) -> String {
+    segments.iter().fold("$".to_owned(), |mut acc, segment| {
+        match segment {
+            JsonPathSegment::Key(key) if is_identifier(key) => {
+                acc.push('.');
+                acc.push_str(key);
+            }
+            JsonPathSegment::Key(key) => {
+                acc.push('.');
+                acc.push_str(&quoted(key));
+            }
+            JsonPathSegment::Index(index) => acc.push_str(&format!("[{index}]")),
+        }
+        acc
+    })
+}
+
+/// Render a path as a Postgres `text[]` literal, e.g. `{items,0,"a,b"}`, quoting the keys
+/// which aren't identifiers
+pub(crate) fn json_path_array(segments: &[JsonPathSegment]) -> String {
+    let elements: Vec<String> = segments
+        .iter()
+        .map(|segment| match segment {
+            JsonPathSegment::Key(key) if is_identifier(key) => key.clone(),
+            JsonPathSegment::Key(key) => quoted(key),
+            JsonPathSegment::Index(index) => index.to_string(),
+        })
+        .collect();
+    format!("{{{}}}", elements.join(","))
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !key.eq_ignore_ascii_case("null")
+}
+
+/// Double quote a key, escaping `"` and `\` with a backslash
+fn quoted(key: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in key.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn json_path_parse() {
+        use JsonPathSegment::*;
+
+        assert_eq!(
+            "a.b[2][3].c".into_json_path(),
+            [
+                Key("a".to_owned()),
+                Key("b".to_owned()),
+                Index(2),
+                Index(3),
+                Key("c".to_owned())
+            ]
+        );
+        assert_eq!("[1]".into_json_path(), [Index(1)]);
+        assert_eq!(
+            "a[x].0".into_json_path(),
+            [Key("a[x]".to_owned()), Key("0".to_owned())]
+        );
+    }
+
+    #[test]
+    fn json_path_render() {
+        use JsonPathSegment::*;
+
+        let path = [
+            Key("items".to_owned()),
+            Key("a.b".to_owned()),
+            Index(0),
+            Key(r#"say "hi"\"#.to_owned()),
+            Key("7".to_owned()),
+        ];
+        assert_eq!(
+            json_path_string(&path),
+            r#"$.items."a.b"[0]."say \"hi\"\\"."7""#
+        );
+        assert_eq!(
+            json_path_array(&path),
+            r#"{items,"a.b",0,"say \"hi\"\\","7"}"#
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{error::json_err, DbErr};
+use serde::de::DeserializeOwned;
+use serde_json::Value as Json;
+
+/// A serde tagged enum stored in a JSON column, implemented by [`crate::FromJsonQueryResult`]
+/// for an enum with `#[serde(tag = "..")]`
+///
+/// A stored value with a missing or unknown tag fails to load with an error naming the tag.
+/// Filter rows by the tag with [`crate::ColumnTrait::json_tag_eq`].
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, FromJsonQueryResult, JsonTagged};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, FromJsonQueryResult)]
+/// #[serde(tag = "type", rename_all = "snake_case")]
+/// pub enum Payload {
+///     OrderPlaced { order_id: i32 },
+///     #[serde(rename = "refund")]
+///     OrderRefunded { order_id: i32, amount: i64 },
+/// }
+///
+/// assert_eq!(Payload::TAG, "type");
+/// assert_eq!(Payload::TAGS, ["order_placed", "refund"]);
+/// assert_eq!(Payload::OrderPlaced { order_id: 1 }.tag(), "order_placed");
+/// ```
+pub trait JsonTagged: Sized {
+    /// The key of the tag
+    const TAG: &'static str;
+
+    /// The tags of the variants
+    const TAGS: &'static [&'static str];
+
+    /// The tag of the variant
+    fn tag(&self) -> &'static str;
+}
+
+/// Deserialize a tagged enum, with an error naming a missing or unknown tag
+pub fn from_tagged_json<T>(json: Json) -> Result<T, DbErr>
+where
+    T: JsonTagged + DeserializeOwned,
+{
+    let type_name = std::any::type_name::<T>();
+    let tag = match json.get(T::TAG) {
+        Some(Json::String(tag)) => tag.clone(),
+        Some(tag) => {
+            return Err(json_err(format!(
+                "The `{}` tag of `{type_name}` is not a string: {tag}",
+                T::TAG
+            )))
+        }
+        None => {
+            return Err(json_err(format!(
+                "The JSON of `{type_name}` has no `{}` tag: {json}",
+                T::TAG
+            )))
+        }
+    };
+    serde_json::from_value(json).map_err(|e| {
+        if T::TAGS.contains(&tag.as_str()) {
+            json_err(format!("Invalid `{tag}` of `{type_name}`: {e}"))
+        } else {
+            json_err(format!(
+                "Unknown `{}` tag `{tag}` of `{type_name}`, expected one of {}",
+                T::TAG,
+                T::TAGS.join(", ")
+            ))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(tag = "kind")]
+    enum Shape {
+        Circle { radius: f64 },
+        Square { side: f64 },
+    }
+
+    impl JsonTagged for Shape {
+        const TAG: &'static str = "kind";
+        const TAGS: &'static [&'static str] = &["Circle", "Square"];
+
+        fn tag(&self) -> &'static str {
+            match self {
+                Self::Circle { .. } => "Circle",
+                Self::Square { .. } => "Square",
+            }
+        }
+    }
+
+    #[test]
+    fn from_tagged_json() {
+        assert_eq!(
+            super::from_tagged_json(json!({ "kind": "Circle", "radius": 1.5 })),
+            Ok(Shape::Circle { radius: 1.5 })
+        );
+
+        let err = |json| {
+            super::from_tagged_json::<Shape>(json)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(err(json!({ "kind": "Triangle" }))
+            .contains("Unknown `kind` tag `Triangle` of `sea_orm::entity::json_tagged::tests::Shape`, expected one of Circle, Square"));
+        assert!(err(json!({ "radius": 1.5 })).contains("has no `kind` tag"));
+        assert!(err(json!({ "kind": 1 })).contains("is not a string"));
+        assert!(err(json!({ "kind": "Square" })).contains("Invalid `Square`"));
+    }
+}
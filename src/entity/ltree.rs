@@ -0,0 +1,164 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{Alias, ArrayType, ColumnType, Nullable, SeaRc, Value, ValueType, ValueTypeErr};
+use std::{fmt, str::FromStr};
+
+/// A PostgreSQL `ltree`, the path of a node in a hierarchy, like `Top.Science.Astronomy`,
+/// to be used as the type of a column.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, PgLtree};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "category_tree")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     // The path is read as text, and the text parameter is cast into an ltree
+///     #[sea_orm(select_as = "text", save_as = "ltree")]
+///     pub path: PgLtree,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgLtree(String);
+
+impl PgLtree {
+    /// Create a path from its labels, which must be non-empty and can't contain a dot
+    pub fn from_labels<I, L>(labels: I) -> Result<Self, DbErr>
+    where
+        I: IntoIterator<Item = L>,
+        L: AsRef<str>,
+    {
+        let mut path = Self::default();
+        for label in labels {
+            path.push(label.as_ref())?;
+        }
+        Ok(path)
+    }
+
+    /// Append a label to the path
+    pub fn push(&mut self, label: &str) -> Result<(), DbErr> {
+        if label.is_empty() || label.contains('.') {
+            return Err(DbErr::Type(format!("Invalid ltree label `{label}`")));
+        }
+        if !self.0.is_empty() {
+            self.0.push('.');
+        }
+        self.0.push_str(label);
+        Ok(())
+    }
+
+    /// The path of a child of this node
+    pub fn child(&self, label: &str) -> Result<Self, DbErr> {
+        let mut path = self.clone();
+        path.push(label)?;
+        Ok(path)
+    }
+
+    /// The path of the parent of this node, or `None` if it is a root or empty
+    pub fn parent(&self) -> Option<Self> {
+        self.0
+            .rsplit_once('.')
+            .map(|(parent, _)| Self(parent.to_owned()))
+    }
+
+    /// The labels of the path
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.').filter(|label| !label.is_empty())
+    }
+
+    /// The number of labels of the path
+    pub fn depth(&self) -> usize {
+        self.labels().count()
+    }
+
+    /// The text of the path
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PgLtree {
+    type Err = DbErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+        Self::from_labels(s.split('.'))
+    }
+}
+
+impl fmt::Display for PgLtree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<PgLtree> for Value {
+    fn from(path: PgLtree) -> Self {
+        Value::String(Some(Box::new(path.0)))
+    }
+}
+
+impl TryGetable for PgLtree {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, idx)?;
+        text.parse().map_err(TryGetError::DbErr)
+    }
+}
+
+impl ValueType for PgLtree {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => text.parse().map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(PgLtree).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(SeaRc::new(Alias::new("ltree")))
+    }
+}
+
+impl Nullable for PgLtree {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ltree_path() -> Result<(), DbErr> {
+        let path: PgLtree = "Top.Science".parse()?;
+        let child = path.child("Astronomy")?;
+        assert_eq!(child.as_str(), "Top.Science.Astronomy");
+        assert_eq!(child.depth(), 3);
+        assert_eq!(child.parent(), Some(path.clone()));
+        assert_eq!(
+            child.labels().collect::<Vec<_>>(),
+            ["Top", "Science", "Astronomy"]
+        );
+        assert_eq!(PgLtree::from_labels(["Top"])?.parent(), None);
+        assert_eq!(PgLtree::default().depth(), 0);
+        assert!(path.child("a.b").is_err());
+        assert!("Top..Science".parse::<PgLtree>().is_err());
+        Ok(())
+    }
+}
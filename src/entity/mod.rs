@@ -100,23 +100,59 @@ mod active_enum;
 mod active_model;
 mod base_entity;
 mod column;
+mod composite;
+mod compressed;
+mod converter;
+mod duration;
+mod encrypted;
+#[cfg(feature = "with-geo")]
+mod geometry;
 mod identity;
+mod interval;
+#[cfg(feature = "with-json")]
+mod json_path;
+#[cfg(feature = "with-json")]
+mod json_tagged;
 mod link;
+mod ltree;
 mod model;
+#[cfg(feature = "with-bigdecimal")]
+mod numeric;
 mod partial_model;
 /// Re-export common types from the entity
 pub mod prelude;
 mod primary_key;
+mod range;
 mod relation;
+mod uuid_repr;
+mod vector;
 
 pub use active_enum::*;
 pub use active_model::*;
 pub use base_entity::*;
 pub use column::*;
+pub use composite::*;
+pub use compressed::*;
+pub use converter::*;
+pub use duration::*;
+pub use encrypted::*;
+#[cfg(feature = "with-geo")]
+pub use geometry::*;
 pub use identity::*;
+pub use interval::*;
+#[cfg(feature = "with-json")]
+pub use json_path::*;
+#[cfg(feature = "with-json")]
+pub use json_tagged::*;
 pub use link::*;
+pub use ltree::*;
 pub use model::*;
+#[cfg(feature = "with-bigdecimal")]
+pub use numeric::*;
 pub use partial_model::*;
 // pub use prelude::*;
 pub use primary_key::*;
+pub use range::*;
 pub use relation::*;
+pub use uuid_repr::*;
+pub use vector::*;
@@ -0,0 +1,123 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use bigdecimal::{num_bigint::BigInt, BigDecimal, ToPrimitive};
+
+/// An integer wider than a `bigint`, stored in a `NUMERIC(39, 0)` column as a [BigDecimal]
+///
+/// A `i128` or `u128` field of an entity is converted with it, as with
+/// `#[sea_orm(save_with = "NumericInteger::into_numeric", load_with = "NumericInteger::from_numeric")]`.
+/// Values compared with the column in filters are [BigDecimal]s. SQLite has no such column type;
+/// only Postgres and MySQL are supported.
+///
+/// ```rust
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "transfer")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub amount: u128,
+///     pub balance_change: Option<i128>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+///
+/// assert_eq!(
+///     Column::Amount.def().get_column_type(),
+///     &ColumnType::Decimal(Some((39, 0)))
+/// );
+/// ```
+pub trait NumericInteger: Sized {
+    /// The stored value
+    type Numeric;
+
+    /// Convert into the stored value
+    fn into_numeric(self) -> Self::Numeric;
+
+    /// Convert from the stored value, returning an error if it's not an integer in range
+    fn from_numeric(numeric: Self::Numeric) -> Result<Self, String>;
+}
+
+macro_rules! numeric_integer {
+    ($type: ty) => {
+        impl NumericInteger for $type {
+            type Numeric = BigDecimal;
+
+            fn into_numeric(self) -> BigDecimal {
+                BigDecimal::new(BigInt::from(self), 0)
+            }
+
+            fn from_numeric(numeric: BigDecimal) -> Result<Self, String> {
+                let out_of_range = || {
+                    format!(
+                        "Numeric `{numeric}` is not an integer in range of `{}`",
+                        stringify!($type)
+                    )
+                };
+                if !numeric.is_integer() {
+                    return Err(out_of_range());
+                }
+                let (int, _) = numeric.with_scale(0).into_bigint_and_exponent();
+                int.to_i128()
+                    .and_then(|n| <$type>::try_from(n).ok())
+                    .or_else(|| int.to_u128().and_then(|n| <$type>::try_from(n).ok()))
+                    .ok_or_else(out_of_range)
+            }
+        }
+
+        impl TryGetable for $type {
+            fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+                let numeric = BigDecimal::try_get_by(res, idx)?;
+                Self::from_numeric(numeric).map_err(|e| TryGetError::DbErr(DbErr::Type(e)))
+            }
+        }
+    };
+}
+
+numeric_integer!(i128);
+numeric_integer!(u128);
+
+impl<T> NumericInteger for Option<T>
+where
+    T: NumericInteger<Numeric = BigDecimal>,
+{
+    type Numeric = Option<BigDecimal>;
+
+    fn into_numeric(self) -> Option<BigDecimal> {
+        self.map(T::into_numeric)
+    }
+
+    fn from_numeric(numeric: Option<BigDecimal>) -> Result<Self, String> {
+        numeric.map(T::from_numeric).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::str::FromStr;
+
+    #[test]
+    fn numeric_integer() {
+        for n in [0, i128::MIN, i128::MAX, -1] {
+            assert_eq!(i128::from_numeric(n.into_numeric()), Ok(n));
+        }
+        assert_eq!(u128::from_numeric(u128::MAX.into_numeric()), Ok(u128::MAX));
+        assert_eq!(
+            u128::MAX.into_numeric().to_string(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(Some(7i128).into_numeric(), Some(BigDecimal::from(7)));
+        assert_eq!(Option::<u128>::from_numeric(None), Ok(None));
+
+        let numeric = BigDecimal::from_str("12.000").unwrap();
+        assert_eq!(u128::from_numeric(numeric), Ok(12));
+        assert!(u128::from_numeric(BigDecimal::from(-1)).is_err());
+        assert!(i128::from_numeric(u128::MAX.into_numeric()).is_err());
+        assert!(i128::from_numeric(BigDecimal::from_str("1.5").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{
+    quote_field, split_fields, value_to_text, ColIdx, CompositeField, DbErr, QueryResult,
+    TryGetError, TryGetable,
+};
+use sea_query::{Alias, ArrayType, ColumnType, Nullable, SeaRc, Value, ValueType, ValueTypeErr};
+use std::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// A PostgreSQL range, e.g. `int4range` or `tstzrange`, to be used as the type of a column.
+///
+/// It is converted to and from the text of the range, like `[1,10)`. An empty range is read
+/// as unbounded on both sides, like in SQLx.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, PgRange};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "booking")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     // The range is read as text, and the text parameter is cast into the range type
+///     #[sea_orm(select_as = "text", save_as = "int4range")]
+///     pub seats: PgRange<i32>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PgRange<T> {
+    /// The lower bound
+    pub start: Bound<T>,
+    /// The upper bound
+    pub end: Bound<T>,
+}
+
+/// The type of the bounds of a [PgRange]
+pub trait PgRangeElement: Into<Value> + CompositeField {
+    /// The name of the range type, e.g. `int4range`
+    fn range_type_name() -> &'static str;
+}
+
+macro_rules! pg_range_element {
+    ( $type: ty, $name: expr ) => {
+        impl PgRangeElement for $type {
+            fn range_type_name() -> &'static str {
+                $name
+            }
+        }
+    };
+}
+
+pg_range_element!(i32, "int4range");
+pg_range_element!(i64, "int8range");
+
+#[cfg(feature = "with-rust_decimal")]
+pg_range_element!(rust_decimal::Decimal, "numrange");
+
+#[cfg(feature = "with-bigdecimal")]
+pg_range_element!(bigdecimal::BigDecimal, "numrange");
+
+#[cfg(feature = "with-chrono")]
+pg_range_element!(chrono::NaiveDate, "daterange");
+
+#[cfg(feature = "with-chrono")]
+pg_range_element!(chrono::NaiveDateTime, "tsrange");
+
+#[cfg(feature = "with-chrono")]
+pg_range_element!(chrono::DateTime<chrono::FixedOffset>, "tstzrange");
+
+#[cfg(feature = "with-chrono")]
+pg_range_element!(chrono::DateTime<chrono::Utc>, "tstzrange");
+
+impl<T> PgRange<T> {
+    /// Create a range from its bounds
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T> PgRange<T>
+where
+    T: PgRangeElement,
+{
+    /// Convert into the text of the range
+    pub fn into_text(self) -> String {
+        let mut text = String::new();
+        let (open, start) = match self.start {
+            Bound::Included(v) => ('[', Some(v)),
+            Bound::Excluded(v) => ('(', Some(v)),
+            Bound::Unbounded => ('(', None),
+        };
+        let (close, end) = match self.end {
+            Bound::Included(v) => (']', Some(v)),
+            Bound::Excluded(v) => (')', Some(v)),
+            Bound::Unbounded => (')', None),
+        };
+        text.push(open);
+        if let Some(start) = start.and_then(|v| value_to_text(&v.into())) {
+            quote_field(&start, &mut text);
+        }
+        text.push(',');
+        if let Some(end) = end.and_then(|v| value_to_text(&v.into())) {
+            quote_field(&end, &mut text);
+        }
+        text.push(close);
+        text
+    }
+
+    /// Parse the text of a range
+    pub fn from_text(text: &str) -> Result<Self, DbErr> {
+        let invalid = || DbErr::Type(format!("Invalid range `{text}`"));
+        if text == "empty" {
+            return Ok(Self::new(Bound::Unbounded, Bound::Unbounded));
+        }
+        let mut chars = text.chars();
+        let (open, close) = (chars.next(), chars.next_back());
+        let [start, end]: [Option<String>; 2] = split_fields(chars.as_str())
+            .ok_or_else(invalid)?
+            .try_into()
+            .map_err(|_| invalid())?;
+        let bound = |value: Option<String>, inclusive: bool| -> Result<Bound<T>, DbErr> {
+            Ok(match value {
+                Some(value) if inclusive => Bound::Included(T::from_field(Some(value))?),
+                Some(value) => Bound::Excluded(T::from_field(Some(value))?),
+                None => Bound::Unbounded,
+            })
+        };
+        let start = match open {
+            Some('[') => bound(start, true)?,
+            Some('(') => bound(start, false)?,
+            _ => return Err(invalid()),
+        };
+        let end = match close {
+            Some(']') => bound(end, true)?,
+            Some(')') => bound(end, false)?,
+            _ => return Err(invalid()),
+        };
+        Ok(Self::new(start, end))
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        Self::new(start, end)
+    }
+}
+
+impl<T> From<Range<T>> for PgRange<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for PgRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> From<RangeFrom<T>> for PgRange<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl<T> From<RangeTo<T>> for PgRange<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for PgRange<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
+
+impl<T> From<PgRange<T>> for Value
+where
+    T: PgRangeElement,
+{
+    fn from(range: PgRange<T>) -> Self {
+        Value::String(Some(Box::new(range.into_text())))
+    }
+}
+
+impl<T> TryGetable for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, idx)?;
+        Self::from_text(&text).map_err(TryGetError::DbErr)
+    }
+}
+
+impl<T> ValueType for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => Self::from_text(&text).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("PgRange<{}>", std::any::type_name::<T>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(SeaRc::new(Alias::new(T::range_type_name())))
+    }
+}
+
+impl<T> Nullable for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn range_text() -> Result<(), DbErr> {
+        assert_eq!(PgRange::from(1..10).into_text(), "[1,10)");
+        assert_eq!(PgRange::from(..=5i64).into_text(), "(,5]");
+        assert_eq!(PgRange::<i32>::from_text("[1,10)")?, PgRange::from(1..10));
+        assert_eq!(PgRange::<i32>::from_text("(,5]")?, PgRange::from(..=5));
+        assert_eq!(
+            PgRange::<i32>::from_text("empty")?,
+            PgRange::new(Bound::Unbounded, Bound::Unbounded)
+        );
+        assert!(PgRange::<i32>::from_text("[1,2,3)").is_err());
+        assert!(PgRange::<i32>::from_text("{1,2}").is_err());
+
+        #[cfg(feature = "with-chrono")]
+        {
+            let start: chrono::DateTime<chrono::FixedOffset> = "2021-09-17T17:50:20+08:00"
+                .parse()
+                .map_err(|e: chrono::ParseError| DbErr::Type(e.to_string()))?;
+            assert_eq!(
+                PgRange::from(start..).into_text(),
+                "[2021-09-17T17:50:20+08:00,)"
+            );
+            assert_eq!(
+                PgRange::from_text(r#"["2021-09-17 09:50:20+00",)"#)?,
+                PgRange::from(start..)
+            );
+        }
+        Ok(())
+    }
+}
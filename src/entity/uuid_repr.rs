@@ -0,0 +1,130 @@
+#[cfg(feature = "with-uuid")]
+use crate::{DbErr, QueryResult};
+#[cfg(feature = "with-uuid")]
+use uuid::Uuid;
+
+/// How the `Uuid` field of a column with a `uuid_repr` is stored, in a `BINARY(16)` column
+/// (`bytea` on Postgres, `blob` on SQLite) instead of the text or native `uuid` column type.
+///
+/// The field stays a `Uuid`: the values bound to the column, in inserts, updates and filters,
+/// are converted into bytes, and the bytes read are converted back.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, UuidRepr};
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "device")]
+/// pub struct Model {
+///     #[sea_orm(primary_key, auto_increment = false, uuid_repr = "binary")]
+///     pub id: Uuid,
+///     #[sea_orm(uuid_repr = "binary_swapped")]
+///     pub session_id: Option<Uuid>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+///
+/// assert_eq!(
+///     Column::SessionId.def().get_uuid_repr(),
+///     Some(UuidRepr::BinarySwapped)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UuidRepr {
+    /// The 16 bytes of the UUID, in order
+    Binary,
+    /// The bytes of the UUID with its time-high and time-low fields swapped, as stored by
+    /// MySQL's `UUID_TO_BIN(uuid, 1)`, so that version 1 UUIDs are ordered by time in indexes
+    BinarySwapped,
+}
+
+#[cfg(feature = "with-uuid")]
+impl UuidRepr {
+    /// The stored bytes of a UUID
+    pub fn to_bytes(self, uuid: Uuid) -> [u8; 16] {
+        let b = uuid.into_bytes();
+        match self {
+            Self::Binary => b,
+            Self::BinarySwapped => [
+                b[6], b[7], b[4], b[5], b[0], b[1], b[2], b[3], b[8], b[9], b[10], b[11], b[12],
+                b[13], b[14], b[15],
+            ],
+        }
+    }
+
+    /// The UUID of the stored bytes
+    pub fn to_uuid(self, bytes: &[u8]) -> Result<Uuid, DbErr> {
+        let b: [u8; 16] = bytes
+            .try_into()
+            .map_err(|_| DbErr::Type(format!("A binary UUID has 16 bytes, not {}", bytes.len())))?;
+        Ok(Uuid::from_bytes(match self {
+            Self::Binary => b,
+            Self::BinarySwapped => [
+                b[4], b[5], b[6], b[7], b[2], b[3], b[0], b[1], b[8], b[9], b[10], b[11], b[12],
+                b[13], b[14], b[15],
+            ],
+        }))
+    }
+}
+
+/// Read the field of a column with a [UuidRepr] from a query result
+#[cfg(feature = "with-uuid")]
+#[doc(hidden)]
+pub trait FromUuidRepr: Sized {
+    /// Read the bytes of a column and convert them back
+    fn try_get_repr(res: &QueryResult, pre: &str, col: &str, repr: UuidRepr)
+        -> Result<Self, DbErr>;
+}
+
+#[cfg(feature = "with-uuid")]
+impl FromUuidRepr for Uuid {
+    fn try_get_repr(
+        res: &QueryResult,
+        pre: &str,
+        col: &str,
+        repr: UuidRepr,
+    ) -> Result<Self, DbErr> {
+        repr.to_uuid(&res.try_get::<Vec<u8>>(pre, col)?)
+    }
+}
+
+#[cfg(feature = "with-uuid")]
+impl FromUuidRepr for Option<Uuid> {
+    fn try_get_repr(
+        res: &QueryResult,
+        pre: &str,
+        col: &str,
+        repr: UuidRepr,
+    ) -> Result<Self, DbErr> {
+        res.try_get::<Option<Vec<u8>>>(pre, col)?
+            .map(|bytes| repr.to_uuid(&bytes))
+            .transpose()
+    }
+}
+
+#[cfg(all(test, feature = "with-uuid"))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn uuid_repr() -> Result<(), DbErr> {
+        // the example of `UUID_TO_BIN` in the MySQL reference manual
+        let uuid = Uuid::parse_str("6ccd780c-baba-1026-9564-5b8c656024db").unwrap();
+        let swapped = UuidRepr::BinarySwapped.to_bytes(uuid);
+        assert_eq!(
+            swapped,
+            *Uuid::parse_str("1026baba-6ccd-780c-9564-5b8c656024db")
+                .unwrap()
+                .as_bytes()
+        );
+        assert_eq!(UuidRepr::BinarySwapped.to_uuid(&swapped)?, uuid);
+
+        assert_eq!(UuidRepr::Binary.to_bytes(uuid), uuid.into_bytes());
+        assert_eq!(UuidRepr::Binary.to_uuid(uuid.as_bytes())?, uuid);
+        assert!(UuidRepr::Binary.to_uuid(&[0; 15]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{Alias, ArrayType, ColumnType, Nullable, SeaRc, Value, ValueType, ValueTypeErr};
+
+/// A pgvector `vector`, e.g. an embedding, to be used as the type of a column.
+///
+/// It is converted to and from the text of the vector, like `[1,2.5,3]`.
+///
+/// ```rust
+/// use sea_orm::{entity::prelude::*, PgVector};
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "document")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     // The vector is read as text, and the text parameter is cast into a vector
+///     #[sea_orm(select_as = "text", save_as = "vector")]
+///     pub embedding: PgVector,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// impl ActiveModelBehavior for ActiveModel {}
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PgVector(pub Vec<f32>);
+
+impl PgVector {
+    /// Convert into the text of the vector
+    pub fn into_text(self) -> String {
+        let elements: Vec<String> = self.0.iter().map(f32::to_string).collect();
+        format!("[{}]", elements.join(","))
+    }
+
+    /// Parse the text of a vector
+    pub fn from_text(text: &str) -> Result<Self, DbErr> {
+        let invalid = || DbErr::Type(format!("Invalid vector `{text}`"));
+        let inner = text
+            .strip_prefix('[')
+            .and_then(|text| text.strip_suffix(']'))
+            .ok_or_else(invalid)?;
+        if inner.is_empty() {
+            return Ok(Self(Vec::new()));
+        }
+        inner
+            .split(',')
+            .map(|element| element.trim().parse().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl From<Vec<f32>> for PgVector {
+    fn from(vec: Vec<f32>) -> Self {
+        Self(vec)
+    }
+}
+
+impl From<PgVector> for Vec<f32> {
+    fn from(vector: PgVector) -> Self {
+        vector.0
+    }
+}
+
+impl From<PgVector> for Value {
+    fn from(vector: PgVector) -> Self {
+        Value::String(Some(Box::new(vector.into_text())))
+    }
+}
+
+impl TryGetable for PgVector {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, idx)?;
+        Self::from_text(&text).map_err(TryGetError::DbErr)
+    }
+}
+
+impl ValueType for PgVector {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => Self::from_text(&text).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(PgVector).to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(SeaRc::new(Alias::new("vector")))
+    }
+}
+
+impl Nullable for PgVector {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn vector_text() -> Result<(), DbErr> {
+        assert_eq!(
+            PgVector(vec![1.0, -2.5, 0.125]).into_text(),
+            "[1,-2.5,0.125]"
+        );
+        assert_eq!(PgVector::default().into_text(), "[]");
+        assert_eq!(
+            PgVector::from_text("[1,-2.5,1e-7]")?,
+            PgVector(vec![1.0, -2.5, 1e-7])
+        );
+        assert_eq!(PgVector::from_text("[]")?, PgVector::default());
+        assert!(PgVector::from_text("[1,x]").is_err());
+        assert!(PgVector::from_text("1,2").is_err());
+        Ok(())
+    }
+}
@@ -73,6 +73,12 @@ pub enum DbErr {
     /// May be the table is empty or the record does not exist
     #[error("None of the records are updated")]
     RecordNotUpdated,
+    /// The statement did not finish before its timeout, see [`crate::Statement::timeout`]
+    #[error("Query timed out after {0:?}")]
+    QueryTimeout(std::time::Duration),
+    /// The token of the statement has been cancelled, see [`crate::Statement::cancel_on`]
+    #[error("Query cancelled")]
+    QueryCancelled,
 }
 
 /// Connection Acquire error
@@ -161,6 +167,12 @@ pub enum SqlErr {
     /// Error for Foreign key constraint
     #[error("Foreign Key Constraint Violated: {0}")]
     ForeignKeyConstraintViolation(String),
+    /// Error for a transaction that could not be serialized with concurrent transactions
+    #[error("Serialization Failure: {0}")]
+    SerializationFailure(String),
+    /// Error for a transaction that was chosen as the victim of a deadlock
+    #[error("Deadlock Detected: {0}")]
+    Deadlock(String),
 }
 
 #[allow(dead_code)]
@@ -204,6 +216,8 @@ impl DbErr {
                         1216 | 1217 | 1451 | 1452 | 1557 | 1761 | 1762 => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        // 1213 Deadlock found when trying to get lock; try restarting transaction
+                        1213 => return Some(SqlErr::Deadlock(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -218,6 +232,8 @@ impl DbErr {
                         "23503" => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        "40001" => return Some(SqlErr::SerializationFailure(e.message().into())),
+                        "40P01" => return Some(SqlErr::Deadlock(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -232,6 +248,9 @@ impl DbErr {
                         "787" => {
                             return Some(SqlErr::ForeignKeyConstraintViolation(e.message().into()))
                         }
+                        // error code 517 refers to a read transaction that can not be upgraded
+                        // to a write transaction, because its snapshot is out of date
+                        "517" => return Some(SqlErr::SerializationFailure(e.message().into())),
                         _ => return None,
                     }
                 }
@@ -0,0 +1,291 @@
+use crate::{
+    error::*, ColumnTrait, ColumnType, ConnectionTrait, DbBackend, EntityTrait, QuerySelect,
+    Select, TryGetable,
+};
+use sea_query::{Alias, Expr, Func, SimpleExpr};
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Get the `SUM` of a column over the rows matched by the query.
+    ///
+    /// The sum of an integer column is returned as `BIGINT` on every backend, so it can be read as `i64`
+    /// (or `u64` for `BIGINT UNSIGNED` columns on MySQL). The sum of a decimal column is read as `Decimal`.
+    /// `None` is returned if no row matched.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::MySql)
+    /// #     .append_query_results([[maplit::btreemap! { "value" => Into::<Value>::into(6i64) }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let sum: Option<i64> = fruit::Entity::find()
+    ///     .filter(fruit::Column::Name.contains("berry"))
+    ///     .sum(fruit::Column::Id, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(sum, Some(6));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::MySql,
+    ///         r#"SELECT CAST(SUM(`fruit`.`id`) AS SIGNED) AS `value` FROM `fruit` WHERE `fruit`.`name` LIKE ? LIMIT ?"#,
+    ///         ["%berry%".into(), 1u64.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sum<T, C>(self, col: E::Column, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        C: ConnectionTrait,
+    {
+        let backend = db.get_database_backend();
+        let col_type = col.def().get_column_type().clone();
+        let expr = col.sum();
+        let expr = match (backend, is_integer(&col_type)) {
+            (DbBackend::Postgres, true) => Expr::expr(expr).cast_as(Alias::new("bigint")),
+            (DbBackend::MySql, true) => match col_type {
+                ColumnType::BigUnsigned => Expr::expr(expr).cast_as(Alias::new("UNSIGNED")),
+                _ => Expr::expr(expr).cast_as(Alias::new("SIGNED")),
+            },
+            _ => expr,
+        };
+        self.aggregate(expr, db).await
+    }
+
+    /// Get the `AVG` of a column over the rows matched by the query.
+    ///
+    /// The average of an integer or floating point column is returned as a double precision float on every backend,
+    /// so it can be read as `f64`. The average of a decimal column is read as `Decimal`.
+    /// `None` is returned if no row matched.
+    pub async fn avg<T, C>(self, col: E::Column, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        C: ConnectionTrait,
+    {
+        let backend = db.get_database_backend();
+        let col_type = col.def().get_column_type().clone();
+        let expr: SimpleExpr = Func::avg(Expr::col((col.entity_name(), col))).into();
+        let expr = match (backend, is_integer(&col_type)) {
+            (DbBackend::Postgres, true) => Expr::expr(expr).cast_as(Alias::new("double precision")),
+            (DbBackend::MySql, true) => Expr::expr(expr).cast_as(Alias::new("DOUBLE")),
+            _ => expr,
+        };
+        self.aggregate(expr, db).await
+    }
+
+    /// Get the `MIN` of a column over the rows matched by the query, read as the column's own type.
+    /// `None` is returned if no row matched.
+    pub async fn min<T, C>(self, col: E::Column, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        C: ConnectionTrait,
+    {
+        self.aggregate(col.min(), db).await
+    }
+
+    /// Get the `MAX` of a column over the rows matched by the query, read as the column's own type.
+    /// `None` is returned if no row matched.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! { "value" => Into::<Value>::into("Strawberry") }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let max: Option<String> = fruit::Entity::find()
+    ///     .max(fruit::Column::Name, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(max, Some("Strawberry".to_owned()));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT MAX("fruit"."name") AS "value" FROM "fruit" LIMIT $1"#,
+    ///         [1u64.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn max<T, C>(self, col: E::Column, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        C: ConnectionTrait,
+    {
+        self.aggregate(col.max(), db).await
+    }
+
+    /// Count the distinct non-null values of a column over the rows matched by the query.
+    pub async fn count_distinct<C>(self, col: E::Column, db: &C) -> Result<i64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let expr = Func::count_distinct(Expr::col((col.entity_name(), col))).into();
+        let count: Option<i64> = self.aggregate(expr, db).await?;
+        Ok(count.unwrap_or_default())
+    }
+
+    async fn aggregate<T, C>(mut self, expr: SimpleExpr, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        C: ConnectionTrait,
+    {
+        self.query.clear_order_by();
+        let value: Option<Option<T>> = self
+            .select_only()
+            .expr_as(expr, "value")
+            .into_tuple()
+            .one(db)
+            .await?;
+        Ok(value.flatten())
+    }
+}
+
+fn is_integer(col_type: &ColumnType) -> bool {
+    matches!(
+        col_type,
+        ColumnType::TinyInteger
+            | ColumnType::SmallInteger
+            | ColumnType::Integer
+            | ColumnType::BigInteger
+            | ColumnType::TinyUnsigned
+            | ColumnType::SmallUnsigned
+            | ColumnType::Unsigned
+            | ColumnType::BigUnsigned
+    )
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::tests_cfg::fruit;
+    use crate::{entity::*, error::*, query::*, DbBackend, MockDatabase, Transaction};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn sum_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "value" => Into::<Value>::into(Option::<i64>::None),
+            }]])
+            .into_connection();
+
+        let sum: Option<i64> = fruit::Entity::find()
+            .filter(fruit::Column::CakeId.eq(3))
+            .order_by_asc(fruit::Column::Name)
+            .sum(fruit::Column::Id, &db)
+            .await?;
+
+        assert_eq!(sum, None);
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT CAST(SUM("fruit"."id") AS bigint) AS "value" FROM "fruit" WHERE "fruit"."cake_id" = $1 LIMIT $2"#,
+                [3i32.into(), 1u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn avg_per_backend() -> Result<(), DbErr> {
+        for (backend, sql) in [
+            (
+                DbBackend::Postgres,
+                r#"SELECT CAST(AVG("fruit"."id") AS double precision) AS "value" FROM "fruit" LIMIT $1"#,
+            ),
+            (
+                DbBackend::MySql,
+                r#"SELECT CAST(AVG(`fruit`.`id`) AS DOUBLE) AS `value` FROM `fruit` LIMIT ?"#,
+            ),
+            (
+                DbBackend::Sqlite,
+                r#"SELECT AVG("fruit"."id") AS "value" FROM "fruit" LIMIT ?"#,
+            ),
+        ] {
+            let db = MockDatabase::new(backend)
+                .append_query_results([[maplit::btreemap! {
+                    "value" => Into::<Value>::into(1.5f64),
+                }]])
+                .into_connection();
+
+            let avg: Option<f64> = fruit::Entity::find().avg(fruit::Column::Id, &db).await?;
+
+            assert_eq!(avg, Some(1.5));
+            assert_eq!(
+                db.into_transaction_log(),
+                [Transaction::from_sql_and_values(
+                    backend,
+                    sql,
+                    [1u64.into()]
+                )]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn min_and_count_distinct() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [maplit::btreemap! {
+                    "value" => Into::<Value>::into(1i32),
+                }],
+                [maplit::btreemap! {
+                    "value" => Into::<Value>::into(2i64),
+                }],
+            ])
+            .into_connection();
+
+        let min: Option<i32> = fruit::Entity::find().min(fruit::Column::Id, &db).await?;
+        let count = fruit::Entity::find()
+            .count_distinct(fruit::Column::CakeId, &db)
+            .await?;
+
+        assert_eq!(min, Some(1));
+        assert_eq!(count, 2);
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT MIN("fruit"."id") AS "value" FROM "fruit" LIMIT $1"#,
+                    [1u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT COUNT(DISTINCT "fruit"."cake_id") AS "value" FROM "fruit" LIMIT $1"#,
+                    [1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,465 @@
+use crate::{
+    error::*, ColumnTrait, ColumnType, ConnectionTrait, EntityTrait, IdenStatic, Iterable,
+    ModelTrait, Select, StreamTrait,
+};
+use arrow_array::{builder::*, ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use async_stream::try_stream;
+use futures::{Stream, TryStreamExt};
+use sea_query::Value;
+use std::{pin::Pin, sync::Arc};
+
+/// A stream of Arrow record batches, see [`Select::stream_arrow`]
+pub type RecordBatchStream<'b> =
+    Pin<Box<dyn Stream<Item = Result<RecordBatch, DbErr>> + 'b + Send>>;
+
+/// The Arrow schema of an Entity, with a field for each column.
+///
+/// | Column type | Arrow type |
+/// |-------------|------------|
+/// | integers, floats, `Boolean` | the primitive type of the same width and sign |
+/// | `Char`, `String`, `Text`, `Enum`, `Json`, `Uuid` and other textual types | `Utf8` |
+/// | `Binary`, `VarBinary`, `Blob` | `Binary` |
+/// | `Decimal`, `Money` | `Decimal128`, with a precision of 38 and scale of 10 if unspecified |
+/// | `Date` | `Date32` |
+/// | `Time` | `Time64(Microsecond)` |
+/// | `DateTime`, `Timestamp` | `Timestamp(Microsecond)` |
+/// | `TimestampWithTimeZone` | `Timestamp(Microsecond, "UTC")` |
+///
+/// Other column types, such as arrays and intervals, are not supported.
+pub fn arrow_schema<E>() -> Result<Schema, DbErr>
+where
+    E: EntityTrait,
+{
+    let fields = E::Column::iter()
+        .map(|col| {
+            let def = col.def();
+            Ok(Field::new(
+                col.as_str(),
+                arrow_type(col.as_str(), def.get_column_type())?,
+                def.is_null(),
+            ))
+        })
+        .collect::<Result<Vec<_>, DbErr>>()?;
+    Ok(Schema::new(fields))
+}
+
+/// Convert Models into an Arrow record batch of the schema given by [`arrow_schema`]
+pub fn to_record_batch<E>(models: &[E::Model]) -> Result<RecordBatch, DbErr>
+where
+    E: EntityTrait,
+{
+    let schema = Arc::new(arrow_schema::<E>()?);
+    record_batch::<E>(&schema, models)
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Stream the Models of the query as Arrow record batches of up to `batch_size` rows,
+    /// e.g. to hand them over to Polars or DataFusion. See [`arrow_schema`] for the type mapping.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use futures::TryStreamExt;
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let mut batches = cake::Entity::find().stream_arrow(db, 8192).await?;
+    ///
+    /// while let Some(batch) = batches.try_next().await? {
+    ///     assert_eq!(batch.num_columns(), 2);
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_arrow<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        batch_size: usize,
+    ) -> Result<RecordBatchStream<'b>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        if batch_size == 0 {
+            return Err(DbErr::Custom("Batch size should not be zero".to_owned()));
+        }
+        let schema = Arc::new(arrow_schema::<E>()?);
+        let mut rows = self.stream(db).await?;
+        Ok(Box::pin(try_stream! {
+            let mut models = Vec::with_capacity(batch_size);
+            while let Some(model) = rows.try_next().await? {
+                models.push(model);
+                if models.len() == batch_size {
+                    yield record_batch::<E>(&schema, &models)?;
+                    models.clear();
+                }
+            }
+            if !models.is_empty() {
+                yield record_batch::<E>(&schema, &models)?;
+            }
+        }))
+    }
+
+    /// Write the Models of the query into a Parquet file, converting `batch_size` rows at a time,
+    /// see [`Select::stream_arrow`]. Returns the number of rows written.
+    #[cfg(feature = "with-parquet")]
+    pub async fn write_parquet<C, W>(
+        self,
+        db: &C,
+        writer: W,
+        batch_size: usize,
+    ) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+        W: std::io::Write + Send,
+    {
+        let parquet_err = |e: parquet::errors::ParquetError| DbErr::Custom(e.to_string());
+        let schema = Arc::new(arrow_schema::<E>()?);
+        let mut writer =
+            parquet::arrow::ArrowWriter::try_new(writer, schema, None).map_err(parquet_err)?;
+        let mut num_rows = 0;
+        let mut batches = self.stream_arrow(db, batch_size).await?;
+        while let Some(batch) = batches.try_next().await? {
+            num_rows += batch.num_rows() as u64;
+            writer.write(&batch).map_err(parquet_err)?;
+        }
+        writer.close().map_err(parquet_err)?;
+        Ok(num_rows)
+    }
+}
+
+fn arrow_err(e: ArrowError) -> DbErr {
+    DbErr::Custom(e.to_string())
+}
+
+fn arrow_type(col: &str, col_type: &ColumnType) -> Result<DataType, DbErr> {
+    Ok(match col_type {
+        ColumnType::Boolean => DataType::Boolean,
+        ColumnType::TinyInteger => DataType::Int8,
+        ColumnType::SmallInteger => DataType::Int16,
+        ColumnType::Integer => DataType::Int32,
+        ColumnType::BigInteger => DataType::Int64,
+        ColumnType::TinyUnsigned => DataType::UInt8,
+        ColumnType::SmallUnsigned => DataType::UInt16,
+        ColumnType::Unsigned => DataType::UInt32,
+        ColumnType::BigUnsigned => DataType::UInt64,
+        ColumnType::Float => DataType::Float32,
+        ColumnType::Double => DataType::Float64,
+        ColumnType::Char(_)
+        | ColumnType::String(_)
+        | ColumnType::Text
+        | ColumnType::Enum { .. }
+        | ColumnType::Custom(_)
+        | ColumnType::Json
+        | ColumnType::JsonBinary
+        | ColumnType::Uuid
+        | ColumnType::Cidr
+        | ColumnType::Inet
+        | ColumnType::MacAddr
+        | ColumnType::LTree => DataType::Utf8,
+        ColumnType::Binary(_) | ColumnType::VarBinary(_) | ColumnType::Blob => DataType::Binary,
+        ColumnType::Decimal(precision) | ColumnType::Money(precision) => {
+            let (precision, scale) = precision.unwrap_or((38, 10));
+            DataType::Decimal128(precision as u8, scale as i8)
+        }
+        ColumnType::Date => DataType::Date32,
+        ColumnType::Time => DataType::Time64(TimeUnit::Microsecond),
+        ColumnType::DateTime | ColumnType::Timestamp => {
+            DataType::Timestamp(TimeUnit::Microsecond, None)
+        }
+        ColumnType::TimestampWithTimeZone => {
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        }
+        col_type => {
+            return Err(type_err(format!(
+                "column `{col}` of type {col_type:?} can not be converted to Arrow"
+            )))
+        }
+    })
+}
+
+fn record_batch<E>(schema: &SchemaRef, models: &[E::Model]) -> Result<RecordBatch, DbErr>
+where
+    E: EntityTrait,
+{
+    let columns = E::Column::iter()
+        .zip(schema.fields().iter())
+        .map(|(col, field)| {
+            let values = models.iter().map(|model| model.get(col));
+            to_array(col.as_str(), field.data_type(), values)
+        })
+        .collect::<Result<Vec<_>, DbErr>>()?;
+    RecordBatch::try_new(schema.clone(), columns).map_err(arrow_err)
+}
+
+macro_rules! build_array {
+    ( $builder: expr, $values: expr, $convert: expr ) => {{
+        let mut builder = $builder;
+        for value in $values {
+            match $convert(value)? {
+                Some(v) => builder.append_value(v),
+                None => builder.append_null(),
+            }
+        }
+        Arc::new(builder.finish()) as ArrayRef
+    }};
+}
+
+fn to_array<I>(col: &str, data_type: &DataType, values: I) -> Result<ArrayRef, DbErr>
+where
+    I: Iterator<Item = Value>,
+{
+    let mismatch = |value: Value| {
+        type_err(format!(
+            "value {value:?} of column `{col}` can not be converted to Arrow {data_type}"
+        ))
+    };
+    let int = |value: Value| -> Result<Option<i128>, DbErr> {
+        Ok(match value {
+            Value::TinyInt(v) => v.map(Into::into),
+            Value::SmallInt(v) => v.map(Into::into),
+            Value::Int(v) => v.map(Into::into),
+            Value::BigInt(v) => v.map(Into::into),
+            Value::TinyUnsigned(v) => v.map(Into::into),
+            Value::SmallUnsigned(v) => v.map(Into::into),
+            Value::Unsigned(v) => v.map(Into::into),
+            Value::BigUnsigned(v) => v.map(Into::into),
+            value => return Err(mismatch(value)),
+        })
+    };
+    macro_rules! int_array {
+        ( $builder: ident, $ty: ty ) => {
+            build_array!(
+                $builder::new(),
+                values,
+                |value: Value| -> Result<Option<$ty>, DbErr> {
+                    int(value.clone())?
+                        .map(|i| <$ty>::try_from(i).map_err(|_| mismatch(value)))
+                        .transpose()
+                }
+            )
+        };
+    }
+    Ok(match data_type {
+        DataType::Boolean => build_array!(BooleanBuilder::new(), values, |value| match value {
+            Value::Bool(v) => Ok(v),
+            value => Err(mismatch(value)),
+        }),
+        DataType::Int8 => int_array!(Int8Builder, i8),
+        DataType::Int16 => int_array!(Int16Builder, i16),
+        DataType::Int32 => int_array!(Int32Builder, i32),
+        DataType::Int64 => int_array!(Int64Builder, i64),
+        DataType::UInt8 => int_array!(UInt8Builder, u8),
+        DataType::UInt16 => int_array!(UInt16Builder, u16),
+        DataType::UInt32 => int_array!(UInt32Builder, u32),
+        DataType::UInt64 => int_array!(UInt64Builder, u64),
+        DataType::Float32 => build_array!(Float32Builder::new(), values, |value| match value {
+            Value::Float(v) => Ok(v),
+            value => Err(mismatch(value)),
+        }),
+        DataType::Float64 => build_array!(Float64Builder::new(), values, |value| match value {
+            Value::Float(v) => Ok(v.map(Into::into)),
+            Value::Double(v) => Ok(v),
+            value => Err(mismatch(value)),
+        }),
+        DataType::Utf8 => build_array!(StringBuilder::new(), values, |value| match value {
+            Value::String(v) => Ok(v.map(|v| *v)),
+            Value::Char(v) => Ok(v.map(String::from)),
+            #[cfg(feature = "with-json")]
+            Value::Json(v) => Ok(v.map(|v| v.to_string())),
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(v) => Ok(v.map(|v| v.to_string())),
+            value => Err(mismatch(value)),
+        }),
+        DataType::Binary => build_array!(BinaryBuilder::new(), values, |value| match value {
+            Value::Bytes(v) => Ok(v.map(|v| *v)),
+            value => Err(mismatch(value)),
+        }),
+        DataType::Decimal128(precision, scale) => build_array!(
+            Decimal128Builder::new()
+                .with_precision_and_scale(*precision, *scale)
+                .map_err(arrow_err)?,
+            values,
+            |value| match value {
+                #[cfg(feature = "with-rust_decimal")]
+                Value::Decimal(v) => Ok(v.map(|v| {
+                    let mut v = *v;
+                    v.rescale(*scale as u32);
+                    v.mantissa()
+                })),
+                value => Err(mismatch(value)),
+            }
+        ),
+        DataType::Date32 => build_array!(Date32Builder::new(), values, |value| match value {
+            #[cfg(feature = "with-chrono")]
+            Value::ChronoDate(v) => Ok(v.map(|v| {
+                use chrono::Datelike;
+                v.num_days_from_ce() - 719_163
+            })),
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(v) => Ok(v.map(|v| v.to_julian_day() - 2_440_588)),
+            value => Err(mismatch(value)),
+        }),
+        DataType::Time64(TimeUnit::Microsecond) => {
+            build_array!(
+                Time64MicrosecondBuilder::new(),
+                values,
+                |value| match value {
+                    #[cfg(feature = "with-chrono")]
+                    Value::ChronoTime(v) => Ok(v.map(|v| {
+                        use chrono::Timelike;
+                        v.num_seconds_from_midnight() as i64 * 1_000_000
+                            + v.nanosecond() as i64 / 1_000
+                    })),
+                    #[cfg(feature = "with-time")]
+                    Value::TimeTime(v) => Ok(v.map(|v| {
+                        let (h, m, s, us) = v.as_hms_micro();
+                        ((h as i64 * 60 + m as i64) * 60 + s as i64) * 1_000_000 + us as i64
+                    })),
+                    value => Err(mismatch(value)),
+                }
+            )
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => build_array!(
+            TimestampMicrosecondBuilder::new().with_timezone_opt(tz.clone()),
+            values,
+            |value| match value {
+                #[cfg(feature = "with-chrono")]
+                Value::ChronoDateTime(v) => Ok(v.map(|v| {
+                    chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(*v, chrono::Utc)
+                        .timestamp_micros()
+                })),
+                #[cfg(feature = "with-chrono")]
+                Value::ChronoDateTimeUtc(v) => Ok(v.map(|v| v.timestamp_micros())),
+                #[cfg(feature = "with-chrono")]
+                Value::ChronoDateTimeLocal(v) => Ok(v.map(|v| v.timestamp_micros())),
+                #[cfg(feature = "with-chrono")]
+                Value::ChronoDateTimeWithTimeZone(v) => Ok(v.map(|v| v.timestamp_micros())),
+                #[cfg(feature = "with-time")]
+                Value::TimeDateTime(v) => {
+                    Ok(v.map(|v| (v.assume_utc().unix_timestamp_nanos() / 1_000) as i64))
+                }
+                #[cfg(feature = "with-time")]
+                Value::TimeDateTimeWithTimeZone(v) => {
+                    Ok(v.map(|v| (v.unix_timestamp_nanos() / 1_000) as i64))
+                }
+                value => Err(mismatch(value)),
+            }
+        ),
+        data_type => {
+            return Err(type_err(format!(
+                "column `{col}` can not be converted to Arrow {data_type}"
+            )))
+        }
+    })
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::tests_cfg::fruit;
+    use crate::{DbBackend, MockDatabase};
+    use arrow_array::{Array, Int32Array, StringArray};
+    use pretty_assertions::assert_eq;
+
+    fn fruits() -> Vec<fruit::Model> {
+        vec![
+            fruit::Model {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: None,
+            },
+            fruit::Model {
+                id: 2,
+                name: "Banana".to_owned(),
+                cake_id: Some(3),
+            },
+            fruit::Model {
+                id: 3,
+                name: "Cherry".to_owned(),
+                cake_id: Some(3),
+            },
+        ]
+    }
+
+    #[test]
+    fn arrow_record_batch() -> Result<(), DbErr> {
+        let schema = arrow_schema::<fruit::Entity>()?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("name", DataType::Utf8, false),
+                Field::new("cake_id", DataType::Int32, true),
+            ])
+        );
+
+        let batch = to_record_batch::<fruit::Entity>(&fruits())?;
+        assert_eq!(batch.num_rows(), 3);
+
+        let names = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .map(|names| names.iter().flatten().collect::<Vec<_>>());
+        assert_eq!(names, Some(vec!["Apple", "Banana", "Cherry"]));
+
+        let cake_ids = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .map(|ids| ids.iter().collect::<Vec<_>>());
+        assert_eq!(cake_ids, Some(vec![None, Some(3), Some(3)]));
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn stream_arrow_batches() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([fruits()])
+            .into_connection();
+
+        let batches: Vec<_> = fruit::Entity::find()
+            .stream_arrow(&db, 2)
+            .await?
+            .try_collect()
+            .await?;
+
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            [2, 1]
+        );
+        assert_eq!(batches[1].column(0).null_count(), 0);
+        Ok(())
+    }
+
+    #[cfg(feature = "with-parquet")]
+    #[smol_potat::test]
+    async fn write_parquet() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([fruits()])
+            .into_connection();
+
+        let mut file = Vec::new();
+        let num_rows = fruit::Entity::find()
+            .write_parquet(&db, &mut file, 1024)
+            .await?;
+
+        assert_eq!(num_rows, 3);
+        assert!(file.starts_with(b"PAR1"));
+        Ok(())
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    error::*, ActiveModelTrait, CancellationToken, ConnectionTrait, DbBackend, DeleteMany,
+    DeleteOne, DeleteResult, EntityTrait, ExecResult, Insert, InsertResult, IntoActiveModel,
+    QueryResult, Select, Statement, UpdateMany, UpdateOne, UpdateResult,
+};
+use std::time::Duration;
+
+/// A query which fails with [`DbErr::QueryTimeout`] if it runs for longer than a timeout,
+/// see [`Statement::timeout`], or with [`DbErr::QueryCancelled`] once a token is cancelled,
+/// see [`Statement::cancel_on`]
+#[derive(Clone, Debug)]
+pub struct CancellableQuery<Q> {
+    query: Q,
+    timeout: Option<Duration>,
+    cancel_token: Option<CancellationToken>,
+}
+
+/// A connection setting the timeout and the cancellation token of every statement run on it
+#[derive(Debug)]
+struct CancellableConnection<'a, C> {
+    db: &'a C,
+    timeout: Option<Duration>,
+    cancel_token: Option<CancellationToken>,
+}
+
+impl<C> CancellableConnection<'_, C> {
+    fn apply(&self, mut stmt: Statement) -> Statement {
+        stmt.timeout = match (stmt.timeout, self.timeout) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        if stmt.cancel_token.is_none() {
+            stmt.cancel_token = self.cancel_token.clone();
+        }
+        stmt
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> ConnectionTrait for CancellableConnection<'_, C>
+where
+    C: ConnectionTrait,
+{
+    fn get_database_backend(&self) -> DbBackend {
+        self.db.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.db.execute(self.apply(stmt)).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        crate::with_cancellation(
+            self.timeout,
+            self.cancel_token.as_ref(),
+            self.db.execute_unprepared(sql),
+        )
+        .await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.db.query_one(self.apply(stmt)).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.db.query_all(self.apply(stmt)).await
+    }
+
+    async fn query_pipeline(&self, stmts: Vec<Statement>) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+        crate::with_cancellation(
+            self.timeout,
+            self.cancel_token.as_ref(),
+            self.db.query_pipeline(stmts),
+        )
+        .await
+    }
+
+    async fn copy_in_raw(
+        &self,
+        statement: &str,
+        data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        crate::with_cancellation(
+            self.timeout,
+            self.cancel_token.as_ref(),
+            self.db.copy_in_raw(statement, data),
+        )
+        .await
+    }
+
+    async fn copy_out_raw(&self, statement: &str) -> Result<crate::CopyOutStream<'_>, DbErr> {
+        crate::with_cancellation(
+            self.timeout,
+            self.cancel_token.as_ref(),
+            self.db.copy_out_raw(statement),
+        )
+        .await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.db.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.db.is_mock_connection()
+    }
+}
+
+macro_rules! impl_cancellable {
+    ($ty: ident < $param: ident >, $bound: path) => {
+        impl<$param> $ty<$param>
+        where
+            $param: $bound,
+        {
+            /// Fail with [`DbErr::QueryTimeout`] if the query runs for longer than the timeout,
+            /// see [`Statement::timeout`]
+            pub fn timeout(self, timeout: Duration) -> CancellableQuery<Self> {
+                CancellableQuery::new(self).timeout(timeout)
+            }
+
+            /// Fail with [`DbErr::QueryCancelled`] once the token is cancelled,
+            /// see [`Statement::cancel_on`]
+            pub fn cancel_on(self, token: CancellationToken) -> CancellableQuery<Self> {
+                CancellableQuery::new(self).cancel_on(token)
+            }
+        }
+    };
+}
+
+impl_cancellable!(Select<E>, EntityTrait);
+impl_cancellable!(Insert<A>, ActiveModelTrait);
+impl_cancellable!(UpdateOne<A>, ActiveModelTrait);
+impl_cancellable!(UpdateMany<E>, EntityTrait);
+impl_cancellable!(DeleteOne<A>, ActiveModelTrait);
+impl_cancellable!(DeleteMany<E>, EntityTrait);
+
+impl<Q> CancellableQuery<Q> {
+    fn new(query: Q) -> Self {
+        Self {
+            query,
+            timeout: None,
+            cancel_token: None,
+        }
+    }
+
+    /// Fail with [`DbErr::QueryTimeout`] if the query runs for longer than the timeout,
+    /// see [`Statement::timeout`]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fail with [`DbErr::QueryCancelled`] once the token is cancelled,
+    /// see [`Statement::cancel_on`]
+    pub fn cancel_on(mut self, token: CancellationToken) -> Self {
+        self.cancel_token = Some(token);
+        self
+    }
+
+    fn on<C>(self, db: &C) -> (Q, CancellableConnection<'_, C>) {
+        let db = CancellableConnection {
+            db,
+            timeout: self.timeout,
+            cancel_token: self.cancel_token,
+        };
+        (self.query, db)
+    }
+}
+
+impl<E> CancellableQuery<Select<E>>
+where
+    E: EntityTrait,
+{
+    /// Get one Model from the SELECT query
+    pub async fn one<C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.one(&db).await
+    }
+
+    /// Get all Models from the SELECT query
+    pub async fn all<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.all(&db).await
+    }
+}
+
+impl<A> CancellableQuery<Insert<A>>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute an insert operation
+    pub async fn exec<C>(self, db: &C) -> Result<InsertResult<A>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec(&db).await
+    }
+
+    /// Execute an insert operation without returning (don't use `RETURNING` syntax)
+    /// Number of rows affected is returned
+    pub async fn exec_without_returning<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec_without_returning(&db).await
+    }
+
+    /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
+    pub async fn exec_with_returning<C>(
+        self,
+        db: &C,
+    ) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec_with_returning(&db).await
+    }
+}
+
+impl<A> CancellableQuery<UpdateOne<A>>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute an update operation on an ActiveModel
+    pub async fn exec<C>(self, db: &C) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec(&db).await
+    }
+}
+
+impl<E> CancellableQuery<UpdateMany<E>>
+where
+    E: EntityTrait,
+{
+    /// Execute an update operation on multiple ActiveModels
+    pub async fn exec<C>(self, db: &C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec(&db).await
+    }
+
+    /// Execute an update operation and return the updated model (use `RETURNING` syntax if supported)
+    pub async fn exec_with_returning<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec_with_returning(&db).await
+    }
+}
+
+impl<A> CancellableQuery<DeleteOne<A>>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute a DELETE operation on one ActiveModel
+    pub async fn exec<C>(self, db: &C) -> Result<DeleteResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec(&db).await
+    }
+}
+
+impl<E> CancellableQuery<DeleteMany<E>>
+where
+    E: EntityTrait,
+{
+    /// Execute a DELETE operation on many ActiveModels
+    pub async fn exec<C>(self, db: &C) -> Result<DeleteResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (query, db) = self.on(db);
+        query.exec(&db).await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, CancellationToken, DbBackend, MockDatabase,
+        MockExecResult, Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[smol_potat::test]
+    async fn cancellable_query() -> Result<(), DbErr> {
+        let token = CancellationToken::new();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([Vec::<cake::Model>::new()])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        cake::Entity::find()
+            .timeout(Duration::from_secs(1))
+            .all(&db)
+            .await?;
+        cake::Entity::delete_many()
+            .timeout(Duration::from_secs(2))
+            .cancel_on(token.clone())
+            .exec(&db)
+            .await?;
+        token.cancel();
+        assert_eq!(
+            cake::Entity::find().cancel_on(token.clone()).all(&db).await,
+            Err(DbErr::QueryCancelled)
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::one(
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                        []
+                    )
+                    .timeout(Duration::from_secs(1))
+                ),
+                Transaction::one(
+                    Statement::from_sql_and_values(
+                        DbBackend::Postgres,
+                        r#"DELETE FROM "cake""#,
+                        []
+                    )
+                    .timeout(Duration::from_secs(2))
+                    .cancel_on(token)
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn with_cancellation() {
+        let timeout = Duration::from_millis(10);
+        let pending = || futures::future::pending::<Result<(), _>>();
+        assert_eq!(
+            crate::with_cancellation(Some(timeout), None, pending()).await,
+            Err(DbErr::QueryTimeout(timeout))
+        );
+        assert_eq!(
+            crate::with_cancellation(Some(timeout), None, async { Ok(1) }).await,
+            Ok(1)
+        );
+
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        std::thread::spawn(move || cancel.cancel());
+        assert_eq!(
+            crate::with_cancellation(None, Some(&token), pending()).await,
+            Err(DbErr::QueryCancelled)
+        );
+        assert_eq!(
+            crate::with_cancellation(None, Some(&token), async { Ok(1) }).await,
+            Err(DbErr::QueryCancelled)
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{ConnectionTrait, Cursor, DbErr, SelectorTrait};
+use sea_query::IntoValueTuple;
+
+/// The arguments of a Relay connection field: `first` / `after` to page forward,
+/// `last` / `before` to page backward. Cursors are tokens of [`Cursor::to_token`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionArgs {
+    /// Take the first N rows
+    pub first: Option<u64>,
+    /// Only rows after the row of this cursor
+    pub after: Option<String>,
+    /// Take the last N rows
+    pub last: Option<u64>,
+    /// Only rows before the row of this cursor
+    pub before: Option<String>,
+}
+
+/// A page of rows in the shape of a Relay connection
+#[derive(Clone, Debug, PartialEq)]
+pub struct Connection<T> {
+    /// The rows, each with its cursor
+    pub edges: Vec<Edge<T>>,
+    /// Information about the neighbouring pages
+    pub page_info: PageInfo,
+}
+
+/// A row along with its cursor
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edge<T> {
+    /// The cursor of the row
+    pub cursor: String,
+    /// The row
+    pub node: T,
+}
+
+/// The `pageInfo` of a Relay connection
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Whether there are more rows after the last edge
+    pub has_next_page: bool,
+    /// Whether there are more rows before the first edge
+    pub has_previous_page: bool,
+    /// The cursor of the first edge
+    pub start_cursor: Option<String>,
+    /// The cursor of the last edge
+    pub end_cursor: Option<String>,
+}
+
+impl<T> Connection<T> {
+    /// Convert the node of each edge, e.g. into a GraphQL output type
+    pub fn map<U, F>(self, f: F) -> Connection<U>
+    where
+        F: Fn(T) -> U,
+    {
+        Connection {
+            edges: self
+                .edges
+                .into_iter()
+                .map(|edge| Edge {
+                    cursor: edge.cursor,
+                    node: f(edge.node),
+                })
+                .collect(),
+            page_info: self.page_info,
+        }
+    }
+}
+
+impl<S> Cursor<S>
+where
+    S: SelectorTrait,
+{
+    /// Fetch a page as a Relay connection.
+    ///
+    /// `cursor_of` returns the values of the cursor columns of a row, which are encoded into the
+    /// cursor of its edge. One extra row is fetched to tell whether there is a next page when paging
+    /// forward, or a previous page when paging backward; in the other direction, a page is assumed
+    /// to exist if an `after` or `before` cursor is given.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model {
+    /// #         id: 1,
+    /// #         name: "Cake".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let args = ConnectionArgs {
+    ///     first: Some(10),
+    ///     ..Default::default()
+    /// };
+    /// let connection = cake::Entity::find()
+    ///     .cursor_by(cake::Column::Id)
+    ///     .connection(db, args, |cake| cake.id)
+    ///     .await?;
+    ///
+    /// assert_eq!(connection.edges[0].node.name, "Cake");
+    /// assert!(!connection.page_info.has_next_page);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connection<C, F, V>(
+        &mut self,
+        db: &C,
+        args: ConnectionArgs,
+        cursor_of: F,
+    ) -> Result<Connection<S::Item>, DbErr>
+    where
+        C: ConnectionTrait,
+        F: Fn(&S::Item) -> V,
+        V: IntoValueTuple,
+    {
+        if let Some(after) = &args.after {
+            self.after_token(after)?;
+        }
+        if let Some(before) = &args.before {
+            self.before_token(before)?;
+        }
+        let (nodes, has_previous_page, has_next_page) = match (args.first, args.last) {
+            (Some(_), Some(_)) => {
+                return Err(DbErr::Custom(
+                    "`first` and `last` can not be used together".to_owned(),
+                ))
+            }
+            (Some(first), None) => {
+                let mut nodes = self.first(first + 1).all(db).await?;
+                let has_next_page = nodes.len() as u64 > first;
+                nodes.truncate(first as usize);
+                (nodes, args.after.is_some(), has_next_page)
+            }
+            (None, Some(last)) => {
+                let mut nodes = self.last(last + 1).all(db).await?;
+                let has_previous_page = nodes.len() as u64 > last;
+                if has_previous_page {
+                    nodes.remove(0);
+                }
+                (nodes, has_previous_page, args.before.is_some())
+            }
+            (None, None) => {
+                let nodes = self.all(db).await?;
+                (nodes, args.after.is_some(), args.before.is_some())
+            }
+        };
+        let edges = nodes
+            .into_iter()
+            .map(|node| {
+                Ok(Edge {
+                    cursor: self.to_token(cursor_of(&node))?,
+                    node,
+                })
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+        let page_info = PageInfo {
+            has_next_page,
+            has_previous_page,
+            start_cursor: edges.first().map(|edge| edge.cursor.clone()),
+            end_cursor: edges.last().map(|edge| edge.cursor.clone()),
+        };
+        Ok(Connection { edges, page_info })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::entity::prelude::*;
+    use crate::tests_cfg::*;
+    use crate::{DbBackend, MockDatabase, Transaction};
+    use pretty_assertions::assert_eq;
+
+    fn fruits(ids: &[i32]) -> Vec<fruit::Model> {
+        ids.iter()
+            .map(|&id| fruit::Model {
+                id,
+                name: format!("Fruit {id}"),
+                cake_id: None,
+            })
+            .collect()
+    }
+
+    #[smol_potat::test]
+    async fn connection_forward() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([fruits(&[3, 4, 5])])
+            .into_connection();
+
+        let mut cursor = fruit::Entity::find().cursor_by(fruit::Column::Id);
+        let after = cursor.to_token(2)?;
+        let args = ConnectionArgs {
+            first: Some(2),
+            after: Some(after),
+            ..Default::default()
+        };
+        let connection = cursor.connection(&db, args, |fruit| fruit.id).await?;
+
+        assert_eq!(
+            connection
+                .edges
+                .iter()
+                .map(|e| e.node.id)
+                .collect::<Vec<_>>(),
+            [3, 4]
+        );
+        assert_eq!(
+            connection.page_info,
+            PageInfo {
+                has_next_page: true,
+                has_previous_page: true,
+                start_cursor: Some(cursor.to_token(3)?),
+                end_cursor: Some(cursor.to_token(4)?),
+            }
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                    r#"FROM "fruit""#,
+                    r#"WHERE "fruit"."id" > $1"#,
+                    r#"ORDER BY "fruit"."id" ASC"#,
+                    r#"LIMIT $2"#,
+                ]
+                .join(" "),
+                [2_i32.into(), 3_u64.into()]
+            )]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn connection_backward() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([fruits(&[4, 3])])
+            .into_connection();
+
+        let mut cursor = fruit::Entity::find().cursor_by(fruit::Column::Id);
+        let before = cursor.to_token(5)?;
+        let args = ConnectionArgs {
+            last: Some(2),
+            before: Some(before),
+            ..Default::default()
+        };
+        let connection = cursor.connection(&db, args, |fruit| fruit.id).await?;
+
+        assert!(connection.page_info.has_next_page);
+        assert!(!connection.page_info.has_previous_page);
+        assert_eq!(
+            connection
+                .map(|fruit| fruit.id)
+                .edges
+                .into_iter()
+                .map(|e| e.node)
+                .collect::<Vec<_>>(),
+            [3, 4]
+        );
+
+        let mut cursor = fruit::Entity::find().cursor_by(fruit::Column::Id);
+        let args = ConnectionArgs {
+            first: Some(2),
+            last: Some(2),
+            ..Default::default()
+        };
+        assert!(cursor
+            .connection(&db, args, |fruit| fruit.id)
+            .await
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{
+    error::*, value_to_text, ActiveModelTrait, ActiveValue, ConnectionTrait, CopyFormat, CopyIn,
+    CopyOutStream, DbBackend, EntityTrait, IdenStatic, Iterable, QueryTrait, Select,
+};
+use sea_query::Value;
+
+/// The size of the chunks of rows sent to the database
+const CHUNK_SIZE: usize = 64 * 1024;
+
+impl<A> CopyIn<'_, A>
+where
+    A: ActiveModelTrait,
+{
+    /// Copy the models, returning the number of rows copied.
+    ///
+    /// The rows are sent as CSV, which PostgreSQL parses into the types of the columns.
+    /// The columns copied are the ones set in the first model, and the other models must set
+    /// the same columns. The columns which are not set get their default value.
+    pub async fn exec<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut chunks = CsvChunks {
+            models: self.models,
+            columns: Vec::new(),
+            chunk: String::new(),
+            done: false,
+        };
+        let first = match chunks.models.next() {
+            Some(model) => model,
+            None => return Ok(0),
+        };
+        chunks.write_row(first)?;
+        let statement = copy_statement::<A::Entity>(&chunks.columns)?;
+        db.copy_in_raw(&statement, &mut chunks).await
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Export the rows of the query with PostgreSQL's `COPY (..) TO STDOUT`, streaming them
+    /// in chunks of bytes, e.g. to dump a table to a file. The values of the query are inlined,
+    /// as `COPY` does not take parameters. On a transaction, no other statement can be run
+    /// on it until the stream is dropped.
+    ///
+    /// ```no_run
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// # use futures::TryStreamExt;
+    /// # async fn copy(db: &DatabaseConnection) -> Result<(), DbErr> {
+    /// let mut csv = Vec::new();
+    /// let mut stream = cake::Entity::find()
+    ///     .filter(cake::Column::Name.contains("Cheese"))
+    ///     .copy_out(db, CopyFormat::CsvWithHeader)
+    ///     .await?;
+    /// while let Some(chunk) = stream.try_next().await? {
+    ///     csv.extend(chunk);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_out<C>(self, db: &C, format: CopyFormat) -> Result<CopyOutStream<'_>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let query = self.build(DbBackend::Postgres).to_string();
+        db.copy_out_raw(&copy_out_statement(&query, format)).await
+    }
+}
+
+fn copy_out_statement(query: &str, format: CopyFormat) -> String {
+    format!("COPY ({query}) TO STDOUT {}", format.options())
+}
+
+/// The rows of the models as CSV, in chunks of about [`CHUNK_SIZE`] bytes
+struct CsvChunks<'a, A> {
+    models: Box<dyn Iterator<Item = A> + Send + 'a>,
+    /// Whether each column of the Entity is set
+    columns: Vec<bool>,
+    chunk: String,
+    done: bool,
+}
+
+impl<A> CsvChunks<'_, A>
+where
+    A: ActiveModelTrait,
+{
+    fn write_row(&mut self, mut model: A) -> Result<(), DbErr> {
+        let columns_empty = self.columns.is_empty();
+        let mut first = true;
+        for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
+            let value = match model.take(col) {
+                ActiveValue::Set(value) | ActiveValue::Unchanged(value) => Some(value),
+                ActiveValue::NotSet => None,
+            };
+            if columns_empty {
+                self.columns.push(value.is_some());
+            } else if self.columns[idx] != value.is_some() {
+                return Err(DbErr::Custom(format!(
+                    "The models copied must set the same columns, `{}` is set in some of them only",
+                    col.as_str()
+                )));
+            }
+            if let Some(value) = value {
+                if !first {
+                    self.chunk.push(',');
+                }
+                first = false;
+                write_field(&value, &mut self.chunk);
+            }
+        }
+        self.chunk.push('\n');
+        Ok(())
+    }
+}
+
+impl<A> Iterator for CsvChunks<'_, A>
+where
+    A: ActiveModelTrait,
+{
+    type Item = Result<Vec<u8>, DbErr>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done && self.chunk.len() < CHUNK_SIZE {
+            match self.models.next() {
+                Some(model) => {
+                    if let Err(err) = self.write_row(model) {
+                        self.done = true;
+                        self.chunk.clear();
+                        return Some(Err(err));
+                    }
+                }
+                None => self.done = true,
+            }
+        }
+        if self.chunk.is_empty() {
+            None
+        } else {
+            Some(Ok(std::mem::take(&mut self.chunk).into_bytes()))
+        }
+    }
+}
+
+fn copy_statement<E>(columns: &[bool]) -> Result<String, DbErr>
+where
+    E: EntityTrait,
+{
+    let columns: Vec<_> = E::Column::iter()
+        .zip(columns)
+        .filter(|(_, set)| **set)
+        .map(|(col, _)| quote(col.as_str()))
+        .collect();
+    if columns.is_empty() {
+        return Err(DbErr::Custom(
+            "The models copied must set at least one column".to_owned(),
+        ));
+    }
+    let entity = E::default();
+    let table = match entity.schema_name() {
+        Some(schema) => format!("{}.{}", quote(schema), quote(entity.table_name())),
+        None => quote(entity.table_name()),
+    };
+    Ok(format!(
+        "COPY {table} ({}) FROM STDIN (FORMAT csv)",
+        columns.join(", ")
+    ))
+}
+
+fn quote(iden: &str) -> String {
+    format!("\"{}\"", iden.replace('"', "\"\""))
+}
+
+/// Write a value as a quoted CSV field, or as an unquoted empty field if it is NULL
+fn write_field(value: &Value, out: &mut String) {
+    if let Some(text) = value_to_text(value) {
+        out.push('"');
+        out.push_str(&text.replace('"', "\"\""));
+        out.push('"');
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{tests_cfg::cake, DbBackend, MockDatabase, NotSet, Set};
+    use pretty_assertions::assert_eq;
+
+    fn csv_chunks(models: Vec<cake::ActiveModel>) -> CsvChunks<'static, cake::ActiveModel> {
+        CsvChunks {
+            models: Box::new(models.into_iter()),
+            columns: Vec::new(),
+            chunk: String::new(),
+            done: false,
+        }
+    }
+
+    #[test]
+    fn copy_in_csv() -> Result<(), DbErr> {
+        let mut chunks = csv_chunks(vec![
+            cake::ActiveModel {
+                id: Set(1),
+                name: Set("Apple Pie".to_owned()),
+            },
+            cake::ActiveModel {
+                id: Set(2),
+                name: Set("\"Cheese\", Cake\n".to_owned()),
+            },
+        ]);
+        assert_eq!(
+            chunks.next().transpose()?,
+            Some(b"\"1\",\"Apple Pie\"\n\"2\",\"\"\"Cheese\"\", Cake\n\"\n".to_vec())
+        );
+        assert_eq!(chunks.next().transpose()?, None);
+        assert_eq!(
+            copy_statement::<cake::Entity>(&chunks.columns)?,
+            r#"COPY "cake" ("id", "name") FROM STDIN (FORMAT csv)"#
+        );
+
+        let mut chunks = csv_chunks(vec![
+            cake::ActiveModel {
+                id: NotSet,
+                name: Set("Apple Pie".to_owned()),
+            },
+            cake::ActiveModel {
+                id: Set(2),
+                name: Set("Cheese Cake".to_owned()),
+            },
+        ]);
+        assert!(matches!(chunks.next(), Some(Err(DbErr::Custom(_)))));
+        assert_eq!(chunks.next().transpose()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn copy_in_values() {
+        assert_eq!(value_to_text(&Value::Int(None)), None);
+        assert_eq!(value_to_text(&true.into()), Some("TRUE".to_owned()));
+        assert_eq!(value_to_text(&1.5f64.into()), Some("1.5".to_owned()));
+        assert_eq!(value_to_text(&"It's".into()), Some("It's".to_owned()));
+        assert_eq!(
+            value_to_text(&vec![0xdeu8, 0xad].into()),
+            Some("\\xDEAD".to_owned())
+        );
+        #[cfg(feature = "postgres-array")]
+        assert_eq!(
+            value_to_text(&Value::Array(
+                sea_query::ArrayType::String,
+                Some(Box::new(vec![
+                    "a \"b\"".into(),
+                    Value::String(None),
+                    "c\\d".into()
+                ]))
+            )),
+            Some(r#"{"a \"b\"",NULL,"c\\d"}"#.to_owned())
+        );
+    }
+
+    #[test]
+    fn copy_out_statement() {
+        use crate::{ColumnTrait, QueryFilter};
+
+        let query = cake::Entity::find()
+            .filter(cake::Column::Name.eq("Cheese"))
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert_eq!(
+            super::copy_out_statement(&query, CopyFormat::CsvWithHeader),
+            [
+                r#"COPY (SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" = 'Cheese')"#,
+                "TO STDOUT (FORMAT csv, HEADER)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[smol_potat::test]
+    async fn copy_not_supported() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let res = cake::Entity::copy_in([cake::ActiveModel {
+            id: NotSet,
+            name: Set("Apple Pie".to_owned()),
+        }])
+        .exec(&db)
+        .await;
+        assert!(matches!(res, Err(DbErr::Custom(_))));
+
+        let res = cake::Entity::find().copy_out(&db, CopyFormat::Csv).await;
+        assert!(matches!(res, Err(DbErr::Custom(_))));
+        Ok(())
+    }
+}
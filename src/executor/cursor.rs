@@ -1,14 +1,13 @@
 use crate::{
     ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IdentityOf, IntoIdentity,
-    PartialModelTrait, PrimaryKeyToColumn, QueryOrder, QuerySelect, Select, SelectModel, SelectTwo,
-    SelectTwoModel, SelectorTrait,
+    Iterable, ModelTrait, PartialModelTrait, PrimaryKeyToColumn, QueryOrder, QuerySelect, Select,
+    SelectModel, SelectTwo, SelectTwoModel, SelectorTrait,
 };
 use sea_query::{
     Condition, DynIden, Expr, IntoValueTuple, Order, SeaRc, SelectStatement, SimpleExpr, Value,
     ValueTuple,
 };
-use std::marker::PhantomData;
-use strum::IntoEnumIterator as Iterable;
+use std::{future::Future, marker::PhantomData};
 
 #[cfg(feature = "with-json")]
 use crate::JsonValue;
@@ -22,6 +21,7 @@ where
     query: SelectStatement,
     table: DynIden,
     order_columns: Identity,
+    directions: Vec<Order>,
     secondary_order_by: Vec<(DynIden, Identity)>,
     first: Option<u64>,
     last: Option<u64>,
@@ -29,6 +29,8 @@ where
     after: Option<ValueTuple>,
     sort_asc: bool,
     is_result_reversed: bool,
+    #[cfg(feature = "cursor-token")]
+    token_key: Option<Vec<u8>>,
     phantom: PhantomData<S>,
 }
 
@@ -45,12 +47,15 @@ where
             query,
             table,
             order_columns: order_columns.into_identity(),
+            directions: Vec::new(),
             last: None,
             first: None,
             after: None,
             before: None,
             sort_asc: true,
             is_result_reversed: false,
+            #[cfg(feature = "cursor-token")]
+            token_key: None,
             phantom: PhantomData,
             secondary_order_by: Default::default(),
         }
@@ -74,11 +79,69 @@ where
         self
     }
 
+    /// Sign tokens with HMAC-SHA256 using a secret key, so that tokens modified by a client are rejected.
+    /// Without a key, tokens are only base64 encoded.
+    #[cfg(feature = "cursor-token")]
+    pub fn token_key<K>(&mut self, key: K) -> &mut Self
+    where
+        K: Into<Vec<u8>>,
+    {
+        self.token_key = Some(key.into());
+        self
+    }
+
+    /// Encode the cursor values of a row, usually the last row of a page, into an opaque token
+    /// that can be handed to clients and passed back to [`Cursor::after_token`] for the next page.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::IntoValueTuple, tests_cfg::fruit};
+    ///
+    /// let mut cursor = fruit::Entity::find().cursor_by((fruit::Column::Name, fruit::Column::Id));
+    /// cursor.token_key("secret");
+    ///
+    /// let token = cursor.to_token(("Banana", 5))?;
+    /// assert_eq!(cursor.from_token(&token)?, ("Banana", 5).into_value_tuple());
+    ///
+    /// cursor.after_token(&token)?.first(10);
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    #[cfg(feature = "cursor-token")]
+    pub fn to_token<V>(&self, values: V) -> Result<String, DbErr>
+    where
+        V: IntoValueTuple,
+    {
+        super::cursor_token::encode_token(values.into_value_tuple(), self.token_key.as_deref())
+    }
+
+    /// Decode the cursor values of a token, checking its signature and the number of values
+    #[cfg(feature = "cursor-token")]
+    pub fn from_token(&self, token: &str) -> Result<ValueTuple, DbErr> {
+        let values = super::cursor_token::decode_token(token, self.token_key.as_deref())?;
+        if values.clone().into_iter().count() != self.order_columns.clone().into_iter().count() {
+            return Err(DbErr::Json("Invalid cursor token".to_owned()));
+        }
+        Ok(values)
+    }
+
+    /// Filter paginated result to the rows after the row of a token, see [`Cursor::to_token`]
+    #[cfg(feature = "cursor-token")]
+    pub fn after_token(&mut self, token: &str) -> Result<&mut Self, DbErr> {
+        let values = self.from_token(token)?;
+        Ok(self.after(values))
+    }
+
+    /// Filter paginated result to the rows before the row of a token, see [`Cursor::to_token`]
+    #[cfg(feature = "cursor-token")]
+    pub fn before_token(&mut self, token: &str) -> Result<&mut Self, DbErr> {
+        let values = self.from_token(token)?;
+        Ok(self.before(values))
+    }
+
     fn apply_filters(&mut self) -> &mut Self {
         if let Some(values) = self.after.clone() {
-            let condition = self.apply_filter(values, |c, v| {
+            let condition = self.apply_filter(values, |i, c, v| {
                 let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
-                if self.sort_asc {
+                if self.is_column_asc(i) {
                     exp.gt(v)
                 } else {
                     exp.lt(v)
@@ -88,9 +151,9 @@ where
         }
 
         if let Some(values) = self.before.clone() {
-            let condition = self.apply_filter(values, |c, v| {
+            let condition = self.apply_filter(values, |i, c, v| {
                 let exp = Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c)));
-                if self.sort_asc {
+                if self.is_column_asc(i) {
                     exp.lt(v)
                 } else {
                     exp.gt(v)
@@ -104,19 +167,19 @@ where
 
     fn apply_filter<F>(&self, values: ValueTuple, f: F) -> Condition
     where
-        F: Fn(&DynIden, Value) -> SimpleExpr,
+        F: Fn(usize, &DynIden, Value) -> SimpleExpr,
     {
         match (&self.order_columns, values) {
-            (Identity::Unary(c1), ValueTuple::One(v1)) => Condition::all().add(f(c1, v1)),
+            (Identity::Unary(c1), ValueTuple::One(v1)) => Condition::all().add(f(0, c1, v1)),
             (Identity::Binary(c1, c2), ValueTuple::Two(v1, v2)) => Condition::any()
                 .add(
                     Condition::all()
                         .add(
                             Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
                         )
-                        .add(f(c2, v2)),
+                        .add(f(1, c2, v2)),
                 )
-                .add(f(c1, v1)),
+                .add(f(0, c1, v1)),
             (Identity::Ternary(c1, c2, c3), ValueTuple::Three(v1, v2, v3)) => Condition::any()
                 .add(
                     Condition::all()
@@ -126,16 +189,16 @@ where
                         .add(
                             Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c2))).eq(v2.clone()),
                         )
-                        .add(f(c3, v3)),
+                        .add(f(2, c3, v3)),
                 )
                 .add(
                     Condition::all()
                         .add(
                             Expr::col((SeaRc::clone(&self.table), SeaRc::clone(c1))).eq(v1.clone()),
                         )
-                        .add(f(c2, v2)),
+                        .add(f(1, c2, v2)),
                 )
-                .add(f(c1, v1)),
+                .add(f(0, c1, v1)),
             (Identity::Many(col_vec), ValueTuple::Many(val_vec))
                 if col_vec.len() == val_vec.len() =>
             {
@@ -176,7 +239,7 @@ where
                                         Expr::col((SeaRc::clone(&self.table), SeaRc::clone(col)))
                                             .eq(val)
                                     } else {
-                                        f(col, val)
+                                        f(i, col, val)
                                     };
                                     // Chain it with AND operator
                                     inner_cond_all.add(expr)
@@ -202,6 +265,36 @@ where
         self
     }
 
+    /// Set the direction of each order by column, e.g. `[Order::Desc, Order::Asc]` to page through
+    /// `ORDER BY created_at DESC, id ASC`. Columns without a direction are sorted ascending.
+    /// Calling [`Cursor::desc`] reverses every direction.
+    ///
+    /// With mixed directions the keyset can not be compared as a single row value, the cursor
+    /// condition is expanded into `(a < x) OR (a = x AND b > y)`, which every backend supports.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let mut cursor = fruit::Entity::find().cursor_by((fruit::Column::Name, fruit::Column::Id));
+    /// cursor
+    ///     .directions([Order::Desc, Order::Asc])
+    ///     .after(("Banana", 5))
+    ///     .first(10);
+    /// ```
+    pub fn directions<I>(&mut self, directions: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Order>,
+    {
+        self.directions = directions.into_iter().collect();
+        self
+    }
+
+    /// Whether the `i`-th order by column is sorted ascending, before reversing for [`Cursor::last`]
+    fn is_column_asc(&self, i: usize) -> bool {
+        let asc = !matches!(self.directions.get(i), Some(Order::Desc));
+        asc == self.sort_asc
+    }
+
     /// Limit result set to only first N rows in ascending order of the order by column
     pub fn first(&mut self, num_rows: u64) -> &mut Self {
         self.last = None;
@@ -241,28 +334,25 @@ where
         self.query.clear_order_by();
         let ord = self.resolve_sort_order();
 
+        let columns: Vec<(DynIden, Order)> = self
+            .order_columns
+            .clone()
+            .into_iter()
+            .enumerate()
+            .map(|(i, col)| {
+                // Columns sorted against the cursor's direction are reversed along with it
+                let col_ord = match (self.is_column_asc(i) == self.sort_asc, &ord) {
+                    (true, ord) => ord.clone(),
+                    (false, Order::Asc) => Order::Desc,
+                    (false, _) => Order::Asc,
+                };
+                (col, col_ord)
+            })
+            .collect();
+
         let query = &mut self.query;
-        let order = |query: &mut SelectStatement, col| {
-            query.order_by((SeaRc::clone(&self.table), SeaRc::clone(col)), ord.clone());
-        };
-        match &self.order_columns {
-            Identity::Unary(c1) => {
-                order(query, c1);
-            }
-            Identity::Binary(c1, c2) => {
-                order(query, c1);
-                order(query, c2);
-            }
-            Identity::Ternary(c1, c2, c3) => {
-                order(query, c1);
-                order(query, c2);
-                order(query, c3);
-            }
-            Identity::Many(vec) => {
-                for col in vec.iter() {
-                    order(query, col);
-                }
-            }
+        for (col, col_ord) in columns {
+            query.order_by((SeaRc::clone(&self.table), col), col_ord);
         }
 
         for (tbl, col) in self.secondary_order_by.iter().cloned() {
@@ -304,12 +394,15 @@ where
             query: self.query,
             table: self.table,
             order_columns: self.order_columns,
+            directions: self.directions,
             last: self.last,
             first: self.first,
             after: self.after,
             before: self.before,
             sort_asc: self.sort_asc,
             is_result_reversed: self.is_result_reversed,
+            #[cfg(feature = "cursor-token")]
+            token_key: self.token_key,
             phantom: PhantomData,
             secondary_order_by: self.secondary_order_by,
         }
@@ -330,12 +423,15 @@ where
             query: self.query,
             table: self.table,
             order_columns: self.order_columns,
+            directions: self.directions,
             last: self.last,
             first: self.first,
             after: self.after,
             before: self.before,
             sort_asc: self.sort_asc,
             is_result_reversed: self.is_result_reversed,
+            #[cfg(feature = "cursor-token")]
+            token_key: self.token_key,
             phantom: PhantomData,
             secondary_order_by: self.secondary_order_by,
         }
@@ -396,6 +492,99 @@ where
     {
         Cursor::new(self.query, SeaRc::new(E::default()), order_columns)
     }
+
+    /// Process every row of the query in batches of up to `batch_size` Models.
+    ///
+    /// Batches are paged by the primary key with a cursor rather than `OFFSET`, so rows inserted
+    /// or deleted while processing do not shift the following batches, and a batch stays as fast
+    /// as the first one. Any ordering of the query is replaced by the primary key.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([vec![cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// cake::Entity::find()
+    ///     .in_batches(db, 1000, |cakes| async move {
+    ///         // Do something with cakes: Vec<cake::Model>
+    ///         Ok(())
+    ///     })
+    ///     .await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn in_batches<C, F, Fut>(self, db: &C, batch_size: u64, mut f: F) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+        F: FnMut(Vec<M>) -> Fut,
+        Fut: Future<Output = Result<(), DbErr>>,
+        M: ModelTrait<Entity = E>,
+    {
+        if batch_size == 0 {
+            return Err(DbErr::Custom("Batch size should not be zero".to_owned()));
+        }
+        let identity = match <E::PrimaryKey as Iterable>::iter()
+            .map(|pk| SeaRc::new(pk.into_column()) as DynIden)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [c1] => Identity::Unary(c1.clone()),
+            [c1, c2] => Identity::Binary(c1.clone(), c2.clone()),
+            [c1, c2, c3] => Identity::Ternary(c1.clone(), c2.clone(), c3.clone()),
+            cols => Identity::Many(cols.to_vec()),
+        };
+        let mut after = None;
+        loop {
+            let mut cursor = self.clone().cursor_by(identity.clone());
+            cursor.first(batch_size);
+            if let Some(values) = after.take() {
+                cursor.after(values);
+            }
+            let models = cursor.all(db).await?;
+            let is_last = (models.len() as u64) < batch_size;
+            after = models.last().and_then(|model| {
+                value_tuple(
+                    <E::PrimaryKey as Iterable>::iter()
+                        .map(|pk| model.get(pk.into_column()))
+                        .collect(),
+                )
+            });
+            if !models.is_empty() {
+                f(models).await?;
+            }
+            if is_last || after.is_none() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Build a [ValueTuple] of the variant matching the number of values
+pub(crate) fn value_tuple(mut values: Vec<Value>) -> Option<ValueTuple> {
+    Some(match values.len() {
+        0 => return None,
+        1 => ValueTuple::One(values.remove(0)),
+        2 => {
+            let v2 = values.remove(1);
+            ValueTuple::Two(values.remove(0), v2)
+        }
+        3 => {
+            let v3 = values.remove(2);
+            let v2 = values.remove(1);
+            ValueTuple::Three(values.remove(0), v2, v3)
+        }
+        _ => ValueTuple::Many(values),
+    })
 }
 
 impl<E, F, M, N> CursorTrait for SelectTwo<E, F>
@@ -467,6 +656,7 @@ mod tests {
     use crate::tests_cfg::*;
     use crate::{DbBackend, MockDatabase, Statement, Transaction};
     use pretty_assertions::assert_eq;
+    use sea_query::Order;
 
     #[smol_potat::test]
     async fn first_2_before_10() -> Result<(), DbErr> {
@@ -1736,6 +1926,110 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn mixed_directions() -> Result<(), DbErr> {
+        use test_entity::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[Model {
+                id: 1,
+                category: "CAT".into(),
+            }]])
+            .into_connection();
+
+        assert!(!Entity::find()
+            .cursor_by((Column::Category, Column::Id))
+            .directions([Order::Desc, Order::Asc])
+            .after(("B".to_owned(), 2))
+            .before(("A".to_owned(), 7))
+            .first(3)
+            .all(&db)
+            .await?
+            .is_empty());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "example"."id", "example"."category""#,
+                    r#"FROM "example""#,
+                    r#"WHERE (("example"."category" = $1 AND "example"."id" > $2)"#,
+                    r#"OR "example"."category" < $3)"#,
+                    r#"AND (("example"."category" = $4 AND "example"."id" < $5)"#,
+                    r#"OR "example"."category" > $6)"#,
+                    r#"ORDER BY "example"."category" DESC, "example"."id" ASC"#,
+                    r#"LIMIT $7"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    "B".to_string().into(),
+                    2i32.into(),
+                    "B".to_string().into(),
+                    "A".to_string().into(),
+                    7i32.into(),
+                    "A".to_string().into(),
+                    3_u64.into(),
+                ]
+            )])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn mixed_directions_last_desc() -> Result<(), DbErr> {
+        use xyz_entity::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[Model {
+                x: 1,
+                y: "CAT".into(),
+                z: 3,
+            }]])
+            .into_connection();
+
+        assert!(!Entity::find()
+            .cursor_by((Column::X, Column::Y, Column::Z))
+            .directions([Order::Asc, Order::Desc])
+            .desc()
+            .after((1, "B".to_owned(), 2i64))
+            .last(3)
+            .all(&db)
+            .await?
+            .is_empty());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "m"."x", "m"."y", "m"."z""#,
+                    r#"FROM "m""#,
+                    r#"WHERE ("m"."x" = $1 AND "m"."y" = $2 AND "m"."z" < $3)"#,
+                    r#"OR ("m"."x" = $4 AND "m"."y" > $5)"#,
+                    r#"OR "m"."x" < $6"#,
+                    r#"ORDER BY "m"."x" ASC, "m"."y" DESC, "m"."z" ASC"#,
+                    r#"LIMIT $7"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    1i32.into(),
+                    "B".to_string().into(),
+                    2i64.into(),
+                    1i32.into(),
+                    "B".to_string().into(),
+                    1i32.into(),
+                    3_u64.into(),
+                ]
+            )])]
+        );
+
+        Ok(())
+    }
+
     mod composite_entity {
         use crate as sea_orm;
         use crate::entity::prelude::*;
@@ -2381,4 +2675,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn in_batches_composite_key() -> Result<(), DbErr> {
+        let model = |cake_id, filling_id| cake_filling::Model {
+            cake_id,
+            filling_id,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([vec![model(1, 1), model(1, 2)], vec![model(2, 1)]])
+            .into_connection();
+
+        let mut batches = Vec::new();
+        cake_filling::Entity::find()
+            .in_batches(&db, 2, |models| {
+                batches.push(models);
+                async { Ok(()) }
+            })
+            .await?;
+
+        assert_eq!(batches, [vec![model(1, 1), model(1, 2)], vec![model(2, 1)]]);
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                        r#"ORDER BY "cake_filling"."cake_id" ASC, "cake_filling"."filling_id" ASC LIMIT $1"#,
+                    ]
+                    .join(" "),
+                    [2_u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                        r#"WHERE ("cake_filling"."cake_id" = $1 AND "cake_filling"."filling_id" > $2)"#,
+                        r#"OR "cake_filling"."cake_id" > $3"#,
+                        r#"ORDER BY "cake_filling"."cake_id" ASC, "cake_filling"."filling_id" ASC LIMIT $4"#,
+                    ]
+                    .join(" "),
+                    [1i32.into(), 2i32.into(), 1i32.into(), 2_u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,162 @@
+use crate::{error::*, JsonValue};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use sea_query::{Value, ValueTuple};
+use serde_json::json;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+macro_rules! value_tags {
+    ( $( $(#[$attr:meta])* $tag: literal => $variant: ident ( $ty: ty ) ),* $(,)? ) => {
+        fn encode_value(value: &Value) -> Result<JsonValue, DbErr> {
+            match value {
+                $(
+                    $(#[$attr])*
+                    Value::$variant(Some(v)) => Ok(json!([$tag, v])),
+                )*
+                value => Err(json_err(format!("{value:?} can not be encoded into a cursor token"))),
+            }
+        }
+
+        fn decode_value(tag: &str, value: JsonValue) -> Result<Value, DbErr> {
+            match tag {
+                $(
+                    $(#[$attr])*
+                    $tag => serde_json::from_value::<$ty>(value)
+                        .map(Into::into)
+                        .map_err(|e| json_err(e.to_string())),
+                )*
+                tag => Err(json_err(format!("Unknown value type `{tag}` in cursor token"))),
+            }
+        }
+    };
+}
+
+value_tags!(
+    "bool" => Bool(bool),
+    "i8" => TinyInt(i8),
+    "i16" => SmallInt(i16),
+    "i32" => Int(i32),
+    "i64" => BigInt(i64),
+    "u8" => TinyUnsigned(u8),
+    "u16" => SmallUnsigned(u16),
+    "u32" => Unsigned(u32),
+    "u64" => BigUnsigned(u64),
+    "f32" => Float(f32),
+    "f64" => Double(f64),
+    "string" => String(String),
+    "char" => Char(char),
+    #[cfg(feature = "with-chrono")]
+    "date" => ChronoDate(chrono::NaiveDate),
+    #[cfg(feature = "with-chrono")]
+    "time" => ChronoTime(chrono::NaiveTime),
+    #[cfg(feature = "with-chrono")]
+    "datetime" => ChronoDateTime(chrono::NaiveDateTime),
+    #[cfg(feature = "with-chrono")]
+    "datetime_utc" => ChronoDateTimeUtc(chrono::DateTime<chrono::Utc>),
+    #[cfg(feature = "with-chrono")]
+    "datetime_tz" => ChronoDateTimeWithTimeZone(chrono::DateTime<chrono::FixedOffset>),
+    #[cfg(feature = "with-uuid")]
+    "uuid" => Uuid(uuid::Uuid),
+);
+
+/// Encode key values into `base64(payload)`, followed by `.base64(HMAC-SHA256(payload))` if a key is given
+pub(crate) fn encode_token(values: ValueTuple, key: Option<&[u8]>) -> Result<String, DbErr> {
+    let values = values
+        .into_iter()
+        .map(|value| encode_value(&value))
+        .collect::<Result<Vec<_>, _>>()?;
+    let payload = JsonValue::Array(values).to_string();
+    let mut token = URL_SAFE_NO_PAD.encode(&payload);
+    if let Some(key) = key {
+        let mut mac = new_mac(key)?;
+        mac.update(payload.as_bytes());
+        token.push('.');
+        token.push_str(&URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()));
+    }
+    Ok(token)
+}
+
+/// Decode key values from a token, verifying its signature if a key is given
+pub(crate) fn decode_token(token: &str, key: Option<&[u8]>) -> Result<ValueTuple, DbErr> {
+    let invalid = || json_err("Invalid cursor token");
+    let (payload, signature) = match token.split_once('.') {
+        Some((payload, signature)) => (payload, Some(signature)),
+        None => (token, None),
+    };
+    let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid())?;
+    if let Some(key) = key {
+        let signature = signature
+            .and_then(|s| URL_SAFE_NO_PAD.decode(s).ok())
+            .ok_or_else(invalid)?;
+        let mut mac = new_mac(key)?;
+        mac.update(&payload);
+        mac.verify_slice(&signature).map_err(|_| invalid())?;
+    }
+    let values = match serde_json::from_slice(&payload).map_err(|_| invalid())? {
+        JsonValue::Array(values) => values,
+        _ => return Err(invalid()),
+    };
+    let values = values
+        .into_iter()
+        .map(|value| match value {
+            JsonValue::Array(mut pair) if pair.len() == 2 => {
+                let value = pair.pop().unwrap_or_default();
+                match pair.pop() {
+                    Some(JsonValue::String(tag)) => decode_value(&tag, value),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(invalid()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    super::cursor::value_tuple(values).ok_or_else(invalid)
+}
+
+fn new_mac(key: &[u8]) -> Result<HmacSha256, DbErr> {
+    HmacSha256::new_from_slice(key).map_err(|e| DbErr::Custom(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cursor_token_round_trip() -> Result<(), DbErr> {
+        let values = ValueTuple::Three(Value::from("Apple"), Value::from(42i64), Value::from(true));
+        for key in [None, Some(b"secret".as_slice())] {
+            let token = encode_token(values.clone(), key)?;
+            assert_eq!(decode_token(&token, key)?, values);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_token_tampered() -> Result<(), DbErr> {
+        let key = Some(b"secret".as_slice());
+        let token = encode_token(ValueTuple::One(Value::from(10)), key)?;
+        let (_, signature) = token.split_once('.').unwrap_or_default();
+        let forged = format!("{}.{signature}", URL_SAFE_NO_PAD.encode(r#"[["i32",11]]"#));
+
+        assert!(decode_token(&forged, key).is_err());
+        assert!(decode_token(&token, Some(b"other".as_slice())).is_err());
+        assert!(decode_token(&URL_SAFE_NO_PAD.encode(r#"[["i32",11]]"#), key).is_err());
+        assert!(decode_token("not a token", None).is_err());
+        assert!(encode_token(ValueTuple::One(Value::Int(None)), key).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_token_arity() -> Result<(), DbErr> {
+        use crate::{entity::*, tests_cfg::fruit};
+
+        let mut cursor = fruit::Entity::find().cursor_by(fruit::Column::Id);
+        let token = cursor.to_token(("Banana", 5))?;
+
+        assert!(cursor.after_token(&token).is_err());
+        assert!(cursor.after_token(&cursor.to_token(5)?).is_ok());
+        Ok(())
+    }
+}
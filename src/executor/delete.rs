@@ -1,4 +1,7 @@
-use crate::{error::*, ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait};
+use crate::{
+    check_writable, error::*, ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteManyUsing,
+    DeleteOne, EntityTrait,
+};
 use sea_query::DeleteStatement;
 use std::future::Future;
 
@@ -24,8 +27,13 @@ where
     where
         C: ConnectionTrait,
     {
+        let writable = check_writable(A::Entity::default());
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        let exec = exec_delete_only(self.query, db);
+        async move {
+            writable?;
+            exec.await
+        }
     }
 }
 
@@ -38,8 +46,31 @@ where
     where
         C: ConnectionTrait,
     {
+        let writable = check_writable(E::default());
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        let exec = exec_delete_only(self.query, db);
+        async move {
+            writable?;
+            exec.await
+        }
+    }
+}
+
+impl<E> DeleteManyUsing<E>
+where
+    E: EntityTrait,
+{
+    /// Execute a DELETE operation on many ActiveModels
+    pub async fn exec<C>(self, db: &C) -> Result<DeleteResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        check_writable(E::default())?;
+        let statement = self.build(db.get_database_backend());
+        let result = db.execute(statement).await?;
+        Ok(DeleteResult {
+            rows_affected: result.rows_affected(),
+        })
     }
 }
 
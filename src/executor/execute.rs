@@ -25,11 +25,21 @@ pub(crate) enum ExecResultHolder {
     /// Holds the result of executing an operation on the Proxy database
     #[cfg(feature = "proxy")]
     Proxy(crate::ProxyExecResult),
+    /// The result of a statement recorded by a dry run, which is not executed
+    DryRun,
 }
 
 // ExecResult //
 
 impl ExecResult {
+    /// The result of a statement recorded by a dry run instead of being executed,
+    /// with no row affected and a last insert id of 0
+    pub fn dry_run() -> Self {
+        Self {
+            result: ExecResultHolder::DryRun,
+        }
+    }
+
     /// Get the last id after `AUTOINCREMENT` is done on the primary key
     ///
     /// # Panics
@@ -56,6 +66,7 @@ impl ExecResult {
             ExecResultHolder::Mock(result) => result.last_insert_id,
             #[cfg(feature = "proxy")]
             ExecResultHolder::Proxy(result) => result.last_insert_id,
+            ExecResultHolder::DryRun => 0,
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
         }
@@ -74,6 +85,7 @@ impl ExecResult {
             ExecResultHolder::Mock(result) => result.rows_affected,
             #[cfg(feature = "proxy")]
             ExecResultHolder::Proxy(result) => result.rows_affected,
+            ExecResultHolder::DryRun => 0,
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
         }
@@ -0,0 +1,204 @@
+use crate::{
+    error::*, ConnectionTrait, EntityTrait, IdenStatic, Iterable, JsonValue, Select, SelectModel,
+    SelectorRaw, StreamTrait,
+};
+use async_stream::try_stream;
+use futures::{Stream, TryStreamExt};
+use std::pin::Pin;
+
+/// The format of exported rows, see [`Select::export`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values, starting with a header line of the column names
+    Csv,
+    /// One JSON object per line
+    Ndjson,
+}
+
+/// A stream of exported lines, each ending with a newline
+pub type ExportStream<'b> = Pin<Box<dyn Stream<Item = Result<String, DbErr>> + 'b + Send>>;
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Stream the rows of the query as lines of CSV or NDJSON, which can be sent as the body of a
+    /// response without holding every row in memory. The CSV header lists the columns of the Entity.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake, \"New York\" style".to_owned() }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use futures::TryStreamExt;
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let lines: Vec<String> = cake::Entity::find()
+    ///     .export(db, ExportFormat::Csv)
+    ///     .await?
+    ///     .try_collect()
+    ///     .await?;
+    ///
+    /// assert_eq!(lines, ["id,name\n", "1,\"Cake, \"\"New York\"\" style\"\n"]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        format: ExportFormat,
+    ) -> Result<ExportStream<'b>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        let headers = E::Column::iter()
+            .map(|col| col.as_str().to_owned())
+            .collect();
+        let rows = self.into_json().stream(db).await?;
+        Ok(export_rows(rows, format, Some(headers)))
+    }
+}
+
+impl SelectorRaw<SelectModel<JsonValue>> {
+    /// Stream the rows of a raw SQL query as lines of CSV or NDJSON, see [`Select::export`].
+    /// The CSV header lists the columns of the first row; nothing is returned if there is no row.
+    pub async fn export<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        format: ExportFormat,
+    ) -> Result<ExportStream<'b>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        let rows = self.stream(db).await?;
+        Ok(export_rows(rows, format, None))
+    }
+}
+
+fn export_rows<'b, S>(
+    rows: S,
+    format: ExportFormat,
+    headers: Option<Vec<String>>,
+) -> ExportStream<'b>
+where
+    S: Stream<Item = Result<JsonValue, DbErr>> + Unpin + Send + 'b,
+{
+    Box::pin(try_stream! {
+        let mut rows = rows;
+        let mut headers = headers;
+        let mut header_written = false;
+        while let Some(row) = rows.try_next().await? {
+            match format {
+                ExportFormat::Ndjson => yield format!("{row}\n"),
+                ExportFormat::Csv => {
+                    let headers = headers.get_or_insert_with(|| match &row {
+                        JsonValue::Object(map) => map.keys().cloned().collect(),
+                        _ => Vec::new(),
+                    });
+                    if !header_written {
+                        header_written = true;
+                        yield csv_line(headers.iter().map(|h| csv_cell(h)));
+                    }
+                    yield csv_line(headers.iter().map(|h| match row.get(h) {
+                        None | Some(JsonValue::Null) => String::new(),
+                        Some(JsonValue::String(s)) => csv_cell(s),
+                        Some(value) => csv_cell(&value.to_string()),
+                    }));
+                }
+            }
+        }
+        if let (ExportFormat::Csv, false, Some(headers)) = (format, header_written, &headers) {
+            yield csv_line(headers.iter().map(|h| csv_cell(h)));
+        }
+    })
+}
+
+fn csv_line<I>(cells: I) -> String
+where
+    I: Iterator<Item = String>,
+{
+    let mut line = cells.collect::<Vec<_>>().join(",");
+    line.push('\n');
+    line
+}
+
+/// Quote a cell if it contains a separator, a quote or a line break
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::tests_cfg::fruit;
+    use crate::{entity::*, error::*, DbBackend, ExportFormat, MockDatabase, Statement};
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn export_csv_and_ndjson() -> Result<(), DbErr> {
+        let fruits = vec![
+            fruit::Model {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: None,
+            },
+            fruit::Model {
+                id: 2,
+                name: "Line\nbreak".to_owned(),
+                cake_id: Some(3),
+            },
+        ];
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([fruits.clone(), fruits, Vec::new()])
+            .into_connection();
+
+        let csv: Vec<String> = fruit::Entity::find()
+            .export(&db, ExportFormat::Csv)
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(
+            csv,
+            ["id,name,cake_id\n", "1,Apple,\n", "2,\"Line\nbreak\",3\n"]
+        );
+
+        let ndjson: Vec<String> = fruit::Entity::find()
+            .export(&db, ExportFormat::Ndjson)
+            .await?
+            .try_collect()
+            .await?;
+        assert_eq!(
+            ndjson,
+            [
+                "{\"cake_id\":null,\"id\":1,\"name\":\"Apple\"}\n",
+                "{\"cake_id\":3,\"id\":2,\"name\":\"Line\\nbreak\"}\n",
+            ]
+        );
+
+        let empty: Vec<String> = fruit::Entity::find()
+            .from_raw_sql(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT * FROM "fruit""#,
+            ))
+            .into_json()
+            .export(&db, ExportFormat::Csv)
+            .await?
+            .try_collect()
+            .await?;
+        assert!(empty.is_empty());
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, Insert,
-    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, SelectModel, SelectorRaw,
-    TryFromU64, TryInsert,
+    check_writable, error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend,
+    EntityTrait, Insert, InsertSelect, IntoActiveModel, Iterable, PrimaryKeyToColumn,
+    PrimaryKeyTrait, SelectModel, SelectorRaw, TryFromU64, TryInsert,
 };
 use sea_query::{FromValueTuple, Iden, InsertStatement, Query, ValueTuple};
 use std::{future::Future, marker::PhantomData};
@@ -115,6 +115,7 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        let writable = check_writable(A::Entity::default());
         // so that self is dropped before entering await
         let mut query = self.query;
         if db.support_returning() {
@@ -126,7 +127,11 @@ where
                 }));
             query.returning(returning);
         }
-        Inserter::<A>::new(self.primary_key, query).exec(db)
+        let exec = Inserter::<A>::new(self.primary_key, query).exec(db);
+        async move {
+            writable?;
+            exec.await
+        }
     }
 
     /// Execute an insert operation without returning (don't use `RETURNING` syntax)
@@ -140,7 +145,12 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db)
+        let writable = check_writable(A::Entity::default());
+        let exec = Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db);
+        async move {
+            writable?;
+            exec.await
+        }
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
@@ -153,7 +163,26 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
+        let writable = check_writable(A::Entity::default());
+        let exec = Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db);
+        async move {
+            writable?;
+            exec.await
+        }
+    }
+}
+
+impl<E> InsertSelect<E>
+where
+    E: EntityTrait,
+{
+    /// Execute an `INSERT INTO ... SELECT` operation, returning the number of rows inserted
+    pub async fn exec<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        check_writable(E::default())?;
+        exec_insert_without_returning(self.query, db).await
     }
 }
 
@@ -0,0 +1,346 @@
+use crate::{
+    error::*, join_condition, query::json_to_value, ActiveModelTrait, ColumnTrait, ConnectionTrait,
+    DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable, JsonValue, Related,
+    SelectJsonRelated, Statement, TryIntoModel,
+};
+use sea_query::{Alias, Expr, Func, JoinType, SelectStatement, SimpleExpr, Value};
+
+const RELATED_ALIAS: &str = "related_json";
+
+impl<E, F> SelectJsonRelated<E, F>
+where
+    E: EntityTrait + Related<F>,
+    F: EntityTrait,
+{
+    /// Build the statement, with the related Models aggregated into a JSON array column
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .find_with_related_json(fruit::Entity)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name","#,
+    ///         r#"(SELECT json_agg(json_build_object('id', "fruit"."id", 'name', "fruit"."name", 'cake_id', "fruit"."cake_id"))"#,
+    ///         r#"FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id") AS "related_json""#,
+    ///         r#"FROM "cake""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        let mut query = self.query.clone();
+        query.expr_as(
+            related_json_expr::<E, F>(db_backend),
+            Alias::new(RELATED_ALIAS),
+        );
+        db_backend.build(&query)
+    }
+
+    /// Get all Models, each paired with its related Models.
+    ///
+    /// The related Models are decoded from JSON objects keyed by column name,
+    /// converting each value according to the type of its column.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Sqlite)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "id" => Into::<Value>::into(1),
+    /// #         "name" => Into::<Value>::into("Apple Cake"),
+    /// #         "related_json" => Into::<Value>::into(serde_json::json!([
+    /// #             { "id": 2, "name": "Apple", "cake_id": 1 },
+    /// #         ])),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let cakes: Vec<(cake::Model, Vec<fruit::Model>)> = cake::Entity::find()
+    ///     .find_with_related_json(fruit::Entity)
+    ///     .all(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(cakes[0].0.name, "Apple Cake");
+    /// assert_eq!(cakes[0].1[0].name, "Apple");
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     [Transaction::from_sql_and_values(
+    ///         DbBackend::Sqlite,
+    ///         [
+    ///             r#"SELECT "cake"."id", "cake"."name","#,
+    ///             r#"(SELECT json_group_array(json_object('id', "fruit"."id", 'name', "fruit"."name", 'cake_id', "fruit"."cake_id"))"#,
+    ///             r#"FROM "fruit" WHERE "cake"."id" = "fruit"."cake_id") AS "related_json""#,
+    ///             r#"FROM "cake""#,
+    ///         ]
+    ///         .join(" ")
+    ///         .as_str(),
+    ///         []
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all<C>(self, db: &C) -> Result<Vec<(E::Model, Vec<F::Model>)>, DbErr>
+    where
+        C: ConnectionTrait,
+        F::ActiveModel: TryIntoModel<F::Model>,
+    {
+        let stmt = self.build(db.get_database_backend());
+        let rows = db.query_all(stmt).await?;
+        let mut models = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            let model = E::Model::from_query_result(row, "")?;
+            let related = match row.try_get::<Option<JsonValue>>("", RELATED_ALIAS)? {
+                // MySQL and Postgres aggregate an empty set into NULL
+                None | Some(JsonValue::Null) => Vec::new(),
+                // MySQL and SQLite may hand back the JSON document as a string
+                Some(JsonValue::String(s)) => {
+                    let json = serde_json::from_str(&s).map_err(|e| DbErr::Json(e.to_string()))?;
+                    related_models::<F>(json)?
+                }
+                Some(json) => related_models::<F>(json)?,
+            };
+            models.push((model, related));
+        }
+        Ok(models)
+    }
+}
+
+/// Decode a JSON array of objects keyed by column name into Models
+fn related_models<F>(json: JsonValue) -> Result<Vec<F::Model>, DbErr>
+where
+    F: EntityTrait,
+    F::ActiveModel: TryIntoModel<F::Model>,
+{
+    let JsonValue::Array(objects) = json else {
+        return Err(DbErr::Json(format!(
+            "expected an array of related rows, got {json}"
+        )));
+    };
+    objects
+        .iter()
+        .map(|object| {
+            let mut active_model = F::ActiveModel::default();
+            for col in F::Column::iter() {
+                let json = object.get(col.as_str()).ok_or_else(|| {
+                    DbErr::Json(format!("missing column `{}` in related row", col.as_str()))
+                })?;
+                active_model.set(col, json_to_value(col, json)?);
+            }
+            active_model.try_into_model()
+        })
+        .collect()
+}
+
+fn related_json_expr<E, F>(db_backend: DbBackend) -> SimpleExpr
+where
+    E: EntityTrait + Related<F>,
+    F: EntityTrait,
+{
+    let (agg_fn, object_fn) = match db_backend {
+        DbBackend::Postgres => ("json_agg", "json_build_object"),
+        DbBackend::MySql => ("JSON_ARRAYAGG", "JSON_OBJECT"),
+        DbBackend::Sqlite => ("json_group_array", "json_object"),
+    };
+    let mut args: Vec<SimpleExpr> = Vec::new();
+    for col in F::Column::iter() {
+        args.push(SimpleExpr::Constant(Value::from(col.as_str())));
+        args.push(Expr::col((col.entity_name(), col)).into());
+    }
+    let object = Func::cust(Alias::new(object_fn)).args(args);
+
+    let mut subquery = SelectStatement::new();
+    subquery
+        .expr(Func::cust(Alias::new(agg_fn)).arg(object))
+        .from(F::default().table_ref());
+    match E::via() {
+        Some(via) => {
+            let rel = E::to();
+            subquery
+                .join(
+                    JoinType::InnerJoin,
+                    rel.from_tbl.clone(),
+                    join_condition(rel),
+                )
+                .cond_where(join_condition(via));
+        }
+        None => {
+            subquery.cond_where(join_condition(E::to()));
+        }
+    }
+    SimpleExpr::SubQuery(None, Box::new(subquery.into_sub_query_statement()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, filling, fruit};
+    use crate::{entity::*, error::*, query::*, DbBackend};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn json_related_mysql() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_with_related_json(fruit::Entity)
+                .filter(cake::Column::Id.eq(1))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name`,",
+                "(SELECT JSON_ARRAYAGG(JSON_OBJECT('id', `fruit`.`id`, 'name', `fruit`.`name`, 'cake_id', `fruit`.`cake_id`))",
+                "FROM `fruit` WHERE `cake`.`id` = `fruit`.`cake_id`) AS `related_json`",
+                "FROM `cake` WHERE `cake`.`id` = 1",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn json_related_via() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_with_related_json(filling::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name","#,
+                r#"(SELECT json_agg(json_build_object('id', "filling"."id", 'name', "filling"."name", 'vendor_id', "filling"."vendor_id"))"#,
+                r#"FROM "filling" INNER JOIN "cake_filling" ON "cake_filling"."filling_id" = "filling"."id""#,
+                r#"WHERE "cake"."id" = "cake_filling"."cake_id") AS "related_json""#,
+                r#"FROM "cake""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn json_related_all() -> Result<(), DbErr> {
+        use crate::MockDatabase;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Apple Cake"),
+                    "related_json" => Into::<Value>::into(serde_json::json!([
+                        { "id": 1, "name": "Apple", "cake_id": 1 },
+                        { "id": 2, "name": "Pear", "cake_id": 1 },
+                    ])),
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(2),
+                    "name" => Into::<Value>::into("Plain Cake"),
+                    "related_json" => Value::Json(None),
+                },
+            ]])
+            .into_connection();
+
+        let cakes = cake::Entity::find()
+            .find_with_related_json(fruit::Entity)
+            .all(&db)
+            .await?;
+
+        assert_eq!(cakes.len(), 2);
+        assert_eq!(cakes[0].0.name, "Apple Cake");
+        assert_eq!(
+            cakes[0]
+                .1
+                .iter()
+                .map(|fruit| fruit.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Apple", "Pear"]
+        );
+        assert_eq!(cakes[0].1[1].id, 2);
+        assert_eq!(cakes[1].0.name, "Plain Cake");
+        assert!(cakes[1].1.is_empty());
+
+        Ok(())
+    }
+
+    mod baker {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+        #[sea_orm(table_name = "baker")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(column_name = "full_name")]
+            #[serde(rename = "fullName")]
+            pub name: String,
+            pub active: bool,
+            pub cake_id: Option<i32>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "crate::tests_cfg::cake::Entity",
+                from = "Column::CakeId",
+                to = "crate::tests_cfg::cake::Column::Id"
+            )]
+            Cake,
+        }
+
+        impl Related<crate::tests_cfg::cake::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::Cake.def()
+            }
+        }
+
+        impl Related<Entity> for crate::tests_cfg::cake::Entity {
+            fn to() -> RelationDef {
+                Relation::Cake.def().rev()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn json_related_renamed_column() -> Result<(), DbErr> {
+        use crate::MockDatabase;
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "id" => Into::<Value>::into(1),
+                "name" => Into::<Value>::into("Apple Cake"),
+                // MySQL hands back booleans as numbers and the document as a string
+                "related_json" => Into::<Value>::into(serde_json::json!(
+                    r#"[{ "id": 5, "full_name": "Alice", "active": 1, "cake_id": 1 }]"#
+                )),
+            }]])
+            .into_connection();
+
+        let cakes = cake::Entity::find()
+            .find_with_related_json(baker::Entity)
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            cakes[0].1,
+            [baker::Model {
+                id: 5,
+                name: "Alice".to_owned(),
+                active: true,
+                cake_id: Some(1),
+            }]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{error::*, ConnectionTrait, EntityTrait, MergeInto, TransactionTrait};
+
+/// The result of a MERGE operation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeResult {
+    /// The number of rows inserted, updated or deleted by the MERGE operation
+    pub rows_affected: u64,
+}
+
+impl<E> MergeInto<E>
+where
+    E: EntityTrait,
+{
+    /// Execute a MERGE operation. On MySQL and SQLite, where it is emulated by several statements,
+    /// they are run in a transaction, nested as a savepoint if the connection is a transaction.
+    pub async fn exec<C>(self, db: &C) -> Result<MergeResult, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let mut statements = self.build(db.get_database_backend())?;
+        if statements.len() == 1 {
+            let rows_affected = db.execute(statements.remove(0)).await?.rows_affected();
+            return Ok(MergeResult { rows_affected });
+        }
+        let txn = db.begin().await?;
+        let mut rows_affected = 0;
+        for statement in statements {
+            rows_affected += txn.execute(statement).await?.rows_affected();
+        }
+        txn.commit().await?;
+        Ok(MergeResult { rows_affected })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::tests_cfg::cake;
+    use crate::{
+        error::*, query::*, DbBackend, MockDatabase, MockExecResult, Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn merge_emulated() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 4,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let incoming = ValuesTable::new("incoming", ["id", "name"])
+            .rows([(1, "Cheese Cake"), (4, "Lemon Tart")]);
+        let result = Merge::target(cake::Entity)
+            .on(incoming.col("id").equals((cake::Entity, cake::Column::Id)))
+            .when_matched_update([(cake::Column::Name, incoming.col("name"))])
+            .when_not_matched_insert([
+                (cake::Column::Id, incoming.col("id")),
+                (cake::Column::Name, incoming.col("name")),
+            ])
+            .using_values(incoming)
+            .exec(&db)
+            .await?;
+
+        assert_eq!(result.rows_affected, 2);
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    [
+                        r#"UPDATE "cake" SET "name" = "incoming"."name""#,
+                        r#"FROM (SELECT "column1" AS "id", "column2" AS "name" FROM (VALUES (?, ?), (?, ?)) AS "t") AS "incoming""#,
+                        r#"WHERE "incoming"."id" = "cake"."id""#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [1i32.into(), "Cheese Cake".into(), 4i32.into(), "Lemon Tart".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    [
+                        r#"INSERT INTO "cake" ("id", "name") SELECT "incoming"."id", "incoming"."name""#,
+                        r#"FROM (SELECT "column1" AS "id", "column2" AS "name" FROM (VALUES (?, ?), (?, ?)) AS "t") AS "incoming""#,
+                        r#"WHERE NOT EXISTS(SELECT 1 FROM "cake" WHERE "incoming"."id" = "cake"."id")"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    [1i32.into(), "Cheese Cake".into(), 4i32.into(), "Lemon Tart".into()]
+                ),
+                Statement::from_string(DbBackend::Sqlite, "COMMIT"),
+            ])]
+        );
+
+        Ok(())
+    }
+}
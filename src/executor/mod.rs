@@ -1,17 +1,45 @@
+mod aggregate;
+#[cfg(feature = "with-arrow")]
+mod arrow;
+mod cancel;
+#[cfg(feature = "cursor-token")]
+mod connection;
+mod copy;
 mod cursor;
+#[cfg(feature = "cursor-token")]
+mod cursor_token;
 mod delete;
 mod execute;
+#[cfg(feature = "with-json")]
+mod export;
 mod insert;
+#[cfg(feature = "with-json")]
+mod json_related;
+mod merge;
 mod paginator;
+mod partition;
+mod pipeline;
 mod query;
 mod select;
+mod shard;
 mod update;
 
+#[cfg(feature = "with-arrow")]
+pub use arrow::*;
+pub use cancel::*;
+#[cfg(feature = "cursor-token")]
+pub use connection::*;
 pub use cursor::*;
 pub use delete::*;
 pub use execute::*;
+#[cfg(feature = "with-json")]
+pub use export::*;
 pub use insert::*;
+pub use merge::*;
 pub use paginator::*;
+pub use partition::*;
+pub use pipeline::*;
 pub use query::*;
 pub use select::*;
+pub use shard::*;
 pub use update::*;
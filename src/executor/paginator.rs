@@ -1,10 +1,10 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
-    SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
+    error::*, DbBackend, EntityTrait, FromQueryResult, ReadConnectionTrait, Select, SelectModel,
+    SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait, Statement,
 };
 use async_stream::stream;
 use futures::Stream;
-use sea_query::{Alias, Expr, SelectStatement};
+use sea_query::{Alias, Expr, Quote, SelectStatement, TableRef};
 use std::{marker::PhantomData, pin::Pin};
 
 /// Pin a Model so that stream operations can be performed on the model
@@ -14,13 +14,15 @@ pub type PinBoxStream<'db, Item> = Pin<Box<dyn Stream<Item = Item> + 'db>>;
 #[derive(Clone, Debug)]
 pub struct Paginator<'db, C, S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + 'db,
 {
     pub(crate) query: SelectStatement,
     pub(crate) page: u64,
     pub(crate) page_size: u64,
     pub(crate) db: &'db C,
+    pub(crate) table: Option<TableRef>,
+    pub(crate) count: Option<Statement>,
     pub(crate) selector: PhantomData<S>,
 }
 
@@ -37,7 +39,7 @@ pub struct ItemsAndPagesNumber {
 
 impl<'db, C, S> Paginator<'db, C, S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + 'db,
 {
     /// Fetch a specific page; page index starts from zero
@@ -48,9 +50,9 @@ where
             .limit(self.page_size)
             .offset(self.page_size * page)
             .to_owned();
-        let builder = self.db.get_database_backend();
+        let builder = self.db.backend();
         let stmt = builder.build(&query);
-        let rows = self.db.query_all(stmt).await?;
+        let rows = self.db.read_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
             // TODO: Error handling
@@ -64,9 +66,48 @@ where
         self.fetch_page(self.page).await
     }
 
+    /// Fetch a specific page, along with whether there is a page after it.
+    ///
+    /// One extra row is fetched instead of counting the rows of the query,
+    /// which is much cheaper on large tables when only a "next" link is needed.
+    pub async fn fetch_page_with_has_next(&self, page: u64) -> Result<(Vec<S::Item>, bool), DbErr> {
+        let query = self
+            .query
+            .clone()
+            .limit(self.page_size + 1)
+            .offset(self.page_size * page)
+            .to_owned();
+        let builder = self.db.backend();
+        let stmt = builder.build(&query);
+        let mut rows = self.db.read_all(stmt).await?;
+        let has_next = rows.len() as u64 > self.page_size;
+        rows.truncate(self.page_size as usize);
+        let mut buffer = Vec::with_capacity(rows.len());
+        for row in rows.into_iter() {
+            buffer.push(S::from_raw_query_result(row)?);
+        }
+        Ok((buffer, has_next))
+    }
+
+    /// Fetch the current page, along with whether there is a page after it
+    pub async fn fetch_with_has_next(&self) -> Result<(Vec<S::Item>, bool), DbErr> {
+        self.fetch_page_with_has_next(self.page).await
+    }
+
     /// Get the total number of items
     pub async fn num_items(&self) -> Result<u64, DbErr> {
-        let builder = self.db.get_database_backend();
+        let builder = self.db.backend();
+        if let Some(stmt) = &self.count {
+            let result = match self.db.read_one(stmt.clone()).await? {
+                Some(res) => res,
+                None => return Ok(0),
+            };
+            let num_items = match builder {
+                DbBackend::Postgres => result.try_get_by_index::<i64>(0)? as u64,
+                _ => result.try_get_by_index::<i32>(0)? as u64,
+            };
+            return Ok(num_items);
+        }
         let stmt = SelectStatement::new()
             .expr(Expr::cust("COUNT(*) AS num_items"))
             .from_subquery(
@@ -80,7 +121,7 @@ where
             )
             .to_owned();
         let stmt = builder.build(&stmt);
-        let result = match self.db.query_one(stmt).await? {
+        let result = match self.db.read_one(stmt).await? {
             Some(res) => res,
             None => return Ok(0),
         };
@@ -91,6 +132,58 @@ where
         Ok(num_items)
     }
 
+    /// Get an estimate of the total number of items from the table statistics,
+    /// which unlike [`Paginator::num_items`] does not scan the table.
+    ///
+    /// The estimate is the number of rows of the whole table, read from `pg_class.reltuples`
+    /// on Postgres and `information_schema.TABLES` on MySQL; filters of the query are not accounted for.
+    /// It falls back to [`Paginator::num_items`] on SQLite, when the statistics are not available yet,
+    /// or when the paginator is not created from [`Select`].
+    pub async fn num_items_estimated(&self) -> Result<u64, DbErr> {
+        let builder = self.db.backend();
+        let stmt = match (builder, &self.table) {
+            (DbBackend::Postgres, Some(table)) => pg_table_name(table).map(|name| {
+                Statement::from_sql_and_values(
+                    builder,
+                    "SELECT reltuples::bigint AS num_items FROM pg_class WHERE oid = to_regclass($1)",
+                    [name.into()],
+                )
+            }),
+            (DbBackend::MySql, Some(TableRef::Table(table))) => {
+                Some(Statement::from_sql_and_values(
+                    builder,
+                    "SELECT TABLE_ROWS AS num_items FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?",
+                    [table.to_string().into()],
+                ))
+            }
+            (DbBackend::MySql, Some(TableRef::SchemaTable(schema, table))) => {
+                Some(Statement::from_sql_and_values(
+                    builder,
+                    "SELECT TABLE_ROWS AS num_items FROM information_schema.TABLES WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+                    [schema.to_string().into(), table.to_string().into()],
+                ))
+            }
+            _ => None,
+        };
+        let estimate = match stmt {
+            Some(stmt) => match self.db.read_one(stmt).await? {
+                // `reltuples` is -1 until the table is first analyzed
+                Some(res) => match builder {
+                    DbBackend::Postgres => res
+                        .try_get::<Option<i64>>("", "num_items")?
+                        .and_then(|n| u64::try_from(n).ok()),
+                    _ => res.try_get::<Option<u64>>("", "num_items")?,
+                },
+                None => None,
+            },
+            None => None,
+        };
+        match estimate {
+            Some(num_items) => Ok(num_items),
+            None => self.num_items().await,
+        }
+    }
+
     /// Get the total number of pages
     pub async fn num_pages(&self) -> Result<u64, DbErr> {
         let num_items = self.num_items().await?;
@@ -206,11 +299,23 @@ where
     }
 }
 
+/// The quoted, schema qualified name of a table as accepted by `regclass` on Postgres
+fn pg_table_name(table: &TableRef) -> Option<String> {
+    let q = Quote::new(b'"');
+    match table {
+        TableRef::Table(table) => Some(format!(r#""{}""#, table.quoted(q))),
+        TableRef::SchemaTable(schema, table) => {
+            Some(format!(r#""{}"."{}""#, schema.quoted(q), table.quoted(q)))
+        }
+        _ => None,
+    }
+}
+
 #[async_trait::async_trait]
 /// A Trait for any type that can paginate results
 pub trait PaginatorTrait<'db, C>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
 {
     /// Select operation
     type Selector: SelectorTrait + Send + Sync + 'db;
@@ -229,7 +334,7 @@ where
 
 impl<'db, C, S> PaginatorTrait<'db, C> for Selector<S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + Send + Sync + 'db,
 {
     type Selector = S;
@@ -241,6 +346,8 @@ where
             page: 0,
             page_size,
             db,
+            table: None,
+            count: None,
             selector: PhantomData,
         }
     }
@@ -248,13 +355,18 @@ where
 
 impl<'db, C, S> PaginatorTrait<'db, C> for SelectorRaw<S>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     S: SelectorTrait + Send + Sync + 'db,
 {
     type Selector = S;
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, S> {
         assert!(page_size != 0, "page_size should not be zero");
-        let sql = self.stmt.sql.trim()[6..].trim();
+        let sql = self.stmt.sql.trim();
+        let sql = match sql.get(..6) {
+            Some(select) if select.eq_ignore_ascii_case("SELECT") => sql[6..].trim().to_owned(),
+            // e.g. a query starting with a `WITH` clause
+            _ => format!("* FROM ({sql}) AS raw_query"),
+        };
         let mut query = SelectStatement::new();
         query.expr(if let Some(values) = self.stmt.values {
             Expr::cust_with_values(sql, values.0)
@@ -267,27 +379,91 @@ where
             page: 0,
             page_size,
             db,
+            table: None,
+            count: None,
             selector: PhantomData,
         }
     }
 }
 
+impl<S> SelectorRaw<S>
+where
+    S: SelectorTrait + Send + Sync,
+{
+    /// Paginate the result of a raw SQL query, counting the items with the given statement
+    /// instead of wrapping the query in a `COUNT(*)`.
+    /// The statement should return the number of items as its first column.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "count" => Into::<Value>::into(8i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    /// let paginator = cake::Entity::find()
+    ///     .from_raw_sql(Statement::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "name" LIKE $1 ORDER BY "id""#,
+    ///         ["%cheese%".into()],
+    ///     ))
+    ///     .paginate_with_count(
+    ///         db,
+    ///         50,
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT COUNT(*) AS "count" FROM "cake" WHERE "name" LIKE $1"#,
+    ///             ["%cheese%".into()],
+    ///         ),
+    ///     );
+    ///
+    /// assert_eq!(paginator.num_pages().await?, 1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate_with_count<'db, C>(
+        self,
+        db: &'db C,
+        page_size: u64,
+        count: Statement,
+    ) -> Paginator<'db, C, S>
+    where
+        C: ReadConnectionTrait,
+        S: 'db,
+    {
+        let mut paginator = self.paginate(db, page_size);
+        paginator.count = Some(count);
+        paginator
+    }
+}
+
 impl<'db, C, M, E> PaginatorTrait<'db, C> for Select<E>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     E: EntityTrait<Model = M>,
     M: FromQueryResult + Sized + Send + Sync + 'db,
 {
     type Selector = SelectModel<M>;
 
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, Self::Selector> {
-        self.into_model().paginate(db, page_size)
+        let mut paginator = self.into_model().paginate(db, page_size);
+        paginator.table = Some(E::default().table_ref());
+        paginator
     }
 }
 
 impl<'db, C, M, N, E, F> PaginatorTrait<'db, C> for SelectTwo<E, F>
 where
-    C: ConnectionTrait,
+    C: ReadConnectionTrait,
     E: EntityTrait<Model = M>,
     F: EntityTrait<Model = N>,
     M: FromQueryResult + Sized + Send + Sync + 'db,
@@ -772,6 +948,187 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn fetch_page_raw_with_clause() -> Result<(), DbErr> {
+        let (db, pages) = setup();
+
+        let raw_stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"WITH "berry" AS (SELECT * FROM "fruit" WHERE "name" LIKE $1) SELECT * FROM "berry""#,
+            ["%berry".into()],
+        );
+
+        let paginator = fruit::Entity::find()
+            .from_raw_sql(raw_stmt)
+            .paginate(&db, 2);
+
+        assert_eq!(paginator.fetch_page(1).await?, pages[0].clone());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT * FROM (WITH "berry" AS (SELECT * FROM "fruit" WHERE "name" LIKE $1)"#,
+                    r#"SELECT * FROM "berry") AS raw_query LIMIT $2 OFFSET $3"#,
+                ]
+                .join(" "),
+                ["%berry".into(), 2u64.into(), 2u64.into()]
+            )]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn num_items_raw_with_count() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "count" => Into::<Value>::into(5i64),
+            }]])
+            .into_connection();
+
+        let count_stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT COUNT(*) AS "count" FROM "fruit""#,
+            [],
+        );
+
+        let paginator = fruit::Entity::find()
+            .from_raw_sql(RAW_STMT.clone())
+            .paginate_with_count(&db, 2, count_stmt.clone());
+
+        assert_eq!(paginator.num_items_and_pages().await?.number_of_pages, 3);
+
+        assert_eq!(db.into_transaction_log(), [Transaction::one(count_stmt)]);
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn fetch_page_with_has_next() -> Result<(), DbErr> {
+        let (db, pages) = setup();
+
+        let paginator = fruit::Entity::find().paginate(&db, 1);
+
+        let (page, has_next) = paginator.fetch_page_with_has_next(0).await?;
+        assert_eq!(page, pages[0][..1]);
+        assert!(has_next);
+
+        let (page, has_next) = paginator.fetch_page_with_has_next(1).await?;
+        assert_eq!(page, pages[1]);
+        assert!(!has_next);
+
+        let mut select = SelectStatement::new()
+            .exprs([
+                Expr::col((fruit::Entity, fruit::Column::Id)),
+                Expr::col((fruit::Entity, fruit::Column::Name)),
+                Expr::col((fruit::Entity, fruit::Column::CakeId)),
+            ])
+            .from(fruit::Entity)
+            .to_owned();
+
+        let query_builder = db.get_database_backend();
+        let stmts = [
+            query_builder.build(select.clone().offset(0).limit(2)),
+            query_builder.build(select.offset(1).limit(2)),
+        ];
+
+        assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn num_items_estimated() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<Value>::into(100_000_000i64),
+            }]])
+            .into_connection();
+
+        let paginator = fruit::Entity::find().paginate(&db, 2);
+        assert_eq!(paginator.num_items_estimated().await?, 100_000_000);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT reltuples::bigint AS num_items FROM pg_class WHERE oid = to_regclass($1)",
+                [r#""fruit""#.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "num_items" => Into::<Value>::into(42u64),
+            }]])
+            .into_connection();
+
+        let paginator = fruit::Entity::find().paginate(&db, 2);
+        assert_eq!(paginator.num_items_estimated().await?, 42);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                "SELECT TABLE_ROWS AS num_items FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?",
+                ["fruit".into()]
+            )]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn num_items_estimated_fallback() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([
+                [maplit::btreemap! {
+                    "num_items" => Into::<Value>::into(-1i64),
+                }],
+                [maplit::btreemap! {
+                    "num_items" => Into::<Value>::into(3i64),
+                }],
+                [maplit::btreemap! {
+                    "num_items" => Into::<Value>::into(3i64),
+                }],
+            ])
+            .into_connection();
+
+        let paginator = fruit::Entity::find().paginate(&db, 2);
+        assert_eq!(paginator.num_items_estimated().await?, 3);
+
+        let paginator = fruit::Entity::find()
+            .from_raw_sql(RAW_STMT.clone())
+            .paginate(&db, 2);
+        assert_eq!(paginator.num_items_estimated().await?, 3);
+
+        let sub_query = SelectStatement::new()
+            .exprs([
+                Expr::col((fruit::Entity, fruit::Column::Id)),
+                Expr::col((fruit::Entity, fruit::Column::Name)),
+                Expr::col((fruit::Entity, fruit::Column::CakeId)),
+            ])
+            .from(fruit::Entity)
+            .to_owned();
+
+        let select = SelectStatement::new()
+            .expr(Expr::cust("COUNT(*) AS num_items"))
+            .from_subquery(sub_query, Alias::new("sub_query"))
+            .to_owned();
+
+        let query_builder = db.get_database_backend();
+        let stmts = [
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT reltuples::bigint AS num_items FROM pg_class WHERE oid = to_regclass($1)",
+                [r#""fruit""#.into()],
+            ),
+            query_builder.build(&select),
+            query_builder.build(&select),
+        ];
+
+        assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
+        Ok(())
+    }
+
     #[smol_potat::test]
     #[should_panic]
     async fn error() {
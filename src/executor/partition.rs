@@ -0,0 +1,293 @@
+use crate::{
+    error::*, ColumnTrait, ColumnType, ConnectionTrait, DbBackend, EntityTrait, IdenStatic,
+    Iterable, PrimaryKeyToColumn, QueryFilter, QueryResult, Select, StreamTrait,
+};
+use futures::{stream, Stream, TryStreamExt};
+use sea_query::{Alias, Expr, SelectStatement, Value};
+use std::pin::Pin;
+
+/// A stream of Models of one partition, see [`Select::stream_partitioned`]
+pub type PartitionStream<'b, M> = Pin<Box<dyn Stream<Item = Result<M, DbErr>> + 'b + Send>>;
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Split the rows into up to `partitions` streams by ranges of the primary key, for processing
+    /// a large table in parallel. Each stream runs its own query when it's first polled, which on a
+    /// [`crate::DatabaseConnection`] holds its own connection of the pool until the stream ends.
+    ///
+    /// Polling more streams at once than the pool has connections waits for a connection which is
+    /// never released, so drive at most that many at once: merge them with
+    /// [`futures::StreamExt::flatten_unordered`] and a limit, rather than
+    /// [`futures::stream::select_all`] which polls all of them.
+    ///
+    /// The ranges are evenly spaced between the smallest and largest key matched by the query,
+    /// so they only hold a similar number of rows if the keys are evenly spread.
+    /// The Entity must have a single integer primary key.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results([[maplit::btreemap! {
+    /// #         "min" => Into::<Value>::into(1i64),
+    /// #         "max" => Into::<Value>::into(1i64),
+    /// #     }]])
+    /// #     .append_query_results([[cake::Model { id: 1, name: "Cake".to_owned() }]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use futures::{stream, StreamExt, TryStreamExt};
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let partitions = cake::Entity::find().stream_partitioned(db, 16).await?;
+    /// // Run up to 4 queries at once
+    /// let mut cakes = stream::iter(partitions).flatten_unordered(4);
+    ///
+    /// while let Some(cake) = cakes.try_next().await? {
+    ///     // Do something with cake: cake::Model
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_partitioned<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        partitions: u64,
+    ) -> Result<Vec<PartitionStream<'b, E::Model>>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        if partitions == 0 {
+            return Err(DbErr::Custom(
+                "Number of partitions should not be zero".to_owned(),
+            ));
+        }
+        let mut keys = E::PrimaryKey::iter();
+        let col = match (keys.next(), keys.next()) {
+            (Some(key), None) => key.into_column(),
+            _ => {
+                return Err(DbErr::Custom(
+                    "Partitioning requires a single column primary key".to_owned(),
+                ))
+            }
+        };
+        if !matches!(
+            col.def().get_column_type(),
+            ColumnType::TinyInteger
+                | ColumnType::SmallInteger
+                | ColumnType::Integer
+                | ColumnType::BigInteger
+                | ColumnType::TinyUnsigned
+                | ColumnType::SmallUnsigned
+                | ColumnType::Unsigned
+                | ColumnType::BigUnsigned
+        ) {
+            return Err(DbErr::Custom(format!(
+                "Partitioning requires an integer primary key, `{}` is not",
+                col.as_str()
+            )));
+        }
+
+        let db_backend = db.get_database_backend();
+        let bound = |expr: Expr| match db_backend {
+            DbBackend::Postgres => expr.cast_as(Alias::new("bigint")),
+            _ => expr.into(),
+        };
+        let stmt = SelectStatement::new()
+            .expr_as(
+                bound(Expr::expr(Expr::col(Alias::new("pk")).min())),
+                Alias::new("min"),
+            )
+            .expr_as(
+                bound(Expr::expr(Expr::col(Alias::new("pk")).max())),
+                Alias::new("max"),
+            )
+            .from_subquery(
+                self.query
+                    .clone()
+                    .clear_selects()
+                    .expr_as(Expr::col((E::default(), col)), Alias::new("pk"))
+                    .clear_order_by()
+                    .to_owned(),
+                Alias::new("sub_query"),
+            )
+            .to_owned();
+        // the keys of a MySQL `BIGINT UNSIGNED` can exceed the range of `i64`
+        let unsigned = db_backend == DbBackend::MySql
+            && matches!(
+                col.def().get_column_type(),
+                ColumnType::TinyUnsigned
+                    | ColumnType::SmallUnsigned
+                    | ColumnType::Unsigned
+                    | ColumnType::BigUnsigned
+            );
+        let get = |row: &QueryResult, name: &str| -> Result<Option<i128>, DbErr> {
+            if unsigned {
+                Ok(row.try_get::<Option<u64>>("", name)?.map(i128::from))
+            } else {
+                Ok(row.try_get::<Option<i64>>("", name)?.map(i128::from))
+            }
+        };
+        let (min, max) = match db.query_one(db_backend.build(&stmt)).await? {
+            Some(row) => (get(&row, "min")?, get(&row, "max")?),
+            None => (None, None),
+        };
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Ok(Vec::new()),
+        };
+
+        let partitions = partitions as i128;
+        let step = ((max - min + 1) + partitions - 1) / partitions;
+        let mut streams = Vec::new();
+        let mut start = min;
+        while start <= max {
+            let end = start + step;
+            let query = self.clone().filter(col.gte(key_value(start)));
+            let query = if end > max {
+                query.filter(col.lte(key_value(max)))
+            } else {
+                query.filter(col.lt(key_value(end)))
+            };
+            let stream = stream::once(query.stream(db)).try_flatten();
+            streams.push(Box::pin(stream) as PartitionStream<'b, E::Model>);
+            start = end;
+        }
+        Ok(streams)
+    }
+}
+
+/// A key within the range of the keys, which are `i64` or `u64`
+fn key_value(key: i128) -> Value {
+    match i64::try_from(key) {
+        Ok(key) => key.into(),
+        Err(_) => (key as u64).into(),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::tests_cfg::fruit;
+    use crate::{entity::*, error::*, query::*, DbBackend, MockDatabase, Transaction};
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    fn fruit_model(id: i32) -> fruit::Model {
+        fruit::Model {
+            id,
+            name: format!("Fruit {id}"),
+            cake_id: Some(1),
+        }
+    }
+
+    #[smol_potat::test]
+    async fn stream_partitioned() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "min" => Into::<Value>::into(1i64),
+                "max" => Into::<Value>::into(10i64),
+            }]])
+            .append_query_results([
+                vec![fruit_model(1), fruit_model(4)],
+                vec![fruit_model(5)],
+                vec![fruit_model(9), fruit_model(10)],
+            ])
+            .into_connection();
+
+        let streams = fruit::Entity::find()
+            .filter(fruit::Column::CakeId.eq(1))
+            .order_by_asc(fruit::Column::Id)
+            .stream_partitioned(&db, 3)
+            .await?;
+        assert_eq!(streams.len(), 3);
+
+        let mut ids = Vec::new();
+        for stream in streams {
+            let fruits: Vec<_> = stream.try_collect().await?;
+            ids.extend(fruits.into_iter().map(|fruit| fruit.id));
+        }
+        assert_eq!(ids, [1, 4, 5, 9, 10]);
+
+        let select = [
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+            r#"WHERE "fruit"."cake_id" = $1 AND "fruit"."id" >= $2 AND "fruit"."id""#,
+        ]
+        .join(" ");
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT CAST(MIN("pk") AS bigint) AS "min", CAST(MAX("pk") AS bigint) AS "max""#,
+                        r#"FROM (SELECT "fruit"."id" AS "pk" FROM "fruit" WHERE "fruit"."cake_id" = $1) AS "sub_query""#,
+                    ]
+                    .join(" "),
+                    [1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(r#"{select} < $3 ORDER BY "fruit"."id" ASC"#),
+                    [1i32.into(), 1i64.into(), 5i64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(r#"{select} < $3 ORDER BY "fruit"."id" ASC"#),
+                    [1i32.into(), 5i64.into(), 9i64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(r#"{select} <= $3 ORDER BY "fruit"."id" ASC"#),
+                    [1i32.into(), 9i64.into(), 10i64.into()]
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn stream_partitioned_lazy() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[maplit::btreemap! {
+                "min" => Into::<Value>::into(1i64),
+                "max" => Into::<Value>::into(100i64),
+            }]])
+            .into_connection();
+
+        let streams = fruit::Entity::find().stream_partitioned(&db, 10).await?;
+        assert_eq!(streams.len(), 10);
+        drop(streams);
+
+        // Only the range of the keys is queried until the streams are polled
+        assert_eq!(db.into_transaction_log().len(), 1);
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn stream_partitioned_empty() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results([[maplit::btreemap! {
+                "min" => Value::BigInt(None),
+                "max" => Value::BigInt(None),
+            }]])
+            .into_connection();
+
+        assert!(fruit::Entity::find()
+            .stream_partitioned(&db, 4)
+            .await?
+            .is_empty());
+        assert!(fruit::Entity::find()
+            .stream_partitioned(&db, 0)
+            .await
+            .is_err());
+        Ok(())
+    }
+}
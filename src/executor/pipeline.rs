@@ -0,0 +1,197 @@
+use crate::{
+    error::*, ConnectionTrait, EntityTrait, QueryResult, Select, SelectModel, Selector,
+    SelectorTrait,
+};
+use sea_query::SelectStatement;
+use std::marker::PhantomData;
+
+/// A batch of independent queries, run one after the other over one connection, e.g. for a
+/// dashboard which shows the results of many small queries.
+///
+/// Each query takes a round trip, so a pipeline does not make the queries faster than running
+/// them in turn. The only exception is a batch on PostgreSQL in which no query has values:
+/// it is sent in one round trip, as a multi-statement simple query.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[cake::Model {
+/// #         id: 1,
+/// #         name: "Cheese Cake".to_owned(),
+/// #     }]])
+/// #     .append_query_results([[fruit::Model {
+/// #         id: 2,
+/// #         name: "Apple".to_owned(),
+/// #         cake_id: Some(1),
+/// #     }]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, Pipeline};
+///
+/// let mut pipeline = Pipeline::new();
+/// let cakes = pipeline.add(cake::Entity::find());
+/// let fruits = pipeline.add(fruit::Entity::find().filter(fruit::Column::CakeId.eq(1)));
+///
+/// let mut results = pipeline.exec(&db).await?;
+/// assert_eq!(results.take(cakes)?[0].name, "Cheese Cake");
+/// assert_eq!(results.take(fruits)?[0].name, "Apple");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Pipeline {
+    queries: Vec<SelectStatement>,
+}
+
+/// The key of a query added to a [`Pipeline`], to take its rows from the [`PipelineResults`]
+#[derive(Debug)]
+pub struct PipelineKey<S>
+where
+    S: SelectorTrait,
+{
+    index: usize,
+    selector: PhantomData<S>,
+}
+
+/// The rows returned by the queries of a [`Pipeline`]
+#[derive(Debug)]
+pub struct PipelineResults {
+    results: Vec<Option<Vec<QueryResult>>>,
+}
+
+impl Pipeline {
+    /// Create an empty pipeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a query returning the Models of an Entity
+    pub fn add<E>(&mut self, select: Select<E>) -> PipelineKey<SelectModel<E::Model>>
+    where
+        E: EntityTrait,
+    {
+        self.add_selector(select.into_model())
+    }
+
+    /// Add a query returning any type, e.g. a partial model or a tuple
+    pub fn add_selector<S>(&mut self, selector: Selector<S>) -> PipelineKey<S>
+    where
+        S: SelectorTrait,
+    {
+        self.queries.push(selector.query);
+        PipelineKey {
+            index: self.queries.len() - 1,
+            selector: PhantomData,
+        }
+    }
+
+    /// Run the queries, returning their rows
+    pub async fn exec<C>(self, db: &C) -> Result<PipelineResults, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if self.queries.is_empty() {
+            return Ok(PipelineResults {
+                results: Vec::new(),
+            });
+        }
+        let db_backend = db.get_database_backend();
+        let stmts = self
+            .queries
+            .iter()
+            .map(|query| db_backend.build(query))
+            .collect();
+        let results = db.query_pipeline(stmts).await?;
+        Ok(PipelineResults {
+            results: results.into_iter().map(Some).collect(),
+        })
+    }
+}
+
+impl PipelineResults {
+    /// Take the rows of a query. They can only be taken once.
+    pub fn take<S>(&mut self, key: PipelineKey<S>) -> Result<Vec<S::Item>, DbErr>
+    where
+        S: SelectorTrait,
+    {
+        self.results
+            .get_mut(key.index)
+            .and_then(Option::take)
+            .ok_or_else(|| {
+                DbErr::Custom(format!(
+                    "The rows of the query {} of the pipeline were already taken",
+                    key.index
+                ))
+            })?
+            .into_iter()
+            .map(S::from_raw_query_result)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{entity::*, error::*, query::*, DbBackend, MockDatabase, Pipeline, Transaction};
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeMap;
+
+    #[smol_potat::test]
+    async fn pipeline() -> Result<(), DbErr> {
+        let cheese = cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cheese.clone()]])
+            .append_query_results([Vec::<fruit::Model>::new()])
+            .append_query_results([[BTreeMap::from([(
+                "name".to_owned(),
+                Value::from("Cheese Cake"),
+            )])]])
+            .into_connection();
+
+        let mut pipeline = Pipeline::new();
+        let cakes = pipeline.add(cake::Entity::find().filter(cake::Column::Id.eq(1)));
+        let fruits = pipeline.add(fruit::Entity::find());
+        let names = pipeline.add_selector(
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Name)
+                .into_tuple::<String>(),
+        );
+        let mut results = pipeline.exec(&db).await?;
+        assert_eq!(results.take(fruits)?, []);
+        assert_eq!(results.take(names)?, ["Cheese Cake"]);
+        assert_eq!(results.take(cakes)?, [cheese]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1"#,
+                    [1.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    []
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."name" FROM "cake""#,
+                    []
+                ),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@ use sqlx::Row;
 #[derive(Debug)]
 pub struct QueryResult {
     pub(crate) row: QueryResultRow,
+    pub(crate) timezone: crate::TimezonePolicy,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -75,6 +76,12 @@ impl From<DbErr> for TryGetError {
 // QueryResult //
 
 impl QueryResult {
+    /// Read the timestamps without a time zone with the policy of the connection
+    pub(crate) fn with_timezone(mut self, timezone: crate::TimezonePolicy) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
     /// Get a value from the query result with an ColIdx
     pub fn try_get_by<T, I>(&self, index: I) -> Result<T, DbErr>
     where
@@ -363,53 +370,15 @@ macro_rules! try_getable_unsigned {
     };
 }
 
-macro_rules! try_getable_mysql {
-    ( $type: ty ) => {
-        impl TryGetable for $type {
-            #[allow(unused_variables)]
-            fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
-                match &res.row {
-                    #[cfg(feature = "sqlx-mysql")]
-                    QueryResultRow::SqlxMySql(row) => row
-                        .try_get::<Option<$type>, _>(idx.as_sqlx_mysql_index())
-                        .map_err(|e| sqlx_error_to_query_err(e).into())
-                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
-                    #[cfg(feature = "sqlx-postgres")]
-                    QueryResultRow::SqlxPostgres(_) => Err(type_err(format!(
-                        "{} unsupported by sqlx-postgres",
-                        stringify!($type)
-                    ))
-                    .into()),
-                    #[cfg(feature = "sqlx-sqlite")]
-                    QueryResultRow::SqlxSqlite(_) => Err(type_err(format!(
-                        "{} unsupported by sqlx-sqlite",
-                        stringify!($type)
-                    ))
-                    .into()),
-                    #[cfg(feature = "mock")]
-                    QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
-                        debug_print!("{:#?}", e.to_string());
-                        err_null_idx_col(idx)
-                    }),
-                    #[cfg(feature = "proxy")]
-                    QueryResultRow::Proxy(row) => row.try_get(idx).map_err(|e| {
-                        debug_print!("{:#?}", e.to_string());
-                        err_null_idx_col(idx)
-                    }),
-                    #[allow(unreachable_patterns)]
-                    _ => unreachable!(),
-                }
-            }
-        }
-    };
-}
-
 #[allow(unused_macros)]
 macro_rules! try_getable_date_time {
     ( $type: ty ) => {
         impl TryGetable for $type {
             #[allow(unused_variables)]
             fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+                if let Some(v) = try_get_naive_date_time(res, idx)? {
+                    return Ok(v.into());
+                }
                 match &res.row {
                     #[cfg(feature = "sqlx-mysql")]
                     QueryResultRow::SqlxMySql(row) => {
@@ -450,6 +419,74 @@ macro_rules! try_getable_date_time {
     };
 }
 
+/// Read a timestamp without a time zone as an instant, if the [crate::TimezonePolicy]
+/// of the connection applies to the column
+#[cfg(feature = "with-chrono")]
+#[allow(unused_imports, unused_variables, unreachable_code)]
+fn try_get_naive_date_time<I: ColIdx>(
+    res: &QueryResult,
+    idx: I,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>, TryGetError> {
+    use crate::NaiveTimezone;
+    use chrono::NaiveDateTime;
+
+    let naive: Option<NaiveDateTime> = match &res.row {
+        #[cfg(feature = "sqlx-postgres")]
+        QueryResultRow::SqlxPostgres(row) => {
+            use sqlx::{Column, TypeInfo};
+            let is_naive = row
+                .try_column(idx.as_sqlx_postgres_index())
+                .map(|col| col.type_info().name() == "TIMESTAMP")
+                .unwrap_or(false);
+            if !(is_naive && res.timezone.utc_as_naive) {
+                return Ok(None);
+            }
+            row.try_get(idx.as_sqlx_postgres_index())
+                .map_err(sqlx_error_to_query_err)?
+        }
+        #[cfg(feature = "sqlx-mysql")]
+        QueryResultRow::SqlxMySql(row) => {
+            // the `time_zone` of the session is local, for `timestamp` columns too
+            if res.timezone.naive == NaiveTimezone::Utc {
+                return Ok(None);
+            }
+            row.try_get(idx.as_sqlx_mysql_index())
+                .map_err(sqlx_error_to_query_err)?
+        }
+        #[cfg(feature = "sqlx-sqlite")]
+        QueryResultRow::SqlxSqlite(row) => {
+            if res.timezone.naive == NaiveTimezone::Utc {
+                return Ok(None);
+            }
+            match row.try_get::<Option<String>, _>(idx.as_sqlx_sqlite_index()) {
+                Ok(Some(text)) => match parse_naive_date_time(&text) {
+                    Some(naive) => Some(naive),
+                    // the text has an offset
+                    None => return Ok(None),
+                },
+                Ok(None) => None,
+                // a timestamp stored as a number
+                Err(_) => return Ok(None),
+            }
+        }
+        #[allow(unreachable_patterns)]
+        _ => return Ok(None),
+    };
+    let naive = naive.ok_or_else(|| err_null_idx_col(idx))?;
+    res.timezone
+        .utc_of(naive)
+        .map(Some)
+        .map_err(TryGetError::DbErr)
+}
+
+/// Parse the text of a SQLite timestamp without an offset
+#[cfg(all(feature = "with-chrono", feature = "sqlx-sqlite"))]
+fn parse_naive_date_time(text: &str) -> Option<chrono::NaiveDateTime> {
+    ["%F %T%.f", "%F %R", "%FT%T%.f", "%FT%R"]
+        .iter()
+        .find_map(|format| chrono::NaiveDateTime::parse_from_str(text, format).ok())
+}
+
 try_getable_all!(bool);
 try_getable_all!(i8);
 try_getable_all!(i16);
@@ -457,7 +494,6 @@ try_getable_all!(i32);
 try_getable_all!(i64);
 try_getable_unsigned!(u8);
 try_getable_unsigned!(u16);
-try_getable_mysql!(u64);
 try_getable_all!(f32);
 try_getable_all!(f64);
 try_getable_all!(String);
@@ -479,10 +515,10 @@ try_getable_all!(chrono::NaiveDateTime);
 try_getable_date_time!(chrono::DateTime<chrono::FixedOffset>);
 
 #[cfg(feature = "with-chrono")]
-try_getable_all!(chrono::DateTime<chrono::Utc>);
+try_getable_date_time!(chrono::DateTime<chrono::Utc>);
 
 #[cfg(feature = "with-chrono")]
-try_getable_all!(chrono::DateTime<chrono::Local>);
+try_getable_date_time!(chrono::DateTime<chrono::Local>);
 
 #[cfg(feature = "with-time")]
 try_getable_all!(time::Date);
@@ -496,6 +532,32 @@ try_getable_all!(time::PrimitiveDateTime);
 #[cfg(feature = "with-time")]
 try_getable_all!(time::OffsetDateTime);
 
+/// The text of a decimal read from SQLite, which stores it as an integer, a real or a text;
+/// a real is formatted as the shortest text that reads back to the same value
+#[cfg(all(
+    feature = "sqlx-sqlite",
+    any(feature = "with-rust_decimal", feature = "with-bigdecimal")
+))]
+fn sqlite_decimal_text<I: ColIdx>(
+    row: &sqlx::sqlite::SqliteRow,
+    idx: I,
+) -> Result<Option<String>, TryGetError> {
+    use sqlx::{Decode, TypeInfo, ValueRef};
+
+    let value = row
+        .try_get_raw(idx.as_sqlx_sqlite_index())
+        .map_err(sqlx_error_to_query_err)?;
+    if value.is_null() {
+        return Ok(None);
+    }
+    let text = match value.type_info().name() {
+        "TEXT" => <String as Decode<sqlx::Sqlite>>::decode(value).map(Some),
+        "INTEGER" => <i64 as Decode<sqlx::Sqlite>>::decode(value).map(|v| Some(v.to_string())),
+        _ => <f64 as Decode<sqlx::Sqlite>>::decode(value).map(|v| Some(v.to_string())),
+    };
+    text.map_err(|e| sqlx_error_to_query_err(sqlx::Error::Decode(e)).into())
+}
+
 #[cfg(feature = "with-rust_decimal")]
 use rust_decimal::Decimal;
 
@@ -515,22 +577,17 @@ impl TryGetable for Decimal {
                 .map_err(|e| sqlx_error_to_query_err(e).into())
                 .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
             #[cfg(feature = "sqlx-sqlite")]
-            QueryResultRow::SqlxSqlite(row) => {
-                let val: Option<f64> = row
-                    .try_get(idx.as_sqlx_sqlite_index())
-                    .map_err(sqlx_error_to_query_err)?;
-                match val {
-                    Some(v) => Decimal::try_from(v).map_err(|e| {
-                        DbErr::TryIntoErr {
-                            from: "f64",
-                            into: "Decimal",
-                            source: Box::new(e),
-                        }
-                        .into()
-                    }),
-                    None => Err(err_null_idx_col(idx)),
-                }
-            }
+            QueryResultRow::SqlxSqlite(row) => match sqlite_decimal_text(row, idx)? {
+                Some(text) => text.parse::<Decimal>().map_err(|e| {
+                    DbErr::TryIntoErr {
+                        from: "String",
+                        into: "Decimal",
+                        source: Box::new(e),
+                    }
+                    .into()
+                }),
+                None => Err(err_null_idx_col(idx)),
+            },
             #[cfg(feature = "mock")]
             #[allow(unused_variables)]
             QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -568,22 +625,17 @@ impl TryGetable for BigDecimal {
                 .map_err(|e| sqlx_error_to_query_err(e).into())
                 .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
             #[cfg(feature = "sqlx-sqlite")]
-            QueryResultRow::SqlxSqlite(row) => {
-                let val: Option<f64> = row
-                    .try_get(idx.as_sqlx_sqlite_index())
-                    .map_err(sqlx_error_to_query_err)?;
-                match val {
-                    Some(v) => BigDecimal::try_from(v).map_err(|e| {
-                        DbErr::TryIntoErr {
-                            from: "f64",
-                            into: "BigDecimal",
-                            source: Box::new(e),
-                        }
-                        .into()
-                    }),
-                    None => Err(err_null_idx_col(idx)),
-                }
-            }
+            QueryResultRow::SqlxSqlite(row) => match sqlite_decimal_text(row, idx)? {
+                Some(text) => text.parse::<BigDecimal>().map_err(|e| {
+                    DbErr::TryIntoErr {
+                        from: "String",
+                        into: "BigDecimal",
+                        source: Box::new(e),
+                    }
+                    .into()
+                }),
+                None => Err(err_null_idx_col(idx)),
+            },
             #[cfg(feature = "mock")]
             #[allow(unused_variables)]
             QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
@@ -660,6 +712,66 @@ try_getable_uuid!(uuid::fmt::Simple, uuid::Uuid::simple);
 #[cfg(feature = "with-uuid")]
 try_getable_uuid!(uuid::fmt::Urn, uuid::Uuid::urn);
 
+// Network addresses are native types in Postgres, and stored as text in MySQL and SQLite
+#[allow(unused_macros)]
+macro_rules! try_getable_network {
+    ( $type: ty ) => {
+        impl TryGetable for $type {
+            #[allow(unused_variables)]
+            fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+                #[allow(dead_code)]
+                fn parse<I: ColIdx>(text: Option<String>, idx: I) -> Result<$type, TryGetError> {
+                    text.ok_or_else(|| err_null_idx_col(idx))?
+                        .parse()
+                        .map_err(|e| {
+                            DbErr::TryIntoErr {
+                                from: "String",
+                                into: stringify!($type),
+                                source: Box::new(e),
+                            }
+                            .into()
+                        })
+                }
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => row
+                        .try_get::<Option<String>, _>(idx.as_sqlx_mysql_index())
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|text| parse(text, idx)),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => row
+                        .try_get::<Option<$type>, _>(idx.as_sqlx_postgres_index())
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx))),
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => row
+                        .try_get::<Option<String>, _>(idx.as_sqlx_sqlite_index())
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|text| parse(text, idx)),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    }),
+                    #[cfg(feature = "proxy")]
+                    QueryResultRow::Proxy(row) => row.try_get(idx).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        err_null_idx_col(idx)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "with-ipnetwork")]
+try_getable_network!(ipnetwork::IpNetwork);
+
+#[cfg(feature = "with-mac_address")]
+try_getable_network!(mac_address::MacAddress);
+
 impl TryGetable for u32 {
     #[allow(unused_variables)]
     fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
@@ -702,6 +814,83 @@ impl TryGetable for u32 {
     }
 }
 
+impl TryGetable for u64 {
+    #[allow(unused_variables)]
+    fn try_get_by<I: ColIdx>(res: &QueryResult, idx: I) -> Result<Self, TryGetError> {
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::{Column, TypeInfo};
+                // a `BIGINT UNSIGNED` is read in full range, and a signed integer
+                // like a `COUNT(*)` if it's not negative
+                let signed = row
+                    .try_column(idx.as_sqlx_mysql_index())
+                    .map(|col| {
+                        matches!(
+                            col.type_info().name(),
+                            "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INT" | "BIGINT"
+                        )
+                    })
+                    .unwrap_or(false);
+                if !signed {
+                    return row
+                        .try_get::<Option<u64>, _>(idx.as_sqlx_mysql_index())
+                        .map_err(|e| sqlx_error_to_query_err(e).into())
+                        .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)));
+                }
+                row.try_get::<Option<i64>, _>(idx.as_sqlx_mysql_index())
+                    .map_err(|e| sqlx_error_to_query_err(e).into())
+                    .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
+                    .and_then(|n| {
+                        u64::try_from(n).map_err(|e| {
+                            DbErr::TryIntoErr {
+                                from: stringify!(i64),
+                                into: stringify!(u64),
+                                source: Box::new(e),
+                            }
+                            .into()
+                        })
+                    })
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                // PostgreSQL has no unsigned types; a `u64` is stored in a `bigint`
+                row.try_get::<Option<i64>, _>(idx.as_sqlx_postgres_index())
+                    .map_err(|e| sqlx_error_to_query_err(e).into())
+                    .and_then(|opt| opt.ok_or_else(|| err_null_idx_col(idx)))
+                    .and_then(|n| {
+                        u64::try_from(n).map_err(|e| {
+                            DbErr::TryIntoErr {
+                                from: stringify!(i64),
+                                into: stringify!(u64),
+                                source: Box::new(e),
+                            }
+                            .into()
+                        })
+                    })
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(_) => {
+                Err(type_err(format!("{} unsupported by sqlx-sqlite", stringify!(u64))).into())
+            }
+            #[cfg(feature = "mock")]
+            #[allow(unused_variables)]
+            QueryResultRow::Mock(row) => row.try_get(idx).map_err(|e| {
+                debug_print!("{:#?}", e.to_string());
+                err_null_idx_col(idx)
+            }),
+            #[cfg(feature = "proxy")]
+            #[allow(unused_variables)]
+            QueryResultRow::Proxy(row) => row.try_get(idx).map_err(|e| {
+                debug_print!("{:#?}", e.to_string());
+                err_null_idx_col(idx)
+            }),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[allow(dead_code)]
 fn err_null_idx_col<I: ColIdx>(idx: I) -> TryGetError {
     TryGetError::Null(format!("{idx:?}"))
@@ -803,6 +992,12 @@ mod postgres_array {
     #[cfg(feature = "with-bigdecimal")]
     try_getable_postgres_array!(bigdecimal::BigDecimal);
 
+    #[cfg(feature = "with-ipnetwork")]
+    try_getable_postgres_array!(ipnetwork::IpNetwork);
+
+    #[cfg(feature = "with-mac_address")]
+    try_getable_postgres_array!(mac_address::MacAddress);
+
     #[allow(unused_macros)]
     macro_rules! try_getable_postgres_array_uuid {
         ( $type: ty, $conversion_fn: expr ) => {
@@ -1396,6 +1591,7 @@ mod tests {
         );
         let query_result = QueryResult {
             row: QueryResultRow::Mock(crate::MockRow { values }),
+            timezone: Default::default(),
         };
         assert_eq!(
             query_result.column_names(),
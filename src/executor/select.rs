@@ -1,13 +1,19 @@
 use crate::{
     error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable,
     ModelTrait, PartialModelTrait, PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait,
-    QueryResult, QuerySelect, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement,
-    StreamTrait, TryGetableMany,
+    QueryResult, QuerySelect, ReadConnectionTrait, Select, SelectA, SelectB, SelectTwo,
+    SelectTwoMany, Statement, StreamTrait, TransactionTrait, TryGetableMany,
 };
+use async_stream::try_stream;
 use futures::{Stream, TryStreamExt};
 use sea_query::{SelectStatement, Value};
 use std::collections::HashMap;
-use std::{hash::Hash, marker::PhantomData, pin::Pin};
+use std::{
+    hash::Hash,
+    marker::PhantomData,
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 #[cfg(feature = "with-json")]
 use crate::JsonValue;
@@ -414,7 +420,7 @@ where
     /// Get one Model from the SELECT query
     pub async fn one<'a, C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().one(db).await
     }
@@ -422,7 +428,7 @@ where
     /// Get all Models from the SELECT query
     pub async fn all<'a, C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().all(db).await
     }
@@ -433,18 +439,33 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<E::Model, DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
 
+    /// Stream the results of a SELECT operation on a Model, reading `fetch_size` rows at a time
+    /// from a server-side cursor on Postgres, see [`SelectorRaw::stream_with_fetch_size`]
+    pub async fn stream_with_fetch_size<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        fetch_size: u64,
+    ) -> Result<impl Stream<Item = Result<E::Model, DbErr>> + 'b + Send, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + TransactionTrait + Send,
+    {
+        self.into_model()
+            .stream_with_fetch_size(db, fetch_size)
+            .await
+    }
+
     /// Stream the result of the operation with PartialModel
     pub async fn stream_partial_model<'a: 'b, 'b, C, M>(
         self,
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<M, DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
     {
         self.into_partial_model().stream(db).await
@@ -492,7 +513,7 @@ where
     /// Get one Model from the Select query
     pub async fn one<'a, C>(self, db: &C) -> Result<Option<(E::Model, Option<F::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().one(db).await
     }
@@ -500,7 +521,7 @@ where
     /// Get all Models from the Select query
     pub async fn all<'a, C>(self, db: &C) -> Result<Vec<(E::Model, Option<F::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_model().all(db).await
     }
@@ -511,7 +532,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(E::Model, Option<F::Model>), DbErr>> + 'b, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
@@ -522,7 +543,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(M, Option<N>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
         N: PartialModelTrait + Send + 'b,
     {
@@ -574,7 +595,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(E::Model, Option<F::Model>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
     {
         self.into_model().stream(db).await
     }
@@ -585,7 +606,7 @@ where
         db: &'a C,
     ) -> Result<impl Stream<Item = Result<(M, Option<N>), DbErr>> + 'b + Send, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         M: PartialModelTrait + Send + 'b,
         N: PartialModelTrait + Send + 'b,
     {
@@ -602,7 +623,7 @@ where
     /// > See https://www.sea-ql.org/SeaORM/docs/basic-crud/select#lazy-loading for details.
     pub async fn all<'a, C>(self, db: &C) -> Result<Vec<(E::Model, Vec<F::Model>)>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         let rows = self.into_model().all(db).await?;
         Ok(consolidate_query_result::<E, F>(rows))
@@ -651,9 +672,9 @@ where
 
     fn into_selector_raw<C>(self, db: &C) -> SelectorRaw<S>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        let builder = db.get_database_backend();
+        let builder = db.backend();
         let stmt = builder.build(&self.query);
         SelectorRaw {
             stmt,
@@ -669,7 +690,7 @@ where
     /// Get an item from the Select query
     pub async fn one<'a, C>(mut self, db: &C) -> Result<Option<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.query.limit(1);
         self.into_selector_raw(db).one(db).await
@@ -678,7 +699,7 @@ where
     /// Get all items from the Select query
     pub async fn all<'a, C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
         self.into_selector_raw(db).all(db).await
     }
@@ -689,12 +710,29 @@ where
         db: &'a C,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<S::Item, DbErr>> + 'b + Send>>, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         S: 'b,
         S::Item: Send,
     {
         self.into_selector_raw(db).stream(db).await
     }
+
+    /// Stream the results of the Select operation, reading `fetch_size` rows at a time
+    /// from a server-side cursor on Postgres, see [`SelectorRaw::stream_with_fetch_size`]
+    pub async fn stream_with_fetch_size<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        fetch_size: u64,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<S::Item, DbErr>> + 'b + Send>>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + TransactionTrait + Send,
+        S: 'b,
+        S::Item: Send,
+    {
+        self.into_selector_raw(db)
+            .stream_with_fetch_size(db, fetch_size)
+            .await
+    }
 }
 
 impl<S> SelectorRaw<S>
@@ -913,9 +951,9 @@ where
     /// ```
     pub async fn one<'a, C>(self, db: &C) -> Result<Option<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        let row = db.query_one(self.stmt).await?;
+        let row = db.read_one(self.stmt).await?;
         match row {
             Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
             None => Ok(None),
@@ -964,9 +1002,9 @@ where
     /// ```
     pub async fn all<'a, C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where
-        C: ConnectionTrait,
+        C: ReadConnectionTrait,
     {
-        let rows = db.query_all(self.stmt).await?;
+        let rows = db.read_all(self.stmt).await?;
         let mut models = Vec::new();
         for row in rows.into_iter() {
             models.push(S::from_raw_query_result(row)?);
@@ -980,7 +1018,7 @@ where
         db: &'a C,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<S::Item, DbErr>> + 'b + Send>>, DbErr>
     where
-        C: ConnectionTrait + StreamTrait + Send,
+        C: ReadConnectionTrait + StreamTrait + Send,
         S: 'b,
         S::Item: Send,
     {
@@ -989,8 +1027,95 @@ where
             futures::future::ready(S::from_raw_query_result(row))
         })))
     }
+
+    /// Stream the results of the Select operation, reading `fetch_size` rows at a time
+    /// so that the result set is never held in memory as a whole.
+    ///
+    /// On Postgres, the query is run as a server-side cursor with `DECLARE` and read with `FETCH`,
+    /// inside a transaction (or a savepoint if `db` is a transaction) which lasts until the stream ends.
+    /// Only one such stream can be open at a time in a transaction.
+    /// On other backends, this is the same as [`SelectorRaw::stream`].
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let owned_db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results([MockExecResult::default(), MockExecResult::default()])
+    /// #     .append_query_results([vec![cake::Model { id: 1, name: "Cake".to_owned() }], vec![]])
+    /// #     .into_connection();
+    /// # let db = &owned_db;
+    /// #
+    /// use futures::TryStreamExt;
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let mut stream = cake::Entity::find()
+    ///     .order_by_asc(cake::Column::Id)
+    ///     .stream_with_fetch_size(db, 1000)
+    ///     .await?;
+    ///
+    /// while let Some(cake) = stream.try_next().await? {
+    ///     // Do something with cake: cake::Model
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_with_fetch_size<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+        fetch_size: u64,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<S::Item, DbErr>> + 'b + Send>>, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + TransactionTrait + Send,
+        S: 'b,
+        S::Item: Send,
+    {
+        if fetch_size == 0 {
+            return Err(DbErr::Custom("Fetch size should not be zero".to_owned()));
+        }
+        let db_backend = db.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return self.stream(db).await;
+        }
+        // A cursor lives until the end of its transaction, which may be shared with other streams
+        let cursor = format!(
+            "sea_orm_cursor_{}",
+            NEXT_CURSOR_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let txn = db.begin().await?;
+        txn.execute(Statement {
+            sql: format!("DECLARE {cursor} NO SCROLL CURSOR FOR {}", self.stmt.sql),
+            ..self.stmt
+        })
+        .await?;
+        let fetch = Statement::from_string(
+            db_backend,
+            format!("FETCH FORWARD {fetch_size} FROM {cursor}"),
+        );
+        Ok(Box::pin(try_stream! {
+            loop {
+                let rows = txn.query_all(fetch.clone()).await?;
+                if rows.is_empty() {
+                    break;
+                }
+                for row in rows {
+                    yield S::from_raw_query_result(row)?;
+                }
+            }
+            txn.execute(Statement::from_string(db_backend, format!("CLOSE {cursor}")))
+                .await?;
+            txn.commit().await?;
+        }))
+    }
 }
 
+/// The number of the next cursor declared by [`SelectorRaw::stream_with_fetch_size`]
+static NEXT_CURSOR_ID: AtomicU64 = AtomicU64::new(0);
+
 #[allow(clippy::unwrap_used)]
 fn consolidate_query_result<L, R>(
     rows: Vec<(L::Model, Option<R::Model>)>,
@@ -1818,4 +1943,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    pub async fn stream_with_fetch_size() -> Result<(), sea_orm::DbErr> {
+        use futures::TryStreamExt;
+        use sea_orm::tests_cfg::*;
+        use sea_orm::{
+            DbBackend, EntityTrait, MockDatabase, MockExecResult, QueryOrder, Statement,
+            Transaction,
+        };
+
+        let expected_log = |cursor: &str| {
+            let fetch = Statement::from_string(
+                DbBackend::Postgres,
+                format!("FETCH FORWARD 2 FROM {cursor}"),
+            );
+            [Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"DECLARE {cursor} NO SCROLL CURSOR FOR SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."id" ASC"#
+                    ),
+                    [],
+                ),
+                fetch.clone(),
+                fetch.clone(),
+                fetch,
+                Statement::from_string(DbBackend::Postgres, format!("CLOSE {cursor}")),
+                Statement::from_string(DbBackend::Postgres, "COMMIT"),
+            ])]
+        };
+
+        // Each stream declares a cursor of its own
+        let id = super::NEXT_CURSOR_ID.load(std::sync::atomic::Ordering::Relaxed);
+        for cursor in [id, id + 1].map(|id| format!("sea_orm_cursor_{id}")) {
+            let db = MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results([MockExecResult::default(), MockExecResult::default()])
+                .append_query_results([
+                    vec![cake_model(1), cake_model(2)],
+                    vec![cake_model(3)],
+                    vec![],
+                ])
+                .into_connection();
+
+            let cakes: Vec<_> = Cake::find()
+                .order_by_asc(cake::Column::Id)
+                .stream_with_fetch_size(&db, 2)
+                .await?
+                .try_collect()
+                .await?;
+
+            assert_eq!(cakes, [cake_model(1), cake_model(2), cake_model(3)]);
+            assert_eq!(db.into_transaction_log(), expected_log(&cursor));
+        }
+
+        Ok(())
+    }
 }
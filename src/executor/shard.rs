@@ -0,0 +1,108 @@
+use crate::{error::*, EntityTrait, Select, ShardedDatabase, Value};
+use futures::future::try_join_all;
+
+/// A select query bound to the shard of a key, see [`Select::shard`]
+#[derive(Clone, Debug)]
+pub struct ShardedSelect<E>
+where
+    E: EntityTrait,
+{
+    select: Select<E>,
+    key: Value,
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Run the query on the shard of a key of a [`ShardedDatabase`]
+    pub fn shard<K>(self, key: K) -> ShardedSelect<E>
+    where
+        K: Into<Value>,
+    {
+        ShardedSelect {
+            select: self,
+            key: key.into(),
+        }
+    }
+
+    /// Run the query on every shard of a [`ShardedDatabase`] concurrently,
+    /// and concatenate the models in the order of the shards.
+    /// An `ORDER BY` or a `LIMIT` applies to the models of each shard.
+    pub async fn all_shards(self, db: &ShardedDatabase) -> Result<Vec<E::Model>, DbErr> {
+        let models = try_join_all(db.shards().iter().map(|shard| self.clone().all(shard))).await?;
+        Ok(models.into_iter().flatten().collect())
+    }
+}
+
+impl<E> ShardedSelect<E>
+where
+    E: EntityTrait,
+{
+    /// Get one Model from the shard of the key
+    pub async fn one(self, db: &ShardedDatabase) -> Result<Option<E::Model>, DbErr> {
+        self.select.one(db.shard(self.key)?).await
+    }
+
+    /// Get all Models from the shard of the key
+    pub async fn all(self, db: &ShardedDatabase) -> Result<Vec<E::Model>, DbErr> {
+        self.select.all(db.shard(self.key)?).await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, ShardedDatabase, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn sharded_select() -> Result<(), DbErr> {
+        let fruit = |id| fruit::Model {
+            id,
+            name: "Apple".to_owned(),
+            cake_id: Some(id),
+        };
+        let db = ShardedDatabase::new(vec![
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([vec![fruit(2)], vec![fruit(4), fruit(6)]])
+                .into_connection(),
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([vec![fruit(3)]])
+                .into_connection(),
+        ]);
+
+        assert_eq!(fruit::Entity::find().shard(2).all(&db).await?, [fruit(2)]);
+        assert_eq!(
+            fruit::Entity::find().all_shards(&db).await?,
+            [fruit(4), fruit(6), fruit(3)]
+        );
+        assert!(std::ptr::eq(
+            db.shard_of(&fruit(3), fruit::Column::CakeId)?,
+            &db.shards()[1]
+        ));
+        assert!(fruit::Entity::find().shard(1.5).all(&db).await.is_err());
+
+        let find = Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+            [],
+        );
+        let logs: Vec<_> = db
+            .shards()
+            .iter()
+            .map(|shard| {
+                shard
+                    .as_mock_connection()
+                    .get_mocker_mutex()
+                    .lock()
+                    .map(|mut mocker| mocker.drain_transaction_log())
+                    .unwrap_or_default()
+            })
+            .collect();
+        assert_eq!(logs, [vec![find.clone(), find.clone()], vec![find]]);
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne,
+    check_writable, error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait,
+    IntoActiveModel, Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany,
+    UpdateManyFrom, UpdateOne,
 };
 use sea_query::{FromValueTuple, Query, UpdateStatement};
 
@@ -28,6 +29,7 @@ where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
     {
+        check_writable(A::Entity::default())?;
         Updater::new(self.query)
             .exec_update_and_return_updated(self.model, db)
             .await
@@ -43,6 +45,7 @@ where
     where
         C: ConnectionTrait,
     {
+        check_writable(E::default())?;
         Updater::new(self.query).exec(db).await
     }
 
@@ -55,12 +58,34 @@ where
     where
         C: ConnectionTrait,
     {
+        check_writable(E::default())?;
         Updater::new(self.query)
             .exec_update_with_returning::<E, _>(db)
             .await
     }
 }
 
+impl<E> UpdateManyFrom<E>
+where
+    E: EntityTrait,
+{
+    /// Execute an update operation on multiple ActiveModels
+    pub async fn exec<C>(self, db: &C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        check_writable(E::default())?;
+        if self.values.is_empty() {
+            return Ok(UpdateResult::default());
+        }
+        let statement = self.build(db.get_database_backend());
+        let result = db.execute(statement).await?;
+        Ok(UpdateResult {
+            rows_affected: result.rows_affected(),
+        })
+    }
+}
+
 impl Updater {
     /// Instantiate an update using an [UpdateStatement]
     pub fn new(query: UpdateStatement) -> Self {
@@ -356,4 +381,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn update_many_from_related() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let result = Update::many_from(fruit::Entity)
+            .from_related(cake::Entity)
+            .col_expr(
+                fruit::Column::Name,
+                Expr::col((cake::Entity, cake::Column::Name)).into(),
+            )
+            .filter(cake::Column::Id.eq(1))
+            .exec(&db)
+            .await?;
+
+        assert_eq!(result, UpdateResult { rows_affected: 2 });
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "UPDATE `fruit`, `cake` SET `fruit`.`name` = `cake`.`name`",
+                    "WHERE `fruit`.`cake_id` = `cake`.`id` AND `cake`.`id` = ?",
+                ]
+                .join(" ")
+                .as_str(),
+                [1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,176 @@
+//! A transaction per request for [actix-web](https://docs.rs/actix-web)
+//!
+//! ```
+//! # #[cfg(feature = "tests-cfg")]
+//! # mod example {
+//! use actix_web::{middleware::from_fn, web, App, HttpResponse};
+//! use sea_orm::{
+//!     entity::*,
+//!     integration::{actix::transaction_per_request, RequestTransaction},
+//!     tests_cfg::cake,
+//!     DatabaseConnection,
+//! };
+//!
+//! async fn delete_cakes(txn: RequestTransaction) -> actix_web::Result<HttpResponse> {
+//!     cake::Entity::delete_many()
+//!         .exec(&txn)
+//!         .await
+//!         .map_err(actix_web::error::ErrorInternalServerError)?;
+//!     Ok(HttpResponse::NoContent().finish())
+//! }
+//!
+//! fn configure(cfg: &mut web::ServiceConfig, db: DatabaseConnection) {
+//!     cfg.app_data(web::Data::new(db)).service(
+//!         web::resource("/cakes/delete")
+//!             .wrap(from_fn(transaction_per_request))
+//!             .route(web::post().to(delete_cakes)),
+//!     );
+//! }
+//! # }
+//! ```
+
+use super::RequestTransaction;
+use crate::DatabaseConnection;
+use actix_web::{
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::ErrorInternalServerError,
+    middleware::Next,
+    web, Error, FromRequest, HttpMessage, HttpRequest,
+};
+use std::future::{ready, Ready};
+
+/// A middleware which begins a [`RequestTransaction`] before running the handler,
+/// to be used with [`actix_web::middleware::from_fn`].
+/// The [`DatabaseConnection`] is taken from the app data, as a `web::Data<DatabaseConnection>`.
+///
+/// The transaction is committed unless the response is a client or server error.
+/// If the transaction can not be begun or committed, the handler fails with an internal server error.
+pub async fn transaction_per_request(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let db = request
+        .app_data::<web::Data<DatabaseConnection>>()
+        .ok_or_else(|| ErrorInternalServerError("The DatabaseConnection is not in the app data"))?
+        .clone();
+    let transaction = RequestTransaction::begin(&db)
+        .await
+        .map_err(ErrorInternalServerError)?;
+    request.extensions_mut().insert(transaction.clone());
+
+    let response = next.call(request).await;
+    let commit = match &response {
+        Ok(response) => {
+            // The request, and hence its extensions, lives as long as the response
+            response
+                .request()
+                .extensions_mut()
+                .remove::<RequestTransaction>();
+            let status = response.status();
+            !status.is_client_error() && !status.is_server_error()
+        }
+        Err(_) => false,
+    };
+    match transaction.end(commit).await {
+        Ok(()) => response,
+        Err(err) if commit => Err(ErrorInternalServerError(err)),
+        Err(err) => {
+            tracing::warn!("Failed to roll back the request transaction: {err}");
+            response
+        }
+    }
+}
+
+impl FromRequest for RequestTransaction {
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(request.extensions().get::<Self>().cloned().ok_or_else(|| {
+            ErrorInternalServerError(
+                "The route is not wrapped by the `transaction_per_request` middleware",
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, MockExecResult, Statement,
+        Transaction,
+    };
+    use actix_web::{http::StatusCode, middleware::from_fn, test, App, HttpResponse};
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    async fn delete_fruits(txn: RequestTransaction) -> Result<HttpResponse, Error> {
+        let res = fruit::Entity::delete_many()
+            .exec(&txn)
+            .await
+            .map_err(ErrorInternalServerError)?;
+        if res.rows_affected > 1 {
+            Ok(HttpResponse::Conflict().finish())
+        } else {
+            Ok(HttpResponse::NoContent().finish())
+        }
+    }
+
+    #[actix_rt::test]
+    async fn transaction_per_request() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+            ])
+            .append_exec_errors([DbErr::Custom("failed".to_owned())])
+            .into_connection();
+        let db = web::Data::new(db);
+        let app = test::init_service(
+            App::new()
+                .app_data(db.clone())
+                .wrap(from_fn(super::transaction_per_request))
+                .route("/", web::post().to(delete_fruits)),
+        )
+        .await;
+
+        for status in [
+            StatusCode::NO_CONTENT,
+            StatusCode::CONFLICT,
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ] {
+            let request = test::TestRequest::post().uri("/").to_request();
+            let response = test::call_service(&app, request).await;
+            assert_eq!(response.status(), status);
+        }
+        drop(app);
+        let db = Arc::try_unwrap(db.into_inner())
+            .map_err(|_| DbErr::Custom("db is shared".to_owned()))?;
+
+        let transaction = |end| {
+            Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(DbBackend::Postgres, r#"DELETE FROM "fruit""#, []),
+                Statement::from_string(DbBackend::Postgres, end),
+            ])
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                transaction("COMMIT"),
+                transaction("ROLLBACK"),
+                transaction("ROLLBACK")
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//! A transaction per request for [axum](https://docs.rs/axum)
+//!
+//! ```
+//! # #[cfg(all(feature = "tests-cfg", not(feature = "mock")))]
+//! # mod example {
+//! use axum::{middleware, routing::post, Router};
+//! use sea_orm::{
+//!     entity::*,
+//!     integration::{axum::transaction_per_request, RequestTransaction},
+//!     tests_cfg::cake,
+//!     DatabaseConnection,
+//! };
+//!
+//! async fn delete_cakes(txn: RequestTransaction) -> Result<(), String> {
+//!     cake::Entity::delete_many()
+//!         .exec(&txn)
+//!         .await
+//!         .map_err(|e| e.to_string())?;
+//!     Ok(())
+//! }
+//!
+//! fn router(db: DatabaseConnection) -> Router {
+//!     Router::new()
+//!         .route("/cakes/delete", post(delete_cakes))
+//!         .layer(middleware::from_fn_with_state(
+//!             db,
+//!             transaction_per_request::<DatabaseConnection>,
+//!         ))
+//! }
+//! # }
+//! ```
+
+use super::RequestTransaction;
+use crate::DatabaseConnection;
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request, State},
+    http::{request::Parts, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::borrow::Borrow;
+
+/// A middleware which begins a [`RequestTransaction`] before running the handler,
+/// to be used with [`axum::middleware::from_fn_with_state`].
+/// The state is the [`DatabaseConnection`], or anything which borrows it, such as an `Arc<DatabaseConnection>`.
+///
+/// The transaction is committed unless the response is a client or server error.
+/// If the transaction can not be begun or committed, the response is an internal server error.
+pub async fn transaction_per_request<D>(
+    State(db): State<D>,
+    mut request: Request,
+    next: Next,
+) -> Response
+where
+    D: Borrow<DatabaseConnection> + Clone + Send + Sync + 'static,
+{
+    let transaction = match RequestTransaction::begin(db.borrow()).await {
+        Ok(transaction) => transaction,
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    };
+    request.extensions_mut().insert(transaction.clone());
+
+    let response = next.run(request).await;
+    let status = response.status();
+    let commit = !status.is_client_error() && !status.is_server_error();
+    match transaction.end(commit).await {
+        Ok(()) => response,
+        Err(err) if commit => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        Err(err) => {
+            tracing::warn!("Failed to roll back the request transaction: {err}");
+            response
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequestTransaction
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<Self>().cloned().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "The route is not wrapped by the `transaction_per_request` middleware",
+        ))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::*, error::*, tests_cfg::*, DbBackend, MockDatabase, MockExecResult, Statement,
+        Transaction,
+    };
+    use axum::{body::Body, middleware, routing::post, Router};
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn delete_fruits(txn: RequestTransaction) -> StatusCode {
+        match fruit::Entity::delete_many().exec(&txn).await {
+            Ok(res) if res.rows_affected > 1 => StatusCode::CONFLICT,
+            Ok(_) => StatusCode::NO_CONTENT,
+            Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    #[tokio::test]
+    async fn transaction_per_request() -> Result<(), DbErr> {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results([
+                    MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected: 1,
+                    },
+                    MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected: 2,
+                    },
+                ])
+                .into_connection(),
+        );
+        let router =
+            Router::new()
+                .route("/", post(delete_fruits))
+                .layer(middleware::from_fn_with_state(
+                    Arc::clone(&db),
+                    super::transaction_per_request::<Arc<DatabaseConnection>>,
+                ));
+
+        for status in [StatusCode::NO_CONTENT, StatusCode::CONFLICT] {
+            let request = axum::http::Request::post("/")
+                .body(Body::empty())
+                .map_err(|e| DbErr::Custom(e.to_string()))?;
+            let response = router
+                .clone()
+                .oneshot(request)
+                .await
+                .map_err(|e| DbErr::Custom(e.to_string()))?;
+            assert_eq!(response.status(), status);
+        }
+        drop(router);
+        let db = Arc::try_unwrap(db).map_err(|_| DbErr::Custom("db is shared".to_owned()))?;
+
+        let transaction = |end| {
+            Transaction::many([
+                Statement::from_string(DbBackend::Postgres, "BEGIN"),
+                Statement::from_sql_and_values(DbBackend::Postgres, r#"DELETE FROM "fruit""#, []),
+                Statement::from_string(DbBackend::Postgres, end),
+            ])
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            [transaction("COMMIT"), transaction("ROLLBACK")]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(feature = "with-actix-web")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-actix-web")))]
+pub mod actix;
+#[cfg(feature = "with-axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-axum")))]
+pub mod axum;
+
+use crate::{
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, ExecResult,
+    QueryResult, Statement, TransactionTrait,
+};
+use std::{ops::Deref, sync::Arc};
+
+/// A transaction which lasts as long as an HTTP request, extracted in a handler.
+///
+/// The transaction is begun by the middleware of the web framework before the handler runs.
+/// It is committed if the response is not a client or server error, and rolled back otherwise.
+/// If the handler panics, the transaction is dropped and thus rolled back.
+#[derive(Clone, Debug)]
+pub struct RequestTransaction(Arc<DatabaseTransaction>);
+
+impl RequestTransaction {
+    pub(crate) async fn begin(db: &DatabaseConnection) -> Result<Self, DbErr> {
+        Ok(Self(Arc::new(db.begin().await?)))
+    }
+
+    /// Commit or roll back the transaction, once every clone of it has been dropped
+    pub(crate) async fn end(self, commit: bool) -> Result<(), DbErr> {
+        match Arc::try_unwrap(self.0) {
+            Ok(transaction) if commit => transaction.commit().await,
+            Ok(transaction) => transaction.rollback().await,
+            Err(_) => Err(DbErr::Custom(
+                "The request transaction is still in use after the response".to_owned(),
+            )),
+        }
+    }
+}
+
+impl Deref for RequestTransaction {
+    type Target = DatabaseTransaction;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for RequestTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.0.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.0.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.0.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.0.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.0.query_all(stmt).await
+    }
+
+    async fn query_pipeline(&self, stmts: Vec<Statement>) -> Result<Vec<Vec<QueryResult>>, DbErr> {
+        self.0.query_pipeline(stmts).await
+    }
+
+    async fn copy_in_raw(
+        &self,
+        statement: &str,
+        data: &mut (dyn Iterator<Item = Result<Vec<u8>, DbErr>> + Send),
+    ) -> Result<u64, DbErr> {
+        self.0.copy_in_raw(statement, data).await
+    }
+
+    async fn copy_out_raw(&self, statement: &str) -> Result<crate::CopyOutStream<'_>, DbErr> {
+        self.0.copy_out_raw(statement).await
+    }
+}
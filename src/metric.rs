@@ -1,7 +1,15 @@
+#[cfg(feature = "sqlx-dep")]
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    RwLock,
+};
 use std::{sync::Arc, time::Duration};
 
 pub(crate) type Callback = Arc<dyn Fn(&Info<'_>) + Send + Sync>;
 
+#[cfg(feature = "sqlx-dep")]
+pub(crate) type PoolCallback = Arc<dyn Fn(&PoolEvent) + Send + Sync>;
+
 #[allow(unused_imports)]
 pub(crate) use inner::metric;
 
@@ -16,6 +24,123 @@ pub struct Info<'a> {
     pub failed: bool,
 }
 
+/// The statistics of a connection pool, see [`crate::DatabaseConnection::pool_stats`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolStats {
+    /// The number of open connections, idle or in use
+    pub size: u32,
+    /// The maximum number of open connections
+    pub max_size: u32,
+    /// The number of idle connections
+    pub idle: usize,
+    /// The number of connections in use
+    pub in_use: usize,
+    /// The time spent waiting to acquire a connection, including failed attempts
+    pub acquire_wait: Histogram,
+}
+
+/// A histogram of durations, with a fixed set of buckets
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Histogram {
+    /// The upper bound of each bucket, and the number of samples in it which exceed the bound of the
+    /// previous bucket. The last bucket is unbounded, with an upper bound of [`Duration::MAX`].
+    pub buckets: Vec<(Duration, u64)>,
+    /// The number of samples
+    pub count: u64,
+    /// The sum of all samples
+    pub sum: Duration,
+}
+
+/// An event of a connection pool, see [`crate::DatabaseConnection::set_pool_callback`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoolEvent {
+    /// A connection was acquired from the pool, or the attempt failed
+    Acquire {
+        /// The time spent waiting for the connection
+        wait: Duration,
+        /// Acquiring the connection failed
+        failed: bool,
+    },
+    /// A connection was released back to the pool
+    Release,
+}
+
+#[cfg(feature = "sqlx-dep")]
+const BUCKETS: [Duration; 9] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::MAX,
+];
+
+/// The acquire wait times of a pool, and the callback subscribed to its events
+#[cfg(feature = "sqlx-dep")]
+#[derive(Default)]
+pub(crate) struct PoolMetrics {
+    acquire_wait: [AtomicU64; BUCKETS.len()],
+    acquire_wait_micros: AtomicU64,
+    callback: RwLock<Option<PoolCallback>>,
+}
+
+#[cfg(feature = "sqlx-dep")]
+impl PoolMetrics {
+    pub(crate) fn set_callback(&self, callback: PoolCallback) {
+        if let Ok(mut current) = self.callback.write() {
+            *current = Some(callback);
+        }
+    }
+
+    pub(crate) fn record_acquire(&self, wait: Duration, failed: bool) {
+        let bucket = BUCKETS.iter().position(|bound| wait <= *bound);
+        let bucket = bucket.unwrap_or(BUCKETS.len() - 1);
+        self.acquire_wait[bucket].fetch_add(1, Ordering::Relaxed);
+        self.acquire_wait_micros
+            .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+        self.emit(&PoolEvent::Acquire { wait, failed });
+    }
+
+    pub(crate) fn record_release(&self) {
+        self.emit(&PoolEvent::Release);
+    }
+
+    fn emit(&self, event: &PoolEvent) {
+        let callback = match self.callback.read() {
+            Ok(callback) => callback.clone(),
+            Err(_) => None,
+        };
+        if let Some(callback) = callback {
+            callback(event);
+        }
+    }
+
+    pub(crate) fn acquire_wait(&self) -> Histogram {
+        let buckets: Vec<_> = BUCKETS
+            .iter()
+            .zip(&self.acquire_wait)
+            .map(|(bound, count)| (*bound, count.load(Ordering::Relaxed)))
+            .collect();
+        Histogram {
+            count: buckets.iter().map(|(_, count)| count).sum(),
+            sum: Duration::from_micros(self.acquire_wait_micros.load(Ordering::Relaxed)),
+            buckets,
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-dep")]
+impl std::fmt::Debug for PoolMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PoolMetrics")
+            .field("acquire_wait", &self.acquire_wait())
+            .finish()
+    }
+}
+
 mod inner {
     #[allow(unused_macros)]
     macro_rules! metric {
@@ -35,3 +160,55 @@ mod inner {
     }
     pub(crate) use metric;
 }
+
+#[cfg(test)]
+#[cfg(feature = "sqlx-dep")]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn pool_metrics() {
+        let metrics = PoolMetrics::default();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_cloned = Arc::clone(&events);
+        metrics.set_callback(Arc::new(move |event: &PoolEvent| {
+            if let Ok(mut events) = events_cloned.lock() {
+                events.push(event.clone());
+            }
+        }));
+
+        metrics.record_acquire(Duration::from_micros(200), false);
+        metrics.record_acquire(Duration::from_millis(5), false);
+        metrics.record_acquire(Duration::from_secs(30), true);
+        metrics.record_release();
+
+        let histogram = metrics.acquire_wait();
+        assert_eq!(histogram.count, 3);
+        assert_eq!(histogram.sum, Duration::from_micros(30_005_200));
+        assert_eq!(histogram.buckets[0], (Duration::from_millis(1), 1));
+        assert_eq!(histogram.buckets[1], (Duration::from_millis(5), 1));
+        assert_eq!(histogram.buckets[8], (Duration::MAX, 1));
+        assert_eq!(
+            events
+                .lock()
+                .map(|events| events.clone())
+                .unwrap_or_default(),
+            [
+                PoolEvent::Acquire {
+                    wait: Duration::from_micros(200),
+                    failed: false
+                },
+                PoolEvent::Acquire {
+                    wait: Duration::from_millis(5),
+                    failed: false
+                },
+                PoolEvent::Acquire {
+                    wait: Duration::from_secs(30),
+                    failed: true
+                },
+                PoolEvent::Release,
+            ]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{IntoSimpleExpr, Value};
+use core::marker::PhantomData;
+use sea_query::{CaseStatement, IntoCondition, SimpleExpr};
+
+/// A typed `CASE WHEN .. THEN .. ELSE .. END` expression, every branch yields a value of type `T`.
+///
+/// It can be used anywhere an expression is accepted, e.g. [`QuerySelect::expr_as`](crate::QuerySelect::expr_as),
+/// [`QueryOrder::order_by`](crate::QueryOrder::order_by) and [`UpdateMany::col_expr`](crate::UpdateMany::col_expr).
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+///
+/// assert_eq!(
+///     fruit::Entity::find()
+///         .order_by_asc(
+///             CaseWhen::new()
+///                 .when(fruit::Column::Name.eq("Apple"), 1)
+///                 .when(fruit::Column::Name.eq("Banana"), 2)
+///                 .otherwise(3)
+///         )
+///         .build(DbBackend::MySql)
+///         .to_string(),
+///     [
+///         "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit` ORDER BY",
+///         "(CASE WHEN (`fruit`.`name` = 'Apple') THEN 1 WHEN (`fruit`.`name` = 'Banana') THEN 2 ELSE 3 END) ASC",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaseWhen<T> {
+    stmt: CaseStatement,
+    value: PhantomData<T>,
+}
+
+impl<T> Default for CaseWhen<T>
+where
+    T: Into<Value>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CaseWhen<T>
+where
+    T: Into<Value>,
+{
+    /// Create an empty `CASE` expression
+    pub fn new() -> Self {
+        Self {
+            stmt: CaseStatement::new(),
+            value: PhantomData,
+        }
+    }
+
+    /// Add a `WHEN cond THEN value` branch
+    pub fn when<C>(mut self, cond: C, then: T) -> Self
+    where
+        C: IntoCondition,
+    {
+        self.stmt = self.stmt.case(cond, then.into());
+        self
+    }
+
+    /// Set the `ELSE` value, used when none of the branches match
+    pub fn otherwise(mut self, value: T) -> Self {
+        self.stmt = self.stmt.finally(value.into());
+        self
+    }
+}
+
+impl<T> IntoSimpleExpr for CaseWhen<T> {
+    fn into_simple_expr(self) -> SimpleExpr {
+        self.stmt.into()
+    }
+}
+
+impl<T> From<CaseWhen<T>> for SimpleExpr {
+    fn from(case: CaseWhen<T>) -> Self {
+        case.into_simple_expr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{fruit, lunch_set, sea_orm_active_enums::Tea};
+    use crate::{entity::*, query::*, DbBackend};
+
+    #[test]
+    fn case_when_select() {
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::Id)
+                .expr_as(
+                    CaseWhen::new()
+                        .when(fruit::Column::CakeId.is_null(), "orphan")
+                        .otherwise("baked"),
+                    "state"
+                )
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id","#,
+                r#"(CASE WHEN ("fruit"."cake_id" IS NULL) THEN 'orphan' ELSE 'baked' END) AS "state""#,
+                r#"FROM "fruit""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn case_when_update() {
+        assert_eq!(
+            Update::many(fruit::Entity)
+                .col_expr(
+                    fruit::Column::CakeId,
+                    CaseWhen::new()
+                        .when(fruit::Column::Name.eq("Apple"), 1)
+                        .otherwise(2)
+                        .into()
+                )
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"UPDATE "fruit" SET "cake_id" = (CASE WHEN ("fruit"."name" = 'Apple') THEN 1 ELSE 2 END)"#
+        );
+    }
+
+    #[test]
+    fn case_map_enum() {
+        assert_eq!(
+            lunch_set::Entity::find()
+                .order_by_desc(
+                    lunch_set::Column::Tea
+                        .case_map([(Tea::BreakfastTea, 2), (Tea::EverydayTea, 1)])
+                        .otherwise(0)
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `lunch_set`.`id`, `lunch_set`.`name`, `lunch_set`.`tea` FROM `lunch_set` ORDER BY",
+                "(CASE WHEN (`lunch_set`.`tea` = ('BreakfastTea')) THEN 2 WHEN (`lunch_set`.`tea` = ('EverydayTea')) THEN 1 ELSE 0 END) DESC",
+            ]
+            .join(" ")
+        );
+    }
+}
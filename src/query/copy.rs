@@ -0,0 +1,62 @@
+use crate::{ActiveModelTrait, IntoActiveModel};
+
+/// Copies ActiveModels into the table of an Entity with PostgreSQL's `COPY .. FROM STDIN`,
+/// see [`EntityTrait::copy_in`](crate::EntityTrait::copy_in)
+pub struct CopyIn<'a, A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) models: Box<dyn Iterator<Item = A> + Send + 'a>,
+}
+
+impl<A> std::fmt::Debug for CopyIn<'_, A>
+where
+    A: ActiveModelTrait,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CopyIn")
+    }
+}
+
+impl<'a, A> CopyIn<'a, A>
+where
+    A: ActiveModelTrait,
+{
+    /// Copy many Model or ActiveModel. They are converted and sent while being copied,
+    /// so they can be read lazily, e.g. from a file.
+    pub fn many<M, I>(models: I) -> Self
+    where
+        A: 'a,
+        M: IntoActiveModel<A> + 'a,
+        I: IntoIterator<Item = M>,
+        I::IntoIter: Send + 'a,
+    {
+        Self {
+            models: Box::new(models.into_iter().map(IntoActiveModel::into_active_model)),
+        }
+    }
+}
+
+/// The format of the data exported by a `COPY .. TO STDOUT`, see [`Select::copy_out`](crate::Select::copy_out)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// Tab separated text, with `\N` for NULL
+    Text,
+    /// CSV, with an unquoted empty field for NULL
+    Csv,
+    /// CSV, starting with a row of the names of the columns
+    CsvWithHeader,
+    /// The binary format of PostgreSQL, which is faster to read back but specific to the column types
+    Binary,
+}
+
+impl CopyFormat {
+    pub(crate) fn options(&self) -> &'static str {
+        match self {
+            Self::Text => "(FORMAT text)",
+            Self::Csv => "(FORMAT csv)",
+            Self::CsvWithHeader => "(FORMAT csv, HEADER)",
+            Self::Binary => "(FORMAT binary)",
+        }
+    }
+}
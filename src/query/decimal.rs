@@ -0,0 +1,328 @@
+use crate::{ColumnTrait, DbBackend, IntoSimpleExpr};
+use sea_query::{Expr, Func, SimpleExpr};
+
+/// An arithmetic expression on decimals, e.g. an amount computed from a price and a quantity
+///
+/// The values of the expression are bound as decimals, and SQLite, which binds them as text,
+/// casts them to `NUMERIC` so that they are added or compared as numbers. Postgres and MySQL
+/// compute with exact decimals and SQLite with reals; [`DecimalExpr::round`] rounds the result to
+/// a scale on all of them.
+///
+/// It can be used anywhere an expression is accepted, e.g. [`QuerySelect::expr_as`](crate::QuerySelect::expr_as),
+/// [`QueryFilter::filter`](crate::QueryFilter::filter) and [`UpdateMany::col_expr`](crate::UpdateMany::col_expr).
+///
+/// ```
+/// use sea_orm::{entity::prelude::Decimal, entity::*, query::*, tests_cfg::cake_filling_price, DbBackend};
+///
+/// assert_eq!(
+///     cake_filling_price::Entity::find()
+///         .select_only()
+///         .column(cake_filling_price::Column::CakeId)
+///         .expr_as(
+///             DecimalExpr::new(DbBackend::Sqlite, cake_filling_price::Column::Price)
+///                 .mul(Decimal::new(12, 1))
+///                 .round(2),
+///             "gross"
+///         )
+///         .filter(
+///             DecimalExpr::new(DbBackend::Sqlite, cake_filling_price::Column::Price)
+///                 .gt(Decimal::new(10, 0))
+///         )
+///         .build(DbBackend::Sqlite)
+///         .to_string(),
+///     [
+///         r#"SELECT "cake_filling_price"."cake_id","#,
+///         r#"ROUND("cake_filling_price"."price" * CAST(1.2 AS NUMERIC), 2) AS "gross""#,
+///         r#"FROM "public"."cake_filling_price""#,
+///         r#"WHERE "cake_filling_price"."price" > CAST(10 AS NUMERIC)"#,
+///     ]
+///     .join(" ")
+/// );
+///
+/// assert_eq!(
+///     cake_filling_price::Entity::find()
+///         .select_only()
+///         .expr_as(
+///             DecimalExpr::new(DbBackend::Postgres, cake_filling_price::Column::Price)
+///                 .sub(Decimal::new(5, 1))
+///                 .avg()
+///                 .round(2),
+///             "average"
+///         )
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     r#"SELECT ROUND(AVG("cake_filling_price"."price" - 0.5), 2) AS "average" FROM "public"."cake_filling_price""#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecimalExpr {
+    db_backend: DbBackend,
+    expr: SimpleExpr,
+}
+
+/// An operand of a [DecimalExpr]: a column, a decimal value or an expression
+pub trait IntoDecimalOperand {
+    /// Convert into an expression, cast to a number if it's a value bound as text
+    fn into_decimal_operand(self, db_backend: DbBackend) -> SimpleExpr;
+}
+
+impl DecimalExpr {
+    /// Start an expression from an operand
+    pub fn new<T>(db_backend: DbBackend, operand: T) -> Self
+    where
+        T: IntoDecimalOperand,
+    {
+        Self {
+            expr: operand.into_decimal_operand(db_backend),
+            db_backend,
+        }
+    }
+
+    fn binary<T, F>(self, rhs: T, f: F) -> Self
+    where
+        T: IntoDecimalOperand,
+        F: FnOnce(SimpleExpr, SimpleExpr) -> SimpleExpr,
+    {
+        let rhs = rhs.into_decimal_operand(self.db_backend);
+        Self {
+            expr: f(self.expr, rhs),
+            db_backend: self.db_backend,
+        }
+    }
+
+    /// Add an operand
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<T>(self, rhs: T) -> Self
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, SimpleExpr::add)
+    }
+
+    /// Subtract an operand
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub<T>(self, rhs: T) -> Self
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, SimpleExpr::sub)
+    }
+
+    /// Multiply by an operand
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul<T>(self, rhs: T) -> Self
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, SimpleExpr::mul)
+    }
+
+    /// Divide by an operand; the scale of the quotient depends on the backend, round it with [`DecimalExpr::round`]
+    #[allow(clippy::should_implement_trait)]
+    pub fn div<T>(self, rhs: T) -> Self
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, SimpleExpr::div)
+    }
+
+    /// Round to `scale` digits after the decimal point, halves away from zero
+    pub fn round(self, scale: i32) -> Self {
+        Self {
+            expr: Func::round_with_precision(self.expr, SimpleExpr::Constant(scale.into())).into(),
+            db_backend: self.db_backend,
+        }
+    }
+
+    /// Express `expr = rhs`
+    pub fn eq<T>(self, rhs: T) -> SimpleExpr
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, |l, r| l.eq(r)).expr
+    }
+
+    /// Express `expr <> rhs`
+    pub fn ne<T>(self, rhs: T) -> SimpleExpr
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, |l, r| l.ne(r)).expr
+    }
+
+    /// Express `expr > rhs`
+    pub fn gt<T>(self, rhs: T) -> SimpleExpr
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, |l, r| Expr::expr(l).gt(r)).expr
+    }
+
+    /// Express `expr >= rhs`
+    pub fn gte<T>(self, rhs: T) -> SimpleExpr
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, |l, r| Expr::expr(l).gte(r)).expr
+    }
+
+    /// Express `expr < rhs`
+    pub fn lt<T>(self, rhs: T) -> SimpleExpr
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, |l, r| Expr::expr(l).lt(r)).expr
+    }
+
+    /// Express `expr <= rhs`
+    pub fn lte<T>(self, rhs: T) -> SimpleExpr
+    where
+        T: IntoDecimalOperand,
+    {
+        self.binary(rhs, |l, r| Expr::expr(l).lte(r)).expr
+    }
+
+    /// The `SUM` of the expression over the rows
+    pub fn sum(self) -> Self {
+        Self {
+            expr: Func::sum(self.expr).into(),
+            db_backend: self.db_backend,
+        }
+    }
+
+    /// The `AVG` of the expression over the rows
+    pub fn avg(self) -> Self {
+        Self {
+            expr: Func::avg(self.expr).into(),
+            db_backend: self.db_backend,
+        }
+    }
+}
+
+impl IntoSimpleExpr for DecimalExpr {
+    fn into_simple_expr(self) -> SimpleExpr {
+        self.expr
+    }
+}
+
+impl From<DecimalExpr> for SimpleExpr {
+    fn from(expr: DecimalExpr) -> Self {
+        expr.into_simple_expr()
+    }
+}
+
+impl IntoDecimalOperand for DecimalExpr {
+    fn into_decimal_operand(self, _: DbBackend) -> SimpleExpr {
+        self.expr
+    }
+}
+
+impl<C> IntoDecimalOperand for C
+where
+    C: ColumnTrait,
+{
+    fn into_decimal_operand(self, _: DbBackend) -> SimpleExpr {
+        self.into_simple_expr()
+    }
+}
+
+impl IntoDecimalOperand for SimpleExpr {
+    fn into_decimal_operand(self, _: DbBackend) -> SimpleExpr {
+        self
+    }
+}
+
+impl IntoDecimalOperand for Expr {
+    fn into_decimal_operand(self, _: DbBackend) -> SimpleExpr {
+        self.into()
+    }
+}
+
+#[cfg(any(feature = "with-rust_decimal", feature = "with-bigdecimal"))]
+fn decimal_value<V>(db_backend: DbBackend, v: V) -> SimpleExpr
+where
+    V: Into<crate::Value>,
+{
+    match db_backend {
+        DbBackend::Sqlite => Expr::val(v).cast_as(sea_query::Alias::new("NUMERIC")),
+        DbBackend::MySql | DbBackend::Postgres => Expr::val(v).into(),
+    }
+}
+
+#[cfg(feature = "with-rust_decimal")]
+impl IntoDecimalOperand for rust_decimal::Decimal {
+    fn into_decimal_operand(self, db_backend: DbBackend) -> SimpleExpr {
+        decimal_value(db_backend, self)
+    }
+}
+
+#[cfg(feature = "with-bigdecimal")]
+impl IntoDecimalOperand for bigdecimal::BigDecimal {
+    fn into_decimal_operand(self, db_backend: DbBackend) -> SimpleExpr {
+        decimal_value(db_backend, self)
+    }
+}
+
+#[cfg(all(test, feature = "with-rust_decimal", feature = "with-bigdecimal"))]
+mod tests {
+    use crate::tests_cfg::cake_filling_price;
+    use crate::{entity::prelude::Decimal, entity::*, query::*, DbBackend};
+    use bigdecimal::BigDecimal;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn decimal_expr_select() {
+        let query = |db_backend| {
+            cake_filling_price::Entity::find()
+                .select_only()
+                .expr_as(
+                    DecimalExpr::new(db_backend, cake_filling_price::Column::Price)
+                        .add(BigDecimal::new(5.into(), 1))
+                        .div(cake_filling_price::Column::FillingId)
+                        .sum()
+                        .round(2),
+                    "total",
+                )
+                .build(db_backend)
+                .to_string()
+        };
+        assert_eq!(
+            query(DbBackend::MySql),
+            "SELECT ROUND(SUM((`cake_filling_price`.`price` + 0.5) / `cake_filling_price`.`filling_id`), 2) AS `total` FROM `public`.`cake_filling_price`"
+        );
+        assert_eq!(
+            query(DbBackend::Postgres),
+            r#"SELECT ROUND(SUM(("cake_filling_price"."price" + 0.5) / "cake_filling_price"."filling_id"), 2) AS "total" FROM "public"."cake_filling_price""#
+        );
+        assert_eq!(
+            query(DbBackend::Sqlite),
+            r#"SELECT ROUND(SUM(("cake_filling_price"."price" + CAST(0.5 AS NUMERIC)) / "cake_filling_price"."filling_id"), 2) AS "total" FROM "public"."cake_filling_price""#
+        );
+    }
+
+    #[test]
+    fn decimal_expr_update() {
+        assert_eq!(
+            cake_filling_price::Entity::update_many()
+                .col_expr(
+                    cake_filling_price::Column::Price,
+                    DecimalExpr::new(DbBackend::Sqlite, cake_filling_price::Column::Price)
+                        .mul(Decimal::new(11, 1))
+                        .round(2)
+                        .into()
+                )
+                .filter(
+                    DecimalExpr::new(DbBackend::Sqlite, cake_filling_price::Column::Price)
+                        .sub(Decimal::ONE)
+                        .lte(Decimal::new(10, 0))
+                )
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"UPDATE "public"."cake_filling_price" SET "price" = ROUND("cake_filling_price"."price" * CAST(1.1 AS NUMERIC), 2)"#,
+                r#"WHERE "cake_filling_price"."price" - CAST(1 AS NUMERIC) <= CAST(10 AS NUMERIC)"#,
+            ]
+            .join(" ")
+        );
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyToColumn, QueryFilter, QueryTrait,
+    join_condition, ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, EntityTrait,
+    IntoActiveModel, Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait, Related, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{
+    ConditionHolder, ConditionalStatement, DeleteStatement, IntoCondition, IntoTableRef,
+    LogicalChainOper, QueryBuilder, SqlWriterValues, TableRef,
+};
+use std::fmt::Write;
 
 /// Defines the structure for a delete operation
 #[derive(Clone, Debug)]
@@ -29,6 +33,25 @@ where
     pub(crate) entity: PhantomData<E>,
 }
 
+/// Perform a delete operation on multiple models, matched against the rows of other tables.
+/// Renders `DELETE .. USING` on Postgres, the multiple-table `DELETE` on MySQL, and a correlated `EXISTS` on SQLite.
+#[derive(Clone, Debug)]
+pub struct DeleteManyUsing<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) using: Vec<TableRef>,
+    pub(crate) condition: DeleteUsingCondition,
+    pub(crate) entity: PhantomData<E>,
+}
+
+/// The `WHERE` clause of a [`DeleteManyUsing`], matching the rows of the deleted table with the
+/// rows of the joined tables
+#[derive(Clone, Debug, Default)]
+pub struct DeleteUsingCondition {
+    pub(crate) condition: ConditionHolder,
+}
+
 impl Delete {
     /// Delete one Model or ActiveModel
     ///
@@ -99,6 +122,62 @@ impl Delete {
             entity: PhantomData,
         }
     }
+
+    /// Delete many ActiveModels, matched against the rows of other tables joined with
+    /// [`DeleteManyUsing::using_related`] or [`DeleteManyUsing::using_table`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Delete::many_using(fruit::Entity)
+    ///         .using_related(cake::Entity)
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"DELETE FROM "fruit" USING "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Delete::many_using(fruit::Entity)
+    ///         .using_related(cake::Entity)
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "DELETE `fruit` FROM `fruit`, `cake`",
+    ///         "WHERE `fruit`.`cake_id` = `cake`.`id` AND `cake`.`name` LIKE '%Cheese%'",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Delete::many_using(fruit::Entity)
+    ///         .using_related(cake::Entity)
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         r#"DELETE FROM "fruit" WHERE EXISTS (SELECT 1 FROM "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%')"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn many_using<E>(_: E) -> DeleteManyUsing<E>
+    where
+        E: EntityTrait,
+    {
+        DeleteManyUsing {
+            using: Vec::new(),
+            condition: DeleteUsingCondition::default(),
+            entity: PhantomData,
+        }
+    }
 }
 
 impl<A> DeleteOne<A>
@@ -142,6 +221,32 @@ where
     }
 }
 
+impl<E> QueryFilter for DeleteManyUsing<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = DeleteUsingCondition;
+
+    fn query(&mut self) -> &mut DeleteUsingCondition {
+        &mut self.condition
+    }
+}
+
+impl ConditionalStatement for DeleteUsingCondition {
+    fn and_or_where(&mut self, condition: LogicalChainOper) -> &mut Self {
+        self.condition.add_and_or(condition);
+        self
+    }
+
+    fn cond_where<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: IntoCondition,
+    {
+        self.condition.add_condition(condition.into_condition());
+        self
+    }
+}
+
 impl<A> QueryTrait for DeleteOne<A>
 where
     A: ActiveModelTrait,
@@ -180,10 +285,93 @@ where
     }
 }
 
+impl<E> DeleteManyUsing<E>
+where
+    E: EntityTrait,
+{
+    /// Join the table of another related Entity
+    pub fn using_related<R>(mut self, _: R) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        if let Some(via) = E::via() {
+            self.using.push(via.to_tbl.clone());
+            self.condition.cond_where(join_condition(via));
+        }
+        let rel = E::to();
+        self.using.push(rel.to_tbl.clone());
+        self.condition.cond_where(join_condition(rel));
+        self
+    }
+
+    /// Join another table, rows are matched by the given condition
+    pub fn using_table<T, C>(mut self, tbl: T, condition: C) -> Self
+    where
+        T: IntoTableRef,
+        C: IntoCondition,
+    {
+        self.using.push(tbl.into_table_ref());
+        self.condition.cond_where(condition);
+        self
+    }
+
+    /// Build the query as [`Statement`]
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        let query_builder = db_backend.get_query_builder();
+        let (placeholder, numbered) = query_builder.placeholder();
+        let mut sql = SqlWriterValues::new(placeholder, numbered);
+        // Writing into a string doesn't fail
+        let _ = self.prepare_delete_using(db_backend, query_builder.as_ref(), &mut sql);
+        let (sql, values) = sql.into_parts();
+        Statement::from_sql_and_values(db_backend, sql, values)
+    }
+
+    fn prepare_delete_using(
+        &self,
+        db_backend: DbBackend,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut SqlWriterValues,
+    ) -> std::fmt::Result {
+        let table = E::default().table_ref();
+        match db_backend {
+            DbBackend::Postgres => {
+                write!(sql, "DELETE FROM ")?;
+                query_builder.prepare_table_ref(&table, sql);
+                write!(sql, " USING ")?;
+            }
+            DbBackend::MySql => {
+                write!(sql, "DELETE ")?;
+                query_builder.prepare_table_ref(&table, sql);
+                write!(sql, " FROM ")?;
+                query_builder.prepare_table_ref(&table, sql);
+                write!(sql, ", ")?;
+            }
+            DbBackend::Sqlite => {
+                write!(sql, "DELETE FROM ")?;
+                query_builder.prepare_table_ref(&table, sql);
+                write!(sql, " WHERE EXISTS (SELECT 1 FROM ")?;
+            }
+        }
+        for (i, tbl) in self.using.iter().enumerate() {
+            if i > 0 {
+                write!(sql, ", ")?;
+            }
+            query_builder.prepare_table_ref(tbl, sql);
+        }
+        query_builder.prepare_condition(&self.condition.condition, "WHERE", sql);
+        if db_backend == DbBackend::Sqlite {
+            write!(sql, ")")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, fruit};
-    use crate::{entity::*, query::*, DbBackend};
+    use crate::tests_cfg::{cake, filling, fruit};
+    use crate::{entity::*, query::*, DbBackend, Statement};
+    use sea_query::Expr;
 
     #[test]
     fn delete_1() {
@@ -217,4 +405,66 @@ mod tests {
             r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Cheese%'"#,
         );
     }
+
+    #[test]
+    fn delete_3() {
+        assert_eq!(
+            Delete::many_using(cake::Entity)
+                .using_related(filling::Entity)
+                .filter(filling::Column::Name.eq("Lemon"))
+                .build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"DELETE FROM "cake" USING "cake_filling", "filling""#,
+                    r#"WHERE "cake"."id" = "cake_filling"."cake_id" AND "cake_filling"."filling_id" = "filling"."id""#,
+                    r#"AND "filling"."name" = $1"#,
+                ]
+                .join(" "),
+                ["Lemon".into()]
+            ),
+        );
+    }
+
+    #[test]
+    fn delete_4() {
+        assert_eq!(
+            Delete::many_using(cake::Entity)
+                .using_table(
+                    fruit::Entity,
+                    Expr::col((fruit::Entity, fruit::Column::CakeId))
+                        .equals((cake::Entity, cake::Column::Id))
+                )
+                .filter(fruit::Column::Name.eq("Apple"))
+                .build(DbBackend::MySql),
+            Statement::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "DELETE `cake` FROM `cake`, `fruit`",
+                    "WHERE `fruit`.`cake_id` = `cake`.`id` AND `fruit`.`name` = ?",
+                ]
+                .join(" "),
+                ["Apple".into()]
+            ),
+        );
+    }
+
+    #[test]
+    fn delete_5() {
+        assert_eq!(
+            Delete::many_using(fruit::Entity)
+                .using_related(cake::Entity)
+                .filter(cake::Column::Name.eq("Cheese"))
+                .build(DbBackend::Sqlite),
+            Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    r#"DELETE FROM "fruit" WHERE EXISTS (SELECT 1 FROM "cake""#,
+                    r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" = ?)"#,
+                ]
+                .join(" "),
+                ["Cheese".into()]
+            ),
+        );
+    }
 }
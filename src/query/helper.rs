@@ -1,9 +1,10 @@
 use crate::{
-    ColumnTrait, EntityTrait, Identity, IntoIdentity, IntoSimpleExpr, Iterable, ModelTrait,
-    PrimaryKeyToColumn, RelationDef,
+    ColumnTrait, DbBackend, EntityTrait, Identity, IntoIdentity, IntoSimpleExpr, Iterable,
+    ModelTrait, PrimaryKeyToColumn, RelationDef, ValuesTable,
 };
 use sea_query::{
-    Alias, ConditionType, Expr, Iden, IntoCondition, IntoIden, LockBehavior, LockType,
+    extension::mysql::{IndexHintScope, MySqlSelectStatementExt},
+    Alias, ConditionType, Expr, Func, Iden, IntoCondition, IntoIden, LockBehavior, LockType,
     NullOrdering, SeaRc, SelectExpr, SelectStatement, SimpleExpr, TableRef,
 };
 pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
@@ -282,6 +283,142 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add a `ROLLUP` grouping, producing a subtotal row for every prefix of `cols` plus a grand total.
+    /// Renders `GROUP BY ROLLUP(..)` on Postgres and `GROUP BY .. WITH ROLLUP` on MySQL, SQLite does not support it.
+    ///
+    /// Use [`ColumnTrait::grouping`] to tell the subtotal rows apart.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::CakeId)
+    ///         .column(fruit::Column::Name)
+    ///         .column_as(fruit::Column::Id.count(), "count")
+    ///         .column_as(fruit::Column::Name.grouping(), "name_grouping")
+    ///         .group_by_rollup(DbBackend::Postgres, [fruit::Column::CakeId, fruit::Column::Name])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."cake_id", "fruit"."name", COUNT("fruit"."id") AS "count", GROUPING("fruit"."name") AS "name_grouping""#,
+    ///         r#"FROM "fruit" GROUP BY ROLLUP("fruit"."cake_id", "fruit"."name")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::CakeId)
+    ///         .column(fruit::Column::Name)
+    ///         .column_as(fruit::Column::Id.count(), "count")
+    ///         .group_by_rollup(DbBackend::MySql, [fruit::Column::CakeId, fruit::Column::Name])
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `fruit`.`cake_id`, `fruit`.`name`, COUNT(`fruit`.`id`) AS `count`",
+    ///         "FROM `fruit` GROUP BY `fruit`.`cake_id`, `fruit`.`name` WITH ROLLUP",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn group_by_rollup<C, I>(mut self, db_backend: DbBackend, cols: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIterator<Item = C>,
+    {
+        let mut exprs: Vec<SimpleExpr> = cols.into_iter().map(|c| c.into_simple_expr()).collect();
+        match db_backend {
+            DbBackend::MySql => {
+                if let Some(last) = exprs.pop() {
+                    exprs.push(Expr::cust_with_expr("? WITH ROLLUP", last));
+                }
+                self.query().add_group_by(exprs);
+            }
+            DbBackend::Postgres | DbBackend::Sqlite => {
+                self.query()
+                    .add_group_by([Func::cust(Alias::new("ROLLUP")).args(exprs).into()]);
+            }
+        }
+        self
+    }
+
+    /// Add a `CUBE` grouping, producing a subtotal row for every combination of `cols`.
+    /// NOTE: this function is only supported by Postgres
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::CakeId)
+    ///         .column(fruit::Column::Name)
+    ///         .column_as(fruit::Column::Id.count(), "count")
+    ///         .group_by_cube([fruit::Column::CakeId, fruit::Column::Name])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."cake_id", "fruit"."name", COUNT("fruit"."id") AS "count""#,
+    ///         r#"FROM "fruit" GROUP BY CUBE("fruit"."cake_id", "fruit"."name")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn group_by_cube<C, I>(mut self, cols: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIterator<Item = C>,
+    {
+        let exprs = cols.into_iter().map(|c| c.into_simple_expr());
+        self.query()
+            .add_group_by([Func::cust(Alias::new("CUBE")).args(exprs).into()]);
+        self
+    }
+
+    /// Add `GROUPING SETS`, each set of columns is grouped separately and the results are combined.
+    /// An empty set produces the grand total.
+    /// NOTE: this function is only supported by Postgres
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::CakeId)
+    ///         .column(fruit::Column::Name)
+    ///         .column_as(fruit::Column::Id.count(), "count")
+    ///         .group_by_grouping_sets([
+    ///             vec![fruit::Column::CakeId, fruit::Column::Name],
+    ///             vec![fruit::Column::CakeId],
+    ///             vec![],
+    ///         ])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."cake_id", "fruit"."name", COUNT("fruit"."id") AS "count""#,
+    ///         r#"FROM "fruit" GROUP BY GROUPING SETS(("fruit"."cake_id", "fruit"."name"), ("fruit"."cake_id"), ())"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn group_by_grouping_sets<C, S, I>(mut self, sets: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        S: IntoIterator<Item = C>,
+        I: IntoIterator<Item = S>,
+    {
+        let sets = sets
+            .into_iter()
+            .map(|set| SimpleExpr::Tuple(set.into_iter().map(|c| c.into_simple_expr()).collect()));
+        self.query()
+            .add_group_by([Func::cust(Alias::new("GROUPING SETS")).args(sets).into()]);
+        self
+    }
+
     /// Add an AND HAVING expression
     /// ```
     /// use sea_orm::{sea_query::{Alias, Expr}, entity::*, query::*, tests_cfg::cake, DbBackend};
@@ -429,6 +566,22 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Join an in-memory list of rows, see [`ValuesTable`] for an example.
+    fn join_values<C>(
+        mut self,
+        join: JoinType,
+        values: &ValuesTable,
+        db_backend: DbBackend,
+        condition: C,
+    ) -> Self
+    where
+        C: IntoCondition,
+    {
+        self.query()
+            .join(join, values.table_ref(db_backend), condition);
+        self
+    }
+
     /// Select lock
     fn lock(mut self, lock_type: LockType) -> Self {
         self.query().lock(lock_type);
@@ -455,6 +608,82 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add a `USE INDEX` hint to the query, only supported by MySQL and ignored by other backends.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .use_index("idx-cake-name")
+    ///         .filter(cake::Column::Name.eq("Cheesecake"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` USE INDEX (`idx-cake-name`) WHERE `cake`.`name` = 'Cheesecake'"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .use_index("idx-cake-name")
+    ///         .filter(cake::Column::Name.eq("Cheesecake"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" = 'Cheesecake'"#
+    /// );
+    /// ```
+    fn use_index<T>(mut self, index: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.query()
+            .use_index(Alias::new(index.into()), IndexHintScope::All);
+        self
+    }
+
+    /// Add a `FORCE INDEX` hint to the query, only supported by MySQL and ignored by other backends.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .force_index("idx-cake-name")
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` FORCE INDEX (`idx-cake-name`)"
+    /// );
+    /// ```
+    fn force_index<T>(mut self, index: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.query()
+            .force_index(Alias::new(index.into()), IndexHintScope::All);
+        self
+    }
+
+    /// Add an `IGNORE INDEX` hint to the query, only supported by MySQL and ignored by other backends.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .ignore_index("idx-cake-name")
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` IGNORE INDEX (`idx-cake-name`)"
+    /// );
+    /// ```
+    fn ignore_index<T>(mut self, index: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.query()
+            .ignore_index(Alias::new(index.into()), IndexHintScope::All);
+        self
+    }
+
     /// Add an expression to the select expression list.
     /// ```
     /// use sea_orm::sea_query::Expr;
@@ -676,6 +905,34 @@ pub trait QueryOrder: Sized {
             .order_by_expr_with_nulls(col.into_simple_expr(), ord, nulls);
         self
     }
+
+    /// Order the rows randomly, with `RANDOM()` or `RAND()` depending on the backend.
+    /// Every row is assigned a random number, so on large tables consider sampling first,
+    /// see [`Select::random_sampled`](crate::Select::random_sampled).
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .order_by_random()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY RANDOM() ASC"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .order_by_random()
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` ORDER BY RAND() ASC"
+    /// );
+    /// ```
+    fn order_by_random(mut self) -> Self {
+        self.query()
+            .order_by_expr(Func::random().into(), Order::Asc);
+        self
+    }
 }
 
 // LINT: when the column does not appear in tables selected from
@@ -1,9 +1,9 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel,
+    IntoSimpleExpr, Iterable, PrimaryKeyTrait, QuerySelect, QueryTrait, Select,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, OnConflict, ValueTuple};
+use sea_query::{Alias, Expr, InsertStatement, IntoIden, OnConflict, TableRef, ValueTuple};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -209,6 +209,37 @@ where
         self
     }
 
+    /// Insert the rows into a partition of the table, which is a table of its own on Postgres,
+    /// in the schema of the Entity
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Alias, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Insert::one(cake::Model {
+    ///         id: 1,
+    ///         name: "Apple Pie".to_owned(),
+    ///     })
+    ///     .partition(Alias::new("cake_1"))
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "cake_1" ("id", "name") VALUES (1, 'Apple Pie')"#,
+    /// );
+    /// ```
+    pub fn partition<T>(mut self, partition: T) -> Self
+    where
+        T: IntoIden,
+    {
+        let table = match A::Entity::default().schema_name() {
+            Some(schema) => {
+                TableRef::SchemaTable(Alias::new(schema).into_iden(), partition.into_iden())
+            }
+            None => TableRef::Table(partition.into_iden()),
+        };
+        self.query.into_table(table);
+        self
+    }
+
     /// Allow insert statement return safely if inserting nothing.
     /// The database will not be affected.
     pub fn do_nothing(self) -> TryInsert<A>
@@ -389,6 +420,89 @@ where
         self.insert_struct.query
     }
 }
+
+/// Performs an `INSERT INTO ... SELECT` operation, copying the rows matched by a query into an Entity's table
+/// in a single statement
+#[derive(Clone, Debug)]
+pub struct InsertSelect<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) query: InsertStatement,
+    pub(crate) entity: PhantomData<E>,
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Insert the rows matched by this query into the table of another Entity.
+    /// Each pair maps a column of the target Entity to the expression selected for it.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .filter(fruit::Column::CakeId.is_null())
+    ///         .insert_into(cake::Entity, [(cake::Column::Name, fruit::Column::Name)])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "cake" ("name") SELECT "fruit"."name" FROM "fruit" WHERE "fruit"."cake_id" IS NULL"#,
+    /// );
+    /// ```
+    pub fn insert_into<T, I, X>(self, _: T, columns: I) -> InsertSelect<T>
+    where
+        T: EntityTrait,
+        I: IntoIterator<Item = (T::Column, X)>,
+        X: IntoSimpleExpr,
+    {
+        let mut select = self.select_only();
+        let mut cols = Vec::new();
+        for (col, expr) in columns.into_iter() {
+            cols.push(col);
+            select = select.expr(expr.into_simple_expr());
+        }
+        let mut query = InsertStatement::new();
+        query.into_table(T::default().table_ref()).columns(cols);
+        // The column and expression lists are built in pairs, so their lengths always match
+        let _ = query.select_from(select.query);
+        InsertSelect {
+            query,
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<E> InsertSelect<E>
+where
+    E: EntityTrait,
+{
+    /// On conflict, e.g. to skip the rows that were copied before
+    pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
+        self.query.on_conflict(on_conflict);
+        self
+    }
+}
+
+impl<E> QueryTrait for InsertSelect<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = InsertStatement;
+
+    fn query(&mut self) -> &mut InsertStatement {
+        &mut self.query
+    }
+
+    fn as_query(&self) -> &InsertStatement {
+        &self.query
+    }
+
+    fn into_query(self) -> InsertStatement {
+        self.query
+    }
+}
 #[cfg(test)]
 mod tests {
     use sea_query::OnConflict;
@@ -640,4 +754,65 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn insert_10() {
+        use crate::tests_cfg::fruit;
+        use crate::{ColumnTrait, IntoSimpleExpr, QueryFilter};
+        use sea_query::{Expr, Func};
+
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::Name.starts_with("A"))
+                .insert_into(
+                    cake::Entity,
+                    [
+                        (cake::Column::Id, fruit::Column::Id.into_simple_expr()),
+                        (
+                            cake::Column::Name,
+                            Func::upper(Expr::col((fruit::Entity, fruit::Column::Name))).into()
+                        ),
+                    ]
+                )
+                .on_conflict(OnConflict::column(cake::Column::Id).do_nothing().to_owned())
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"INSERT INTO "cake" ("id", "name")"#,
+                r#"SELECT "fruit"."id", UPPER("fruit"."name") FROM "fruit" WHERE "fruit"."name" LIKE 'A%'"#,
+                r#"ON CONFLICT ("id") DO NOTHING"#,
+            ]
+            .join(" "),
+        );
+    }
+
+    #[smol_potat::test]
+    async fn insert_11() -> Result<(), DbErr> {
+        use crate::tests_cfg::fruit;
+        use crate::{DbBackend, MockDatabase, MockExecResult, Statement, Transaction};
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 3,
+            }])
+            .into_connection();
+
+        let rows_affected = fruit::Entity::find()
+            .insert_into(cake::Entity, [(cake::Column::Name, fruit::Column::Name)])
+            .exec(&db)
+            .await?;
+
+        assert_eq!(rows_affected, 3);
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::many([Statement::from_sql_and_values(
+                DbBackend::MySql,
+                r#"INSERT INTO `cake` (`name`) SELECT `fruit`.`name` FROM `fruit`"#,
+                []
+            )])]
+        );
+
+        Ok(())
+    }
 }
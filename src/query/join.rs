@@ -1,7 +1,11 @@
+#[cfg(feature = "with-json")]
+use crate::SelectJsonRelated;
 use crate::{
     join_tbl_on_condition, unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic, Iterable,
     Linked, QuerySelect, Related, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
 };
+#[cfg(feature = "with-json")]
+use core::marker::PhantomData;
 pub use sea_query::JoinType;
 use sea_query::{Alias, Condition, Expr, IntoIden, SeaRc, SelectExpr};
 
@@ -62,6 +66,22 @@ where
         self.left_join(r).select_with(r)
     }
 
+    /// Select the related Entity as a JSON array aggregated by a correlated subquery,
+    /// so that the Models and their related Models are fetched in a single query without duplicating rows.
+    ///
+    /// This is intended for small sets of related rows; use [`LoaderTrait`](crate::LoaderTrait) otherwise.
+    #[cfg(feature = "with-json")]
+    pub fn find_with_related_json<R>(self, _: R) -> SelectJsonRelated<E, R>
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        SelectJsonRelated {
+            query: self.query,
+            entity: PhantomData,
+        }
+    }
+
     /// Left Join with a Linked Entity and select both Entity.
     pub fn find_also_linked<L, T>(self, l: L) -> SelectTwo<E, T>
     where
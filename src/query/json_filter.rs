@@ -0,0 +1,307 @@
+use crate::{
+    error::*, query::select::column_by_name, ColumnTrait, ColumnType, EntityTrait, JsonValue,
+    QueryFilter, Select,
+};
+use sea_query::{Condition, Nullable, SimpleExpr, Value};
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+
+/// Parse a JSON filter document into a [`Condition`] over the columns of an Entity.
+///
+/// The document is an object whose keys are either column names or one of the combinators
+/// `and` / `or` (taking an array of documents) and `not` (taking a document).
+/// Keys of the same object are joined with `AND`.
+///
+/// A column maps to an object of operators, or directly to a value as a shorthand for `eq`:
+///
+/// | Operator | Value |
+/// |----------|-------|
+/// | `eq`, `ne`, `gt`, `gte`, `lt`, `lte` | a value; `eq` and `ne` also accept `null` |
+/// | `in`, `not_in` | an array of values |
+/// | `like`, `not_like`, `contains`, `starts_with`, `ends_with` | a string |
+/// | `is_null` | a boolean |
+///
+/// Column names are matched exactly against the Entity's columns, and values are checked against
+/// the column type, so the document can be taken from an untrusted request.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+/// use serde_json::json;
+///
+/// let filter = json!({
+///     "and": [
+///         { "name": { "contains": "berry" } },
+///         { "or": [{ "cake_id": 1 }, { "cake_id": { "is_null": true } }] },
+///     ]
+/// });
+///
+/// assert_eq!(
+///     fruit::Entity::find()
+///         .try_filter_json(&filter)?
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+///         r#"WHERE "fruit"."name" LIKE '%berry%' AND ("fruit"."cake_id" = 1 OR "fruit"."cake_id" IS NULL)"#,
+///     ]
+///     .join(" ")
+/// );
+///
+/// assert!(parse_json_filter::<fruit::Entity>(&json!({ "cake_id": "one" })).is_err());
+/// assert!(parse_json_filter::<fruit::Entity>(&json!({ "password": "x" })).is_err());
+/// # Ok::<(), sea_orm::DbErr>(())
+/// ```
+pub fn parse_json_filter<E>(filter: &JsonValue) -> Result<Condition, DbErr>
+where
+    E: EntityTrait,
+{
+    match filter {
+        JsonValue::Object(map) => parse_object::<E>(map),
+        _ => Err(json_err("a filter must be an object")),
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Filter by a JSON filter document, see [`parse_json_filter`]
+    pub fn try_filter_json(self, filter: &JsonValue) -> Result<Self, DbErr> {
+        Ok(self.filter(parse_json_filter::<E>(filter)?))
+    }
+}
+
+fn parse_object<E>(map: &Map<String, JsonValue>) -> Result<Condition, DbErr>
+where
+    E: EntityTrait,
+{
+    let mut cond = Condition::all();
+    for (key, value) in map {
+        cond = match key.as_str() {
+            "and" | "or" => {
+                let mut inner = if key == "and" {
+                    Condition::all()
+                } else {
+                    Condition::any()
+                };
+                let filters = value
+                    .as_array()
+                    .ok_or_else(|| json_err(format!("`{key}` expects an array")))?;
+                for filter in filters {
+                    inner = inner.add(parse_json_filter::<E>(filter)?);
+                }
+                cond.add(inner)
+            }
+            "not" => cond.add(parse_json_filter::<E>(value)?.not()),
+            name => {
+                let col = column_by_name::<E>(name)?;
+                match value {
+                    JsonValue::Object(ops) => {
+                        for (op, operand) in ops {
+                            cond = cond.add(parse_operator(col, op, operand)?);
+                        }
+                        cond
+                    }
+                    value => cond.add(parse_operator(col, "eq", value)?),
+                }
+            }
+        };
+    }
+    Ok(cond)
+}
+
+fn parse_operator<C>(col: C, op: &str, operand: &JsonValue) -> Result<SimpleExpr, DbErr>
+where
+    C: ColumnTrait,
+{
+    Ok(match (op, operand) {
+        ("eq", JsonValue::Null) => col.is_null(),
+        ("ne", JsonValue::Null) => col.is_not_null(),
+        ("eq", v) => col.eq(to_value(col, v)?),
+        ("ne", v) => col.ne(to_value(col, v)?),
+        ("gt", v) => col.gt(to_value(col, v)?),
+        ("gte", v) => col.gte(to_value(col, v)?),
+        ("lt", v) => col.lt(to_value(col, v)?),
+        ("lte", v) => col.lte(to_value(col, v)?),
+        ("in" | "not_in", JsonValue::Array(vs)) => {
+            let values = vs
+                .iter()
+                .map(|v| to_value(col, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            if op == "in" {
+                col.is_in(values)
+            } else {
+                col.is_not_in(values)
+            }
+        }
+        ("like", JsonValue::String(s)) => col.like(s),
+        ("not_like", JsonValue::String(s)) => col.not_like(s),
+        ("contains", JsonValue::String(s)) => col.contains(s),
+        ("starts_with", JsonValue::String(s)) => col.starts_with(s),
+        ("ends_with", JsonValue::String(s)) => col.ends_with(s),
+        ("is_null", JsonValue::Bool(true)) => col.is_null(),
+        ("is_null", JsonValue::Bool(false)) => col.is_not_null(),
+        (
+            "in" | "not_in" | "like" | "not_like" | "contains" | "starts_with" | "ends_with"
+            | "is_null",
+            _,
+        ) => {
+            return Err(json_err(format!(
+                "unexpected operand {operand} of `{op}` on column `{}`",
+                col.as_str()
+            )))
+        }
+        _ => return Err(json_err(format!("unknown operator `{op}`"))),
+    })
+}
+
+/// Convert a JSON value into a [`Value`] of the column's type
+fn to_value<C>(col: C, json: &JsonValue) -> Result<Value, DbErr>
+where
+    C: ColumnTrait,
+{
+    if json.is_null() {
+        return Err(type_err(format!(
+            "column `{}`: unexpected null",
+            col.as_str()
+        )));
+    }
+    json_to_value(col, json)
+}
+
+/// Convert a JSON value into a [`Value`] of the column's type, reading `null` as a null value
+/// if the column is nullable and numbers as booleans, as MySQL and SQLite encode them
+pub(crate) fn json_to_value<C>(col: C, json: &JsonValue) -> Result<Value, DbErr>
+where
+    C: ColumnTrait,
+{
+    fn typed<T>(json: &JsonValue) -> Result<Value, serde_json::Error>
+    where
+        T: DeserializeOwned + Into<Value> + Nullable,
+    {
+        serde_json::from_value::<Option<T>>(json.clone()).map(Into::into)
+    }
+
+    let col_def = col.def();
+    if json.is_null() && !col_def.is_null() {
+        return Err(type_err(format!(
+            "column `{}` is not nullable, got null",
+            col.as_str()
+        )));
+    }
+    let value = match col_def.get_column_type() {
+        ColumnType::TinyInteger => typed::<i8>(json),
+        ColumnType::SmallInteger => typed::<i16>(json),
+        ColumnType::Integer => typed::<i32>(json),
+        ColumnType::BigInteger => typed::<i64>(json),
+        ColumnType::TinyUnsigned => typed::<u8>(json),
+        ColumnType::SmallUnsigned => typed::<u16>(json),
+        ColumnType::Unsigned => typed::<u32>(json),
+        ColumnType::BigUnsigned => typed::<u64>(json),
+        ColumnType::Float => typed::<f32>(json),
+        ColumnType::Double => typed::<f64>(json),
+        ColumnType::Boolean => match json {
+            JsonValue::Number(n) => Ok(Value::Bool(Some(n.as_f64() != Some(0.0)))),
+            json => typed::<bool>(json),
+        },
+        ColumnType::Char(_)
+        | ColumnType::String(_)
+        | ColumnType::Text
+        | ColumnType::Enum { .. } => typed::<String>(json),
+        ColumnType::Json | ColumnType::JsonBinary => match json {
+            JsonValue::Null => Ok(Value::Json(None)),
+            json => Ok(json.clone().into()),
+        },
+        #[cfg(feature = "with-chrono")]
+        ColumnType::Date => typed::<chrono::NaiveDate>(json),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::Time => typed::<chrono::NaiveTime>(json),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::DateTime | ColumnType::Timestamp => typed::<chrono::NaiveDateTime>(json),
+        #[cfg(feature = "with-chrono")]
+        ColumnType::TimestampWithTimeZone => typed::<chrono::DateTime<chrono::FixedOffset>>(json),
+        #[cfg(feature = "with-uuid")]
+        ColumnType::Uuid => typed::<uuid::Uuid>(json),
+        #[cfg(feature = "with-rust_decimal")]
+        ColumnType::Decimal(_) | ColumnType::Money(_) => {
+            let s = match json {
+                JsonValue::Null => return Ok(Value::Decimal(None)),
+                JsonValue::String(s) => s.clone(),
+                json => json.to_string(),
+            };
+            return s
+                .parse::<rust_decimal::Decimal>()
+                .map(Into::into)
+                .map_err(|e| type_err(format!("column `{}`: {e}", col.as_str())));
+        }
+        col_type => {
+            return Err(type_err(format!(
+                "column `{}` of type {col_type:?} cannot be read from JSON",
+                col.as_str()
+            )))
+        }
+    };
+    value.map_err(|e| type_err(format!("column `{}`: {e}", col.as_str())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{entity::*, error::*, query::*, DbBackend, Statement};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn json_filter_operators() -> Result<(), DbErr> {
+        let filter = json!({
+            "id": { "gte": 2, "lt": 10, "not_in": [4, 5] },
+            "name": { "ne": null, "starts_with": "Ch" },
+            "not": { "or": [{ "name": "Cheese" }, { "name": { "ends_with": "Pie" } }] },
+        });
+        assert_eq!(
+            cake::Entity::find()
+                .try_filter_json(&filter)?
+                .build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    r#"WHERE "cake"."id" >= $1 AND "cake"."id" < $2 AND "cake"."id" NOT IN ($3, $4)"#,
+                    r#"AND "cake"."name" IS NOT NULL AND "cake"."name" LIKE $5"#,
+                    r#"AND (NOT ("cake"."name" = $6 OR "cake"."name" LIKE $7))"#,
+                ]
+                .join(" "),
+                [
+                    2i32.into(),
+                    10i32.into(),
+                    4i32.into(),
+                    5i32.into(),
+                    "Ch%".into(),
+                    "Cheese".into(),
+                    "%Pie".into(),
+                ]
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_filter_errors() {
+        for filter in [
+            json!([{ "id": 1 }]),
+            json!({ "and": { "id": 1 } }),
+            json!({ "unknown": 1 }),
+            json!({ "id": { "between": [1, 2] } }),
+            json!({ "id": { "in": 1 } }),
+            json!({ "id": 1.5 }),
+            json!({ "id": 4294967296i64 }),
+            json!({ "name": { "like": 1 } }),
+            json!({ "cake_id": { "is_null": "yes" } }),
+        ] {
+            assert!(
+                parse_json_filter::<fruit::Entity>(&filter).is_err(),
+                "{filter}"
+            );
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{
+    error::*, DbBackend, EntityTrait, IntoIdentity, IntoSimpleExpr, Select, Statement, Update,
+    ValuesTable,
+};
+use crate::{Delete, QueryTrait};
+use core::marker::PhantomData;
+use sea_query::{
+    ColumnRef, Condition, DynIden, Expr, InsertStatement, IntoCondition, IntoIden, QueryBuilder,
+    SelectStatement, SimpleExpr, SqlWriterValues, TableRef,
+};
+use std::fmt::Write;
+
+/// Defines a `MERGE` operation, synchronizing a target Entity with the rows of a source query
+#[derive(Clone, Debug)]
+pub struct Merge;
+
+/// A `MERGE` operation into the table of an Entity.
+///
+/// Rendered as a single `MERGE` statement on Postgres (15 or later). MySQL and SQLite do not support `MERGE`,
+/// there it is emulated by an `UPDATE` / `DELETE` of the matched rows followed by an `INSERT` of the unmatched rows,
+/// which should be executed within a transaction. Combining `DELETE` with `INSERT` cannot be emulated.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::cake, DbBackend};
+///
+/// let incoming = ValuesTable::new("incoming", ["id", "name"])
+///     .row((1, "Cheese Cake"))
+///     .row((4, "Lemon Tart"));
+///
+/// let merge = Merge::target(cake::Entity)
+///     .on(incoming.col("id").equals((cake::Entity, cake::Column::Id)))
+///     .when_matched_update([(cake::Column::Name, incoming.col("name"))])
+///     .when_not_matched_insert([
+///         (cake::Column::Id, incoming.col("id")),
+///         (cake::Column::Name, incoming.col("name")),
+///     ])
+///     .using_values(incoming);
+///
+/// assert_eq!(
+///     merge
+///         .build(DbBackend::Postgres)?
+///         .iter()
+///         .map(|stmt| stmt.to_string())
+///         .collect::<Vec<_>>(),
+///     [[
+///         r#"MERGE INTO "cake""#,
+///         r#"USING (SELECT "column1" AS "id", "column2" AS "name" FROM (VALUES (1, 'Cheese Cake'), (4, 'Lemon Tart')) AS "t") AS "incoming""#,
+///         r#"ON "incoming"."id" = "cake"."id""#,
+///         r#"WHEN MATCHED THEN UPDATE SET "name" = "incoming"."name""#,
+///         r#"WHEN NOT MATCHED THEN INSERT ("id", "name") VALUES ("incoming"."id", "incoming"."name")"#,
+///     ]
+///     .join(" ")]
+/// );
+///
+/// assert_eq!(
+///     merge
+///         .build(DbBackend::MySql)?
+///         .iter()
+///         .map(|stmt| stmt.to_string())
+///         .collect::<Vec<_>>(),
+///     [
+///         [
+///             "UPDATE `cake`, (SELECT 1 AS `id`, 'Cheese Cake' AS `name` UNION ALL (SELECT 4, 'Lemon Tart')) AS `incoming`",
+///             "SET `cake`.`name` = `incoming`.`name` WHERE `incoming`.`id` = `cake`.`id`",
+///         ]
+///         .join(" "),
+///         [
+///             "INSERT INTO `cake` (`id`, `name`) SELECT `incoming`.`id`, `incoming`.`name`",
+///             "FROM (SELECT 1 AS `id`, 'Cheese Cake' AS `name` UNION ALL (SELECT 4, 'Lemon Tart')) AS `incoming`",
+///             "WHERE NOT EXISTS(SELECT 1 FROM `cake` WHERE `incoming`.`id` = `cake`.`id`)",
+///         ]
+///         .join(" "),
+///     ]
+/// );
+/// # Ok::<(), sea_orm::DbErr>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct MergeInto<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) source: Option<MergeSource>,
+    pub(crate) on: Option<Condition>,
+    pub(crate) matched: Option<MergeMatched>,
+    pub(crate) not_matched: Vec<(DynIden, SimpleExpr)>,
+    pub(crate) entity: PhantomData<E>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum MergeSource {
+    Query(Box<SelectStatement>, DynIden),
+    Values(ValuesTable),
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum MergeMatched {
+    Update(Vec<(DynIden, SimpleExpr)>),
+    Delete,
+}
+
+impl Merge {
+    /// Merge into the table of an Entity
+    pub fn target<E>(_: E) -> MergeInto<E>
+    where
+        E: EntityTrait,
+    {
+        MergeInto {
+            source: None,
+            on: None,
+            matched: None,
+            not_matched: Vec::new(),
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<E> MergeInto<E>
+where
+    E: EntityTrait,
+{
+    /// Use the rows of a query as the source, referred to by `alias`
+    pub fn using<S, A>(mut self, select: Select<S>, alias: A) -> Self
+    where
+        S: EntityTrait,
+        A: IntoIdentity,
+    {
+        self.source = Some(MergeSource::Query(
+            Box::new(select.into_query()),
+            alias.into_identity().into_iden(),
+        ));
+        self
+    }
+
+    /// Use an in-memory list of rows as the source
+    pub fn using_values(mut self, values: ValuesTable) -> Self {
+        self.source = Some(MergeSource::Values(values));
+        self
+    }
+
+    /// Refer to a column of the source
+    pub fn source_col<C>(&self, col: C) -> Expr
+    where
+        C: IntoIdentity,
+    {
+        match &self.source {
+            Some(MergeSource::Query(_, alias)) => {
+                Expr::col((alias.clone(), col.into_identity().into_iden()))
+            }
+            Some(MergeSource::Values(values)) => values.col(col),
+            None => Expr::col(col.into_identity().into_iden()),
+        }
+    }
+
+    /// The condition matching a source row with a target row
+    pub fn on<C>(mut self, condition: C) -> Self
+    where
+        C: IntoCondition,
+    {
+        self.on = Some(condition.into_condition());
+        self
+    }
+
+    /// `WHEN MATCHED THEN UPDATE`, setting each target column to the given expression
+    pub fn when_matched_update<I, X>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = (E::Column, X)>,
+        X: IntoSimpleExpr,
+    {
+        self.matched = Some(MergeMatched::Update(
+            values
+                .into_iter()
+                .map(|(col, expr)| (col.into_iden(), expr.into_simple_expr()))
+                .collect(),
+        ));
+        self
+    }
+
+    /// `WHEN MATCHED THEN DELETE`
+    pub fn when_matched_delete(mut self) -> Self {
+        self.matched = Some(MergeMatched::Delete);
+        self
+    }
+
+    /// `WHEN NOT MATCHED THEN INSERT`, setting each target column to the given expression
+    pub fn when_not_matched_insert<I, X>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = (E::Column, X)>,
+        X: IntoSimpleExpr,
+    {
+        self.not_matched = values
+            .into_iter()
+            .map(|(col, expr)| (col.into_iden(), expr.into_simple_expr()))
+            .collect();
+        self
+    }
+
+    /// Build the statements to execute in order, a single `MERGE` statement on Postgres
+    pub fn build(&self, db_backend: DbBackend) -> Result<Vec<Statement>, DbErr> {
+        let source = match &self.source {
+            Some(MergeSource::Query(query, alias)) => {
+                TableRef::SubQuery(query.as_ref().clone(), alias.clone())
+            }
+            Some(MergeSource::Values(values)) => values.table_ref(db_backend),
+            None => return Err(DbErr::Custom("MERGE requires a source".to_owned())),
+        };
+        let on = match &self.on {
+            Some(on) => on.clone(),
+            None => return Err(DbErr::Custom("MERGE requires an ON condition".to_owned())),
+        };
+        if self.matched.is_none() && self.not_matched.is_empty() {
+            return Err(DbErr::Custom(
+                "MERGE requires a WHEN MATCHED or WHEN NOT MATCHED action".to_owned(),
+            ));
+        }
+
+        if let DbBackend::Postgres = db_backend {
+            let query_builder = db_backend.get_query_builder();
+            let (placeholder, numbered) = query_builder.placeholder();
+            let mut sql = SqlWriterValues::new(placeholder, numbered);
+            self.prepare_merge(query_builder.as_ref(), &source, &on, &mut sql)
+                .map_err(|e| DbErr::Custom(e.to_string()))?;
+            let (sql, values) = sql.into_parts();
+            return Ok(vec![Statement::from_sql_and_values(
+                db_backend, sql, values,
+            )]);
+        }
+
+        let mut stmts = Vec::new();
+        match &self.matched {
+            Some(MergeMatched::Update(values)) => {
+                let mut update =
+                    Update::many_from(E::default()).from_table(source.clone(), on.clone());
+                for (col, expr) in values {
+                    update = update.col_expr(col.clone(), expr.clone());
+                }
+                stmts.push(update.build(db_backend));
+            }
+            Some(MergeMatched::Delete) if !self.not_matched.is_empty() => {
+                return Err(DbErr::Custom(format!(
+                    "MERGE with both DELETE and INSERT actions is not supported on {db_backend:?}"
+                )));
+            }
+            Some(MergeMatched::Delete) => {
+                let delete =
+                    Delete::many_using(E::default()).using_table(source.clone(), on.clone());
+                stmts.push(delete.build(db_backend));
+            }
+            None => {}
+        }
+        if !self.not_matched.is_empty() {
+            let mut exists = SelectStatement::new();
+            exists
+                .expr(Expr::cust("1"))
+                .from(E::default().table_ref())
+                .cond_where(on);
+            let mut select = SelectStatement::new();
+            select
+                .exprs(self.not_matched.iter().map(|(_, expr)| expr.clone()))
+                .from(source)
+                .and_where(Expr::exists(exists).not());
+            let mut insert = InsertStatement::new();
+            insert
+                .into_table(E::default().table_ref())
+                .columns(self.not_matched.iter().map(|(col, _)| col.clone()));
+            insert
+                .select_from(select)
+                .map_err(|e| DbErr::Custom(e.to_string()))?;
+            stmts.push(db_backend.build(&insert));
+        }
+        Ok(stmts)
+    }
+
+    fn prepare_merge(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        source: &TableRef,
+        on: &Condition,
+        sql: &mut SqlWriterValues,
+    ) -> std::fmt::Result {
+        write!(sql, "MERGE INTO ")?;
+        query_builder.prepare_table_ref(&E::default().table_ref(), sql);
+        write!(sql, " USING ")?;
+        query_builder.prepare_table_ref(source, sql);
+        write!(sql, " ON ")?;
+        query_builder.prepare_condition_where(on, sql);
+        match &self.matched {
+            Some(MergeMatched::Update(values)) => {
+                write!(sql, " WHEN MATCHED THEN UPDATE SET ")?;
+                for (i, (col, expr)) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(sql, ", ")?;
+                    }
+                    query_builder.prepare_column_ref(&ColumnRef::Column(col.clone()), sql);
+                    write!(sql, " = ")?;
+                    query_builder.prepare_simple_expr(expr, sql);
+                }
+            }
+            Some(MergeMatched::Delete) => write!(sql, " WHEN MATCHED THEN DELETE")?,
+            None => {}
+        }
+        if !self.not_matched.is_empty() {
+            write!(sql, " WHEN NOT MATCHED THEN INSERT (")?;
+            for (i, (col, _)) in self.not_matched.iter().enumerate() {
+                if i > 0 {
+                    write!(sql, ", ")?;
+                }
+                query_builder.prepare_column_ref(&ColumnRef::Column(col.clone()), sql);
+            }
+            write!(sql, ") VALUES (")?;
+            for (i, (_, expr)) in self.not_matched.iter().enumerate() {
+                if i > 0 {
+                    write!(sql, ", ")?;
+                }
+                query_builder.prepare_simple_expr(expr, sql);
+            }
+            write!(sql, ")")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{entity::*, error::*, query::*, DbBackend, Statement};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn merge_using_select() -> Result<(), DbErr> {
+        let merge = Merge::target(fruit::Entity).using(
+            fruit::Entity::find().filter(fruit::Column::CakeId.eq(2)),
+            "source",
+        );
+        let on = merge
+            .source_col("id")
+            .equals((fruit::Entity, fruit::Column::Id));
+        let merge = merge.on(on).when_matched_delete();
+
+        assert_eq!(
+            merge.build(DbBackend::Postgres)?,
+            [Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"MERGE INTO "fruit" USING"#,
+                    r#"(SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."cake_id" = $1) AS "source""#,
+                    r#"ON "source"."id" = "fruit"."id" WHEN MATCHED THEN DELETE"#,
+                ]
+                .join(" "),
+                [2i32.into()]
+            )]
+        );
+        assert_eq!(
+            merge.build(DbBackend::MySql)?,
+            [Statement::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "DELETE `fruit` FROM `fruit`,",
+                    "(SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit` WHERE `fruit`.`cake_id` = ?) AS `source`",
+                    "WHERE `source`.`id` = `fruit`.`id`",
+                ]
+                .join(" "),
+                [2i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_errors() {
+        let incoming = ValuesTable::new("incoming", ["id"]).row(1);
+        let on = incoming.col("id").equals((cake::Entity, cake::Column::Id));
+
+        assert!(Merge::target(cake::Entity)
+            .on(on.clone())
+            .when_matched_delete()
+            .build(DbBackend::Postgres)
+            .is_err());
+        assert!(Merge::target(cake::Entity)
+            .using_values(incoming.clone())
+            .on(on.clone())
+            .build(DbBackend::Postgres)
+            .is_err());
+
+        let merge = Merge::target(cake::Entity)
+            .using_values(incoming.clone())
+            .on(on)
+            .when_matched_delete()
+            .when_not_matched_insert([(cake::Column::Id, incoming.col("id"))]);
+        assert!(merge.build(DbBackend::Postgres).is_ok());
+        assert!(merge.build(DbBackend::Sqlite).is_err());
+    }
+}
@@ -1,27 +1,48 @@
+mod case;
 pub(crate) mod combine;
+mod copy;
+mod decimal;
 mod delete;
 mod helper;
 mod insert;
 mod join;
 #[cfg(feature = "with-json")]
 mod json;
+#[cfg(feature = "with-json")]
+mod json_filter;
 mod loader;
+mod merge;
+#[cfg(feature = "query-string")]
+mod query_string;
+mod sample;
 mod select;
+mod temporal;
 mod traits;
 mod update;
 mod util;
+mod values;
 
+pub use case::*;
 pub use combine::{SelectA, SelectB};
+pub use copy::*;
+pub use decimal::*;
 pub use delete::*;
 pub use helper::*;
 pub use insert::*;
 #[cfg(feature = "with-json")]
 pub use json::*;
+#[cfg(feature = "with-json")]
+pub use json_filter::*;
 pub use loader::*;
+pub use merge::*;
+#[cfg(feature = "query-string")]
+pub use query_string::*;
+pub use sample::*;
 pub use select::*;
 pub use traits::*;
 pub use update::*;
 pub use util::*;
+pub use values::*;
 
 pub use crate::{
     ConnectionTrait, CursorTrait, InsertResult, PaginatorTrait, Statement, StreamTrait,
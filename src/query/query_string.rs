@@ -0,0 +1,399 @@
+use crate::{
+    error::*, parse_json_filter, query::select::column_by_name, ColumnTrait, ColumnType,
+    ConnectionTrait, EntityTrait, JsonValue, Paginator, PaginatorTrait, QueryFilter, QuerySelect,
+    Select, SelectModel,
+};
+use sea_query::Order;
+use std::collections::BTreeMap;
+
+/// The parameters of a JSON:API style query string, e.g.
+/// `filter[name][contains]=berry&sort=-id,name&page[number]=2&page[size]=20&fields[fruit]=id,name`.
+///
+/// | Parameter | Meaning |
+/// |-----------|---------|
+/// | `filter[col]=v` | the column equals `v` |
+/// | `filter[col][op]=v` | an operator of [`parse_json_filter`]; `in` and `not_in` take a comma separated list |
+/// | `sort=a,-b` | order by `a` ascending, then by `b` descending |
+/// | `page[number]=n`, `page[size]=s` | the `n`-th page (starting from 1) of `s` rows |
+/// | `fields[table]=a,b` | only select the columns `a` and `b` of `table` |
+///
+/// Other parameters are ignored. Column names are validated when applied to a [`Select`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueryString {
+    /// Filters as (column, operator, value)
+    pub filters: Vec<(String, String, String)>,
+    /// Columns to order by
+    pub sort: Vec<(String, Order)>,
+    /// The requested page, starting from 1
+    pub page_number: Option<u64>,
+    /// The requested number of rows per page
+    pub page_size: Option<u64>,
+    /// The columns to select, keyed by table name
+    pub fields: BTreeMap<String, Vec<String>>,
+}
+
+impl QueryString {
+    /// Parse a query string, with or without the leading `?`
+    pub fn parse(query: &str) -> Result<Self, DbErr> {
+        let mut params = Self::default();
+        let query = query.strip_prefix('?').unwrap_or(query);
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            let value = value.into_owned();
+            let (name, path) = split_key(&key)?;
+            match (name, path.as_slice()) {
+                ("filter", [col]) => {
+                    params
+                        .filters
+                        .push((col.to_string(), "eq".to_owned(), value));
+                }
+                ("filter", [col, op]) => {
+                    params
+                        .filters
+                        .push((col.to_string(), op.to_string(), value));
+                }
+                ("sort", []) => {
+                    for col in value.split(',').filter(|col| !col.is_empty()) {
+                        params.sort.push(match col.strip_prefix('-') {
+                            Some(col) => (col.to_owned(), Order::Desc),
+                            None => (col.to_owned(), Order::Asc),
+                        });
+                    }
+                }
+                ("page", ["number"]) => params.page_number = Some(parse_page(&key, &value)?),
+                ("page", ["size"]) => params.page_size = Some(parse_page(&key, &value)?),
+                ("fields", [table]) => {
+                    params.fields.insert(
+                        table.to_string(),
+                        value
+                            .split(',')
+                            .filter(|col| !col.is_empty())
+                            .map(str::to_owned)
+                            .collect(),
+                    );
+                }
+                ("filter" | "sort" | "page" | "fields", _) => {
+                    return Err(DbErr::Custom(format!("Unexpected query parameter `{key}`")))
+                }
+                _ => {}
+            }
+        }
+        Ok(params)
+    }
+
+    /// Apply the filters, the ordering and the sparse fieldset of the Entity's table to a query.
+    ///
+    /// When only some columns are selected, the rows no longer deserialize into `E::Model`;
+    /// read them with [`Select::into_json`] instead.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let params = QueryString::parse(
+    ///     "filter[name][contains]=berry&filter[cake_id][in]=1,2&sort=-id&fields[fruit]=id,name",
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     params
+    ///         .apply(fruit::Entity::find())?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name" FROM "fruit""#,
+    ///         r#"WHERE "fruit"."name" LIKE '%berry%' AND "fruit"."cake_id" IN (1, 2)"#,
+    ///         r#"ORDER BY "fruit"."id" DESC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert!(QueryString::parse("sort=password")?
+    ///     .apply(fruit::Entity::find())
+    ///     .is_err());
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn apply<E>(&self, mut select: Select<E>) -> Result<Select<E>, DbErr>
+    where
+        E: EntityTrait,
+    {
+        if !self.filters.is_empty() {
+            select = select.filter(parse_json_filter::<E>(&self.filter_json::<E>())?);
+        }
+        for (col, ord) in self.sort.iter() {
+            select = select.try_order_by_str(col, ord.clone())?;
+        }
+        if let Some(fields) = self.fields.get(E::default().table_name()) {
+            let cols = fields
+                .iter()
+                .map(|name| column_by_name::<E>(name))
+                .collect::<Result<Vec<_>, _>>()?;
+            select = select.select_only().columns(cols);
+        }
+        Ok(select)
+    }
+
+    /// The index of the requested page, starting from 0
+    pub fn page_index(&self) -> u64 {
+        self.page_number.unwrap_or(1).saturating_sub(1)
+    }
+
+    /// Apply the parameters to a query and paginate it, positioned at the requested page,
+    /// which is then fetched with [`Paginator::fetch`].
+    /// Without a `page[size]` parameter, `default_page_size` rows are fetched per page;
+    /// a `page[size]` above `max_page_size` is an error.
+    ///
+    /// The rows are decoded into `E::Model`, so restricting its columns with `fields[..]` is an error;
+    /// use [`QueryString::paginate_json`] to honour it.
+    pub fn paginate<'db, C, E>(
+        &self,
+        select: Select<E>,
+        db: &'db C,
+        default_page_size: u64,
+        max_page_size: u64,
+    ) -> Result<Paginator<'db, C, SelectModel<E::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        E: EntityTrait,
+        E::Model: Sync,
+    {
+        let table = E::default();
+        if self.fields.contains_key(table.table_name()) {
+            return Err(DbErr::Custom(format!(
+                "`fields[{}]` cannot be decoded into a model, paginate it as JSON instead",
+                table.table_name()
+            )));
+        }
+        let page_size = self.page_size(default_page_size, max_page_size)?;
+        let mut paginator = self.apply(select)?.paginate(db, page_size);
+        paginator.page = self.page_index();
+        Ok(paginator)
+    }
+
+    /// Like [`QueryString::paginate`], but fetching each row as a JSON object,
+    /// holding only the columns requested by `fields[..]` if any.
+    pub fn paginate_json<'db, C, E>(
+        &self,
+        select: Select<E>,
+        db: &'db C,
+        default_page_size: u64,
+        max_page_size: u64,
+    ) -> Result<Paginator<'db, C, SelectModel<JsonValue>>, DbErr>
+    where
+        C: ConnectionTrait,
+        E: EntityTrait,
+    {
+        let page_size = self.page_size(default_page_size, max_page_size)?;
+        let mut paginator = self.apply(select)?.into_json().paginate(db, page_size);
+        paginator.page = self.page_index();
+        Ok(paginator)
+    }
+
+    fn page_size(&self, default_page_size: u64, max_page_size: u64) -> Result<u64, DbErr> {
+        match self.page_size.unwrap_or(default_page_size) {
+            0 => Err(DbErr::Custom("Page size should not be zero".to_owned())),
+            page_size if page_size > max_page_size => Err(DbErr::Custom(format!(
+                "Page size should not exceed {max_page_size}, got {page_size}"
+            ))),
+            page_size => Ok(page_size),
+        }
+    }
+
+    /// The filters as a document of [`parse_json_filter`], with each value converted according to its column
+    fn filter_json<E>(&self) -> JsonValue
+    where
+        E: EntityTrait,
+    {
+        let filters = self
+            .filters
+            .iter()
+            .map(|(col, op, value)| {
+                let col_type = E::Column::from_name(col).map(|c| c.def().get_column_type().clone());
+                let value = match op.as_str() {
+                    "in" | "not_in" => JsonValue::Array(
+                        value
+                            .split(',')
+                            .map(|v| to_json(col_type.as_ref(), v))
+                            .collect(),
+                    ),
+                    _ => to_json(col_type.as_ref(), value),
+                };
+                serde_json::json!({ col: { op: value } })
+            })
+            .collect();
+        serde_json::json!({ "and": JsonValue::Array(filters) })
+    }
+}
+
+/// Query string values are untyped; read them as JSON scalars unless the column is textual
+fn to_json(col_type: Option<&ColumnType>, value: &str) -> JsonValue {
+    match col_type {
+        Some(
+            ColumnType::Char(_)
+            | ColumnType::String(_)
+            | ColumnType::Text
+            | ColumnType::Enum { .. },
+        ) => JsonValue::String(value.to_owned()),
+        _ => match serde_json::from_str::<JsonValue>(value) {
+            Ok(json) if !json.is_array() && !json.is_object() => json,
+            _ => JsonValue::String(value.to_owned()),
+        },
+    }
+}
+
+/// Split `filter[name][op]` into `filter` and `[name, op]`
+fn split_key(key: &str) -> Result<(&str, Vec<&str>), DbErr> {
+    let (name, mut rest) = match key.find('[') {
+        Some(i) => key.split_at(i),
+        None => return Ok((key, Vec::new())),
+    };
+    let mut path = Vec::new();
+    while !rest.is_empty() {
+        match rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+            Some((segment, r)) => {
+                path.push(segment);
+                rest = r;
+            }
+            None => return Err(DbErr::Custom(format!("Malformed query parameter `{key}`"))),
+        }
+    }
+    Ok((name, path))
+}
+
+fn parse_page(key: &str, value: &str) -> Result<u64, DbErr> {
+    value
+        .parse()
+        .map_err(|_| DbErr::Custom(format!("`{key}` expects a number, got `{value}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryString;
+    use crate::tests_cfg::cake;
+    use crate::{entity::*, error::*, query::*, DbBackend, Statement};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn query_string_parse() -> Result<(), DbErr> {
+        let params = QueryString::parse(
+            "?filter%5Bname%5D=Cheese+Cake&filter[id][gte]=2&sort=name,-id&page[number]=3&page[size]=10&fields[cake]=name&utm_source=x",
+        )?;
+        assert_eq!(
+            params.filters,
+            [
+                ("name".to_owned(), "eq".to_owned(), "Cheese Cake".to_owned()),
+                ("id".to_owned(), "gte".to_owned(), "2".to_owned()),
+            ]
+        );
+        assert_eq!(params.page_index(), 2);
+        assert_eq!(params.page_size, Some(10));
+
+        assert_eq!(
+            params.apply(cake::Entity::find())?.build(DbBackend::MySql),
+            Statement::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "SELECT `cake`.`name` FROM `cake` WHERE `cake`.`name` = ? AND `cake`.`id` >= ?",
+                    "ORDER BY `cake`.`name` ASC, `cake`.`id` DESC",
+                ]
+                .join(" "),
+                ["Cheese Cake".into(), 2i32.into()]
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn query_string_errors() -> Result<(), DbErr> {
+        assert!(QueryString::parse("page[number]=two").is_err());
+        assert!(QueryString::parse("filter[name]][eq]=x").is_err());
+        assert!(QueryString::parse("filter=x").is_err());
+        assert!(QueryString::parse("filter[id]=abc")?
+            .apply(cake::Entity::find())
+            .is_err());
+        assert!(QueryString::parse("sort=%zz")?
+            .apply(cake::Entity::find())
+            .is_err());
+        assert!(QueryString::parse("fields[cake]=id,secret")?
+            .apply(cake::Entity::find())
+            .is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn query_string_paginate_errors() -> Result<(), DbErr> {
+        use crate::MockDatabase;
+
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        assert!(QueryString::parse("page[size]=0")?
+            .paginate(cake::Entity::find(), &db, 20, 100)
+            .is_err());
+        assert!(QueryString::parse("page[size]=101")?
+            .paginate(cake::Entity::find(), &db, 20, 100)
+            .is_err());
+        assert!(QueryString::parse("page[size]=101")?
+            .paginate_json(cake::Entity::find(), &db, 20, 100)
+            .is_err());
+        assert!(QueryString::parse("fields[cake]=name")?
+            .paginate(cake::Entity::find(), &db, 20, 100)
+            .is_err());
+        assert!(QueryString::parse("page[size]=100")?
+            .paginate(cake::Entity::find(), &db, 20, 100)
+            .is_ok());
+        Ok(())
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn query_string_paginate() -> Result<(), DbErr> {
+        use crate::{MockDatabase, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[cake::Model {
+                id: 3,
+                name: "Lemon Tart".to_owned(),
+            }]])
+            .into_connection();
+
+        let cakes = QueryString::parse("page[number]=3&page[size]=1&sort=id")?
+            .paginate(cake::Entity::find(), &db, 20, 100)?
+            .fetch()
+            .await?;
+
+        assert_eq!(cakes[0].id, 3);
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."id" ASC LIMIT $1 OFFSET $2"#,
+                [1u64.into(), 2u64.into()]
+            )]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn query_string_paginate_json() -> Result<(), DbErr> {
+        use crate::{MockDatabase, Transaction};
+        use std::collections::BTreeMap;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([[BTreeMap::from([("name".to_owned(), "Lemon Tart".into())])]])
+            .into_connection();
+
+        let cakes = QueryString::parse("page[number]=2&page[size]=1&fields[cake]=name")?
+            .paginate_json(cake::Entity::find(), &db, 20, 100)?
+            .fetch()
+            .await?;
+
+        assert_eq!(cakes, [serde_json::json!({ "name": "Lemon Tart" })]);
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."name" FROM "cake" LIMIT $1 OFFSET $2"#,
+                [1u64.into(), 1u64.into()]
+            )]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Select};
+use sea_query::{DynIden, Expr, Func, Iden, Quote, SeaRc, TableRef};
+use std::fmt;
+
+/// The sampling method of a `TABLESAMPLE` clause
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleMethod {
+    /// Each row is picked with the given probability; slower but evenly spread
+    Bernoulli,
+    /// Each storage page is picked with the given probability; faster but clustered
+    System,
+}
+
+/// A table name followed by its `TABLESAMPLE` clause
+struct SampledTable {
+    table: DynIden,
+    method: SampleMethod,
+    percent: f64,
+}
+
+impl Iden for SampledTable {
+    fn prepare(&self, s: &mut dyn fmt::Write, q: Quote) {
+        self.table.prepare(s, q);
+        let method = match self.method {
+            SampleMethod::Bernoulli => "BERNOULLI",
+            SampleMethod::System => "SYSTEM",
+        };
+        let _ = write!(s, " TABLESAMPLE {method} ({})", self.percent);
+    }
+
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        self.table.unquoted(s);
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Select a random sample of about `percent` percent of the rows of the Entity's table.
+    ///
+    /// Rendered as `TABLESAMPLE` on Postgres. MySQL and SQLite do not support `TABLESAMPLE`,
+    /// there every row is kept with the same probability by filtering on a random number,
+    /// which still scans the whole table; the method is ignored.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .table_sample(SampleMethod::Bernoulli, 10.0, DbBackend::Postgres)
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" TABLESAMPLE BERNOULLI (10)"#,
+    ///         r#"WHERE "cake"."name" LIKE '%cheese%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .table_sample(SampleMethod::System, 2.5, DbBackend::MySql)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE RAND() < 0.025"
+    /// );
+    /// ```
+    pub fn table_sample(
+        mut self,
+        method: SampleMethod,
+        percent: f64,
+        db_backend: DbBackend,
+    ) -> Self {
+        match db_backend {
+            DbBackend::Postgres => {
+                let sampled = |table: DynIden| -> DynIden {
+                    SeaRc::new(SampledTable {
+                        table,
+                        method,
+                        percent,
+                    })
+                };
+                let table_ref = match E::default().table_ref() {
+                    TableRef::Table(table) => TableRef::Table(sampled(table)),
+                    TableRef::SchemaTable(schema, table) => {
+                        TableRef::SchemaTable(schema, sampled(table))
+                    }
+                    table_ref => table_ref,
+                };
+                self.query.from_clear().from(table_ref);
+                self
+            }
+            DbBackend::MySql => self.filter(Expr::expr(Func::random()).lt(percent / 100.0)),
+            DbBackend::Sqlite => {
+                // `RANDOM()` returns a 64-bit signed integer on SQLite
+                let threshold = (percent * 10_000.0) as i64;
+                self.filter(
+                    Expr::expr(Func::abs(Expr::expr(Func::random()).modulo(1_000_000)))
+                        .lt(threshold),
+                )
+            }
+        }
+    }
+
+    /// Pick up to `n` random rows out of a sample of about `percent` percent of the table,
+    /// instead of ordering every row of the table randomly as [`EntityTrait::find_random`] does.
+    ///
+    /// On Postgres the sample is taken with `TABLESAMPLE SYSTEM`, which only reads the sampled pages.
+    /// Fewer than `n` rows are returned if the sample turns out too small.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .random_sampled(5, 1.0, DbBackend::Postgres)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" TABLESAMPLE SYSTEM (1) ORDER BY RANDOM() ASC LIMIT 5"#
+    /// );
+    /// ```
+    pub fn random_sampled(self, n: u64, percent: f64, db_backend: DbBackend) -> Self {
+        self.table_sample(SampleMethod::System, percent, db_backend)
+            .order_by_random()
+            .limit(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{entity::*, query::*, DbBackend};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn table_sample_with_join() {
+        assert_eq!(
+            cake::Entity::find()
+                .table_sample(SampleMethod::System, 1.0, DbBackend::Postgres)
+                .inner_join(fruit::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" TABLESAMPLE SYSTEM (1)"#,
+                r#"INNER JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn table_sample_sqlite() {
+        assert_eq!(
+            fruit::Entity::find()
+                .table_sample(SampleMethod::Bernoulli, 12.5, DbBackend::Sqlite)
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE ABS(RANDOM() % 1000000) < 125000"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn random_sampled_mysql() {
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::CakeId.eq(1))
+                .random_sampled(2, 50.0, DbBackend::MySql)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE `fruit`.`cake_id` = 1 AND RAND() < 0.5 ORDER BY RAND() ASC LIMIT 2",
+            ]
+            .join(" ")
+        );
+    }
+}
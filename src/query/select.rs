@@ -1,7 +1,10 @@
-use crate::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use crate::{
+    error::*, ColumnFromStrErr, ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder,
+    QuerySelect, QueryTrait,
+};
 use core::fmt::Debug;
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoColumnRef, SelectStatement, SimpleExpr};
+use sea_query::{Expr, IntoColumnRef, Order, SelectStatement, SimpleExpr, Value};
 
 /// Defines a structure to perform select operations
 #[derive(Clone, Debug)]
@@ -35,6 +38,19 @@ where
     pub(crate) entity: PhantomData<(E, F)>,
 }
 
+/// Defines a structure to perform a SELECT operation on a Model, with the related Models
+/// aggregated into a JSON array within the same row
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug)]
+pub struct SelectJsonRelated<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    pub(crate) query: SelectStatement,
+    pub(crate) entity: PhantomData<(E, F)>,
+}
+
 /// Performs a conversion to [SimpleExpr]
 pub trait IntoSimpleExpr {
     /// Method to perform the conversion
@@ -77,6 +93,19 @@ macro_rules! impl_trait {
                 &mut self.query
             }
         }
+
+        #[cfg(feature = "with-json")]
+        impl<E, F> $trait for SelectJsonRelated<E, F>
+        where
+            E: EntityTrait,
+            F: EntityTrait,
+        {
+            type QueryStatement = SelectStatement;
+
+            fn query(&mut self) -> &mut SelectStatement {
+                &mut self.query
+            }
+        }
     };
 }
 
@@ -135,6 +164,64 @@ where
     }
 }
 
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Order by a column referenced by its name, e.g. taken from a `sort=` parameter of an HTTP request.
+    /// An error is returned if the Entity has no column of that name.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .try_order_by_str("name", Order::Desc)?
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` ORDER BY `cake`.`name` DESC"
+    /// );
+    ///
+    /// assert!(cake::Entity::find()
+    ///     .try_order_by_str("name; DROP TABLE cake", Order::Asc)
+    ///     .is_err());
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn try_order_by_str(self, name: &str, ord: Order) -> Result<Self, DbErr> {
+        Ok(self.order_by(column_by_name::<E>(name)?, ord))
+    }
+
+    /// Filter by a column referenced by its name being equal to a value.
+    /// An error is returned if the Entity has no column of that name.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .try_filter_str("cake_id", 2)?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."cake_id" = 2"#
+    /// );
+    /// # Ok::<(), sea_orm::DbErr>(())
+    /// ```
+    pub fn try_filter_str<V>(self, name: &str, value: V) -> Result<Self, DbErr>
+    where
+        V: Into<Value>,
+    {
+        Ok(self.filter(column_by_name::<E>(name)?.eq(value)))
+    }
+}
+
+pub(crate) fn column_by_name<E>(name: &str) -> Result<E::Column, DbErr>
+where
+    E: EntityTrait,
+{
+    E::Column::from_name(name)
+        .ok_or_else(|| DbErr::Custom(ColumnFromStrErr(name.to_owned()).to_string()))
+}
+
 impl<E> QueryTrait for Select<E>
 where
     E: EntityTrait,
@@ -0,0 +1,170 @@
+use crate::{DbBackend, EntityTrait, Iterable, Select};
+use sea_query::{
+    Alias, DynIden, Expr, Iden, IntoIden, MysqlQueryBuilder, Query, QueryBuilder, Quote, SeaRc,
+    TableRef, UnionType, Value,
+};
+use std::fmt;
+
+/// A table name followed by its `FOR SYSTEM_TIME AS OF` clause
+struct VersionedTable {
+    table: DynIden,
+    timestamp: String,
+}
+
+impl Iden for VersionedTable {
+    fn prepare(&self, s: &mut dyn fmt::Write, q: Quote) {
+        self.table.prepare(s, q);
+        let _ = write!(s, " FOR SYSTEM_TIME AS OF TIMESTAMP {}", self.timestamp);
+    }
+
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        self.table.unquoted(s);
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Select the rows of the Entity's table as they were at the given time.
+    ///
+    /// On MySQL, the table has to be a system-versioned table of MariaDB, which is queried
+    /// `FOR SYSTEM_TIME AS OF` the time.
+    ///
+    /// On Postgres and SQLite, the table has to keep its history in a shadow table, as created by
+    /// `create_history` of `sea-orm-migration` on Postgres: the table has a `valid_from` column of
+    /// the time each row was last changed, and the `<table>_history` table has the previous
+    /// versions of the rows with their `valid_from` and `valid_to` times. The rows valid at the
+    /// time are selected from both tables.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .as_of("2024-01-01 00:00:00", DbBackend::MySql)
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name`",
+    ///         "FROM `cake` FOR SYSTEM_TIME AS OF TIMESTAMP '2024-01-01 00:00:00'",
+    ///         "WHERE `cake`.`name` LIKE '%cheese%'",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .as_of("2024-01-01 00:00:00", DbBackend::Postgres)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name""#,
+    ///         r#"FROM (SELECT "id", "name" FROM "cake""#,
+    ///         r#"WHERE "valid_from" <= CAST('2024-01-01 00:00:00' AS timestamptz)"#,
+    ///         r#"UNION ALL (SELECT "id", "name" FROM "cake_history""#,
+    ///         r#"WHERE "valid_from" <= CAST('2024-01-01 00:00:00' AS timestamptz)"#,
+    ///         r#"AND "valid_to" > CAST('2024-01-01 00:00:00' AS timestamptz)))"#,
+    ///         r#"AS "cake""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn as_of<T>(mut self, time: T, db_backend: DbBackend) -> Self
+    where
+        T: Into<Value>,
+    {
+        let entity = E::default();
+        match db_backend {
+            DbBackend::MySql => {
+                let timestamp = MysqlQueryBuilder.value_to_string(&time.into());
+                let versioned =
+                    |table: DynIden| -> DynIden { SeaRc::new(VersionedTable { table, timestamp }) };
+                let table_ref = match entity.table_ref() {
+                    TableRef::Table(table) => TableRef::Table(versioned(table)),
+                    TableRef::SchemaTable(schema, table) => {
+                        TableRef::SchemaTable(schema, versioned(table))
+                    }
+                    table_ref => table_ref,
+                };
+                self.query.from_clear().from(table_ref);
+            }
+            DbBackend::Postgres | DbBackend::Sqlite => {
+                let time = match db_backend {
+                    DbBackend::Postgres => Expr::val(time).cast_as(Alias::new("timestamptz")),
+                    _ => Expr::val(time).into(),
+                };
+                let history = Alias::new(format!("{}_history", entity.table_name())).into_iden();
+                let history_ref = match entity.schema_name() {
+                    Some(schema) => TableRef::SchemaTable(Alias::new(schema).into_iden(), history),
+                    None => TableRef::Table(history),
+                };
+                let valid_from = Expr::col(Alias::new("valid_from")).lte(time.clone());
+                let valid_to = Expr::col(Alias::new("valid_to")).gt(time);
+
+                let mut rows = Query::select()
+                    .columns(E::Column::iter())
+                    .from(entity.table_ref())
+                    .and_where(valid_from.clone())
+                    .to_owned();
+                rows.union(
+                    UnionType::All,
+                    Query::select()
+                        .columns(E::Column::iter())
+                        .from(history_ref)
+                        .and_where(valid_from)
+                        .and_where(valid_to)
+                        .to_owned(),
+                );
+                self.query
+                    .from_clear()
+                    .from_subquery(rows, entity.into_iden());
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{entity::*, query::*, DbBackend};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn as_of_with_join() {
+        assert_eq!(
+            cake::Entity::find()
+                .as_of("2024-01-01 00:00:00", DbBackend::MySql)
+                .inner_join(fruit::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name`",
+                "FROM `cake` FOR SYSTEM_TIME AS OF TIMESTAMP '2024-01-01 00:00:00'",
+                "INNER JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn find_as_of_sqlite() {
+        assert_eq!(
+            fruit::Entity::find_as_of("2024-01-01 00:00:00", DbBackend::Sqlite)
+                .filter(fruit::Column::CakeId.eq(1))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                r#"FROM (SELECT "id", "name", "cake_id" FROM "fruit""#,
+                r#"WHERE "valid_from" <= '2024-01-01 00:00:00'"#,
+                r#"UNION ALL SELECT "id", "name", "cake_id" FROM "fruit_history""#,
+                r#"WHERE "valid_from" <= '2024-01-01 00:00:00' AND "valid_to" > '2024-01-01 00:00:00')"#,
+                r#"AS "fruit" WHERE "fruit"."cake_id" = 1"#,
+            ]
+            .join(" ")
+        );
+    }
+}
@@ -1,9 +1,17 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    join_condition, ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, EntityTrait, Iterable,
+    PrimaryKeyToColumn, QueryFilter, QueryTrait, Related, Statement,
 };
+#[cfg(feature = "with-json")]
+use crate::{json_path_array, json_path_string, IntoJsonPath, JsonValue};
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+#[cfg(feature = "with-json")]
+use sea_query::{Alias, Func, Value};
+use sea_query::{
+    ConditionHolder, ConditionalStatement, DynIden, Expr, IntoCondition, IntoIden, IntoTableRef,
+    LogicalChainOper, QueryBuilder, SeaRc, SimpleExpr, SqlWriterValues, TableRef, UpdateStatement,
+};
+use std::fmt::Write;
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -29,6 +37,26 @@ where
     pub(crate) entity: PhantomData<E>,
 }
 
+/// Defines an UPDATE operation on multiple ActiveModels, driven by the rows of other tables.
+/// Renders `UPDATE .. FROM` on Postgres and SQLite, and the multiple-table `UPDATE` syntax on MySQL.
+#[derive(Clone, Debug)]
+pub struct UpdateManyFrom<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) values: Vec<(DynIden, SimpleExpr)>,
+    pub(crate) from: Vec<TableRef>,
+    pub(crate) condition: UpdateFromCondition,
+    pub(crate) entity: PhantomData<E>,
+}
+
+/// The `WHERE` clause of an [`UpdateManyFrom`], matching the rows of the updated table with the
+/// rows of the joined tables
+#[derive(Clone, Debug, Default)]
+pub struct UpdateFromCondition {
+    pub(crate) condition: ConditionHolder,
+}
+
 impl Update {
     /// Update one ActiveModel
     ///
@@ -83,6 +111,52 @@ impl Update {
             entity: PhantomData,
         }
     }
+
+    /// Update many ActiveModels, driven by the rows of other tables joined with
+    /// [`UpdateManyFrom::from_related`] or [`UpdateManyFrom::from_table`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Update::many_from(fruit::Entity)
+    ///         .from_related(cake::Entity)
+    ///         .col_expr(fruit::Column::Name, Expr::col((cake::Entity, cake::Column::Name)).into())
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET "name" = "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."name" LIKE '%Cheese%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Update::many_from(fruit::Entity)
+    ///         .from_related(cake::Entity)
+    ///         .col_expr(fruit::Column::Name, Expr::col((cake::Entity, cake::Column::Name)).into())
+    ///         .filter(cake::Column::Name.contains("Cheese"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "UPDATE `fruit`, `cake` SET `fruit`.`name` = `cake`.`name`",
+    ///         "WHERE `fruit`.`cake_id` = `cake`.`id` AND `cake`.`name` LIKE '%Cheese%'",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn many_from<E>(_: E) -> UpdateManyFrom<E>
+    where
+        E: EntityTrait,
+    {
+        UpdateManyFrom {
+            values: Vec::new(),
+            from: Vec::new(),
+            condition: UpdateFromCondition::default(),
+            entity: PhantomData,
+        }
+    }
 }
 
 impl<A> UpdateOne<A>
@@ -141,6 +215,32 @@ where
     }
 }
 
+impl<E> QueryFilter for UpdateManyFrom<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = UpdateFromCondition;
+
+    fn query(&mut self) -> &mut UpdateFromCondition {
+        &mut self.condition
+    }
+}
+
+impl ConditionalStatement for UpdateFromCondition {
+    fn and_or_where(&mut self, condition: LogicalChainOper) -> &mut Self {
+        self.condition.add_and_or(condition);
+        self
+    }
+
+    fn cond_where<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: IntoCondition,
+    {
+        self.condition.add_condition(condition.into_condition());
+        self
+    }
+}
+
 impl<A> QueryTrait for UpdateOne<A>
 where
     A: ActiveModelTrait,
@@ -208,13 +308,173 @@ where
         self.query.value(col, expr);
         self
     }
+
+    /// Set the value at `path` inside a JSON column, leaving the rest of the document untouched.
+    /// See [`ColumnTrait::json_get`] for the path syntax.
+    ///
+    /// Renders `jsonb_set` on Postgres (the column must be `jsonb`) and `JSON_SET` on MySQL and SQLite.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    /// use serde_json::json;
+    ///
+    /// assert_eq!(
+    ///     Update::many(recipe::Entity)
+    ///         .json_set(recipe::Column::Payload, DbBackend::Postgres, "user.id", json!(5))
+    ///         .filter(recipe::Column::Id.eq(1))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"UPDATE "recipe" SET "payload" = jsonb_set("recipe"."payload", CAST('{user,id}' AS text[]), '5') WHERE "recipe"."id" = 1"#,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Update::many(recipe::Entity)
+    ///         .json_set(recipe::Column::Payload, DbBackend::MySql, "user.id", json!(5))
+    ///         .filter(recipe::Column::Id.eq(1))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"UPDATE `recipe` SET `payload` = JSON_SET(`recipe`.`payload`, '$.user.id', CAST('5' AS JSON)) WHERE `recipe`.`id` = 1"#,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Update::many(recipe::Entity)
+    ///         .json_set(recipe::Column::Payload, DbBackend::Sqlite, "tags[0]", json!("vegan"))
+    ///         .filter(recipe::Column::Id.eq(1))
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"UPDATE "recipe" SET "payload" = JSON_SET("recipe"."payload", '$.tags[0]', JSON('"vegan"')) WHERE "recipe"."id" = 1"#,
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    pub fn json_set<C, P, V>(mut self, col: C, db_backend: DbBackend, path: P, value: V) -> Self
+    where
+        C: ColumnTrait,
+        P: IntoJsonPath,
+        V: Into<JsonValue>,
+    {
+        let segments = path.into_json_path();
+        let target = Expr::col((col.entity_name(), col));
+        let value = Value::Json(Some(Box::new(value.into())));
+        let expr: SimpleExpr = match db_backend {
+            DbBackend::Postgres => Func::cust(Alias::new("jsonb_set"))
+                .arg(target)
+                .arg(Expr::val(json_path_array(&segments)).cast_as(Alias::new("text[]")))
+                .arg(Expr::val(value))
+                .into(),
+            DbBackend::MySql => Func::cust(Alias::new("JSON_SET"))
+                .arg(target)
+                .arg(json_path_string(&segments))
+                .arg(Expr::val(value).cast_as(Alias::new("JSON")))
+                .into(),
+            DbBackend::Sqlite => Func::cust(Alias::new("JSON_SET"))
+                .arg(target)
+                .arg(json_path_string(&segments))
+                .arg(Func::cust(Alias::new("JSON")).arg(Expr::val(value)))
+                .into(),
+        };
+        self.query.value(col, expr);
+        self
+    }
+}
+
+impl<E> UpdateManyFrom<E>
+where
+    E: EntityTrait,
+{
+    /// Join the table of another related Entity
+    pub fn from_related<R>(mut self, _: R) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        if let Some(via) = E::via() {
+            self.from.push(via.to_tbl.clone());
+            self.condition.cond_where(join_condition(via));
+        }
+        let rel = E::to();
+        self.from.push(rel.to_tbl.clone());
+        self.condition.cond_where(join_condition(rel));
+        self
+    }
+
+    /// Join another table, rows are matched by the given condition
+    pub fn from_table<T, C>(mut self, tbl: T, condition: C) -> Self
+    where
+        T: IntoTableRef,
+        C: IntoCondition,
+    {
+        self.from.push(tbl.into_table_ref());
+        self.condition.cond_where(condition);
+        self
+    }
+
+    /// Creates a [SimpleExpr] from a column
+    pub fn col_expr<T>(mut self, col: T, expr: SimpleExpr) -> Self
+    where
+        T: IntoIden,
+    {
+        self.values.push((col.into_iden(), expr));
+        self
+    }
+
+    /// Build the query as [`Statement`]
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        let query_builder = db_backend.get_query_builder();
+        let (placeholder, numbered) = query_builder.placeholder();
+        let mut sql = SqlWriterValues::new(placeholder, numbered);
+        // Writing into a string doesn't fail
+        let _ = self.prepare_update_from(db_backend, query_builder.as_ref(), &mut sql);
+        let (sql, values) = sql.into_parts();
+        Statement::from_sql_and_values(db_backend, sql, values)
+    }
+
+    fn prepare_update_from(
+        &self,
+        db_backend: DbBackend,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut SqlWriterValues,
+    ) -> std::fmt::Result {
+        write!(sql, "UPDATE ")?;
+        query_builder.prepare_table_ref(&E::default().table_ref(), sql);
+        if db_backend == DbBackend::MySql {
+            for tbl in self.from.iter() {
+                write!(sql, ", ")?;
+                query_builder.prepare_table_ref(tbl, sql);
+            }
+        }
+
+        write!(sql, " SET ")?;
+        for (i, (col, expr)) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(sql, ", ")?;
+            }
+            // On MySQL, the target columns have to be qualified, as the joined tables may share column names
+            let col = match db_backend {
+                DbBackend::MySql => Expr::col((E::default(), SeaRc::clone(col))),
+                DbBackend::Postgres | DbBackend::Sqlite => Expr::col(SeaRc::clone(col)),
+            };
+            query_builder.prepare_simple_expr(&col.into(), sql);
+            write!(sql, " = ")?;
+            query_builder.prepare_simple_expr(expr, sql);
+        }
+
+        if db_backend != DbBackend::MySql {
+            for (i, tbl) in self.from.iter().enumerate() {
+                write!(sql, "{}", if i == 0 { " FROM " } else { ", " })?;
+                query_builder.prepare_table_ref(tbl, sql);
+            }
+        }
+
+        query_builder.prepare_condition(&self.condition.condition, "WHERE", sql);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, fruit, lunch_set, sea_orm_active_enums::Tea};
-    use crate::{entity::*, query::*, DbBackend};
-    use sea_query::{Expr, Value};
+    use crate::tests_cfg::{cake, filling, fruit, lunch_set, sea_orm_active_enums::Tea};
+    use crate::{entity::*, query::*, DbBackend, Statement};
+    use sea_query::{Expr, Func, Value};
 
     #[test]
     fn update_1() {
@@ -328,4 +588,73 @@ mod tests {
             r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."id" = 1"#,
         );
     }
+
+    #[test]
+    fn update_9() {
+        assert_eq!(
+            Update::many_from(fruit::Entity)
+                .from_related(cake::Entity)
+                .col_expr(
+                    fruit::Column::Name,
+                    Func::coalesce([
+                        Expr::col((cake::Entity, cake::Column::Name)).into(),
+                        Expr::val("Unnamed").into(),
+                    ])
+                    .into()
+                )
+                .filter(cake::Column::Id.eq(3))
+                .build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"UPDATE "fruit" SET "name" = COALESCE("cake"."name", $1) FROM "cake""#,
+                    r#"WHERE "fruit"."cake_id" = "cake"."id" AND "cake"."id" = $2"#,
+                ]
+                .join(" "),
+                ["Unnamed".into(), 3i32.into()]
+            ),
+        );
+    }
+
+    #[test]
+    fn update_10() {
+        assert_eq!(
+            Update::many_from(cake::Entity)
+                .from_related(filling::Entity)
+                .col_expr(cake::Column::Name, Expr::value("Filled"))
+                .filter(filling::Column::Name.eq("Lemon"))
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"UPDATE "cake" SET "name" = 'Filled' FROM "cake_filling", "filling""#,
+                r#"WHERE "cake"."id" = "cake_filling"."cake_id" AND "cake_filling"."filling_id" = "filling"."id""#,
+                r#"AND "filling"."name" = 'Lemon'"#,
+            ]
+            .join(" "),
+        );
+    }
+
+    #[test]
+    fn update_11() {
+        assert_eq!(
+            Update::many_from(cake::Entity)
+                .from_table(
+                    fruit::Entity,
+                    Expr::col((fruit::Entity, fruit::Column::CakeId))
+                        .equals((cake::Entity, cake::Column::Id))
+                )
+                .col_expr(cake::Column::Name, Expr::value("Fruity"))
+                .filter(fruit::Column::Name.eq("Apple"))
+                .build(DbBackend::MySql),
+            Statement::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "UPDATE `cake`, `fruit` SET `cake`.`name` = ?",
+                    "WHERE `fruit`.`cake_id` = `cake`.`id` AND `fruit`.`name` = ?",
+                ]
+                .join(" "),
+                ["Fruity".into(), "Apple".into()]
+            ),
+        );
+    }
 }
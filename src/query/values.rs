@@ -0,0 +1,265 @@
+use crate::{ColumnTrait, DbBackend, EntityTrait, IntoIdentity, QueryOrder, QuerySelect, Select};
+use sea_query::{
+    Alias, DynIden, Expr, IntoIden, IntoValueTuple, JoinType, Order, SeaRc, SelectStatement,
+    TableRef, UnionType, Value, ValueTuple,
+};
+
+/// An in-memory list of rows that can be used as a derived table, e.g. joined against an Entity for bulk lookups.
+///
+/// Rendered as a `VALUES` list on Postgres and SQLite, and as `SELECT .. UNION ALL SELECT ..` on MySQL.
+/// Every row must have one value per column.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+///
+/// let prices = ValuesTable::new("price", ["name", "cents"])
+///     .row(("Apple", 120))
+///     .row(("Banana", 80));
+///
+/// assert_eq!(
+///     fruit::Entity::find()
+///         .join_values(
+///             JoinType::InnerJoin,
+///             &prices,
+///             DbBackend::Postgres,
+///             prices.col("name").equals((fruit::Entity, fruit::Column::Name)),
+///         )
+///         .filter(prices.col("cents").lt(100))
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+///         r#"INNER JOIN (SELECT "column1" AS "name", "column2" AS "cents" FROM (VALUES ('Apple', 120), ('Banana', 80)) AS "t") AS "price""#,
+///         r#"ON "price"."name" = "fruit"."name" WHERE "price"."cents" < 100"#,
+///     ]
+///     .join(" ")
+/// );
+///
+/// assert_eq!(
+///     fruit::Entity::find()
+///         .join_values(
+///             JoinType::InnerJoin,
+///             &prices,
+///             DbBackend::MySql,
+///             prices.col("name").equals((fruit::Entity, fruit::Column::Name)),
+///         )
+///         .build(DbBackend::MySql)
+///         .to_string(),
+///     [
+///         "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+///         "INNER JOIN (SELECT 'Apple' AS `name`, 120 AS `cents` UNION ALL (SELECT 'Banana', 80)) AS `price`",
+///         "ON `price`.`name` = `fruit`.`name`",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ValuesTable {
+    alias: DynIden,
+    columns: Vec<DynIden>,
+    rows: Vec<ValueTuple>,
+}
+
+impl ValuesTable {
+    /// Create an empty list of rows, referred to by `alias` and with the given column names
+    pub fn new<A, C, I>(alias: A, columns: I) -> Self
+    where
+        A: IntoIdentity,
+        C: IntoIdentity,
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            alias: alias.into_identity().into_iden(),
+            columns: columns
+                .into_iter()
+                .map(|col| col.into_identity().into_iden())
+                .collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Add a row, e.g. a tuple with one value per column
+    pub fn row<T>(mut self, row: T) -> Self
+    where
+        T: IntoValueTuple,
+    {
+        self.rows.push(row.into_value_tuple());
+        self
+    }
+
+    /// Add many rows
+    pub fn rows<T, I>(mut self, rows: I) -> Self
+    where
+        T: IntoValueTuple,
+        I: IntoIterator<Item = T>,
+    {
+        for row in rows.into_iter() {
+            self.rows.push(row.into_value_tuple());
+        }
+        self
+    }
+
+    /// Refer to a column of this table
+    pub fn col<C>(&self, col: C) -> Expr
+    where
+        C: IntoIdentity,
+    {
+        Expr::col((SeaRc::clone(&self.alias), col.into_identity().into_iden()))
+    }
+
+    /// Build the derived table for the given backend
+    pub fn table_ref(&self, db_backend: DbBackend) -> TableRef {
+        let query = match (db_backend, self.rows.is_empty()) {
+            (DbBackend::Postgres | DbBackend::Sqlite, false) => {
+                // Columns of a `VALUES` list are named `column1`, `column2`.. on Postgres and SQLite
+                let mut query = SelectStatement::new();
+                for (i, col) in self.columns.iter().enumerate() {
+                    query.expr_as(
+                        Expr::col(Alias::new(format!("column{}", i + 1))),
+                        SeaRc::clone(col),
+                    );
+                }
+                query.from(TableRef::ValuesList(
+                    self.rows.clone(),
+                    Alias::new("t").into_iden(),
+                ));
+                query
+            }
+            (DbBackend::MySql, false) => {
+                let mut rows = self.rows.iter();
+                let mut query = SelectStatement::new();
+                if let Some(row) = rows.next() {
+                    for (value, col) in row.clone().into_iter().zip(self.columns.iter()) {
+                        query.expr_as(Expr::val(value), SeaRc::clone(col));
+                    }
+                }
+                for row in rows {
+                    let mut union = SelectStatement::new();
+                    union.exprs(row.clone().into_iter().map(Expr::val));
+                    query.union(UnionType::All, union);
+                }
+                query
+            }
+            (_, true) => {
+                let mut query = SelectStatement::new();
+                for col in self.columns.iter() {
+                    query.expr_as(Expr::val(Value::Int(None)), SeaRc::clone(col));
+                }
+                query.and_where(Expr::val(1).eq(2));
+                query
+            }
+        };
+        TableRef::SubQuery(query, SeaRc::clone(&self.alias))
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Keep only the rows whose `col` is one of `values`, and return them in the order of `values`.
+    /// The values are joined as a [`ValuesTable`] together with their position.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter_in_order(cake::Column::Id, DbBackend::Sqlite, [3, 1, 2])
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"INNER JOIN (SELECT "column1" AS "value", "column2" AS "position" FROM (VALUES (3, 0), (1, 1), (2, 2)) AS "t") AS "in_order""#,
+    ///         r#"ON "in_order"."value" = "cake"."id" ORDER BY "in_order"."position" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn filter_in_order<C, V, I>(self, col: C, db_backend: DbBackend, values: I) -> Self
+    where
+        C: ColumnTrait,
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        let values = ValuesTable::new("in_order", ["value", "position"]).rows(
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| (v.into(), i as i64)),
+        );
+        let on = values.col("value").equals((col.entity_name(), col));
+        self.join_values(JoinType::InnerJoin, &values, db_backend, on)
+            .order_by(values.col("position"), Order::Asc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{entity::*, query::*, DbBackend, Statement};
+
+    #[test]
+    fn values_table_params() {
+        let ids = ValuesTable::new("ids", ["id"]).rows([1, 2]);
+        assert_eq!(
+            fruit::Entity::find()
+                .join_values(
+                    JoinType::InnerJoin,
+                    &ids,
+                    DbBackend::Postgres,
+                    ids.col("id").equals((fruit::Entity, fruit::Column::CakeId)),
+                )
+                .filter(fruit::Column::Name.ne("Apple"))
+                .build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"INNER JOIN (SELECT "column1" AS "id" FROM (VALUES ($1), ($2)) AS "t") AS "ids""#,
+                    r#"ON "ids"."id" = "fruit"."cake_id" WHERE "fruit"."name" <> $3"#,
+                ]
+                .join(" "),
+                [1i32.into(), 2i32.into(), "Apple".into()]
+            )
+        );
+    }
+
+    #[test]
+    fn filter_in_order_mysql() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_in_order(cake::Column::Id, DbBackend::MySql, [2, 1])
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "INNER JOIN (SELECT 2 AS `value`, 0 AS `position` UNION ALL (SELECT 1, 1)) AS `in_order`",
+                "ON `in_order`.`value` = `cake`.`id` ORDER BY `in_order`.`position` ASC",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn values_table_empty() {
+        let ids = ValuesTable::new("ids", ["id"]);
+        assert_eq!(
+            cake::Entity::find()
+                .join_values(
+                    JoinType::InnerJoin,
+                    &ids,
+                    DbBackend::Sqlite,
+                    ids.col("id").equals((cake::Entity, cake::Column::Id)),
+                )
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"INNER JOIN (SELECT NULL AS "id" WHERE 1 = 2) AS "ids""#,
+                r#"ON "ids"."id" = "cake"."id""#,
+            ]
+            .join(" ")
+        );
+    }
+}
@@ -0,0 +1,529 @@
+use super::entity::{
+    column_def_from_entity_column, create_index_from_entity, create_table_from_entity,
+};
+use crate::{ConnectionTrait, DbBackend, DbErr, EntityTrait, IdenStatic, Iterable, Statement};
+use sea_query::{Alias, IndexCreateStatement, Table, TableAlterStatement, TableCreateStatement};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The default table of the applied migrations, never dropped by a [SchemaDiff]
+const MIGRATION_TABLE: &str = "seaql_migrations";
+
+/// Compare the tables of entities with the schema of a live database, to generate the migration
+/// bringing the database up to date, see [SchemaDiff::changes]
+///
+/// The tables missing from the database are created, with their indexes, and the columns missing
+/// from a table are added; the columns of a table that are not of its entity are dropped with
+/// [SchemaDiff::drop_columns], and the tables not of an entity with [SchemaDiff::drop_tables].
+/// Tables and columns are matched by name: a renamed column is dropped and added, and the changes
+/// of the type of a column are not detected. Postgres enums are not created.
+///
+/// ```
+/// # #[cfg(feature = "mock")]
+/// # async fn function() -> Result<(), sea_orm::DbErr> {
+/// # use sea_orm::{tests_cfg::*, ConnectionTrait, DbBackend, MockDatabase, Value};
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results([[maplit::btreemap! {
+/// #         "table_name" => Into::<Value>::into("cake"),
+/// #         "column_name" => Into::<Value>::into("id"),
+/// #     }]])
+/// #     .into_connection();
+/// use sea_orm::SchemaDiff;
+///
+/// let changes = SchemaDiff::new(db.get_database_backend())
+///     .entity(cake::Entity)
+///     .entity(fruit::Entity)
+///     .changes(&db)
+///     .await?;
+///
+/// if !changes.is_empty() {
+///     std::fs::write(
+///         "migration/src/m20240601_000001_sync_entities.rs",
+///         changes.to_migration(),
+///     )
+///     .expect("Failed to write the migration");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SchemaDiff {
+    backend: DbBackend,
+    tables: Vec<EntityTable>,
+    drop_columns: bool,
+    drop_tables: bool,
+    ignored_tables: BTreeSet<String>,
+    migration_table: String,
+}
+
+/// The table of an entity
+#[derive(Debug)]
+struct EntityTable {
+    name: String,
+    create: TableCreateStatement,
+    indexes: Vec<IndexCreateStatement>,
+    columns: Vec<(String, TableAlterStatement)>,
+}
+
+/// A change of the schema of a database, bringing it up to date with the entities
+#[derive(Debug, Clone)]
+pub enum SchemaChange {
+    /// Create the table of an entity, and its indexes
+    CreateTable {
+        /// The name of the table
+        table: String,
+        /// The statement creating the table
+        stmt: TableCreateStatement,
+        /// The statements creating the indexes of the table
+        indexes: Vec<IndexCreateStatement>,
+    },
+    /// Add a column of an entity to its table
+    AddColumn {
+        /// The name of the table
+        table: String,
+        /// The name of the column
+        column: String,
+        /// The statement adding the column
+        stmt: TableAlterStatement,
+    },
+    /// Drop a column that is not of the entity of its table
+    DropColumn {
+        /// The name of the table
+        table: String,
+        /// The name of the column
+        column: String,
+    },
+    /// Drop a table that is not of an entity
+    DropTable {
+        /// The name of the table
+        table: String,
+    },
+}
+
+/// The changes of the schema of a database found by a [SchemaDiff], in the order they're applied
+#[derive(Debug, Clone)]
+pub struct SchemaChanges {
+    backend: DbBackend,
+    changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    /// Compare the tables of entities with a database of a backend
+    pub fn new(backend: DbBackend) -> Self {
+        Self {
+            backend,
+            tables: Vec::new(),
+            drop_columns: false,
+            drop_tables: false,
+            ignored_tables: BTreeSet::new(),
+            migration_table: MIGRATION_TABLE.to_owned(),
+        }
+    }
+
+    /// Add the table of an entity; tables are created in the order their entities are added,
+    /// so an entity should be added after the entities it references
+    ///
+    /// The view of an entity marked `#[sea_orm(view)]` is left as it is in the database.
+    pub fn entity<E>(mut self, entity: E) -> Self
+    where
+        E: EntityTrait,
+    {
+        if entity.is_view() {
+            self.ignored_tables.insert(entity.table_name().to_owned());
+            return self;
+        }
+        self.tables.push(EntityTable {
+            name: entity.table_name().to_owned(),
+            create: create_table_from_entity(entity, self.backend, true),
+            indexes: create_index_from_entity(entity, self.backend, false),
+            columns: E::Column::iter()
+                .map(|column| {
+                    let stmt = Table::alter()
+                        .table(entity.table_ref())
+                        .add_column(column_def_from_entity_column::<E>(column, self.backend))
+                        .to_owned();
+                    (column.as_str().to_owned(), stmt)
+                })
+                .collect(),
+        });
+        self
+    }
+
+    /// Drop the columns of the tables of the entities that are not of the entity
+    pub fn drop_columns(mut self) -> Self {
+        self.drop_columns = true;
+        self
+    }
+
+    /// Drop the tables of the database that are not of an entity, except the table of
+    /// the applied migrations and the ignored tables
+    pub fn drop_tables(mut self) -> Self {
+        self.drop_tables = true;
+        self
+    }
+
+    /// Leave a table that is not of an entity in the database, with [SchemaDiff::drop_tables]
+    pub fn ignore_table<T>(mut self, table: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.ignored_tables.insert(table.into());
+        self
+    }
+
+    /// Set the table of the applied migrations, `seaql_migrations` by default, which is never
+    /// dropped, e.g. the one of `MigratorTrait::migration_table_name`
+    pub fn migration_table<T>(mut self, table: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.migration_table = table.into();
+        self
+    }
+
+    /// Query the tables and columns of the database, and compare them with the entities
+    pub async fn changes<C>(&self, db: &C) -> Result<SchemaChanges, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let live = query_columns(db, self.backend).await?;
+        let mut changes = Vec::new();
+        for table in self.tables.iter() {
+            let live_columns = match live.get(&table.name) {
+                Some(live_columns) => live_columns,
+                None => {
+                    changes.push(SchemaChange::CreateTable {
+                        table: table.name.clone(),
+                        stmt: table.create.clone(),
+                        indexes: table.indexes.clone(),
+                    });
+                    continue;
+                }
+            };
+            for (column, stmt) in table.columns.iter() {
+                if !live_columns.contains(column) {
+                    changes.push(SchemaChange::AddColumn {
+                        table: table.name.clone(),
+                        column: column.clone(),
+                        stmt: stmt.clone(),
+                    });
+                }
+            }
+            for column in live_columns.iter().filter(|_| self.drop_columns) {
+                if !table.columns.iter().any(|(name, _)| name == column) {
+                    changes.push(SchemaChange::DropColumn {
+                        table: table.name.clone(),
+                        column: column.clone(),
+                    });
+                }
+            }
+        }
+        if self.drop_tables {
+            for table in live.keys() {
+                if table != &self.migration_table
+                    && !self.ignored_tables.contains(table)
+                    && !self.tables.iter().any(|t| &t.name == table)
+                {
+                    changes.push(SchemaChange::DropTable {
+                        table: table.clone(),
+                    });
+                }
+            }
+        }
+        Ok(SchemaChanges {
+            backend: self.backend,
+            changes,
+        })
+    }
+}
+
+/// The columns of the tables of the current schema, in order, by table name
+async fn query_columns<C>(
+    db: &C,
+    backend: DbBackend,
+) -> Result<BTreeMap<String, Vec<String>>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let sql = match backend {
+        DbBackend::MySql => {
+            "SELECT c.TABLE_NAME AS table_name, c.COLUMN_NAME AS column_name \
+             FROM information_schema.COLUMNS c \
+             JOIN information_schema.TABLES t \
+             ON t.TABLE_SCHEMA = c.TABLE_SCHEMA AND t.TABLE_NAME = c.TABLE_NAME \
+             WHERE c.TABLE_SCHEMA = DATABASE() AND t.TABLE_TYPE = 'BASE TABLE' \
+             ORDER BY c.TABLE_NAME, c.ORDINAL_POSITION"
+        }
+        DbBackend::Postgres => {
+            "SELECT c.table_name::text AS table_name, c.column_name::text AS column_name \
+             FROM information_schema.columns c \
+             JOIN information_schema.tables t \
+             ON t.table_schema = c.table_schema AND t.table_name = c.table_name \
+             WHERE c.table_schema = current_schema() AND t.table_type = 'BASE TABLE' \
+             ORDER BY c.table_name, c.ordinal_position"
+        }
+        DbBackend::Sqlite => {
+            "SELECT m.name AS table_name, p.name AS column_name \
+             FROM sqlite_master m JOIN pragma_table_info(m.name) p \
+             WHERE m.type = 'table' AND m.name NOT LIKE 'sqlite_%' \
+             ORDER BY m.name, p.cid"
+        }
+    };
+    let mut columns: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for row in db.query_all(Statement::from_string(backend, sql)).await? {
+        let table: String = row.try_get("", "table_name")?;
+        let column: String = row.try_get("", "column_name")?;
+        columns.entry(table).or_default().push(column);
+    }
+    Ok(columns)
+}
+
+impl SchemaChange {
+    /// The name of the table changed
+    pub fn table(&self) -> &str {
+        match self {
+            Self::CreateTable { table, .. }
+            | Self::AddColumn { table, .. }
+            | Self::DropColumn { table, .. }
+            | Self::DropTable { table } => table,
+        }
+    }
+
+    /// The statements applying the change
+    pub fn up_statements(&self, backend: DbBackend) -> Vec<Statement> {
+        match self {
+            Self::CreateTable { stmt, indexes, .. } => std::iter::once(backend.build(stmt))
+                .chain(indexes.iter().map(|index| backend.build(index)))
+                .collect(),
+            Self::AddColumn { stmt, .. } => vec![backend.build(stmt)],
+            Self::DropColumn { table, column } => vec![backend.build(
+                Table::alter()
+                    .table(Alias::new(table))
+                    .drop_column(Alias::new(column)),
+            )],
+            Self::DropTable { table } => {
+                vec![backend.build(Table::drop().table(Alias::new(table)))]
+            }
+        }
+    }
+
+    /// The statements reverting the change, or `None` if a dropped table or column can't be restored
+    pub fn down_statements(&self, backend: DbBackend) -> Option<Vec<Statement>> {
+        match self {
+            Self::CreateTable { table, .. } => {
+                Some(vec![backend.build(Table::drop().table(Alias::new(table)))])
+            }
+            Self::AddColumn { table, column, .. } => Some(vec![backend.build(
+                Table::alter()
+                    .table(Alias::new(table))
+                    .drop_column(Alias::new(column)),
+            )]),
+            Self::DropColumn { .. } | Self::DropTable { .. } => None,
+        }
+    }
+}
+
+impl SchemaChanges {
+    /// Whether the database is up to date with the entities
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The changes, in the order they're applied
+    pub fn changes(&self) -> &[SchemaChange] {
+        &self.changes
+    }
+
+    /// The statements applying the changes
+    pub fn up_statements(&self) -> Vec<Statement> {
+        self.changes
+            .iter()
+            .flat_map(|change| change.up_statements(self.backend))
+            .collect()
+    }
+
+    /// The statements reverting the changes, in reverse order, or `None` if a dropped table or
+    /// column can't be restored
+    pub fn down_statements(&self) -> Option<Vec<Statement>> {
+        self.changes
+            .iter()
+            .rev()
+            .map(|change| change.down_statements(self.backend))
+            .collect::<Option<Vec<_>>>()
+            .map(|stmts| stmts.into_iter().flatten().collect())
+    }
+
+    /// The source of a `sea-orm-migration` migration file applying the changes with SQL statements
+    /// for the backend; a migration dropping a table or column can't be rolled back
+    pub fn to_migration(&self) -> String {
+        fn execute(stmts: &[Statement]) -> String {
+            stmts
+                .iter()
+                .map(|stmt| format!("        db.execute_unprepared({:?}).await?;\n", stmt.sql))
+                .collect()
+        }
+
+        let up = execute(&self.up_statements());
+        let (down_manager, down) = match self.down_statements() {
+            Some(stmts) => (
+                "manager",
+                format!(
+                    "        let db = manager.get_connection();\n\n{}\n        Ok(())\n",
+                    execute(&stmts)
+                ),
+            ),
+            None => (
+                "_manager",
+                "        Err(DbErr::Migration(\"A dropped table or column can't be restored\".to_owned()))\n"
+                    .to_owned(),
+            ),
+        };
+        format!(
+            r#"use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {{
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {{
+        let db = manager.get_connection();
+
+{up}
+        Ok(())
+    }}
+
+    async fn down(&self, {down_manager}: &SchemaManager) -> Result<(), DbErr> {{
+{down}    }}
+}}
+"#
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{tests_cfg::*, DbBackend, DbErr, MockDatabase, SchemaDiff, Value};
+    use pretty_assertions::assert_eq;
+
+    fn columns(columns: &[(&str, &str)]) -> Vec<std::collections::BTreeMap<&'static str, Value>> {
+        columns
+            .iter()
+            .map(|(table, column)| {
+                maplit::btreemap! {
+                    "table_name" => Into::<Value>::into(table.to_string()),
+                    "column_name" => Into::<Value>::into(column.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    #[smol_potat::test]
+    async fn schema_diff() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([columns(&[
+                ("cake", "id"),
+                ("cake", "title"),
+                ("fruit", "id"),
+                ("fruit", "name"),
+                ("legacy", "id"),
+                ("migrations", "version"),
+            ])])
+            .into_connection();
+
+        let changes = SchemaDiff::new(DbBackend::Sqlite)
+            .entity(cake::Entity)
+            .entity(fruit::Entity)
+            .entity(filling::Entity)
+            .drop_columns()
+            .drop_tables()
+            .migration_table("migrations")
+            .changes(&db)
+            .await?;
+        assert_eq!(
+            changes
+                .up_statements()
+                .into_iter()
+                .map(|stmt| stmt.sql)
+                .collect::<Vec<_>>(),
+            [
+                r#"ALTER TABLE "cake" ADD COLUMN "name" varchar NOT NULL"#,
+                r#"ALTER TABLE "cake" DROP COLUMN "title""#,
+                r#"ALTER TABLE "fruit" ADD COLUMN "cake_id" integer"#,
+                [
+                    r#"CREATE TABLE "filling" ( "id" integer NOT NULL PRIMARY KEY AUTOINCREMENT,"#,
+                    r#""name" varchar NOT NULL, "vendor_id" integer,"#,
+                    r#"FOREIGN KEY ("vendor_id") REFERENCES "vendor" ("id") )"#,
+                ]
+                .join(" ")
+                .as_str(),
+                r#"DROP TABLE "legacy""#,
+            ]
+        );
+        assert!(changes.down_statements().is_none());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn schema_diff_keep_columns() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results([columns(&[
+                ("cake", "id"),
+                ("cake", "name"),
+                ("cake", "title"),
+                ("seaql_migrations", "version"),
+            ])])
+            .into_connection();
+
+        let changes = SchemaDiff::new(DbBackend::Sqlite)
+            .entity(cake::Entity)
+            .drop_tables()
+            .changes(&db)
+            .await?;
+        assert!(changes.is_empty());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn schema_diff_migration() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results([columns(&[("fruit", "id"), ("fruit", "name")])])
+            .into_connection();
+
+        let changes = SchemaDiff::new(DbBackend::Postgres)
+            .entity(fruit::Entity)
+            .changes(&db)
+            .await?;
+        assert_eq!(
+            changes.to_migration(),
+            r#"use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("ALTER TABLE \"fruit\" ADD COLUMN \"cake_id\" integer").await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("ALTER TABLE \"fruit\" DROP COLUMN \"cake_id\"").await?;
+
+        Ok(())
+    }
+}
+"#
+        );
+
+        Ok(())
+    }
+}
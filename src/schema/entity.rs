@@ -1,10 +1,11 @@
 use crate::{
-    ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Iterable, PrimaryKeyArity,
-    PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    ActiveEnum, ColumnTrait, ColumnType, ColumnTypeTrait, DbBackend, EntityTrait, Iterable,
+    PrimaryKeyArity, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
 };
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
-    ColumnDef, Iden, Index, IndexCreateStatement, SeaRc, TableCreateStatement,
+    Alias, ColumnDef, DynIden, Iden, Index, IndexCreateStatement, SeaRc, StringLen,
+    TableCreateStatement,
 };
 
 impl Schema {
@@ -29,7 +30,7 @@ impl Schema {
     where
         E: EntityTrait,
     {
-        create_table_from_entity(entity, self.backend)
+        create_table_from_entity(entity, self.backend, self.foreign_keys)
     }
 
     /// Creates the indexes from an Entity, returning an empty Vec if there are none
@@ -38,7 +39,7 @@ impl Schema {
     where
         E: EntityTrait,
     {
-        create_index_from_entity(entity, self.backend)
+        create_index_from_entity(entity, self.backend, self.foreign_key_indexes)
     }
 
     /// Creates a column definition for example to update a table.
@@ -133,6 +134,7 @@ where
 pub(crate) fn create_index_from_entity<E>(
     entity: E,
     _backend: DbBackend,
+    foreign_key_indexes: bool,
 ) -> Vec<IndexCreateStatement>
 where
     E: EntityTrait,
@@ -154,10 +156,56 @@ where
             .to_owned();
         vec.push(stmt)
     }
+    if foreign_key_indexes {
+        vec.extend(create_foreign_key_index_from_entity(entity));
+    }
+    vec
+}
+
+fn create_foreign_key_index_from_entity<E>(entity: E) -> Vec<IndexCreateStatement>
+where
+    E: EntityTrait,
+{
+    let primary_key: Vec<String> = E::PrimaryKey::iter()
+        .map(|primary_key| primary_key.into_column().to_string())
+        .collect();
+    let mut indexed: Vec<Vec<String>> = E::Column::iter()
+        .filter(|column| {
+            let column_def = column.def();
+            column_def.indexed || column_def.unique
+        })
+        .map(|column| vec![column.to_string()])
+        .collect();
+
+    let mut vec = Vec::new();
+    for relation in E::Relation::iter() {
+        let relation = relation.def();
+        if relation.is_owner {
+            continue;
+        }
+        let columns: Vec<DynIden> = relation.from_col.into_iter().collect();
+        let names: Vec<String> = columns.iter().map(|column| column.to_string()).collect();
+        // An index on columns leading the primary key, or on the same columns, serves the foreign key
+        if primary_key.starts_with(&names) || indexed.contains(&names) {
+            continue;
+        }
+        let mut stmt = Index::create();
+        stmt.name(format!("idx-{}-{}", entity.to_string(), names.join("-")))
+            .table(entity);
+        for column in columns {
+            stmt.col(column);
+        }
+        vec.push(stmt);
+        indexed.push(names);
+    }
     vec
 }
 
-pub(crate) fn create_table_from_entity<E>(entity: E, backend: DbBackend) -> TableCreateStatement
+pub(crate) fn create_table_from_entity<E>(
+    entity: E,
+    backend: DbBackend,
+    foreign_keys: bool,
+) -> TableCreateStatement
 where
     E: EntityTrait,
 {
@@ -180,23 +228,29 @@ where
         stmt.primary_key(idx_pk.name(&format!("pk-{}", entity.to_string())).primary());
     }
 
-    for relation in E::Relation::iter() {
-        let relation = relation.def();
-        if relation.is_owner {
-            continue;
+    if foreign_keys {
+        for relation in E::Relation::iter() {
+            let relation = relation.def();
+            if relation.is_owner {
+                continue;
+            }
+            stmt.foreign_key(&mut relation.into());
         }
-        stmt.foreign_key(&mut relation.into());
     }
 
     stmt.table(entity.table_ref()).take()
 }
 
-fn column_def_from_entity_column<E>(column: E::Column, backend: DbBackend) -> ColumnDef
+pub(crate) fn column_def_from_entity_column<E>(column: E::Column, backend: DbBackend) -> ColumnDef
 where
     E: EntityTrait,
 {
     let orm_column_def = column.def();
     let types = match orm_column_def.col_type {
+        // the domain is created beforehand, over the column type
+        _ if backend == DbBackend::Postgres && orm_column_def.domain.is_some() => {
+            ColumnType::custom(orm_column_def.domain.as_deref().unwrap_or_default())
+        }
         ColumnType::Enum {
             ref name,
             ref variants,
@@ -208,6 +262,26 @@ where
             DbBackend::Postgres => ColumnType::Custom(SeaRc::clone(name)),
             DbBackend::Sqlite => orm_column_def.col_type,
         },
+        // network addresses are stored as text on the backends without network types
+        ColumnType::Inet | ColumnType::Cidr if backend != DbBackend::Postgres => {
+            ColumnType::String(StringLen::N(49))
+        }
+        ColumnType::MacAddr if backend != DbBackend::Postgres => {
+            ColumnType::String(StringLen::N(17))
+        }
+        // MySQL compares text case-insensitively with the default collations
+        ColumnType::Custom(_) if orm_column_def.is_citext() => match backend {
+            DbBackend::MySql => ColumnType::Text,
+            DbBackend::Postgres => orm_column_def.col_type,
+            DbBackend::Sqlite => ColumnType::custom("text collate nocase"),
+        },
+        // a `u64` is stored in a `bigint`, which can be a `bigserial`
+        ColumnType::BigUnsigned if backend == DbBackend::Postgres => ColumnType::BigInteger,
+        ColumnType::Interval(..) => match backend {
+            DbBackend::MySql => ColumnType::custom("time(6)"),
+            DbBackend::Postgres => orm_column_def.col_type,
+            DbBackend::Sqlite => ColumnType::Text,
+        },
         _ => orm_column_def.col_type,
     };
     let mut column_def = ColumnDef::new_with_type(column, types);
@@ -223,6 +297,11 @@ where
     if let Some(comment) = orm_column_def.comment {
         column_def.comment(comment);
     }
+    if let Some(collation) = orm_column_def.collation {
+        let mut sql = String::from("COLLATE ");
+        Alias::new(collation).prepare(&mut sql, backend.get_query_builder().quote());
+        column_def.extra(sql);
+    }
     for primary_key in E::PrimaryKey::iter() {
         if column.to_string() == primary_key.into_column().to_string() {
             if E::PrimaryKey::auto_increment() {
@@ -326,6 +405,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_foreign_key_index_from_entity() {
+        for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let schema = Schema::new(builder).foreign_key_indexes(true);
+
+            // Both relations of fruit are on `cake_id`
+            let stmts = schema.create_index_from_entity(fruit::Entity);
+            assert_eq!(stmts.len(), 1);
+            let idx: IndexCreateStatement = Index::create()
+                .name("idx-fruit-cake_id")
+                .table(fruit::Entity)
+                .col(fruit::Column::CakeId)
+                .to_owned();
+            assert_eq!(builder.build(&stmts[0]), builder.build(&idx));
+
+            // `cake_id` leads the primary key, which serves as its index
+            let stmts = schema.create_index_from_entity(cake_filling::Entity);
+            assert_eq!(stmts.len(), 1);
+            let idx: IndexCreateStatement = Index::create()
+                .name("idx-cake_filling-filling_id")
+                .table(cake_filling::Entity)
+                .col(cake_filling::Column::FillingId)
+                .to_owned();
+            assert_eq!(builder.build(&stmts[0]), builder.build(&idx));
+
+            let schema = Schema::new(builder);
+            assert!(schema.create_index_from_entity(fruit::Entity).is_empty());
+            assert!(builder
+                .build(&schema.create_table_from_entity(fruit::Entity))
+                .sql
+                .contains("FOREIGN KEY"));
+
+            let schema = Schema::new(builder).foreign_keys(false);
+            assert!(!builder
+                .build(&schema.create_table_from_entity(fruit::Entity))
+                .sql
+                .contains("FOREIGN KEY"));
+        }
+    }
+
     fn get_indexes_stmt() -> TableCreateStatement {
         Table::create()
             .col(
@@ -354,4 +473,97 @@ mod tests {
             )
             .to_owned()
     }
+
+    #[test]
+    fn test_create_table_from_entity_with_comment() {
+        mod applog {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+            #[sea_orm(table_name = "applog", comment = "app logs")]
+            pub struct Entity;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveModel, DeriveActiveModel)]
+            pub struct Model {
+                pub id: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+            pub enum Column {
+                Id,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+            pub enum PrimaryKey {
+                Id,
+            }
+
+            impl PrimaryKeyTrait for PrimaryKey {
+                type ValueType = i32;
+
+                fn auto_increment() -> bool {
+                    true
+                }
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter)]
+            pub enum Relation {}
+
+            impl ColumnTrait for Column {
+                type EntityName = Entity;
+
+                fn def(&self) -> ColumnDef {
+                    match self {
+                        Self::Id => ColumnType::Integer.def().comment("ID"),
+                    }
+                }
+            }
+
+            impl RelationTrait for Relation {
+                fn def(&self) -> RelationDef {
+                    panic!("No RelationDef")
+                }
+            }
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(applog::Entity.comment(), Some("app logs"));
+        assert_eq!(
+            Schema::new(DbBackend::MySql)
+                .create_table_from_entity(applog::Entity)
+                .to_string(MysqlQueryBuilder),
+            "CREATE TABLE `applog` ( `id` int NOT NULL COMMENT 'ID' AUTO_INCREMENT PRIMARY KEY ) COMMENT 'app logs'"
+        );
+    }
+
+    #[test]
+    fn test_create_table_from_entity_with_collation() {
+        mod word {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "word")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(collation = "und-x-icu")]
+                pub spelling: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            Schema::new(DbBackend::Postgres)
+                .create_table_from_entity(word::Entity)
+                .to_string(PostgresQueryBuilder),
+            r#"CREATE TABLE "word" ( "id" serial NOT NULL PRIMARY KEY, "spelling" varchar NOT NULL COLLATE "und-x-icu" )"#
+        );
+    }
 }
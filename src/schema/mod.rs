@@ -1,17 +1,41 @@
 use crate::DbBackend;
 
+mod diff;
 mod entity;
+mod view;
+
+pub use diff::*;
 
 /// This is a helper struct to convert [`EntityTrait`](crate::EntityTrait)
 /// into different [`sea_query`](crate::sea_query) statements.
 #[derive(Debug)]
 pub struct Schema {
     backend: DbBackend,
+    foreign_keys: bool,
+    foreign_key_indexes: bool,
 }
 
 impl Schema {
     /// Create a helper for a specific database backend
     pub fn new(backend: DbBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            foreign_keys: true,
+            foreign_key_indexes: false,
+        }
+    }
+
+    /// Whether [Schema::create_table_from_entity] creates the foreign keys of the relations
+    /// of the entity, with their `ON DELETE` and `ON UPDATE` actions; it does by default
+    pub fn foreign_keys(mut self, foreign_keys: bool) -> Self {
+        self.foreign_keys = foreign_keys;
+        self
+    }
+
+    /// Whether [Schema::create_index_from_entity] also creates an index on the columns of each
+    /// foreign key, unless they are indexed already, being unique or leading the primary key
+    pub fn foreign_key_indexes(mut self, foreign_key_indexes: bool) -> Self {
+        self.foreign_key_indexes = foreign_key_indexes;
+        self
     }
 }
@@ -0,0 +1,86 @@
+use crate::{DbBackend, QueryTrait, Schema, Statement};
+use sea_query::{DynIden, IntoIden, SelectStatement};
+
+impl Schema {
+    /// Creates a view of the rows of a select, e.g. to be queried by an entity marked `#[sea_orm(view)]`
+    ///
+    /// The values of the select are inlined, as a view can't have bound parameters.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Alias, tests_cfg::cake, DbBackend, Schema};
+    ///
+    /// let schema = Schema::new(DbBackend::Postgres);
+    /// let select = cake::Entity::find().filter(cake::Column::Name.contains("chocolate"));
+    ///
+    /// assert_eq!(
+    ///     schema
+    ///         .create_view_from_select(select, Alias::new("chocolate_cake"))
+    ///         .to_string(),
+    ///     [
+    ///         r#"CREATE VIEW "chocolate_cake" AS"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."name" LIKE '%chocolate%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn create_view_from_select<S, N>(&self, select: S, name: N) -> Statement
+    where
+        S: QueryTrait<QueryStatement = SelectStatement>,
+        N: IntoIden,
+    {
+        create_view_from_select(select.as_query(), name.into_iden(), false, self.backend)
+    }
+
+    /// Creates a materialized view of the rows of a select, which is supported by Postgres
+    ///
+    /// The rows are stored when the view is created, and selected again by
+    /// [`EntityTrait::refresh_materialized`](crate::EntityTrait::refresh_materialized).
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Alias, tests_cfg::cake, DbBackend, Schema};
+    ///
+    /// let schema = Schema::new(DbBackend::Postgres);
+    /// let select = cake::Entity::find()
+    ///     .select_only()
+    ///     .column(cake::Column::Name)
+    ///     .column_as(cake::Column::Id.count(), "count")
+    ///     .group_by(cake::Column::Name);
+    ///
+    /// assert_eq!(
+    ///     schema
+    ///         .create_materialized_view_from_select(select, Alias::new("cake_count"))
+    ///         .to_string(),
+    ///     [
+    ///         r#"CREATE MATERIALIZED VIEW "cake_count" AS"#,
+    ///         r#"SELECT "cake"."name", COUNT("cake"."id") AS "count" FROM "cake""#,
+    ///         r#"GROUP BY "cake"."name""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn create_materialized_view_from_select<S, N>(&self, select: S, name: N) -> Statement
+    where
+        S: QueryTrait<QueryStatement = SelectStatement>,
+        N: IntoIden,
+    {
+        create_view_from_select(select.as_query(), name.into_iden(), true, self.backend)
+    }
+}
+
+pub(crate) fn create_view_from_select(
+    select: &SelectStatement,
+    name: DynIden,
+    materialized: bool,
+    backend: DbBackend,
+) -> Statement {
+    let mut sql = String::from(if materialized {
+        "CREATE MATERIALIZED VIEW "
+    } else {
+        "CREATE VIEW "
+    });
+    name.prepare(&mut sql, backend.get_query_builder().quote());
+    sql.push_str(" AS ");
+    sql.push_str(&backend.build(select).to_string());
+    Statement::from_string(backend, sql)
+}
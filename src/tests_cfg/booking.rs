@@ -0,0 +1,17 @@
+use crate as sea_orm;
+use crate::entity::prelude::*;
+use crate::PgRange;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "booking")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(select_as = "text", save_as = "int4range")]
+    pub seats: PgRange<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
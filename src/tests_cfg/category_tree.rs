@@ -0,0 +1,18 @@
+use crate as sea_orm;
+use crate::entity::prelude::*;
+use crate::PgLtree;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "category_tree")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[sea_orm(select_as = "text", save_as = "ltree")]
+    pub path: PgLtree,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
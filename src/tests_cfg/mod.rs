@@ -2,27 +2,45 @@
 
 //! Configurations for test cases and examples. Not intended for actual use.
 
+pub mod booking;
 pub mod cake;
 pub mod cake_expanded;
 pub mod cake_filling;
 pub mod cake_filling_price;
+pub mod category_tree;
+pub mod document;
 pub mod entity_linked;
 pub mod filling;
 pub mod fruit;
+#[cfg(feature = "with-ipnetwork")]
+pub mod host;
 pub mod indexes;
 pub mod lunch_set;
 pub mod lunch_set_expanded;
+#[cfg(feature = "with-geo")]
+pub mod place;
+#[cfg(feature = "with-json")]
+pub mod recipe;
 pub mod rust_keyword;
 pub mod sea_orm_active_enums;
 pub mod vendor;
 
+pub use booking::Entity as Booking;
 pub use cake::Entity as Cake;
 pub use cake_expanded::Entity as CakeExpanded;
 pub use cake_filling::Entity as CakeFilling;
 pub use cake_filling_price::Entity as CakeFillingPrice;
+pub use category_tree::Entity as CategoryTree;
+pub use document::Entity as Document;
 pub use filling::Entity as Filling;
 pub use fruit::Entity as Fruit;
+#[cfg(feature = "with-ipnetwork")]
+pub use host::Entity as Host;
 pub use lunch_set::Entity as LunchSet;
 pub use lunch_set_expanded::Entity as LunchSetExpanded;
+#[cfg(feature = "with-geo")]
+pub use place::Entity as Place;
+#[cfg(feature = "with-json")]
+pub use recipe::Entity as Recipe;
 pub use rust_keyword::Entity as RustKeyword;
 pub use vendor::Entity as Vendor;
@@ -0,0 +1,17 @@
+use crate as sea_orm;
+use crate::entity::prelude::*;
+use crate::PgGeometry;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "place")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(select_as = "text", save_as = "geometry")]
+    pub location: PgGeometry<geo_types::Point>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
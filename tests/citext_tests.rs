@@ -0,0 +1,62 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, DbBackend, Schema};
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("citext_tests").await;
+    create_tag_table(&ctx.db).await?;
+    insert_tag(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_tag_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    if builder == DbBackend::Postgres {
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS citext")
+            .await?;
+    }
+    let stmt = Schema::new(builder).create_table_from_entity(Tag);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_tag(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let tag = tag::Model {
+        id: 1,
+        name: "SeaORM".to_owned(),
+    };
+    let result = tag.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, tag);
+
+    let found = Tag::find()
+        .filter(tag::Column::Name.eq("seaorm"))
+        .one(db)
+        .await?;
+    assert_eq!(found, Some(tag.clone()));
+
+    let found = Tag::find()
+        .filter(tag::Column::Name.is_in(["SEAORM", "sea-query"]))
+        .all(db)
+        .await?;
+    assert_eq!(found, [tag]);
+
+    // the unique constraint is case-insensitive as well
+    let result = tag::ActiveModel {
+        name: Set("seaorm".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use sea_orm::{entity::prelude::*, PgRange};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "booking")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(select_as = "text", save_as = "int4range")]
+    pub seats: PgRange<i32>,
+    #[sea_orm(select_as = "text", save_as = "tstzrange")]
+    pub period: PgRange<DateTimeWithTimeZone>,
+    #[sea_orm(select_as = "text", save_as = "numrange")]
+    pub price: Option<PgRange<Decimal>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "contact")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(select_as = "text", save_as = "address")]
+    pub address: Address,
+    #[sea_orm(select_as = "text", save_as = "address")]
+    pub billing_address: Option<Address>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveCompositeType)]
+pub struct Address {
+    pub street: String,
+    pub city: Option<String>,
+    pub zip: i32,
+    pub location: Option<Location>,
+    pub verified_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveCompositeType)]
+#[sea_orm(type_name = "address_location")]
+pub struct Location {
+    pub x: i32,
+    pub y: i32,
+}
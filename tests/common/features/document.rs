@@ -0,0 +1,15 @@
+use sea_orm::{entity::prelude::*, Compressed};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "document")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub body: Compressed<String>,
+    pub attachment: Option<Compressed<Vec<u8>>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "domain_event")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub payload: OrderEvent,
+    pub compensated_by: Option<OrderEvent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, FromJsonQueryResult)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrderEvent {
+    OrderPlaced {
+        order_id: i32,
+        total: i64,
+    },
+    OrderShipped {
+        order_id: i32,
+        carrier: String,
+    },
+    #[serde(rename = "cancelled")]
+    OrderCancelled {
+        order_id: i32,
+    },
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,15 @@
+use sea_orm::{entity::prelude::*, Interval};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "job")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub timeout: Interval<std::time::Duration>,
+    pub elapsed: Option<Interval<chrono::Duration>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -4,26 +4,44 @@ pub mod active_enum_vec;
 pub mod applog;
 pub mod binary;
 pub mod bits;
+pub mod booking;
 pub mod byte_primary_key;
 pub mod categories;
+pub mod category_tree;
 pub mod collection;
 pub mod collection_expanded;
+pub mod contact;
 pub mod custom_active_model;
+pub mod device;
+pub mod document;
+pub mod domain_event;
 pub mod dyn_table_name_lazy_static;
 pub mod edit_log;
 pub mod event_trigger;
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
+pub mod host;
 pub mod insert_default;
+pub mod invoice_line;
+pub mod job;
 pub mod json_struct;
 pub mod json_vec;
 pub mod json_vec_derive;
+pub mod ledger_entry;
+pub mod mailbox;
 pub mod metadata;
+pub mod patient;
 pub mod pi;
+pub mod reminder;
 pub mod repository;
 pub mod satellite;
+pub mod scheduled_task;
 pub mod schema;
 pub mod sea_orm_active_enums;
 pub mod self_join;
+pub mod subscriber;
+pub mod tag;
 pub mod teas;
+pub mod token_transfer;
 pub mod transaction_log;
 pub mod uuid_fmt;
 pub mod value_type;
@@ -34,25 +52,43 @@ pub use active_enum_vec::Entity as ActiveEnumVec;
 pub use applog::Entity as Applog;
 pub use binary::Entity as Binary;
 pub use bits::Entity as Bits;
+pub use booking::Entity as Booking;
 pub use byte_primary_key::Entity as BytePrimaryKey;
 pub use categories::Entity as Categories;
+pub use category_tree::Entity as CategoryTree;
 pub use collection::Entity as Collection;
 pub use collection_expanded::Entity as CollectionExpanded;
+pub use contact::Entity as Contact;
+pub use device::Entity as Device;
+pub use document::Entity as Document;
+pub use domain_event::Entity as DomainEvent;
 pub use dyn_table_name_lazy_static::Entity as DynTableNameLazyStatic;
 pub use edit_log::Entity as EditLog;
 pub use event_trigger::Entity as EventTrigger;
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
+pub use host::Entity as Host;
 pub use insert_default::Entity as InsertDefault;
+pub use invoice_line::Entity as InvoiceLine;
+pub use job::Entity as Job;
 pub use json_struct::Entity as JsonStruct;
 pub use json_vec::Entity as JsonVec;
 pub use json_vec_derive::json_string_vec::Entity as JsonStringVec;
 pub use json_vec_derive::json_struct_vec::Entity as JsonStructVec;
+pub use ledger_entry::Entity as LedgerEntry;
+pub use mailbox::Entity as Mailbox;
 pub use metadata::Entity as Metadata;
+pub use patient::Entity as Patient;
 pub use pi::Entity as Pi;
+pub use reminder::Entity as Reminder;
 pub use repository::Entity as Repository;
 pub use satellite::Entity as Satellite;
+pub use scheduled_task::Entity as ScheduledTask;
 pub use schema::*;
 pub use sea_orm_active_enums::*;
 pub use self_join::Entity as SelfJoin;
+pub use subscriber::Entity as Subscriber;
+pub use tag::Entity as Tag;
 pub use teas::Entity as Teas;
+pub use token_transfer::Entity as TokenTransfer;
 pub use transaction_log::Entity as TransactionLog;
 pub use uuid_fmt::Entity as UuidFmt;
@@ -0,0 +1,17 @@
+use sea_orm::{entity::prelude::*, BlindIndex, Encrypted};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "patient")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub email: Encrypted<String>,
+    #[sea_orm(unique)]
+    pub email_index: BlindIndex,
+    pub notes: Option<Encrypted<String>>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,19 @@
+use sea_orm::entity::prelude::*;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "scheduled_task")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    #[sea_orm(duration_repr = "millis")]
+    pub interval: Duration,
+    #[sea_orm(duration_repr = "micros")]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -61,6 +61,9 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
         create_collection_table(db).await?;
         create_event_trigger_table(db).await?;
         create_categories_table(db).await?;
+        create_contact_table(db).await?;
+        create_booking_table(db).await?;
+        create_category_tree_table(db).await?;
     }
 
     Ok(())
@@ -518,6 +521,95 @@ pub async fn create_event_trigger_table(db: &DbConn) -> Result<ExecResult, DbErr
     create_table(db, &stmt, EventTrigger).await
 }
 
+pub async fn create_contact_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    for stmt in [
+        "CREATE TYPE address_location AS (x integer, y integer)",
+        "CREATE TYPE address AS (street text, city text, zip integer, location address_location, verified_at timestamptz)",
+    ] {
+        db.execute(sea_orm::Statement::from_string(
+            db.get_database_backend(),
+            stmt,
+        ))
+        .await?;
+    }
+
+    let stmt = sea_query::Table::create()
+        .table(contact::Entity)
+        .col(
+            ColumnDef::new(contact::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(contact::Column::Address)
+                .custom(Alias::new("address"))
+                .not_null(),
+        )
+        .col(ColumnDef::new(contact::Column::BillingAddress).custom(Alias::new("address")))
+        .to_owned();
+
+    create_table(db, &stmt, Contact).await
+}
+
+pub async fn create_booking_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(booking::Entity)
+        .col(
+            ColumnDef::new(booking::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(booking::Column::Seats)
+                .custom(Alias::new("int4range"))
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(booking::Column::Period)
+                .custom(Alias::new("tstzrange"))
+                .not_null(),
+        )
+        .col(ColumnDef::new(booking::Column::Price).custom(Alias::new("numrange")))
+        .to_owned();
+
+    create_table(db, &stmt, Booking).await
+}
+
+pub async fn create_category_tree_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    db.execute(sea_orm::Statement::from_string(
+        db.get_database_backend(),
+        "CREATE EXTENSION IF NOT EXISTS ltree",
+    ))
+    .await?;
+
+    let stmt = sea_query::Table::create()
+        .table(category_tree::Entity)
+        .col(
+            ColumnDef::new(category_tree::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(category_tree::Column::Name)
+                .string()
+                .not_null(),
+        )
+        .col(
+            ColumnDef::new(category_tree::Column::Path)
+                .custom(Alias::new("ltree"))
+                .not_null(),
+        )
+        .to_owned();
+
+    create_table(db, &stmt, CategoryTree).await
+}
+
 pub async fn create_uuid_fmt_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(uuid_fmt::Entity)
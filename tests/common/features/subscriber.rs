@@ -0,0 +1,64 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "subscriber")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(save_with = "Plan::into_code", load_with = "Plan::from_code")]
+    pub plan: Plan,
+    #[sea_orm(save_with = "ApiKey::expose", load_with = "ApiKey::load")]
+    pub api_key: Option<ApiKey>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Plan {
+    Free,
+    Pro,
+}
+
+impl Plan {
+    pub fn into_code(self) -> i16 {
+        match self {
+            Self::Free => 0,
+            Self::Pro => 1,
+        }
+    }
+
+    pub fn from_code(code: i16) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::Free),
+            1 => Ok(Self::Pro),
+            _ => Err(format!("Unknown plan code {code}")),
+        }
+    }
+}
+
+/// A secret which is never printed
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    pub fn new(key: &str) -> Self {
+        Self(key.to_owned())
+    }
+
+    pub fn expose(key: Option<Self>) -> Option<String> {
+        key.map(|key| key.0)
+    }
+
+    pub fn load(key: Option<String>) -> Result<Option<Self>, std::convert::Infallible> {
+        Ok(key.map(Self))
+    }
+}
+
+impl std::fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ApiKey(***)")
+    }
+}
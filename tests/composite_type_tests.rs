@@ -0,0 +1,74 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{
+    features::{
+        contact::{Address, Location},
+        *,
+    },
+    setup::*,
+    TestContext,
+};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("composite_type_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_contact(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_contact(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let address = Address {
+        street: "1 \"Main\" St, (rear) \\ back".to_owned(),
+        city: None,
+        zip: 12345,
+        location: Some(Location { x: 3, y: -4 }),
+        verified_at: Some("2021-09-17T17:50:20.5+08:00".parse().unwrap()),
+    };
+    let contact = contact::Model {
+        id: 1,
+        address: address.clone(),
+        billing_address: None,
+    };
+
+    let result = contact.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, contact);
+
+    let billing_address = Address {
+        street: String::new(),
+        city: Some("Springfield".to_owned()),
+        zip: 0,
+        location: None,
+        verified_at: None,
+    };
+    let result = contact::ActiveModel {
+        id: Set(1),
+        billing_address: Set(Some(billing_address.clone())),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+    assert_eq!(result.billing_address, Some(billing_address.clone()));
+
+    let model = contact::Entity::find()
+        .filter(contact::Column::Address.eq(address.clone()))
+        .one(db)
+        .await?;
+    assert_eq!(
+        model,
+        Some(contact::Model {
+            id: 1,
+            address,
+            billing_address: Some(billing_address),
+        })
+    );
+
+    Ok(())
+}
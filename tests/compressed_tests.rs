@@ -0,0 +1,55 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, Compressed, DatabaseConnection, Schema, Statement};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("compressed_tests").await;
+    create_document_table(&ctx.db).await?;
+    insert_document(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_document_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(Document);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_document(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let body = "SeaORM is a relational ORM. ".repeat(100);
+    let document = document::Model {
+        id: 1,
+        body: Compressed(body.clone()),
+        attachment: Some(Compressed(vec![1, 2, 3])),
+    };
+    let result = document.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, document);
+
+    let row = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT body, attachment FROM document",
+        ))
+        .await?
+        .unwrap();
+    let stored: Vec<u8> = row.try_get("", "body")?;
+    #[cfg(any(feature = "with-gzip", feature = "with-zstd"))]
+    assert!(stored.len() < body.len());
+    // too small to be compressed
+    let stored: Vec<u8> = row.try_get("", "attachment")?;
+    assert_eq!(stored, [0, 1, 2, 3]);
+
+    let found = Document::find_by_id(1).one(db).await?;
+    assert_eq!(found, Some(document));
+
+    Ok(())
+}
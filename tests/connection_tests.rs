@@ -4,7 +4,7 @@ pub mod common;
 
 pub use common::{bakery_chain::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::prelude::*;
+use sea_orm::{metric::PoolEvent, prelude::*, DbBackend, Statement};
 
 #[sea_orm_macros::test]
 pub async fn connection_ping() {
@@ -15,6 +15,31 @@ pub async fn connection_ping() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+pub async fn connection_pool_stats() {
+    let ctx = TestContext::new("connection_pool_stats").await;
+
+    let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let events_cloned = std::sync::Arc::clone(&events);
+    ctx.db.set_pool_callback(move |event| {
+        events_cloned.lock().unwrap().push(event.clone());
+    });
+    ctx.db.ping().await.unwrap();
+
+    let stats = ctx.db.pool_stats().unwrap();
+    assert!(stats.size >= 1);
+    assert_eq!(stats.in_use, 0);
+    assert_eq!(stats.idle, stats.size as usize);
+    assert!(stats.acquire_wait.count >= 1);
+    let events = events.lock().unwrap();
+    assert!(matches!(
+        events.as_slice(),
+        [PoolEvent::Acquire { failed: false, .. }, PoolEvent::Release]
+    ));
+
+    ctx.delete().await;
+}
+
 #[sea_orm_macros::test]
 #[cfg(feature = "sqlx-mysql")]
 pub async fn connection_ping_closed_mysql() {
@@ -140,3 +165,186 @@ pub async fn connection_ping_closed_postgres() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn connection_after_connect_sqlite() {
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let mut opt = sea_orm::ConnectOptions::new(&base_url);
+    opt.after_connect(|conn| {
+        Box::pin(async move {
+            conn.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+            Ok(())
+        })
+    });
+    let db = sea_orm::Database::connect(opt).await.unwrap();
+
+    let row = db
+        .query_one(Statement::from_string(
+            DbBackend::Sqlite,
+            "PRAGMA foreign_keys",
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.try_get_by_index::<i32>(0).unwrap(), 0);
+
+    let mut opt = sea_orm::ConnectOptions::new(&base_url);
+    opt.acquire_timeout(std::time::Duration::from_secs(2))
+        .after_connect(|_| Box::pin(async { Err(DbErr::Custom("failed".to_owned())) }));
+    assert!(matches!(
+        sea_orm::Database::connect(opt).await,
+        Err(DbErr::Conn(_))
+    ));
+}
+
+/// A query running for a few seconds
+fn slow_query(backend: DbBackend) -> Statement {
+    let sql = match backend {
+        DbBackend::MySql => "SELECT SLEEP(5)",
+        DbBackend::Postgres => "SELECT pg_sleep(5)",
+        DbBackend::Sqlite => {
+            "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 100000000) SELECT COUNT(*) FROM t"
+        }
+    };
+    Statement::from_string(backend, sql)
+}
+
+#[sea_orm_macros::test]
+pub async fn connection_query_timeout() {
+    let ctx = TestContext::new("connection_query_timeout").await;
+    let backend = ctx.db.get_database_backend();
+    let timeout = std::time::Duration::from_millis(100);
+
+    let res = ctx.db.query_one(slow_query(backend).timeout(timeout)).await;
+    assert_eq!(res.map(|_| ()), Err(DbErr::QueryTimeout(timeout)));
+
+    // the query has been cancelled on the server, so the connection can still be used
+    if backend != DbBackend::Sqlite {
+        ctx.db.ping().await.unwrap();
+    }
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+pub async fn connection_query_cancel() {
+    let ctx = TestContext::new("connection_query_cancel").await;
+    let backend = ctx.db.get_database_backend();
+
+    let token = sea_orm::CancellationToken::new();
+    let cancel = token.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        cancel.cancel();
+    });
+    let res = ctx
+        .db
+        .query_one(slow_query(backend).cancel_on(token.clone()))
+        .await;
+    assert_eq!(res.map(|_| ()), Err(DbErr::QueryCancelled));
+
+    // the query has been cancelled on the server, so the connection can still be used
+    if backend != DbBackend::Sqlite {
+        ctx.db.ping().await.unwrap();
+    }
+
+    // a cancelled token fails the queries started afterwards
+    let res = ctx
+        .db
+        .query_one(Statement::from_string(backend, "SELECT 1").cancel_on(token))
+        .await;
+    assert_eq!(res.map(|_| ()), Err(DbErr::QueryCancelled));
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+pub async fn connection_health_check() {
+    let ctx = TestContext::new("connection_health_check").await;
+
+    let health = ctx.db.health_check().await.unwrap();
+    assert_eq!(health.backend, ctx.db.get_database_backend());
+    assert!(!health.version.is_empty());
+    assert_eq!(health.replication_lag, None);
+    assert!(health.pool.is_some());
+    assert!(health.replicas.is_empty());
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn connection_sqlite_options() {
+    use sea_orm::{SqliteJournalMode, SqliteOptions, SqliteSynchronous};
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let mut opt = sea_orm::ConnectOptions::new(&base_url);
+    opt.sqlite(
+        SqliteOptions::new()
+            .journal_mode(SqliteJournalMode::Memory)
+            .synchronous(SqliteSynchronous::Off)
+            .busy_timeout(std::time::Duration::from_millis(1234))
+            .foreign_keys(false),
+    );
+    let db = sea_orm::Database::connect(opt).await.unwrap();
+
+    for (pragma, column, value) in [
+        ("journal_mode", "journal_mode", "memory"),
+        ("synchronous", "synchronous", "0"),
+        ("busy_timeout", "timeout", "1234"),
+        ("foreign_keys", "foreign_keys", "0"),
+    ] {
+        let row = db
+            .query_one(Statement::from_string(
+                DbBackend::Sqlite,
+                format!("SELECT CAST(\"{column}\" AS TEXT) FROM pragma_{pragma}"),
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            row.try_get_by_index::<String>(0).unwrap(),
+            value,
+            "{pragma}"
+        );
+    }
+
+    let mut opt = sea_orm::ConnectOptions::new(&base_url);
+    opt.acquire_timeout(std::time::Duration::from_secs(2))
+        .sqlite(SqliteOptions::new().extension("sea_orm_missing_extension"));
+    assert!(matches!(
+        sea_orm::Database::connect(opt).await,
+        Err(DbErr::Conn(_))
+    ));
+}
+
+#[sea_orm_macros::test]
+pub async fn connection_listen_notify() {
+    use futures::TryStreamExt;
+
+    let ctx = TestContext::new("connection_listen_notify").await;
+    if ctx.db.get_database_backend() != DbBackend::Postgres {
+        assert!(ctx.db.listen("cake_changed").await.is_err());
+        assert!(ctx.db.notify("cake_changed", "1").await.is_err());
+        ctx.delete().await;
+        return;
+    }
+
+    let mut notifications = ctx
+        .db
+        .listen_all(["cake_changed", "fruit_changed"])
+        .await
+        .unwrap();
+    ctx.db.notify("cake_changed", "42").await.unwrap();
+    ctx.db.notify("fruit_changed", "").await.unwrap();
+
+    let notification = notifications.try_next().await.unwrap().unwrap();
+    assert_eq!(notification.channel, "cake_changed");
+    assert_eq!(notification.parse::<i32>().unwrap(), 42);
+    let notification = notifications.try_next().await.unwrap().unwrap();
+    assert_eq!(notification.channel, "fruit_changed");
+    assert_eq!(notification.payload, "");
+
+    ctx.delete().await;
+}
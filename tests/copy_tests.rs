@@ -0,0 +1,135 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, CopyFormat, DatabaseConnection, DbBackend, IntoActiveModel, QueryOrder,
+    Set, TransactionTrait,
+};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("copy_tests").await;
+    create_tables(&ctx.db).await?;
+    copy_in(&ctx.db).await?;
+    copy_out(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn copy_in(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let logs: Vec<_> = (1..=1000)
+        .map(|id| applog::Model {
+            id,
+            action: format!("\"Copied\", {id}\nwith 'quotes' and \\"),
+            json: serde_json::json!({ "id": id, "tags": ["a,b", "\"c\""] }),
+            created_at: "2021-09-17T17:50:20.123456+08:00".parse().unwrap(),
+        })
+        .collect();
+
+    let res = Applog::copy_in(
+        logs.clone()
+            .into_iter()
+            .map(IntoActiveModel::into_active_model),
+    )
+    .exec(db)
+    .await;
+    if db.get_database_backend() != DbBackend::Postgres {
+        assert!(res.is_err());
+        return Ok(());
+    }
+    assert_eq!(res?, 1000);
+    assert_eq!(
+        Applog::find()
+            .order_by_asc(applog::Column::Id)
+            .all(db)
+            .await?,
+        logs
+    );
+
+    let rows = active_enum::Entity::copy_in([
+        active_enum::ActiveModel {
+            category: Set(Some(Category::Big)),
+            color: Set(None),
+            tea: Set(Some(Tea::EverydayTea)),
+            ..Default::default()
+        },
+        active_enum::ActiveModel {
+            category: Set(None),
+            color: Set(Some(Color::White)),
+            tea: Set(None),
+            ..Default::default()
+        },
+    ])
+    .exec(db)
+    .await?;
+    assert_eq!(rows, 2);
+    assert_eq!(
+        active_enum::Entity::find()
+            .order_by_asc(active_enum::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| (model.category, model.color, model.tea))
+            .collect::<Vec<_>>(),
+        [
+            (Some(Category::Big), None, Some(Tea::EverydayTea)),
+            (None, Some(Color::White), None),
+        ]
+    );
+
+    let res = active_enum::Entity::copy_in([
+        active_enum::ActiveModel {
+            category: Set(Some(Category::Big)),
+            ..Default::default()
+        },
+        active_enum::ActiveModel {
+            tea: Set(Some(Tea::BreakfastTea)),
+            ..Default::default()
+        },
+    ])
+    .exec(db)
+    .await;
+    assert!(matches!(res, Err(DbErr::Custom(_))));
+    assert_eq!(active_enum::Entity::find().count(db).await?, 2);
+
+    Ok(())
+}
+
+pub async fn copy_out(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let select = || {
+        active_enum::Entity::find()
+            .filter(active_enum::Column::Id.lte(2))
+            .order_by_asc(active_enum::Column::Id)
+    };
+    let res = select().copy_out(db, CopyFormat::Csv).await;
+    if db.get_database_backend() != DbBackend::Postgres {
+        assert!(res.is_err());
+        return Ok(());
+    }
+    let csv: Vec<Vec<u8>> = select()
+        .copy_out(db, CopyFormat::CsvWithHeader)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(
+        String::from_utf8(csv.concat()).unwrap(),
+        "id,category,color,tea\n1,B,,EverydayTea\n2,,1,\n"
+    );
+
+    let txn = db.begin().await?;
+    let binary: Vec<Vec<u8>> = select()
+        .copy_out(&txn, CopyFormat::Binary)
+        .await?
+        .try_collect()
+        .await?;
+    assert!(binary.concat().starts_with(b"PGCOPY\n\xff\r\n\0"));
+    assert_eq!(active_enum::Entity::find().count(&txn).await?, 2);
+    txn.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, DatabaseConnection, DecimalExpr, Schema};
+use std::str::FromStr;
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("decimal_expr_tests").await;
+    create_invoice_line_table(&ctx.db).await?;
+    insert_invoice_lines(&ctx.db).await?;
+    select_amounts(&ctx.db).await?;
+    update_prices(&ctx.db).await?;
+    select_values(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_invoice_line_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(InvoiceLine);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+fn dec(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+fn big_dec(s: &str) -> BigDecimal {
+    BigDecimal::from_str(s).unwrap()
+}
+
+pub async fn insert_invoice_lines(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for (id, unit_price, quantity, tax_rate) in [
+        (1, "19.99", 3, "0.2"),
+        (2, "0.10", 7, "0.07"),
+        (3, "100.00", 1, "0"),
+    ] {
+        let line = invoice_line::Model {
+            id,
+            unit_price: dec(unit_price),
+            quantity,
+            tax_rate: big_dec(tax_rate),
+        };
+        let result = line.clone().into_active_model().insert(db).await?;
+        assert_eq!(result, line);
+    }
+
+    Ok(())
+}
+
+fn gross(db: &DatabaseConnection) -> DecimalExpr {
+    let backend = db.get_database_backend();
+    DecimalExpr::new(backend, invoice_line::Column::UnitPrice)
+        .mul(invoice_line::Column::Quantity)
+        .mul(DecimalExpr::new(backend, Decimal::ONE).add(invoice_line::Column::TaxRate))
+        .round(2)
+}
+
+pub async fn select_amounts(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let amounts: Vec<(i32, Decimal)> = InvoiceLine::find()
+        .select_only()
+        .column(invoice_line::Column::Id)
+        .expr_as(gross(db), "gross")
+        .order_by_asc(invoice_line::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(
+        amounts,
+        [(1, dec("71.96")), (2, dec("0.75")), (3, dec("100.00"))]
+    );
+
+    let total: Option<BigDecimal> = InvoiceLine::find()
+        .select_only()
+        .expr(gross(db).sum().round(2))
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(total, Some(big_dec("172.71")));
+
+    let ids: Vec<i32> = InvoiceLine::find()
+        .select_only()
+        .column(invoice_line::Column::Id)
+        .filter(gross(db).gt(dec("71.95")))
+        .filter(gross(db).lte(dec("100")))
+        .order_by_asc(invoice_line::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(ids, [1, 3]);
+
+    Ok(())
+}
+
+pub async fn update_prices(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    let res = InvoiceLine::update_many()
+        .col_expr(
+            invoice_line::Column::UnitPrice,
+            DecimalExpr::new(backend, invoice_line::Column::UnitPrice)
+                .mul(dec("1.1"))
+                .round(2)
+                .into(),
+        )
+        .filter(
+            DecimalExpr::new(backend, invoice_line::Column::UnitPrice)
+                .mul(invoice_line::Column::Quantity)
+                .gt(dec("50")),
+        )
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 2);
+
+    let prices: Vec<Decimal> = InvoiceLine::find()
+        .select_only()
+        .column(invoice_line::Column::UnitPrice)
+        .order_by_asc(invoice_line::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(prices, [dec("21.99"), dec("0.10"), dec("110.00")]);
+
+    let average: Option<Decimal> = InvoiceLine::find()
+        .select_only()
+        .expr(
+            DecimalExpr::new(backend, invoice_line::Column::UnitPrice)
+                .avg()
+                .round(2),
+        )
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(average, Some(dec("44.03")));
+
+    Ok(())
+}
+
+pub async fn select_values(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // SQLite returns a value bound as text
+    let values: Option<(Decimal, BigDecimal)> = InvoiceLine::find()
+        .select_only()
+        .expr(Expr::val(dec("10.50")))
+        .expr(Expr::val(big_dec("0.0001")))
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(values, Some((dec("10.50"), big_dec("0.0001"))));
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, DbBackend, Schema};
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("domain_tests").await;
+    create_mailbox_table(&ctx.db).await?;
+    insert_mailbox(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_mailbox_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    if builder == DbBackend::Postgres {
+        db.execute_unprepared("CREATE DOMAIN email_address AS text CHECK (VALUE LIKE '%@%')")
+            .await?;
+        db.execute_unprepared("CREATE DOMAIN quota AS integer CHECK (VALUE > 0)")
+            .await?;
+    }
+    let stmt = Schema::new(builder).create_table_from_entity(Mailbox);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_mailbox(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert_eq!(
+        mailbox::Column::Address.def().get_domain(),
+        Some("email_address")
+    );
+
+    let mailbox = mailbox::Model {
+        id: 1,
+        address: "sea@orm.rs".to_owned(),
+        quota: Some(100),
+    };
+    let result = mailbox.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, mailbox);
+
+    let found = Mailbox::find()
+        .filter(mailbox::Column::Address.eq("sea@orm.rs"))
+        .filter(mailbox::Column::Quota.gt(10))
+        .one(db)
+        .await?;
+    assert_eq!(found, Some(mailbox));
+
+    if db.get_database_backend() == DbBackend::Postgres {
+        // the check of the domain
+        let result = mailbox::ActiveModel {
+            address: Set("sea-orm".to_owned()),
+            quota: Set(None),
+            ..Default::default()
+        }
+        .insert(db)
+        .await;
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
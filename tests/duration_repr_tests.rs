@@ -0,0 +1,80 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, entity::*, DatabaseConnection, IntegerDuration, QueryOrder, QuerySelect,
+    Schema,
+};
+use std::time::Duration;
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("duration_repr_tests").await;
+    create_scheduled_task_table(&ctx.db).await?;
+    insert_scheduled_task(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_scheduled_task_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(ScheduledTask);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_scheduled_task(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let task = scheduled_task::Model {
+        id: 1,
+        name: "cleanup".to_owned(),
+        interval: Duration::from_secs(3600),
+        timeout: Some(Duration::from_micros(2_500_001)),
+    };
+    let result = task.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, task);
+
+    let result = scheduled_task::ActiveModel {
+        name: Set("heartbeat".to_owned()),
+        interval: Set(Duration::from_millis(1_500)),
+        timeout: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(result.interval, Duration::from_millis(1_500));
+    assert_eq!(result.timeout, None);
+
+    let found = ScheduledTask::find()
+        .filter(scheduled_task::Column::Interval.lt(Duration::from_secs(60).into_millis()))
+        .all(db)
+        .await?;
+    assert_eq!(found, [result.clone()]);
+
+    // the stored values are counts of the units
+    let stored: Vec<(i64, Option<i64>)> = ScheduledTask::find()
+        .select_only()
+        .column(scheduled_task::Column::Interval)
+        .column(scheduled_task::Column::Timeout)
+        .order_by_asc(scheduled_task::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(stored, [(3_600_000, Some(2_500_001)), (1_500, None)]);
+
+    let mut task: scheduled_task::ActiveModel = result.into();
+    task.timeout = Set(Some(Duration::from_millis(250)));
+    let task = task.update(db).await?;
+    assert_eq!(task.timeout, Some(Duration::from_millis(250)));
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, entity::*, BlindIndex, Cipher, DatabaseConnection, Encrypted, Schema,
+    Statement,
+};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    sea_orm::set_cipher(XorCipher);
+
+    let ctx = TestContext::new("encrypted_tests").await;
+    create_patient_table(&ctx.db).await?;
+    insert_patient(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+/// A toy cipher, xor-ing the plaintext with a key and a nonce
+struct XorCipher;
+
+impl Cipher for XorCipher {
+    fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = plaintext.len() as u8;
+        let mut ciphertext = vec![nonce];
+        ciphertext.extend(plaintext.iter().map(|b| b ^ 0x5a ^ nonce));
+        ciphertext
+    }
+
+    fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, DbErr> {
+        let (nonce, ciphertext) = ciphertext
+            .split_first()
+            .ok_or_else(|| DbErr::Type("Empty ciphertext".to_owned()))?;
+        Ok(ciphertext.iter().map(|b| b ^ 0x5a ^ nonce).collect())
+    }
+
+    fn blind_index(&self, plaintext: &[u8]) -> Vec<u8> {
+        plaintext.iter().map(|b| b ^ 0xa5).collect()
+    }
+}
+
+pub async fn create_patient_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(Patient);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_patient(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let email = "patient@example.com".to_owned();
+    let patient = patient::Model {
+        id: 1,
+        email: Encrypted(email.clone()),
+        email_index: BlindIndex::of(&email)?,
+        notes: Some(Encrypted("allergic to penicillin".to_owned())),
+    };
+    let result = patient.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, patient);
+
+    // the plaintext isn't stored
+    let row = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT email FROM patient",
+        ))
+        .await?
+        .unwrap();
+    let stored: Vec<u8> = row.try_get("", "email")?;
+    assert_ne!(&stored[1..], email.as_bytes());
+
+    let found = Patient::find()
+        .filter(patient::Column::EmailIndex.eq(BlindIndex::of(&email)?))
+        .one(db)
+        .await?;
+    assert_eq!(found, Some(patient.clone()));
+
+    let mut patient = patient.into_active_model();
+    patient.notes = Set(None);
+    let result = patient.update(db).await?;
+    assert_eq!(result.notes, None);
+    assert_eq!(result.email, Encrypted(email));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, Interval, QueryOrder, Schema};
+use std::time::Duration;
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("interval_tests").await;
+    create_job_table(&ctx.db).await?;
+    insert_job(&ctx.db).await?;
+    filter_job(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_job_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(Job);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_job(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let job = job::Model {
+        id: 1,
+        timeout: Interval(Duration::from_secs(90_000)),
+        elapsed: Some(Interval(-chrono::Duration::milliseconds(1_500))),
+    };
+    let result = job.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, job);
+    assert_eq!(Job::find_by_id(1).one(db).await?, Some(job));
+
+    let job = job::Model {
+        id: 2,
+        timeout: Interval(Duration::from_micros(500)),
+        elapsed: None,
+    };
+    let result = job.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, job);
+
+    Ok(())
+}
+
+pub async fn filter_job(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let jobs = Job::find()
+        .filter(job::Column::Timeout.eq(Interval(Duration::from_secs(90_000))))
+        .all(db)
+        .await?;
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, 1);
+
+    let mut job: job::ActiveModel = jobs[0].clone().into();
+    job.timeout = Set(Interval(Duration::from_millis(250)));
+    let job = job.update(db).await?;
+    assert_eq!(*job.timeout, Duration::from_millis(250));
+
+    Ok(())
+}
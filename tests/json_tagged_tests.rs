@@ -0,0 +1,146 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use domain_event::OrderEvent;
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, entity::*, query::*, DatabaseConnection, JsonTagged, Schema, Statement,
+};
+use serde_json::json;
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("json_tagged_tests").await;
+    create_domain_event_table(&ctx.db).await?;
+    insert_domain_event(&ctx.db).await?;
+    filter_by_tag(&ctx.db).await?;
+    unknown_tag(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_domain_event_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(DomainEvent);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+fn events() -> Vec<domain_event::Model> {
+    vec![
+        domain_event::Model {
+            id: 1,
+            payload: OrderEvent::OrderPlaced {
+                order_id: 1,
+                total: 1200,
+            },
+            compensated_by: Some(OrderEvent::OrderCancelled { order_id: 1 }),
+        },
+        domain_event::Model {
+            id: 2,
+            payload: OrderEvent::OrderShipped {
+                order_id: 2,
+                carrier: "Post".to_owned(),
+            },
+            compensated_by: None,
+        },
+        domain_event::Model {
+            id: 3,
+            payload: OrderEvent::OrderCancelled { order_id: 1 },
+            compensated_by: None,
+        },
+    ]
+}
+
+pub async fn insert_domain_event(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for event in events() {
+        let result = event.clone().into_active_model().insert(db).await?;
+        assert_eq!(result, event);
+    }
+    assert_eq!(DomainEvent::find().all(db).await?, events());
+
+    let payload: Option<Json> = DomainEvent::find_by_id(3)
+        .select_only()
+        .column(domain_event::Column::Payload)
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(payload, Some(json!({ "type": "cancelled", "order_id": 1 })));
+
+    Ok(())
+}
+
+pub async fn filter_by_tag(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    assert_eq!(
+        OrderEvent::TAGS,
+        ["order_placed", "order_shipped", "cancelled"]
+    );
+
+    let shipped = DomainEvent::find()
+        .filter(
+            domain_event::Column::Payload.json_tag_eq::<OrderEvent>(
+                backend,
+                OrderEvent::OrderShipped {
+                    order_id: 0,
+                    carrier: String::new(),
+                }
+                .tag(),
+            ),
+        )
+        .all(db)
+        .await?;
+    assert_eq!(shipped, events()[1..2]);
+
+    let ids: Vec<i32> = DomainEvent::find()
+        .select_only()
+        .column(domain_event::Column::Id)
+        .filter(
+            domain_event::Column::Payload
+                .json_tag_is_in::<OrderEvent, _>(backend, ["order_placed", "cancelled"]),
+        )
+        .order_by_asc(domain_event::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(ids, [1, 3]);
+
+    Ok(())
+}
+
+pub async fn unknown_tag(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    let update = |payload: Json| {
+        DomainEvent::update_many()
+            .col_expr(domain_event::Column::Payload, Expr::value(payload))
+            .filter(domain_event::Column::Id.eq(2))
+            .exec(db)
+    };
+
+    update(json!({ "type": "order_lost", "order_id": 2 })).await?;
+    let err = DomainEvent::find_by_id(2).one(db).await.unwrap_err();
+    assert!(err.to_string().contains(
+        "Unknown `type` tag `order_lost` of `json_tagged_tests::common::features::domain_event::OrderEvent`, expected one of order_placed, order_shipped, cancelled"
+    ));
+
+    update(json!({ "type": "order_shipped", "order_id": 2 })).await?;
+    let err = DomainEvent::find_by_id(2).one(db).await.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Invalid `order_shipped` of `json_tagged_tests::common::features::domain_event::OrderEvent`: missing field `carrier`"));
+
+    update(json!({ "order_id": 2 })).await?;
+    let err = DomainEvent::find_by_id(2).one(db).await.unwrap_err();
+    assert!(err.to_string().contains("has no `type` tag"));
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, PgLtree, QueryOrder};
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("ltree_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_category_tree(&ctx.db).await?;
+    filter_category_tree(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn path(s: &str) -> PgLtree {
+    s.parse().unwrap()
+}
+
+pub async fn insert_category_tree(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let paths = [
+        "Top",
+        "Top.Science",
+        "Top.Science.Astronomy",
+        "Top.Science.Astronomy.Cosmology",
+        "Top.Hobbies",
+        "Top.Hobbies.Amateurs_Astronomy",
+    ];
+    for (i, p) in paths.into_iter().enumerate() {
+        let category = category_tree::Model {
+            id: i as i32 + 1,
+            name: p.rsplit('.').next().unwrap().to_owned(),
+            path: path(p),
+        };
+        let result = category.clone().into_active_model().insert(db).await?;
+        assert_eq!(result, category);
+    }
+
+    Ok(())
+}
+
+pub async fn filter_category_tree(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let find = |filter| {
+        CategoryTree::find()
+            .filter(filter)
+            .order_by_asc(category_tree::Column::Id)
+            .all(db)
+    };
+    let names = |models: Vec<category_tree::Model>| {
+        models
+            .into_iter()
+            .map(|model| model.name)
+            .collect::<Vec<_>>()
+    };
+
+    let ancestors =
+        find(category_tree::Column::Path.ltree_ancestor_of(path("Top.Science.Astronomy"))).await?;
+    assert_eq!(names(ancestors), ["Top", "Science", "Astronomy"]);
+
+    let descendants =
+        find(category_tree::Column::Path.ltree_descendant_of(path("Top.Science"))).await?;
+    assert_eq!(names(descendants), ["Science", "Astronomy", "Cosmology"]);
+
+    let matches = find(category_tree::Column::Path.ltree_matches("*.Astronomy.*")).await?;
+    assert_eq!(names(matches), ["Astronomy", "Cosmology"]);
+
+    let parent = CategoryTree::find()
+        .filter(category_tree::Column::Path.eq(path("Top.Science.Astronomy").parent().unwrap()))
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(parent.name, "Science");
+
+    Ok(())
+}
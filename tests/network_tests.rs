@@ -0,0 +1,107 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, QueryOrder, Schema};
+
+#[sea_orm_macros::test]
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("network_tests").await;
+    create_host_table(&ctx.db).await?;
+    insert_host(&ctx.db).await?;
+    #[cfg(feature = "sqlx-postgres")]
+    filter_host(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
+pub async fn create_host_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(Host);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
+pub async fn insert_host(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let hosts = [
+        ("gateway", "192.168.1.1/32", Some("00:1A:2B:3C:4D:5E")),
+        ("printer", "192.168.1.20/32", None),
+        ("nas", "10.0.0.5/32", Some("AA:BB:CC:DD:EE:FF")),
+        ("router", "2001:db8::1/128", None),
+    ];
+    for (i, (hostname, ip, mac)) in hosts.into_iter().enumerate() {
+        let host = host::Model {
+            id: i as i32 + 1,
+            hostname: hostname.to_owned(),
+            ip: ip.parse().unwrap(),
+            mac: mac.map(|mac| mac.parse().unwrap()),
+        };
+        let result = host.clone().into_active_model().insert(db).await?;
+        assert_eq!(result, host);
+    }
+
+    let gateway = Host::find()
+        .filter(host::Column::Ip.eq("192.168.1.1/32".parse::<ipnetwork::IpNetwork>().unwrap()))
+        .one(db)
+        .await?
+        .unwrap();
+    assert_eq!(gateway.hostname, "gateway");
+
+    Ok(())
+}
+
+#[cfg(all(feature = "with-ipnetwork", feature = "with-mac_address"))]
+pub async fn filter_host(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let subnet: ipnetwork::IpNetwork = "192.168.1.0/24".parse().unwrap();
+    let hosts = Host::find()
+        .filter(host::Column::Ip.inet_contained_by(subnet))
+        .order_by_asc(host::Column::Id)
+        .all(db)
+        .await?;
+    assert_eq!(
+        hosts
+            .into_iter()
+            .map(|host| host.hostname)
+            .collect::<Vec<_>>(),
+        ["gateway", "printer"]
+    );
+
+    let hosts = Host::find()
+        .filter(
+            host::Column::Ip
+                .inet_contained_by_or_eq("10.0.0.5/32".parse::<ipnetwork::IpNetwork>().unwrap()),
+        )
+        .all(db)
+        .await?;
+    assert_eq!(
+        hosts
+            .into_iter()
+            .map(|host| host.hostname)
+            .collect::<Vec<_>>(),
+        ["nas"]
+    );
+
+    let hosts = Host::find()
+        .filter(
+            host::Column::Ip
+                .inet_contains_or_eq("2001:db8::1/128".parse::<ipnetwork::IpNetwork>().unwrap()),
+        )
+        .all(db)
+        .await?;
+    assert_eq!(
+        hosts
+            .into_iter()
+            .map(|host| host.hostname)
+            .collect::<Vec<_>>(),
+        ["router"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, entity::*, DatabaseConnection, FromQueryResult, NumericInteger, QueryOrder,
+    QuerySelect, Schema,
+};
+
+#[sea_orm_macros::test]
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-postgres"))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("numeric_integer_tests").await;
+    create_token_transfer_table(&ctx.db).await?;
+    insert_token_transfer(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_token_transfer_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(TokenTransfer);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_token_transfer(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let transfer = token_transfer::Model {
+        id: 1,
+        amount: u128::MAX,
+        balance_change: Some(i128::MIN),
+    };
+    let result = transfer.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, transfer);
+
+    let result = token_transfer::ActiveModel {
+        id: Set(2),
+        amount: Set(100_000_000_000_000_000_000),
+        balance_change: Set(None),
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(result.amount, 100_000_000_000_000_000_000);
+    assert_eq!(result.balance_change, None);
+
+    let found = TokenTransfer::find()
+        .filter(token_transfer::Column::Amount.gt((u64::MAX as u128).into_numeric()))
+        .order_by_asc(token_transfer::Column::Amount)
+        .all(db)
+        .await?;
+    assert_eq!(found, [result, transfer]);
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct TotalAmount {
+        total: u128,
+    }
+
+    // the amount is read with `TryGetable`
+    let total = TokenTransfer::find()
+        .select_only()
+        .column_as(token_transfer::Column::Amount.min(), "total")
+        .into_model::<TotalAmount>()
+        .one(db)
+        .await?;
+    assert_eq!(
+        total,
+        Some(TotalAmount {
+            total: 100_000_000_000_000_000_000
+        })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, DatabaseConnection, IntoActiveModel, Pipeline, QueryOrder, QuerySelect,
+    Set, TransactionTrait,
+};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("pipeline_tests").await;
+    create_tables(&ctx.db).await?;
+    pipeline(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn pipeline(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let log = applog::Model {
+        id: 1,
+        action: "It's \"piped\"; twice".to_owned(),
+        json: serde_json::json!({ "piped": true }),
+        created_at: "2021-09-17T17:50:20+08:00".parse().unwrap(),
+    };
+    Applog::insert(log.clone().into_active_model())
+        .exec(db)
+        .await?;
+    let active_enum = active_enum::Model {
+        id: 1,
+        category: Some(Category::Big),
+        color: None,
+        tea: Some(Tea::BreakfastTea),
+    };
+    active_enum::Entity::insert(active_enum.clone().into_active_model())
+        .exec(db)
+        .await?;
+
+    let mut pipeline = Pipeline::new();
+    let logs = pipeline.add(Applog::find().filter(applog::Column::Action.eq(&log.action)));
+    let none = pipeline.add(Applog::find().filter(applog::Column::Id.gt(1)));
+    let enums = pipeline.add(active_enum::Entity::find());
+    let ids = pipeline.add_selector(
+        active_enum::Entity::find()
+            .select_only()
+            .column(active_enum::Column::Id)
+            .into_tuple::<i32>(),
+    );
+    let mut results = pipeline.exec(db).await?;
+    assert_eq!(results.take(logs)?, [log.clone()]);
+    assert_eq!(results.take(none)?, []);
+    assert_eq!(results.take(enums)?, [active_enum]);
+    assert_eq!(results.take(ids)?, [1]);
+
+    let txn = db.begin().await?;
+    let mut pipeline = Pipeline::new();
+    let logs = pipeline.add(Applog::find());
+    let count = pipeline.add_selector(
+        Applog::find()
+            .select_only()
+            .column_as(applog::Column::Id.count(), "count")
+            .into_tuple::<i64>(),
+    );
+    let mut results = pipeline.exec(&txn).await?;
+    assert_eq!(results.take(logs)?, [log]);
+    assert_eq!(results.take(count)?, [1]);
+    txn.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+use sea_orm::{ConnectionTrait, Database, DbErr};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    dotenv::from_filename(".env.local").ok();
+    dotenv::from_filename(".env").ok();
+
+    let base_url =
+        std::env::var("DATABASE_URL").expect("Enviroment variable 'DATABASE_URL' not set");
+    let url = if cfg!(feature = "sqlx-mysql") || cfg!(feature = "sqlx-postgres") {
+        format!("{base_url}/provision_tests")
+    } else {
+        let path = std::env::temp_dir().join("sea_orm_provision_tests.db");
+        format!("sqlite://{}", path.display())
+    };
+
+    create_and_drop_database(&url).await
+}
+
+pub async fn create_and_drop_database(url: &str) -> Result<(), DbErr> {
+    Database::drop_database(url).await?;
+    Database::create_database(url).await?;
+    assert!(Database::create_database(url).await.is_err());
+
+    let db = Database::connect(url).await?;
+    db.execute_unprepared("CREATE TABLE provisioned (id integer NOT NULL PRIMARY KEY)")
+        .await?;
+    db.close().await?;
+
+    // The database is gone along with its tables, so it can be created anew
+    Database::drop_database(url).await?;
+    Database::drop_database(url).await?;
+    Database::create_database(url).await?;
+    let db = Database::connect(url).await?;
+    db.execute_unprepared("CREATE TABLE provisioned (id integer NOT NULL PRIMARY KEY)")
+        .await?;
+    db.close().await?;
+    Database::drop_database(url).await?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, PgRange, QueryOrder};
+use std::ops::Bound;
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("range_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_booking(&ctx.db).await?;
+    filter_booking(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn datetime(s: &str) -> DateTimeWithTimeZone {
+    s.parse().unwrap()
+}
+
+pub async fn insert_booking(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let booking = booking::Model {
+        id: 1,
+        seats: PgRange::from(1..10),
+        period: PgRange::from(
+            datetime("2021-09-17T17:50:20.5+08:00")..datetime("2021-09-18T17:50:20+08:00"),
+        ),
+        price: Some(PgRange::new(
+            Bound::Excluded("9.99".parse().unwrap()),
+            Bound::Unbounded,
+        )),
+    };
+    let result = booking.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, booking);
+
+    // discrete ranges are normalized to include the lower bound and exclude the upper bound
+    let result = booking::ActiveModel {
+        id: Set(2),
+        seats: Set(PgRange::from(20..=29)),
+        period: Set(PgRange::from(datetime("2021-09-19T00:00:00Z")..)),
+        price: Set(None),
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(result.seats, PgRange::from(20..30));
+    assert_eq!(
+        result.period,
+        PgRange::from(datetime("2021-09-19T00:00:00Z")..)
+    );
+    assert_eq!(result.price, None);
+
+    Ok(())
+}
+
+pub async fn filter_booking(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let ids = |select: Select<Booking>| async move {
+        select
+            .order_by_asc(booking::Column::Id)
+            .all(db)
+            .await
+            .map(|models| models.into_iter().map(|m| m.id).collect::<Vec<_>>())
+    };
+
+    assert_eq!(
+        ids(Booking::find().filter(booking::Column::Seats.range_overlaps(PgRange::from(9..21))))
+            .await?,
+        [1, 2]
+    );
+    assert_eq!(
+        ids(Booking::find().filter(booking::Column::Seats.range_contains(25))).await?,
+        [2]
+    );
+    assert_eq!(
+        ids(Booking::find()
+            .filter(booking::Column::Seats.range_contains_range(PgRange::from(2..=4))))
+        .await?,
+        [1]
+    );
+    assert_eq!(
+        ids(Booking::find().filter(booking::Column::Seats.range_contained_by(PgRange::from(..15))))
+            .await?,
+        [1]
+    );
+    assert_eq!(
+        ids(Booking::find()
+            .filter(booking::Column::Period.range_contains(datetime("2021-09-18T00:00:00Z"))))
+        .await?,
+        [1]
+    );
+
+    Ok(())
+}
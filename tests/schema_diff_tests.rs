@@ -0,0 +1,98 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*,
+    sea_query::{Alias, ColumnDef, Table},
+    ConnectionTrait, DatabaseConnection, SchemaChange, SchemaDiff,
+};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("schema_diff_tests").await;
+    create_outdated_tables(&ctx.db).await?;
+    apply_schema_diff(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_outdated_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Table::create()
+        .table(Alias::new("scheduled_task"))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Alias::new("name")).string().not_null())
+        .col(
+            ColumnDef::new(Alias::new("interval"))
+                .big_integer()
+                .not_null(),
+        )
+        .col(ColumnDef::new(Alias::new("legacy")).string())
+        .to_owned();
+    db.execute(builder.build(&stmt)).await?;
+
+    let stmt = Table::create()
+        .table(Alias::new("obsolete_report"))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .primary_key(),
+        )
+        .to_owned();
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+fn schema_diff(db: &DatabaseConnection) -> SchemaDiff {
+    SchemaDiff::new(db.get_database_backend())
+        .entity(ScheduledTask)
+        .entity(InvoiceLine)
+        .drop_columns()
+        .drop_tables()
+}
+
+pub async fn apply_schema_diff(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let changes = schema_diff(db).changes(db).await?;
+    assert_eq!(
+        changes
+            .changes()
+            .iter()
+            .map(|change| match change {
+                SchemaChange::CreateTable { table, .. } => format!("create {table}"),
+                SchemaChange::AddColumn { table, column, .. } => format!("add {table}.{column}"),
+                SchemaChange::DropColumn { table, column } => format!("drop {table}.{column}"),
+                SchemaChange::DropTable { table } => format!("drop {table}"),
+            })
+            .collect::<Vec<_>>(),
+        [
+            "add scheduled_task.timeout",
+            "drop scheduled_task.legacy",
+            "create invoice_line",
+            "drop obsolete_report",
+        ]
+    );
+    assert!(changes.down_statements().is_none());
+
+    for stmt in changes.up_statements() {
+        db.execute(stmt).await?;
+    }
+    assert!(schema_diff(db).changes(db).await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, entity::*, ConnectOptions, Database, DatabaseConnection, DbBackend,
+    NaiveTimezone, Schema, Statement, TimezonePolicy, TransactionTrait,
+};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    // a time zone without daylight saving time
+    std::env::set_var("TZ", "Asia/Tokyo");
+
+    let ctx = TestContext::new("timezone_policy_tests").await;
+    utc_policy(&ctx).await?;
+    local_policy(&ctx).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn connect(policy: TimezonePolicy) -> Result<DatabaseConnection, DbErr> {
+    let base_url =
+        std::env::var("DATABASE_URL").expect("Enviroment variable 'DATABASE_URL' not set");
+    let url = if base_url.starts_with("sqlite") {
+        base_url
+    } else {
+        format!("{base_url}/timezone_policy_tests")
+    };
+    let mut options = ConnectOptions::new(url);
+    options.sqlx_logging(false).timezone_policy(policy);
+    let db = Database::connect(options).await?;
+
+    let builder = db.get_database_backend();
+    let mut stmt = Schema::new(builder).create_table_from_entity(Reminder);
+    db.execute(builder.build(stmt.if_not_exists())).await?;
+
+    Ok(db)
+}
+
+fn reminder(id: i32) -> reminder::Model {
+    reminder::Model {
+        id,
+        remind_at: "2024-03-01T12:30:00Z".parse().unwrap(),
+        snoozed_until: Some("2024-03-02T08:00:00+09:00".parse().unwrap()),
+    }
+}
+
+async fn select_text(db: &DatabaseConnection, id: i32) -> Result<(String, String), DbErr> {
+    let sql = match db.get_database_backend() {
+        DbBackend::Postgres => {
+            "SELECT CAST(remind_at AS text) AS remind_at, CAST(snoozed_until AS text) AS snoozed_until FROM reminder WHERE id = $1"
+        }
+        DbBackend::MySql => {
+            "SELECT CAST(remind_at AS char) AS remind_at, CAST(snoozed_until AS char) AS snoozed_until FROM reminder WHERE id = ?"
+        }
+        DbBackend::Sqlite => "SELECT remind_at, snoozed_until FROM reminder WHERE id = ?",
+    };
+    let stmt = Statement::from_sql_and_values(db.get_database_backend(), sql, [id.into()]);
+    let row = db
+        .query_one(stmt)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("reminder".to_owned()))?;
+    Ok((
+        row.try_get("", "remind_at")?,
+        row.try_get("", "snoozed_until")?,
+    ))
+}
+
+pub async fn utc_policy(ctx: &TestContext) -> Result<(), DbErr> {
+    let db = connect(TimezonePolicy::new().utc_as_naive(true)).await?;
+
+    let model = reminder(1).into_active_model().insert(&db).await?;
+    assert_eq!(model, reminder(1));
+    assert_eq!(Reminder::find_by_id(1).one(&db).await?, Some(reminder(1)));
+
+    if db.get_database_backend() == DbBackend::Postgres {
+        assert_eq!(
+            select_text(&db, 1).await?,
+            (
+                "2024-03-01 12:30:00".to_owned(),
+                "2024-03-01 23:00:00".to_owned()
+            )
+        );
+        // without the policy, a `DateTimeUtc` can't be read from a `timestamp`
+        assert!(Reminder::find_by_id(1).one(&ctx.db).await.is_err());
+    }
+
+    // in a transaction and a stream
+    let txn = db.begin().await?;
+    assert_eq!(Reminder::find_by_id(1).one(&txn).await?, Some(reminder(1)));
+    txn.commit().await?;
+    let mut stream = Reminder::find().stream(&db).await?;
+    assert_eq!(stream.try_next().await?, Some(reminder(1)));
+
+    Ok(())
+}
+
+pub async fn local_policy(_ctx: &TestContext) -> Result<(), DbErr> {
+    let db = connect(
+        TimezonePolicy::new()
+            .naive(NaiveTimezone::Local)
+            .utc_as_naive(true),
+    )
+    .await?;
+
+    let txn = db.begin().await?;
+    reminder(2).into_active_model().insert(&txn).await?;
+    txn.commit().await?;
+
+    // the values are stored in local time
+    assert_eq!(
+        select_text(&db, 2).await?,
+        (
+            "2024-03-01 21:30:00".to_owned(),
+            "2024-03-02 08:00:00".to_owned()
+        )
+    );
+    assert_eq!(Reminder::find_by_id(2).one(&db).await?, Some(reminder(2)));
+
+    // filters are bound in local time too
+    let found = Reminder::find()
+        .filter(
+            reminder::Column::RemindAt.gt("2024-03-01T12:00:00Z".parse::<DateTimeUtc>().unwrap()),
+        )
+        .filter(
+            reminder::Column::RemindAt.lt("2024-03-01T13:00:00Z".parse::<DateTimeUtc>().unwrap()),
+        )
+        .all(&db)
+        .await?;
+    assert_eq!(found, vec![reminder(2)]);
+
+    let mut stream = Reminder::find_by_id(2).stream(&db).await?;
+    assert_eq!(stream.try_next().await?, Some(reminder(2)));
+
+    Ok(())
+}
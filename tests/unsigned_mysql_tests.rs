@@ -0,0 +1,113 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, DatabaseConnection, Schema};
+use serde_json::json;
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-mysql")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("unsigned_mysql_tests").await;
+    create_ledger_entry_table(&ctx.db).await?;
+    full_range(&ctx.db).await?;
+    stream_partitioned(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_ledger_entry_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(LedgerEntry);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn full_range(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let first = ledger_entry::ActiveModel {
+        account_id: Set(u64::MAX),
+        parent_id: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(
+        first,
+        ledger_entry::Model {
+            id: 1,
+            account_id: u64::MAX,
+            parent_id: None,
+        }
+    );
+
+    // a key above `i64::MAX`, and the auto increment after it
+    let above = ledger_entry::Model {
+        id: i64::MAX as u64 + 1,
+        account_id: i64::MAX as u64 + 1,
+        parent_id: Some(u64::MAX),
+    };
+    let res = LedgerEntry::insert(above.clone().into_active_model())
+        .exec(db)
+        .await?;
+    assert_eq!(res.last_insert_id, above.id);
+    let next = ledger_entry::ActiveModel {
+        account_id: Set(2),
+        parent_id: Set(Some(above.id)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(next.id, above.id + 1);
+
+    let found = LedgerEntry::find()
+        .filter(ledger_entry::Column::AccountId.gt(i64::MAX as u64))
+        .order_by_asc(ledger_entry::Column::Id)
+        .all(db)
+        .await?;
+    assert_eq!(found, [first, above.clone()]);
+    assert_eq!(
+        LedgerEntry::find_by_id(above.id).one(db).await?,
+        Some(above.clone())
+    );
+
+    assert_eq!(
+        LedgerEntry::find_by_id(above.id)
+            .select_only()
+            .column(ledger_entry::Column::ParentId)
+            .into_json()
+            .one(db)
+            .await?,
+        Some(json!({ "parent_id": u64::MAX }))
+    );
+
+    // a signed `COUNT(*)` is read into a `u64` too
+    assert_eq!(LedgerEntry::find().count(db).await?, 3);
+    let count: Option<u64> = LedgerEntry::find()
+        .select_only()
+        .column_as(ledger_entry::Column::Id.count(), "count")
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(count, Some(3));
+
+    Ok(())
+}
+
+pub async fn stream_partitioned(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let partitions = LedgerEntry::find().stream_partitioned(db, 4).await?;
+    assert_eq!(partitions.len(), 4);
+
+    let mut ids: Vec<u64> = futures::stream::select_all(partitions)
+        .map_ok(|entry| entry.id)
+        .try_collect()
+        .await?;
+    ids.sort_unstable();
+    assert_eq!(ids, [1, i64::MAX as u64 + 1, i64::MAX as u64 + 2]);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, Schema};
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("unsigned_postgres_tests").await;
+    create_ledger_entry_table(&ctx.db).await?;
+    insert_ledger_entry(&ctx.db).await?;
+    out_of_range(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_ledger_entry_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(LedgerEntry);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_ledger_entry(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let result = ledger_entry::ActiveModel {
+        account_id: Set(i64::MAX as u64),
+        parent_id: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(
+        result,
+        ledger_entry::Model {
+            id: 1,
+            account_id: i64::MAX as u64,
+            parent_id: None,
+        }
+    );
+
+    let found = LedgerEntry::find()
+        .filter(ledger_entry::Column::AccountId.is_in([i64::MAX as u64, 2]))
+        .all(db)
+        .await?;
+    assert_eq!(found, [result]);
+
+    Ok(())
+}
+
+pub async fn out_of_range(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // a `u64` beyond the range of `bigint` isn't bound
+    let result = ledger_entry::ActiveModel {
+        account_id: Set(u64::MAX),
+        parent_id: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await;
+    assert!(matches!(
+        result,
+        Err(DbErr::TryIntoErr {
+            from: "u64",
+            into: "i64",
+            ..
+        })
+    ));
+
+    // a negative `bigint` isn't read
+    db.execute_unprepared("INSERT INTO ledger_entry (account_id, parent_id) VALUES (1, -1)")
+        .await?;
+    let result = LedgerEntry::find()
+        .filter(ledger_entry::Column::AccountId.eq(1u64))
+        .one(db)
+        .await;
+    assert!(matches!(
+        result,
+        Err(DbErr::TryIntoErr {
+            from: "i64",
+            into: "u64",
+            ..
+        })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, DatabaseConnection, Schema, UuidRepr};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("uuid_repr_tests").await;
+    create_device_table(&ctx.db).await?;
+    insert_device(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_device_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert_eq!(
+        device::Column::Id.def().get_column_type(),
+        &ColumnType::Binary(16)
+    );
+
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(Device);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_device(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // a version 1 UUID, ordered by time once swapped
+    let session_id = Uuid::parse_str("6ccd780c-baba-1026-9564-5b8c656024db").unwrap();
+    let device = device::Model {
+        id: Uuid::new_v4(),
+        name: "Sensor".to_owned(),
+        session_id: Some(session_id),
+    };
+    let result = device.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, device);
+
+    // the bytes stored
+    let bytes: Option<(Vec<u8>, Vec<u8>)> = Device::find()
+        .select_only()
+        .column(device::Column::Id)
+        .column(device::Column::SessionId)
+        .into_tuple()
+        .one(db)
+        .await?;
+    assert_eq!(
+        bytes,
+        Some((
+            device.id.as_bytes().to_vec(),
+            hex_bytes("1026baba6ccd780c95645b8c656024db")
+        ))
+    );
+
+    // the values of filters are converted too
+    assert_eq!(
+        Device::find_by_id(device.id).one(db).await?,
+        Some(device.clone())
+    );
+    assert_eq!(
+        Device::find()
+            .filter(device::Column::SessionId.is_in([session_id, Uuid::nil()]))
+            .all(db)
+            .await?,
+        [device.clone()]
+    );
+
+    let mut active = device.clone().into_active_model();
+    active.session_id = Set(None);
+    let updated = active.update(db).await?;
+    assert_eq!(updated.session_id, None);
+    assert_eq!(
+        Device::find()
+            .filter(device::Column::SessionId.is_null())
+            .one(db)
+            .await?,
+        Some(updated)
+    );
+
+    let res = Device::delete_by_id(device.id).exec(db).await?;
+    assert_eq!(res.rows_affected, 1);
+
+    Ok(())
+}
+
+fn hex_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
@@ -0,0 +1,69 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection, DbBackend, Schema};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("value_converter_tests").await;
+    create_subscriber_table(&ctx.db).await?;
+    insert_subscriber(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_subscriber_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let builder = db.get_database_backend();
+    let stmt = Schema::new(builder).create_table_from_entity(Subscriber);
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_subscriber(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use subscriber::{ApiKey, Plan};
+
+    assert_eq!(
+        subscriber::Column::Plan.def().get_column_type(),
+        &ColumnType::SmallInteger
+    );
+
+    let subscriber = subscriber::Model {
+        id: 1,
+        plan: Plan::Pro,
+        api_key: Some(ApiKey::new("secret")),
+    };
+    let result = subscriber.clone().into_active_model().insert(db).await?;
+    assert_eq!(result, subscriber);
+
+    let found = Subscriber::find()
+        .filter(subscriber::Column::Plan.eq(Plan::Pro.into_code()))
+        .one(db)
+        .await?;
+    assert_eq!(found, Some(subscriber.clone()));
+
+    let mut subscriber = subscriber.into_active_model();
+    subscriber.plan = Set(Plan::Free);
+    subscriber.api_key = Set(None);
+    let result = subscriber.update(db).await?;
+    assert_eq!(
+        result,
+        subscriber::Model {
+            id: 1,
+            plan: Plan::Free,
+            api_key: None,
+        }
+    );
+
+    // a stored value rejected by the `load_with` function
+    db.execute_unprepared("UPDATE subscriber SET plan = 9")
+        .await?;
+    let result = Subscriber::find_by_id(1).one(db).await;
+    assert_eq!(result, Err(DbErr::Type("Unknown plan code 9".to_owned())));
+
+    Ok(())
+}